@@ -0,0 +1,271 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels computing the similarity of vectors stored in a [`FixedSizeListArray`]
+//!
+//! Each kernel compares a query vector to the list in every row of an array of
+//! `FixedSizeList<Float32>` or `FixedSizeList<Float16>`, returning a [`Float32Array`]
+//! with a value for each row. Rows that are null, or contain null values, are null
+//! in the output.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_arith::distance::{cosine_similarity, dot_product, l2_distance};
+//! # use arrow_array::{FixedSizeListArray, Float32Array};
+//! let values = Arc::new(Float32Array::from(vec![1., 0., 3., 4., 0., 0.]));
+//! let vectors = FixedSizeListArray::try_from_flat(values, 2, None).unwrap();
+//! let query = [1., 1.];
+//!
+//! let dot = dot_product(&vectors, &query).unwrap();
+//! assert_eq!(dot.values(), &[1., 7., 0.]);
+//!
+//! let l2 = l2_distance(&vectors, &query).unwrap();
+//! assert_eq!(l2.values()[..2], [1., 3.6055512]);
+//!
+//! // The cosine similarity of a zero vector is NaN
+//! let cosine = cosine_similarity(&vectors, &query).unwrap();
+//! assert_eq!(cosine.value(1), 7. / (5. * 2_f32.sqrt()));
+//! assert!(cosine.value(2).is_nan());
+//! ```
+
+use arrow_array::builder::BooleanBufferBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float16Type, Float32Type};
+use arrow_array::*;
+use arrow_buffer::NullBuffer;
+use arrow_schema::{ArrowError, DataType};
+
+/// The number of independent accumulators used to sum products, allowing the
+/// compiler to vectorize what would otherwise be a sequential floating point sum
+const LANES: usize = 8;
+
+/// Returns the dot product of `query` and the vector in each row of `array`
+///
+/// `array` must be a `FixedSizeList<Float32>` or `FixedSizeList<Float16>` with lists of
+/// the same length as `query`, see the [module docs](self) for details
+pub fn dot_product(array: &dyn Array, query: &[f32]) -> Result<Float32Array, ArrowError> {
+    map_vectors(array, query, |v| sum_lanes(v, query, |a, b| a * b))
+}
+
+/// Returns the cosine similarity of `query` and the vector in each row of `array`
+///
+/// This is NaN if either vector has a magnitude of zero. `array` must be a
+/// `FixedSizeList<Float32>` or `FixedSizeList<Float16>` with lists of the same length
+/// as `query`, see the [module docs](self) for details
+pub fn cosine_similarity(array: &dyn Array, query: &[f32]) -> Result<Float32Array, ArrowError> {
+    let query_norm = sum_lanes(query, query, |a, b| a * b).sqrt();
+    map_vectors(array, query, |v| {
+        let dot = sum_lanes(v, query, |a, b| a * b);
+        let norm = sum_lanes(v, v, |a, b| a * b).sqrt();
+        match norm == 0. || query_norm == 0. {
+            true => f32::NAN,
+            false => dot / (norm * query_norm),
+        }
+    })
+}
+
+/// Returns the euclidean distance between `query` and the vector in each row of `array`
+///
+/// `array` must be a `FixedSizeList<Float32>` or `FixedSizeList<Float16>` with lists of
+/// the same length as `query`, see the [module docs](self) for details
+pub fn l2_distance(array: &dyn Array, query: &[f32]) -> Result<Float32Array, ArrowError> {
+    map_vectors(array, query, |v| {
+        sum_lanes(v, query, |a, b| (a - b) * (a - b)).sqrt()
+    })
+}
+
+/// Returns the sum of `f` applied to the corresponding elements of `a` and `b`
+#[inline]
+fn sum_lanes(a: &[f32], b: &[f32], f: impl Fn(f32, f32) -> f32) -> f32 {
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let remainder = a_chunks.remainder().iter().zip(b_chunks.remainder());
+
+    let mut acc = [0_f32; LANES];
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for i in 0..LANES {
+            acc[i] += f(a[i], b[i]);
+        }
+    }
+    let sum: f32 = remainder.map(|(a, b)| f(*a, *b)).sum();
+    sum + acc.iter().sum::<f32>()
+}
+
+/// Applies `op` to the vector in each row of `array`, which must have the same length
+/// as `query`
+fn map_vectors(
+    array: &dyn Array,
+    query: &[f32],
+    op: impl Fn(&[f32]) -> f32,
+) -> Result<Float32Array, ArrowError> {
+    let list = array.as_fixed_size_list_opt().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Expected FixedSizeList of Float32 or Float16, got {}",
+            array.data_type()
+        ))
+    })?;
+    let size = list.value_length() as usize;
+    if size != query.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Query vector of length {} does not match FixedSizeList of size {size}",
+            query.len()
+        )));
+    }
+
+    let values = list.flatten();
+    let results: Vec<f32> = match values.data_type() {
+        _ if size == 0 => vec![op(&[]); list.len()],
+        DataType::Float32 => {
+            let values = values.as_primitive::<Float32Type>();
+            values.values().chunks_exact(size).map(op).collect()
+        }
+        DataType::Float16 => {
+            let values = values.as_primitive::<Float16Type>();
+            let mut vector = vec![0_f32; size];
+            values
+                .values()
+                .chunks_exact(size)
+                .map(|v| {
+                    vector.iter_mut().zip(v).for_each(|(d, s)| *d = s.to_f32());
+                    op(&vector)
+                })
+                .collect()
+        }
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected FixedSizeList of Float32 or Float16, got FixedSizeList of {d}"
+            )))
+        }
+    };
+
+    let nulls = match values.logical_nulls().filter(|n| n.null_count() > 0) {
+        Some(value_nulls) => {
+            let mut builder = BooleanBufferBuilder::new(list.len());
+            for i in 0..list.len() {
+                let valid = value_nulls.slice(i * size, size).null_count() == 0;
+                builder.append(valid && list.is_valid(i));
+            }
+            Some(NullBuffer::new(builder.finish()))
+        }
+        None => list.nulls().cloned(),
+    };
+    Ok(Float32Array::new(results.into(), nulls))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+    use half::f16;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_distance_nulls() {
+        let values = Float32Array::from(vec![
+            Some(1.),
+            Some(2.),
+            Some(3.),
+            None,
+            Some(5.),
+            Some(6.),
+            Some(7.),
+            Some(8.),
+            Some(9.),
+            Some(10.),
+        ]);
+        let nulls = NullBuffer::from(vec![true, true, true, false, true]);
+        let vectors = FixedSizeListArray::from_flat(Arc::new(values), 2, Some(nulls));
+        let query = [1., -1.];
+
+        let dot = dot_product(&vectors, &query).unwrap();
+        assert_eq!(
+            dot.iter().collect::<Vec<_>>(),
+            vec![Some(-1.), None, Some(-1.), None, Some(-1.)]
+        );
+
+        let sliced = vectors.slice(2, 3);
+        let l2 = l2_distance(&sliced, &[6., 6.]).unwrap();
+        assert_eq!(
+            l2.iter().collect::<Vec<_>>(),
+            vec![Some(1.), None, Some(5.)]
+        );
+    }
+
+    #[test]
+    fn test_distance_long_vectors() {
+        // Exercise both the accumulator lanes and the remainder
+        let size = 2 * LANES + 3;
+        let values: Vec<f32> = (0..3 * size).map(|x| x as f32).collect();
+        let query: Vec<f32> = (0..size).map(|x| (x % 3) as f32).collect();
+
+        let expected: Vec<f32> = values
+            .chunks_exact(size)
+            .map(|v| v.iter().zip(&query).map(|(a, b)| a * b).sum())
+            .collect();
+
+        let vectors = FixedSizeListArray::from_flat(
+            Arc::new(Float32Array::from(values.clone())),
+            size as i32,
+            None,
+        );
+        let dot = dot_product(&vectors, &query).unwrap();
+        assert_eq!(dot.values(), expected.as_slice());
+        assert_eq!(dot.null_count(), 0);
+
+        let values: Vec<_> = values.into_iter().map(f16::from_f32).collect();
+        let vectors =
+            FixedSizeListArray::from_flat(Arc::new(Float16Array::from(values)), size as i32, None);
+        let dot = dot_product(&vectors, &query).unwrap();
+        assert_eq!(dot.values(), expected.as_slice());
+
+        let cosine = cosine_similarity(&vectors, &query).unwrap();
+        let similarity = cosine_similarity(&vectors.slice(0, 1), &query).unwrap();
+        assert_eq!(cosine.value(0), similarity.value(0));
+        assert!(cosine.values().iter().all(|x| (0. ..=1.).contains(x)));
+    }
+
+    #[test]
+    fn test_distance_invalid() {
+        let vectors = FixedSizeListArray::from_flat(
+            Arc::new(Float32Array::from(vec![1., 2., 3., 4.])),
+            2,
+            None,
+        );
+        let err = dot_product(&vectors, &[1., 2., 3.]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Query vector of length 3 does not match FixedSizeList of size 2"
+        );
+
+        let err = l2_distance(&Float32Array::from(vec![1.]), &[1.]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected FixedSizeList of Float32 or Float16, got Float32"
+        );
+
+        let vectors = FixedSizeListArray::from_flat(
+            Arc::new(PrimitiveArray::<Int32Type>::from(vec![1, 2])),
+            2,
+            None,
+        );
+        let err = cosine_similarity(&vectors, &[1., 2.]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected FixedSizeList of Float32 or Float16, got FixedSizeList of Int32"
+        );
+    }
+}
@@ -22,7 +22,7 @@ use crate::{make_array, Array, ArrayAccessor, ArrayRef, ArrowPrimitiveType};
 use arrow_buffer::buffer::NullBuffer;
 use arrow_buffer::ArrowNativeType;
 use arrow_data::{ArrayData, ArrayDataBuilder};
-use arrow_schema::{ArrowError, DataType, FieldRef};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -197,6 +197,54 @@ impl FixedSizeListArray {
         })
     }
 
+    /// Create a new [`FixedSizeListArray`] from a flat array of `values`, where each
+    /// consecutive run of `size` values forms a list, panicking on failure
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::try_from_flat`] returns an error
+    pub fn from_flat(values: ArrayRef, size: i32, nulls: Option<NullBuffer>) -> Self {
+        Self::try_from_flat(values, size, nulls).unwrap()
+    }
+
+    /// Create a new [`FixedSizeListArray`] from a flat array of `values`, where each
+    /// consecutive run of `size` values forms a list, returning an error on failure
+    ///
+    /// The list field is nullable and named `"item"`, as created by
+    /// [`FixedSizeListBuilder`]
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Array, FixedSizeListArray, Float32Array};
+    /// let values = Arc::new(Float32Array::from(vec![0., 1., 2., 3., 4., 5.]));
+    /// let list = FixedSizeListArray::try_from_flat(values.clone(), 3, None).unwrap();
+    /// assert_eq!(list.len(), 2);
+    /// assert_eq!(list.value_length(), 3);
+    ///
+    /// assert!(FixedSizeListArray::try_from_flat(values, 4, None).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * `size < 0`
+    /// * `values.len()` is not a multiple of `size`
+    /// * `nulls.len() != values.len() / size`
+    pub fn try_from_flat(
+        values: ArrayRef,
+        size: i32,
+        nulls: Option<NullBuffer>,
+    ) -> Result<Self, ArrowError> {
+        if size > 0 && values.len() % size as usize != 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Length of values {} is not a multiple of size {}",
+                values.len(),
+                size
+            )));
+        }
+        let field = Arc::new(Field::new("item", values.data_type().clone(), true));
+        Self::try_new(field, size, values, nulls)
+    }
+
     /// Create a new [`FixedSizeListArray`] of length `len` where all values are null
     ///
     /// # Panics
@@ -230,6 +278,14 @@ impl FixedSizeListArray {
         &self.values
     }
 
+    /// Returns the values of all the lists in this array, in order, as a single array
+    ///
+    /// This is a zero-copy slice of [`Self::values`] containing exactly
+    /// `self.len() * self.value_length()` values, including those of null lists
+    pub fn flatten(&self) -> ArrayRef {
+        self.values.slice(0, self.len * self.value_length as usize)
+    }
+
     /// Returns a clone of the value type of this list.
     pub fn value_type(&self) -> DataType {
         self.values.data_type().clone()
@@ -674,4 +730,46 @@ mod tests {
         let err = FixedSizeListArray::try_new(field, 2, values, None).unwrap_err();
         assert_eq!(err.to_string(), "Invalid argument error: FixedSizeListArray expected data type Int64 got Int32 for \"item\"");
     }
+
+    #[test]
+    fn test_fixed_size_list_from_flat() {
+        let values = Arc::new(Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6, 7])) as ArrayRef;
+        let nulls = NullBuffer::from(vec![true, false, true, true]);
+        let list = FixedSizeListArray::from_flat(values.clone(), 2, Some(nulls));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.null_count(), 1);
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        assert_eq!(list.data_type(), &DataType::FixedSizeList(field, 2));
+
+        let err = FixedSizeListArray::try_from_flat(values.clone(), 3, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Length of values 8 is not a multiple of size 3"
+        );
+
+        let nulls = NullBuffer::new_null(3);
+        let err = FixedSizeListArray::try_from_flat(values, 2, Some(nulls)).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid argument error: Incorrect length of null buffer for FixedSizeListArray, expected 4 got 3");
+    }
+
+    #[test]
+    fn test_fixed_size_list_flatten() {
+        let values = Arc::new(Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6])) as ArrayRef;
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+        // The trailing value does not belong to any list
+        let list = FixedSizeListArray::new(field, 3, values, None);
+        let flat = list.flatten();
+        assert_eq!(
+            flat.as_primitive::<Int32Type>().values(),
+            &[0, 1, 2, 3, 4, 5]
+        );
+
+        let sliced = list.slice(1, 1);
+        let flat = sliced.flatten();
+        assert_eq!(flat.as_primitive::<Int32Type>().values(), &[3, 4, 5]);
+        // Flattening does not copy the values
+        let values = list.values().as_primitive::<Int32Type>().values();
+        let flat = flat.as_primitive::<Int32Type>().values();
+        assert_eq!(flat.as_ptr(), values[3..].as_ptr());
+    }
 }
@@ -480,6 +480,58 @@ pub use crate::types::ArrowPrimitiveType;
 /// assert_eq!(array.values(), &[1, 0, 2]);
 /// assert!(array.is_null(1));
 /// ```
+///
+/// # Example: Zero-copy access to values
+///
+/// [`PrimitiveArray::values`] returns the values as a slice of the native type, aligned
+/// as required by the type and accounting for any offset of a sliced array. This
+/// can be used to expose the values to other runtimes without copying, for example
+/// via the Python buffer protocol, with the lifetime of the slice tied to the array.
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// let array = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]).slice(1, 3);
+/// let values: &[i32] = array.values();
+/// assert_eq!(values.len(), 3);
+/// assert_eq!(values.as_ptr() as usize % std::mem::align_of::<i32>(), 0);
+/// assert_eq!(&values[1..], &[3, 4]);
+///
+/// // The validity is a bitmap, which may start at a non-zero bit offset
+/// let nulls = array.nulls().unwrap();
+/// assert_eq!((nulls.offset(), nulls.len()), (1, 3));
+/// assert!(nulls.is_null(0));
+/// ```
+///
+/// # Example: From foreign memory
+///
+/// Arrays can be constructed over memory owned by another runtime, without copying,
+/// using [`Buffer::from_owner`](arrow_buffer::Buffer::from_owner), where the owner
+/// frees the memory when dropped. The memory must be aligned to the native type.
+///
+/// ```
+/// # use arrow_array::Int64Array;
+/// # use arrow_buffer::{Buffer, ScalarBuffer};
+/// /// Memory owned by a foreign runtime
+/// struct Foreign(Vec<i64>);
+///
+/// impl AsRef<[u8]> for Foreign {
+///     fn as_ref(&self) -> &[u8] {
+///         let len = std::mem::size_of_val(self.0.as_slice());
+///         // SAFETY: the memory of the Vec is valid for `len` bytes
+///         unsafe { std::slice::from_raw_parts(self.0.as_ptr() as *const u8, len) }
+///     }
+/// }
+///
+/// impl Drop for Foreign {
+///     fn drop(&mut self) {
+///         // Release the memory back to the foreign runtime
+///     }
+/// }
+///
+/// let buffer = Buffer::from_owner(Foreign(vec![1, 2, 3]));
+/// let array = Int64Array::new(ScalarBuffer::from(buffer), None);
+/// assert_eq!(array.values(), &[1, 2, 3]);
+/// ```
 pub struct PrimitiveArray<T: ArrowPrimitiveType> {
     data_type: DataType,
     /// Values data
@@ -222,6 +222,62 @@ impl UnionArray {
         Ok(new_self)
     }
 
+    /// Attempts to create a new `UnionArray` of `mode` from per-slot `type_ids`,
+    /// computing the value offsets for a [`UnionMode::Dense`] union.
+    ///
+    /// For a dense union, the slots with a given type id are assigned consecutive
+    /// offsets into the corresponding child array, in the order they appear in
+    /// `type_ids`. Each child array must therefore contain at least as many
+    /// values as there are slots with its type id.
+    ///
+    /// For a sparse union, each child array must have the same length as `type_ids`.
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Float64Array, Int32Array, UnionArray};
+    /// # use arrow_buffer::Buffer;
+    /// # use arrow_schema::{DataType, Field, UnionMode};
+    /// # use std::sync::Arc;
+    /// let children = vec![
+    ///     (Field::new("A", DataType::Int32, false), Arc::new(Int32Array::from(vec![1, 34])) as _),
+    ///     (Field::new("B", DataType::Float64, false), Arc::new(Float64Array::from(vec![3.2])) as _),
+    /// ];
+    /// let type_ids = Buffer::from_slice_ref([0_i8, 1, 0]);
+    /// let array = UnionArray::try_new_from_type_ids(&[0, 1], type_ids, UnionMode::Dense, children)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(array.offsets().unwrap().as_ref(), &[0, 0, 1]);
+    /// ```
+    pub fn try_new_from_type_ids(
+        field_type_ids: &[i8],
+        type_ids: Buffer,
+        mode: UnionMode,
+        child_arrays: Vec<(Field, ArrayRef)>,
+    ) -> Result<Self, ArrowError> {
+        let value_offsets = match mode {
+            UnionMode::Sparse => None,
+            UnionMode::Dense => {
+                let mut next_offset = [0_i32; 128];
+                let type_id_slice: &[i8] = type_ids.typed_data();
+                let offsets = type_id_slice
+                    .iter()
+                    .map(|type_id| {
+                        if *type_id < 0 || !field_type_ids.contains(type_id) {
+                            return Err(ArrowError::InvalidArgumentError(format!(
+                                "Type Id {type_id} is not one of the union's type ids {field_type_ids:?}"
+                            )));
+                        }
+                        let next = &mut next_offset[*type_id as usize];
+                        let offset = *next;
+                        *next += 1;
+                        Ok(offset)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Some(Buffer::from_vec(offsets))
+            }
+        };
+        Self::try_new(field_type_ids, type_ids, value_offsets, child_arrays)
+    }
+
     /// Accesses the child array for `type_id`.
     ///
     /// # Panics
@@ -1200,4 +1256,86 @@ mod tests {
         assert_eq!(v.len(), 1);
         assert_eq!(v.as_string::<i32>().value(0), "baz");
     }
+
+    #[test]
+    fn test_append_array() {
+        for mut builder in [UnionBuilder::new_dense(), UnionBuilder::new_sparse()] {
+            builder.append::<Int32Type>("a", 1).unwrap();
+            builder
+                .append_array("b", &Float64Array::from(vec![Some(2.0), None]))
+                .unwrap();
+            builder
+                .append_array("a", &Int32Array::from(vec![3, 4]))
+                .unwrap();
+            builder.append_null::<Float64Type>("b").unwrap();
+            let union = builder.build().unwrap();
+
+            assert_eq!(union.len(), 6);
+            assert_eq!(*union.type_ids(), [0, 1, 1, 0, 0, 1]);
+            let a = union.child(0).as_primitive::<Int32Type>();
+            let b = union.child(1).as_primitive::<Float64Type>();
+            match union.offsets() {
+                Some(offsets) => {
+                    assert_eq!(*offsets, [0, 0, 1, 1, 2, 2]);
+                    assert_eq!(a, &Int32Array::from(vec![1, 3, 4]));
+                    assert_eq!(b, &Float64Array::from(vec![Some(2.0), None, None]));
+                }
+                None => {
+                    assert_eq!(a.len(), 6);
+                    assert_eq!(a.null_count(), 3);
+                    assert_eq!(b.len(), 6);
+                    assert_eq!(b.null_count(), 5);
+                    assert_eq!(b.value(1), 2.0);
+                }
+            }
+            assert_eq!(union.value(4).as_primitive::<Int32Type>().value(0), 4);
+        }
+    }
+
+    #[test]
+    fn test_try_new_from_type_ids() {
+        let children = || {
+            vec![
+                (
+                    Field::new("a", DataType::Int32, true),
+                    Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+                ),
+                (
+                    Field::new("b", DataType::Utf8, true),
+                    Arc::new(StringArray::from(vec!["x"])) as ArrayRef,
+                ),
+            ]
+        };
+
+        let type_ids = Buffer::from_slice_ref([5_i8, 3, 5]);
+        let union =
+            UnionArray::try_new_from_type_ids(&[5, 3], type_ids, UnionMode::Dense, children())
+                .unwrap();
+        assert_eq!(*union.offsets().unwrap(), [0, 0, 1]);
+        assert_eq!(union.value(1).as_string::<i32>().value(0), "x");
+        assert_eq!(union.value(2).as_primitive::<Int32Type>().value(0), 2);
+
+        let type_ids = Buffer::from_slice_ref([5_i8, 4]);
+        let err =
+            UnionArray::try_new_from_type_ids(&[5, 3], type_ids, UnionMode::Dense, children())
+                .unwrap_err();
+        assert!(err.to_string().contains("Type Id 4 is not one of"), "{err}");
+
+        let type_ids = Buffer::from_slice_ref([5_i8, 3]);
+        let union = UnionArray::try_new_from_type_ids(
+            &[5, 3],
+            type_ids,
+            UnionMode::Sparse,
+            vec![
+                children().remove(0),
+                (
+                    Field::new("b", DataType::Utf8, true),
+                    Arc::new(StringArray::from(vec![None, Some("x")])) as ArrayRef,
+                ),
+            ],
+        )
+        .unwrap();
+        assert!(union.offsets().is_none());
+        assert_eq!(union.value(1).as_string::<i32>().value(0), "x");
+    }
 }
@@ -0,0 +1,299 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::builder::{ArrayBuilder, FixedSizeBinaryBuilder, PrimitiveBuilder};
+use crate::types::ArrowDictionaryKeyType;
+use crate::{Array, ArrayRef, DictionaryArray, FixedSizeBinaryArray};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType};
+use hashbrown::hash_map::RawEntryMut;
+use hashbrown::HashMap;
+use std::any::Any;
+use std::sync::Arc;
+
+/// Builder for [`DictionaryArray`] of [`FixedSizeBinaryArray`]
+///
+/// ```
+/// # use arrow_array::builder::FixedSizeBinaryDictionaryBuilder;
+/// # use arrow_array::types::Int8Type;
+/// # use arrow_array::{Array, FixedSizeBinaryArray, Int8Array};
+/// let mut builder = FixedSizeBinaryDictionaryBuilder::<Int8Type>::new(3);
+/// builder.append(b"abc").unwrap();
+/// builder.append_null();
+/// builder.append(b"def").unwrap();
+/// builder.append(b"abc").unwrap();
+/// // Values must have the configured byte width
+/// assert!(builder.append(b"ab").is_err());
+/// let array = builder.finish();
+///
+/// assert_eq!(
+///   array.keys(),
+///   &Int8Array::from(vec![Some(0), None, Some(1), Some(0)])
+/// );
+///
+/// let values = array.values().as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+/// assert_eq!(values.value(0), b"abc");
+/// assert_eq!(values.value(1), b"def");
+/// ```
+#[derive(Debug)]
+pub struct FixedSizeBinaryDictionaryBuilder<K>
+where
+    K: ArrowDictionaryKeyType,
+{
+    state: ahash::RandomState,
+    /// Used to provide a lookup from value to key type
+    ///
+    /// Note: usize's hash implementation is not used, instead the raw entry
+    /// API is used to store keys w.r.t the hash of the values themselves
+    dedup: HashMap<usize, (), ()>,
+
+    keys_builder: PrimitiveBuilder<K>,
+    values_builder: FixedSizeBinaryBuilder,
+    byte_width: i32,
+}
+
+impl<K> FixedSizeBinaryDictionaryBuilder<K>
+where
+    K: ArrowDictionaryKeyType,
+{
+    /// Creates a new `FixedSizeBinaryDictionaryBuilder` for values of `byte_width` bytes
+    pub fn new(byte_width: i32) -> Self {
+        Self::with_capacity(1024, 1024, byte_width)
+    }
+
+    /// Creates a new `FixedSizeBinaryDictionaryBuilder` with the provided capacities
+    ///
+    /// `keys_capacity`: the number of keys, i.e. length of array to build
+    /// `value_capacity`: the number of distinct dictionary values, i.e. size of dictionary
+    /// `byte_width`: the byte width of each dictionary value
+    pub fn with_capacity(keys_capacity: usize, value_capacity: usize, byte_width: i32) -> Self {
+        Self {
+            state: Default::default(),
+            dedup: HashMap::with_capacity_and_hasher(value_capacity, ()),
+            keys_builder: PrimitiveBuilder::with_capacity(keys_capacity),
+            values_builder: FixedSizeBinaryBuilder::with_capacity(value_capacity, byte_width),
+            byte_width,
+        }
+    }
+
+    /// Append a value to the array. Return an existing index
+    /// if already present in the values array or a new index if the
+    /// value is appended to the values array.
+    ///
+    /// Returns an error if `value` does not have the configured byte width, or
+    /// if the new index would overflow the key type, in which case the builder
+    /// is left unchanged.
+    pub fn append(&mut self, value: impl AsRef<[u8]>) -> Result<K::Native, ArrowError> {
+        let value_bytes = value.as_ref();
+        if value_bytes.len() != self.byte_width as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid input length passed to FixedSizeBinaryDictionaryBuilder. Expected {} got {}",
+                self.byte_width,
+                value_bytes.len()
+            )));
+        }
+
+        let state = &self.state;
+        let storage = &mut self.values_builder;
+        let byte_width = self.byte_width as usize;
+        let hash = state.hash_one(value_bytes);
+
+        let entry = self.dedup.raw_entry_mut().from_hash(hash, |idx| {
+            value_bytes == get_bytes(storage, byte_width, *idx)
+        });
+
+        let key = match entry {
+            RawEntryMut::Occupied(entry) => K::Native::usize_as(*entry.into_key()),
+            RawEntryMut::Vacant(entry) => {
+                let idx = storage.len();
+                let key =
+                    K::Native::from_usize(idx).ok_or(ArrowError::DictionaryKeyOverflowError)?;
+                storage.append_value(value_bytes)?;
+
+                entry.insert_with_hasher(hash, idx, (), |idx| {
+                    state.hash_one(get_bytes(storage, byte_width, *idx))
+                });
+
+                key
+            }
+        };
+        self.keys_builder.append_value(key);
+
+        Ok(key)
+    }
+
+    /// Infallibly append a value to this builder
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not have the configured byte width, or if the resulting
+    /// length of the dictionary values array would exceed `K::Native::MAX`
+    pub fn append_value(&mut self, value: impl AsRef<[u8]>) {
+        self.append(value).unwrap();
+    }
+
+    /// Appends a null slot into the builder
+    #[inline]
+    pub fn append_null(&mut self) {
+        self.keys_builder.append_null()
+    }
+
+    /// Append an `Option` value into the builder
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`Self::append_value`]
+    #[inline]
+    pub fn append_option(&mut self, value: Option<impl AsRef<[u8]>>) {
+        match value {
+            None => self.append_null(),
+            Some(v) => self.append_value(v),
+        };
+    }
+
+    /// Builds the `DictionaryArray` and reset this builder.
+    pub fn finish(&mut self) -> DictionaryArray<K> {
+        self.dedup.clear();
+        let values = self.values_builder.finish();
+        let keys = self.keys_builder.finish();
+        build_dictionary(keys.into_data(), values)
+    }
+
+    /// Builds the `DictionaryArray` without resetting the builder.
+    pub fn finish_cloned(&self) -> DictionaryArray<K> {
+        let values = self.values_builder.finish_cloned();
+        let keys = self.keys_builder.finish_cloned();
+        build_dictionary(keys.into_data(), values)
+    }
+}
+
+fn build_dictionary<K: ArrowDictionaryKeyType>(
+    keys: arrow_data::ArrayData,
+    values: FixedSizeBinaryArray,
+) -> DictionaryArray<K> {
+    let data_type =
+        DataType::Dictionary(Box::new(K::DATA_TYPE), Box::new(values.data_type().clone()));
+
+    let builder = keys
+        .into_builder()
+        .data_type(data_type)
+        .child_data(vec![values.into_data()]);
+
+    DictionaryArray::from(unsafe { builder.build_unchecked() })
+}
+
+fn get_bytes(values: &FixedSizeBinaryBuilder, byte_width: usize, idx: usize) -> &[u8] {
+    let start = idx * byte_width;
+    &values.values_slice()[start..start + byte_width]
+}
+
+impl<K> ArrayBuilder for FixedSizeBinaryDictionaryBuilder<K>
+where
+    K: ArrowDictionaryKeyType,
+{
+    /// Returns the builder as an non-mutable `Any` reference.
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Returns the builder as an mutable `Any` reference.
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    /// Returns the boxed builder as a box of `Any`.
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    /// Returns the number of array slots in the builder
+    fn len(&self) -> usize {
+        self.keys_builder.len()
+    }
+
+    /// Builds the array and reset this builder.
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(self.finish())
+    }
+
+    /// Builds the array without resetting the builder.
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(self.finish_cloned())
+    }
+}
+
+impl<K: ArrowDictionaryKeyType, V: AsRef<[u8]>> Extend<Option<V>>
+    for FixedSizeBinaryDictionaryBuilder<K>
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = Option<V>>>(&mut self, iter: I) {
+        for v in iter {
+            self.append_option(v)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::types::{Int16Type, Int8Type};
+
+    #[test]
+    fn test_fixed_size_binary_dictionary_builder() {
+        let mut builder = FixedSizeBinaryDictionaryBuilder::<Int16Type>::new(2);
+        builder.extend([Some(b"ab"), None, Some(b"cd"), Some(b"ab")]);
+        let cloned = builder.finish_cloned();
+        let array = builder.finish();
+        assert_eq!(cloned, array);
+
+        assert_eq!(
+            array.data_type(),
+            &DataType::Dictionary(
+                Box::new(DataType::Int16),
+                Box::new(DataType::FixedSizeBinary(2))
+            )
+        );
+        assert_eq!(array.keys().values(), &[0, 0, 1, 0]);
+        assert!(array.is_null(1));
+
+        let values = array.values().as_any();
+        let values = values.downcast_ref::<FixedSizeBinaryArray>().unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.value(0), b"ab");
+        assert_eq!(values.value(1), b"cd");
+
+        // Builder is reset
+        builder.append_value(b"cd");
+        assert_eq!(builder.finish().keys().values(), &[0]);
+    }
+
+    #[test]
+    fn test_fixed_size_binary_dictionary_overflow() {
+        let mut builder = FixedSizeBinaryDictionaryBuilder::<Int8Type>::new(4);
+        for i in 0..128_i32 {
+            builder.append(i.to_le_bytes()).unwrap();
+        }
+        let err = builder.append(1000_i32.to_le_bytes()).unwrap_err();
+        assert!(matches!(err, ArrowError::DictionaryKeyOverflowError));
+        builder.append(5_i32.to_le_bytes()).unwrap();
+
+        let array = builder.finish();
+        assert_eq!(array.len(), 129);
+        assert_eq!(array.values().len(), 128);
+    }
+}
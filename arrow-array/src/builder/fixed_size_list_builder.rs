@@ -19,7 +19,7 @@ use crate::builder::ArrayBuilder;
 use crate::{ArrayRef, FixedSizeListArray};
 use arrow_buffer::NullBufferBuilder;
 use arrow_data::ArrayData;
-use arrow_schema::{DataType, Field};
+use arrow_schema::{DataType, Field, FieldRef};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -67,6 +67,7 @@ pub struct FixedSizeListBuilder<T: ArrayBuilder> {
     null_buffer_builder: NullBufferBuilder,
     values_builder: T,
     list_len: i32,
+    field: Option<FieldRef>,
 }
 
 impl<T: ArrayBuilder> FixedSizeListBuilder<T> {
@@ -89,6 +90,21 @@ impl<T: ArrayBuilder> FixedSizeListBuilder<T> {
             null_buffer_builder: NullBufferBuilder::new(capacity),
             values_builder,
             list_len: value_length,
+            field: None,
+        }
+    }
+
+    /// Override the field passed to [`FixedSizeListArray`]
+    ///
+    /// By default a nullable field is created with the name `item`
+    ///
+    /// Note: [`Self::finish`] and [`Self::finish_cloned`] will panic if the
+    /// field's data type does not match that of `T`, or if the field is not
+    /// nullable and the values contain nulls
+    pub fn with_field(self, field: impl Into<FieldRef>) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
         }
     }
 }
@@ -151,6 +167,29 @@ where
         self.null_buffer_builder.append(is_valid);
     }
 
+    /// Returns the item field for `values`, validating it against any provided field
+    fn item_field(&self, values: &ArrayData) -> FieldRef {
+        match &self.field {
+            Some(f) => {
+                assert_eq!(
+                    f.data_type(),
+                    values.data_type(),
+                    "FixedSizeListArray expected data type {} got {} for {:?}",
+                    f.data_type(),
+                    values.data_type(),
+                    f.name()
+                );
+                assert!(
+                    f.is_nullable() || values.null_count() == 0,
+                    "Non-nullable field of FixedSizeListArray {:?} cannot contain nulls",
+                    f.name()
+                );
+                f.clone()
+            }
+            None => Arc::new(Field::new("item", values.data_type().clone(), true)),
+        }
+    }
+
     /// Builds the [`FixedSizeListBuilder`] and reset this builder.
     pub fn finish(&mut self) -> FixedSizeListArray {
         let len = self.len();
@@ -166,13 +205,11 @@ where
         );
 
         let nulls = self.null_buffer_builder.finish();
-        let array_data = ArrayData::builder(DataType::FixedSizeList(
-            Arc::new(Field::new("item", values_data.data_type().clone(), true)),
-            self.list_len,
-        ))
-        .len(len)
-        .add_child_data(values_data)
-        .nulls(nulls);
+        let field = self.item_field(&values_data);
+        let array_data = ArrayData::builder(DataType::FixedSizeList(field, self.list_len))
+            .len(len)
+            .add_child_data(values_data)
+            .nulls(nulls);
 
         let array_data = unsafe { array_data.build_unchecked() };
 
@@ -194,13 +231,11 @@ where
         );
 
         let nulls = self.null_buffer_builder.finish_cloned();
-        let array_data = ArrayData::builder(DataType::FixedSizeList(
-            Arc::new(Field::new("item", values_data.data_type().clone(), true)),
-            self.list_len,
-        ))
-        .len(len)
-        .add_child_data(values_data)
-        .nulls(nulls);
+        let field = self.item_field(&values_data);
+        let array_data = ArrayData::builder(DataType::FixedSizeList(field, self.list_len))
+            .len(len)
+            .add_child_data(values_data)
+            .nulls(nulls);
 
         let array_data = unsafe { array_data.build_unchecked() };
 
@@ -337,4 +372,43 @@ mod tests {
 
         builder.finish();
     }
+
+    #[test]
+    fn test_fixed_size_list_with_field() {
+        let field = Arc::new(Field::new("bar", DataType::Int32, false));
+        let mut builder =
+            FixedSizeListBuilder::new(Int32Builder::new(), 2).with_field(field.clone());
+        builder.values().append_slice(&[1, 2]);
+        builder.append(true);
+        builder.values().append_slice(&[0, 0]);
+        builder.append(false);
+        let expected = DataType::FixedSizeList(field, 2);
+        assert_eq!(builder.finish_cloned().data_type(), &expected);
+        let array = builder.finish();
+        assert_eq!(array.data_type(), &expected);
+        assert_eq!(array.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Non-nullable field of FixedSizeListArray \"item\" cannot contain nulls"
+    )]
+    fn test_fixed_size_list_with_field_checks_nullability() {
+        let field = Arc::new(Field::new("item", DataType::Int32, false));
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 2).with_field(field);
+        builder.values().append_value(1);
+        builder.values().append_null();
+        builder.append(true);
+        builder.finish();
+    }
+
+    #[test]
+    #[should_panic(expected = "FixedSizeListArray expected data type Int64 got Int32")]
+    fn test_fixed_size_list_with_field_checks_data_type() {
+        let field = Arc::new(Field::new("item", DataType::Int64, true));
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(), 1).with_field(field);
+        builder.values().append_value(1);
+        builder.append(true);
+        builder.finish();
+    }
 }
@@ -207,7 +207,8 @@ where
     /// if already present in the values array or a new index if the
     /// value is appended to the values array.
     ///
-    /// Returns an error if the new index would overflow the key type.
+    /// Returns an error if the new index would overflow the key type, in which
+    /// case the builder is left unchanged.
     pub fn append(&mut self, value: impl AsRef<T::Native>) -> Result<K::Native, ArrowError> {
         let value_native: &T::Native = value.as_ref();
         let value_bytes: &[u8] = value_native.as_ref();
@@ -225,13 +226,15 @@ where
             RawEntryMut::Occupied(entry) => K::Native::usize_as(*entry.into_key()),
             RawEntryMut::Vacant(entry) => {
                 let idx = storage.len();
+                let key =
+                    K::Native::from_usize(idx).ok_or(ArrowError::DictionaryKeyOverflowError)?;
                 storage.append_value(value);
 
                 entry.insert_with_hasher(hash, idx, (), |idx| {
                     state.hash_one(get_bytes(storage, *idx))
                 });
 
-                K::Native::from_usize(idx).ok_or(ArrowError::DictionaryKeyOverflowError)?
+                key
             }
         };
         self.keys_builder.append_value(key);
@@ -267,6 +270,17 @@ where
         };
     }
 
+    /// Returns the dictionary index of `value_bytes` if it has already been interned
+    fn lookup(&self, value_bytes: &[u8]) -> Option<usize> {
+        let hash = self.state.hash_one(value_bytes);
+        self.dedup
+            .raw_entry()
+            .from_hash(hash, |idx| {
+                value_bytes == get_bytes(&self.values_builder, *idx)
+            })
+            .map(|(idx, _)| *idx)
+    }
+
     /// Builds the `DictionaryArray` and reset this builder.
     pub fn finish(&mut self) -> DictionaryArray<K> {
         self.dedup.clear();
@@ -312,6 +326,171 @@ impl<K: ArrowDictionaryKeyType, T: ByteArrayType, V: AsRef<T::Native>> Extend<Op
     }
 }
 
+/// Builder for [`DictionaryArray`] of [`GenericByteArray`] that falls back to plain
+/// encoding once the dictionary grows beyond a configured size
+///
+/// Dictionary encoding high-cardinality data wastes memory on the hash table
+/// used for deduplication without reducing the size of the output. This builder
+/// dictionary encodes values until adding a new distinct value would exceed
+/// either the maximum number of dictionary values, the maximum number of
+/// dictionary value bytes, or the range of the key type `K`. At this point the
+/// builder *spills*: the deduplication state is released and the offending value,
+/// along with all subsequent values, is appended to a plain [`GenericByteBuilder`].
+///
+/// ```
+/// # use arrow_array::builder::SpillableStringDictionaryBuilder;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_array::Array;
+/// let mut builder = SpillableStringDictionaryBuilder::<Int32Type>::new(2, usize::MAX);
+/// assert!(builder.append("a"));
+/// assert!(builder.append("b"));
+/// assert!(builder.append("a"));
+/// // A third distinct value exceeds the limit of 2 dictionary values
+/// assert!(!builder.append("c"));
+/// // Once spilled, all subsequent values are plain encoded
+/// assert!(!builder.append("a"));
+///
+/// let output = builder.finish();
+/// assert_eq!(output.dictionary.len(), 3);
+/// assert_eq!(output.dictionary.values().len(), 2);
+/// assert_eq!(output.plain.unwrap().len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct SpillableByteDictionaryBuilder<K, T>
+where
+    K: ArrowDictionaryKeyType,
+    T: ByteArrayType,
+{
+    dictionary: GenericByteDictionaryBuilder<K, T>,
+    plain: Option<GenericByteBuilder<T>>,
+    max_values: usize,
+    max_bytes: usize,
+}
+
+/// The output of [`SpillableByteDictionaryBuilder`]
+///
+/// Rows `0..dictionary.len()` were dictionary encoded, any remaining rows
+/// were appended after the builder spilled and are contained in `plain`
+#[derive(Debug)]
+pub struct SpillableByteDictionary<K, T>
+where
+    K: ArrowDictionaryKeyType,
+    T: ByteArrayType,
+{
+    /// The densely encoded leading rows
+    pub dictionary: DictionaryArray<K>,
+    /// The plain encoded trailing rows, if the builder spilled
+    pub plain: Option<GenericByteArray<T>>,
+}
+
+impl<K, T> SpillableByteDictionaryBuilder<K, T>
+where
+    K: ArrowDictionaryKeyType,
+    T: ByteArrayType,
+{
+    /// Creates a new `SpillableByteDictionaryBuilder` that spills once the dictionary
+    /// would contain more than `max_values` distinct values, or more than `max_bytes`
+    /// bytes of value data
+    pub fn new(max_values: usize, max_bytes: usize) -> Self {
+        Self {
+            dictionary: GenericByteDictionaryBuilder::new(),
+            plain: None,
+            max_values,
+            max_bytes,
+        }
+    }
+
+    /// Returns true if this builder has spilled to plain encoding
+    pub fn is_spilled(&self) -> bool {
+        self.plain.is_some()
+    }
+
+    /// Returns the number of rows that have been dictionary encoded
+    pub fn dictionary_len(&self) -> usize {
+        self.dictionary.len()
+    }
+
+    /// Returns the total number of rows appended to this builder
+    pub fn len(&self) -> usize {
+        self.dictionary.len() + self.plain.as_ref().map(|p| p.len()).unwrap_or_default()
+    }
+
+    /// Returns true if no rows have been appended to this builder
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends a value to this builder, returning `true` if it was dictionary encoded
+    /// and `false` if it was plain encoded
+    pub fn append(&mut self, value: impl AsRef<T::Native>) -> bool {
+        if let Some(plain) = self.plain.as_mut() {
+            plain.append_value(value);
+            return false;
+        }
+
+        let value_bytes: &[u8] = value.as_ref().as_ref();
+        if self.dictionary.lookup(value_bytes).is_none() {
+            let values = &self.dictionary.values_builder;
+            let num_values = values.len() + 1;
+            let num_bytes = values.values_slice().len() + value_bytes.len();
+            let overflow = K::Native::from_usize(values.len()).is_none();
+            if overflow || num_values > self.max_values || num_bytes > self.max_bytes {
+                self.spill().append_value(value);
+                return false;
+            }
+        }
+        // Cannot fail as key overflow is checked above
+        self.dictionary.append_value(value);
+        true
+    }
+
+    /// Appends a null slot into the builder
+    pub fn append_null(&mut self) {
+        match self.plain.as_mut() {
+            Some(plain) => plain.append_null(),
+            None => self.dictionary.append_null(),
+        }
+    }
+
+    /// Append an `Option` value into the builder, returning `true` if it was
+    /// dictionary encoded and `false` if it was plain encoded
+    pub fn append_option(&mut self, value: Option<impl AsRef<T::Native>>) -> bool {
+        match value {
+            Some(v) => self.append(v),
+            None => {
+                self.append_null();
+                !self.is_spilled()
+            }
+        }
+    }
+
+    /// Switches this builder to plain encoding, releasing the deduplication state
+    fn spill(&mut self) -> &mut GenericByteBuilder<T> {
+        self.dictionary.dedup = HashMap::with_hasher(());
+        self.plain.insert(GenericByteBuilder::new())
+    }
+
+    /// Builds the output and resets this builder
+    pub fn finish(&mut self) -> SpillableByteDictionary<K, T> {
+        let output = SpillableByteDictionary {
+            dictionary: self.dictionary.finish(),
+            plain: self.plain.take().map(|mut p| p.finish()),
+        };
+        self.dictionary.dedup = HashMap::with_hasher(());
+        output
+    }
+}
+
+/// Builder for [`DictionaryArray`] of [`StringArray`](crate::array::StringArray)
+/// that falls back to plain encoding, see [`SpillableByteDictionaryBuilder`]
+pub type SpillableStringDictionaryBuilder<K> =
+    SpillableByteDictionaryBuilder<K, GenericStringType<i32>>;
+
+/// Builder for [`DictionaryArray`] of [`BinaryArray`](crate::array::BinaryArray)
+/// that falls back to plain encoding, see [`SpillableByteDictionaryBuilder`]
+pub type SpillableBinaryDictionaryBuilder<K> =
+    SpillableByteDictionaryBuilder<K, GenericBinaryType<i32>>;
+
 fn get_bytes<T: ByteArrayType>(values: &GenericByteBuilder<T>, idx: usize) -> &[u8] {
     let offsets = values.offsets_slice();
     let values = values.values_slice();
@@ -623,4 +802,59 @@ mod tests {
         assert_eq!(dict.keys().values(), &[0, 1, 2, 0, 1, 2, 2, 3, 0]);
         assert_eq!(dict.values().len(), 4);
     }
+
+    #[test]
+    fn test_spillable_max_values() {
+        let mut builder = SpillableStringDictionaryBuilder::<Int32Type>::new(2, usize::MAX);
+        assert!(builder.append("a"));
+        assert!(builder.append_option(None::<&str>));
+        assert!(builder.append("b"));
+        assert!(builder.append("a"));
+        assert!(!builder.is_spilled());
+        assert!(!builder.append("c"));
+        assert!(builder.is_spilled());
+        assert!(!builder.append("a"));
+        assert!(!builder.append_option(None::<&str>));
+        assert_eq!(builder.dictionary_len(), 4);
+        assert_eq!(builder.len(), 7);
+
+        let output = builder.finish();
+        assert_eq!(output.dictionary.keys().len(), 4);
+        assert_eq!(output.dictionary.keys().null_count(), 1);
+        let values = output.dictionary.values().as_any();
+        let values = values.downcast_ref::<StringArray>().unwrap();
+        assert_eq!(values, &StringArray::from(vec!["a", "b"]));
+
+        let plain = output.plain.unwrap();
+        assert_eq!(plain, StringArray::from(vec![Some("c"), Some("a"), None]));
+
+        // Builder is reset
+        assert!(builder.is_empty());
+        assert!(builder.append("c"));
+        assert!(builder.finish().plain.is_none());
+    }
+
+    #[test]
+    fn test_spillable_max_bytes() {
+        let mut builder = SpillableBinaryDictionaryBuilder::<Int32Type>::new(usize::MAX, 5);
+        assert!(builder.append(b"abc"));
+        assert!(builder.append(b"de"));
+        assert!(builder.append(b"abc"));
+        assert!(!builder.append(b"f"));
+        let output = builder.finish();
+        assert_eq!(output.dictionary.len(), 3);
+        assert_eq!(output.plain.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_spillable_key_overflow() {
+        let mut builder = SpillableStringDictionaryBuilder::<Int8Type>::new(usize::MAX, usize::MAX);
+        for i in 0..200 {
+            let encoded = builder.append(i.to_string());
+            assert_eq!(encoded, i < 128, "{i}");
+        }
+        let output = builder.finish();
+        assert_eq!(output.dictionary.len(), 128);
+        assert_eq!(output.plain.unwrap().len(), 72);
+    }
 }
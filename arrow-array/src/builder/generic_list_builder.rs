@@ -19,8 +19,9 @@ use crate::builder::{ArrayBuilder, BufferBuilder};
 use crate::{Array, ArrayRef, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::NullBufferBuilder;
 use arrow_buffer::{Buffer, OffsetBuffer};
-use arrow_schema::{Field, FieldRef};
+use arrow_schema::{DataType, Field, FieldRef};
 use std::any::Any;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Builder for [`GenericListArray`]
@@ -92,6 +93,7 @@ pub struct GenericListBuilder<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> {
     null_buffer_builder: NullBufferBuilder,
     values_builder: T,
     field: Option<FieldRef>,
+    item: Field,
 }
 
 impl<O: OffsetSizeTrait, T: ArrayBuilder + Default> Default for GenericListBuilder<O, T> {
@@ -117,6 +119,7 @@ impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> GenericListBuilder<OffsetSize
             null_buffer_builder: NullBufferBuilder::new(capacity),
             values_builder,
             field: None,
+            item: Field::new("item", DataType::Null, true),
         }
     }
 
@@ -132,6 +135,50 @@ impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> GenericListBuilder<OffsetSize
             ..self
         }
     }
+
+    /// Override the name of the item field, defaults to `item`
+    ///
+    /// Unlike [`Self::with_field`] the data type of the item field is
+    /// derived from the values builder, making this suitable for nested
+    /// builders whose data type is not known upfront. Ignored if
+    /// [`Self::with_field`] is specified
+    pub fn with_item_name(self, name: impl Into<String>) -> Self {
+        Self {
+            item: self.item.with_name(name),
+            ..self
+        }
+    }
+
+    /// Override the nullability of the item field, defaults to `true`
+    ///
+    /// Ignored if [`Self::with_field`] is specified
+    ///
+    /// Note: [`Self::finish`] and [`Self::finish_cloned`] will panic if the
+    /// item field is not nullable and the values contain nulls
+    pub fn with_item_nullable(self, nullable: bool) -> Self {
+        Self {
+            item: self.item.with_nullable(nullable),
+            ..self
+        }
+    }
+
+    /// Override the metadata of the item field, defaults to empty
+    ///
+    /// Ignored if [`Self::with_field`] is specified
+    pub fn with_item_metadata(self, metadata: HashMap<String, String>) -> Self {
+        Self {
+            item: self.item.with_metadata(metadata),
+            ..self
+        }
+    }
+
+    /// Returns the item field for values of `data_type`
+    fn item_field(&self, data_type: &DataType) -> FieldRef {
+        match &self.field {
+            Some(f) => f.clone(),
+            None => Arc::new(self.item.clone().with_data_type(data_type.clone())),
+        }
+    }
 }
 
 impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> ArrayBuilder
@@ -297,10 +344,7 @@ where
         let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.into()) };
         self.offsets_builder.append(OffsetSize::zero());
 
-        let field = match &self.field {
-            Some(f) => f.clone(),
-            None => Arc::new(Field::new("item", values.data_type().clone(), true)),
-        };
+        let field = self.item_field(values.data_type());
 
         GenericListArray::new(field, offsets, values, nulls)
     }
@@ -314,10 +358,7 @@ where
         // Safety: safe by construction
         let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.into()) };
 
-        let field = match &self.field {
-            Some(f) => f.clone(),
-            None => Arc::new(Field::new("item", values.data_type().clone(), true)),
-        };
+        let field = self.item_field(values.data_type());
 
         GenericListArray::new(field, offsets, values, nulls)
     }
@@ -351,11 +392,11 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::builder::{make_builder, Int32Builder, ListBuilder};
+    use crate::builder::{make_builder, Int32Builder, LargeListBuilder, ListBuilder};
+    use crate::builder::{StringBuilder, StructBuilder};
     use crate::cast::AsArray;
     use crate::types::Int32Type;
     use crate::{Array, Int32Array};
-    use arrow_schema::DataType;
 
     fn _test_generic_list_array_builder<O: OffsetSizeTrait>() {
         let values_builder = Int32Builder::with_capacity(10);
@@ -798,4 +839,50 @@ mod tests {
         builder.append_value([Some(1)]);
         builder.finish();
     }
+
+    #[test]
+    fn test_with_item() {
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let mut builder = ListBuilder::new(Int32Builder::new())
+            .with_item_name("element")
+            .with_item_nullable(false)
+            .with_item_metadata(metadata.clone());
+        builder.append_value([Some(1), Some(2)]);
+        builder.append_null();
+        let array = builder.finish();
+
+        let expected = Field::new("element", DataType::Int32, false).with_metadata(metadata);
+        assert_eq!(array.data_type(), &DataType::List(Arc::new(expected)));
+    }
+
+    #[test]
+    fn test_with_item_nested() {
+        let fields = vec![Field::new("a", DataType::Utf8, true)];
+        let values = StructBuilder::from_fields(fields.clone(), 0);
+        let inner = ListBuilder::new(values).with_item_name("s");
+        let mut builder = LargeListBuilder::new(inner).with_item_nullable(false);
+
+        let inner = builder.values();
+        let s = inner.values();
+        s.field_builder::<StringBuilder>(0)
+            .unwrap()
+            .append_value("foo");
+        s.append(true);
+        inner.append(true);
+        builder.append(true);
+
+        let array = builder.finish();
+        let s = Field::new("s", DataType::Struct(fields.into()), true);
+        let inner = Field::new("item", DataType::List(Arc::new(s)), false);
+        assert_eq!(array.data_type(), &DataType::LargeList(Arc::new(inner)));
+        assert_eq!(array.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Non-nullable field of ListArray \\\"item\\\" cannot contain nulls")]
+    fn test_with_item_checks_nullability() {
+        let mut builder = ListBuilder::new(Int32Builder::new()).with_item_nullable(false);
+        builder.append_value([Some(1), None]);
+        builder.finish();
+    }
 }
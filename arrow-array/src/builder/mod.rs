@@ -156,6 +156,8 @@ mod buffer_builder;
 pub use buffer_builder::*;
 mod fixed_size_binary_builder;
 pub use fixed_size_binary_builder::*;
+mod fixed_size_binary_dictionary_builder;
+pub use fixed_size_binary_dictionary_builder::*;
 mod fixed_size_list_builder;
 pub use fixed_size_list_builder::*;
 mod generic_bytes_builder;
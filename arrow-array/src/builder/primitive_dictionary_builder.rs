@@ -16,7 +16,7 @@
 // under the License.
 
 use crate::builder::{ArrayBuilder, PrimitiveBuilder};
-use crate::types::ArrowDictionaryKeyType;
+use crate::types::{ArrowDictionaryKeyType, ArrowTimestampType, DecimalType};
 use crate::{Array, ArrayRef, ArrowPrimitiveType, DictionaryArray};
 use arrow_buffer::{ArrowNativeType, ToByteSlice};
 use arrow_schema::{ArrowError, DataType};
@@ -169,6 +169,41 @@ where
     }
 }
 
+impl<K, V> PrimitiveDictionaryBuilder<K, V>
+where
+    K: ArrowPrimitiveType,
+    V: DecimalType,
+{
+    /// Sets the precision and scale of the dictionary values
+    pub fn with_precision_and_scale(self, precision: u8, scale: i8) -> Result<Self, ArrowError> {
+        Ok(Self {
+            values_builder: self
+                .values_builder
+                .with_precision_and_scale(precision, scale)?,
+            ..self
+        })
+    }
+}
+
+impl<K, V> PrimitiveDictionaryBuilder<K, V>
+where
+    K: ArrowPrimitiveType,
+    V: ArrowTimestampType,
+{
+    /// Sets the timezone of the dictionary values
+    pub fn with_timezone(self, timezone: impl Into<Arc<str>>) -> Self {
+        self.with_timezone_opt(Some(timezone.into()))
+    }
+
+    /// Sets an optional timezone of the dictionary values
+    pub fn with_timezone_opt<S: Into<Arc<str>>>(self, timezone: Option<S>) -> Self {
+        Self {
+            values_builder: self.values_builder.with_timezone_opt(timezone),
+            ..self
+        }
+    }
+}
+
 impl<K, V> ArrayBuilder for PrimitiveDictionaryBuilder<K, V>
 where
     K: ArrowDictionaryKeyType,
@@ -213,15 +248,20 @@ where
     /// Append a primitive value to the array. Return an existing index
     /// if already present in the values array or a new index if the
     /// value is appended to the values array.
+    ///
+    /// Returns an error if the new index would overflow the key type, in which
+    /// case the builder is left unchanged.
     #[inline]
     pub fn append(&mut self, value: V::Native) -> Result<K::Native, ArrowError> {
         let key = match self.map.entry(Value(value)) {
             Entry::Vacant(vacant) => {
                 // Append new value.
-                let key = self.values_builder.len();
+                let idx = self.values_builder.len();
+                let key =
+                    K::Native::from_usize(idx).ok_or(ArrowError::DictionaryKeyOverflowError)?;
                 self.values_builder.append_value(value);
-                vacant.insert(key);
-                K::Native::from_usize(key).ok_or(ArrowError::DictionaryKeyOverflowError)?
+                vacant.insert(idx);
+                key
             }
             Entry::Occupied(o) => K::Native::usize_as(*o.get()),
         };
@@ -282,7 +322,8 @@ where
         let values = self.values_builder.finish_cloned();
         let keys = self.keys_builder.finish_cloned();
 
-        let data_type = DataType::Dictionary(Box::new(K::DATA_TYPE), Box::new(V::DATA_TYPE));
+        let data_type =
+            DataType::Dictionary(Box::new(K::DATA_TYPE), Box::new(values.data_type().clone()));
 
         let builder = keys
             .into_data()
@@ -323,7 +364,13 @@ mod tests {
     use crate::array::UInt32Array;
     use crate::array::UInt8Array;
     use crate::builder::Decimal128Builder;
-    use crate::types::{Decimal128Type, Int32Type, UInt32Type, UInt8Type};
+    use crate::types::{
+        Date32Type, Decimal128Type, Decimal256Type, Int16Type, Int32Type, Int8Type,
+        TimestampMillisecondType, UInt32Type, UInt64Type, UInt8Type,
+    };
+    use crate::UInt64Array;
+    use arrow_buffer::i256;
+    use arrow_schema::TimeUnit;
 
     #[test]
     fn test_primitive_dictionary_builder() {
@@ -395,4 +442,56 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_primitive_dictionary_overflow_unchanged() {
+        let mut builder = PrimitiveDictionaryBuilder::<Int8Type, UInt32Type>::new();
+        for i in 0..128 {
+            builder.append(i).unwrap();
+        }
+        builder.append(1000).unwrap_err();
+        // Existing values can still be appended
+        builder.append(5).unwrap();
+        let dict = builder.finish();
+        assert_eq!(dict.len(), 129);
+        assert_eq!(dict.values().len(), 128);
+    }
+
+    #[test]
+    fn test_primitive_dictionary_decimal() {
+        let mut builder = PrimitiveDictionaryBuilder::<Int16Type, Decimal256Type>::new()
+            .with_precision_and_scale(40, 3)
+            .unwrap();
+        builder.append_value(i256::from_i128(123));
+        builder.append_value(i256::from_i128(456));
+        builder.append_value(i256::from_i128(123));
+        let expected = DataType::Dictionary(
+            Box::new(DataType::Int16),
+            Box::new(DataType::Decimal256(40, 3)),
+        );
+        assert_eq!(builder.finish_cloned().data_type(), &expected);
+        let dict = builder.finish();
+        assert_eq!(dict.data_type(), &expected);
+        assert_eq!(dict.keys().values(), &[0, 1, 0]);
+    }
+
+    #[test]
+    fn test_primitive_dictionary_temporal() {
+        let mut builder = PrimitiveDictionaryBuilder::<UInt64Type, TimestampMillisecondType>::new()
+            .with_timezone("+01:00");
+        builder.extend([Some(1), None, Some(1), Some(2)]);
+        let dict = builder.finish();
+        assert_eq!(
+            dict.values().data_type(),
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into()))
+        );
+        assert_eq!(
+            dict.keys(),
+            &UInt64Array::from(vec![Some(0), None, Some(0), Some(1)])
+        );
+
+        let mut builder = PrimitiveDictionaryBuilder::<Int8Type, Date32Type>::new();
+        builder.extend([Some(1), Some(1)]);
+        assert_eq!(builder.finish().values().data_type(), &DataType::Date32);
+    }
 }
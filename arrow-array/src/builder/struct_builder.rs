@@ -16,9 +16,11 @@
 // under the License.
 
 use crate::builder::*;
+use crate::types::ArrowDictionaryKeyType;
+use crate::{downcast_integer, downcast_primitive};
 use crate::{ArrayRef, StructArray};
 use arrow_buffer::NullBufferBuilder;
-use arrow_schema::{DataType, Fields, IntervalUnit, SchemaBuilder, TimeUnit};
+use arrow_schema::{ArrowError, DataType, Fields, IntervalUnit, SchemaBuilder, TimeUnit};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -89,9 +91,44 @@ impl ArrayBuilder for StructBuilder {
 /// Returns a builder with capacity `capacity` that corresponds to the datatype `DataType`
 /// This function is useful to construct arrays from an arbitrary vectors with known/expected
 /// schema.
+///
+/// # Panics
+///
+/// Panics if `datatype` is not supported, see [`try_make_builder`] for a fallible version
 pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilder> {
+    try_make_builder(datatype, capacity).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Returns a builder with capacity `capacity` that corresponds to the datatype `DataType`,
+/// or an error if `datatype` is not supported
+///
+/// Dictionary types can be built for any integer key type, with the builder for
+/// [`DataType::Dictionary`] being one of [`PrimitiveDictionaryBuilder`],
+/// [`GenericByteDictionaryBuilder`] or [`FixedSizeBinaryDictionaryBuilder`]
+/// depending on the value type.
+///
+/// ```
+/// # use arrow_array::builder::{try_make_builder, StringDictionaryBuilder};
+/// # use arrow_array::types::Int16Type;
+/// # use arrow_schema::{DataType, UnionFields, UnionMode};
+/// let data_type = DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8));
+/// let mut builder = try_make_builder(&data_type, 10).unwrap();
+/// let dictionary = builder
+///     .as_any_mut()
+///     .downcast_mut::<StringDictionaryBuilder<Int16Type>>()
+///     .unwrap();
+/// dictionary.append_value("foo");
+/// assert_eq!(builder.finish().data_type(), &data_type);
+///
+/// let union = DataType::Union(UnionFields::empty(), UnionMode::Sparse);
+/// assert!(try_make_builder(&union, 10).is_err());
+/// ```
+pub fn try_make_builder(
+    datatype: &DataType,
+    capacity: usize,
+) -> Result<Box<dyn ArrayBuilder>, ArrowError> {
     use crate::builder::*;
-    match datatype {
+    Ok(match datatype {
         DataType::Null => Box::new(NullBuilder::with_capacity(capacity)),
         DataType::Boolean => Box::new(BooleanBuilder::with_capacity(capacity)),
         DataType::Int8 => Box::new(Int8Builder::with_capacity(capacity)),
@@ -170,12 +207,20 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
             Box::new(DurationNanosecondBuilder::with_capacity(capacity))
         }
         DataType::List(field) => {
-            let builder = make_builder(field.data_type(), capacity);
-            Box::new(ListBuilder::with_capacity(builder, capacity))
+            let builder = try_make_builder(field.data_type(), capacity)?;
+            Box::new(ListBuilder::with_capacity(builder, capacity).with_field(field.clone()))
         }
         DataType::LargeList(field) => {
-            let builder = make_builder(field.data_type(), capacity);
-            Box::new(LargeListBuilder::with_capacity(builder, capacity))
+            let builder = try_make_builder(field.data_type(), capacity)?;
+            Box::new(LargeListBuilder::with_capacity(builder, capacity).with_field(field.clone()))
+        }
+        DataType::FixedSizeList(field, size) => {
+            let values_capacity = capacity.saturating_mul(*size as usize);
+            let builder = try_make_builder(field.data_type(), values_capacity)?;
+            Box::new(
+                FixedSizeListBuilder::with_capacity(builder, *size, capacity)
+                    .with_field(field.clone()),
+            )
         }
         DataType::Map(field, _) => match field.data_type() {
             DataType::Struct(fields) => {
@@ -184,8 +229,8 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                     value: fields[1].name().clone(),
                     entry: field.name().clone(),
                 };
-                let key_builder = make_builder(fields[0].data_type(), capacity);
-                let value_builder = make_builder(fields[1].data_type(), capacity);
+                let key_builder = try_make_builder(fields[0].data_type(), capacity)?;
+                let value_builder = try_make_builder(fields[1].data_type(), capacity)?;
                 Box::new(MapBuilder::with_capacity(
                     Some(map_field_names),
                     key_builder,
@@ -193,11 +238,70 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                     capacity,
                 ))
             }
-            t => panic!("The field of Map data type {t:?} should has a child Struct field"),
+            t => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The field of Map data type {t:?} should has a child Struct field"
+                )))
+            }
         },
-        DataType::Struct(fields) => Box::new(StructBuilder::from_fields(fields.clone(), capacity)),
-        t => panic!("Data type {t:?} is not currently supported"),
+        DataType::Struct(fields) => {
+            Box::new(StructBuilder::try_from_fields(fields.clone(), capacity)?)
+        }
+        DataType::Dictionary(key_type, value_type) => {
+            macro_rules! dictionary_helper {
+                ($t:ty, $value_type:expr, $capacity:expr) => {
+                    make_dictionary_builder::<$t>($value_type, $capacity)?
+                };
+            }
+            downcast_integer! {
+                key_type.as_ref() => (dictionary_helper, value_type, capacity),
+                _ => return Err(unsupported(datatype)),
+            }
+        }
+        t => return Err(unsupported(t)),
+    })
+}
+
+fn unsupported(data_type: &DataType) -> ArrowError {
+    ArrowError::NotYetImplemented(format!(
+        "Data type {data_type:?} is not currently supported"
+    ))
+}
+
+/// Returns a dictionary builder with keys `K` for values of `value_type`
+fn make_dictionary_builder<K: ArrowDictionaryKeyType>(
+    value_type: &DataType,
+    capacity: usize,
+) -> Result<Box<dyn ArrayBuilder>, ArrowError> {
+    macro_rules! primitive_helper {
+        ($t:ty, $value_type:expr, $capacity:expr) => {
+            Box::new(
+                PrimitiveDictionaryBuilder::<K, $t>::new_from_empty_builders(
+                    PrimitiveBuilder::with_capacity($capacity),
+                    PrimitiveBuilder::new().with_data_type($value_type.clone()),
+                ),
+            )
+        };
     }
+
+    Ok(downcast_primitive! {
+        value_type => (primitive_helper, value_type, capacity),
+        DataType::Utf8 => Box::new(StringDictionaryBuilder::<K>::with_capacity(capacity, 0, 0)),
+        DataType::LargeUtf8 => {
+            Box::new(LargeStringDictionaryBuilder::<K>::with_capacity(capacity, 0, 0))
+        }
+        DataType::Binary => Box::new(BinaryDictionaryBuilder::<K>::with_capacity(capacity, 0, 0)),
+        DataType::LargeBinary => {
+            Box::new(LargeBinaryDictionaryBuilder::<K>::with_capacity(capacity, 0, 0))
+        }
+        DataType::FixedSizeBinary(width) => Box::new(
+            FixedSizeBinaryDictionaryBuilder::<K>::with_capacity(capacity, 0, *width),
+        ),
+        _ => {
+            let data_type = DataType::Dictionary(Box::new(K::DATA_TYPE), Box::new(value_type.clone()));
+            return Err(unsupported(&data_type));
+        }
+    })
 }
 
 impl StructBuilder {
@@ -211,13 +315,23 @@ impl StructBuilder {
     }
 
     /// Creates a new `StructBuilder` from [`Fields`] and `capacity`
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the fields has an unsupported data type, see [`Self::try_from_fields`]
     pub fn from_fields(fields: impl Into<Fields>, capacity: usize) -> Self {
+        Self::try_from_fields(fields, capacity).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Creates a new `StructBuilder` from [`Fields`] and `capacity`, returning an
+    /// error if any of the fields has a data type not supported by [`try_make_builder`]
+    pub fn try_from_fields(fields: impl Into<Fields>, capacity: usize) -> Result<Self, ArrowError> {
         let fields = fields.into();
-        let mut builders = Vec::with_capacity(fields.len());
-        for field in &fields {
-            builders.push(make_builder(field.data_type(), capacity));
-        }
-        Self::new(fields, builders)
+        let builders = fields
+            .iter()
+            .map(|field| try_make_builder(field.data_type(), capacity))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(fields, builders))
     }
 
     /// Returns a mutable reference to the child field builder at index `i`.
@@ -227,6 +341,14 @@ impl StructBuilder {
         self.field_builders[i].as_any_mut().downcast_mut::<T>()
     }
 
+    /// Returns a mutable reference to the child field builder for the field named `name`.
+    /// Result will be `None` if there is no such field, or if the input type `T` provided
+    /// doesn't match the actual field builder's type.
+    pub fn field_builder_by_name<T: ArrayBuilder>(&mut self, name: &str) -> Option<&mut T> {
+        let (idx, _) = self.fields.find(name)?;
+        self.field_builder(idx)
+    }
+
     /// Returns the number of fields for the struct this builder is building.
     pub fn num_fields(&self) -> usize {
         self.field_builders.len()
@@ -309,9 +431,12 @@ mod tests {
     use super::*;
     use arrow_buffer::Buffer;
     use arrow_data::ArrayData;
-    use arrow_schema::Field;
+    use arrow_schema::{Field, UnionFields, UnionMode};
 
     use crate::array::Array;
+    use crate::types::{
+        Decimal128Type, Int16Type, Int32Type, Int64Type, TimestampSecondType, UInt32Type, UInt8Type,
+    };
 
     #[test]
     fn test_struct_array_builder() {
@@ -544,13 +669,13 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Data type Dictionary(Int32, Utf8) is not currently supported")]
+    #[should_panic(expected = "Data type Dictionary(Int32, Null) is not currently supported")]
     fn test_struct_array_builder_from_schema_unsupported_type() {
         let fields = vec![
             Field::new("f1", DataType::Int16, false),
             Field::new(
                 "f2",
-                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Null)),
                 false,
             ),
         ];
@@ -558,6 +683,113 @@ mod tests {
         let _ = StructBuilder::from_fields(fields, 5);
     }
 
+    #[test]
+    fn test_struct_array_builder_try_from_fields_unsupported_type() {
+        let union = DataType::Union(UnionFields::empty(), UnionMode::Dense);
+        let fields = vec![Field::new(
+            "f1",
+            DataType::List(Arc::new(Field::new("item", union, true))),
+            true,
+        )];
+        let err = StructBuilder::try_from_fields(fields, 5).unwrap_err();
+        assert!(matches!(err, ArrowError::NotYetImplemented(_)));
+        assert!(err.to_string().contains("Union"), "{err}");
+
+        let map = DataType::Map(
+            Arc::new(Field::new("entries", DataType::Int32, false)),
+            false,
+        );
+        let err = try_make_builder(&map, 5).err().unwrap();
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)));
+    }
+
+    #[test]
+    fn test_struct_array_builder_dictionary_fields() {
+        let dict = |k: DataType, v: DataType| DataType::Dictionary(Box::new(k), Box::new(v));
+        let fields = Fields::from(vec![
+            Field::new("utf8", dict(DataType::UInt8, DataType::Utf8), true),
+            Field::new(
+                "large_binary",
+                dict(DataType::Int64, DataType::LargeBinary),
+                true,
+            ),
+            Field::new(
+                "fixed",
+                dict(DataType::Int16, DataType::FixedSizeBinary(2)),
+                true,
+            ),
+            Field::new(
+                "decimal",
+                dict(DataType::UInt32, DataType::Decimal128(10, 2)),
+                true,
+            ),
+            Field::new(
+                "timestamp",
+                dict(
+                    DataType::Int32,
+                    DataType::Timestamp(TimeUnit::Second, Some("UTC".into())),
+                ),
+                true,
+            ),
+            Field::new_list("list", Field::new("element", DataType::Int32, false), true),
+            Field::new(
+                "fixed_list",
+                DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Utf8, true)), 2),
+                true,
+            ),
+        ]);
+
+        let mut builder = StructBuilder::from_fields(fields.clone(), 2);
+        builder
+            .field_builder_by_name::<StringDictionaryBuilder<UInt8Type>>("utf8")
+            .unwrap()
+            .append_value("a");
+        builder
+            .field_builder_by_name::<LargeBinaryDictionaryBuilder<Int64Type>>("large_binary")
+            .unwrap()
+            .append_value(b"b");
+        builder
+            .field_builder_by_name::<FixedSizeBinaryDictionaryBuilder<Int16Type>>("fixed")
+            .unwrap()
+            .append_value(b"cd");
+        builder
+            .field_builder_by_name::<PrimitiveDictionaryBuilder<UInt32Type, Decimal128Type>>(
+                "decimal",
+            )
+            .unwrap()
+            .append_value(123);
+        builder
+            .field_builder_by_name::<PrimitiveDictionaryBuilder<Int32Type, TimestampSecondType>>(
+                "timestamp",
+            )
+            .unwrap()
+            .append_value(1);
+        builder
+            .field_builder_by_name::<ListBuilder<Box<dyn ArrayBuilder>>>("list")
+            .unwrap()
+            .append(true);
+        let fixed_list = builder
+            .field_builder_by_name::<FixedSizeListBuilder<Box<dyn ArrayBuilder>>>("fixed_list")
+            .unwrap();
+        let values = fixed_list.values().as_any_mut();
+        let values = values.downcast_mut::<StringBuilder>().unwrap();
+        values.append_value("e");
+        values.append_null();
+        fixed_list.append(true);
+        builder.append(true);
+
+        assert!(builder
+            .field_builder_by_name::<Int32Builder>("utf8")
+            .is_none());
+        assert!(builder
+            .field_builder_by_name::<Int32Builder>("missing")
+            .is_none());
+
+        let array = builder.finish();
+        assert_eq!(array.data_type(), &DataType::Struct(fields));
+        assert_eq!(array.len(), 1);
+    }
+
     #[test]
     fn test_struct_array_builder_field_builder_type_mismatch() {
         let int_builder = Int32Builder::with_capacity(10);
@@ -17,7 +17,7 @@
 
 use crate::builder::buffer_builder::{Int32BufferBuilder, Int8BufferBuilder};
 use crate::builder::BufferBuilder;
-use crate::{make_array, ArrowPrimitiveType, UnionArray};
+use crate::{make_array, Array, ArrowPrimitiveType, PrimitiveArray, UnionArray};
 use arrow_buffer::NullBufferBuilder;
 use arrow_buffer::{ArrowNativeType, Buffer};
 use arrow_data::ArrayDataBuilder;
@@ -46,6 +46,8 @@ trait FieldDataValues: std::fmt::Debug {
 
     fn append_null(&mut self);
 
+    fn append_nulls(&mut self, n: usize);
+
     fn finish(&mut self) -> Buffer;
 }
 
@@ -58,6 +60,10 @@ impl<T: ArrowNativeType> FieldDataValues for BufferBuilder<T> {
         self.advance(1)
     }
 
+    fn append_nulls(&mut self, n: usize) {
+        self.advance(n)
+    }
+
     fn finish(&mut self) -> Buffer {
         self.finish()
     }
@@ -87,12 +93,34 @@ impl FieldData {
         self.slots += 1;
     }
 
+    /// Appends the values of `array` to this `FieldData`'s `values_buffer`.
+    fn append_array<T: ArrowPrimitiveType>(&mut self, array: &PrimitiveArray<T>) {
+        self.values_buffer
+            .as_mut_any()
+            .downcast_mut::<BufferBuilder<T::Native>>()
+            .expect("Tried to append unexpected type")
+            .append_slice(array.values());
+
+        match array.nulls() {
+            Some(nulls) => self.null_buffer_builder.append_buffer(nulls),
+            None => self.null_buffer_builder.append_n_non_nulls(array.len()),
+        }
+        self.slots += array.len();
+    }
+
     /// Appends a null to this `FieldData`.
     fn append_null(&mut self) {
         self.values_buffer.append_null();
         self.null_buffer_builder.append(false);
         self.slots += 1;
     }
+
+    /// Appends `n` nulls to this `FieldData`.
+    fn append_nulls(&mut self, n: usize) {
+        self.values_buffer.append_nulls(n);
+        self.null_buffer_builder.append_n_nulls(n);
+        self.slots += n;
+    }
 }
 
 /// Builder for [`UnionArray`]
@@ -208,14 +236,64 @@ impl UnionBuilder {
         self.append_option::<T>(type_name, Some(v))
     }
 
-    fn append_option<T: ArrowPrimitiveType>(
+    /// Appends all the values of `array` to this builder as values of `type_name`.
+    ///
+    /// This is equivalent to, but significantly faster than, calling [`Self::append`]
+    /// and [`Self::append_null`] for each slot of `array`.
+    ///
+    /// ```
+    /// # use arrow_array::builder::UnionBuilder;
+    /// # use arrow_array::types::{Float64Type, Int32Type};
+    /// # use arrow_array::{Array, Float64Array, Int32Array};
+    /// let mut builder = UnionBuilder::new_dense();
+    /// builder.append_array("a", &Int32Array::from(vec![Some(1), None])).unwrap();
+    /// builder.append::<Float64Type>("b", 3.0).unwrap();
+    /// builder.append_array("a", &Int32Array::from(vec![4])).unwrap();
+    /// let union = builder.build().unwrap();
+    ///
+    /// assert_eq!(union.len(), 4);
+    /// assert_eq!(union.type_ids().as_ref(), &[0, 0, 1, 0]);
+    /// assert_eq!(union.offsets().unwrap().as_ref(), &[0, 1, 0, 2]);
+    /// ```
+    pub fn append_array<T: ArrowPrimitiveType>(
         &mut self,
         type_name: &str,
-        v: Option<T::Native>,
+        array: &PrimitiveArray<T>,
     ) -> Result<(), ArrowError> {
+        let len = array.len();
         let type_name = type_name.to_string();
+        let mut field_data = self.take_field_data::<T>(&type_name)?;
 
-        let mut field_data = match self.fields.remove(&type_name) {
+        self.type_id_builder.append_n(len, field_data.type_id);
+
+        match &mut self.value_offset_builder {
+            // Dense Union
+            Some(offset_builder) => {
+                let start = field_data.slots as i32;
+                offset_builder.extend(start..start + len as i32);
+            }
+            // Sparse Union
+            None => {
+                for (_, fd) in self.fields.iter_mut() {
+                    // Append to all bar the FieldData currently being appended to
+                    fd.append_nulls(len);
+                }
+            }
+        }
+
+        field_data.append_array(array);
+
+        self.fields.insert(type_name, field_data);
+        self.len += len;
+        Ok(())
+    }
+
+    /// Removes the `FieldData` for `type_name` from `self.fields`, creating it if necessary
+    fn take_field_data<T: ArrowPrimitiveType>(
+        &mut self,
+        type_name: &str,
+    ) -> Result<FieldData, ArrowError> {
+        Ok(match self.fields.remove(type_name) {
             Some(data) => {
                 if data.data_type != T::DATA_TYPE {
                     return Err(ArrowError::InvalidArgumentError(format!(
@@ -240,13 +318,21 @@ impl UnionBuilder {
                         T::DATA_TYPE,
                         self.len.max(self.initial_capacity),
                     );
-                    for _ in 0..self.len {
-                        fd.append_null();
-                    }
+                    fd.append_nulls(self.len);
                     fd
                 }
             },
-        };
+        })
+    }
+
+    fn append_option<T: ArrowPrimitiveType>(
+        &mut self,
+        type_name: &str,
+        v: Option<T::Native>,
+    ) -> Result<(), ArrowError> {
+        let type_name = type_name.to_string();
+        let mut field_data = self.take_field_data::<T>(&type_name)?;
+
         self.type_id_builder.append(field_data.type_id);
 
         match &mut self.value_offset_builder {
@@ -0,0 +1,254 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for constructing user-defined [`Array`](crate::Array) implementations for
+//! [extension types](https://arrow.apache.org/docs/format/Columnar.html#extension-types)
+//!
+//! An extension type is identified by the [`EXTENSION_TYPE_NAME_KEY`] metadata of a
+//! [`Field`], with the data itself stored using the field's [`DataType`], known as the
+//! storage type. As [`ArrayData`] does not carry field metadata, [`make_array`] always
+//! returns an array of the storage type, e.g. a [`StringArray`](crate::StringArray).
+//!
+//! [`DataType`]: arrow_schema::DataType
+//!
+//! An [`ArrayFactoryRegistry`] allows registering a factory for an extension type name,
+//! which [`ArrayFactoryRegistry::make_array`] then uses to construct a user-defined
+//! array for fields of that extension type.
+//!
+//! ```
+//! # use std::any::Any;
+//! # use std::sync::Arc;
+//! # use arrow_array::{Array, ArrayRef, RecordBatch, StringArray};
+//! # use arrow_array::extension::{ArrayFactoryRegistry, EXTENSION_TYPE_NAME_KEY};
+//! # use arrow_buffer::NullBuffer;
+//! # use arrow_data::ArrayData;
+//! # use arrow_schema::{DataType, Field, Schema};
+//! /// A user-defined array of well-known text geometries
+//! #[derive(Debug)]
+//! struct GeometryArray(StringArray);
+//!
+//! impl GeometryArray {
+//!     fn wkt(&self, idx: usize) -> &str {
+//!         self.0.value(idx)
+//!     }
+//! }
+//!
+//! impl Array for GeometryArray {
+//!     fn as_any(&self) -> &dyn Any { self }
+//!     fn to_data(&self) -> ArrayData { self.0.to_data() }
+//!     fn into_data(self) -> ArrayData { self.0.into_data() }
+//!     fn data_type(&self) -> &DataType { self.0.data_type() }
+//!     fn slice(&self, offset: usize, length: usize) -> ArrayRef {
+//!         Arc::new(Self(self.0.slice(offset, length)))
+//!     }
+//!     fn len(&self) -> usize { self.0.len() }
+//!     fn is_empty(&self) -> bool { self.0.is_empty() }
+//!     fn offset(&self) -> usize { self.0.offset() }
+//!     fn nulls(&self) -> Option<&NullBuffer> { self.0.nulls() }
+//!     fn get_buffer_memory_size(&self) -> usize { self.0.get_buffer_memory_size() }
+//!     fn get_array_memory_size(&self) -> usize { self.0.get_array_memory_size() }
+//! }
+//!
+//! let mut registry = ArrayFactoryRegistry::new();
+//! registry.register("geo.wkt", |data| Ok(Arc::new(GeometryArray(data.into()))));
+//!
+//! // A batch as returned by a reader, with the storage array for the extension type
+//! let metadata = [(EXTENSION_TYPE_NAME_KEY.to_string(), "geo.wkt".to_string())];
+//! let field = Field::new("geometry", DataType::Utf8, false).with_metadata(metadata.into());
+//! let schema = Arc::new(Schema::new(vec![field]));
+//! let array = StringArray::from(vec!["POINT (1 2)", "POINT (3 4)"]);
+//! let batch = RecordBatch::try_new(schema, vec![Arc::new(array)]).unwrap();
+//!
+//! let batch = registry.make_record_batch(&batch).unwrap();
+//! let geometries = batch.column(0).as_any().downcast_ref::<GeometryArray>().unwrap();
+//! assert_eq!(geometries.wkt(1), "POINT (3 4)");
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, Field};
+
+use crate::{make_array, ArrayRef, RecordBatch};
+
+/// The [`Field`] metadata key identifying the name of an extension type
+pub const EXTENSION_TYPE_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The [`Field`] metadata key containing the serialized metadata of an extension type
+pub const EXTENSION_TYPE_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// A function constructing an [`ArrayRef`] from the [`ArrayData`] of an extension type
+///
+/// The returned array must have the same [`DataType`](arrow_schema::DataType) as `data`
+pub type ArrayFactory = Arc<dyn Fn(ArrayData) -> Result<ArrayRef, ArrowError> + Send + Sync>;
+
+/// A registry of [`ArrayFactory`] keyed by extension type name
+///
+/// See the [module documentation](self) for an example
+#[derive(Clone, Default)]
+pub struct ArrayFactoryRegistry {
+    factories: HashMap<String, ArrayFactory>,
+}
+
+impl std::fmt::Debug for ArrayFactoryRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArrayFactoryRegistry")
+            .field("extension_types", &self.factories.keys())
+            .finish()
+    }
+}
+
+impl ArrayFactoryRegistry {
+    /// Create a new, empty [`ArrayFactoryRegistry`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `factory` for the extension type `name`, returning the
+    /// previously registered factory, if any
+    pub fn register<F>(&mut self, name: impl Into<String>, factory: F) -> Option<ArrayFactory>
+    where
+        F: Fn(ArrayData) -> Result<ArrayRef, ArrowError> + Send + Sync + 'static,
+    {
+        self.factories.insert(name.into(), Arc::new(factory))
+    }
+
+    /// Removes the factory registered for the extension type `name`, if any
+    pub fn deregister(&mut self, name: &str) -> Option<ArrayFactory> {
+        self.factories.remove(name)
+    }
+
+    /// Returns the factory registered for the extension type `name`, if any
+    pub fn get(&self, name: &str) -> Option<&ArrayFactory> {
+        self.factories.get(name)
+    }
+
+    /// Constructs an array for `field` from `data`
+    ///
+    /// If `field` is an extension type with a registered factory, the factory is used
+    /// to construct the array, otherwise this is equivalent to [`make_array`]
+    ///
+    /// Returns an error if the data type of `data` does not match that of `field`,
+    /// or if the factory returns an array of a different data type
+    pub fn make_array(&self, field: &Field, data: ArrayData) -> Result<ArrayRef, ArrowError> {
+        if data.data_type() != field.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Data type {} of array does not match {} of field \"{}\"",
+                data.data_type(),
+                field.data_type(),
+                field.name()
+            )));
+        }
+
+        let factory = match field
+            .metadata()
+            .get(EXTENSION_TYPE_NAME_KEY)
+            .and_then(|name| self.get(name))
+        {
+            Some(factory) => factory,
+            None => return Ok(make_array(data)),
+        };
+
+        let array = factory(data)?;
+        if array.data_type() != field.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Extension array factory for field \"{}\" returned an array of type {}, expected {}",
+                field.name(),
+                array.data_type(),
+                field.data_type()
+            )));
+        }
+        Ok(array)
+    }
+
+    /// Returns a new [`RecordBatch`] with the columns of `batch` that are of a
+    /// registered extension type constructed by [`Self::make_array`]
+    pub fn make_record_batch(&self, batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+        let schema = batch.schema();
+        let columns = schema
+            .fields()
+            .iter()
+            .zip(batch.columns())
+            .map(
+                |(field, column)| match field.metadata().get(EXTENSION_TYPE_NAME_KEY) {
+                    Some(name) if self.factories.contains_key(name) => {
+                        self.make_array(field, column.to_data())
+                    }
+                    _ => Ok(column.clone()),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        RecordBatch::try_new(schema, columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Array, Int32Array};
+    use arrow_schema::DataType;
+    use std::collections::HashMap;
+
+    fn extension_field(name: &str, data_type: DataType) -> Field {
+        let metadata = HashMap::from([(EXTENSION_TYPE_NAME_KEY.to_string(), name.to_string())]);
+        Field::new("a", data_type, true).with_metadata(metadata)
+    }
+
+    #[test]
+    fn test_make_array() {
+        let mut registry = ArrayFactoryRegistry::new();
+        assert!(registry
+            .register("wrong_type", |_| Ok(Arc::new(Int32Array::from(vec![1]))))
+            .is_none());
+        registry.register("failing", |_| {
+            Err(ArrowError::InvalidArgumentError("invalid".to_string()))
+        });
+
+        let data = crate::StringArray::from(vec!["a", "b"]).into_data();
+
+        // Fields without a registered factory use make_array
+        let plain = Field::new("a", DataType::Utf8, true);
+        let array = registry.make_array(&plain, data.clone()).unwrap();
+        assert_eq!(array.to_data(), data);
+        let unknown = extension_field("unknown", DataType::Utf8);
+        let array = registry.make_array(&unknown, data.clone()).unwrap();
+        assert_eq!(array.to_data(), data);
+
+        let field = extension_field("wrong_type", DataType::Utf8);
+        let err = registry.make_array(&field, data.clone()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Extension array factory for field \"a\" returned an array of type Int32, expected Utf8"
+        );
+
+        let field = extension_field("failing", DataType::Utf8);
+        let err = registry.make_array(&field, data.clone()).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid argument error: invalid");
+
+        let field = extension_field("failing", DataType::Int32);
+        let err = registry.make_array(&field, data).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Data type Utf8 of array does not match Int32 of field \"a\""
+        );
+
+        assert!(registry.deregister("failing").is_some());
+        assert!(registry.get("failing").is_none());
+        assert!(registry.get("wrong_type").is_some());
+    }
+}
@@ -197,12 +197,14 @@ pub use scalar::*;
 pub mod builder;
 pub mod cast;
 mod delta;
+pub mod extension;
 pub mod iterator;
 pub mod run_iterator;
 pub mod temporal_conversions;
 pub mod timezone;
 mod trusted_len;
 pub mod types;
+pub mod visitor;
 
 #[cfg(test)]
 mod tests {
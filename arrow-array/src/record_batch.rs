@@ -18,7 +18,9 @@
 //! A two-dimensional batch of column-oriented data with a defined
 //! [schema](arrow_schema::Schema).
 
+use crate::cast::AsArray;
 use crate::{new_empty_array, Array, ArrayRef, StructArray};
+use arrow_buffer::{ArrowNativeType, BooleanBufferBuilder, NullBuffer};
 use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaBuilder, SchemaRef};
 use std::ops::Index;
 use std::sync::Arc;
@@ -205,6 +207,12 @@ impl RecordBatch {
                 "column types must match schema types, expected {field_type:?} but found {col_type:?} at column index {i}")));
         }
 
+        if options.strict_nullability {
+            for (c, f) in columns.iter().zip(schema.fields().iter()) {
+                validate_nested_nullability(f.name(), c.as_ref(), c.nulls())?;
+            }
+        }
+
         Ok(RecordBatch {
             schema,
             columns,
@@ -258,6 +266,7 @@ impl RecordBatch {
             &RecordBatchOptions {
                 match_field_names: true,
                 row_count: Some(self.row_count),
+                strict_nullability: false,
             },
         )
     }
@@ -481,6 +490,13 @@ pub struct RecordBatchOptions {
 
     /// Optional row count, useful for specifying a row count for a RecordBatch with no columns
     pub row_count: Option<usize>,
+
+    /// Verify that the nested children of columns, such as the fields of a struct or
+    /// the values of a list, contain no nulls if declared non-nullable.
+    ///
+    /// Nulls in a non-nullable child are permitted where masked by a null in an ancestor,
+    /// e.g. a null struct slot or a null list slot. Top-level columns are always verified.
+    pub strict_nullability: bool,
 }
 
 impl RecordBatchOptions {
@@ -489,6 +505,7 @@ impl RecordBatchOptions {
         Self {
             match_field_names: true,
             row_count: None,
+            strict_nullability: false,
         }
     }
     /// Sets the row_count of RecordBatchOptions and returns self
@@ -501,7 +518,99 @@ impl RecordBatchOptions {
         self.match_field_names = match_field_names;
         self
     }
+    /// Sets the strict_nullability of RecordBatchOptions and returns self
+    pub fn with_strict_nullability(mut self, strict_nullability: bool) -> Self {
+        self.strict_nullability = strict_nullability;
+        self
+    }
+}
+/// Verifies that the non-nullable descendants of `array`, whose field is at `path`,
+/// contain no nulls in the slots that are not masked by `mask`
+fn validate_nested_nullability(
+    path: &str,
+    array: &dyn Array,
+    mask: Option<&NullBuffer>,
+) -> Result<(), ArrowError> {
+    let check = |field: &Field, child: &dyn Array, mask: Option<&NullBuffer>| {
+        let path = format!("{path}.{}", field.name());
+        let child_mask = NullBuffer::union(mask, child.nulls());
+        // A null is only valid in a non-nullable child if masked by an ancestor
+        let masked = mask.map(|m| m.null_count()).unwrap_or_default();
+        let nulls = child_mask
+            .as_ref()
+            .map(|m| m.null_count())
+            .unwrap_or_default();
+        if !field.is_nullable() && nulls > masked {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Field '{path}' is declared as non-nullable but contains null values"
+            )));
+        }
+        validate_nested_nullability(&path, child, child_mask.as_ref())
+    };
+
+    // Returns the mask of the values of a list, only the values of valid list slots
+    // are unmasked
+    let list_mask = |offsets: &mut dyn Iterator<Item = (usize, usize)>, len: usize| {
+        let mut builder = BooleanBufferBuilder::new(len);
+        builder.append_n(len, false);
+        for (idx, (start, end)) in offsets.enumerate() {
+            if mask.map(|m| m.is_valid(idx)).unwrap_or(true) {
+                (start..end).for_each(|i| builder.set_bit(i, true));
+            }
+        }
+        NullBuffer::new(builder.finish())
+    };
+
+    match array.data_type() {
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            fields
+                .iter()
+                .zip(array.columns())
+                .try_for_each(|(f, c)| check(f, c.as_ref(), mask))
+        }
+        DataType::List(f) => {
+            let array = array.as_list::<i32>();
+            let values = array.values();
+            let mut offsets = array
+                .offsets()
+                .windows(2)
+                .map(|w| (w[0].as_usize(), w[1].as_usize()));
+            let mask = list_mask(&mut offsets, values.len());
+            check(f, values.as_ref(), Some(&mask))
+        }
+        DataType::LargeList(f) => {
+            let array = array.as_list::<i64>();
+            let values = array.values();
+            let mut offsets = array
+                .offsets()
+                .windows(2)
+                .map(|w| (w[0].as_usize(), w[1].as_usize()));
+            let mask = list_mask(&mut offsets, values.len());
+            check(f, values.as_ref(), Some(&mask))
+        }
+        DataType::FixedSizeList(f, size) => {
+            let array = array.as_fixed_size_list();
+            let values = array.values();
+            let size = *size as usize;
+            let mut offsets = (0..array.len()).map(|i| (i * size, (i + 1) * size));
+            let mask = list_mask(&mut offsets, values.len());
+            check(f, values.as_ref(), Some(&mask))
+        }
+        DataType::Map(f, _) => {
+            let array = array.as_map();
+            let entries = array.entries();
+            let mut offsets = array
+                .offsets()
+                .windows(2)
+                .map(|w| (w[0].as_usize(), w[1].as_usize()));
+            let mask = list_mask(&mut offsets, entries.len());
+            check(f, entries, Some(&mask))
+        }
+        _ => Ok(()),
+    }
 }
+
 impl Default for RecordBatchOptions {
     fn default() -> Self {
         Self::new()
@@ -620,7 +729,7 @@ where
 mod tests {
     use super::*;
     use crate::{BooleanArray, Int32Array, Int64Array, Int8Array, ListArray, StringArray};
-    use arrow_buffer::{Buffer, ToByteSlice};
+    use arrow_buffer::{Buffer, OffsetBuffer, ToByteSlice};
     use arrow_data::{ArrayData, ArrayDataBuilder};
     use arrow_schema::Fields;
 
@@ -804,6 +913,7 @@ mod tests {
         let options = RecordBatchOptions {
             match_field_names: false,
             row_count: None,
+            strict_nullability: false,
         };
         let batch = RecordBatch::try_new_with_options(schema, vec![a], &options);
         assert!(batch.is_ok());
@@ -1048,6 +1158,7 @@ mod tests {
             &RecordBatchOptions {
                 match_field_names: true,
                 row_count: Some(3),
+                strict_nullability: false,
             },
         )
         .expect("valid conversion");
@@ -1155,4 +1266,72 @@ mod tests {
         let size = get_size(reader);
         assert_eq!(size, 0);
     }
+
+    #[test]
+    fn test_strict_nullability() {
+        let options = RecordBatchOptions::new().with_strict_nullability(true);
+
+        // Struct with a non-nullable child containing a null masked by the parent
+        let child = Field::new("c", DataType::Int32, false);
+        let fields = Fields::from(vec![child.clone()]);
+        let values = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef;
+        let parent_nulls = NullBuffer::from(vec![true, false, true]);
+        let s = StructArray::new(fields.clone(), vec![values.clone()], Some(parent_nulls));
+        let schema = Arc::new(Schema::new(vec![Field::new_struct(
+            "s",
+            fields.clone(),
+            true,
+        )]));
+        RecordBatch::try_new_with_options(schema.clone(), vec![Arc::new(s)], &options).unwrap();
+
+        // Unmasked null in a non-nullable child, e.g. as created via FFI
+        let s = unsafe { StructArray::new_unchecked(fields, vec![values], None) };
+        let columns = vec![Arc::new(s) as ArrayRef];
+        RecordBatch::try_new(schema.clone(), columns.clone()).unwrap();
+        let err = RecordBatch::try_new_with_options(schema, columns, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field 's.c' is declared as non-nullable but contains null values"
+        );
+
+        // List with a non-nullable item, nulls only masked by null list slots
+        let item = Arc::new(Field::new("item", DataType::Int32, false));
+        let values = Int32Array::from(vec![Some(1), None, Some(3), None]);
+        let list = |offsets: Vec<i32>, nulls: Option<NullBuffer>| {
+            let builder = ArrayData::builder(DataType::List(item.clone()))
+                .len(offsets.len() - 1)
+                .add_buffer(Buffer::from_vec(offsets))
+                .add_child_data(values.to_data())
+                .nulls(nulls);
+            let data = unsafe { builder.build_unchecked() };
+            Arc::new(ListArray::from(data)) as ArrayRef
+        };
+        let schema = Arc::new(Schema::new(vec![Field::new_list("l", item.clone(), true)]));
+        let list_nulls = NullBuffer::from(vec![true, false, true]);
+        let columns = vec![list(vec![0, 1, 2, 3], Some(list_nulls))];
+        RecordBatch::try_new_with_options(schema.clone(), columns, &options).unwrap();
+
+        let columns = vec![list(vec![0, 2, 3, 4], None)];
+        RecordBatch::try_new(schema.clone(), columns.clone()).unwrap();
+        let err = RecordBatch::try_new_with_options(schema, columns, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field 'l.item' is declared as non-nullable but contains null values"
+        );
+
+        // Nulls nested within a struct within a list
+        let fields = Fields::from(vec![Field::new("c", DataType::Int32, false)]);
+        let values = Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef;
+        let s = unsafe { StructArray::new_unchecked(fields.clone(), vec![values], None) };
+        let item = Arc::new(Field::new_struct("item", fields, true));
+        let offsets = OffsetBuffer::new(vec![0, 2].into());
+        let list = ListArray::new(item.clone(), offsets, Arc::new(s), None);
+        let schema = Arc::new(Schema::new(vec![Field::new_list("l", item, true)]));
+        let err =
+            RecordBatch::try_new_with_options(schema, vec![Arc::new(list)], &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field 'l.item.c' is declared as non-nullable but contains null values"
+        );
+    }
 }
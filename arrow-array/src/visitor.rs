@@ -0,0 +1,389 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Dispatch of a [`&dyn Array`](Array) to its concrete array type with an [`ArrayVisitor`]
+//!
+//! ```
+//! # use arrow_array::{Array, BooleanArray, Int32Array, ArrowPrimitiveType, PrimitiveArray, StructArray};
+//! # use arrow_array::visitor::{visit, ArrayVisitor};
+//! # use std::sync::Arc;
+//! /// Counts the number of values in an array, including those of its children
+//! struct CountValues;
+//!
+//! impl ArrayVisitor for CountValues {
+//!     type Output = usize;
+//!
+//!     fn visit_primitive<T: ArrowPrimitiveType>(&mut self, array: &PrimitiveArray<T>) -> usize {
+//!         array.len()
+//!     }
+//!
+//!     fn visit_struct(&mut self, array: &StructArray) -> usize {
+//!         array.columns().iter().map(|c| visit(c.as_ref(), self)).sum()
+//!     }
+//!
+//!     fn visit_other(&mut self, array: &dyn Array) -> usize {
+//!         array.len()
+//!     }
+//! }
+//!
+//! let array = StructArray::try_from(vec![
+//!     ("a", Arc::new(Int32Array::from(vec![1, 2])) as _),
+//!     ("b", Arc::new(BooleanArray::from(vec![true, false])) as _),
+//! ])
+//! .unwrap();
+//! assert_eq!(visit(&array, &mut CountValues), 4);
+//! ```
+
+use crate::cast::AsArray;
+use crate::types::{ArrowDictionaryKeyType, ByteArrayType, RunEndIndexType};
+use crate::{
+    downcast_integer, downcast_primitive, downcast_run_end_index, Array, ArrowPrimitiveType,
+    BooleanArray, DictionaryArray, FixedSizeBinaryArray, FixedSizeListArray, GenericByteArray,
+    GenericListArray, MapArray, NullArray, OffsetSizeTrait, PrimitiveArray, RunArray, StructArray,
+    UnionArray,
+};
+use arrow_schema::DataType;
+
+/// A visitor of the concrete array types, see [`visit`]
+///
+/// All methods default to [`ArrayVisitor::visit_other`], allowing implementations
+/// to only handle the array types they are interested in. Nested arrays are not
+/// traversed automatically, instead implementations can call [`visit`] on the
+/// children they wish to visit.
+pub trait ArrayVisitor {
+    /// The value returned by the visitor
+    type Output;
+
+    /// Visit a [`NullArray`]
+    fn visit_null(&mut self, array: &NullArray) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`BooleanArray`]
+    fn visit_boolean(&mut self, array: &BooleanArray) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`PrimitiveArray`]
+    fn visit_primitive<T: ArrowPrimitiveType>(
+        &mut self,
+        array: &PrimitiveArray<T>,
+    ) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`GenericByteArray`], such as a [`StringArray`](crate::StringArray)
+    /// or a [`BinaryArray`](crate::BinaryArray)
+    fn visit_bytes<T: ByteArrayType>(&mut self, array: &GenericByteArray<T>) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`FixedSizeBinaryArray`]
+    fn visit_fixed_size_binary(&mut self, array: &FixedSizeBinaryArray) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`GenericListArray`]
+    fn visit_list<O: OffsetSizeTrait>(&mut self, array: &GenericListArray<O>) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`FixedSizeListArray`]
+    fn visit_fixed_size_list(&mut self, array: &FixedSizeListArray) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`StructArray`]
+    fn visit_struct(&mut self, array: &StructArray) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`MapArray`]
+    fn visit_map(&mut self, array: &MapArray) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`UnionArray`]
+    fn visit_union(&mut self, array: &UnionArray) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`DictionaryArray`]
+    fn visit_dictionary<K: ArrowDictionaryKeyType>(
+        &mut self,
+        array: &DictionaryArray<K>,
+    ) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit a [`RunArray`]
+    fn visit_run<R: RunEndIndexType>(&mut self, array: &RunArray<R>) -> Self::Output {
+        self.visit_other(array)
+    }
+
+    /// Visit an array not handled by any of the other methods
+    ///
+    /// This is also called for arrays that are not one of the arrays provided by
+    /// this crate, such as user-defined arrays for an extension type
+    fn visit_other(&mut self, array: &dyn Array) -> Self::Output;
+}
+
+macro_rules! visit_primitive_helper {
+    ($t:ty, $array:ident, $visitor:ident) => {
+        match $array.as_primitive_opt::<$t>() {
+            Some(a) => $visitor.visit_primitive(a),
+            None => $visitor.visit_other($array),
+        }
+    };
+}
+
+macro_rules! visit_dictionary_helper {
+    ($t:ty, $array:ident, $visitor:ident) => {
+        match $array.as_dictionary_opt::<$t>() {
+            Some(a) => $visitor.visit_dictionary(a),
+            None => $visitor.visit_other($array),
+        }
+    };
+}
+
+macro_rules! visit_run_helper {
+    ($t:ty, $array:ident, $visitor:ident) => {
+        match $array.as_any().downcast_ref::<RunArray<$t>>() {
+            Some(a) => $visitor.visit_run(a),
+            None => $visitor.visit_other($array),
+        }
+    };
+}
+
+/// Dispatches `array` to the [`ArrayVisitor`] method for its concrete array type
+///
+/// Arrays that cannot be downcast to the array type corresponding to their
+/// [`DataType`], such as user-defined arrays, are passed to [`ArrayVisitor::visit_other`]
+pub fn visit<V: ArrayVisitor + ?Sized>(array: &dyn Array, visitor: &mut V) -> V::Output {
+    fn downcast<V: ArrayVisitor + ?Sized, A: 'static>(
+        array: &dyn Array,
+        visitor: &mut V,
+        f: impl FnOnce(&mut V, &A) -> V::Output,
+    ) -> V::Output {
+        match array.as_any().downcast_ref::<A>() {
+            Some(a) => f(visitor, a),
+            None => visitor.visit_other(array),
+        }
+    }
+
+    downcast_primitive! {
+        array.data_type() => (visit_primitive_helper, array, visitor),
+        DataType::Null => downcast(array, visitor, |v, a| v.visit_null(a)),
+        DataType::Boolean => downcast(array, visitor, |v, a| v.visit_boolean(a)),
+        DataType::Utf8 => match array.as_string_opt::<i32>() {
+            Some(a) => visitor.visit_bytes(a),
+            None => visitor.visit_other(array),
+        },
+        DataType::LargeUtf8 => match array.as_string_opt::<i64>() {
+            Some(a) => visitor.visit_bytes(a),
+            None => visitor.visit_other(array),
+        },
+        DataType::Binary => match array.as_binary_opt::<i32>() {
+            Some(a) => visitor.visit_bytes(a),
+            None => visitor.visit_other(array),
+        },
+        DataType::LargeBinary => match array.as_binary_opt::<i64>() {
+            Some(a) => visitor.visit_bytes(a),
+            None => visitor.visit_other(array),
+        },
+        DataType::FixedSizeBinary(_) => {
+            downcast(array, visitor, |v, a| v.visit_fixed_size_binary(a))
+        }
+        DataType::List(_) => match array.as_list_opt::<i32>() {
+            Some(a) => visitor.visit_list(a),
+            None => visitor.visit_other(array),
+        },
+        DataType::LargeList(_) => match array.as_list_opt::<i64>() {
+            Some(a) => visitor.visit_list(a),
+            None => visitor.visit_other(array),
+        },
+        DataType::FixedSizeList(_, _) => {
+            downcast(array, visitor, |v, a| v.visit_fixed_size_list(a))
+        }
+        DataType::Struct(_) => downcast(array, visitor, |v, a| v.visit_struct(a)),
+        DataType::Map(_, _) => downcast(array, visitor, |v, a| v.visit_map(a)),
+        DataType::Union(_, _) => downcast(array, visitor, |v, a| v.visit_union(a)),
+        DataType::Dictionary(k, _) => downcast_integer! {
+            k.as_ref() => (visit_dictionary_helper, array, visitor),
+            _ => visitor.visit_other(array),
+        },
+        DataType::RunEndEncoded(r, _) => downcast_run_end_index! {
+            r.data_type() => (visit_run_helper, array, visitor),
+            _ => visitor.visit_other(array),
+        },
+        _ => visitor.visit_other(array),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::{ListBuilder, StringBuilder};
+    use crate::types::{Int16Type, Int32Type};
+    use crate::{ArrayRef, Float64Array, Int32Array, StringArray};
+    use std::sync::Arc;
+
+    /// Describes the structure of an array
+    struct Describe;
+
+    impl ArrayVisitor for Describe {
+        type Output = String;
+
+        fn visit_null(&mut self, _: &NullArray) -> String {
+            "null".to_string()
+        }
+
+        fn visit_boolean(&mut self, _: &BooleanArray) -> String {
+            "boolean".to_string()
+        }
+
+        fn visit_primitive<T: ArrowPrimitiveType>(&mut self, _: &PrimitiveArray<T>) -> String {
+            format!("primitive({})", T::DATA_TYPE)
+        }
+
+        fn visit_bytes<T: ByteArrayType>(&mut self, _: &GenericByteArray<T>) -> String {
+            format!("bytes({})", T::DATA_TYPE)
+        }
+
+        fn visit_list<O: OffsetSizeTrait>(&mut self, array: &GenericListArray<O>) -> String {
+            format!("list({})", visit(array.values().as_ref(), self))
+        }
+
+        fn visit_struct(&mut self, array: &StructArray) -> String {
+            let children: Vec<_> = array
+                .columns()
+                .iter()
+                .map(|c| visit(c.as_ref(), self))
+                .collect();
+            format!("struct({})", children.join(", "))
+        }
+
+        fn visit_dictionary<K: ArrowDictionaryKeyType>(
+            &mut self,
+            array: &DictionaryArray<K>,
+        ) -> String {
+            format!(
+                "dictionary({}, {})",
+                K::DATA_TYPE,
+                visit(array.values().as_ref(), self)
+            )
+        }
+
+        fn visit_run<R: RunEndIndexType>(&mut self, array: &RunArray<R>) -> String {
+            format!(
+                "run({}, {})",
+                R::DATA_TYPE,
+                visit(array.values().as_ref(), self)
+            )
+        }
+
+        fn visit_other(&mut self, array: &dyn Array) -> String {
+            format!("other({})", array.data_type())
+        }
+    }
+
+    /// A user-defined array with the same data type as [`Int32Array`]
+    #[derive(Debug)]
+    struct Wrapper(Int32Array);
+
+    impl Array for Wrapper {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn to_data(&self) -> arrow_data::ArrayData {
+            self.0.to_data()
+        }
+
+        fn into_data(self) -> arrow_data::ArrayData {
+            self.0.into_data()
+        }
+
+        fn data_type(&self) -> &DataType {
+            self.0.data_type()
+        }
+
+        fn slice(&self, offset: usize, length: usize) -> ArrayRef {
+            Arc::new(Self(self.0.slice(offset, length)))
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        fn offset(&self) -> usize {
+            self.0.offset()
+        }
+
+        fn nulls(&self) -> Option<&arrow_buffer::NullBuffer> {
+            self.0.nulls()
+        }
+
+        fn get_buffer_memory_size(&self) -> usize {
+            self.0.get_buffer_memory_size()
+        }
+
+        fn get_array_memory_size(&self) -> usize {
+            self.0.get_array_memory_size()
+        }
+    }
+
+    #[test]
+    fn test_visit() {
+        let mut list = ListBuilder::new(StringBuilder::new());
+        list.append_value([Some("a")]);
+        let dict: DictionaryArray<Int16Type> = vec!["a", "b"].into_iter().collect();
+        let run = RunArray::<Int32Type>::try_new(
+            &Int32Array::from(vec![2]),
+            &Float64Array::from(vec![1.]),
+        )
+        .unwrap();
+
+        let array = StructArray::try_from(vec![
+            ("a", Arc::new(NullArray::new(1)) as ArrayRef),
+            ("b", Arc::new(BooleanArray::from(vec![true])) as _),
+            ("c", Arc::new(StringArray::from(vec!["a"])) as _),
+            ("d", Arc::new(list.finish()) as _),
+        ])
+        .unwrap();
+        assert_eq!(
+            visit(&array, &mut Describe),
+            "struct(null, boolean, bytes(Utf8), list(bytes(Utf8)))"
+        );
+        assert_eq!(
+            visit(&dict, &mut Describe),
+            "dictionary(Int16, bytes(Utf8))"
+        );
+        assert_eq!(visit(&run, &mut Describe), "run(Int32, primitive(Float64))");
+
+        let fixed = FixedSizeBinaryArray::try_from_iter([[1_u8, 2]].into_iter()).unwrap();
+        assert_eq!(visit(&fixed, &mut Describe), "other(FixedSizeBinary(2))");
+
+        let wrapper = Wrapper(Int32Array::from(vec![1]));
+        assert_eq!(visit(&wrapper, &mut Describe), "other(Int32)");
+        assert_eq!(visit(&wrapper.0, &mut Describe), "primitive(Int32)");
+    }
+}
@@ -16,16 +16,16 @@
 // under the License.
 
 use crate::bigint::div::div_rem;
+use core::cmp::Ordering;
+use core::num::ParseIntError;
+use core::ops::{BitAnd, BitOr, BitXor, Neg, Shl, Shr};
+use core::str::FromStr;
 use num::cast::AsPrimitive;
 use num::{BigInt, FromPrimitive, ToPrimitive};
-use std::cmp::Ordering;
-use std::num::ParseIntError;
-use std::ops::{BitAnd, BitOr, BitXor, Neg, Shl, Shr};
-use std::str::FromStr;
 
 mod div;
 
-/// An opaque error similar to [`std::num::ParseIntError`]
+/// An opaque error similar to [`core::num::ParseIntError`]
 #[derive(Debug)]
 pub struct ParseI256Error {}
 
@@ -35,11 +35,12 @@ impl From<ParseIntError> for ParseI256Error {
     }
 }
 
-impl std::fmt::Display for ParseI256Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for ParseI256Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Failed to parse as i256")
     }
 }
+#[cfg(feature = "std")]
 impl std::error::Error for ParseI256Error {}
 
 /// Error returned by i256::DivRem
@@ -58,14 +59,14 @@ pub struct i256 {
     high: i128,
 }
 
-impl std::fmt::Debug for i256 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for i256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self}")
     }
 }
 
-impl std::fmt::Display for i256 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for i256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", BigInt::from_signed_bytes_le(&self.to_le_bytes()))
     }
 }
@@ -640,7 +641,7 @@ fn mulx(a: u128, b: u128) -> (u128, u128) {
 
 macro_rules! derive_op {
     ($t:ident, $op:ident, $wrapping:ident, $checked:ident) => {
-        impl std::ops::$t for i256 {
+        impl core::ops::$t for i256 {
             type Output = i256;
 
             #[cfg(debug_assertions)]
@@ -654,7 +655,7 @@ macro_rules! derive_op {
             }
         }
 
-        impl<'a> std::ops::$t<i256> for &'a i256 {
+        impl<'a> core::ops::$t<i256> for &'a i256 {
             type Output = i256;
 
             fn $op(self, rhs: i256) -> Self::Output {
@@ -662,7 +663,7 @@ macro_rules! derive_op {
             }
         }
 
-        impl<'a> std::ops::$t<&'a i256> for i256 {
+        impl<'a> core::ops::$t<&'a i256> for i256 {
             type Output = i256;
 
             fn $op(self, rhs: &'a i256) -> Self::Output {
@@ -670,7 +671,7 @@ macro_rules! derive_op {
             }
         }
 
-        impl<'a, 'b> std::ops::$t<&'b i256> for &'a i256 {
+        impl<'a, 'b> core::ops::$t<&'b i256> for &'a i256 {
             type Output = i256;
 
             fn $op(self, rhs: &'b i256) -> Self::Output {
@@ -686,7 +687,7 @@ derive_op!(Mul, mul, wrapping_mul, checked_mul);
 derive_op!(Div, div, wrapping_div, checked_div);
 derive_op!(Rem, rem, wrapping_rem, checked_rem);
 
-impl std::ops::Neg for i256 {
+impl core::ops::Neg for i256 {
     type Output = i256;
 
     #[cfg(debug_assertions)]
@@ -838,9 +839,9 @@ impl ToPrimitive for i256 {
 #[cfg(all(test, not(miri)))] // llvm.x86.subborrow.64 not supported by MIRI
 mod tests {
     use super::*;
+    use core::ops::Neg;
     use num::{BigInt, FromPrimitive, Signed, ToPrimitive};
     use rand::{thread_rng, Rng};
-    use std::ops::Neg;
 
     #[test]
     fn test_signed_cmp() {
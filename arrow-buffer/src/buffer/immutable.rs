@@ -15,11 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::alloc::Layout;
-use std::fmt::Debug;
-use std::iter::FromIterator;
-use std::ptr::NonNull;
-use std::sync::Arc;
+use alloc_crate::alloc::Layout;
+use alloc_crate::sync::Arc;
+use alloc_crate::vec::Vec;
+use core::fmt::Debug;
+use core::iter::FromIterator;
+use core::ptr::NonNull;
 
 use crate::alloc::{Allocation, Deallocation, ALIGNMENT};
 use crate::util::bit_chunk_iterator::{BitChunks, UnalignedBitChunk};
@@ -81,7 +82,7 @@ impl Buffer {
     /// Initializes a [Buffer] from a slice of items.
     pub fn from_slice_ref<U: ArrowNativeType, T: AsRef<[U]>>(items: T) -> Self {
         let slice = items.as_ref();
-        let capacity = std::mem::size_of_val(slice);
+        let capacity = core::mem::size_of_val(slice);
         let mut buffer = MutableBuffer::with_capacity(capacity);
         buffer.extend_from_slice(slice);
         buffer.into()
@@ -127,6 +128,50 @@ impl Buffer {
         Buffer::build_with_arguments(ptr, len, Deallocation::Custom(owner, len))
     }
 
+    /// Creates a buffer over the memory of `owner`, without copying
+    ///
+    /// This provides a safe way to construct a [`Buffer`] over memory owned by
+    /// another runtime or allocator, for example a memory-mapped file or a buffer
+    /// exported by an embedding runtime. `owner` is kept alive as long as the
+    /// returned buffer or any slice of it, and its [`Drop`] implementation is
+    /// responsible for freeing the memory.
+    ///
+    /// Note: to be used as the values of an array, the memory must be aligned to
+    /// the element type, see [`ScalarBuffer`](crate::ScalarBuffer)
+    ///
+    /// ```
+    /// # use arrow_buffer::{Buffer, ScalarBuffer};
+    /// /// Memory allocated by a foreign runtime
+    /// struct Foreign(Vec<u64>);
+    ///
+    /// impl AsRef<[u8]> for Foreign {
+    ///     fn as_ref(&self) -> &[u8] {
+    ///         let len = self.0.len() * core::mem::size_of::<u64>();
+    ///         // SAFETY: the memory of the Vec is valid for `len` bytes
+    ///         unsafe { core::slice::from_raw_parts(self.0.as_ptr() as *const u8, len) }
+    ///     }
+    /// }
+    ///
+    /// impl Drop for Foreign {
+    ///     fn drop(&mut self) {
+    ///         // Release the memory back to the foreign runtime
+    ///     }
+    /// }
+    ///
+    /// let buffer = Buffer::from_owner(Foreign(vec![1, 2, 3]));
+    /// let values = ScalarBuffer::<u64>::from(buffer);
+    /// assert_eq!(&values, &[1, 2, 3]);
+    /// ```
+    pub fn from_owner<T: AsRef<[u8]> + Allocation + 'static>(owner: T) -> Self {
+        let owner = Arc::new(owner);
+        let data = owner.as_ref().as_ref();
+        let ptr = NonNull::from(data).cast();
+        let len = data.len();
+        // SAFETY: `data` borrows from `owner`, which cannot be mutated once moved
+        // into the `Arc`, and is kept alive by the returned buffer
+        unsafe { Self::from_custom_allocation(ptr, len, owner) }
+    }
+
     /// Auxiliary method to create a new Buffer
     unsafe fn build_with_arguments(
         ptr: NonNull<u8>,
@@ -163,7 +208,7 @@ impl Buffer {
 
     /// Returns the byte slice stored in this buffer
     pub fn as_slice(&self) -> &[u8] {
-        unsafe { std::slice::from_raw_parts(self.ptr, self.length) }
+        unsafe { core::slice::from_raw_parts(self.ptr, self.length) }
     }
 
     pub(crate) fn deallocation(&self) -> &Deallocation {
@@ -300,7 +345,7 @@ impl Buffer {
             return Err(self); // Data is offset
         }
 
-        let v_capacity = layout.size() / std::mem::size_of::<T>();
+        let v_capacity = layout.size() / core::mem::size_of::<T>();
         match Layout::array::<T>(v_capacity) {
             Ok(expected) if layout == &expected => {}
             _ => return Err(self), // Incorrect layout
@@ -308,12 +353,12 @@ impl Buffer {
 
         let length = self.length;
         let ptr = self.ptr;
-        let v_len = self.length / std::mem::size_of::<T>();
+        let v_len = self.length / core::mem::size_of::<T>();
 
         Arc::try_unwrap(self.data)
             .map(|bytes| unsafe {
                 let ptr = bytes.ptr().as_ptr() as _;
-                std::mem::forget(bytes);
+                core::mem::forget(bytes);
                 // Safety
                 // Verified that bytes layout matches that of Vec
                 Vec::from_raw_parts(ptr, v_len, v_capacity)
@@ -357,11 +402,11 @@ impl FromIterator<bool> for Buffer {
     }
 }
 
-impl std::ops::Deref for Buffer {
+impl core::ops::Deref for Buffer {
     type Target = [u8];
 
     fn deref(&self) -> &[u8] {
-        unsafe { std::slice::from_raw_parts(self.as_ptr(), self.len()) }
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len()) }
     }
 }
 
@@ -424,7 +469,7 @@ impl Buffer {
 impl<T: ArrowNativeType> FromIterator<T> for Buffer {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut iterator = iter.into_iter();
-        let size = std::mem::size_of::<T>();
+        let size = core::mem::size_of::<T>();
 
         // first iteration, which will likely reserve sufficient space for the buffer.
         let mut buffer = match iterator.next() {
@@ -433,7 +478,7 @@ impl<T: ArrowNativeType> FromIterator<T> for Buffer {
                 let (lower, _) = iterator.size_hint();
                 let mut buffer = MutableBuffer::new(lower.saturating_add(1) * size);
                 unsafe {
-                    std::ptr::write(buffer.as_mut_ptr() as *mut T, element);
+                    core::ptr::write(buffer.as_mut_ptr() as *mut T, element);
                     buffer.set_len(size);
                 }
                 buffer
@@ -448,7 +493,7 @@ impl<T: ArrowNativeType> FromIterator<T> for Buffer {
 #[cfg(test)]
 mod tests {
     use crate::i256;
-    use std::panic::{RefUnwindSafe, UnwindSafe};
+    use core::panic::{RefUnwindSafe, UnwindSafe};
     use std::thread;
 
     use super::*;
@@ -678,7 +723,7 @@ mod tests {
         let buffer = unsafe {
             Buffer::from_custom_allocation(
                 NonNull::new_unchecked(vector.as_mut_ptr() as *mut u8),
-                vector.len() * std::mem::size_of::<i32>(),
+                vector.len() * core::mem::size_of::<i32>(),
                 Arc::new(vector),
             )
         };
@@ -686,7 +731,7 @@ mod tests {
         let slice = buffer.typed_data::<i32>();
         assert_eq!(slice, &[1, 2, 3, 4, 5]);
 
-        let buffer = buffer.slice(std::mem::size_of::<i32>());
+        let buffer = buffer.slice(core::mem::size_of::<i32>());
 
         let slice = buffer.typed_data::<i32>();
         assert_eq!(slice, &[2, 3, 4, 5]);
@@ -820,4 +865,37 @@ mod tests {
         let b = b.into_vec::<u32>().unwrap();
         assert_eq!(b, &[1, 3, 5]);
     }
+
+    #[test]
+    fn test_from_owner() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        struct Owner<'a>(Vec<u8>, &'a AtomicUsize);
+        impl AsRef<[u8]> for Owner<'_> {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl Drop for Owner<'_> {
+            fn drop(&mut self) {
+                self.1.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+        let buffer = Buffer::from_owner(Owner(vec![1, 2, 3, 4], &DROPPED));
+        assert_eq!(buffer.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(buffer.capacity(), 4);
+
+        // Slices keep the owner alive
+        let sliced = buffer.slice(2);
+        drop(buffer);
+        assert_eq!(DROPPED.load(Ordering::SeqCst), 0);
+        assert_eq!(sliced.as_slice(), &[3, 4]);
+        drop(sliced);
+        assert_eq!(DROPPED.load(Ordering::SeqCst), 1);
+
+        let empty = Buffer::from_owner(Vec::<u8>::new());
+        assert!(empty.is_empty());
+    }
 }
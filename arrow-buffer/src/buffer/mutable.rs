@@ -15,9 +15,10 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::alloc::{handle_alloc_error, Layout};
-use std::mem;
-use std::ptr::NonNull;
+use alloc_crate::alloc::{handle_alloc_error, Layout};
+use alloc_crate::vec::Vec;
+use core::mem;
+use core::ptr::NonNull;
 
 use crate::alloc::{Deallocation, ALIGNMENT};
 use crate::{
@@ -75,7 +76,7 @@ impl MutableBuffer {
             0 => dangling_ptr(),
             _ => {
                 // Safety: Verified size != 0
-                let raw_ptr = unsafe { std::alloc::alloc(layout) };
+                let raw_ptr = unsafe { alloc_crate::alloc::alloc(layout) };
                 NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout))
             }
         };
@@ -103,7 +104,7 @@ impl MutableBuffer {
             0 => dangling_ptr(),
             _ => {
                 // Safety: Verified size != 0
-                let raw_ptr = unsafe { std::alloc::alloc_zeroed(layout) };
+                let raw_ptr = unsafe { alloc_crate::alloc::alloc_zeroed(layout) };
                 NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout))
             }
         };
@@ -148,7 +149,7 @@ impl MutableBuffer {
         assert!(end <= self.layout.size());
         let v = if val { 255 } else { 0 };
         unsafe {
-            std::ptr::write_bytes(self.data.as_ptr(), v, end);
+            core::ptr::write_bytes(self.data.as_ptr(), v, end);
             self.len = end;
         }
         self
@@ -169,7 +170,7 @@ impl MutableBuffer {
 
         // Safety: `self.data[start..][..count]` is in-bounds and well-aligned for `u8`
         unsafe {
-            std::ptr::write_bytes(self.data.as_ptr().add(start), 0, count);
+            core::ptr::write_bytes(self.data.as_ptr().add(start), 0, count);
         }
     }
 
@@ -191,7 +192,7 @@ impl MutableBuffer {
         let required_cap = self.len + additional;
         if required_cap > self.layout.size() {
             let new_capacity = bit_util::round_upto_multiple_of_64(required_cap);
-            let new_capacity = std::cmp::max(new_capacity, self.layout.size() * 2);
+            let new_capacity = core::cmp::max(new_capacity, self.layout.size() * 2);
             self.reallocate(new_capacity)
         }
     }
@@ -202,7 +203,7 @@ impl MutableBuffer {
         if new_layout.size() == 0 {
             if self.layout.size() != 0 {
                 // Safety: data was allocated with layout
-                unsafe { std::alloc::dealloc(self.as_mut_ptr(), self.layout) };
+                unsafe { alloc_crate::alloc::dealloc(self.as_mut_ptr(), self.layout) };
                 self.layout = new_layout
             }
             return;
@@ -210,9 +211,9 @@ impl MutableBuffer {
 
         let data = match self.layout.size() {
             // Safety: new_layout is not empty
-            0 => unsafe { std::alloc::alloc(new_layout) },
+            0 => unsafe { alloc_crate::alloc::alloc(new_layout) },
             // Safety: verified new layout is valid and not empty
-            _ => unsafe { std::alloc::realloc(self.as_mut_ptr(), self.layout, capacity) },
+            _ => unsafe { alloc_crate::alloc::realloc(self.as_mut_ptr(), self.layout, capacity) },
         };
         self.data = NonNull::new(data).unwrap_or_else(|| handle_alloc_error(new_layout));
         self.layout = new_layout;
@@ -335,7 +336,7 @@ impl MutableBuffer {
     #[inline]
     pub(super) fn into_buffer(self) -> Buffer {
         let bytes = unsafe { Bytes::new(self.data, self.len, Deallocation::Standard(self.layout)) };
-        std::mem::forget(self);
+        core::mem::forget(self);
         Buffer::from_bytes(bytes)
     }
 
@@ -387,7 +388,7 @@ impl MutableBuffer {
             // which is correct for all ArrowNativeType implementations.
             let src = items.as_ptr() as *const u8;
             let dst = self.data.as_ptr().add(self.len);
-            std::ptr::copy_nonoverlapping(src, dst, additional)
+            core::ptr::copy_nonoverlapping(src, dst, additional)
         }
         self.len += additional;
     }
@@ -402,12 +403,12 @@ impl MutableBuffer {
     /// ```
     #[inline]
     pub fn push<T: ToByteSlice>(&mut self, item: T) {
-        let additional = std::mem::size_of::<T>();
+        let additional = core::mem::size_of::<T>();
         self.reserve(additional);
         unsafe {
             let src = item.to_byte_slice().as_ptr();
             let dst = self.data.as_ptr().add(self.len);
-            std::ptr::copy_nonoverlapping(src, dst, additional);
+            core::ptr::copy_nonoverlapping(src, dst, additional);
         }
         self.len += additional;
     }
@@ -417,10 +418,10 @@ impl MutableBuffer {
     /// Caller must ensure that the capacity()-len()>=`size_of<T>`()
     #[inline]
     pub unsafe fn push_unchecked<T: ToByteSlice>(&mut self, item: T) {
-        let additional = std::mem::size_of::<T>();
+        let additional = core::mem::size_of::<T>();
         let src = item.to_byte_slice().as_ptr();
         let dst = self.data.as_ptr().add(self.len);
-        std::ptr::copy_nonoverlapping(src, dst, additional);
+        core::ptr::copy_nonoverlapping(src, dst, additional);
         self.len += additional;
     }
 
@@ -512,7 +513,7 @@ impl MutableBuffer {
         &mut self,
         mut iterator: I,
     ) {
-        let item_size = std::mem::size_of::<T>();
+        let item_size = core::mem::size_of::<T>();
         let (lower, _) = iterator.size_hint();
         let additional = lower * item_size;
         self.reserve(additional);
@@ -526,7 +527,7 @@ impl MutableBuffer {
             if let Some(item) = iterator.next() {
                 unsafe {
                     let src = item.to_byte_slice().as_ptr();
-                    std::ptr::copy_nonoverlapping(src, dst, item_size);
+                    core::ptr::copy_nonoverlapping(src, dst, item_size);
                     dst = dst.add(item_size);
                 }
                 len.local_len += item_size;
@@ -560,7 +561,7 @@ impl MutableBuffer {
     pub unsafe fn from_trusted_len_iter<T: ArrowNativeType, I: Iterator<Item = T>>(
         iterator: I,
     ) -> Self {
-        let item_size = std::mem::size_of::<T>();
+        let item_size = core::mem::size_of::<T>();
         let (_, upper) = iterator.size_hint();
         let upper = upper.expect("from_trusted_len_iter requires an upper limit");
         let len = upper * item_size;
@@ -571,7 +572,7 @@ impl MutableBuffer {
         for item in iterator {
             // note how there is no reserve here (compared with `extend_from_iter`)
             let src = item.to_byte_slice().as_ptr();
-            std::ptr::copy_nonoverlapping(src, dst, item_size);
+            core::ptr::copy_nonoverlapping(src, dst, item_size);
             dst = dst.add(item_size);
         }
         assert_eq!(
@@ -622,7 +623,7 @@ impl MutableBuffer {
     >(
         iterator: I,
     ) -> Result<Self, E> {
-        let item_size = std::mem::size_of::<T>();
+        let item_size = core::mem::size_of::<T>();
         let (_, upper) = iterator.size_hint();
         let upper = upper.expect("try_from_trusted_len_iter requires an upper limit");
         let len = upper * item_size;
@@ -634,7 +635,7 @@ impl MutableBuffer {
             let item = item?;
             // note how there is no reserve here (compared with `extend_from_iter`)
             let src = item.to_byte_slice().as_ptr();
-            std::ptr::copy_nonoverlapping(src, dst, item_size);
+            core::ptr::copy_nonoverlapping(src, dst, item_size);
             dst = dst.add(item_size);
         }
         // try_from_trusted_len_iter is instantiated a lot, so we extract part of it into a less
@@ -658,17 +659,17 @@ impl Default for MutableBuffer {
     }
 }
 
-impl std::ops::Deref for MutableBuffer {
+impl core::ops::Deref for MutableBuffer {
     type Target = [u8];
 
     fn deref(&self) -> &[u8] {
-        unsafe { std::slice::from_raw_parts(self.as_ptr(), self.len) }
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len) }
     }
 }
 
-impl std::ops::DerefMut for MutableBuffer {
+impl core::ops::DerefMut for MutableBuffer {
     fn deref_mut(&mut self) -> &mut [u8] {
-        unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
     }
 }
 
@@ -676,7 +677,7 @@ impl Drop for MutableBuffer {
     fn drop(&mut self) {
         if self.layout.size() != 0 {
             // Safety: data was allocated with standard allocator with given layout
-            unsafe { std::alloc::dealloc(self.data.as_ptr() as _, self.layout) };
+            unsafe { alloc_crate::alloc::dealloc(self.data.as_ptr() as _, self.layout) };
         }
     }
 }
@@ -719,7 +720,7 @@ impl Drop for SetLenOnDrop<'_> {
 }
 
 /// Creating a `MutableBuffer` instance by setting bits according to the boolean values
-impl std::iter::FromIterator<bool> for MutableBuffer {
+impl core::iter::FromIterator<bool> for MutableBuffer {
     fn from_iter<I>(iter: I) -> Self
     where
         I: IntoIterator<Item = bool>,
@@ -773,7 +774,7 @@ impl std::iter::FromIterator<bool> for MutableBuffer {
     }
 }
 
-impl<T: ArrowNativeType> std::iter::FromIterator<T> for MutableBuffer {
+impl<T: ArrowNativeType> core::iter::FromIterator<T> for MutableBuffer {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let mut buffer = Self::default();
         buffer.extend_from_iter(iter.into_iter());
@@ -21,9 +21,12 @@ mod boolean;
 pub use boolean::*;
 mod null;
 pub use null::*;
+mod offset;
+pub use offset::*;
 
 use crate::{ArrowNativeType, Buffer, MutableBuffer};
-use std::{iter, marker::PhantomData};
+use alloc_crate::vec::Vec;
+use core::{iter, marker::PhantomData};
 
 /// Builder for creating a [Buffer] object.
 ///
@@ -74,7 +77,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
     /// ```
     #[inline]
     pub fn new(capacity: usize) -> Self {
-        let buffer = MutableBuffer::new(capacity * std::mem::size_of::<T>());
+        let buffer = MutableBuffer::new(capacity * core::mem::size_of::<T>());
 
         Self {
             buffer,
@@ -88,7 +91,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
         let buffer_len = buffer.len();
         Self {
             buffer,
-            len: buffer_len / std::mem::size_of::<T>(),
+            len: buffer_len / core::mem::size_of::<T>(),
             _marker: PhantomData,
         }
     }
@@ -132,7 +135,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
     /// functions.
     pub fn capacity(&self) -> usize {
         let byte_capacity = self.buffer.capacity();
-        byte_capacity / std::mem::size_of::<T>()
+        byte_capacity / core::mem::size_of::<T>()
     }
 
     /// Increases the number of elements in the internal buffer by `n`
@@ -154,7 +157,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
     /// ```
     #[inline]
     pub fn advance(&mut self, i: usize) {
-        self.buffer.extend_zeros(i * std::mem::size_of::<T>());
+        self.buffer.extend_zeros(i * core::mem::size_of::<T>());
         self.len += i;
     }
 
@@ -172,7 +175,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
     /// ```
     #[inline]
     pub fn reserve(&mut self, n: usize) {
-        self.buffer.reserve(n * std::mem::size_of::<T>());
+        self.buffer.reserve(n * core::mem::size_of::<T>());
     }
 
     /// Appends a value of type `T` into the builder,
@@ -228,7 +231,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
     /// assert_eq!(builder.as_slice(), &[0, 0, 0])
     #[inline]
     pub fn append_n_zeroed(&mut self, n: usize) {
-        self.buffer.extend_zeros(n * std::mem::size_of::<T>());
+        self.buffer.extend_zeros(n * core::mem::size_of::<T>());
         self.len += n;
     }
 
@@ -268,7 +271,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
         // - MutableBuffer is aligned and initialized for len elements of T
         // - MutableBuffer corresponds to a single allocation
         // - MutableBuffer does not support modification whilst active immutable borrows
-        unsafe { std::slice::from_raw_parts(self.buffer.as_ptr() as _, self.len) }
+        unsafe { core::slice::from_raw_parts(self.buffer.as_ptr() as _, self.len) }
     }
 
     /// View the contents of this buffer as a mutable slice
@@ -293,7 +296,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
         // - MutableBuffer is aligned and initialized for len elements of T
         // - MutableBuffer corresponds to a single allocation
         // - MutableBuffer does not support modification whilst active immutable borrows
-        unsafe { std::slice::from_raw_parts_mut(self.buffer.as_mut_ptr() as _, self.len) }
+        unsafe { core::slice::from_raw_parts_mut(self.buffer.as_mut_ptr() as _, self.len) }
     }
 
     /// Shorten this BufferBuilder to `len` items
@@ -318,7 +321,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
     /// ```
     #[inline]
     pub fn truncate(&mut self, len: usize) {
-        self.buffer.truncate(len * std::mem::size_of::<T>());
+        self.buffer.truncate(len * core::mem::size_of::<T>());
         self.len = len;
     }
 
@@ -352,7 +355,7 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
     /// ```
     #[inline]
     pub fn finish(&mut self) -> Buffer {
-        let buf = std::mem::take(&mut self.buffer);
+        let buf = core::mem::take(&mut self.buffer);
         self.len = 0;
         buf.into()
     }
@@ -389,7 +392,7 @@ impl<T: ArrowNativeType> FromIterator<T> for BufferBuilder<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::mem;
+    use core::mem;
 
     #[test]
     fn default() {
@@ -125,6 +125,18 @@ impl NullBufferBuilder {
         }
     }
 
+    /// Appends the validity of a [`NullBuffer`] into the builder
+    pub fn append_buffer(&mut self, buffer: &NullBuffer) {
+        if buffer.null_count() > 0 {
+            self.materialize_if_needed();
+        }
+        if let Some(buf) = self.bitmap_builder.as_mut() {
+            buf.append_buffer(buffer.inner())
+        } else {
+            self.len += buffer.len();
+        }
+    }
+
     /// Builds the null buffer and resets the builder.
     /// Returns `None` if the builder only contains `true`s.
     pub fn finish(&mut self) -> Option<NullBuffer> {
@@ -234,4 +246,16 @@ mod tests {
         let buf = builder.finish().unwrap();
         assert_eq!(&[0b1011_u8], buf.validity());
     }
+
+    #[test]
+    fn test_null_buffer_builder_append_buffer() {
+        let mut builder = NullBufferBuilder::new(0);
+        builder.append_buffer(&NullBuffer::new_valid(2));
+        assert!(builder.as_slice().is_none());
+        builder.append_buffer(&NullBuffer::from(vec![false, true, false]));
+        assert_eq!(5, builder.len());
+
+        let buf = builder.finish().unwrap();
+        assert_eq!(&[0b01011_u8], buf.validity());
+    }
 }
@@ -0,0 +1,194 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use alloc_crate::vec::Vec;
+use core::ops::Deref;
+
+use crate::{ArrowNativeType, OffsetBuffer};
+
+/// Builder of [`OffsetBuffer`], accumulating the lengths of slices into
+/// monotonically increasing offsets
+///
+/// ```
+/// # use arrow_buffer::OffsetBufferBuilder;
+/// let mut builder = OffsetBufferBuilder::<i32>::new(3);
+/// builder.push_length(1);
+/// builder.push_length(3);
+/// assert_eq!(builder.checked_push_length(usize::MAX), None);
+/// assert_eq!(builder.checked_push_length(5), Some(9));
+///
+/// let offsets = builder.finish();
+/// assert_eq!(offsets.as_ref(), &[0, 1, 4, 9]);
+/// ```
+#[derive(Debug)]
+pub struct OffsetBufferBuilder<O: ArrowNativeType> {
+    offsets: Vec<O>,
+    last_offset: O,
+}
+
+impl<O: ArrowNativeType> OffsetBufferBuilder<O> {
+    /// Create a new builder with space for `capacity` lengths
+    pub fn new(capacity: usize) -> Self {
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(O::usize_as(0));
+        Self {
+            offsets,
+            last_offset: O::usize_as(0),
+        }
+    }
+
+    /// Push a slice of `length` onto the builder
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting offset is not representable by `O`
+    #[inline]
+    pub fn push_length(&mut self, length: usize) {
+        self.checked_push_length(length).expect("offset overflow");
+    }
+
+    /// Push a slice of `length` onto the builder, returning the new last offset, or
+    /// `None` without modifying the builder if it is not representable by `O`
+    #[inline]
+    pub fn checked_push_length(&mut self, length: usize) -> Option<O> {
+        let offset = self
+            .last_offset
+            .as_usize()
+            .checked_add(length)
+            .and_then(O::from_usize)?;
+        self.offsets.push(offset);
+        self.last_offset = offset;
+        Some(offset)
+    }
+
+    /// Reserve space for at least `additional` further lengths
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+    }
+
+    /// Returns the number of lengths pushed onto the builder
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns true if no lengths have been pushed onto the builder
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the last offset, i.e. the total of the lengths pushed so far
+    #[inline]
+    pub fn last_offset(&self) -> O {
+        self.last_offset
+    }
+
+    /// Builds the [`OffsetBuffer`]
+    pub fn finish(self) -> OffsetBuffer<O> {
+        // SAFETY: offsets are non-empty, start at zero, and are monotonically increasing
+        unsafe { OffsetBuffer::new_unchecked(self.offsets.into()) }
+    }
+
+    /// Builds the [`OffsetBuffer`] without consuming the builder
+    pub fn finish_cloned(&self) -> OffsetBuffer<O> {
+        // SAFETY: offsets are non-empty, start at zero, and are monotonically increasing
+        unsafe { OffsetBuffer::new_unchecked(self.offsets.clone().into()) }
+    }
+}
+
+impl<O: ArrowNativeType> Deref for OffsetBufferBuilder<O> {
+    type Target = [O];
+
+    fn deref(&self) -> &Self::Target {
+        &self.offsets
+    }
+}
+
+/// Computes the [`OffsetBuffer`] of the slices with the given `lengths`, returning
+/// `None` if the total length is not representable by `O`
+///
+/// ```
+/// # use arrow_buffer::lengths_to_offsets;
+/// let offsets = lengths_to_offsets::<i32>([2, 0, 3]).unwrap();
+/// assert_eq!(offsets.as_ref(), &[0, 2, 2, 5]);
+///
+/// assert!(lengths_to_offsets::<i32>([i32::MAX as usize, 1]).is_none());
+/// assert!(lengths_to_offsets::<i64>([i32::MAX as usize, 1]).is_some());
+/// ```
+pub fn lengths_to_offsets<O: ArrowNativeType>(
+    lengths: impl IntoIterator<Item = usize>,
+) -> Option<OffsetBuffer<O>> {
+    let lengths = lengths.into_iter();
+    let mut builder = OffsetBufferBuilder::new(lengths.size_hint().0);
+    for length in lengths {
+        builder.checked_push_length(length)?;
+    }
+    Some(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_buffer_builder() {
+        let mut builder = OffsetBufferBuilder::<i32>::new(0);
+        assert!(builder.is_empty());
+        assert_eq!(builder.finish_cloned().as_ref(), &[0]);
+
+        builder.push_length(3);
+        builder.push_length(0);
+        builder.reserve(2);
+        builder.push_length(2);
+        assert_eq!(builder.len(), 3);
+        assert_eq!(builder.last_offset(), 5);
+        assert_eq!(&*builder, &[0, 3, 3, 5]);
+
+        // Overflow leaves the builder unchanged
+        let remaining = (i32::MAX - 5) as usize;
+        assert_eq!(builder.checked_push_length(remaining + 1), None);
+        assert_eq!(builder.checked_push_length(usize::MAX), None);
+        assert_eq!(builder.len(), 3);
+        assert_eq!(builder.checked_push_length(remaining), Some(i32::MAX));
+
+        let offsets = builder.finish();
+        assert_eq!(offsets.as_ref(), &[0, 3, 3, 5, i32::MAX]);
+    }
+
+    #[test]
+    #[should_panic(expected = "offset overflow")]
+    fn test_offset_buffer_builder_overflow() {
+        let mut builder = OffsetBufferBuilder::<i32>::new(2);
+        builder.push_length(i32::MAX as usize);
+        builder.push_length(1);
+    }
+
+    #[test]
+    fn test_lengths_to_offsets() {
+        let offsets = lengths_to_offsets::<i64>([]).unwrap();
+        assert_eq!(offsets.as_ref(), &[0]);
+
+        let offsets = lengths_to_offsets::<i64>([1, 2, 3]).unwrap();
+        assert_eq!(offsets.as_ref(), &[0, 1, 3, 6]);
+
+        let offsets = lengths_to_offsets::<i64>([i32::MAX as usize; 2]).unwrap();
+        assert_eq!(offsets.last(), Some(&(2 * i32::MAX as i64)));
+        assert!(lengths_to_offsets::<i32>([i32::MAX as usize; 2]).is_none());
+    }
+}
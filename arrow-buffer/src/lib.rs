@@ -16,6 +16,16 @@
 // under the License.
 
 //! Low-level buffer abstractions for [Apache Arrow Rust](https://docs.rs/arrow)
+//!
+//! # Crate Features
+//!
+//! * `std` (default): depend on the standard library. Disabling this allows the
+//!   crate to be used in `no_std` environments that provide a global allocator
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+// Renamed to not conflict with the `alloc` module
+extern crate alloc as alloc_crate;
 
 pub mod alloc;
 pub mod buffer;
@@ -16,9 +16,22 @@
 // under the License.
 
 //! Types for iterating over bitmasks in 64-bit chunks
+//!
+//! Kernels operating on validity or boolean masks are typically much faster when they
+//! process 64 bits at a time, rather than testing each bit individually. This module
+//! provides two ways to do so:
+//!
+//! * [`BitChunks`] yields 64-bit chunks starting at an arbitrary bit offset, followed
+//!   by a remainder of fewer than 64 bits, making it straightforward to zip the chunks
+//!   of several masks together
+//! * [`UnalignedBitChunk`] yields 64-bit chunks read directly from aligned memory,
+//!   with a padded prefix and suffix, avoiding the shifts needed by [`BitChunks`] for
+//!   offsets that are not a multiple of 8
+//!
+//! Both can be iterated in reverse, for instance to find the last set bit
 
 use crate::util::bit_util::ceil;
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 /// Iterates over an arbitrarily aligned byte buffer
 ///
@@ -27,6 +40,27 @@ use std::fmt::Debug;
 ///
 /// This is unlike [`BitChunkIterator`] which only exposes a trailing u64,
 /// and consequently has to perform more work for each read
+///
+/// The prefix contains [`Self::lead_padding`] zero bits before the first bit of the
+/// mask, and the last u64 contains [`Self::trailing_padding`] zero bits after the last
+/// bit of the mask, so bit `i` of the mask is bit `i + lead_padding` of the chunks
+///
+/// ```
+/// # use arrow_buffer::bit_chunk_iterator::UnalignedBitChunk;
+/// let bytes = [0b1111_0000_u8; 32];
+/// // 200 bits starting at bit offset 4, all of which are set
+/// let chunk = UnalignedBitChunk::new(&bytes, 4, 200);
+/// assert_eq!(chunk.count_ones(), 100);
+///
+/// let chunks: Vec<u64> = chunk.iter().collect();
+/// let padded = chunk.lead_padding() + 200 + chunk.trailing_padding();
+/// assert_eq!(chunks.len() * 64, padded);
+///
+/// // Find the index of the last set bit by iterating in reverse
+/// let idx = chunks.len() - 1 - chunk.iter().rev().position(|c| c != 0).unwrap();
+/// let last_set = idx * 64 + 63 - chunks[idx].leading_zeros() as usize - chunk.lead_padding();
+/// assert_eq!(last_set, 195);
+/// ```
 #[derive(Debug)]
 pub struct UnalignedBitChunk<'a> {
     lead_padding: usize,
@@ -131,26 +165,36 @@ impl<'a> UnalignedBitChunk<'a> {
         }
     }
 
+    /// Returns the number of zero bits before the first bit of the mask in the first chunk
     pub fn lead_padding(&self) -> usize {
         self.lead_padding
     }
 
+    /// Returns the number of zero bits after the last bit of the mask in the last chunk
     pub fn trailing_padding(&self) -> usize {
         self.trailing_padding
     }
 
+    /// Returns the chunk before [`Self::chunks`], if any, containing
+    /// [`Self::lead_padding`] zero bits followed by the first bits of the mask
     pub fn prefix(&self) -> Option<u64> {
         self.prefix
     }
 
+    /// Returns the chunk after [`Self::chunks`], if any, containing the last bits
+    /// of the mask followed by [`Self::trailing_padding`] zero bits
     pub fn suffix(&self) -> Option<u64> {
         self.suffix
     }
 
+    /// Returns the aligned chunks between [`Self::prefix`] and [`Self::suffix`]
     pub fn chunks(&self) -> &'a [u64] {
         self.chunks
     }
 
+    /// Returns an iterator over the prefix, chunks and suffix, in that order
+    ///
+    /// The returned iterator is double-ended, and so can be reversed
     pub fn iter(&self) -> UnalignedBitChunkIterator<'a> {
         self.prefix
             .into_iter()
@@ -164,9 +208,10 @@ impl<'a> UnalignedBitChunk<'a> {
     }
 }
 
-pub type UnalignedBitChunkIterator<'a> = std::iter::Chain<
-    std::iter::Chain<std::option::IntoIter<u64>, std::iter::Cloned<std::slice::Iter<'a, u64>>>,
-    std::option::IntoIter<u64>,
+/// Iterator over the chunks of an [`UnalignedBitChunk`]
+pub type UnalignedBitChunkIterator<'a> = core::iter::Chain<
+    core::iter::Chain<core::option::IntoIter<u64>, core::iter::Cloned<core::slice::Iter<'a, u64>>>,
+    core::option::IntoIter<u64>,
 >;
 
 #[inline]
@@ -200,6 +245,31 @@ fn compute_suffix_mask(len: usize, lead_padding: usize) -> (u64, usize) {
 /// Yields an iterator of u64, and a remainder. The first byte in the buffer
 /// will be the least significant byte in output u64
 ///
+/// ```
+/// # use arrow_buffer::bit_chunk_iterator::BitChunks;
+/// let left = [0b1010_1010_u8; 9];
+/// let right = [0b1100_1100_u8; 9];
+///
+/// // Count the bits set in both masks, starting at bit offset 3
+/// let left = BitChunks::new(&left, 3, 66);
+/// let right = BitChunks::new(&right, 3, 66);
+/// assert_eq!(left.chunk_len(), 1);
+/// assert_eq!(left.remainder_len(), 2);
+///
+/// let chunks: u32 = left
+///     .iter()
+///     .zip(right.iter())
+///     .map(|(l, r)| (l & r).count_ones())
+///     .sum();
+/// let remainder = (left.remainder_bits() & right.remainder_bits()).count_ones();
+/// assert_eq!(chunks + remainder, 17);
+///
+/// // Chunks can also be visited in reverse order
+/// let forward: Vec<u64> = left.iter().collect();
+/// let mut reverse: Vec<u64> = left.iter().rev().collect();
+/// reverse.reverse();
+/// assert_eq!(forward, reverse);
+/// ```
 #[derive(Debug)]
 pub struct BitChunks<'a> {
     buffer: &'a [u8],
@@ -212,6 +282,11 @@ pub struct BitChunks<'a> {
 }
 
 impl<'a> BitChunks<'a> {
+    /// Create a new [`BitChunks`] over the `len` bits of `buffer` starting at bit `offset`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` contains fewer than `offset + len` bits
     pub fn new(buffer: &'a [u8], offset: usize, len: usize) -> Self {
         assert!(ceil(offset + len, 8) <= buffer.len() * 8);
 
@@ -232,11 +307,16 @@ impl<'a> BitChunks<'a> {
     }
 }
 
+/// Iterator over the complete 64-bit chunks of a [`BitChunks`]
+///
+/// This is double-ended, and so can be reversed
 #[derive(Debug)]
 pub struct BitChunkIterator<'a> {
     buffer: &'a [u8],
     bit_offset: usize,
+    /// exclusive end of the chunks yet to be returned
     chunk_len: usize,
+    /// start of the chunks yet to be returned
     index: usize,
 }
 
@@ -253,7 +333,15 @@ impl<'a> BitChunks<'a> {
         self.chunk_len
     }
 
-    /// Returns the bitmask of remaining bits
+    /// Returns a mask with the lowest [`Self::remainder_len`] bits set, i.e. those bits
+    /// of [`Self::remainder_bits`] that are part of the mask
+    #[inline]
+    pub fn remainder_mask(&self) -> u64 {
+        (1 << self.remainder_len) - 1
+    }
+
+    /// Returns the bitmask of remaining bits, with the bits above
+    /// [`Self::remainder_len`] set to zero
     #[inline]
     pub fn remainder_bits(&self) -> u64 {
         let bit_len = self.remainder_len;
@@ -268,12 +356,12 @@ impl<'a> BitChunks<'a> {
             let base = unsafe {
                 self.buffer
                     .as_ptr()
-                    .add(self.chunk_len * std::mem::size_of::<u64>())
+                    .add(self.chunk_len * core::mem::size_of::<u64>())
             };
 
-            let mut bits = unsafe { std::ptr::read(base) } as u64 >> bit_offset;
+            let mut bits = unsafe { core::ptr::read(base) } as u64 >> bit_offset;
             for i in 1..byte_len {
-                let byte = unsafe { std::ptr::read(base.add(i)) };
+                let byte = unsafe { core::ptr::read(base.add(i)) };
                 bits |= (byte as u64) << (i * 8 - bit_offset);
             }
 
@@ -293,9 +381,11 @@ impl<'a> BitChunks<'a> {
     }
 
     /// Returns an iterator over chunks of 64 bits, with the remaining bits zero padded to 64-bits
+    ///
+    /// Note: this always yields a final chunk for the remainder, even if it is empty
     #[inline]
-    pub fn iter_padded(&self) -> impl Iterator<Item = u64> + 'a {
-        self.iter().chain(std::iter::once(self.remainder_bits()))
+    pub fn iter_padded(&self) -> impl DoubleEndedIterator<Item = u64> + 'a {
+        self.iter().chain(core::iter::once(self.remainder_bits()))
     }
 }
 
@@ -308,40 +398,44 @@ impl<'a> IntoIterator for BitChunks<'a> {
     }
 }
 
-impl Iterator for BitChunkIterator<'_> {
-    type Item = u64;
-
+impl BitChunkIterator<'_> {
+    /// Reads the chunk at `index`, which must be less than the number of chunks
     #[inline]
-    fn next(&mut self) -> Option<u64> {
-        let index = self.index;
-        if index >= self.chunk_len {
-            return None;
-        }
-
+    fn read_chunk(&self, index: usize) -> u64 {
         // cast to *const u64 should be fine since we are using read_unaligned below
         #[allow(clippy::cast_ptr_alignment)]
         let raw_data = self.buffer.as_ptr() as *const u64;
 
         // bit-packed buffers are stored starting with the least-significant byte first
         // so when reading as u64 on a big-endian machine, the bytes need to be swapped
-        let current = unsafe { std::ptr::read_unaligned(raw_data.add(index)).to_le() };
+        let current = unsafe { core::ptr::read_unaligned(raw_data.add(index)).to_le() };
 
         let bit_offset = self.bit_offset;
 
-        let combined = if bit_offset == 0 {
+        if bit_offset == 0 {
             current
         } else {
             // the constructor ensures that bit_offset is in 0..8
             // that means we need to read at most one additional byte to fill in the high bits
             let next =
-                unsafe { std::ptr::read_unaligned(raw_data.add(index + 1) as *const u8) as u64 };
+                unsafe { core::ptr::read_unaligned(raw_data.add(index + 1) as *const u8) as u64 };
 
             (current >> bit_offset) | (next << (64 - bit_offset))
-        };
+        }
+    }
+}
 
-        self.index = index + 1;
+impl Iterator for BitChunkIterator<'_> {
+    type Item = u64;
 
-        Some(combined)
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        let index = self.index;
+        if index >= self.chunk_len {
+            return None;
+        }
+        self.index = index + 1;
+        Some(self.read_chunk(index))
     }
 
     #[inline]
@@ -353,6 +447,17 @@ impl Iterator for BitChunkIterator<'_> {
     }
 }
 
+impl DoubleEndedIterator for BitChunkIterator<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<u64> {
+        if self.index >= self.chunk_len {
+            return None;
+        }
+        self.chunk_len -= 1;
+        Some(self.read_chunk(self.chunk_len))
+    }
+}
+
 impl ExactSizeIterator for BitChunkIterator<'_> {
     #[inline]
     fn len(&self) -> usize {
@@ -460,10 +565,56 @@ mod tests {
 
         let bitchunks = buffer.bit_chunks(57, ALLOC_SIZE * 8 - 57);
 
-        assert_eq!(u64::MAX, bitchunks.iter().last().unwrap());
+        assert_eq!(u64::MAX, bitchunks.iter().next_back().unwrap());
         assert_eq!(0x7F, bitchunks.remainder_bits());
     }
 
+    #[test]
+    fn test_iter_reverse() {
+        let input: Vec<u8> = (0..33).collect();
+        let buffer = Buffer::from(input);
+
+        for offset in [0, 3, 8, 13] {
+            let bitchunks = buffer.bit_chunks(offset, 256 - offset);
+            let forward: Vec<_> = bitchunks.iter().collect();
+            assert_eq!(forward.len(), 4 - (offset > 0) as usize);
+
+            let mut reverse: Vec<_> = bitchunks.iter().rev().collect();
+            reverse.reverse();
+            assert_eq!(forward, reverse);
+
+            // Interleave forward and reverse iteration
+            let mut iter = bitchunks.iter();
+            assert_eq!(iter.next_back(), forward.last().copied());
+            assert_eq!(iter.next(), forward.first().copied());
+            assert_eq!(iter.len(), forward.len() - 2);
+            let rest: Vec<_> = iter.by_ref().rev().collect();
+            assert_eq!(rest.len(), forward.len() - 2);
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+
+            let padded: Vec<_> = bitchunks.iter_padded().rev().collect();
+            assert_eq!(padded[0], bitchunks.remainder_bits());
+            assert_eq!(padded.len(), forward.len() + 1);
+        }
+    }
+
+    #[test]
+    fn test_remainder_mask() {
+        let buffer = Buffer::from(&[0xFF; 16]);
+
+        let bitchunks = buffer.bit_chunks(0, 64);
+        assert_eq!(bitchunks.remainder_mask(), 0);
+
+        let bitchunks = buffer.bit_chunks(5, 70);
+        assert_eq!(bitchunks.remainder_len(), 6);
+        assert_eq!(bitchunks.remainder_mask(), 0b111111);
+        assert_eq!(bitchunks.remainder_bits(), bitchunks.remainder_mask());
+
+        let bitchunks = buffer.bit_chunks(1, 127);
+        assert_eq!(bitchunks.remainder_mask(), u64::MAX >> 1);
+    }
+
     #[test]
     #[allow(clippy::assertions_on_constants)]
     fn test_unaligned_bit_chunk_iterator() {
@@ -619,7 +770,7 @@ mod tests {
 
         for _ in 0..100 {
             let mask_len = rng.gen_range(0..1024);
-            let bools: Vec<_> = std::iter::from_fn(|| Some(rng.gen()))
+            let bools: Vec<_> = core::iter::from_fn(|| Some(rng.gen()))
                 .take(mask_len)
                 .collect();
 
@@ -653,6 +804,10 @@ mod tests {
             for (idx, b) in bool_slice.iter().enumerate() {
                 assert_eq!(*b, get_bit(idx))
             }
+
+            let mut reversed: Vec<u64> = unaligned.iter().rev().collect();
+            reversed.reverse();
+            assert_eq!(collected, reversed);
         }
     }
 }
@@ -41,13 +41,16 @@ use chrono::{NaiveTime, Offset, TimeZone, Utc};
 use std::cmp::Ordering;
 use std::sync::Arc;
 
+use crate::date64::{apply_date64_policy, Date64Policy};
 use crate::display::{ArrayFormatter, FormatOptions};
 use crate::parse::{
     parse_interval_day_time, parse_interval_month_day_nano, parse_interval_year_month,
     string_to_datetime, Parser,
 };
+use crate::rounding::{div_rounded, round_float, round_float_array, RoundingMode};
+use crate::timestamp::{convert_time_unit, time_unit_multiple, OverflowPolicy};
 use arrow_array::{builder::*, cast::*, temporal_conversions::*, timezone::Tz, types::*, *};
-use arrow_buffer::{i256, ArrowNativeType, OffsetBuffer};
+use arrow_buffer::{i256, OffsetBuffer};
 use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::*;
@@ -62,6 +65,23 @@ pub struct CastOptions<'a> {
     pub safe: bool,
     /// Formatting options when casting from temporal types to string
     pub format_options: FormatOptions<'a>,
+    /// Delimiter to use when casting between strings and lists
+    ///
+    /// If set, casting a string to a list splits each string on this delimiter and casts
+    /// the resulting values to the list's value type, and casting a list to a string
+    /// joins the formatted values of each list with this delimiter. Otherwise a string
+    /// is cast to a single element list, and a list is cast to its display representation
+    pub list_delimiter: Option<&'a str>,
+    /// How to handle values not aligned to midnight when casting to [`DataType::Date64`]
+    ///
+    /// Note that [`Date64Policy::Error`] returns an error regardless of [`Self::safe`]
+    pub date64_policy: Date64Policy,
+    /// How to round values when casting floating point values to integers or
+    /// decimals, and decimals to integers or decimals of smaller scale
+    ///
+    /// If `None`, values are truncated when cast to integers, and rounded half away
+    /// from zero when cast to decimals
+    pub rounding_mode: Option<RoundingMode>,
 }
 
 impl<'a> Default for CastOptions<'a> {
@@ -69,6 +89,9 @@ impl<'a> Default for CastOptions<'a> {
         Self {
             safe: true,
             format_options: FormatOptions::default(),
+            list_delimiter: None,
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
         }
     }
 }
@@ -304,16 +327,15 @@ where
     })?;
 
     let array = if scale < 0 {
+        let mode = cast_options.rounding_mode.unwrap_or(RoundingMode::Down);
         match cast_options.safe {
             true => array.unary_opt::<_, D>(|v| {
-                v.as_().div_checked(scale_factor).ok().and_then(|v| {
-                    (D::validate_decimal_precision(v, precision).is_ok()).then_some(v)
-                })
+                let v = div_rounded(v.as_(), scale_factor, mode);
+                (D::validate_decimal_precision(v, precision).is_ok()).then_some(v)
             }),
             false => array.try_unary::<_, D, _>(|v| {
-                v.as_()
-                    .div_checked(scale_factor)
-                    .and_then(|v| D::validate_decimal_precision(v, precision).map(|_| v))
+                let v = div_rounded(v.as_(), scale_factor, mode);
+                D::validate_decimal_precision(v, precision).map(|_| v)
             })?,
         }
     } else {
@@ -344,12 +366,12 @@ where
     <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
 {
     let mul = 10_f64.powi(scale as i32);
+    let mode = cast_options.rounding_mode.unwrap_or(RoundingMode::HalfUp);
 
     if cast_options.safe {
         array
             .unary_opt::<_, Decimal128Type>(|v| {
-                (mul * v.as_())
-                    .round()
+                round_float(mul * v.as_(), mode)
                     .to_i128()
                     .filter(|v| Decimal128Type::validate_decimal_precision(*v, precision).is_ok())
             })
@@ -358,8 +380,7 @@ where
     } else {
         array
             .try_unary::<_, Decimal128Type, _>(|v| {
-                (mul * v.as_())
-                    .round()
+                round_float(mul * v.as_(), mode)
                     .to_i128()
                     .ok_or_else(|| {
                         ArrowError::CastError(format!(
@@ -389,11 +410,12 @@ where
     <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
 {
     let mul = 10_f64.powi(scale as i32);
+    let mode = cast_options.rounding_mode.unwrap_or(RoundingMode::HalfUp);
 
     if cast_options.safe {
         array
             .unary_opt::<_, Decimal256Type>(|v| {
-                i256::from_f64((v.as_() * mul).round())
+                i256::from_f64(round_float(v.as_() * mul, mode))
                     .filter(|v| Decimal256Type::validate_decimal_precision(*v, precision).is_ok())
             })
             .with_precision_and_scale(precision, scale)
@@ -401,7 +423,7 @@ where
     } else {
         array
             .try_unary::<_, Decimal256Type, _>(|v| {
-                i256::from_f64((v.as_() * mul).round())
+                i256::from_f64(round_float(v.as_() * mul, mode))
                     .ok_or_else(|| {
                         ArrowError::CastError(format!(
                             "Cannot cast to {}({}, {}). Overflowing on {:?}",
@@ -571,6 +593,7 @@ where
         ))
     })?;
 
+    let mode = cast_options.rounding_mode.unwrap_or(RoundingMode::Down);
     let mut value_builder = PrimitiveBuilder::<T>::with_capacity(array.len());
 
     if cast_options.safe {
@@ -578,11 +601,8 @@ where
             if array.is_null(i) {
                 value_builder.append_null();
             } else {
-                let v = array
-                    .value(i)
-                    .div_checked(div)
-                    .ok()
-                    .and_then(<T::Native as NumCast>::from::<D::Native>);
+                let v = div_rounded(array.value(i), div, mode);
+                let v = <T::Native as NumCast>::from::<D::Native>(v);
 
                 value_builder.append_option(v);
             }
@@ -592,7 +612,7 @@ where
             if array.is_null(i) {
                 value_builder.append_null();
             } else {
-                let v = array.value(i).div_checked(div)?;
+                let v = div_rounded(array.value(i), div, mode);
 
                 let value = <T::Native as NumCast>::from::<D::Native>(v).ok_or_else(|| {
                     ArrowError::CastError(format!(
@@ -684,6 +704,8 @@ fn as_time_res_with_timezone<T: ArrowPrimitiveType>(
 /// * List to FixedSizeList: the underlying data type is cast. If safe is true and a list element
 /// has the wrong length it will be replaced with NULL, otherwise an error will be returned
 /// * Primitive to List: a list array with 1 value per slot is created
+/// * Utf8 to List and List to Utf8: if [`CastOptions::list_delimiter`] is set, strings are
+///   split on the delimiter, and the values of lists joined with it
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
@@ -691,6 +713,8 @@ fn as_time_res_with_timezone<T: ArrowPrimitiveType>(
 /// * Casting from `float32/float64` to `Decimal(precision, scale)` rounds to the `scale` decimals
 ///   (i.e. casting `6.4999` to Decimal(10, 1) becomes `6.5`). Prior to  version `26.0.0`,
 ///   casting would truncate instead (i.e. outputs `6.4` instead)
+/// * Casting from floating point or decimal to integer truncates the fractional part,
+///   unless [`CastOptions::rounding_mode`] is set
 ///
 /// Unsupported Casts
 /// * To or from `StructArray`
@@ -700,6 +724,69 @@ pub fn cast_with_options(
     array: &dyn Array,
     to_type: &DataType,
     cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = cast_with_options_impl(array, to_type, cast_options)?;
+    match to_type {
+        DataType::Date64 if cast_options.date64_policy != Date64Policy::Preserve => {
+            apply_date64_policy(&array, cast_options.date64_policy)
+        }
+        _ => Ok(array),
+    }
+}
+
+/// Cast `array` to the provided data type, casting slices of `chunk_len` rows on the
+/// rayon global thread pool, and concatenating the results
+///
+/// This can considerably reduce the time taken by expensive casts of large arrays,
+/// such as parsing strings, at the cost of copying the results. Arrays of at most
+/// `chunk_len` rows are cast with [`cast_with_options`]
+///
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int64Type;
+/// # use arrow_cast::{cast_with_options_parallel, CastOptions};
+/// # use arrow_schema::DataType;
+/// let array = StringArray::from(vec!["1", "2", "x", "4", "5"]);
+/// let options = CastOptions::default();
+/// let cast = cast_with_options_parallel(&array, &DataType::Int64, &options, 2).unwrap();
+/// let cast = cast.as_primitive::<Int64Type>();
+/// assert_eq!(cast.iter().collect::<Vec<_>>(), vec![Some(1), Some(2), None, Some(4), Some(5)]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `chunk_len` is zero
+#[cfg(feature = "parallel")]
+pub fn cast_with_options_parallel(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+    chunk_len: usize,
+) -> Result<ArrayRef, ArrowError> {
+    use rayon::prelude::*;
+
+    assert_ne!(chunk_len, 0, "chunk_len must be non-zero");
+    if array.len() <= chunk_len {
+        return cast_with_options(array, to_type, cast_options);
+    }
+    let chunks = (0..array.len())
+        .step_by(chunk_len)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|offset| {
+            let len = chunk_len.min(array.len() - offset);
+            cast_with_options(&array.slice(offset, len), to_type, cast_options)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let chunks: Vec<_> = chunks.iter().map(|c| c.as_ref()).collect();
+    arrow_select::concat::concat(&chunks)
+}
+
+fn cast_with_options_impl(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     use DataType::*;
     let from_type = array.data_type();
@@ -707,6 +794,16 @@ pub fn cast_with_options(
     if from_type == to_type {
         return Ok(make_array(array.to_data()));
     }
+    if let Some(mode) = cast_options.rounding_mode {
+        if from_type.is_floating() && to_type.is_integer() {
+            let rounded = round_float_array(array, mode)?;
+            let cast_options = CastOptions {
+                rounding_mode: None,
+                ..cast_options.clone()
+            };
+            return cast_with_options_impl(&rounded, to_type, &cast_options);
+        }
+    }
     match (from_type, to_type) {
         (
             Null,
@@ -778,6 +875,18 @@ pub fn cast_with_options(
             let array = array.as_list::<i64>();
             cast_list_to_fixed_size_list::<i64>(array, field, *size, cast_options)
         }
+        (List(_), Utf8) if cast_options.list_delimiter.is_some() => {
+            cast_list_to_string::<i32, i32>(array, cast_options)
+        }
+        (List(_), LargeUtf8) if cast_options.list_delimiter.is_some() => {
+            cast_list_to_string::<i32, i64>(array, cast_options)
+        }
+        (LargeList(_), Utf8) if cast_options.list_delimiter.is_some() => {
+            cast_list_to_string::<i64, i32>(array, cast_options)
+        }
+        (LargeList(_), LargeUtf8) if cast_options.list_delimiter.is_some() => {
+            cast_list_to_string::<i64, i64>(array, cast_options)
+        }
         (List(_) | LargeList(_), _) => match to_type {
             Utf8 => value_to_string::<i32>(array, cast_options),
             LargeUtf8 => value_to_string::<i64>(array, cast_options),
@@ -820,6 +929,18 @@ pub fn cast_with_options(
                 array.nulls().cloned(),
             )?))
         }
+        (Utf8, List(ref to)) if cast_options.list_delimiter.is_some() => {
+            cast_string_to_list::<i32, i32>(array, to, cast_options)
+        }
+        (Utf8, LargeList(ref to)) if cast_options.list_delimiter.is_some() => {
+            cast_string_to_list::<i32, i64>(array, to, cast_options)
+        }
+        (LargeUtf8, List(ref to)) if cast_options.list_delimiter.is_some() => {
+            cast_string_to_list::<i64, i32>(array, to, cast_options)
+        }
+        (LargeUtf8, LargeList(ref to)) if cast_options.list_delimiter.is_some() => {
+            cast_string_to_list::<i64, i64>(array, to, cast_options)
+        }
         (_, List(ref to)) => cast_values_to_list::<i32>(array, to, cast_options),
         (_, LargeList(ref to)) => cast_values_to_list::<i64>(array, to, cast_options),
         (_, FixedSizeList(ref to, size)) if *size == 1 => {
@@ -1681,25 +1802,11 @@ pub fn cast_with_options(
         (Timestamp(from_unit, from_tz), Timestamp(to_unit, to_tz)) => {
             let array = cast_with_options(array, &Int64, cast_options)?;
             let time_array = array.as_primitive::<Int64Type>();
-            let from_size = time_unit_multiple(from_unit);
-            let to_size = time_unit_multiple(to_unit);
-            // we either divide or multiply, depending on size of each unit
-            // units are never the same when the types are the same
-            let converted = match from_size.cmp(&to_size) {
-                Ordering::Greater => {
-                    let divisor = from_size / to_size;
-                    time_array.unary::<_, Int64Type>(|o| o / divisor)
-                }
-                Ordering::Equal => time_array.clone(),
-                Ordering::Less => {
-                    let mul = to_size / from_size;
-                    if cast_options.safe {
-                        time_array.unary_opt::<_, Int64Type>(|o| o.checked_mul(mul))
-                    } else {
-                        time_array.try_unary::<_, Int64Type, _>(|o| o.mul_checked(mul))?
-                    }
-                }
+            let policy = match cast_options.safe {
+                true => OverflowPolicy::Null,
+                false => OverflowPolicy::Error,
             };
+            let converted = convert_time_unit(time_array, from_unit, to_unit, policy)?;
             // Normalize timezone
             let adjusted = match (from_tz, to_tz) {
                 // Only this case needs to be adjusted because we're casting from
@@ -2087,16 +2194,6 @@ pub fn cast_with_options(
     }
 }
 
-/// Get the time unit as a multiple of a second
-const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
-    match unit {
-        TimeUnit::Second => 1,
-        TimeUnit::Millisecond => MILLISECONDS,
-        TimeUnit::Microsecond => MICROSECONDS,
-        TimeUnit::Nanosecond => NANOSECONDS,
-    }
-}
-
 /// A utility trait that provides checked conversions between
 /// decimal types inspired by [`NumCast`]
 trait DecimalCast: Sized {
@@ -2174,22 +2271,10 @@ where
         .unwrap()
         .pow_checked((input_scale - output_scale) as u32)?;
 
-    let half = div.div_wrapping(I::Native::from_usize(2).unwrap());
-    let half_neg = half.neg_wrapping();
+    let mode = cast_options.rounding_mode.unwrap_or(RoundingMode::HalfUp);
 
-    let f = |x: I::Native| {
-        // div is >= 10 and so this cannot overflow
-        let d = x.div_wrapping(div);
-        let r = x.mod_wrapping(div);
-
-        // Round result
-        let adjusted = match x >= I::Native::ZERO {
-            true if r >= half => d.add_wrapping(I::Native::ONE),
-            false if r <= half_neg => d.sub_wrapping(I::Native::ONE),
-            _ => d,
-        };
-        O::Native::from_decimal(adjusted)
-    };
+    // div is >= 10 and so this cannot overflow
+    let f = |x: I::Native| O::Native::from_decimal(div_rounded(x, div, mode));
 
     Ok(match cast_options.safe {
         true => array.unary_opt(f),
@@ -2895,56 +2980,105 @@ fn dictionary_cast<K: ArrowDictionaryKeyType>(
                     )
                 })?;
 
-            let keys_array: ArrayRef =
-                Arc::new(PrimitiveArray::<K>::from(dict_array.keys().to_data()));
-            let values_array = dict_array.values();
-            let cast_keys = cast_with_options(&keys_array, to_index_type, cast_options)?;
-            let cast_values = cast_with_options(values_array, to_value_type, cast_options)?;
-
-            // Failure to cast keys (because they don't fit in the
-            // target type) results in NULL values;
-            if cast_keys.null_count() > keys_array.null_count() {
-                return Err(ArrowError::ComputeError(format!(
-                    "Could not convert {} dictionary indexes from {:?} to {:?}",
-                    cast_keys.null_count() - keys_array.null_count(),
-                    keys_array.data_type(),
-                    to_index_type
-                )));
-            }
-
-            let data = cast_keys.into_data();
-            let builder = data
-                .into_builder()
-                .data_type(to_type.clone())
-                .child_data(vec![cast_values.into_data()]);
-
-            // Safety
-            // Cast keys are still valid
-            let data = unsafe { builder.build_unchecked() };
-
-            // create the appropriate array type
-            let new_array: ArrayRef = match **to_index_type {
-                Int8 => Arc::new(DictionaryArray::<Int8Type>::from(data)),
-                Int16 => Arc::new(DictionaryArray::<Int16Type>::from(data)),
-                Int32 => Arc::new(DictionaryArray::<Int32Type>::from(data)),
-                Int64 => Arc::new(DictionaryArray::<Int64Type>::from(data)),
-                UInt8 => Arc::new(DictionaryArray::<UInt8Type>::from(data)),
-                UInt16 => Arc::new(DictionaryArray::<UInt16Type>::from(data)),
-                UInt32 => Arc::new(DictionaryArray::<UInt32Type>::from(data)),
-                UInt64 => Arc::new(DictionaryArray::<UInt64Type>::from(data)),
-                _ => {
-                    return Err(ArrowError::CastError(format!(
-                        "Unsupported type {to_index_type:?} for dictionary index"
-                    )));
-                }
+            // Values of the same type are reused by reference
+            let values = match dict_array.values().data_type() == to_value_type.as_ref() {
+                true => dict_array.values().clone(),
+                false => cast_with_options(dict_array.values(), to_value_type, cast_options)?,
             };
-
-            Ok(new_array)
+            cast_dictionary_keys_to(dict_array, values, to_index_type)
         }
         _ => unpack_dictionary::<K>(array, to_type, cast_options),
     }
 }
 
+/// Cast the keys of the dictionary `array` to the integer type `to_key_type`, reusing its
+/// values array by reference
+///
+/// Unlike [`cast`] to a dictionary type, the values are never cast or copied, making
+/// this suitable for narrowing or widening the keys of dictionaries to align them
+/// with a target schema.
+///
+/// Returns an error if `array` is not a dictionary, `to_key_type` is not an integer
+/// type, or a key of `array` does not fit in `to_key_type`.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Array, DictionaryArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::{Int32Type, Int8Type};
+/// # use arrow_cast::cast::cast_dictionary_keys;
+/// # use arrow_schema::DataType;
+/// let array: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+/// let cast = cast_dictionary_keys(&array, &DataType::Int8).unwrap();
+/// let cast = cast.as_dictionary::<Int8Type>();
+/// assert_eq!(cast.keys().values(), &[0, 1, 0]);
+/// assert!(Arc::ptr_eq(cast.values(), array.values()));
+/// ```
+pub fn cast_dictionary_keys(
+    array: &dyn Array,
+    to_key_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    downcast_dictionary_array! {
+        array => cast_dictionary_keys_to(array, array.values().clone(), to_key_type),
+        t => Err(ArrowError::CastError(format!(
+            "Cannot cast keys of non-dictionary type {t:?}"
+        )))
+    }
+}
+
+/// Returns a dictionary of the keys of `dict_array` cast to `to_key_type`, and `values`
+fn cast_dictionary_keys_to<K: ArrowDictionaryKeyType>(
+    dict_array: &DictionaryArray<K>,
+    values: ArrayRef,
+    to_key_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    macro_rules! key_helper {
+        ($t:ty, $dict:expr, $values:expr) => {
+            Ok(Arc::new(cast_keys::<K, $t>($dict, $values)?) as ArrayRef)
+        };
+    }
+    downcast_integer! {
+        to_key_type => (key_helper, dict_array, values),
+        _ => Err(ArrowError::CastError(format!(
+            "Unsupported type {to_key_type:?} for dictionary index"
+        ))),
+    }
+}
+
+fn cast_keys<K: ArrowDictionaryKeyType, T: ArrowDictionaryKeyType>(
+    dict_array: &DictionaryArray<K>,
+    values: ArrayRef,
+) -> Result<DictionaryArray<T>, ArrowError> {
+    use arrow_buffer::ArrowNativeType;
+    let keys = dict_array.keys();
+
+    // Valid keys are less than the length of values, and so only need to be checked
+    // if the largest index of values does not fit in T
+    let fits = values.is_empty() || T::Native::from_usize(values.len() - 1).is_some();
+    if !fits {
+        let invalid = keys
+            .iter()
+            .flatten()
+            .filter(|k| T::Native::from_usize(k.as_usize()).is_none())
+            .count();
+        if invalid > 0 {
+            return Err(ArrowError::ComputeError(format!(
+                "Could not convert {} dictionary indexes from {:?} to {:?}",
+                invalid,
+                K::DATA_TYPE,
+                T::DATA_TYPE
+            )));
+        }
+    }
+
+    // Null keys may have any value, and are replaced with 0 if out of range
+    let keys: PrimitiveArray<T> =
+        keys.unary(|k| T::Native::from_usize(k.as_usize()).unwrap_or_default());
+
+    // Safety: every valid key is an index of values
+    Ok(unsafe { DictionaryArray::new_unchecked(keys, values) })
+}
+
 // Unpack a dictionary where the keys are of type <K> into a flattened array of type to_type
 fn unpack_dictionary<K>(
     array: &dyn Array,
@@ -3063,6 +3197,78 @@ fn cast_values_to_list<O: OffsetSizeTrait>(
     Ok(Arc::new(list))
 }
 
+/// Returns the [`CastOptions::list_delimiter`], which must be non-empty
+fn list_delimiter<'a>(cast_options: &CastOptions<'a>) -> Result<&'a str, ArrowError> {
+    match cast_options.list_delimiter {
+        Some(delimiter) if !delimiter.is_empty() => Ok(delimiter),
+        _ => Err(ArrowError::InvalidArgumentError(
+            "List delimiter must be a non-empty string".to_string(),
+        )),
+    }
+}
+
+/// Helper function that splits each string on [`CastOptions::list_delimiter`] and
+/// casts the resulting values to a (generic) list array.
+///
+/// Null strings are cast to null lists, and empty strings to empty lists
+fn cast_string_to_list<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    array: &dyn Array,
+    to: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let delimiter = list_delimiter(cast_options)?;
+    let array = array.as_string::<I>();
+
+    let mut values = GenericStringBuilder::<I>::new();
+    let mut lengths = Vec::with_capacity(array.len());
+    for value in array.iter() {
+        let len = match value {
+            Some(value) if !value.is_empty() => value
+                .split(delimiter)
+                .map(|v| values.append_value(v))
+                .count(),
+            _ => 0,
+        };
+        lengths.push(len);
+    }
+
+    let values = cast_with_options(&values.finish(), to.data_type(), cast_options)?;
+    let offsets = OffsetBuffer::<O>::from_lengths(lengths);
+    let list = GenericListArray::<O>::try_new(to.clone(), offsets, values, array.nulls().cloned())?;
+    Ok(Arc::new(list))
+}
+
+/// Helper function that formats the values of each list and joins them with
+/// [`CastOptions::list_delimiter`] to create a (generic) string array
+fn cast_list_to_string<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    use std::fmt::Write;
+
+    let delimiter = list_delimiter(cast_options)?;
+    let array = array.as_list::<I>();
+    let formatter = ArrayFormatter::try_new(array.values().as_ref(), &cast_options.format_options)?;
+
+    let mut builder = GenericStringBuilder::<O>::new();
+    for (idx, offsets) in array.value_offsets().windows(2).enumerate() {
+        if array.is_null(idx) {
+            builder.append_null();
+            continue;
+        }
+        for (i, value_idx) in (offsets[0].as_usize()..offsets[1].as_usize()).enumerate() {
+            if i != 0 {
+                // writing to a string builder is infallible
+                builder.write_str(delimiter).unwrap();
+            }
+            formatter.value(value_idx).write(&mut builder)?;
+        }
+        // tell the builder the row is finished
+        builder.append_value("");
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
 /// Helper function that takes a primitive array and casts to a fixed size list array.
 fn cast_values_to_fixed_size_list(
     array: &dyn Array,
@@ -3366,6 +3572,9 @@ mod tests {
             let cast_option = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             };
             let result = cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
             assert_eq!($OUTPUT_TYPE, result.data_type());
@@ -3565,6 +3774,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 38). Overflowing on 170141183460469231731687303715884105727",
@@ -3585,6 +3797,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 76). Overflowing on 170141183460469231731687303715884105727",
@@ -3624,6 +3839,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 7). Overflowing on 170141183460469231731687303715884105727",
@@ -3643,6 +3861,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 55). Overflowing on 170141183460469231731687303715884105727",
@@ -3795,6 +4016,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!(
@@ -3808,6 +4032,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -3822,6 +4049,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!(
@@ -3835,6 +4065,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -3998,6 +4231,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!(
@@ -4011,6 +4247,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -4428,6 +4667,9 @@ mod tests {
         let cast_option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            list_delimiter: None,
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
         };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
@@ -4557,6 +4799,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         match result {
@@ -4588,6 +4833,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         match casted {
@@ -4972,6 +5220,9 @@ mod tests {
                 let options = CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    list_delimiter: None,
+                    date64_policy: Date64Policy::Preserve,
+                    rounding_mode: None,
                 };
                 let err = cast_with_options(array, &to_type, &options).unwrap_err();
                 assert_eq!(
@@ -5013,6 +5264,9 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -5033,6 +5287,9 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            list_delimiter: None,
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
         };
         let result = cast_with_options(&a, &to_type, &options).unwrap();
         let c = result.as_primitive::<Date32Type>();
@@ -5075,6 +5332,9 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
@@ -5110,6 +5370,9 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
@@ -5139,6 +5402,9 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
@@ -5168,6 +5434,9 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
@@ -5197,6 +5466,9 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -5213,6 +5485,9 @@ mod tests {
             let options = CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             };
 
             let target_interval_array = cast_with_options(
@@ -5340,6 +5615,9 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             };
             let arrow_err = cast_with_options(
                 &string_array.clone(),
@@ -5446,6 +5724,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(array_ref.is_err());
@@ -5456,6 +5737,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(array_ref.is_err());
@@ -5575,11 +5859,54 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            list_delimiter: None,
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
         };
         let b = cast_with_options(&array, &DataType::Date64, &options);
         assert!(b.is_err());
     }
 
+    #[test]
+    fn test_cast_date64_policy() {
+        let array =
+            TimestampMillisecondArray::from(vec![Some(864000000005), Some(864000000000), None]);
+
+        let options = CastOptions {
+            date64_policy: Date64Policy::Truncate,
+            rounding_mode: None,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Date64, &options).unwrap();
+        let c = b.as_primitive::<Date64Type>();
+        assert_eq!(864000000000, c.value(0));
+        assert_eq!(864000000000, c.value(1));
+        assert!(c.is_null(2));
+
+        // Also applies to casts between Date64 arrays
+        let b = cast_with_options(
+            &cast(&array, &DataType::Date64).unwrap(),
+            &DataType::Date64,
+            &options,
+        )
+        .unwrap();
+        assert_eq!(864000000000, b.as_primitive::<Date64Type>().value(0));
+
+        let options = CastOptions {
+            date64_policy: Date64Policy::Error,
+            rounding_mode: None,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &DataType::Date64, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Date64 value 864000000005 at index 0 is not a multiple of 86400000 milliseconds"
+        );
+        let array = StringArray::from(vec!["2018-12-25T00:00:00", "2018-12-25T00:00:01"]);
+        assert!(cast_with_options(&array, &DataType::Date64, &options).is_err());
+        assert!(cast_with_options(&array.slice(0, 1), &DataType::Date64, &options).is_ok());
+    }
+
     #[test]
     fn test_cast_timestamp_to_time64() {
         // test timestamp secs
@@ -5930,6 +6257,9 @@ mod tests {
             format_options: FormatOptions::default()
                 .with_timestamp_format(Some(ts_format))
                 .with_timestamp_tz_format(Some(ts_format)),
+            list_delimiter: None,
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
         };
         // "2018-12-25T00:00:02.001", "1997-05-19T00:00:03.005", None
         let array_without_tz =
@@ -7329,6 +7659,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cast_dictionary_keys() {
+        let values: ArrayRef = Arc::new(StringArray::from_iter_values(
+            (0..200).map(|i| i.to_string()),
+        ));
+        let keys = Int32Array::from(vec![Some(0), None, Some(199), Some(5)]);
+        let array = DictionaryArray::new(keys, values.clone());
+
+        for to_key_type in [DataType::Int16, DataType::Int64, DataType::UInt8] {
+            let cast = cast_dictionary_keys(&array, &to_key_type).unwrap();
+            let expected = DataType::Dictionary(Box::new(to_key_type), Box::new(DataType::Utf8));
+            assert_eq!(cast.data_type(), &expected);
+            assert_eq!(
+                array_to_strings(&cast),
+                array_to_strings(&(Arc::new(array.clone()) as _))
+            );
+            assert!(Arc::ptr_eq(cast.as_any_dictionary().values(), &values));
+        }
+
+        let err = cast_dictionary_keys(&array, &DataType::Int8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Could not convert 1 dictionary indexes from Int32 to Int8"
+        );
+
+        // Null keys need not be in range
+        let keys = Int32Array::new(vec![0, 150, 1].into(), Some(vec![true, false, true].into()));
+        let array = DictionaryArray::new(keys, values.slice(0, 2));
+        let narrowed = cast_dictionary_keys(&array, &DataType::Int8).unwrap();
+        let narrowed = narrowed.as_dictionary::<Int8Type>();
+        assert_eq!(
+            narrowed.keys(),
+            &Int8Array::from(vec![Some(0), None, Some(1)])
+        );
+
+        // cast reuses the values when only the key type changes
+        let to_type = DataType::Dictionary(Box::new(DataType::Int64), Box::new(DataType::Utf8));
+        let cast = cast(&array, &to_type).unwrap();
+        assert!(Arc::ptr_eq(
+            cast.as_any_dictionary().values(),
+            array.values()
+        ));
+
+        let err = cast_dictionary_keys(&array, &DataType::Utf8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Unsupported type Utf8 for dictionary index"
+        );
+        let err = cast_dictionary_keys(&values, &DataType::Int8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast keys of non-dictionary type Utf8"
+        );
+    }
+
     #[test]
     fn test_cast_primitive_dict() {
         // FROM a dictionary with of INT32 values
@@ -7727,6 +8112,99 @@ mod tests {
         assert_eq!(&expect.value(0), &actual.value(0));
     }
 
+    #[test]
+    fn test_cast_utf8_to_list_with_delimiter() {
+        let options = CastOptions {
+            list_delimiter: Some(", "),
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
+            ..Default::default()
+        };
+        let array = Arc::new(StringArray::from(vec![
+            Some("1, 2, 3"),
+            None,
+            Some(""),
+            Some("4"),
+            Some("5, x"),
+        ])) as ArrayRef;
+        let field = Arc::new(Field::new("item", DataType::Int32, true));
+
+        let list_array =
+            cast_with_options(&array, &DataType::List(field.clone()), &options).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>([
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4)]),
+            Some(vec![Some(5), None]),
+        ]);
+        assert_eq!(list_array.as_list::<i32>(), &expected);
+
+        let array = cast(&array, &DataType::LargeUtf8).unwrap();
+        let list_array =
+            cast_with_options(&array, &DataType::LargeList(field.clone()), &options).unwrap();
+        assert_eq!(
+            list_array.as_list::<i64>().value_offsets(),
+            &[0, 3, 3, 3, 4, 6]
+        );
+
+        let strict = CastOptions {
+            safe: false,
+            ..options.clone()
+        };
+        let err = cast_with_options(&array, &DataType::List(field.clone()), &strict).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast string 'x' to value of Int32 type"
+        );
+
+        let empty = CastOptions {
+            list_delimiter: Some(""),
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &DataType::List(field), &empty).unwrap_err();
+        assert!(err.to_string().contains("non-empty"), "{err}");
+    }
+
+    #[test]
+    fn test_cast_list_to_utf8_with_delimiter() {
+        let options = CastOptions {
+            list_delimiter: Some("|"),
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
+            format_options: FormatOptions::default().with_null("NULL"),
+            ..Default::default()
+        };
+        let array = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>([
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(4), None]),
+        ])) as ArrayRef;
+
+        let out = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let expected = StringArray::from(vec![Some("1|2|3"), None, Some(""), Some("4|NULL")]);
+        assert_eq!(out.as_string::<i32>(), &expected);
+
+        let array = cast(
+            &array,
+            &DataType::LargeList(Arc::new(Field::new("item", DataType::Int32, true))),
+        )
+        .unwrap();
+        let out = cast_with_options(&array, &DataType::LargeUtf8, &options).unwrap();
+        let expected = LargeStringArray::from(vec![Some("1|2|3"), None, Some(""), Some("4|NULL")]);
+        assert_eq!(out.as_string::<i64>(), &expected);
+
+        // Round trip through a list of strings
+        let strings = Arc::new(StringArray::from(vec!["a|b", "c"])) as ArrayRef;
+        let list_type = DataType::List(Arc::new(Field::new("item", DataType::Utf8, true)));
+        let list = cast_with_options(&strings, &list_type, &options).unwrap();
+        let back = cast_with_options(&list, &DataType::Utf8, &options).unwrap();
+        assert_eq!(back.as_ref(), strings.as_ref());
+    }
+
     #[test]
     fn test_cast_list_containers() {
         // large-list to list
@@ -8160,6 +8638,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -8171,6 +8652,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_err());
@@ -8186,6 +8670,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -8197,11 +8684,109 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_err());
     }
 
+    #[test]
+    fn test_cast_rounding_mode() {
+        let cast = |array: &dyn Array, to_type: &DataType, mode| {
+            let options = CastOptions {
+                rounding_mode: mode,
+                ..Default::default()
+            };
+            cast_with_options(array, to_type, &options).unwrap()
+        };
+        let down = Some(RoundingMode::Down);
+        let half_up = Some(RoundingMode::HalfUp);
+        let half_even = Some(RoundingMode::HalfEven);
+
+        // 1.25, 1.35, -1.25, 1.27 and null
+        let array = create_decimal_array(
+            vec![Some(125), Some(135), Some(-125), Some(127), None],
+            10,
+            2,
+        )
+        .unwrap();
+
+        let to_int = |mode| {
+            let array = cast(&array, &DataType::Int64, mode);
+            array.as_primitive::<Int64Type>().iter().collect::<Vec<_>>()
+        };
+        assert_eq!(
+            to_int(None),
+            vec![Some(1), Some(1), Some(-1), Some(1), None]
+        );
+        assert_eq!(to_int(down), to_int(None));
+        assert_eq!(to_int(half_up), to_int(None));
+
+        let to_decimal = |to_type: &DataType, mode| {
+            let array = cast(&array, to_type, mode);
+            match to_type {
+                DataType::Decimal128(_, _) => array
+                    .as_primitive::<Decimal128Type>()
+                    .iter()
+                    .map(|v| v.map(i256::from_i128))
+                    .collect::<Vec<_>>(),
+                _ => array.as_primitive::<Decimal256Type>().iter().collect(),
+            }
+        };
+        let expected = |values: [i128; 4]| {
+            let mut values: Vec<_> = values
+                .into_iter()
+                .map(|v| Some(i256::from_i128(v)))
+                .collect();
+            values.push(None);
+            values
+        };
+        for to_type in [DataType::Decimal128(10, 1), DataType::Decimal256(10, 1)] {
+            assert_eq!(to_decimal(&to_type, None), expected([13, 14, -13, 13]));
+            assert_eq!(to_decimal(&to_type, half_up), expected([13, 14, -13, 13]));
+            assert_eq!(to_decimal(&to_type, down), expected([12, 13, -12, 12]));
+            assert_eq!(to_decimal(&to_type, half_even), expected([12, 14, -12, 13]));
+        }
+
+        // 15, 25, -25 and 27 at scale -1
+        let to_type = DataType::Decimal128(10, -1);
+        let array = Int32Array::from(vec![15, 25, -25, 27]);
+        let to_int_decimal = |mode| {
+            let array = cast(&array, &to_type, mode);
+            array.as_primitive::<Decimal128Type>().values().to_vec()
+        };
+        assert_eq!(to_int_decimal(None), vec![1, 2, -2, 2]);
+        assert_eq!(to_int_decimal(half_up), vec![2, 3, -3, 3]);
+        assert_eq!(to_int_decimal(half_even), vec![2, 2, -2, 3]);
+
+        let array = Float64Array::from(vec![0.125, 0.135, -0.125, 0.1271]);
+        let to_type = DataType::Decimal128(10, 2);
+        let from_float = |mode| {
+            let array = cast(&array, &to_type, mode);
+            array.as_primitive::<Decimal128Type>().values().to_vec()
+        };
+        assert_eq!(from_float(None), vec![13, 14, -13, 13]);
+        assert_eq!(from_float(down), vec![12, 13, -12, 12]);
+        assert_eq!(from_float(half_even), vec![12, 14, -12, 13]);
+
+        // Rounding happens before checking for overflow
+        let array = Float32Array::from(vec![Some(126.5), Some(127.5), Some(-128.5), None]);
+        let to_int8 = |mode| {
+            let array = cast(&array, &DataType::Int8, mode);
+            array.as_primitive::<Int8Type>().iter().collect::<Vec<_>>()
+        };
+        assert_eq!(to_int8(None), vec![Some(126), Some(127), Some(-128), None]);
+        assert_eq!(to_int8(half_up), vec![Some(127), None, None, None]);
+        assert_eq!(to_int8(half_even), vec![Some(126), None, Some(-128), None]);
+
+        let array = Float16Array::from(vec![f16::from_f32(2.5), f16::from_f32(-3.5)]);
+        let array = cast(&array, &DataType::UInt8, half_even);
+        let array = array.as_primitive::<UInt8Type>();
+        assert_eq!(array.iter().collect::<Vec<_>>(), vec![Some(2), None]);
+    }
+
     #[test]
     fn test_cast_floating_point_to_decimal128_precision_overflow() {
         let array = Float64Array::from(vec![1.1]);
@@ -8212,6 +8797,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -8223,6 +8811,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8243,6 +8834,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -8254,6 +8848,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8274,6 +8871,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -8285,6 +8885,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8305,6 +8908,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -8316,6 +8922,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8695,6 +9304,9 @@ mod tests {
         let option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            list_delimiter: None,
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
         };
         let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
         assert!(casted_err
@@ -8737,6 +9349,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -8748,6 +9363,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal128 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
@@ -8820,6 +9438,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -8831,6 +9452,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal256 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
@@ -8977,6 +9601,9 @@ mod tests {
                 &CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    list_delimiter: None,
+                    date64_policy: Date64Policy::Preserve,
+                    rounding_mode: None,
                 },
             )
             .unwrap();
@@ -9028,6 +9655,9 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            list_delimiter: None,
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
         };
         let array = cast_with_options(&s, &DataType::Utf8, &options).unwrap();
         let a = array.as_string::<i32>();
@@ -9124,6 +9754,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -9135,6 +9768,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal128 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9150,6 +9786,9 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_ok());
@@ -9161,6 +9800,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal256 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9205,6 +9847,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_err());
@@ -9235,6 +9880,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_err());
@@ -9265,6 +9913,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         );
         assert!(casted_array.is_err());
@@ -9288,6 +9939,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         )
         .unwrap();
@@ -9315,6 +9969,9 @@ mod tests {
         let fallible = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            list_delimiter: None,
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
         };
 
         // from interval month day nano to duration second
@@ -9478,6 +10135,9 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                list_delimiter: None,
+                date64_policy: Date64Policy::Preserve,
+                rounding_mode: None,
             },
         )
         .unwrap();
@@ -9539,6 +10199,9 @@ mod tests {
     const CAST_OPTIONS: CastOptions<'static> = CastOptions {
         safe: true,
         format_options: FormatOptions::new(),
+        list_delimiter: None,
+        date64_policy: Date64Policy::Preserve,
+        rounding_mode: None,
     };
 
     #[test]
@@ -9552,6 +10215,9 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default().with_null("null"),
+            list_delimiter: None,
+            date64_policy: Date64Policy::Preserve,
+            rounding_mode: None,
         };
         let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
             Some(vec![Some(0), Some(1), Some(2)]),
@@ -9720,4 +10386,32 @@ mod tests {
             "Cast non-nullable to non-nullable struct field returning null should fail",
         );
     }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_cast_with_options_parallel() {
+        let strings: StringArray = (0..1000)
+            .map(|i| (i % 7 != 0).then(|| format!("{}.{}", i, i % 3)))
+            .collect();
+        let sliced = strings.slice(3, 995);
+
+        let options = CastOptions::default();
+        for to_type in [
+            DataType::Float64,
+            DataType::Utf8,
+            DataType::Decimal128(10, 2),
+        ] {
+            let expected = cast_with_options(&sliced, &to_type, &options).unwrap();
+            let actual = cast_with_options_parallel(&sliced, &to_type, &options, 64).unwrap();
+            assert_eq!(actual.as_ref(), expected.as_ref());
+        }
+
+        // Errors from any chunk are returned
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options_parallel(&strings, &DataType::Int32, &options, 64).unwrap_err();
+        assert!(matches!(err, ArrowError::CastError(_)), "{err}");
+    }
 }
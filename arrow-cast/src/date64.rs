@@ -0,0 +1,234 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Validation and normalization of [`Date64Array`]
+//!
+//! The Arrow specification requires [`DataType::Date64`] values to be a whole number
+//! of days, that is milliseconds since the UNIX epoch evenly divisible by
+//! [`MILLISECONDS_IN_DAY`]. Values with a non-zero time of day are nonetheless
+//! representable, and compare unequal to the same date at midnight.
+//!
+//! ```
+//! # use arrow_array::Date64Array;
+//! # use arrow_cast::date64::{normalize_date64, validate_date64};
+//! let array = Date64Array::from(vec![Some(86_400_000), None, Some(86_400_001)]);
+//! assert!(validate_date64(&array).is_err());
+//!
+//! let normalized = normalize_date64(&array).unwrap();
+//! assert_eq!(normalized, Date64Array::from(vec![Some(86_400_000), None, Some(86_400_000)]));
+//! validate_date64(&normalized).unwrap();
+//! ```
+
+use arrow_array::temporal_conversions::MILLISECONDS_IN_DAY;
+use arrow_array::types::Date64Type;
+use arrow_array::{make_array, Array, ArrayRef, Date64Array, RecordBatch};
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType};
+
+/// How to handle [`DataType::Date64`] values that are not aligned to midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Date64Policy {
+    /// Leave values unchanged
+    #[default]
+    Preserve,
+    /// Truncate values to midnight of the same day, see [`normalize_date64`]
+    Truncate,
+    /// Return an error for values not aligned to midnight, see [`validate_date64`]
+    Error,
+}
+
+/// Returns an error if any non-null value of `array` is not aligned to midnight
+pub fn validate_date64(array: &Date64Array) -> Result<(), ArrowError> {
+    let invalid = array
+        .iter()
+        .enumerate()
+        .find_map(|(idx, v)| v.filter(|v| v % MILLISECONDS_IN_DAY != 0).map(|v| (idx, v)));
+
+    match invalid {
+        Some((idx, v)) => Err(ArrowError::InvalidArgumentError(format!(
+            "Date64 value {v} at index {idx} is not a multiple of {MILLISECONDS_IN_DAY} milliseconds"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Truncates the non-null values of `array` to midnight of the same day
+///
+/// Values before the UNIX epoch are truncated towards negative infinity, so that
+/// they remain within the same day. Returns an error on overflow
+pub fn normalize_date64(array: &Date64Array) -> Result<Date64Array, ArrowError> {
+    array.try_unary::<_, Date64Type, _>(|v| {
+        v.checked_sub(v.rem_euclid(MILLISECONDS_IN_DAY))
+            .ok_or_else(|| {
+                ArrowError::ComputeError(format!("Overflow normalizing Date64 value {v}"))
+            })
+    })
+}
+
+/// Applies `policy` to all [`DataType::Date64`] values of `array`, including
+/// those of nested types such as lists and structs
+pub fn apply_date64_policy(
+    array: &dyn Array,
+    policy: Date64Policy,
+) -> Result<ArrayRef, ArrowError> {
+    if policy == Date64Policy::Preserve || !contains_date64(array.data_type()) {
+        return Ok(make_array(array.to_data()));
+    }
+    apply_date64_policy_data(array.to_data(), policy).map(make_array)
+}
+
+/// Applies `policy` to all [`DataType::Date64`] values of the columns of `batch`,
+/// see [`apply_date64_policy`]
+pub fn apply_date64_policy_batch(
+    batch: &RecordBatch,
+    policy: Date64Policy,
+) -> Result<RecordBatch, ArrowError> {
+    if policy == Date64Policy::Preserve {
+        return Ok(batch.clone());
+    }
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|c| match contains_date64(c.data_type()) {
+            true => apply_date64_policy(c.as_ref(), policy),
+            false => Ok(c.clone()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    RecordBatch::try_new(batch.schema(), columns)
+}
+
+fn apply_date64_policy_data(
+    data: ArrayData,
+    policy: Date64Policy,
+) -> Result<ArrayData, ArrowError> {
+    if data.data_type() == &DataType::Date64 {
+        let array = Date64Array::from(data);
+        return match policy {
+            Date64Policy::Preserve => Ok(array.into_data()),
+            Date64Policy::Truncate => Ok(normalize_date64(&array)?.into_data()),
+            Date64Policy::Error => validate_date64(&array).map(|_| array.into_data()),
+        };
+    }
+
+    let children = data
+        .child_data()
+        .iter()
+        .map(|c| match contains_date64(c.data_type()) {
+            true => apply_date64_policy_data(c.clone(), policy),
+            false => Ok(c.clone()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    data.into_builder().child_data(children).build()
+}
+
+/// Returns true if `data_type` is or contains [`DataType::Date64`]
+fn contains_date64(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Date64 => true,
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::FixedSizeList(f, _)
+        | DataType::Map(f, _) => contains_date64(f.data_type()),
+        DataType::Struct(fields) => fields.iter().any(|f| contains_date64(f.data_type())),
+        DataType::Union(fields, _) => fields.iter().any(|(_, f)| contains_date64(f.data_type())),
+        DataType::Dictionary(_, v) => contains_date64(v),
+        DataType::RunEndEncoded(_, v) => contains_date64(v.data_type()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Int32Array, ListArray, StructArray};
+    use arrow_schema::{Field, Schema};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_validate_normalize() {
+        let array = Date64Array::from(vec![
+            Some(0),
+            None,
+            Some(MILLISECONDS_IN_DAY * 3 + 1),
+            Some(-1),
+            Some(-MILLISECONDS_IN_DAY),
+        ]);
+        let err = validate_date64(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Date64 value 259200001 at index 2 is not a multiple of 86400000 milliseconds"
+        );
+
+        let normalized = normalize_date64(&array).unwrap();
+        let expected = Date64Array::from(vec![
+            Some(0),
+            None,
+            Some(MILLISECONDS_IN_DAY * 3),
+            Some(-MILLISECONDS_IN_DAY),
+            Some(-MILLISECONDS_IN_DAY),
+        ]);
+        assert_eq!(normalized, expected);
+        validate_date64(&normalized).unwrap();
+
+        let err = normalize_date64(&Date64Array::from(vec![i64::MIN])).unwrap_err();
+        assert!(
+            err.to_string().contains("Overflow normalizing Date64"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_apply_date64_policy() {
+        let dates = Date64Array::from(vec![Some(1), None, Some(MILLISECONDS_IN_DAY)]);
+        let list = ListArray::new(
+            Arc::new(Field::new("item", DataType::Date64, true)),
+            arrow_buffer::OffsetBuffer::new(vec![0, 1, 3].into()),
+            Arc::new(dates.clone()),
+            None,
+        );
+        let ints = Int32Array::from(vec![1, 2]);
+        let strukt = StructArray::try_from(vec![
+            ("list", Arc::new(list) as ArrayRef),
+            ("ints", Arc::new(ints) as ArrayRef),
+        ])
+        .unwrap();
+
+        let preserved = apply_date64_policy(&strukt, Date64Policy::Preserve).unwrap();
+        assert_eq!(preserved.to_data(), strukt.to_data());
+
+        let err = apply_date64_policy(&strukt, Date64Policy::Error).unwrap_err();
+        assert!(
+            err.to_string().contains("Date64 value 1 at index 0"),
+            "{err}"
+        );
+
+        let truncated = apply_date64_policy(&strukt, Date64Policy::Truncate).unwrap();
+        let list = truncated.as_struct().column(0).as_list::<i32>();
+        let values = list.values().as_primitive::<Date64Type>();
+        assert_eq!(
+            values,
+            &Date64Array::from(vec![Some(0), None, Some(MILLISECONDS_IN_DAY)])
+        );
+
+        let schema = Arc::new(Schema::new(vec![Field::new("d", DataType::Date64, true)]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(dates)]).unwrap();
+        let truncated = apply_date64_policy_batch(&batch, Date64Policy::Truncate).unwrap();
+        validate_date64(truncated.column(0).as_primitive()).unwrap();
+        assert!(apply_date64_policy_batch(&batch, Date64Policy::Error).is_err());
+    }
+}
@@ -272,6 +272,103 @@ impl<'a> ArrayFormatter<'a> {
     }
 }
 
+/// Implements [`Display`] for all the values of an [`Array`], see [`ArrayDisplayExt`]
+///
+/// Values are formatted with an [`ArrayFormatter`], separated by `", "` and enclosed
+/// in square brackets. By default, all values are displayed, with nulls formatted as
+/// `null`.
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_cast::display::ArrayDisplayExt;
+/// let array = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+/// assert_eq!(array.display().to_string(), "[1, null, 3, 4]");
+///
+/// let truncated = array.display().with_max_elements(Some(2));
+/// assert_eq!(truncated.to_string(), "[1, null, ...2 more]");
+/// ```
+pub struct ArrayDisplay<'a> {
+    array: &'a dyn Array,
+    options: FormatOptions<'a>,
+    max_elements: Option<usize>,
+}
+
+impl<'a> ArrayDisplay<'a> {
+    /// Create a new [`ArrayDisplay`] for `array`
+    pub fn new(array: &'a dyn Array) -> Self {
+        Self {
+            array,
+            options: FormatOptions::new().with_null("null"),
+            max_elements: None,
+        }
+    }
+
+    /// Overrides the [`FormatOptions`] used to format the values
+    ///
+    /// Defaults to [`FormatOptions::default`], with nulls formatted as `null`
+    pub fn with_options(mut self, options: FormatOptions<'a>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Limits the number of values displayed, with the number of remaining values
+    /// displayed in their place
+    ///
+    /// Defaults to `None`, displaying all values
+    pub fn with_max_elements(mut self, max_elements: Option<usize>) -> Self {
+        self.max_elements = max_elements;
+        self
+    }
+}
+
+impl<'a> Display for ArrayDisplay<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let formatter = match ArrayFormatter::try_new(self.array, &self.options) {
+            Ok(formatter) => formatter,
+            Err(e) if self.options.safe => return write!(f, "ERROR: {e}"),
+            Err(_) => return Err(std::fmt::Error),
+        };
+
+        let len = self.array.len();
+        let shown = self.max_elements.map(|max| max.min(len)).unwrap_or(len);
+        f.write_char('[')?;
+        for idx in 0..shown {
+            if idx != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", formatter.value(idx))?;
+        }
+        if shown < len {
+            if shown != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "...{} more", len - shown)?;
+        }
+        f.write_char(']')
+    }
+}
+
+/// Extension trait providing [`Display`] for all arrays, see [`ArrayDisplay`]
+///
+/// This allows logging arrays without first converting them to a [`RecordBatch`]
+/// for pretty printing
+pub trait ArrayDisplayExt {
+    /// Returns an [`ArrayDisplay`] implementing [`Display`] for this array
+    fn display(&self) -> ArrayDisplay<'_>;
+}
+
+impl<T: Array> ArrayDisplayExt for T {
+    fn display(&self) -> ArrayDisplay<'_> {
+        ArrayDisplay::new(self)
+    }
+}
+
+impl ArrayDisplayExt for dyn Array + '_ {
+    fn display(&self) -> ArrayDisplay<'_> {
+        ArrayDisplay::new(self)
+    }
+}
+
 fn make_formatter<'a>(
     array: &'a dyn Array,
     options: &FormatOptions<'a>,
@@ -984,6 +1081,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_array_display() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(array.display().to_string(), "[1, null, 3]");
+        assert_eq!(
+            array.display().with_max_elements(Some(5)).to_string(),
+            "[1, null, 3]"
+        );
+        assert_eq!(
+            array.display().with_max_elements(Some(1)).to_string(),
+            "[1, ...2 more]"
+        );
+        assert_eq!(
+            array.display().with_max_elements(Some(0)).to_string(),
+            "[...3 more]"
+        );
+        let options = FormatOptions::new().with_null("NULL");
+        assert_eq!(
+            array.display().with_options(options).to_string(),
+            "[1, NULL, 3]"
+        );
+
+        let empty = StringArray::from(Vec::<&str>::new());
+        assert_eq!(empty.display().to_string(), "[]");
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+        ]);
+        let array: ArrayRef = std::sync::Arc::new(list);
+        assert_eq!(array.display().to_string(), "[[1, 2], null, []]");
+        assert_eq!(array.as_ref().display().to_string(), "[[1, 2], null, []]");
+
+        let dict: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        assert_eq!(format!("{}", dict.display()), "[a, b, a]");
+    }
+
     fn format_array(array: &dyn Array, fmt: &FormatOptions) -> Vec<String> {
         let fmt = ArrayFormatter::try_new(array, fmt).unwrap();
         (0..array.len()).map(|x| fmt.value(x).to_string()).collect()
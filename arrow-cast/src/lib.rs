@@ -19,9 +19,12 @@
 
 pub mod cast;
 pub use cast::*;
+pub mod date64;
 pub mod display;
 pub mod parse;
 #[cfg(feature = "prettyprint")]
 pub mod pretty;
+pub mod rounding;
 
 pub mod base64;
+pub mod timestamp;
@@ -19,7 +19,7 @@ use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::{ArrowNativeTypeOp, ArrowPrimitiveType};
 use arrow_buffer::ArrowNativeType;
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, TimeUnit};
 use chrono::prelude::*;
 use half::f16;
 use std::str::FromStr;
@@ -792,11 +792,77 @@ pub fn parse_interval_month_day_nano(
     Ok(IntervalMonthDayNanoType::make_value(months, days, nanos))
 }
 
+/// Parse a duration with the given [`TimeUnit`], returning the number of `unit`s
+///
+/// Accepts an integer number of `unit`s, an ISO 8601 duration such as `PT1H30M`, or
+/// a Postgres style interval such as `1 hour 30 minutes`. Durations containing years
+/// or months are rejected, as they do not have a fixed length, as are durations that
+/// cannot be represented exactly in `unit`
+pub fn parse_duration(value: &str, unit: TimeUnit) -> Result<i64, ArrowError> {
+    if let Ok(v) = value.parse::<i64>() {
+        return Ok(v);
+    }
+
+    let (default_unit, nanos_per_unit) = match unit {
+        TimeUnit::Second => (IntervalUnit::Second, NANOS_PER_SECOND),
+        TimeUnit::Millisecond => (IntervalUnit::Millisecond, NANOS_PER_MILLIS),
+        TimeUnit::Microsecond => (IntervalUnit::Microsecond, 1_000),
+        TimeUnit::Nanosecond => (IntervalUnit::Nanosecond, 1),
+    };
+    let interval = Interval::parse(value, &IntervalParseConfig::new(default_unit))?;
+    if interval.months != 0 {
+        return Err(ArrowError::ParseError(format!(
+            "Unable to represent {value:?} as a duration as it contains months"
+        )));
+    }
+
+    let nanos = (interval.days as i64)
+        .mul_checked(NANOS_PER_DAY)?
+        .add_checked(interval.nanos)?;
+    if nanos % nanos_per_unit != 0 {
+        return Err(ArrowError::ParseError(format!(
+            "Unable to represent {value:?} as a duration in {unit:?} without loss of precision"
+        )));
+    }
+    Ok(nanos / nanos_per_unit)
+}
+
+macro_rules! parser_duration {
+    ($t:ty, $unit:expr) => {
+        impl Parser for $t {
+            fn parse(string: &str) -> Option<Self::Native> {
+                parse_duration(string, $unit).ok()
+            }
+        }
+    };
+}
+parser_duration!(DurationSecondType, TimeUnit::Second);
+parser_duration!(DurationMillisecondType, TimeUnit::Millisecond);
+parser_duration!(DurationMicrosecondType, TimeUnit::Microsecond);
+parser_duration!(DurationNanosecondType, TimeUnit::Nanosecond);
+
+impl Parser for IntervalYearMonthType {
+    fn parse(string: &str) -> Option<Self::Native> {
+        parse_interval_year_month(string).ok()
+    }
+}
+
+impl Parser for IntervalDayTimeType {
+    fn parse(string: &str) -> Option<Self::Native> {
+        parse_interval_day_time(string).ok()
+    }
+}
+
+impl Parser for IntervalMonthDayNanoType {
+    fn parse(string: &str) -> Option<Self::Native> {
+        parse_interval_month_day_nano(string).ok()
+    }
+}
+
 const NANOS_PER_MILLIS: i64 = 1_000_000;
 const NANOS_PER_SECOND: i64 = 1_000 * NANOS_PER_MILLIS;
 const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SECOND;
 const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MINUTE;
-#[cfg(test)]
 const NANOS_PER_DAY: i64 = 24 * NANOS_PER_HOUR;
 
 #[rustfmt::skip]
@@ -971,9 +1037,13 @@ impl Interval {
     }
 
     /// Parse string value in traditional Postgres format such as
-    /// `1 year 2 months 3 days 4 hours 5 minutes 6 seconds`
+    /// `1 year 2 months 3 days 4 hours 5 minutes 6 seconds`, or
+    /// in ISO 8601 format such as `P1Y2M3DT4H5M6S`
     fn parse(value: &str, config: &IntervalParseConfig) -> Result<Self, ArrowError> {
-        let components = parse_interval_components(value, config)?;
+        let components = match parse_iso8601_components(value) {
+            Some(components) => components?,
+            None => parse_interval_components(value, config)?,
+        };
 
         components
             .into_iter()
@@ -1212,6 +1282,72 @@ fn parse_interval_components(
     Ok(result.collect::<Vec<_>>())
 }
 
+/// Parse an ISO 8601 duration such as `P1Y2M3DT4H5M6.5S` into a vector of interval
+/// components, returning `None` if `value` does not start with `P` or `-P`
+///
+/// A leading `-` negates the whole duration, and only the seconds may be fractional
+fn parse_iso8601_components(
+    value: &str,
+) -> Option<Result<Vec<(IntervalAmount, IntervalUnit)>, ArrowError>> {
+    let (negative, rest) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value),
+    };
+    let rest = rest.strip_prefix(['P', 'p'])?;
+    let err = || ArrowError::ParseError(format!("Invalid ISO 8601 duration: {value:?}"));
+
+    let mut components = vec![];
+    let mut observed_interval_types = 0;
+    let mut in_time = false;
+    let mut start = 0;
+    for (idx, c) in rest.char_indices() {
+        if c.is_ascii_digit() || c == '.' {
+            continue;
+        }
+        let number = &rest[start..idx];
+        start = idx + c.len_utf8();
+
+        let unit = match (c.to_ascii_uppercase(), in_time) {
+            ('T', false) if number.is_empty() => {
+                in_time = true;
+                continue;
+            }
+            ('Y', false) => IntervalUnit::Year,
+            ('M', false) => IntervalUnit::Month,
+            ('W', false) => IntervalUnit::Week,
+            ('D', false) => IntervalUnit::Day,
+            ('H', true) => IntervalUnit::Hour,
+            ('M', true) => IntervalUnit::Minute,
+            ('S', true) => IntervalUnit::Second,
+            _ => return Some(Err(err())),
+        };
+
+        if number.is_empty()
+            || (number.contains('.') && !matches!(unit, IntervalUnit::Second))
+            || observed_interval_types & (unit as u16) != 0
+        {
+            return Some(Err(err()));
+        }
+        observed_interval_types |= unit as u16;
+
+        let amount = match IntervalAmount::from_str(number) {
+            Ok(amount) if negative => IntervalAmount {
+                integer: -amount.integer,
+                frac: -amount.frac,
+            },
+            Ok(amount) => amount,
+            Err(_) => return Some(Err(err())),
+        };
+        components.push((amount, unit));
+    }
+
+    // Reject trailing numbers without a designator, and `P` or `PT` without components
+    if start != rest.len() || components.is_empty() || rest.ends_with(['T', 't']) {
+        return Some(Err(err()));
+    }
+    Some(Ok(components))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2010,6 +2146,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_iso8601_interval() {
+        let config = IntervalParseConfig::new(IntervalUnit::Month);
+
+        assert_eq!(
+            Interval::new(
+                14,
+                3,
+                4 * NANOS_PER_HOUR + 5 * NANOS_PER_MINUTE + 6 * NANOS_PER_SECOND
+            ),
+            Interval::parse("P1Y2M3DT4H5M6S", &config).unwrap(),
+        );
+        assert_eq!(
+            Interval::new(0, 14, 0),
+            Interval::parse("P2W", &config).unwrap(),
+        );
+        assert_eq!(
+            Interval::new(0, 0, 2 * NANOS_PER_MINUTE + NANOS_PER_SECOND / 2),
+            Interval::parse("PT2M0.5S", &config).unwrap(),
+        );
+        assert_eq!(
+            Interval::new(-1, -1, -NANOS_PER_HOUR),
+            Interval::parse("-P1M1DT1H", &config).unwrap(),
+        );
+
+        for invalid in [
+            "P", "PT", "P1", "P1H", "PT1D", "P1.5D", "P1D1D", "P1DT", "PX",
+        ] {
+            let err = Interval::parse(invalid, &config).unwrap_err();
+            assert!(
+                err.to_string().contains("Invalid ISO 8601 duration"),
+                "{invalid}: {err}"
+            );
+        }
+
+        assert_eq!(
+            parse_interval_year_month("P1Y6M").unwrap(),
+            IntervalYearMonthType::make_value(1, 6)
+        );
+        assert_eq!(
+            parse_interval_day_time("P1DT0.25S").unwrap(),
+            IntervalDayTimeType::make_value(1, 250)
+        );
+        assert_eq!(
+            IntervalMonthDayNanoType::parse("1 month 2 days").unwrap(),
+            IntervalMonthDayNanoType::make_value(1, 2, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("12", TimeUnit::Millisecond).unwrap(), 12);
+        assert_eq!(parse_duration("PT1M30S", TimeUnit::Second).unwrap(), 90);
+        assert_eq!(
+            parse_duration("P1DT0.5S", TimeUnit::Millisecond).unwrap(),
+            86_400_500
+        );
+        assert_eq!(
+            parse_duration("-PT1.000001S", TimeUnit::Microsecond).unwrap(),
+            -1_000_001
+        );
+        assert_eq!(
+            parse_duration("1 hour 1 second", TimeUnit::Second).unwrap(),
+            3601
+        );
+        assert_eq!(
+            parse_duration("2 weeks", TimeUnit::Nanosecond).unwrap(),
+            14 * NANOS_PER_DAY
+        );
+        assert_eq!(DurationMillisecondType::parse("PT2S"), Some(2000));
+
+        let err = parse_duration("P1M", TimeUnit::Second).unwrap_err();
+        assert!(err.to_string().contains("contains months"), "{err}");
+
+        let err = parse_duration("PT0.5S", TimeUnit::Second).unwrap_err();
+        assert!(err.to_string().contains("loss of precision"), "{err}");
+
+        assert!(parse_duration("P100000000D", TimeUnit::Nanosecond).is_err());
+        assert_eq!(DurationSecondType::parse("foo"), None);
+    }
+
     #[test]
     fn test_interval_amount_parsing() {
         // integer
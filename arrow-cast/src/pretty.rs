@@ -22,7 +22,8 @@ use crate::display::{ArrayFormatter, FormatOptions};
 use arrow_array::{Array, ArrayRef, RecordBatch};
 use arrow_schema::ArrowError;
 use comfy_table::{Cell, Table};
-use std::fmt::Display;
+use std::borrow::Cow;
+use std::fmt::{Display, Write};
 
 /// Create a visual representation of record batches
 pub fn pretty_format_batches(results: &[RecordBatch]) -> Result<impl Display, ArrowError> {
@@ -129,6 +130,241 @@ fn create_column(
     Ok(table)
 }
 
+/// Options for rendering record batches with [`record_batches_to_html`]
+/// and [`record_batches_to_markdown`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RenderOptions<'a> {
+    format_options: FormatOptions<'a>,
+    max_cell_width: Option<usize>,
+}
+
+impl<'a> Default for RenderOptions<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> RenderOptions<'a> {
+    /// Create a new [`RenderOptions`], displaying formatting errors and
+    /// without limiting the width of cells
+    pub fn new() -> Self {
+        Self {
+            format_options: FormatOptions::new().with_display_error(true),
+            max_cell_width: None,
+        }
+    }
+
+    /// Set the [`FormatOptions`] used to format values
+    pub fn with_format_options(self, format_options: FormatOptions<'a>) -> Self {
+        Self {
+            format_options,
+            ..self
+        }
+    }
+
+    /// Truncate the header and values of cells longer than `max_cell_width` characters,
+    /// replacing the truncated characters with `…`
+    pub fn with_max_cell_width(self, max_cell_width: Option<usize>) -> Self {
+        Self {
+            max_cell_width,
+            ..self
+        }
+    }
+}
+
+/// Render record batches as an HTML `<table>`
+///
+/// Headers and values are escaped, so that they are displayed verbatim. Returns
+/// an empty string if `results` is empty
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_cast::pretty::{record_batches_to_html, RenderOptions};
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+///     ("b", Arc::new(StringArray::from(vec!["<b>", "x & y"])) as ArrayRef),
+/// ]).unwrap();
+///
+/// let html = record_batches_to_html(&[batch], &RenderOptions::default()).unwrap();
+/// assert_eq!(
+///     html,
+///     "<table>\n\
+///      <thead>\n<tr><th>a</th><th>b</th></tr>\n</thead>\n\
+///      <tbody>\n\
+///      <tr><td>1</td><td>&lt;b&gt;</td></tr>\n\
+///      <tr><td>2</td><td>x &amp; y</td></tr>\n\
+///      </tbody>\n\
+///      </table>\n"
+/// );
+/// ```
+pub fn record_batches_to_html(
+    results: &[RecordBatch],
+    options: &RenderOptions,
+) -> Result<String, ArrowError> {
+    let (header, rows) = match render_cells(results, options)? {
+        Some(cells) => cells,
+        None => return Ok(String::new()),
+    };
+
+    let mut out = String::from("<table>\n<thead>\n<tr>");
+    for name in &header {
+        write!(out, "<th>{}</th>", escape_html(name)).unwrap();
+    }
+    out.push_str("</tr>\n</thead>\n<tbody>\n");
+    for row in &rows {
+        out.push_str("<tr>");
+        for value in row {
+            write!(out, "<td>{}</td>", escape_html(value)).unwrap();
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</tbody>\n</table>\n");
+    Ok(out)
+}
+
+/// Render record batches as a [GitHub Flavored Markdown] table
+///
+/// Pipes within headers and values are escaped, and line breaks replaced with `<br>`,
+/// so that each row of the batches is rendered as a single row of the table. Columns
+/// are padded to a common width. Returns an empty string if `results` is empty
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_cast::pretty::{record_batches_to_markdown, RenderOptions};
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 200])) as ArrayRef),
+///     ("b", Arc::new(StringArray::from(vec!["x|y", "a long value"])) as ArrayRef),
+/// ]).unwrap();
+///
+/// let options = RenderOptions::default().with_max_cell_width(Some(8));
+/// let markdown = record_batches_to_markdown(&[batch], &options).unwrap();
+/// assert_eq!(
+///     markdown,
+///     "| a   | b        |\n\
+///      |-----|----------|\n\
+///      | 1   | x\\|y     |\n\
+///      | 200 | a long … |\n"
+/// );
+/// ```
+///
+/// [GitHub Flavored Markdown]: https://github.github.com/gfm/#tables-extension-
+pub fn record_batches_to_markdown(
+    results: &[RecordBatch],
+    options: &RenderOptions,
+) -> Result<String, ArrowError> {
+    let (header, rows) = match render_cells(results, options)? {
+        Some(cells) => cells,
+        None => return Ok(String::new()),
+    };
+
+    let header: Vec<_> = header.iter().map(|x| escape_markdown(x)).collect();
+    let rows: Vec<Vec<_>> = rows
+        .iter()
+        .map(|row| row.iter().map(|x| escape_markdown(x)).collect())
+        .collect();
+
+    // The delimiter row requires at least three dashes
+    let mut widths: Vec<_> = header.iter().map(|x| x.chars().count().max(3)).collect();
+    for row in &rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    write_markdown_row(&mut out, &header, &widths);
+    for width in &widths {
+        write!(out, "|{}", "-".repeat(width + 2)).unwrap();
+    }
+    out.push_str("|\n");
+    for row in &rows {
+        write_markdown_row(&mut out, row, &widths);
+    }
+    Ok(out)
+}
+
+fn write_markdown_row(out: &mut String, values: &[String], widths: &[usize]) {
+    for (value, width) in values.iter().zip(widths) {
+        write!(out, "| {value:width$} ").unwrap();
+    }
+    out.push_str("|\n");
+}
+
+/// A header, and rows of formatted values
+type Cells = (Vec<String>, Vec<Vec<String>>);
+
+/// Formats the header and values of `results`, truncated to the maximum cell width,
+/// returning `None` if `results` is empty
+fn render_cells(
+    results: &[RecordBatch],
+    options: &RenderOptions,
+) -> Result<Option<Cells>, ArrowError> {
+    if results.is_empty() {
+        return Ok(None);
+    }
+
+    let truncate = |value: String| match options.max_cell_width {
+        Some(max) if value.chars().count() > max => {
+            let mut truncated: String = value.chars().take(max.saturating_sub(1)).collect();
+            truncated.push('…');
+            truncated
+        }
+        _ => value,
+    };
+
+    let schema = results[0].schema();
+    let header = schema
+        .fields()
+        .iter()
+        .map(|f| truncate(f.name().clone()))
+        .collect();
+
+    let mut rows = vec![];
+    for batch in results {
+        let formatters = batch
+            .columns()
+            .iter()
+            .map(|c| ArrayFormatter::try_new(c.as_ref(), &options.format_options))
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        for row in 0..batch.num_rows() {
+            let cells = formatters
+                .iter()
+                .map(|f| truncate(f.value(row).to_string()))
+                .collect();
+            rows.push(cells);
+        }
+    }
+    Ok(Some((header, rows)))
+}
+
+fn escape_html(value: &str) -> Cow<'_, str> {
+    if !value.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(value);
+    }
+    let mut escaped = String::with_capacity(value.len() + 8);
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+fn escape_markdown(value: &str) -> String {
+    value
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1002,4 +1238,64 @@ mod tests {
         let actual: Vec<&str> = batch.lines().collect();
         assert_eq!(expected, actual, "Actual result:\n{batch}");
     }
+
+    #[test]
+    fn test_render_html_and_markdown() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "id",
+                Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef,
+            ),
+            (
+                "a \"quoted\" name",
+                Arc::new(StringArray::from(vec!["line\nbreak", "it's <fine>"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let batches = [batch.clone(), batch.slice(0, 1)];
+
+        let format_options = FormatOptions::default().with_null("null");
+        let options = RenderOptions::default().with_format_options(format_options);
+        let html = record_batches_to_html(&batches, &options).unwrap();
+        let expected = vec![
+            "<table>",
+            "<thead>",
+            "<tr><th>id</th><th>a &quot;quoted&quot; name</th></tr>",
+            "</thead>",
+            "<tbody>",
+            "<tr><td>1</td><td>line",
+            "break</td></tr>",
+            "<tr><td>null</td><td>it&#39;s &lt;fine&gt;</td></tr>",
+            "<tr><td>1</td><td>line",
+            "break</td></tr>",
+            "</tbody>",
+            "</table>",
+        ];
+        let actual: Vec<&str> = html.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{html}");
+
+        let markdown = record_batches_to_markdown(&batches, &options).unwrap();
+        let expected = vec![
+            "| id   | a \"quoted\" name |",
+            "|------|-----------------|",
+            "| 1    | line<br>break   |",
+            "| null | it's <fine>     |",
+            "| 1    | line<br>break   |",
+        ];
+        let actual: Vec<&str> = markdown.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{markdown}");
+
+        let options = options.with_max_cell_width(Some(4));
+        let markdown = record_batches_to_markdown(&batches[1..], &options).unwrap();
+        let expected = vec!["| id  | a \"… |", "|-----|------|", "| 1   | lin… |"];
+        let actual: Vec<&str> = markdown.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{markdown}");
+
+        let options = options.with_max_cell_width(Some(1));
+        let html = record_batches_to_html(&batches[1..], &options).unwrap();
+        assert!(html.contains("<tr><td>1</td><td>…</td></tr>"), "{html}");
+
+        assert_eq!(record_batches_to_html(&[], &options).unwrap(), "");
+        assert_eq!(record_batches_to_markdown(&[], &options).unwrap(), "");
+    }
 }
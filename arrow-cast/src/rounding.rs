@@ -0,0 +1,206 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Rounding of values cast to types of lower precision
+//!
+//! Casting a floating point or decimal value to an integer, or a decimal to a
+//! decimal of smaller scale, discards digits, which are rounded according to
+//! [`CastOptions::rounding_mode`](crate::CastOptions::rounding_mode)
+//!
+//! ```
+//! # use arrow_array::{Array, Float64Array};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::Int32Type;
+//! # use arrow_cast::{cast_with_options, CastOptions};
+//! # use arrow_cast::rounding::RoundingMode;
+//! # use arrow_schema::DataType;
+//! let array = Float64Array::from(vec![1.5, 2.5, -2.5, 2.7]);
+//! let cast = |mode| {
+//!     let options = CastOptions {
+//!         rounding_mode: mode,
+//!         ..Default::default()
+//!     };
+//!     let array = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+//!     array.as_primitive::<Int32Type>().values().to_vec()
+//! };
+//!
+//! assert_eq!(cast(None), vec![1, 2, -2, 2]);
+//! assert_eq!(cast(Some(RoundingMode::Down)), vec![1, 2, -2, 2]);
+//! assert_eq!(cast(Some(RoundingMode::HalfUp)), vec![2, 3, -3, 3]);
+//! assert_eq!(cast(Some(RoundingMode::HalfEven)), vec![2, 2, -2, 3]);
+//! ```
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float16Type, Float32Type, Float64Type};
+use arrow_array::{Array, ArrayRef, ArrowNativeTypeOp};
+use arrow_schema::{ArrowError, DataType};
+use half::f16;
+use num::Float;
+
+/// How to round values cast to a type of lower precision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards zero, discarding the excess digits
+    Down,
+    /// Round to the nearest value, with ties rounded away from zero
+    HalfUp,
+    /// Round to the nearest value, with ties rounded to the even value
+    HalfEven,
+}
+
+/// Divides `x` by the positive `div`, rounding the quotient according to `mode`
+pub(crate) fn div_rounded<T: ArrowNativeTypeOp>(x: T, div: T, mode: RoundingMode) -> T {
+    let d = x.div_wrapping(div);
+    let r = x.mod_wrapping(div);
+    if r.is_zero() {
+        return d;
+    }
+
+    let negative = x < T::ZERO;
+    let r = if negative { r.neg_wrapping() } else { r };
+    // Compare `r` to `div - r` as `2 * r` may overflow
+    let remaining = div.sub_wrapping(r);
+    let away = match mode {
+        RoundingMode::Down => false,
+        RoundingMode::HalfUp => r >= remaining,
+        RoundingMode::HalfEven => {
+            let odd = !d.mod_wrapping(T::ONE.add_wrapping(T::ONE)).is_zero();
+            r > remaining || (r == remaining && odd)
+        }
+    };
+    match (away, negative) {
+        (false, _) => d,
+        (true, false) => d.add_wrapping(T::ONE),
+        (true, true) => d.sub_wrapping(T::ONE),
+    }
+}
+
+/// Rounds `x` to an integral value according to `mode`
+pub(crate) fn round_float<F: Float>(x: F, mode: RoundingMode) -> F {
+    match mode {
+        RoundingMode::Down => x.trunc(),
+        RoundingMode::HalfUp => x.round(),
+        RoundingMode::HalfEven => {
+            let rounded = x.round();
+            let two = F::one() + F::one();
+            let tie = (x - x.trunc()).abs() == F::one() / two;
+            match tie && (rounded / two).fract() != F::zero() {
+                true => rounded - x.signum(),
+                false => rounded,
+            }
+        }
+    }
+}
+
+/// Rounds the values of the floating point `array` to integral values according to `mode`
+pub(crate) fn round_float_array(
+    array: &dyn Array,
+    mode: RoundingMode,
+) -> Result<ArrayRef, ArrowError> {
+    Ok(match array.data_type() {
+        DataType::Float16 => Arc::new(
+            array
+                .as_primitive::<Float16Type>()
+                .unary::<_, Float16Type>(|x| f16::from_f32(round_float(x.to_f32(), mode))),
+        ),
+        DataType::Float32 => Arc::new(
+            array
+                .as_primitive::<Float32Type>()
+                .unary::<_, Float32Type>(|x| round_float(x, mode)),
+        ),
+        DataType::Float64 => Arc::new(
+            array
+                .as_primitive::<Float64Type>()
+                .unary::<_, Float64Type>(|x| round_float(x, mode)),
+        ),
+        d => {
+            return Err(ArrowError::CastError(format!(
+                "Expected floating point array, got {d}"
+            )))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_div_rounded() {
+        let cases = [
+            (15_i64, [1, 2, 2]),
+            (25, [2, 3, 2]),
+            (26, [2, 3, 3]),
+            (24, [2, 2, 2]),
+            (-15, [-1, -2, -2]),
+            (-25, [-2, -3, -2]),
+            (-26, [-2, -3, -3]),
+            (30, [3, 3, 3]),
+            (-4, [0, 0, 0]),
+            (-5, [0, -1, 0]),
+        ];
+        let modes = [
+            RoundingMode::Down,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+        ];
+        for (x, expected) in cases {
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(div_rounded(x, 10, mode), expected, "{x} {mode:?}");
+            }
+        }
+
+        // Does not overflow for large divisors
+        let div = 10_i128.pow(38);
+        let x = div / 2 * 3;
+        assert_eq!(div_rounded(x, div, RoundingMode::HalfUp), 2);
+        assert_eq!(div_rounded(x, div, RoundingMode::HalfEven), 2);
+        assert_eq!(div_rounded(-x - 1, div, RoundingMode::HalfEven), -2);
+    }
+
+    #[test]
+    fn test_round_float() {
+        let cases = [
+            (0.5_f64, [0., 1., 0.]),
+            (1.5, [1., 2., 2.]),
+            (2.5, [2., 3., 2.]),
+            (2.4999, [2., 2., 2.]),
+            (-0.5, [0., -1., 0.]),
+            (-1.5, [-1., -2., -2.]),
+            (-2.5, [-2., -3., -2.]),
+            (-2.6, [-2., -3., -3.]),
+        ];
+        let modes = [
+            RoundingMode::Down,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfEven,
+        ];
+        for (x, expected) in cases {
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(round_float(x, mode), expected, "{x} {mode:?}");
+                assert_eq!(round_float(x as f32, mode), expected as f32, "{x} {mode:?}");
+            }
+        }
+        assert!(round_float(f64::NAN, RoundingMode::HalfEven).is_nan());
+        assert_eq!(
+            round_float(f64::INFINITY, RoundingMode::HalfEven),
+            f64::INFINITY
+        );
+    }
+}
@@ -0,0 +1,237 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of timestamps between [`TimeUnit`]
+//!
+//! Converting to a finer unit multiplies the values, which overflows for timestamps
+//! outside the range representable by the target unit, e.g. those after the year 2262
+//! for [`TimeUnit::Nanosecond`]. An [`OverflowPolicy`] determines how such values are
+//! handled. Converting to a coarser unit truncates the values towards zero, and
+//! never overflows.
+//!
+//! ```
+//! # use arrow_array::{Array, TimestampSecondArray};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::TimestampNanosecondType;
+//! # use arrow_cast::timestamp::{convert_timestamp_unit, OverflowPolicy};
+//! # use arrow_schema::TimeUnit;
+//! // 1970-01-01T00:00:01 and 3000-01-01T00:00:00
+//! let array = TimestampSecondArray::from(vec![1, 32503680000]).with_timezone("+01:00");
+//!
+//! let nanos = convert_timestamp_unit(&array, TimeUnit::Nanosecond, OverflowPolicy::Null).unwrap();
+//! let nanos = nanos.as_primitive::<TimestampNanosecondType>();
+//! assert_eq!(nanos.timezone(), Some("+01:00"));
+//! assert_eq!(nanos.value(0), 1_000_000_000);
+//! assert!(nanos.is_null(1));
+//!
+//! let nanos = convert_timestamp_unit(&array, TimeUnit::Nanosecond, OverflowPolicy::Saturate).unwrap();
+//! assert_eq!(nanos.as_primitive::<TimestampNanosecondType>().value(1), i64::MAX);
+//!
+//! assert!(convert_timestamp_unit(&array, TimeUnit::Nanosecond, OverflowPolicy::Error).is_err());
+//! ```
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::temporal_conversions::{MICROSECONDS, MILLISECONDS, NANOSECONDS};
+use arrow_array::types::*;
+use arrow_array::{Array, ArrayRef, ArrowNativeTypeOp, Int64Array};
+use arrow_schema::{ArrowError, DataType, TimeUnit};
+
+/// How to handle values that overflow when converting to a finer [`TimeUnit`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Return an error
+    Error,
+    /// Replace the value with null
+    Null,
+    /// Clamp the value to [`i64::MIN`] or [`i64::MAX`]
+    Saturate,
+}
+
+/// Converts the timestamp `array` to `to_unit`, preserving its timezone
+///
+/// Values that overflow are handled according to `policy`, see the
+/// [module documentation](self) for more details
+///
+/// Returns an error if `array` is not a timestamp array
+pub fn convert_timestamp_unit(
+    array: &dyn Array,
+    to_unit: TimeUnit,
+    policy: OverflowPolicy,
+) -> Result<ArrayRef, ArrowError> {
+    let (from_unit, tz) = match array.data_type() {
+        DataType::Timestamp(unit, tz) => (unit, tz.clone()),
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected timestamp array, got {d}"
+            )))
+        }
+    };
+
+    let values: Int64Array = match from_unit {
+        TimeUnit::Second => array
+            .as_primitive::<TimestampSecondType>()
+            .reinterpret_cast(),
+        TimeUnit::Millisecond => array
+            .as_primitive::<TimestampMillisecondType>()
+            .reinterpret_cast(),
+        TimeUnit::Microsecond => array
+            .as_primitive::<TimestampMicrosecondType>()
+            .reinterpret_cast(),
+        TimeUnit::Nanosecond => array
+            .as_primitive::<TimestampNanosecondType>()
+            .reinterpret_cast(),
+    };
+    let converted = convert_time_unit(&values, from_unit, &to_unit, policy)?;
+
+    Ok(match to_unit {
+        TimeUnit::Second => Arc::new(
+            converted
+                .reinterpret_cast::<TimestampSecondType>()
+                .with_timezone_opt(tz),
+        ),
+        TimeUnit::Millisecond => Arc::new(
+            converted
+                .reinterpret_cast::<TimestampMillisecondType>()
+                .with_timezone_opt(tz),
+        ),
+        TimeUnit::Microsecond => Arc::new(
+            converted
+                .reinterpret_cast::<TimestampMicrosecondType>()
+                .with_timezone_opt(tz),
+        ),
+        TimeUnit::Nanosecond => Arc::new(
+            converted
+                .reinterpret_cast::<TimestampNanosecondType>()
+                .with_timezone_opt(tz),
+        ),
+    })
+}
+
+/// Converts `values` in `from_unit` to `to_unit`, handling overflow according to `policy`
+pub(crate) fn convert_time_unit(
+    values: &Int64Array,
+    from_unit: &TimeUnit,
+    to_unit: &TimeUnit,
+    policy: OverflowPolicy,
+) -> Result<Int64Array, ArrowError> {
+    let from_size = time_unit_multiple(from_unit);
+    let to_size = time_unit_multiple(to_unit);
+    Ok(match from_size.cmp(&to_size) {
+        Ordering::Greater => {
+            let divisor = from_size / to_size;
+            values.unary(|v| v / divisor)
+        }
+        Ordering::Equal => values.clone(),
+        Ordering::Less => {
+            let mul = to_size / from_size;
+            match policy {
+                OverflowPolicy::Error => values.try_unary(|v| v.mul_checked(mul))?,
+                OverflowPolicy::Null => values.unary_opt(|v| v.checked_mul(mul)),
+                OverflowPolicy::Saturate => values.unary(|v| v.saturating_mul(mul)),
+            }
+        }
+    })
+}
+
+/// Get the time unit as a multiple of a second
+pub(crate) const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{TimestampMicrosecondArray, TimestampMillisecondArray};
+
+    #[test]
+    fn test_convert_timestamp_unit() {
+        let array = TimestampMillisecondArray::from(vec![
+            Some(1_500),
+            Some(-1_500),
+            None,
+            Some(i64::MAX / 10),
+            Some(i64::MIN / 10),
+        ])
+        .with_timezone("UTC");
+
+        let seconds =
+            convert_timestamp_unit(&array, TimeUnit::Second, OverflowPolicy::Error).unwrap();
+        let seconds = seconds.as_primitive::<TimestampSecondType>();
+        assert_eq!(seconds.timezone(), Some("UTC"));
+        assert_eq!(seconds.value(0), 1);
+        assert_eq!(seconds.value(1), -1);
+        assert!(seconds.is_null(2));
+
+        let micros =
+            convert_timestamp_unit(&array, TimeUnit::Microsecond, OverflowPolicy::Null).unwrap();
+        let expected = TimestampMicrosecondArray::from(vec![
+            Some(1_500_000),
+            Some(-1_500_000),
+            None,
+            None,
+            None,
+        ])
+        .with_timezone("UTC");
+        assert_eq!(micros.as_primitive::<TimestampMicrosecondType>(), &expected);
+
+        let micros =
+            convert_timestamp_unit(&array, TimeUnit::Microsecond, OverflowPolicy::Saturate)
+                .unwrap();
+        let micros = micros.as_primitive::<TimestampMicrosecondType>();
+        assert_eq!(micros.value(0), 1_500_000);
+        assert!(micros.is_null(2));
+        assert_eq!(micros.value(3), i64::MAX);
+        assert_eq!(micros.value(4), i64::MIN);
+
+        let err = convert_timestamp_unit(&array, TimeUnit::Microsecond, OverflowPolicy::Error)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Compute error: Overflow happened on: {} * 1000",
+                i64::MAX / 10
+            )
+        );
+
+        // Nulls are not checked for overflow
+        let array = TimestampMillisecondArray::from(vec![None, Some(1)]);
+        let array =
+            TimestampMillisecondArray::new(vec![i64::MAX, 1].into(), array.nulls().cloned());
+        let nanos =
+            convert_timestamp_unit(&array, TimeUnit::Nanosecond, OverflowPolicy::Error).unwrap();
+        assert!(nanos.is_null(0));
+
+        let err = convert_timestamp_unit(
+            &Int64Array::from(vec![1]),
+            TimeUnit::Second,
+            OverflowPolicy::Error,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected timestamp array, got Int64"
+        );
+    }
+}
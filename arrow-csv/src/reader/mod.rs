@@ -128,6 +128,7 @@ mod records;
 use arrow_array::builder::PrimitiveBuilder;
 use arrow_array::types::*;
 use arrow_array::*;
+use arrow_cast::date64::{apply_date64_policy_batch, Date64Policy};
 use arrow_cast::parse::{parse_decimal, string_to_datetime, Parser};
 use arrow_schema::*;
 use chrono::{TimeZone, Utc};
@@ -155,6 +156,22 @@ lazy_static! {
         r"^\d{4}-\d\d-\d\d[T ]\d\d:\d\d:\d\d\.\d{1,6}(?:[^\d].*)?$", //Timestamp(Microsecond)
         r"^\d{4}-\d\d-\d\d[T ]\d\d:\d\d:\d\d\.\d{1,9}(?:[^\d].*)?$", //Timestamp(Nanosecond)
     ]).unwrap();
+
+    /// ISO 8601 durations without years or months, order should match [`InferredDataType`]
+    static ref DURATION_REGEX_SET: RegexSet = RegexSet::new([
+        duration_regex(r"\d+S"), //Duration(Second)
+        duration_regex(r"\d+\.\d{1,3}S"), //Duration(Millisecond)
+        duration_regex(r"\d+\.\d{1,6}S"), //Duration(Microsecond)
+        duration_regex(r"\d+\.\d{1,9}S"), //Duration(Nanosecond)
+    ]).unwrap();
+}
+
+/// Returns a regex matching an ISO 8601 duration of weeks, days, hours, minutes
+/// and seconds, with the seconds component matching `seconds`
+fn duration_regex(seconds: &str) -> String {
+    format!(
+        r"^-?P(?:\d+W|\d+D|(?:\d+D)?T(?:\d+H(?:\d+M)?(?:{seconds})?|\d+M(?:{seconds})?|{seconds}))$"
+    )
 }
 
 /// A wrapper over `Option<Regex>` to check if the value is `NULL`.
@@ -186,6 +203,10 @@ struct InferredDataType {
     /// 6 - Timestamp(Microsecond)
     /// 7 - Timestamp(Nanosecond)
     /// 8 - Utf8
+    /// 9 - Duration(Second)
+    /// 10 - Duration(Millisecond)
+    /// 11 - Duration(Microsecond)
+    /// 12 - Duration(Nanosecond)
     packed: u16,
 }
 
@@ -206,16 +227,31 @@ impl InferredDataType {
                 12 => DataType::Date32,
                 _ => unreachable!(),
             },
+            b if b != 0 && (b & !0b1_1110_0000_0000) == 0 => match b.leading_zeros() {
+                // Promote to highest precision duration type
+                3 => DataType::Duration(TimeUnit::Nanosecond),
+                4 => DataType::Duration(TimeUnit::Microsecond),
+                5 => DataType::Duration(TimeUnit::Millisecond),
+                6 => DataType::Duration(TimeUnit::Second),
+                _ => unreachable!(),
+            },
             _ => DataType::Utf8,
         }
     }
 
     /// Updates the [`InferredDataType`] with the given string
-    fn update(&mut self, string: &str) {
+    ///
+    /// ISO 8601 durations are only recognised if `infer_durations` is true
+    fn update(&mut self, string: &str, infer_durations: bool) {
         self.packed |= if string.starts_with('"') {
             1 << 8 // Utf8
         } else if let Some(m) = REGEX_SET.matches(string).into_iter().next() {
             1 << m
+        } else if let Some(m) = infer_durations
+            .then(|| DURATION_REGEX_SET.matches(string).into_iter().next())
+            .flatten()
+        {
+            1 << (9 + m)
         } else {
             1 << 8 // Utf8
         }
@@ -231,6 +267,7 @@ pub struct Format {
     quote: Option<u8>,
     terminator: Option<u8>,
     null_regex: NullRegex,
+    infer_durations: bool,
 }
 
 impl Format {
@@ -265,6 +302,15 @@ impl Format {
         self
     }
 
+    /// Infer [`DataType::Duration`] for columns containing ISO 8601 durations without
+    /// years or months, such as `PT1H30M` or `P2DT0.5S`, defaults to `false`
+    ///
+    /// The [`TimeUnit`] is chosen based on the precision of the seconds component
+    pub fn with_infer_durations(mut self, infer_durations: bool) -> Self {
+        self.infer_durations = infer_durations;
+        self
+    }
+
     /// Infer schema of CSV records from the provided `reader`
     ///
     /// If `max_records` is `None`, all records will be read, otherwise up to `max_records`
@@ -309,7 +355,7 @@ impl Format {
             for (i, column_type) in column_types.iter_mut().enumerate().take(header_length) {
                 if let Some(string) = record.get(i) {
                     if !self.null_regex.is_null(string) {
-                        column_type.update(string)
+                        column_type.update(string, self.infer_durations)
                     }
                 }
             }
@@ -581,6 +627,9 @@ pub struct Decoder {
 
     /// Check if the string matches this pattern for `NULL`.
     null_regex: NullRegex,
+
+    /// How to handle [`DataType::Date64`] values not aligned to midnight
+    date64_policy: Date64Policy,
 }
 
 impl Decoder {
@@ -628,6 +677,7 @@ impl Decoder {
             self.line_number,
             &self.null_regex,
         )?;
+        let batch = apply_date64_policy_batch(&batch, self.date64_policy)?;
         self.line_number += rows.len();
         Ok(Some(batch))
     }
@@ -759,6 +809,38 @@ fn parse(
                         null_regex,
                     )
                 }
+                DataType::Duration(TimeUnit::Second) => {
+                    build_primitive_array::<DurationSecondType>(line_number, rows, i, null_regex)
+                }
+                DataType::Duration(TimeUnit::Millisecond) => build_primitive_array::<
+                    DurationMillisecondType,
+                >(
+                    line_number, rows, i, null_regex
+                ),
+                DataType::Duration(TimeUnit::Microsecond) => build_primitive_array::<
+                    DurationMicrosecondType,
+                >(
+                    line_number, rows, i, null_regex
+                ),
+                DataType::Duration(TimeUnit::Nanosecond) => build_primitive_array::<
+                    DurationNanosecondType,
+                >(
+                    line_number, rows, i, null_regex
+                ),
+                DataType::Interval(IntervalUnit::YearMonth) => {
+                    build_primitive_array::<IntervalYearMonthType>(line_number, rows, i, null_regex)
+                }
+                DataType::Interval(IntervalUnit::DayTime) => {
+                    build_primitive_array::<IntervalDayTimeType>(line_number, rows, i, null_regex)
+                }
+                DataType::Interval(IntervalUnit::MonthDayNano) => {
+                    build_primitive_array::<IntervalMonthDayNanoType>(
+                        line_number,
+                        rows,
+                        i,
+                        null_regex,
+                    )
+                }
                 DataType::Null => Ok(Arc::new(NullArray::builder(rows.len()).finish()) as ArrayRef),
                 DataType::Utf8 => Ok(Arc::new(
                     rows.iter()
@@ -1017,6 +1099,8 @@ pub struct ReaderBuilder {
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<usize>>,
+    /// How to handle [`DataType::Date64`] values not aligned to midnight
+    date64_policy: Date64Policy,
 }
 
 impl ReaderBuilder {
@@ -1048,6 +1132,7 @@ impl ReaderBuilder {
             batch_size: 1024,
             bounds: None,
             projection: None,
+            date64_policy: Date64Policy::Preserve,
         }
     }
 
@@ -1117,6 +1202,13 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set how to handle [`DataType::Date64`] values that are not aligned to
+    /// midnight, defaults to [`Date64Policy::Preserve`]
+    pub fn with_date64_policy(mut self, date64_policy: Date64Policy) -> Self {
+        self.date64_policy = date64_policy;
+        self
+    }
+
     /// Create a new `Reader` from a non-buffered reader
     ///
     /// If `R: BufRead` consider using [`Self::build_buffered`] to avoid unnecessary additional
@@ -1154,6 +1246,7 @@ impl ReaderBuilder {
             projection: self.projection,
             batch_size: self.batch_size,
             null_regex: self.format.null_regex,
+            date64_policy: self.date64_policy,
         }
     }
 }
@@ -1691,7 +1784,7 @@ mod tests {
     /// Infer the data type of a record
     fn infer_field_schema(string: &str) -> DataType {
         let mut v = InferredDataType::default();
-        v.update(string);
+        v.update(string, false);
         v.get()
     }
 
@@ -1916,6 +2009,145 @@ mod tests {
         assert!(csv.next().is_none());
     }
 
+    #[test]
+    fn test_duration_and_interval() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("d_s", DataType::Duration(TimeUnit::Second), true),
+            Field::new("d_ms", DataType::Duration(TimeUnit::Millisecond), true),
+            Field::new("i_ym", DataType::Interval(IntervalUnit::YearMonth), true),
+            Field::new("i_dt", DataType::Interval(IntervalUnit::DayTime), true),
+            Field::new(
+                "i_mdn",
+                DataType::Interval(IntervalUnit::MonthDayNano),
+                true,
+            ),
+        ]));
+        let data = "PT1H,P1DT0.5S,P1Y2M,1 day 2 hours,P1M2DT3S\n\
+                    ,,,,\n\
+                    30,1 minute,3 months,-P1D,1 day\n";
+
+        let mut csv = ReaderBuilder::new(schema)
+            .build_buffered(Cursor::new(data.as_bytes()))
+            .unwrap();
+        let batch = csv.next().unwrap().unwrap();
+
+        let d_s = batch.column(0).as_primitive::<DurationSecondType>();
+        assert_eq!(
+            d_s,
+            &DurationSecondArray::from(vec![Some(3600), None, Some(30)])
+        );
+
+        let d_ms = batch.column(1).as_primitive::<DurationMillisecondType>();
+        assert_eq!(
+            d_ms,
+            &DurationMillisecondArray::from(vec![Some(86_400_500), None, Some(60_000)])
+        );
+
+        let i_ym = batch.column(2).as_primitive::<IntervalYearMonthType>();
+        assert_eq!(
+            i_ym,
+            &IntervalYearMonthArray::from(vec![Some(14), None, Some(3)])
+        );
+
+        let i_dt = batch.column(3).as_primitive::<IntervalDayTimeType>();
+        let expected = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTimeType::make_value(1, 2 * 60 * 60 * 1000)),
+            None,
+            Some(IntervalDayTimeType::make_value(-1, 0)),
+        ]);
+        assert_eq!(i_dt, &expected);
+
+        let i_mdn = batch.column(4).as_primitive::<IntervalMonthDayNanoType>();
+        let expected = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNanoType::make_value(1, 2, 3_000_000_000)),
+            None,
+            Some(IntervalMonthDayNanoType::make_value(0, 1, 0)),
+        ]);
+        assert_eq!(i_mdn, &expected);
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "d",
+            DataType::Duration(TimeUnit::Second),
+            true,
+        )]));
+        let mut csv = ReaderBuilder::new(schema)
+            .build_buffered(Cursor::new("P1M".as_bytes()))
+            .unwrap();
+        let err = csv.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error while parsing value P1M for column 0 at line 0"
+        );
+    }
+
+    #[test]
+    fn test_date64_policy() {
+        let schema = Arc::new(Schema::new(vec![Field::new("d", DataType::Date64, true)]));
+        let data = "2018-11-13\n2018-11-13T17:11:10\n";
+        let read = |policy| {
+            ReaderBuilder::new(schema.clone())
+                .with_date64_policy(policy)
+                .build_buffered(Cursor::new(data.as_bytes()))
+                .unwrap()
+                .next()
+                .unwrap()
+        };
+
+        let batch = read(Date64Policy::Preserve).unwrap();
+        let dates = batch.column(0).as_primitive::<Date64Type>();
+        assert_eq!(dates.value(1), 1542129070000);
+
+        let batch = read(Date64Policy::Truncate).unwrap();
+        let dates = batch.column(0).as_primitive::<Date64Type>();
+        assert_eq!(dates.value(0), 1542067200000);
+        assert_eq!(dates.value(1), 1542067200000);
+
+        let err = read(Date64Policy::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Date64 value 1542129070000 at index 1 is not a multiple of 86400000 milliseconds"
+        );
+    }
+
+    #[test]
+    fn test_infer_durations() {
+        let data = "a,b,c,d\nPT1H,P1DT0.5S,P1D,1 hour\nP2W,PT0.000001S,10,2 hours\n";
+
+        let format = Format::default().with_header(true);
+        let (schema, _) = format.infer_schema(data.as_bytes(), None).unwrap();
+        for field in schema.fields() {
+            assert_eq!(field.data_type(), &DataType::Utf8);
+        }
+
+        let format = format.with_infer_durations(true);
+        let (schema, _) = format.infer_schema(data.as_bytes(), None).unwrap();
+        let types: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                DataType::Duration(TimeUnit::Second),
+                DataType::Duration(TimeUnit::Microsecond),
+                DataType::Utf8,
+                DataType::Utf8,
+            ]
+        );
+
+        let mut v = InferredDataType::default();
+        for s in ["PT1M", "P1DT1H1M1S", "-PT1.5S", "PT1.1234S"] {
+            v.update(s, true);
+        }
+        assert_eq!(v.get(), DataType::Duration(TimeUnit::Microsecond));
+        for s in ["P1Y", "P1M", "PT", "P", "PT1.5M", "P1DT"] {
+            let mut v = InferredDataType::default();
+            v.update(s, true);
+            assert_eq!(v.get(), DataType::Utf8, "{s}");
+        }
+    }
+
     #[test]
     fn test_empty_projection() {
         let schema = Schema::new(vec![Field::new("int", DataType::UInt32, false)]);
@@ -2351,7 +2583,7 @@ mod tests {
         for (values, expected) in cases {
             let mut t = InferredDataType::default();
             for v in *values {
-                t.update(v)
+                t.update(v, false)
             }
             assert_eq!(&t.get(), expected, "{values:?}")
         }
@@ -1491,6 +1491,24 @@ impl ArrayData {
 
 /// Return the expected [`DataTypeLayout`] Arrays of this data
 /// type are expected to have
+///
+/// This describes the buffers of [`ArrayData`] with this type, excluding the null
+/// buffer and any child data, allowing serializers to handle types generically
+///
+/// ```
+/// # use arrow_data::{layout, BufferSpec};
+/// # use arrow_schema::DataType;
+/// let utf8 = layout(&DataType::Utf8);
+/// assert!(utf8.can_contain_null_mask);
+/// // A null buffer followed by an offsets buffer and a values buffer
+/// assert_eq!(utf8.num_buffers(), 3);
+/// assert_eq!(utf8.buffers[0].bit_width(), Some(32));
+/// assert_eq!(utf8.buffers[1], BufferSpec::VariableWidth);
+///
+/// let null = layout(&DataType::Null);
+/// assert!(!null.can_contain_null_mask);
+/// assert_eq!(null.num_buffers(), 0);
+/// ```
 pub fn layout(data_type: &DataType) -> DataTypeLayout {
     // based on C/C++ implementation in
     // https://github.com/apache/arrow/blob/661c7d749150905a63dd3b52e0a04dac39030d95/cpp/src/arrow/type.h (and .cc)
@@ -1546,7 +1564,10 @@ pub fn layout(data_type: &DataType) -> DataTypeLayout {
         DataType::LargeList(_) => DataTypeLayout::new_fixed_width::<i64>(),
         DataType::Map(_, _) => DataTypeLayout::new_fixed_width::<i32>(),
         DataType::Struct(_) => DataTypeLayout::new_empty(), // all in child data,
-        DataType::RunEndEncoded(_, _) => DataTypeLayout::new_empty(), // all in child data,
+        DataType::RunEndEncoded(_, _) => DataTypeLayout {
+            buffers: vec![], // all in child data
+            can_contain_null_mask: false,
+        },
         DataType::Union(_, mode) => {
             let type_ids = BufferSpec::FixedWidth {
                 byte_width: mem::size_of::<i8>(),
@@ -1608,6 +1629,12 @@ impl DataTypeLayout {
         }
     }
 
+    /// Returns the total number of buffers, including the null buffer if
+    /// [`Self::can_contain_null_mask`], as expected by the C Data Interface and IPC format
+    pub fn num_buffers(&self) -> usize {
+        self.buffers.len() + usize::from(self.can_contain_null_mask)
+    }
+
     /// Describes a basic numeric array where each element has a fixed
     /// with offset buffer of type `T`, followed by a
     /// variable width data buffer
@@ -1655,6 +1682,18 @@ pub enum BufferSpec {
     AlwaysNull,
 }
 
+impl BufferSpec {
+    /// Returns the number of bits used by each element of this buffer, or `None` for
+    /// [`BufferSpec::VariableWidth`] and [`BufferSpec::AlwaysNull`]
+    pub fn bit_width(&self) -> Option<usize> {
+        match self {
+            Self::FixedWidth { byte_width, .. } => Some(byte_width * 8),
+            Self::BitMap => Some(1),
+            Self::VariableWidth | Self::AlwaysNull => None,
+        }
+    }
+}
+
 impl PartialEq for ArrayData {
     fn eq(&self, other: &Self) -> bool {
         equal::equal(self, other)
@@ -2054,6 +2093,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_layout() {
+        let spec = layout(&DataType::Boolean);
+        assert_eq!(spec.num_buffers(), 2);
+        assert_eq!(spec.buffers[0].bit_width(), Some(1));
+
+        let spec = layout(&DataType::Decimal256(10, 2));
+        assert_eq!(spec.buffers[0].bit_width(), Some(256));
+
+        let dictionary = DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8));
+        let spec = layout(&dictionary);
+        assert_eq!(spec.num_buffers(), 2);
+        assert_eq!(spec.buffers[0].bit_width(), Some(16));
+
+        let spec = layout(&DataType::LargeList(Arc::new(Field::new(
+            "item",
+            DataType::Int32,
+            true,
+        ))));
+        assert_eq!(spec.num_buffers(), 2);
+        assert_eq!(spec.buffers[0].bit_width(), Some(64));
+
+        let fields = UnionFields::new([0], [Field::new("a", DataType::Int32, true)]);
+        let spec = layout(&DataType::Union(fields, UnionMode::Dense));
+        assert_eq!(spec.num_buffers(), 2);
+        assert_eq!(spec.buffers[1].bit_width(), Some(32));
+
+        let run_ends = Arc::new(Field::new("run_ends", DataType::Int32, false));
+        let values = Arc::new(Field::new("values", DataType::Utf8, true));
+        let spec = layout(&DataType::RunEndEncoded(run_ends, values));
+        assert!(!spec.can_contain_null_mask);
+        assert_eq!(spec.num_buffers(), 0);
+
+        assert_eq!(BufferSpec::VariableWidth.bit_width(), None);
+    }
+
     #[test]
     fn test_count_nulls() {
         let buffer = Buffer::from(vec![0b00010110, 0b10011111]);
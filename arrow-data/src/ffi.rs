@@ -132,14 +132,9 @@ impl FFI_ArrowArray {
         };
 
         // `n_buffers` is the number of buffers by the spec.
-        let n_buffers = {
-            data_layout.buffers.len() + {
-                // If the layout has a null buffer by Arrow spec.
-                // Note that even the array doesn't have a null buffer because it has
-                // no null value, we still need to count 1 here to follow the spec.
-                usize::from(data_layout.can_contain_null_mask)
-            }
-        } as i64;
+        // Note that even if the array doesn't have a null buffer because it has
+        // no null value, it is still counted here to follow the spec.
+        let n_buffers = data_layout.num_buffers() as i64;
 
         let buffers_ptr = buffers
             .iter()
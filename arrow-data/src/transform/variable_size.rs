@@ -21,8 +21,9 @@ use num::traits::AsPrimitive;
 use num::{CheckedAdd, Integer};
 
 use super::{
-    Extend, _MutableArrayData,
+    _MutableArrayData,
     utils::{extend_offsets, get_last_offset},
+    Extend,
 };
 
 #[inline]
@@ -195,6 +195,13 @@ pub fn data_type_from_json(json: &serde_json::Value) -> Result<DataType> {
                     ))
                 }
             }
+            Some(s) if s == "runendencoded" => {
+                // return a run end encoded type with any types as its children aren't defined in the map
+                Ok(DataType::RunEndEncoded(
+                    default_field.clone(),
+                    default_field,
+                ))
+            }
             Some(s) if s == "struct" => {
                 // return an empty `struct` type as its children aren't defined in the map
                 Ok(DataType::Struct(Fields::empty()))
@@ -340,7 +347,7 @@ pub fn data_type_to_json(data_type: &DataType) -> serde_json::Value {
         DataType::Map(_, keys_sorted) => {
             json!({"name": "map", "keysSorted": keys_sorted})
         }
-        DataType::RunEndEncoded(_, _) => todo!(),
+        DataType::RunEndEncoded(_, _) => json!({"name": "runendencoded"}),
     }
 }
 
@@ -194,6 +194,22 @@ pub fn field_from_json(json: &serde_json::Value) -> Result<Field> {
                         }
                     }
                 }
+                DataType::RunEndEncoded(_, _) => match map.get("children") {
+                    Some(Value::Array(values)) if values.len() == 2 => DataType::RunEndEncoded(
+                        Arc::new(field_from_json(&values[0])?),
+                        Arc::new(field_from_json(&values[1])?),
+                    ),
+                    Some(_) => {
+                        return Err(ArrowError::ParseError(
+                            "Field 'children' must be an array with 2 elements".to_string(),
+                        ))
+                    }
+                    None => {
+                        return Err(ArrowError::ParseError(
+                            "Field missing 'children' attribute".to_string(),
+                        ));
+                    }
+                },
                 DataType::Union(fields, mode) => match map.get("children") {
                     Some(Value::Array(values)) => {
                         let fields = fields
@@ -270,6 +286,9 @@ pub fn field_to_json(field: &Field) -> serde_json::Value {
         | DataType::LargeList(field)
         | DataType::FixedSizeList(field, _)
         | DataType::Map(field, _) => vec![field_to_json(field)],
+        DataType::RunEndEncoded(run_ends, values) => {
+            vec![field_to_json(run_ends), field_to_json(values)]
+        }
         _ => vec![],
     };
 
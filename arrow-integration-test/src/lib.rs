@@ -859,6 +859,24 @@ pub fn array_from_json(
             .unwrap();
             Ok(Arc::new(array))
         }
+        DataType::RunEndEncoded(run_ends_field, values_field) => {
+            let children = json_col.children.unwrap();
+            if children.len() != 2 {
+                return Err(ArrowError::JsonError(format!(
+                    "Expected 2 children for run end encoded column, found {}",
+                    children.len()
+                )));
+            }
+            let mut children = children.into_iter();
+            let run_ends = array_from_json(run_ends_field, children.next().unwrap(), dictionaries)?;
+            let values = array_from_json(values_field, children.next().unwrap(), dictionaries)?;
+            let array_data = ArrayData::builder(field.data_type().clone())
+                .len(json_col.count)
+                .add_child_data(run_ends.into_data())
+                .add_child_data(values.into_data())
+                .build()?;
+            Ok(make_array(array_data))
+        }
         t => Err(ArrowError::JsonError(format!(
             "data type {t:?} not supported"
         ))),
@@ -1276,4 +1294,84 @@ mod tests {
         // test record batch
         assert_eq!(arrow_json.get_record_batches().unwrap()[0], record_batch);
     }
+
+    #[test]
+    fn test_run_end_encoded() {
+        let json = r#"
+        {
+            "schema": {
+                "fields": [
+                    {
+                        "name": "ree",
+                        "type": {"name": "runendencoded"},
+                        "nullable": false,
+                        "children": [
+                            {
+                                "name": "run_ends",
+                                "type": {"name": "int", "isSigned": true, "bitWidth": 32},
+                                "nullable": false,
+                                "children": []
+                            },
+                            {
+                                "name": "values",
+                                "type": {"name": "utf8"},
+                                "nullable": true,
+                                "children": []
+                            }
+                        ]
+                    }
+                ]
+            },
+            "batches": [
+                {
+                    "count": 6,
+                    "columns": [
+                        {
+                            "name": "ree",
+                            "count": 6,
+                            "children": [
+                                {
+                                    "name": "run_ends",
+                                    "count": 3,
+                                    "VALIDITY": [1, 1, 1],
+                                    "DATA": [2, 3, 6]
+                                },
+                                {
+                                    "name": "values",
+                                    "count": 3,
+                                    "VALIDITY": [1, 0, 1],
+                                    "OFFSET": [0, 1, 1, 3],
+                                    "DATA": ["a", "", "bc"]
+                                }
+                            ]
+                        }
+                    ]
+                }
+            ]
+        }"#;
+        let arrow_json: ArrowJson = serde_json::from_str(json).unwrap();
+
+        let run_ends = Field::new("run_ends", DataType::Int32, false);
+        let values = Field::new("values", DataType::Utf8, true);
+        let data_type = DataType::RunEndEncoded(Arc::new(run_ends), Arc::new(values));
+        let schema = Schema::new(vec![Field::new("ree", data_type, false)]);
+        assert!(arrow_json.schema.equals_schema(&schema));
+
+        let array: RunArray<Int32Type> = vec![
+            Some("a"),
+            Some("a"),
+            None,
+            Some("bc"),
+            Some("bc"),
+            Some("bc"),
+        ]
+        .into_iter()
+        .collect();
+        let batch = arrow_json.get_record_batches().unwrap().pop().unwrap();
+        assert_eq!(batch.column(0).as_ref(), &array as &dyn Array);
+
+        // Round trip the schema through JSON
+        let field_json = field_to_json(schema.field(0));
+        assert_eq!(field_from_json(&field_json).unwrap(), *schema.field(0));
+    }
 }
@@ -18,7 +18,6 @@
 //! Tests for reading the content of  [`FileReader`] and [`StreamReader`]
 //! in `testing/arrow-ipc-stream/integration/...`
 
-use arrow::error::ArrowError;
 use arrow::ipc::reader::{FileReader, StreamReader};
 use arrow::util::test_util::arrow_test_data;
 use arrow_integration_testing::read_gzip_json;
@@ -59,6 +58,7 @@ fn read_0_1_7() {
 #[test]
 fn read_1_0_0_bigendian() {
     let testdata = arrow_test_data();
+    let version = "1.0.0-bigendian";
     let paths = [
         "generated_decimal",
         "generated_dictionary",
@@ -73,17 +73,8 @@ fn read_1_0_0_bigendian() {
         "generated_primitive",
     ];
     paths.iter().for_each(|path| {
-        let file = File::open(format!(
-            "{testdata}/arrow-ipc-stream/integration/1.0.0-bigendian/{path}.arrow_file"
-        ))
-        .unwrap();
-
-        let reader = FileReader::try_new(file, None);
-
-        assert!(reader.is_err());
-        let err = reader.err().unwrap();
-        assert!(matches!(err, ArrowError::IpcError(_)));
-        assert_eq!(err.to_string(), "Ipc error: the endianness of the source system does not match the endianness of the target system.");
+        verify_arrow_file(&testdata, version, path);
+        verify_arrow_stream(&testdata, version, path);
     });
 }
 
@@ -106,6 +106,12 @@ for f in `ls *.rs`; do
     sed -i '' '/pub mod apache {/d' $f
     sed -i '' '/pub mod arrow {/d' $f
     sed -i '' '/pub mod flatbuf {/d' $f
+    sed -i '' '/pub mod ipc {/d' $f
+    sed -i '' '/pub mod feather {/d' $f
+    sed -i '' '/pub mod fbs {/d' $f
+    sed -i '' '/}  \/\/ pub mod fbs/d' $f
+    sed -i '' '/}  \/\/ pub mod feather/d' $f
+    sed -i '' '/}  \/\/ pub mod ipc/d' $f
     sed -i '' '/}  \/\/ pub mod flatbuf/d' $f
     sed -i '' '/}  \/\/ pub mod arrow/d' $f
     sed -i '' '/}  \/\/ pub mod apache/d' $f
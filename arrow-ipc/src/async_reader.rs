@@ -0,0 +1,690 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Asynchronous readers of the Arrow IPC format, reading from a [`tokio::io::AsyncRead`]
+//!
+//! [`AsyncStreamReader`] reads the IPC streaming format, and [`AsyncFileReader`]
+//! reads the IPC file format, which additionally requires [`tokio::io::AsyncSeek`]
+//!
+//! ```
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # use std::io::Cursor;
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! # use arrow_ipc::async_reader::AsyncStreamReader;
+//! # use arrow_ipc::writer::StreamWriter;
+//! # use futures::TryStreamExt;
+//! let batch = RecordBatch::try_from_iter([
+//!     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+//! ]).unwrap();
+//! let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+//! writer.write(&batch).unwrap();
+//! let buf = writer.into_inner().unwrap();
+//!
+//! // Any `AsyncRead`, such as a `tokio::net::TcpStream`, can be used
+//! let reader = AsyncStreamReader::try_new(Cursor::new(buf), None).await.unwrap();
+//! let batches: Vec<_> = reader.try_collect().await.unwrap();
+//! assert_eq!(batches, vec![batch]);
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Formatter;
+use std::io::SeekFrom;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::future::BoxFuture;
+use futures::{FutureExt, Stream};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+use crate::reader::{
+    decode_stream_message, dictionary_block_id, read_footer_length, read_schema_message,
+    stream_message_body_len, FileFooter, FileReaderBuilder, ReadLimits, RecordBatchWithMetadata,
+};
+use crate::{Block, CONTINUATION_MARKER};
+
+/// Reads the length of the metadata of the next message in a stream, returning
+/// `None` if the end of the stream has been reached
+async fn read_metadata_len<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<Option<usize>, ArrowError> {
+    let mut meta_size: [u8; 4] = [0; 4];
+    match reader.read_exact(&mut meta_size).await {
+        Ok(_) => (),
+        // Handle EOF without the "0xFFFFFFFF 0x00000000"
+        // valid according to:
+        // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+
+    // If a continuation marker is encountered, skip over it and read
+    // the size from the next four bytes.
+    if meta_size == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_size).await?;
+    }
+    match i32::from_le_bytes(meta_size) {
+        0 => Ok(None),
+        len => Ok(Some(len as usize)),
+    }
+}
+
+/// The state of an [`AsyncStreamReader`] between messages
+struct MessageReader<R> {
+    reader: R,
+    schema: SchemaRef,
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
+    projection: Option<Vec<usize>>,
+    endianness: crate::Endianness,
+}
+
+impl<R: AsyncRead + Unpin> MessageReader<R> {
+    /// Reads messages until the next record batch, or the end of the stream
+    async fn next_batch(&mut self) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
+        loop {
+            let Some(meta_len) = read_metadata_len(&mut self.reader).await? else {
+                return Ok(None);
+            };
+            let mut meta_buffer = vec![0; meta_len];
+            self.reader.read_exact(&mut meta_buffer).await?;
+
+            let message = crate::root_as_message(&meta_buffer).map_err(|err| {
+                ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
+            })?;
+
+            // read the block that makes up the message body into a buffer
+            let mut buf = MutableBuffer::from_len_zeroed(stream_message_body_len(&message));
+            self.reader.read_exact(buf.as_slice_mut()).await?;
+
+            let batch = decode_stream_message(
+                &message,
+                buf.into(),
+                &self.schema,
+                &mut self.dictionaries_by_id,
+                self.projection.as_deref(),
+                None,
+                &[],
+                self.endianness,
+                None,
+            )?;
+            match message.header_type() {
+                // read the next message until we encounter a RecordBatch
+                crate::MessageHeader::DictionaryBatch => continue,
+                _ => return Ok(batch),
+            }
+        }
+    }
+}
+
+/// A future reading the next record batch, returning the decoder along with the result
+type NextBatchFuture<R> = BoxFuture<
+    'static,
+    (
+        Box<MessageReader<R>>,
+        Result<Option<RecordBatchWithMetadata>, ArrowError>,
+    ),
+>;
+
+enum StreamState<R> {
+    /// Waiting to read the next message
+    Init(Box<MessageReader<R>>),
+    /// Reading the next record batch
+    Reading(NextBatchFuture<R>),
+    /// The end of the stream has been reached, or an error was encountered
+    Finished,
+}
+
+/// An asynchronous [`Stream`] of [`RecordBatch`] read from an Arrow IPC stream,
+/// the async counterpart of [`StreamReader`](crate::reader::StreamReader)
+///
+/// Reads from a [`tokio::io::AsyncRead`], such as a network socket, without
+/// blocking the runtime worker. Messages are read and decoded one at a time,
+/// with dictionary batches applied to subsequent record batches.
+pub struct AsyncStreamReader<R> {
+    schema: SchemaRef,
+    state: StreamState<R>,
+}
+
+impl<R> std::fmt::Debug for AsyncStreamReader<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let state = match self.state {
+            StreamState::Init(_) => "Init",
+            StreamState::Reading(_) => "Reading",
+            StreamState::Finished => "Finished",
+        };
+        f.debug_struct("AsyncStreamReader")
+            .field("schema", &self.schema)
+            .field("state", &format_args!("{state}"))
+            .finish()
+    }
+}
+
+impl<R: AsyncRead + Unpin + Send + 'static> AsyncStreamReader<R> {
+    /// Try to create a new stream reader, reading the schema from the first message
+    /// of the stream
+    ///
+    /// If `projection` is provided, only the columns with the given (zero-based)
+    /// indices are decoded
+    pub async fn try_new(
+        mut reader: R,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let meta_len = read_metadata_len(&mut reader).await?.ok_or_else(|| {
+            ArrowError::IpcError("Expected a schema message at the start of the stream".to_string())
+        })?;
+        let mut meta_buffer = vec![0; meta_len];
+        reader.read_exact(&mut meta_buffer).await?;
+        let (schema, endianness) = read_schema_message(&meta_buffer, &Default::default())?;
+
+        if let Some(projection) = &projection {
+            schema.project(projection)?;
+        }
+
+        let schema = Arc::new(schema);
+        let decoder = MessageReader {
+            reader,
+            schema: schema.clone(),
+            dictionaries_by_id: HashMap::new(),
+            projection,
+            endianness,
+        };
+        Ok(Self {
+            schema,
+            state: StreamState::Init(Box::new(decoder)),
+        })
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Check if the stream is finished
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, StreamState::Finished)
+    }
+
+    /// Returns a [`Stream`] of the remaining record batches, along with the custom
+    /// metadata of their IPC messages
+    pub fn into_stream_with_metadata(
+        mut self,
+    ) -> impl Stream<Item = Result<RecordBatchWithMetadata, ArrowError>> {
+        futures::stream::poll_fn(move |cx| self.poll_next_with_metadata(cx))
+    }
+
+    /// Polls for the next record batch, along with the custom metadata of its IPC message
+    fn poll_next_with_metadata(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<RecordBatchWithMetadata, ArrowError>>> {
+        loop {
+            match std::mem::replace(&mut self.state, StreamState::Finished) {
+                StreamState::Init(mut decoder) => {
+                    let fut = async move {
+                        let result = decoder.next_batch().await;
+                        (decoder, result)
+                    };
+                    self.state = StreamState::Reading(fut.boxed());
+                }
+                StreamState::Reading(mut fut) => {
+                    return match fut.poll_unpin(cx) {
+                        Poll::Pending => {
+                            self.state = StreamState::Reading(fut);
+                            Poll::Pending
+                        }
+                        Poll::Ready((decoder, Ok(Some(batch)))) => {
+                            self.state = StreamState::Init(decoder);
+                            Poll::Ready(Some(Ok(batch)))
+                        }
+                        Poll::Ready((_, Ok(None))) => Poll::Ready(None),
+                        Poll::Ready((_, Err(e))) => Poll::Ready(Some(Err(e))),
+                    }
+                }
+                StreamState::Finished => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin + Send + 'static> Stream for AsyncStreamReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_next_with_metadata(cx)
+            .map(|r| r.map(|r| r.map(|(batch, _)| batch)))
+    }
+}
+
+/// Reads the metadata and body of `block`, returning `None` without reading the
+/// body if `skip` returns `true` for its metadata
+async fn read_block<R: AsyncRead + AsyncSeek + Unpin>(
+    reader: &mut R,
+    block: &Block,
+    limits: &ReadLimits,
+    skip: impl FnOnce(&[u8]) -> bool,
+) -> Result<Option<Buffer>, ArrowError> {
+    let (metadata_len, body_len) = limits.check_block(block)?;
+    reader.seek(SeekFrom::Start(block.offset() as u64)).await?;
+    let total_len = body_len + metadata_len;
+
+    let mut buf = MutableBuffer::from_len_zeroed(total_len);
+    reader.read_exact(&mut buf[..metadata_len]).await?;
+    if skip(&buf[..metadata_len]) {
+        return Ok(None);
+    }
+    reader.read_exact(&mut buf[metadata_len..]).await?;
+    Ok(Some(buf.into()))
+}
+
+/// Reads Arrow IPC files from a [`tokio::io::AsyncRead`] + [`tokio::io::AsyncSeek`],
+/// the async counterpart of [`FileReader`](crate::reader::FileReader)
+///
+/// The footer and the dictionaries required by the projected fields are read when
+/// the reader is created. Record batches are then read one block at a time, either
+/// sequentially with [`Self::next_batch`] or [`Self::into_stream`], or at random
+/// with [`Self::set_index`].
+pub struct AsyncFileReader<R> {
+    reader: R,
+    footer: FileFooter,
+    current_block: usize,
+}
+
+impl<R> std::fmt::Debug for AsyncFileReader<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFileReader")
+            .field("decoder", &self.footer.decoder)
+            .field("blocks", &self.footer.blocks)
+            .field("current_block", &self.current_block)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin + Send> AsyncFileReader<R> {
+    /// Try to create a new file reader
+    ///
+    /// Returns errors if the file does not meet the Arrow Format footer requirements
+    pub async fn try_new(reader: R, projection: Option<Vec<usize>>) -> Result<Self, ArrowError> {
+        let mut builder = FileReaderBuilder::new();
+        if let Some(projection) = projection {
+            builder = builder.with_projection(projection);
+        }
+        Self::try_new_with_builder(reader, builder).await
+    }
+
+    /// Try to create a new file reader with the options of a [`FileReaderBuilder`]
+    pub async fn try_new_with_builder(
+        mut reader: R,
+        builder: FileReaderBuilder,
+    ) -> Result<Self, ArrowError> {
+        // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
+        let mut buffer = [0; 10];
+        reader.seek(SeekFrom::End(-10)).await?;
+        reader.read_exact(&mut buffer).await?;
+
+        let footer_len = read_footer_length(buffer)?;
+        builder.limits().check_message_size(footer_len as i64)?;
+
+        // read footer
+        let mut footer_data = vec![0; footer_len];
+        reader.seek(SeekFrom::End(-10 - footer_len as i64)).await?;
+        reader.read_exact(&mut footer_data).await?;
+
+        let mut footer = builder.read_footer(&footer_data)?;
+
+        // Read the dictionaries used by the projected fields that have not been provided
+        for idx in 0..footer.dictionary_blocks.len() {
+            let block = footer.dictionary_blocks[idx];
+            let buf = read_block(&mut reader, &block, &footer.limits, |metadata| {
+                matches!(dictionary_block_id(metadata), Some(id) if !footer.requires_dictionary(id))
+            })
+            .await?;
+            if let Some(buf) = buf {
+                footer.read_dictionary(idx, &buf)?;
+            }
+        }
+
+        Ok(Self {
+            reader,
+            footer,
+            current_block: 0,
+        })
+    }
+
+    /// Return user defined customized metadata
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.footer.custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.footer.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.footer.decoder.schema()
+    }
+
+    /// Sets the current block to the index, allowing random reads
+    pub fn set_index(&mut self, index: usize) -> Result<(), ArrowError> {
+        if index >= self.num_batches() {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot set batch to index {} from {} total batches",
+                index,
+                self.num_batches()
+            )))
+        } else {
+            self.current_block = index;
+            Ok(())
+        }
+    }
+
+    /// Read the record batch at the current index, returning `None` once all
+    /// batches have been read
+    pub async fn next_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let batch = self.next_batch_with_metadata().await?;
+        Ok(batch.map(|(batch, _)| batch))
+    }
+
+    /// Read the record batch at the current index, along with the custom metadata
+    /// of its IPC message, returning `None` once all batches have been read
+    pub async fn next_batch_with_metadata(
+        &mut self,
+    ) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
+        while self.current_block < self.num_batches() {
+            let idx = self.current_block;
+            self.current_block += 1;
+
+            let block = self.footer.blocks[idx];
+            let buf = read_block(&mut self.reader, &block, &self.footer.limits, |_| false).await?;
+            if let Some(batch) = self.footer.read_record_batch(idx, &buf.unwrap())? {
+                return Ok(Some(batch));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns a [`Stream`] of the record batches from the current index onwards
+    pub fn into_stream(self) -> impl Stream<Item = Result<RecordBatch, ArrowError>> {
+        futures::stream::try_unfold(self, |mut reader| async move {
+            let batch = reader.next_batch().await?;
+            Ok(batch.map(|batch| (batch, reader)))
+        })
+    }
+
+    /// Returns a [`Stream`] of the record batches from the current index onwards,
+    /// along with the custom metadata of their IPC messages
+    pub fn into_stream_with_metadata(
+        self,
+    ) -> impl Stream<Item = Result<RecordBatchWithMetadata, ArrowError>> {
+        futures::stream::try_unfold(self, |mut reader| async move {
+            let batch = reader.next_batch_with_metadata().await?;
+            Ok(batch.map(|batch| (batch, reader)))
+        })
+    }
+
+    /// Gets a reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::StreamReader;
+    use crate::writer::{FileWriter, StreamWriter};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{DictionaryArray, Int64Array, StringArray};
+    use futures::TryStreamExt;
+    use std::io::Cursor;
+
+    fn write_stream(batches: &[RecordBatch]) -> Vec<u8> {
+        let mut writer = StreamWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    fn batches() -> Vec<RecordBatch> {
+        (0..3)
+            .map(|i| {
+                let dict: DictionaryArray<Int32Type> =
+                    vec![Some("a"), None, Some("b")].into_iter().collect();
+                let values = StringArray::from(vec![Some("x"); 3]);
+                let ints = Int64Array::from(vec![i, i + 1, i + 2]);
+                RecordBatch::try_from_iter([
+                    ("dict", Arc::new(dict) as ArrayRef),
+                    ("values", Arc::new(values) as _),
+                    ("ints", Arc::new(ints) as _),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader() {
+        let batches = batches();
+        let buf = write_stream(&batches);
+
+        let reader = AsyncStreamReader::try_new(Cursor::new(buf.clone()), None)
+            .await
+            .unwrap();
+        assert_eq!(reader.schema(), batches[0].schema());
+        let read: Vec<_> = reader.try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        let reader = AsyncStreamReader::try_new(Cursor::new(buf.clone()), Some(vec![2, 0]))
+            .await
+            .unwrap();
+        let read: Vec<_> = reader.try_collect().await.unwrap();
+        let expected: Vec<_> = StreamReader::try_new(Cursor::new(buf), Some(vec![2, 0]))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read, expected);
+        assert_eq!(read[0].num_columns(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_custom_metadata() {
+        let batches = batches();
+        let metadata = |idx: usize| HashMap::from([("idx".to_string(), idx.to_string())]);
+
+        let mut writer = StreamWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for (idx, batch) in batches.iter().enumerate() {
+            writer.write_with_metadata(batch, &metadata(idx)).unwrap();
+        }
+        let buf = writer.into_inner().unwrap();
+
+        let reader = AsyncStreamReader::try_new(Cursor::new(buf), None)
+            .await
+            .unwrap();
+        let read: Vec<_> = reader
+            .into_stream_with_metadata()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(read.len(), 3);
+        for (idx, (batch, read_metadata)) in read.iter().enumerate() {
+            assert_eq!(batch, &batches[idx]);
+            assert_eq!(read_metadata, &metadata(idx));
+        }
+
+        let mut writer = FileWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for (idx, batch) in batches.iter().enumerate() {
+            writer.write_with_metadata(batch, &metadata(idx)).unwrap();
+        }
+        let buf = writer.into_inner().unwrap();
+
+        let mut reader = AsyncFileReader::try_new(Cursor::new(buf), None)
+            .await
+            .unwrap();
+        reader.set_index(1).unwrap();
+        let (batch, read_metadata) = reader.next_batch_with_metadata().await.unwrap().unwrap();
+        assert_eq!(batch, batches[1]);
+        assert_eq!(read_metadata, metadata(1));
+        let read: Vec<_> = reader
+            .into_stream_with_metadata()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(read, vec![(batches[2].clone(), metadata(2))]);
+    }
+
+    #[tokio::test]
+    async fn test_async_file_reader() {
+        let batches = batches();
+        let mut writer = FileWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.write_metadata("key", "value");
+        let buf = writer.into_inner().unwrap();
+
+        let reader = AsyncFileReader::try_new(Cursor::new(buf.clone()), None)
+            .await
+            .unwrap();
+        assert_eq!(reader.num_batches(), 3);
+        assert_eq!(reader.schema(), batches[0].schema());
+        assert_eq!(reader.custom_metadata()["key"], "value");
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        // Random access with a projection
+        let mut reader = AsyncFileReader::try_new(Cursor::new(buf.clone()), Some(vec![0, 2]))
+            .await
+            .unwrap();
+        reader.set_index(2).unwrap();
+        let batch = reader.next_batch().await.unwrap().unwrap();
+        assert_eq!(batch, batches[2].project(&[0, 2]).unwrap());
+        assert!(reader.next_batch().await.unwrap().is_none());
+        reader.set_index(1).unwrap();
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        let expected: Vec<_> = batches[1..]
+            .iter()
+            .map(|b| b.project(&[0, 2]).unwrap())
+            .collect();
+        assert_eq!(read, expected);
+
+        let mut reader = AsyncFileReader::try_new(Cursor::new(buf.clone()), None)
+            .await
+            .unwrap();
+        let err = reader.set_index(3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot set batch to index 3 from 3 total batches"
+        );
+
+        let err = AsyncFileReader::try_new(Cursor::new(buf[..buf.len() - 1].to_vec()), None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Arrow file does not contain correct footer"
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "checksum")]
+    async fn test_async_file_reader_projected_dictionaries() {
+        let batches = batches();
+        let mut writer = FileWriter::try_new(vec![], &batches[0].schema())
+            .unwrap()
+            .try_with_block_checksums(true)
+            .unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let buf = writer.into_inner().unwrap();
+
+        // The dictionary is not required by the projection and so is not read
+        let reader = AsyncFileReader::try_new(Cursor::new(buf.clone()), Some(vec![1]))
+            .await
+            .unwrap();
+        assert!(reader.footer.decoder.dictionaries().is_empty());
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        assert_eq!(read[1], batches[1].project(&[1]).unwrap());
+
+        // Corrupt the end of the last record batch, which precedes the end of stream
+        // marker and the footer, detected by its checksum
+        let mut corrupt = buf.clone();
+        let footer_len = read_footer_length(buf[buf.len() - 10..].try_into().unwrap()).unwrap();
+        let idx = buf.len() - 10 - footer_len - 8 - 1;
+        corrupt[idx] ^= 1;
+        let mut reader = AsyncFileReader::try_new(Cursor::new(corrupt), None)
+            .await
+            .unwrap();
+        reader.set_index(2).unwrap();
+        let err = reader.next_batch().await.unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Checksum mismatch for record batch block 2"));
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader_errors() {
+        let batches = batches();
+        let buf = write_stream(&batches);
+
+        // Without the end of stream marker
+        let truncated = buf[..buf.len() - 8].to_vec();
+        let reader = AsyncStreamReader::try_new(Cursor::new(truncated), None)
+            .await
+            .unwrap();
+        let read: Vec<_> = reader.try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        // Truncated within the body of the last batch
+        let truncated = buf[..buf.len() - 16].to_vec();
+        let mut reader = AsyncStreamReader::try_new(Cursor::new(truncated), None)
+            .await
+            .unwrap();
+        let mut results = vec![];
+        while let Some(result) = futures::StreamExt::next(&mut reader).await {
+            results.push(result);
+        }
+        assert_eq!(results.len(), 3);
+        assert!(results[2].is_err());
+        assert!(reader.is_finished());
+
+        let err = AsyncStreamReader::try_new(Cursor::new(vec![]), None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Expected a schema message at the start of the stream"
+        );
+    }
+}
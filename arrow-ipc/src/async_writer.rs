@@ -0,0 +1,221 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Asynchronous writers of the Arrow IPC format, writing to a [`tokio::io::AsyncWrite`]
+//!
+//! ```
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # use std::io::Cursor;
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! # use arrow_ipc::async_reader::AsyncFileReader;
+//! # use arrow_ipc::async_writer::AsyncFileWriter;
+//! # use futures::TryStreamExt;
+//! let batch = RecordBatch::try_from_iter([
+//!     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+//! ]).unwrap();
+//!
+//! // Any `AsyncWrite`, such as a `tokio::fs::File`, can be used
+//! let mut writer = AsyncFileWriter::try_new(vec![], &batch.schema()).unwrap();
+//! writer.write(&batch).await.unwrap();
+//! let buf = writer.into_inner().await.unwrap();
+//!
+//! let reader = AsyncFileReader::try_new(Cursor::new(buf), None).await.unwrap();
+//! let batches: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+//! assert_eq!(batches, vec![batch]);
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::writer::{FileWriter, IpcWriteOptions};
+
+/// Writes Arrow IPC files to a [`tokio::io::AsyncWrite`], the async counterpart
+/// of [`FileWriter`]
+///
+/// Each record batch, along with any dictionaries it requires, is encoded into an
+/// in-memory buffer by a [`FileWriter`], and then written to the underlying writer.
+/// As such, memory usage is bounded by the encoded size of a single record batch.
+pub struct AsyncFileWriter<W> {
+    /// The object to write to
+    writer: W,
+    /// Encodes the file into an in-memory buffer
+    encoder: FileWriter<Vec<u8>>,
+    /// Whether the writer footer has been written, and the writer is finished
+    finished: bool,
+}
+
+impl<W> std::fmt::Debug for AsyncFileWriter<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFileWriter")
+            .field("schema", self.encoder.schema())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: AsyncWrite + Unpin + Send> AsyncFileWriter<W> {
+    /// Try create a new writer, with the schema written as part of the header
+    pub fn try_new(writer: W, schema: &Schema) -> Result<Self, ArrowError> {
+        Self::try_new_with_options(writer, schema, IpcWriteOptions::default())
+    }
+
+    /// Try create a new writer with IpcWriteOptions
+    pub fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let encoder = FileWriter::try_new_with_options(vec![], schema, write_options)?;
+        Ok(Self {
+            writer,
+            encoder,
+            finished: false,
+        })
+    }
+
+    /// Configures whether to compute a CRC32 checksum of each block, see
+    /// [`FileWriter::try_with_block_checksums`]
+    pub fn try_with_block_checksums(mut self, enabled: bool) -> Result<Self, ArrowError> {
+        self.encoder = self.encoder.try_with_block_checksums(enabled)?;
+        Ok(self)
+    }
+
+    /// Adds a key-value pair to the custom metadata of the file footer
+    pub fn write_metadata(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.encoder.write_metadata(key, value)
+    }
+
+    /// Write a record batch to the file
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, &HashMap::new()).await
+    }
+
+    /// Write a record batch to the file, along with custom metadata for the batch,
+    /// see [`FileWriter::write_with_metadata`]
+    pub async fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
+        self.encoder.write_with_metadata(batch, custom_metadata)?;
+        self.flush_encoded().await
+    }
+
+    /// Write footer and closing tag, then mark the writer as done
+    pub async fn finish(&mut self) -> Result<(), ArrowError> {
+        self.encoder.finish()?;
+        self.flush_encoded().await?;
+        self.writer.flush().await?;
+        self.finished = true;
+        Ok(())
+    }
+
+    /// Returns the arrow [`SchemaRef`] for this arrow file.
+    pub fn schema(&self) -> &SchemaRef {
+        self.encoder.schema()
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Finishes the file, if not already finished, and returns the underlying writer
+    pub async fn into_inner(mut self) -> Result<W, ArrowError> {
+        if !self.finished {
+            self.finish().await?;
+        }
+        Ok(self.writer)
+    }
+
+    /// Writes the data encoded so far to the underlying writer
+    async fn flush_encoded(&mut self) -> Result<(), ArrowError> {
+        self.encoder.flush()?;
+        let buf = std::mem::take(self.encoder.get_mut());
+        self.writer.write_all(&buf).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::FileReader;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, DictionaryArray, Int64Array};
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_async_file_writer() {
+        let batches: Vec<_> = (0..3)
+            .map(|i| {
+                let dict: DictionaryArray<Int32Type> =
+                    vec![Some("a"), None, Some("b")].into_iter().collect();
+                let ints = Int64Array::from(vec![i, i + 1, i + 2]);
+                RecordBatch::try_from_iter([
+                    ("dict", Arc::new(dict) as ArrayRef),
+                    ("ints", Arc::new(ints) as _),
+                ])
+                .unwrap()
+            })
+            .collect();
+
+        let checksums = cfg!(feature = "checksum");
+        let mut writer = AsyncFileWriter::try_new(vec![], &batches[0].schema())
+            .unwrap()
+            .try_with_block_checksums(checksums)
+            .unwrap();
+        writer.write_metadata("key", "value");
+        for (idx, batch) in batches.iter().enumerate() {
+            let metadata = HashMap::from([("idx".to_string(), idx.to_string())]);
+            writer.write_with_metadata(batch, &metadata).await.unwrap();
+            // Each batch is written to the underlying writer once encoded
+            assert!(!writer.get_ref().is_empty());
+        }
+        writer.finish().await.unwrap();
+
+        let err = writer.write(&batches[0]).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Cannot write record batch to file writer as it is closed"
+        );
+        let buf = writer.into_inner().await.unwrap();
+
+        let mut reader = FileReader::try_new(Cursor::new(buf), None).unwrap();
+        assert_eq!(reader.custom_metadata()["key"], "value");
+        assert_eq!(reader.verify_integrity().unwrap(), checksums);
+        for (idx, batch) in batches.iter().enumerate() {
+            let (read, metadata) = reader.next_with_metadata().unwrap().unwrap();
+            assert_eq!(&read, batch);
+            assert_eq!(metadata["idx"], idx.to_string());
+        }
+        assert!(reader.next().is_none());
+    }
+}
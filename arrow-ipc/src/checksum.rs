@@ -0,0 +1,235 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Checksums of Arrow IPC data
+//!
+//! The CRC32 checksums of the blocks of an Arrow IPC file are stored in the custom
+//! metadata of the file footer, as a comma separated list of hexadecimal CRC32 values,
+//! one per block in footer order
+//!
+//! The CRC32C (Castagnoli) checksum of the body of an individual message is stored in
+//! the custom metadata of that message, as a hexadecimal value. Unlike the block
+//! checksums, these do not require a file footer, and so can also protect streams
+//!
+//! Computing and verifying checksums requires the `checksum` feature. Without it,
+//! reading data that contains checksums returns an error, rather than silently
+//! skipping verification, unless verification is explicitly disabled
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use arrow_schema::ArrowError;
+
+use crate::Message;
+
+/// Custom metadata key for the checksums of the dictionary blocks
+pub(crate) const DICTIONARY_CHECKSUMS_KEY: &str = "arrow-rs:dictionary_crc32";
+
+/// Custom metadata key for the checksums of the record batch blocks
+pub(crate) const RECORD_BATCH_CHECKSUMS_KEY: &str = "arrow-rs:record_batch_crc32";
+
+/// Custom metadata key for the checksum of the body of a message
+pub(crate) const MESSAGE_CHECKSUM_KEY: &str = "arrow-rs:message_crc32c";
+
+/// Returns an error if support for checksums was not enabled at compile time
+pub(crate) fn check_available() -> Result<(), ArrowError> {
+    match cfg!(feature = "checksum") {
+        true => Ok(()),
+        false => Err(ArrowError::InvalidArgumentError(
+            "IPC checksums require the checksum feature".to_string(),
+        )),
+    }
+}
+
+/// Returns an error as checksums present in the data being read cannot be verified,
+/// if support for checksums was not enabled at compile time
+pub(crate) fn check_verifiable() -> Result<(), ArrowError> {
+    match cfg!(feature = "checksum") {
+        true => Ok(()),
+        false => Err(ArrowError::NotYetImplemented(
+            "Unable to verify IPC checksums: checksum feature disabled".to_string(),
+        )),
+    }
+}
+
+/// Returns the CRC32 checksum of `data`
+#[cfg(feature = "checksum")]
+pub(crate) fn checksum(data: &[u8]) -> Result<u32, ArrowError> {
+    Ok(crc32fast::hash(data))
+}
+
+#[cfg(not(feature = "checksum"))]
+pub(crate) fn checksum(_data: &[u8]) -> Result<u32, ArrowError> {
+    check_available().map(|_| 0)
+}
+
+/// Returns the CRC32C checksum of `data`, as stored for the body of a message
+#[cfg(feature = "checksum")]
+pub(crate) fn message_checksum(data: &[u8]) -> Result<u32, ArrowError> {
+    Ok(crc32c::crc32c(data))
+}
+
+#[cfg(not(feature = "checksum"))]
+pub(crate) fn message_checksum(_data: &[u8]) -> Result<u32, ArrowError> {
+    check_available().map(|_| 0)
+}
+
+/// A [`Write`] computing the CRC32 of the bytes written through it
+pub(crate) struct ChecksumWriter<W> {
+    inner: W,
+    #[cfg(feature = "checksum")]
+    hasher: crc32fast::Hasher,
+}
+
+impl<W: Write> ChecksumWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        Self {
+            inner,
+            #[cfg(feature = "checksum")]
+            hasher: crc32fast::Hasher::new(),
+        }
+    }
+
+    /// Returns the checksum of the bytes written
+    #[cfg(feature = "checksum")]
+    pub(crate) fn finalize(self) -> Result<u32, ArrowError> {
+        Ok(self.hasher.finalize())
+    }
+
+    #[cfg(not(feature = "checksum"))]
+    pub(crate) fn finalize(self) -> Result<u32, ArrowError> {
+        check_available().map(|_| 0)
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        #[cfg(feature = "checksum")]
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Encodes `checksums` for storage in custom metadata
+pub(crate) fn format_checksums(checksums: &[u32]) -> String {
+    let checksums: Vec<_> = checksums.iter().map(|c| format!("{c:08x}")).collect();
+    checksums.join(",")
+}
+
+/// Parses a single checksum encoded by [`format_checksums`]
+fn parse_checksum(value: &str, key: &str) -> Result<u32, ArrowError> {
+    u32::from_str_radix(value, 16)
+        .map_err(|_| ArrowError::IpcError(format!("Invalid checksum \"{value}\" for {key}")))
+}
+
+/// Returns an error describing `what` if `actual` does not match `expected`
+fn check(actual: u32, expected: u32, what: impl std::fmt::Display) -> Result<(), ArrowError> {
+    if actual != expected {
+        return Err(ArrowError::IpcError(format!(
+            "Checksum mismatch for {what}, expected {expected:08x} got {actual:08x}"
+        )));
+    }
+    Ok(())
+}
+
+/// Parses the checksums stored under `key` in `metadata`, if any, checking that
+/// there is one per block
+pub(crate) fn parse_checksums(
+    metadata: &HashMap<String, String>,
+    key: &str,
+    num_blocks: usize,
+) -> Result<Option<Vec<u32>>, ArrowError> {
+    let Some(value) = metadata.get(key) else {
+        return Ok(None);
+    };
+    let checksums = match value.is_empty() {
+        true => vec![],
+        false => value
+            .split(',')
+            .map(|c| parse_checksum(c, key))
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+    if checksums.len() != num_blocks {
+        return Err(ArrowError::IpcError(format!(
+            "Expected {num_blocks} checksums for {key}, found {}",
+            checksums.len()
+        )));
+    }
+    Ok(Some(checksums))
+}
+
+/// Verifies that the checksum of `data` matches `expected`
+pub(crate) fn verify_checksum(
+    data: &[u8],
+    expected: u32,
+    kind: &str,
+    index: usize,
+) -> Result<(), ArrowError> {
+    check(
+        checksum(data)?,
+        expected,
+        format_args!("{kind} block {index}"),
+    )
+}
+
+/// Verifies the checksum of the `body` of `message`, if it contains one
+///
+/// Returns an error if `message` contains a checksum and the `checksum` feature
+/// is not enabled
+pub(crate) fn verify_message_checksum(message: &Message, body: &[u8]) -> Result<(), ArrowError> {
+    let Some(metadata) = message.custom_metadata() else {
+        return Ok(());
+    };
+    let Some(expected) = metadata
+        .iter()
+        .find(|kv| kv.key() == Some(MESSAGE_CHECKSUM_KEY))
+        .and_then(|kv| kv.value())
+    else {
+        return Ok(());
+    };
+    check_verifiable()?;
+    let expected = parse_checksum(expected, MESSAGE_CHECKSUM_KEY)?;
+
+    let body_len = (message.bodyLength().max(0) as usize).min(body.len());
+    let what = format_args!("{:?} message", message.header_type());
+    check(message_checksum(&body[..body_len])?, expected, what)
+}
+
+#[cfg(test)]
+#[cfg(feature = "checksum")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum_vectors() {
+        // Check values from the CRC catalogue for the input "123456789"
+        assert_eq!(checksum(b"123456789").unwrap(), 0xcbf43926);
+        assert_eq!(message_checksum(b"123456789").unwrap(), 0xe3069283);
+
+        let encoded = format_checksums(&[message_checksum(b"123456789").unwrap()]);
+        assert_eq!(encoded, "e3069283");
+        assert_eq!(
+            parse_checksum(&encoded, MESSAGE_CHECKSUM_KEY).unwrap(),
+            0xe3069283
+        );
+    }
+}
@@ -44,6 +44,20 @@ impl TryFrom<CompressionType> for CompressionCodec {
 }
 
 impl CompressionCodec {
+    /// Returns an error if support for this codec was not enabled at compile time
+    pub(crate) fn check_available(&self) -> Result<(), ArrowError> {
+        let (name, enabled) = match self {
+            CompressionCodec::Lz4Frame => ("lz4", cfg!(feature = "lz4")),
+            CompressionCodec::Zstd => ("zstd", cfg!(feature = "zstd")),
+        };
+        match enabled {
+            true => Ok(()),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "{name} IPC compression requires the {name} feature"
+            ))),
+        }
+    }
+
     /// Compresses the data in `input` to `output` and appends the
     /// data using the specified compression mechanism.
     ///
@@ -93,7 +107,7 @@ impl CompressionCodec {
     pub(crate) fn decompress_to_buffer(&self, input: &Buffer) -> Result<Buffer, ArrowError> {
         // read the first 8 bytes to determine if the data is
         // compressed
-        let decompressed_length = read_uncompressed_size(input);
+        let decompressed_length = read_uncompressed_size(input)?;
         let buffer = if decompressed_length == 0 {
             // empty
             Buffer::from([])
@@ -213,14 +227,39 @@ fn decompress_zstd(_input: &[u8], _decompressed_size: usize) -> Result<Vec<u8>,
 ///    0: indicate that there is no data
 ///   positive number: indicate the uncompressed length for the following data
 #[inline]
-fn read_uncompressed_size(buffer: &[u8]) -> i64 {
-    let len_buffer = &buffer[0..8];
+fn read_uncompressed_size(buffer: &[u8]) -> Result<i64, ArrowError> {
+    let len_buffer = buffer.get(0..8).ok_or_else(|| {
+        ArrowError::IpcError(format!(
+            "Compressed buffer of length {} is too short to contain the uncompressed length",
+            buffer.len()
+        ))
+    })?;
     // 64-bit little-endian signed integer
-    i64::from_le_bytes(len_buffer.try_into().unwrap())
+    Ok(i64::from_le_bytes(len_buffer.try_into().unwrap()))
 }
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn test_decompress_truncated() {
+        let input = arrow_buffer::Buffer::from_slice_ref([1_u8, 2, 3]);
+        let err = super::CompressionCodec::Zstd
+            .decompress_to_buffer(&input)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Compressed buffer of length 3 is too short to contain the uncompressed length"
+        );
+
+        // Uncompressed data is passed through regardless of the codec
+        let mut input = (-1_i64).to_le_bytes().to_vec();
+        input.extend_from_slice(b"hello");
+        let output = super::CompressionCodec::Lz4Frame
+            .decompress_to_buffer(&input.into())
+            .unwrap();
+        assert_eq!(output.as_slice(), b"hello");
+    }
+
     #[test]
     #[cfg(feature = "lz4")]
     fn test_lz4_compression() {
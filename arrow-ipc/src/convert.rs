@@ -18,7 +18,7 @@
 //! Utilities for converting between IPC types and native Arrow types
 
 use arrow_schema::*;
-use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, UnionWIPOffset, Vector, WIPOffset};
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, Table, UnionWIPOffset, Vector, WIPOffset};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -55,9 +55,26 @@ pub fn metadata_to_fb<'a>(
     fbb.create_vector(&custom_metadata)
 }
 
+/// The endianness of the system, recorded in serialized schemas by default
+pub(crate) const NATIVE_ENDIANNESS: crate::Endianness = if cfg!(target_endian = "big") {
+    crate::Endianness::Big
+} else {
+    crate::Endianness::Little
+};
+
 pub fn schema_to_fb_offset<'a>(
     fbb: &mut FlatBufferBuilder<'a>,
     schema: &Schema,
+) -> WIPOffset<crate::Schema<'a>> {
+    schema_to_fb_offset_with_endianness(fbb, schema, NATIVE_ENDIANNESS)
+}
+
+/// Serialize a schema in IPC format, recording that the data described by it is
+/// encoded with the given endianness
+pub(crate) fn schema_to_fb_offset_with_endianness<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    schema: &Schema,
+    endianness: crate::Endianness,
 ) -> WIPOffset<crate::Schema<'a>> {
     let fields = schema
         .fields()
@@ -70,6 +87,7 @@ pub fn schema_to_fb_offset<'a>(
         (!schema.metadata().is_empty()).then(|| metadata_to_fb(fbb, schema.metadata()));
 
     let mut builder = crate::SchemaBuilder::new(fbb);
+    builder.add_endianness(endianness);
     builder.add_fields(fb_field_list);
     if let Some(fb_metadata_list) = fb_metadata_list {
         builder.add_custom_metadata(fb_metadata_list);
@@ -116,12 +134,6 @@ pub fn fb_to_schema(fb: crate::Schema) -> Schema {
     let len = c_fields.len();
     for i in 0..len {
         let c_field: crate::Field = c_fields.get(i);
-        match c_field.type_type() {
-            crate::Type::Decimal if fb.endianness() == crate::Endianness::Big => {
-                unimplemented!("Big Endian is not supported for Decimal!")
-            }
-            _ => (),
-        };
         fields.push(c_field.into());
     }
 
@@ -142,11 +154,57 @@ pub fn fb_to_schema(fb: crate::Schema) -> Schema {
     Schema::new_with_metadata(fields, metadata)
 }
 
+/// Deserialize a Schema table from flat buffer format to Schema data type, returning
+/// an error if it contains types not supported by this implementation
+///
+/// Unlike [`fb_to_schema`], which panics on such types, this allows readers to reject
+/// data written by newer implementations, such as the view types `Utf8View`,
+/// `BinaryView`, `ListView` and `LargeListView`
+pub fn try_fb_to_schema(fb: crate::Schema) -> Result<Schema, ArrowError> {
+    fb.fields()
+        .into_iter()
+        .flatten()
+        .try_for_each(check_field_type)?;
+    Ok(fb_to_schema(fb))
+}
+
+// Ids of the view types in the `Type` union of the upstream Schema.fbs, which are
+// not present in the bundled flatbuffer definitions, see
+// https://github.com/apache/arrow/blob/main/format/Schema.fbs
+const BINARY_VIEW_TYPE_ID: u8 = 23;
+const UTF8_VIEW_TYPE_ID: u8 = 24;
+const LIST_VIEW_TYPE_ID: u8 = 25;
+const LARGE_LIST_VIEW_TYPE_ID: u8 = 26;
+
+/// Returns an error if `field`, or any of its children, has a type not supported
+/// by [`get_data_type`]
+fn check_field_type(field: crate::Field) -> Result<(), ArrowError> {
+    let type_type = field.type_type();
+    if type_type.0 > crate::Type::ENUM_MAX || type_type == crate::Type::NONE {
+        let name = match type_type.0 {
+            BINARY_VIEW_TYPE_ID => "BinaryView".to_string(),
+            UTF8_VIEW_TYPE_ID => "Utf8View".to_string(),
+            LIST_VIEW_TYPE_ID => "ListView".to_string(),
+            LARGE_LIST_VIEW_TYPE_ID => "LargeListView".to_string(),
+            id => format!("with id {id}"),
+        };
+        return Err(ArrowError::NotYetImplemented(format!(
+            "IPC type {name} of field \"{}\" is not supported",
+            field.name().unwrap_or_default()
+        )));
+    }
+    field
+        .children()
+        .into_iter()
+        .flatten()
+        .try_for_each(check_field_type)
+}
+
 /// Try deserialize flat buffer format bytes into a schema
 pub fn try_schema_from_flatbuffer_bytes(bytes: &[u8]) -> Result<Schema, ArrowError> {
     if let Ok(ipc) = crate::root_as_message(bytes) {
-        if let Some(schema) = ipc.header_as_schema().map(fb_to_schema) {
-            Ok(schema)
+        if let Some(schema) = ipc.header_as_schema() {
+            try_fb_to_schema(schema)
         } else {
             Err(ArrowError::ParseError(
                 "Unable to get head as schema".to_string(),
@@ -190,7 +248,7 @@ pub fn try_schema_from_ipc_buffer(buffer: &[u8]) -> Result<Schema, ArrowError> {
         let ipc_schema = msg.header_as_schema().ok_or_else(|| {
             ArrowError::ParseError("Unable to convert flight info to a schema".to_string())
         })?;
-        Ok(fb_to_schema(ipc_schema))
+        try_fb_to_schema(ipc_schema)
     } else {
         Err(ArrowError::ParseError(
             "The buffer length is less than 4 and missing the continuation maker or length of buffer".to_string()
@@ -221,11 +279,109 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
         }
     }
 
+    let fixed_width = field
+        .type_()
+        .and_then(|type_| get_fixed_width_data_type(field.type_type(), type_));
+    if let Some(data_type) = fixed_width {
+        return data_type;
+    }
+
     match field.type_type() {
         crate::Type::Null => DataType::Null,
+        crate::Type::Binary => DataType::Binary,
+        crate::Type::LargeBinary => DataType::LargeBinary,
+        crate::Type::Utf8 => DataType::Utf8,
+        crate::Type::LargeUtf8 => DataType::LargeUtf8,
+        crate::Type::List => {
+            let children = field.children().unwrap();
+            if children.len() != 1 {
+                panic!("expect a list to have one child")
+            }
+            DataType::List(Arc::new(children.get(0).into()))
+        }
+        crate::Type::LargeList => {
+            let children = field.children().unwrap();
+            if children.len() != 1 {
+                panic!("expect a large list to have one child")
+            }
+            DataType::LargeList(Arc::new(children.get(0).into()))
+        }
+        crate::Type::FixedSizeList => {
+            let children = field.children().unwrap();
+            if children.len() != 1 {
+                panic!("expect a list to have one child")
+            }
+            let fsl = field.type_as_fixed_size_list().unwrap();
+            DataType::FixedSizeList(Arc::new(children.get(0).into()), fsl.listSize())
+        }
+        crate::Type::Struct_ => {
+            let fields = match field.children() {
+                Some(children) => children.iter().map(Field::from).collect(),
+                None => Fields::empty(),
+            };
+            DataType::Struct(fields)
+        }
+        crate::Type::RunEndEncoded => {
+            let children = field.children().unwrap();
+            if children.len() != 2 {
+                panic!(
+                    "RunEndEncoded type should have exactly two children. Found {}",
+                    children.len()
+                )
+            }
+            let run_ends_field = children.get(0).into();
+            let values_field = children.get(1).into();
+            DataType::RunEndEncoded(Arc::new(run_ends_field), Arc::new(values_field))
+        }
+        crate::Type::Map => {
+            let map = field.type_as_map().unwrap();
+            let children = field.children().unwrap();
+            if children.len() != 1 {
+                panic!("expect a map to have one child")
+            }
+            DataType::Map(Arc::new(children.get(0).into()), map.keysSorted())
+        }
+        crate::Type::Union => {
+            let union = field.type_as_union().unwrap();
+
+            let union_mode = match union.mode() {
+                crate::UnionMode::Dense => UnionMode::Dense,
+                crate::UnionMode::Sparse => UnionMode::Sparse,
+                mode => panic!("Unexpected union mode: {mode:?}"),
+            };
+
+            let mut fields = vec![];
+            if let Some(children) = field.children() {
+                for i in 0..children.len() {
+                    fields.push(Field::from(children.get(i)));
+                }
+            };
+
+            let fields = match union.typeIds() {
+                None => UnionFields::new(0_i8..fields.len() as i8, fields),
+                Some(ids) => UnionFields::new(ids.iter().map(|i| i as i8), fields),
+            };
+
+            DataType::Union(fields, union_mode)
+        }
+        t => unimplemented!("Type {:?} not supported", t),
+    }
+}
+
+/// Get the Arrow data type from the table of a fixed-width flatbuffer type, as stored
+/// in a Field or Tensor table, returning `None` for other types
+fn get_fixed_width_data_type(type_type: crate::Type, type_: Table) -> Option<DataType> {
+    // SAFETY: `type_type` identifies the type of the `type_` table
+    macro_rules! table {
+        ($t:ident) => {
+            unsafe { crate::$t::init_from_table(type_) }
+        };
+    }
+
+    Some(match type_type {
         crate::Type::Bool => DataType::Boolean,
         crate::Type::Int => {
-            let int = field.type_as_int().unwrap();
+            let int = table!(Int);
             match (int.bitWidth(), int.is_signed()) {
                 (8, true) => DataType::Int8,
                 (8, false) => DataType::UInt8,
@@ -241,16 +397,12 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
                 ),
             }
         }
-        crate::Type::Binary => DataType::Binary,
-        crate::Type::LargeBinary => DataType::LargeBinary,
-        crate::Type::Utf8 => DataType::Utf8,
-        crate::Type::LargeUtf8 => DataType::LargeUtf8,
         crate::Type::FixedSizeBinary => {
-            let fsb = field.type_as_fixed_size_binary().unwrap();
+            let fsb = table!(FixedSizeBinary);
             DataType::FixedSizeBinary(fsb.byteWidth())
         }
         crate::Type::FloatingPoint => {
-            let float = field.type_as_floating_point().unwrap();
+            let float = table!(FloatingPoint);
             match float.precision() {
                 crate::Precision::HALF => DataType::Float16,
                 crate::Precision::SINGLE => DataType::Float32,
@@ -259,7 +411,7 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
             }
         }
         crate::Type::Date => {
-            let date = field.type_as_date().unwrap();
+            let date = table!(Date);
             match date.unit() {
                 crate::DateUnit::DAY => DataType::Date32,
                 crate::DateUnit::MILLISECOND => DataType::Date64,
@@ -267,7 +419,7 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
             }
         }
         crate::Type::Time => {
-            let time = field.type_as_time().unwrap();
+            let time = table!(Time);
             match (time.bitWidth(), time.unit()) {
                 (32, crate::TimeUnit::SECOND) => DataType::Time32(TimeUnit::Second),
                 (32, crate::TimeUnit::MILLISECOND) => DataType::Time32(TimeUnit::Millisecond),
@@ -280,7 +432,7 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
             }
         }
         crate::Type::Timestamp => {
-            let timestamp = field.type_as_timestamp().unwrap();
+            let timestamp = table!(Timestamp);
             let timezone: Option<_> = timestamp.timezone().map(|tz| tz.into());
             match timestamp.unit() {
                 crate::TimeUnit::SECOND => DataType::Timestamp(TimeUnit::Second, timezone),
@@ -295,7 +447,7 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
             }
         }
         crate::Type::Interval => {
-            let interval = field.type_as_interval().unwrap();
+            let interval = table!(Interval);
             match interval.unit() {
                 crate::IntervalUnit::YEAR_MONTH => DataType::Interval(IntervalUnit::YearMonth),
                 crate::IntervalUnit::DAY_TIME => DataType::Interval(IntervalUnit::DayTime),
@@ -306,7 +458,7 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
             }
         }
         crate::Type::Duration => {
-            let duration = field.type_as_duration().unwrap();
+            let duration = table!(Duration);
             match duration.unit() {
                 crate::TimeUnit::SECOND => DataType::Duration(TimeUnit::Second),
                 crate::TimeUnit::MILLISECOND => DataType::Duration(TimeUnit::Millisecond),
@@ -315,57 +467,8 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
                 z => panic!("Duration type with unit of {z:?} unsupported"),
             }
         }
-        crate::Type::List => {
-            let children = field.children().unwrap();
-            if children.len() != 1 {
-                panic!("expect a list to have one child")
-            }
-            DataType::List(Arc::new(children.get(0).into()))
-        }
-        crate::Type::LargeList => {
-            let children = field.children().unwrap();
-            if children.len() != 1 {
-                panic!("expect a large list to have one child")
-            }
-            DataType::LargeList(Arc::new(children.get(0).into()))
-        }
-        crate::Type::FixedSizeList => {
-            let children = field.children().unwrap();
-            if children.len() != 1 {
-                panic!("expect a list to have one child")
-            }
-            let fsl = field.type_as_fixed_size_list().unwrap();
-            DataType::FixedSizeList(Arc::new(children.get(0).into()), fsl.listSize())
-        }
-        crate::Type::Struct_ => {
-            let fields = match field.children() {
-                Some(children) => children.iter().map(Field::from).collect(),
-                None => Fields::empty(),
-            };
-            DataType::Struct(fields)
-        }
-        crate::Type::RunEndEncoded => {
-            let children = field.children().unwrap();
-            if children.len() != 2 {
-                panic!(
-                    "RunEndEncoded type should have exactly two children. Found {}",
-                    children.len()
-                )
-            }
-            let run_ends_field = children.get(0).into();
-            let values_field = children.get(1).into();
-            DataType::RunEndEncoded(Arc::new(run_ends_field), Arc::new(values_field))
-        }
-        crate::Type::Map => {
-            let map = field.type_as_map().unwrap();
-            let children = field.children().unwrap();
-            if children.len() != 1 {
-                panic!("expect a map to have one child")
-            }
-            DataType::Map(Arc::new(children.get(0).into()), map.keysSorted())
-        }
         crate::Type::Decimal => {
-            let fsb = field.type_as_decimal().unwrap();
+            let fsb = table!(Decimal);
             let bit_width = fsb.bitWidth();
             if bit_width == 128 {
                 DataType::Decimal128(
@@ -381,31 +484,38 @@ pub(crate) fn get_data_type(field: crate::Field, may_be_dictionary: bool) -> Dat
                 panic!("Unexpected decimal bit width {bit_width}")
             }
         }
-        crate::Type::Union => {
-            let union = field.type_as_union().unwrap();
-
-            let union_mode = match union.mode() {
-                crate::UnionMode::Dense => UnionMode::Dense,
-                crate::UnionMode::Sparse => UnionMode::Sparse,
-                mode => panic!("Unexpected union mode: {mode:?}"),
-            };
-
-            let mut fields = vec![];
-            if let Some(children) = field.children() {
-                for i in 0..children.len() {
-                    fields.push(Field::from(children.get(i)));
-                }
-            };
+        _ => return None,
+    })
+}
 
-            let fields = match union.typeIds() {
-                None => UnionFields::new(0_i8..fields.len() as i8, fields),
-                Some(ids) => UnionFields::new(ids.iter().map(|i| i as i8), fields),
-            };
+/// Get the Arrow data type of the values of a flatbuffer Tensor table
+///
+/// Returns an error if the values are not of a fixed-width type, as required by the
+/// specification
+pub fn tensor_data_type(tensor: crate::Tensor) -> Result<DataType, ArrowError> {
+    get_fixed_width_data_type(tensor.type_type(), tensor.type_()).ok_or_else(|| {
+        ArrowError::IpcError(format!(
+            "Tensor values of type {:?} are not supported",
+            tensor.type_type()
+        ))
+    })
+}
 
-            DataType::Union(fields, union_mode)
-        }
-        t => unimplemented!("Type {:?} not supported", t),
+/// Get the IPC type of the values of a tensor of `data_type`, returning the
+/// [`crate::Type`] and table to store in a [`crate::Tensor`]
+///
+/// Returns an error if `data_type` is not a fixed-width type
+pub fn tensor_type_to_fb<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    data_type: &DataType,
+) -> Result<(crate::Type, WIPOffset<UnionWIPOffset>), ArrowError> {
+    if !matches!(data_type, Boolean | FixedSizeBinary(_)) && data_type.primitive_width().is_none() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Tensor values of type {data_type} are not supported"
+        )));
     }
+    let field_type = get_fb_field_type(data_type, fbb);
+    Ok((field_type.type_type, field_type.type_))
 }
 
 pub(crate) struct FBFieldType<'b> {
@@ -1055,4 +1165,49 @@ mod tests {
         assert!(ipc.custom_metadata().is_none());
         assert!(ipc2.custom_metadata().is_none());
     }
+
+    #[test]
+    fn try_fb_to_schema_unsupported() {
+        // Build a schema containing a struct with a Utf8View child, as written by
+        // implementations supporting the view types
+        let mut fbb = FlatBufferBuilder::new();
+        let name = fbb.create_string("view");
+        let type_ = crate::Utf8Builder::new(&mut fbb).finish().as_union_value();
+        let mut field = crate::FieldBuilder::new(&mut fbb);
+        field.add_name(name);
+        field.add_type_type(crate::Type(UTF8_VIEW_TYPE_ID));
+        field.add_type_(type_);
+        field.add_nullable(true);
+        let child = field.finish();
+
+        let children = fbb.create_vector(&[child]);
+        let name = fbb.create_string("struct");
+        let type_ = crate::Struct_Builder::new(&mut fbb)
+            .finish()
+            .as_union_value();
+        let mut field = crate::FieldBuilder::new(&mut fbb);
+        field.add_name(name);
+        field.add_type_type(crate::Type::Struct_);
+        field.add_type_(type_);
+        field.add_children(children);
+        let field = field.finish();
+
+        let fields = fbb.create_vector(&[field]);
+        let mut schema = crate::SchemaBuilder::new(&mut fbb);
+        schema.add_fields(fields);
+        let schema = schema.finish();
+        fbb.finish(schema, None);
+
+        let ipc = crate::root_as_schema(fbb.finished_data()).unwrap();
+        let err = try_fb_to_schema(ipc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: IPC type Utf8View of field \"view\" is not supported"
+        );
+
+        let schema = Schema::new(vec![Field::new("a", DataType::Utf8, true)]);
+        let fb = schema_to_fb(&schema);
+        let ipc = crate::root_as_schema(fb.finished_data()).unwrap();
+        assert_eq!(try_fb_to_schema(ipc).unwrap(), schema);
+    }
 }
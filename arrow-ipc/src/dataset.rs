@@ -0,0 +1,518 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Read multiple Arrow IPC files as a single stream of [`RecordBatch`]
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch, RecordBatchReader, StringArray};
+//! # use arrow_ipc::dataset::DatasetReaderBuilder;
+//! # use arrow_ipc::writer::FileWriter;
+//! let dir = tempfile::tempdir().unwrap();
+//!
+//! let a = RecordBatch::try_from_iter([
+//!     ("id", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+//! ]).unwrap();
+//! let b = RecordBatch::try_from_iter([
+//!     ("id", Arc::new(Int32Array::from(vec![3])) as ArrayRef),
+//!     ("name", Arc::new(StringArray::from(vec!["c"])) as ArrayRef),
+//! ]).unwrap();
+//!
+//! for (name, batch) in [("a.arrow", &a), ("b.arrow", &b)] {
+//!     let file = std::fs::File::create(dir.path().join(name)).unwrap();
+//!     let mut writer = FileWriter::try_new(file, &batch.schema()).unwrap();
+//!     writer.write(batch).unwrap();
+//!     writer.finish().unwrap();
+//! }
+//!
+//! let reader = DatasetReaderBuilder::try_new_from_dir(dir.path())
+//!     .unwrap()
+//!     .build()
+//!     .unwrap();
+//!
+//! // The schemas of the files are unified
+//! assert_eq!(reader.schema().fields().len(), 2);
+//!
+//! let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+//! assert_eq!(batches.len(), 2);
+//! // Columns missing from a file are filled with nulls
+//! assert_eq!(batches[0].column(1).null_count(), 2);
+//! ```
+
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow_array::{
+    new_null_array, ArrayRef, BooleanArray, RecordBatch, RecordBatchOptions, RecordBatchReader,
+};
+use arrow_cast::cast;
+use arrow_schema::{ArrowError, FieldRef, Schema, SchemaRef};
+use arrow_select::filter::filter_record_batch;
+
+use crate::reader::{FileReader, FileReaderBuilder};
+
+/// A predicate evaluated against each [`RecordBatch`] read by a [`DatasetReader`],
+/// returning the rows to keep
+type RowFilter = Box<dyn FnMut(&RecordBatch) -> Result<BooleanArray, ArrowError> + Send>;
+
+/// Builds a [`DatasetReader`] reading multiple Arrow IPC files
+pub struct DatasetReaderBuilder {
+    /// The files to read, in order
+    paths: Vec<PathBuf>,
+    /// Optional schema to coerce the files to, otherwise unified from the files
+    schema: Option<SchemaRef>,
+    /// Optional projection into the dataset schema
+    projection: Option<Vec<usize>>,
+    /// Optional row filter
+    filter: Option<RowFilter>,
+}
+
+impl fmt::Debug for DatasetReaderBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DatasetReaderBuilder")
+            .field("paths", &self.paths)
+            .field("schema", &self.schema)
+            .field("projection", &self.projection)
+            .field("filter", &self.filter.is_some())
+            .finish()
+    }
+}
+
+impl DatasetReaderBuilder {
+    /// Create a new [`DatasetReaderBuilder`] reading the given files, in order
+    pub fn new<I, P>(paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        Self {
+            paths: paths.into_iter().map(Into::into).collect(),
+            schema: None,
+            projection: None,
+            filter: None,
+        }
+    }
+
+    /// Create a new [`DatasetReaderBuilder`] reading the files with the extension
+    /// `arrow` within the directory `dir`, ordered by file name
+    pub fn try_new_from_dir(dir: impl AsRef<Path>) -> Result<Self, ArrowError> {
+        let mut paths = vec![];
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().map_or(false, |e| e == "arrow") {
+                paths.push(path);
+            }
+        }
+        paths.sort_unstable();
+        Ok(Self::new(paths))
+    }
+
+    /// Set the schema of the dataset
+    ///
+    /// Columns of each file are cast to the type of the field with the same name,
+    /// and fields not present in a file are filled with nulls. Columns of a file
+    /// not present in the schema are ignored.
+    ///
+    /// If not specified, the schema is determined by merging the schemas of all
+    /// files, see [`Schema::try_merge`], with fields missing from any file made
+    /// nullable
+    pub fn with_schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Only read the columns with the given (zero-based) indices in the dataset schema
+    ///
+    /// The projection is translated to each file by column name, and so columns not
+    /// included are not decoded
+    pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Only return the rows for which `filter` returns `true`
+    ///
+    /// The filter is evaluated against each projected [`RecordBatch`], after it has
+    /// been coerced to the dataset schema. Null values are treated as `false`
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+    where
+        F: FnMut(&RecordBatch) -> Result<BooleanArray, ArrowError> + Send + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Build the [`DatasetReader`]
+    ///
+    /// If no schema was set with [`Self::with_schema`], this reads the footer of every
+    /// file to determine the dataset schema. Otherwise no file is opened until the
+    /// reader reaches it
+    pub fn build(self) -> Result<DatasetReader, ArrowError> {
+        let schema = match self.schema {
+            Some(schema) => schema,
+            None => {
+                let mut schemas = Vec::with_capacity(self.paths.len());
+                for path in &self.paths {
+                    // An empty projection avoids decoding any dictionaries
+                    let reader = FileReader::try_new(open(path)?, Some(vec![]))?;
+                    schemas.push(reader.schema());
+                }
+                Arc::new(unify_schemas(&schemas)?)
+            }
+        };
+
+        let projected = match &self.projection {
+            Some(projection) => Arc::new(schema.project(projection)?),
+            None => schema,
+        };
+
+        Ok(DatasetReader {
+            schema: projected,
+            paths: self.paths.into_iter(),
+            current: None,
+            filter: self.filter,
+        })
+    }
+}
+
+/// Merge `schemas`, making any field not present in all schemas nullable
+fn unify_schemas(schemas: &[SchemaRef]) -> Result<Schema, ArrowError> {
+    let merged = Schema::try_merge(schemas.iter().map(|s| s.as_ref().clone()))?;
+    let fields: Vec<FieldRef> = merged
+        .fields()
+        .iter()
+        .map(|field| {
+            let missing = schemas.iter().any(|s| s.index_of(field.name()).is_err());
+            match missing && !field.is_nullable() {
+                true => Arc::new(field.as_ref().clone().with_nullable(true)),
+                false => field.clone(),
+            }
+        })
+        .collect();
+    Ok(Schema::new_with_metadata(fields, merged.metadata().clone()))
+}
+
+fn open(path: &Path) -> Result<BufReader<File>, ArrowError> {
+    let file = File::open(path)
+        .map_err(|e| ArrowError::IoError(format!("Failed to open {}: {e}", path.display()), e))?;
+    Ok(BufReader::new(file))
+}
+
+/// The file currently being read by a [`DatasetReader`]
+struct FileState {
+    reader: FileReader<BufReader<File>>,
+    /// For each field of the dataset schema, the index of the corresponding
+    /// column in the batches read from `reader`, if any
+    columns: Vec<Option<usize>>,
+}
+
+/// Reads a sequence of Arrow IPC files as a single [`RecordBatchReader`],
+/// created with [`DatasetReaderBuilder`]
+///
+/// Each file is read in turn, with its batches coerced to the dataset schema
+pub struct DatasetReader {
+    /// The projected dataset schema
+    schema: SchemaRef,
+    /// The files yet to be opened
+    paths: std::vec::IntoIter<PathBuf>,
+    /// The file currently being read
+    current: Option<FileState>,
+    filter: Option<RowFilter>,
+}
+
+impl fmt::Debug for DatasetReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DatasetReader")
+            .field("schema", &self.schema)
+            .field("paths", &self.paths)
+            .field("filter", &self.filter.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl DatasetReader {
+    /// Open the file at `path`, projecting it to the columns in the dataset schema
+    fn open_file(&self, path: &Path) -> Result<FileState, ArrowError> {
+        // Project the columns by name, so that the footer is only parsed once
+        let names = self.schema.fields().iter().map(|f| f.name().as_str());
+        let reader = FileReaderBuilder::new()
+            .with_projection_names(names)
+            .with_skip_missing_names(true)
+            .build(open(path)?)?;
+        let file_schema = reader.schema();
+
+        // The projected columns are in the order of the dataset schema
+        let mut num_columns = 0;
+        let mut columns = Vec::with_capacity(self.schema.fields().len());
+        for field in self.schema.fields() {
+            match file_schema.index_of(field.name()) {
+                Ok(_) => {
+                    columns.push(Some(num_columns));
+                    num_columns += 1;
+                }
+                Err(_) if field.is_nullable() => columns.push(None),
+                Err(_) => {
+                    return Err(ArrowError::SchemaError(format!(
+                        "File {} does not contain non-nullable field \"{}\"",
+                        path.display(),
+                        field.name()
+                    )))
+                }
+            }
+        }
+        Ok(FileState { reader, columns })
+    }
+
+    fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        loop {
+            let state = match &mut self.current {
+                Some(state) => state,
+                None => match self.paths.next() {
+                    Some(path) => {
+                        let state = self.open_file(&path)?;
+                        self.current.insert(state)
+                    }
+                    None => return Ok(None),
+                },
+            };
+
+            let batch = match state.reader.next().transpose()? {
+                Some(batch) => batch,
+                None => {
+                    self.current = None;
+                    continue;
+                }
+            };
+
+            let batch = coerce(&self.schema, batch, &state.columns)?;
+
+            return match &mut self.filter {
+                Some(filter) => {
+                    let predicate = filter(&batch)?;
+                    filter_record_batch(&batch, &predicate).map(Some)
+                }
+                None => Ok(Some(batch)),
+            };
+        }
+    }
+}
+
+/// Coerce a batch read with `columns` to `schema`, see [`FileState::columns`]
+fn coerce(
+    schema: &SchemaRef,
+    batch: RecordBatch,
+    columns: &[Option<usize>],
+) -> Result<RecordBatch, ArrowError> {
+    let arrays = schema
+        .fields()
+        .iter()
+        .zip(columns)
+        .map(|(field, column)| {
+            coerce_column(field, column.map(|c| batch.column(c)), batch.num_rows())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    RecordBatch::try_new_with_options(schema.clone(), arrays, &options)
+}
+
+/// Cast `array` to the type of `field`, or create a null array if not present
+fn coerce_column(
+    field: &FieldRef,
+    array: Option<&ArrayRef>,
+    len: usize,
+) -> Result<ArrayRef, ArrowError> {
+    match array {
+        Some(array) if array.data_type() == field.data_type() => Ok(array.clone()),
+        Some(array) => cast(array, field.data_type()),
+        None => Ok(new_null_array(field.data_type(), len)),
+    }
+}
+
+impl Iterator for DatasetReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.maybe_next().transpose()
+    }
+}
+
+impl RecordBatchReader for DatasetReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::FileWriter;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{Int32Array, Int64Array, StringArray};
+    use arrow_schema::{DataType, Field};
+
+    fn write(path: &Path, batches: &[RecordBatch]) {
+        let file = File::create(path).unwrap();
+        let mut writer = FileWriter::try_new(file, &batches[0].schema()).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn write_dataset() -> (tempfile::TempDir, RecordBatch) {
+        let dir = tempfile::tempdir().unwrap();
+        let a = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("id", DataType::Int32, false),
+                Field::new("name", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+            ],
+        )
+        .unwrap();
+        let b = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("score", DataType::Int32, false),
+                Field::new("id", DataType::Int32, false),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![10, 20])),
+                Arc::new(Int32Array::from(vec![4, 5])),
+            ],
+        )
+        .unwrap();
+        write(&dir.path().join("1.arrow"), std::slice::from_ref(&a));
+        write(&dir.path().join("2.arrow"), &[b.clone(), b]);
+        std::fs::write(dir.path().join("README"), "not an arrow file").unwrap();
+        (dir, a)
+    }
+
+    #[test]
+    fn test_unified_schema() {
+        let (dir, a) = write_dataset();
+        let reader = DatasetReaderBuilder::try_new_from_dir(dir.path())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("score", DataType::Int32, true),
+        ]);
+        assert_eq!(reader.schema().as_ref(), &expected);
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].column(0), a.column(0));
+        assert_eq!(batches[0].column(1), a.column(1));
+        assert_eq!(batches[0].column(2).null_count(), 3);
+
+        for batch in &batches[1..] {
+            assert_eq!(batch.column(1).null_count(), 2);
+            let ids = batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Int32Type>();
+            assert_eq!(ids.values(), &[4, 5]);
+            let scores = batch
+                .column(2)
+                .as_primitive::<arrow_array::types::Int32Type>();
+            assert_eq!(scores.values(), &[10, 20]);
+        }
+    }
+
+    #[test]
+    fn test_projection_filter_and_coercion() {
+        let (dir, _) = write_dataset();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("id", DataType::Int64, false),
+        ]));
+        let reader =
+            DatasetReaderBuilder::new([dir.path().join("1.arrow"), dir.path().join("2.arrow")])
+                .with_schema(schema)
+                .with_projection(vec![1])
+                .with_filter(|batch| {
+                    let ids = batch.column(0).as_primitive::<Int64Type>();
+                    Ok(ids.iter().map(|v| v.map(|v| v % 2 == 1)).collect())
+                })
+                .build()
+                .unwrap();
+
+        let expected = Schema::new(vec![Field::new("id", DataType::Int64, false)]);
+        assert_eq!(reader.schema().as_ref(), &expected);
+
+        let ids: Vec<_> = reader
+            .map(|b| b.unwrap().column(0).as_primitive::<Int64Type>().clone())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                Int64Array::from(vec![1, 3]),
+                Int64Array::from(vec![5]),
+                Int64Array::from(vec![5])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_non_nullable() {
+        let (dir, _) = write_dataset();
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "score",
+            DataType::Int32,
+            false,
+        )]));
+        let mut reader = DatasetReaderBuilder::try_new_from_dir(dir.path())
+            .unwrap()
+            .with_schema(schema)
+            .build()
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err().to_string();
+        assert!(
+            err.contains("does not contain non-nullable field \"score\""),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_incompatible_schemas() {
+        let (dir, _) = write_dataset();
+        let batch = RecordBatch::try_from_iter([(
+            "id",
+            Arc::new(StringArray::from(vec!["x"])) as ArrayRef,
+        )])
+        .unwrap();
+        write(&dir.path().join("3.arrow"), &[batch]);
+
+        let err = DatasetReaderBuilder::try_new_from_dir(dir.path())
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Fail to merge schema field 'id'"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Byte-swapping of buffers for reading and writing data with non-native endianness
+
+use arrow_data::BufferSpec;
+use arrow_schema::{ArrowError, DataType, IntervalUnit};
+
+/// Returns an error if `endianness` is neither little nor big endian
+pub(crate) fn check_endianness(
+    endianness: crate::Endianness,
+) -> Result<crate::Endianness, ArrowError> {
+    match endianness {
+        crate::Endianness::Little | crate::Endianness::Big => Ok(endianness),
+        _ => Err(ArrowError::IpcError(format!(
+            "Unsupported endianness {endianness:?}"
+        ))),
+    }
+}
+
+/// Returns the widths in bytes of the components of each element of the values
+/// buffer of a fixed-width `data_type`, which are byte-swapped individually
+pub(crate) fn value_components(data_type: &DataType) -> Vec<usize> {
+    match data_type {
+        DataType::Interval(IntervalUnit::DayTime) => vec![4, 4],
+        DataType::Interval(IntervalUnit::MonthDayNano) => vec![4, 4, 8],
+        DataType::FixedSizeBinary(_) => vec![],
+        DataType::Dictionary(key, _) => value_components(key),
+        _ => match data_type.primitive_width() {
+            Some(width) if width > 1 => vec![width],
+            _ => vec![],
+        },
+    }
+}
+
+/// Returns the widths in bytes of the components of each element of the buffer
+/// of `data_type` described by `spec`, see [`value_components`]
+///
+/// Offsets buffers, such as those of variable-length and dense union arrays,
+/// consist of a single component of the offset width
+pub(crate) fn buffer_components(data_type: &DataType, spec: &BufferSpec) -> Vec<usize> {
+    match (data_type, spec) {
+        (DataType::FixedSizeBinary(_), _) => vec![],
+        (_, BufferSpec::FixedWidth { byte_width, .. }) => {
+            let components = value_components(data_type);
+            match components.iter().sum::<usize>() == *byte_width {
+                true => components,
+                false if *byte_width > 1 => vec![*byte_width],
+                false => vec![],
+            }
+        }
+        _ => vec![],
+    }
+}
+
+/// Reverses the bytes of each component of the elements of `buffer`, where
+/// `components` are the widths in bytes of the components of an element
+pub(crate) fn swap_components(buffer: &[u8], components: &[usize]) -> Vec<u8> {
+    let element_width: usize = components.iter().sum();
+    let mut swapped = buffer.to_vec();
+    if element_width == 0 {
+        return swapped;
+    }
+    for element in swapped.chunks_exact_mut(element_width) {
+        let mut start = 0;
+        for width in components {
+            element[start..start + width].reverse();
+            start += width;
+        }
+    }
+    swapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_data::layout;
+    use arrow_schema::{Field, UnionFields, UnionMode};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_buffer_components() {
+        let components = |data_type: DataType| -> Vec<Vec<usize>> {
+            layout(&data_type)
+                .buffers
+                .iter()
+                .map(|spec| buffer_components(&data_type, spec))
+                .collect()
+        };
+        assert_eq!(components(DataType::Int8), vec![vec![]]);
+        assert_eq!(components(DataType::Float64), vec![vec![8]]);
+        assert_eq!(components(DataType::Decimal256(4, 2)), vec![vec![32]]);
+        assert_eq!(components(DataType::Boolean), vec![vec![]]);
+        assert_eq!(components(DataType::Utf8), vec![vec![4], vec![]]);
+        assert_eq!(components(DataType::LargeBinary), vec![vec![8], vec![]]);
+        assert_eq!(components(DataType::FixedSizeBinary(4)), vec![vec![]]);
+        assert_eq!(
+            components(DataType::Interval(IntervalUnit::DayTime)),
+            vec![vec![4, 4]]
+        );
+        assert_eq!(
+            components(DataType::Interval(IntervalUnit::MonthDayNano)),
+            vec![vec![4, 4, 8]]
+        );
+        let list = Arc::new(Field::new("item", DataType::Int32, true));
+        assert_eq!(components(DataType::LargeList(list)), vec![vec![8]]);
+        let dictionary = DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8));
+        assert_eq!(components(dictionary), vec![vec![2]]);
+
+        let fields = UnionFields::new([0], [Field::new("a", DataType::Int64, true)]);
+        let dense = DataType::Union(fields.clone(), UnionMode::Dense);
+        assert_eq!(components(dense), vec![vec![], vec![4]]);
+        let sparse = DataType::Union(fields, UnionMode::Sparse);
+        assert_eq!(components(sparse), vec![vec![]]);
+    }
+
+    #[test]
+    fn test_swap_components() {
+        let buffer = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(swap_components(&buffer, &[]), buffer);
+        assert_eq!(swap_components(&buffer, &[8]), [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(swap_components(&buffer, &[2]), [2, 1, 4, 3, 6, 5, 8, 7]);
+        assert_eq!(swap_components(&buffer, &[2, 2]), [2, 1, 4, 3, 6, 5, 8, 7]);
+        assert_eq!(swap_components(&buffer, &[4, 4]), [4, 3, 2, 1, 8, 7, 6, 5]);
+    }
+}
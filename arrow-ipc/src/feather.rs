@@ -0,0 +1,761 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for reading the legacy Feather v1 format
+//!
+//! Feather v1 predates the Arrow IPC file format, and stores a single table as a
+//! sequence of arrays followed by flatbuffers metadata, delimited by the `FEA1` magic.
+//! It is still found in archived datasets written by older versions of pandas and R.
+//!
+//! Feather v2 is the Arrow IPC file format, and can be read with
+//! [`FileReader`](crate::reader::FileReader)
+
+use std::fmt;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use arrow_array::{make_array, ArrayRef, RecordBatch, RecordBatchOptions};
+use arrow_buffer::{bit_util, Buffer};
+use arrow_data::{ArrayData, ArrayDataBuilder};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+
+use crate::gen::feather as fbs;
+use crate::reader::read_exact_buffer;
+
+/// The magic at the start and end of a Feather v1 file
+const FEATHER_MAGIC: [u8; 4] = *b"FEA1";
+
+/// The oldest version of the metadata that can be read, older versions stored
+/// arrays without padding
+const FEATHER_MIN_VERSION: i32 = 2;
+
+/// The alignment of the buffers of an array
+const FEATHER_ALIGNMENT: usize = 8;
+
+/// The location of an array in a Feather v1 file
+#[derive(Debug, Clone)]
+struct ArrayLayout {
+    /// The position of the array relative to the start of the file
+    offset: u64,
+    length: usize,
+    null_count: usize,
+    total_bytes: usize,
+}
+
+impl ArrayLayout {
+    /// Decodes `array`, returning its layout and physical type
+    ///
+    /// Returns an error if the array does not lie within the first `data_len` bytes
+    fn try_new(array: &fbs::PrimitiveArray, data_len: u64) -> Result<(Self, DataType), ArrowError> {
+        if array.encoding() != fbs::Encoding::PLAIN {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Feather array encoding {:?} is not supported",
+                array.encoding()
+            )));
+        }
+        let data_type = physical_type(array.type_())?;
+        let layout = Self {
+            offset: non_negative(array.offset(), "offset")?,
+            length: non_negative(array.length(), "length")?,
+            null_count: non_negative(array.null_count(), "null count")?,
+            total_bytes: non_negative(array.total_bytes(), "total bytes")?,
+        };
+
+        let end = layout.offset.checked_add(layout.total_bytes as u64);
+        if end.map_or(true, |end| end > data_len) {
+            return Err(ArrowError::ParseError(format!(
+                "Feather array of {} bytes at offset {} exceeds the data of {data_len} bytes",
+                layout.total_bytes, layout.offset
+            )));
+        }
+        Ok((layout, data_type))
+    }
+}
+
+/// The location and type of a column in a Feather v1 file
+#[derive(Debug, Clone)]
+struct ColumnLayout {
+    data_type: DataType,
+    values: ArrayLayout,
+    /// The dictionary values of a categorical column
+    levels: Option<ArrayLayout>,
+}
+
+/// Reads a Feather v1 file
+///
+/// The metadata is decoded on construction, with the columns only read by
+/// [`Self::read`] or [`Self::read_columns`]
+///
+/// ```no_run
+/// # use std::fs::File;
+/// # use arrow_ipc::feather::FeatherV1Reader;
+/// let file = File::open("data.feather").unwrap();
+/// let mut reader = FeatherV1Reader::try_new(file).unwrap();
+/// let batch = reader.read().unwrap();
+/// assert_eq!(batch.num_rows(), reader.num_rows());
+/// ```
+pub struct FeatherV1Reader<R: Read + Seek> {
+    reader: R,
+    schema: SchemaRef,
+    columns: Vec<ColumnLayout>,
+    num_rows: usize,
+    description: Option<String>,
+}
+
+impl<R: Read + Seek> fmt::Debug for FeatherV1Reader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FeatherV1Reader<R>")
+            .field("schema", &self.schema)
+            .field("num_rows", &self.num_rows)
+            .field("description", &self.description)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read + Seek> FeatherV1Reader<R> {
+    /// Try to create a new reader, decoding the metadata of the file
+    ///
+    /// Returns an error if `reader` is not a valid Feather v1 file, or uses features
+    /// that are not supported
+    pub fn try_new(mut reader: R) -> Result<Self, ArrowError> {
+        // The leading magic, and the trailing metadata length and magic
+        let file_len = reader.seek(SeekFrom::End(0))?;
+        if file_len < 12 {
+            return Err(ArrowError::ParseError(format!(
+                "Feather file of {file_len} bytes is too small"
+            )));
+        }
+
+        let mut magic = [0; 4];
+        reader.seek(SeekFrom::Start(0))?;
+        reader.read_exact(&mut magic)?;
+        let mut footer = [0; 8];
+        reader.seek(SeekFrom::End(-8))?;
+        reader.read_exact(&mut footer)?;
+        if magic != FEATHER_MAGIC || footer[4..] != FEATHER_MAGIC {
+            return Err(ArrowError::ParseError(
+                "Feather file does not contain correct magic".to_string(),
+            ));
+        }
+
+        let metadata_len = i32::from_le_bytes(footer[..4].try_into().unwrap());
+        let data_len = u64::try_from(metadata_len)
+            .ok()
+            .and_then(|len| (file_len - 12).checked_sub(len))
+            .ok_or_else(|| {
+                ArrowError::ParseError(format!("Invalid Feather metadata length: {metadata_len}"))
+            })?
+            + 4;
+
+        reader.seek(SeekFrom::Start(data_len))?;
+        let metadata = read_exact_buffer(&mut reader, metadata_len as usize)?;
+        let table = fbs::root_as_ctable(&metadata).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as Feather table: {err:?}"))
+        })?;
+        if table.version() < FEATHER_MIN_VERSION {
+            return Err(ArrowError::ParseError(format!(
+                "Feather file version {} is no longer supported",
+                table.version()
+            )));
+        }
+
+        let num_rows = non_negative(table.num_rows(), "row count")?;
+        let mut fields = vec![];
+        let mut columns = vec![];
+        for (idx, column) in table.columns().into_iter().flatten().enumerate() {
+            let (field, layout) = decode_column(idx, &column, num_rows, data_len)?;
+            fields.push(field);
+            columns.push(layout);
+        }
+
+        Ok(Self {
+            reader,
+            schema: Arc::new(Schema::new(fields)),
+            columns,
+            num_rows,
+            description: table.description().map(ToString::to_string),
+        })
+    }
+
+    /// Return the schema of the file
+    ///
+    /// All fields are nullable, as nullability is not recorded by Feather v1
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Return the number of rows in the file
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Return the description of the file, if any
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Read all the columns of the file into a [`RecordBatch`]
+    pub fn read(&mut self) -> Result<RecordBatch, ArrowError> {
+        let indices: Vec<_> = (0..self.columns.len()).collect();
+        self.read_columns(&indices)
+    }
+
+    /// Read the columns at `indices` of the file into a [`RecordBatch`]
+    pub fn read_columns(&mut self, indices: &[usize]) -> Result<RecordBatch, ArrowError> {
+        let schema = Arc::new(self.schema.project(indices)?);
+        let columns = indices
+            .iter()
+            .map(|idx| read_column(&mut self.reader, &self.columns[*idx]))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(self.num_rows));
+        RecordBatch::try_new_with_options(schema, columns, &options)
+    }
+}
+
+/// Decodes the column at `idx`, returning its field and layout
+fn decode_column(
+    idx: usize,
+    column: &fbs::Column,
+    num_rows: usize,
+    data_len: u64,
+) -> Result<(Field, ColumnLayout), ArrowError> {
+    let name = column.name().unwrap_or_default();
+    let missing =
+        |what: &str| ArrowError::ParseError(format!("Feather column {name:?} has no {what}"));
+    let values = column.values().ok_or_else(|| missing("values"))?;
+    let (values, physical) = ArrayLayout::try_new(&values, data_len)?;
+    if values.length != num_rows {
+        return Err(ArrowError::ParseError(format!(
+            "Feather column {name:?} has {} rows, expected {num_rows}",
+            values.length
+        )));
+    }
+
+    // The physical type a logical type is stored as
+    let expect = |expected: DataType, logical: DataType| match physical == expected {
+        true => Ok(logical),
+        false => Err(ArrowError::ParseError(format!(
+            "Feather column {name:?} of type {logical} must be stored as {expected}, got {physical}"
+        ))),
+    };
+
+    let mut levels = None;
+    let field = match column.metadata_type() {
+        fbs::TypeMetadata::NONE => Field::new(name, physical.clone(), true),
+        fbs::TypeMetadata::CategoryMetadata => {
+            let metadata = column
+                .metadata_as_category_metadata()
+                .ok_or_else(|| missing("category metadata"))?;
+            let array = metadata.levels().ok_or_else(|| missing("levels"))?;
+            let (layout, value_type) = ArrayLayout::try_new(&array, data_len)?;
+            if !physical.is_dictionary_key_type() {
+                return Err(ArrowError::ParseError(format!(
+                    "Feather column {name:?} has invalid category index type {physical}"
+                )));
+            }
+            levels = Some(layout);
+            let data_type = DataType::Dictionary(Box::new(physical.clone()), Box::new(value_type));
+            Field::new_dict(name, data_type, true, idx as i64, metadata.ordered())
+        }
+        fbs::TypeMetadata::TimestampMetadata => {
+            let metadata = column
+                .metadata_as_timestamp_metadata()
+                .ok_or_else(|| missing("timestamp metadata"))?;
+            let tz = metadata.timezone().filter(|tz| !tz.is_empty());
+            let data_type = DataType::Timestamp(time_unit(metadata.unit())?, tz.map(Into::into));
+            Field::new(name, expect(DataType::Int64, data_type)?, true)
+        }
+        fbs::TypeMetadata::DateMetadata => {
+            Field::new(name, expect(DataType::Int32, DataType::Date32)?, true)
+        }
+        fbs::TypeMetadata::TimeMetadata => {
+            let metadata = column
+                .metadata_as_time_metadata()
+                .ok_or_else(|| missing("time metadata"))?;
+            let data_type = match time_unit(metadata.unit())? {
+                unit @ (TimeUnit::Second | TimeUnit::Millisecond) => {
+                    expect(DataType::Int32, DataType::Time32(unit))?
+                }
+                unit => expect(DataType::Int64, DataType::Time64(unit))?,
+            };
+            Field::new(name, data_type, true)
+        }
+        t => {
+            return Err(ArrowError::ParseError(format!(
+                "Feather column {name:?} has unsupported metadata type {t:?}"
+            )))
+        }
+    };
+
+    let layout = ColumnLayout {
+        data_type: field.data_type().clone(),
+        values,
+        levels,
+    };
+    Ok((field, layout))
+}
+
+/// Reads the column described by `layout` from `reader`
+fn read_column<R: Read + Seek>(
+    reader: &mut R,
+    layout: &ColumnLayout,
+) -> Result<ArrayRef, ArrowError> {
+    let data = match (&layout.data_type, &layout.levels) {
+        (DataType::Dictionary(_, value_type), Some(levels)) => {
+            let levels = read_array(reader, value_type.as_ref().clone(), levels, None)?;
+            read_array(
+                reader,
+                layout.data_type.clone(),
+                &layout.values,
+                Some(levels),
+            )?
+        }
+        (data_type, _) => read_array(reader, data_type.clone(), &layout.values, None)?,
+    };
+    Ok(make_array(data))
+}
+
+/// Reads the array described by `layout` from `reader`, validating its contents
+///
+/// An array consists of an optional validity bitmap, present if it contains nulls,
+/// followed by the offsets of variable length types, followed by the values, with
+/// each buffer padded to [`FEATHER_ALIGNMENT`]
+fn read_array<R: Read + Seek>(
+    reader: &mut R,
+    data_type: DataType,
+    layout: &ArrayLayout,
+    dictionary: Option<ArrayData>,
+) -> Result<ArrayData, ArrowError> {
+    reader.seek(SeekFrom::Start(layout.offset))?;
+    let buffer = Buffer::from(read_exact_buffer(reader, layout.total_bytes)?);
+    let len = layout.length;
+
+    let mut offset = 0_usize;
+    let mut next_buffer = |size: usize| {
+        let start = offset;
+        offset = start.saturating_add(padded(size));
+        match start.checked_add(size) {
+            Some(end) if end <= buffer.len() => Ok(buffer.slice_with_length(start, size)),
+            _ => Err(ArrowError::ParseError(format!(
+                "Feather array of {} bytes is too small for its {len} values",
+                buffer.len()
+            ))),
+        }
+    };
+
+    let nulls = match layout.null_count {
+        0 => None,
+        _ => Some(next_buffer(bit_util::ceil(len, 8))?),
+    };
+    let offset_size = match &data_type {
+        DataType::Utf8 | DataType::Binary => Some(4),
+        DataType::LargeUtf8 | DataType::LargeBinary => Some(8),
+        _ => None,
+    };
+    let mut buffers = vec![];
+    if let Some(size) = offset_size {
+        buffers.push(next_buffer(len.saturating_add(1).saturating_mul(size))?);
+    }
+    let values_start = offset.min(buffer.len());
+    buffers.push(buffer.slice(values_start));
+
+    let builder = ArrayDataBuilder::new(data_type)
+        .len(len)
+        .null_bit_buffer(nulls)
+        .buffers(buffers)
+        .child_data(dictionary.into_iter().collect());
+    builder.build()
+}
+
+/// Returns `size` rounded up to [`FEATHER_ALIGNMENT`]
+fn padded(size: usize) -> usize {
+    bit_util::round_upto_power_of_2(size, FEATHER_ALIGNMENT)
+}
+
+/// Converts `value` of the metadata to `T`, returning an error if it is negative
+fn non_negative<T: TryFrom<i64>>(value: i64, name: &str) -> Result<T, ArrowError> {
+    T::try_from(value)
+        .map_err(|_| ArrowError::ParseError(format!("Invalid Feather {name}: {value}")))
+}
+
+/// Returns the [`DataType`] of the values of an array of type `t`
+fn physical_type(t: fbs::Type) -> Result<DataType, ArrowError> {
+    Ok(match t {
+        fbs::Type::BOOL => DataType::Boolean,
+        fbs::Type::INT8 => DataType::Int8,
+        fbs::Type::INT16 => DataType::Int16,
+        fbs::Type::INT32 => DataType::Int32,
+        fbs::Type::INT64 => DataType::Int64,
+        fbs::Type::UINT8 => DataType::UInt8,
+        fbs::Type::UINT16 => DataType::UInt16,
+        fbs::Type::UINT32 => DataType::UInt32,
+        fbs::Type::UINT64 => DataType::UInt64,
+        fbs::Type::FLOAT => DataType::Float32,
+        fbs::Type::DOUBLE => DataType::Float64,
+        fbs::Type::UTF8 => DataType::Utf8,
+        fbs::Type::BINARY => DataType::Binary,
+        fbs::Type::LARGE_UTF8 => DataType::LargeUtf8,
+        fbs::Type::LARGE_BINARY => DataType::LargeBinary,
+        t => {
+            return Err(ArrowError::ParseError(format!(
+                "Unsupported Feather array type {t:?}"
+            )))
+        }
+    })
+}
+
+fn time_unit(unit: fbs::TimeUnit) -> Result<TimeUnit, ArrowError> {
+    Ok(match unit {
+        fbs::TimeUnit::SECOND => TimeUnit::Second,
+        fbs::TimeUnit::MILLISECOND => TimeUnit::Millisecond,
+        fbs::TimeUnit::MICROSECOND => TimeUnit::Microsecond,
+        fbs::TimeUnit::NANOSECOND => TimeUnit::Nanosecond,
+        u => {
+            return Err(ArrowError::ParseError(format!(
+                "Unsupported Feather time unit {u:?}"
+            )))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::*;
+    use arrow_array::*;
+    use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+    /// The metadata of a column written by [`write_feather`]
+    enum Metadata {
+        None,
+        Category(ArrayRef, bool),
+        Timestamp(fbs::TimeUnit, &'static str),
+        Date,
+        Time(fbs::TimeUnit),
+    }
+
+    fn fbs_type(data_type: &DataType) -> fbs::Type {
+        match data_type {
+            DataType::Boolean => fbs::Type::BOOL,
+            DataType::Int8 => fbs::Type::INT8,
+            DataType::Int16 => fbs::Type::INT16,
+            DataType::Int32 => fbs::Type::INT32,
+            DataType::Int64 => fbs::Type::INT64,
+            DataType::UInt8 => fbs::Type::UINT8,
+            DataType::Float32 => fbs::Type::FLOAT,
+            DataType::Float64 => fbs::Type::DOUBLE,
+            DataType::Utf8 => fbs::Type::UTF8,
+            DataType::Binary => fbs::Type::BINARY,
+            DataType::LargeUtf8 => fbs::Type::LARGE_UTF8,
+            DataType::LargeBinary => fbs::Type::LARGE_BINARY,
+            d => unimplemented!("{d}"),
+        }
+    }
+
+    fn pad(out: &mut Vec<u8>) {
+        out.resize(padded(out.len()), 0);
+    }
+
+    /// Appends `array` to `out`, returning its metadata
+    fn write_array<'a>(
+        fbb: &mut FlatBufferBuilder<'a>,
+        out: &mut Vec<u8>,
+        array: &dyn Array,
+    ) -> WIPOffset<fbs::PrimitiveArray<'a>> {
+        let data = array.to_data();
+        assert_eq!(data.offset(), 0);
+        let offset = out.len();
+        if let Some(nulls) = data.nulls().filter(|n| n.null_count() > 0) {
+            out.extend_from_slice(&nulls.validity()[..bit_util::ceil(data.len(), 8)]);
+            pad(out);
+        }
+        for buffer in data.buffers() {
+            out.extend_from_slice(buffer.as_slice());
+            pad(out);
+        }
+
+        let args = fbs::PrimitiveArrayArgs {
+            type_: fbs_type(data.data_type()),
+            encoding: fbs::Encoding::PLAIN,
+            offset: offset as i64,
+            length: data.len() as i64,
+            null_count: data.null_count() as i64,
+            total_bytes: (out.len() - offset) as i64,
+        };
+        fbs::PrimitiveArray::create(fbb, &args)
+    }
+
+    /// Writes a Feather v1 file in the layout of the Arrow C++ implementation
+    fn write_feather(version: i32, columns: Vec<(&str, ArrayRef, Metadata)>) -> Vec<u8> {
+        let mut out = FEATHER_MAGIC.to_vec();
+        pad(&mut out);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let num_rows = columns.first().map(|(_, a, _)| a.len()).unwrap_or_default();
+        let mut offsets = vec![];
+        for (name, array, metadata) in &columns {
+            let values = write_array(&mut fbb, &mut out, array);
+            let (metadata_type, metadata) = match metadata {
+                Metadata::None => (fbs::TypeMetadata::NONE, None),
+                Metadata::Category(levels, ordered) => {
+                    let args = fbs::CategoryMetadataArgs {
+                        levels: Some(write_array(&mut fbb, &mut out, levels)),
+                        ordered: *ordered,
+                    };
+                    let metadata = fbs::CategoryMetadata::create(&mut fbb, &args);
+                    let t = fbs::TypeMetadata::CategoryMetadata;
+                    (t, Some(metadata.as_union_value()))
+                }
+                Metadata::Timestamp(unit, tz) => {
+                    let args = fbs::TimestampMetadataArgs {
+                        unit: *unit,
+                        timezone: Some(fbb.create_string(tz)),
+                    };
+                    let metadata = fbs::TimestampMetadata::create(&mut fbb, &args);
+                    let t = fbs::TypeMetadata::TimestampMetadata;
+                    (t, Some(metadata.as_union_value()))
+                }
+                Metadata::Date => {
+                    let args = fbs::DateMetadataArgs {};
+                    let metadata = fbs::DateMetadata::create(&mut fbb, &args);
+                    let t = fbs::TypeMetadata::DateMetadata;
+                    (t, Some(metadata.as_union_value()))
+                }
+                Metadata::Time(unit) => {
+                    let args = fbs::TimeMetadataArgs { unit: *unit };
+                    let metadata = fbs::TimeMetadata::create(&mut fbb, &args);
+                    let t = fbs::TypeMetadata::TimeMetadata;
+                    (t, Some(metadata.as_union_value()))
+                }
+            };
+
+            let args = fbs::ColumnArgs {
+                name: Some(fbb.create_string(name)),
+                values: Some(values),
+                metadata_type,
+                metadata,
+                user_metadata: None,
+            };
+            offsets.push(fbs::Column::create(&mut fbb, &args));
+        }
+
+        let args = fbs::CTableArgs {
+            description: Some(fbb.create_string("test file")),
+            num_rows: num_rows as i64,
+            columns: Some(fbb.create_vector(&offsets)),
+            version,
+            metadata: None,
+        };
+        let root = fbs::CTable::create(&mut fbb, &args);
+        fbs::finish_ctable_buffer(&mut fbb, root);
+
+        let metadata = fbb.finished_data();
+        out.extend_from_slice(metadata);
+        out.extend_from_slice(&(metadata.len() as i32).to_le_bytes());
+        out.extend_from_slice(&FEATHER_MAGIC);
+        out
+    }
+
+    fn read(bytes: Vec<u8>) -> Result<RecordBatch, ArrowError> {
+        FeatherV1Reader::try_new(Cursor::new(bytes))?.read()
+    }
+
+    #[test]
+    fn test_read_primitive() {
+        let ints = Int32Array::from(vec![Some(1), None, Some(3), Some(-4), None]);
+        let floats = Float64Array::from(vec![1.5, 2.5, f64::NAN, -0.0, 1e10]);
+        let bools = BooleanArray::from(vec![Some(true), Some(false), None, Some(true), None]);
+        let strings = StringArray::from(vec![Some("a"), None, Some(""), Some("héllo"), None]);
+        let binary = BinaryArray::from(vec![&b"x"[..], b"", b"yz", b"\0", b"abc"]);
+        let large = LargeStringArray::from(vec!["foo", "bar", "baz", "", "qux"]);
+        let file = write_feather(
+            2,
+            vec![
+                ("ints", Arc::new(ints.clone()), Metadata::None),
+                ("floats", Arc::new(floats.clone()), Metadata::None),
+                ("bools", Arc::new(bools.clone()), Metadata::None),
+                ("strings", Arc::new(strings.clone()), Metadata::None),
+                ("binary", Arc::new(binary.clone()), Metadata::None),
+                ("large", Arc::new(large.clone()), Metadata::None),
+            ],
+        );
+
+        let mut reader = FeatherV1Reader::try_new(Cursor::new(file)).unwrap();
+        assert_eq!(reader.num_rows(), 5);
+        assert_eq!(reader.description(), Some("test file"));
+        let schema = reader.schema();
+        assert_eq!(schema.fields().len(), 6);
+        assert!(schema.fields().iter().all(|f| f.is_nullable()));
+
+        let batch = reader.read().unwrap();
+        assert_eq!(batch.column(0).as_primitive::<Int32Type>(), &ints);
+        let col = batch.column(1).as_primitive::<Float64Type>();
+        assert_eq!(col.values().to_vec()[..2], [1.5, 2.5]);
+        assert!(col.value(2).is_nan());
+        assert_eq!(col.value(3).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(batch.column(2).as_boolean(), &bools);
+        assert_eq!(batch.column(3).as_string::<i32>(), &strings);
+        assert_eq!(batch.column(4).as_binary::<i32>(), &binary);
+        assert_eq!(batch.column(5).as_string::<i64>(), &large);
+
+        let batch = reader.read_columns(&[5, 0]).unwrap();
+        assert_eq!(batch.schema().field(0).name(), "large");
+        assert_eq!(batch.column(1).as_primitive::<Int32Type>(), &ints);
+
+        let batch = reader.read_columns(&[]).unwrap();
+        assert_eq!(batch.num_rows(), 5);
+        assert!(reader.read_columns(&[6]).is_err());
+    }
+
+    #[test]
+    fn test_read_logical() {
+        let keys = Int8Array::from(vec![Some(1), None, Some(0), Some(1)]);
+        let levels: ArrayRef = Arc::new(StringArray::from(vec!["low", "high"]));
+        let timestamps = Int64Array::from(vec![Some(1_000), None, Some(-5), Some(0)]);
+        let dates = Int32Array::from(vec![19000, 0, -1, 1]);
+        let times32 = Int32Array::from(vec![0, 1_000, 86_399, 5]);
+        let times64 = Int64Array::from(vec![Some(1), Some(2), None, Some(3)]);
+        let file = write_feather(
+            3,
+            vec![
+                (
+                    "category",
+                    Arc::new(keys.clone()),
+                    Metadata::Category(levels.clone(), true),
+                ),
+                (
+                    "ts",
+                    Arc::new(timestamps),
+                    Metadata::Timestamp(fbs::TimeUnit::MILLISECOND, "UTC"),
+                ),
+                (
+                    "ts_naive",
+                    Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+                    Metadata::Timestamp(fbs::TimeUnit::NANOSECOND, ""),
+                ),
+                ("date", Arc::new(dates), Metadata::Date),
+                (
+                    "time32",
+                    Arc::new(times32),
+                    Metadata::Time(fbs::TimeUnit::MILLISECOND),
+                ),
+                (
+                    "time64",
+                    Arc::new(times64),
+                    Metadata::Time(fbs::TimeUnit::MICROSECOND),
+                ),
+            ],
+        );
+
+        let batch = read(file).unwrap();
+        let schema = batch.schema();
+        let dict_type = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        assert_eq!(schema.field(0).data_type(), &dict_type);
+        assert_eq!(schema.field(0).dict_is_ordered(), Some(true));
+        let expected = DictionaryArray::new(keys, levels);
+        assert_eq!(batch.column(0).as_dictionary::<Int8Type>(), &expected);
+
+        let expected = TimestampMillisecondArray::from(vec![Some(1_000), None, Some(-5), Some(0)])
+            .with_timezone("UTC");
+        assert_eq!(
+            batch.column(1).as_primitive::<TimestampMillisecondType>(),
+            &expected
+        );
+        assert_eq!(
+            schema.field(2).data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, None)
+        );
+        let expected = Date32Array::from(vec![19000, 0, -1, 1]);
+        assert_eq!(batch.column(3).as_primitive::<Date32Type>(), &expected);
+        let expected = Time32MillisecondArray::from(vec![0, 1_000, 86_399, 5]);
+        assert_eq!(
+            batch.column(4).as_primitive::<Time32MillisecondType>(),
+            &expected
+        );
+        let expected = Time64MicrosecondArray::from(vec![Some(1), Some(2), None, Some(3)]);
+        assert_eq!(
+            batch.column(5).as_primitive::<Time64MicrosecondType>(),
+            &expected
+        );
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let file = write_feather(2, vec![("a", ints.clone(), Metadata::None)]);
+
+        let mut bad_magic = file.clone();
+        bad_magic[0] = b'X';
+        let err = read(bad_magic).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Feather file does not contain correct magic"
+        );
+
+        let err = read(file[..8].to_vec()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Feather file of 8 bytes is too small"
+        );
+
+        let mut truncated = file[..8].to_vec();
+        truncated.extend_from_slice(&file[file.len() - 8..]);
+        let err = read(truncated).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Parser error: Invalid Feather metadata length"));
+
+        let err = read(write_feather(1, vec![("a", ints.clone(), Metadata::None)])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Feather file version 1 is no longer supported"
+        );
+
+        // Removing some of the values leaves the column extending into the metadata
+        let long: ArrayRef = Arc::new(Int32Array::from(vec![0; 1000]));
+        let mut file = write_feather(2, vec![("a", long, Metadata::None)]);
+        file.drain(8..3008);
+        let err = read(file).unwrap_err();
+        assert!(err.to_string().contains("exceeds the data"), "{err}");
+
+        let strings: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let file = write_feather(
+            2,
+            vec![("a", ints, Metadata::Timestamp(fbs::TimeUnit::SECOND, ""))],
+        );
+        let err = read(file).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Feather column \"a\" of type Timestamp(Second, None) must be stored as Int64, got Int32"
+        );
+
+        let file = write_feather(
+            2,
+            vec![("a", strings.clone(), Metadata::Category(strings, false))],
+        );
+        let err = read(file).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Feather column \"a\" has invalid category index type Utf8"
+        );
+    }
+}
@@ -0,0 +1,1802 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+#![allow(dead_code)]
+#![allow(unused_imports)]
+
+use flatbuffers::EndianScalar;
+use std::{cmp::Ordering, mem};
+// automatically generated by the FlatBuffers compiler, do not modify
+
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_TYPE: i8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_TYPE: i8 = 18;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TYPE: [Type; 19] = [
+    Type::BOOL,
+    Type::INT8,
+    Type::INT16,
+    Type::INT32,
+    Type::INT64,
+    Type::UINT8,
+    Type::UINT16,
+    Type::UINT32,
+    Type::UINT64,
+    Type::FLOAT,
+    Type::DOUBLE,
+    Type::UTF8,
+    Type::BINARY,
+    Type::CATEGORY,
+    Type::TIMESTAMP,
+    Type::DATE,
+    Type::TIME,
+    Type::LARGE_UTF8,
+    Type::LARGE_BINARY,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Type(pub i8);
+#[allow(non_upper_case_globals)]
+impl Type {
+    pub const BOOL: Self = Self(0);
+    pub const INT8: Self = Self(1);
+    pub const INT16: Self = Self(2);
+    pub const INT32: Self = Self(3);
+    pub const INT64: Self = Self(4);
+    pub const UINT8: Self = Self(5);
+    pub const UINT16: Self = Self(6);
+    pub const UINT32: Self = Self(7);
+    pub const UINT64: Self = Self(8);
+    pub const FLOAT: Self = Self(9);
+    pub const DOUBLE: Self = Self(10);
+    pub const UTF8: Self = Self(11);
+    pub const BINARY: Self = Self(12);
+    pub const CATEGORY: Self = Self(13);
+    pub const TIMESTAMP: Self = Self(14);
+    pub const DATE: Self = Self(15);
+    pub const TIME: Self = Self(16);
+    pub const LARGE_UTF8: Self = Self(17);
+    pub const LARGE_BINARY: Self = Self(18);
+
+    pub const ENUM_MIN: i8 = 0;
+    pub const ENUM_MAX: i8 = 18;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::BOOL,
+        Self::INT8,
+        Self::INT16,
+        Self::INT32,
+        Self::INT64,
+        Self::UINT8,
+        Self::UINT16,
+        Self::UINT32,
+        Self::UINT64,
+        Self::FLOAT,
+        Self::DOUBLE,
+        Self::UTF8,
+        Self::BINARY,
+        Self::CATEGORY,
+        Self::TIMESTAMP,
+        Self::DATE,
+        Self::TIME,
+        Self::LARGE_UTF8,
+        Self::LARGE_BINARY,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::BOOL => Some("BOOL"),
+            Self::INT8 => Some("INT8"),
+            Self::INT16 => Some("INT16"),
+            Self::INT32 => Some("INT32"),
+            Self::INT64 => Some("INT64"),
+            Self::UINT8 => Some("UINT8"),
+            Self::UINT16 => Some("UINT16"),
+            Self::UINT32 => Some("UINT32"),
+            Self::UINT64 => Some("UINT64"),
+            Self::FLOAT => Some("FLOAT"),
+            Self::DOUBLE => Some("DOUBLE"),
+            Self::UTF8 => Some("UTF8"),
+            Self::BINARY => Some("BINARY"),
+            Self::CATEGORY => Some("CATEGORY"),
+            Self::TIMESTAMP => Some("TIMESTAMP"),
+            Self::DATE => Some("DATE"),
+            Self::TIME => Some("TIME"),
+            Self::LARGE_UTF8 => Some("LARGE_UTF8"),
+            Self::LARGE_BINARY => Some("LARGE_BINARY"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for Type {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for Type {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for Type {
+    type Output = Type;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for Type {
+    type Scalar = i8;
+    #[inline]
+    fn to_little_endian(self) -> i8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: i8) -> Self {
+        let b = i8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Type {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        i8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Type {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_ENCODING: i8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_ENCODING: i8 = 1;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ENCODING: [Encoding; 2] = [Encoding::PLAIN, Encoding::DICTIONARY];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Encoding(pub i8);
+#[allow(non_upper_case_globals)]
+impl Encoding {
+    pub const PLAIN: Self = Self(0);
+    /// Data is stored dictionary-encoded
+    /// dictionary size: <INT32 Dictionary size>
+    /// dictionary data: <TYPE primitive array>
+    /// dictionary index: <INT32 primitive array>
+    ///
+    /// TODO: do we care about storing the index values in a smaller typed array
+    /// if possible?
+    pub const DICTIONARY: Self = Self(1);
+
+    pub const ENUM_MIN: i8 = 0;
+    pub const ENUM_MAX: i8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::PLAIN, Self::DICTIONARY];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::PLAIN => Some("PLAIN"),
+            Self::DICTIONARY => Some("DICTIONARY"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for Encoding {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for Encoding {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for Encoding {
+    type Output = Encoding;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for Encoding {
+    type Scalar = i8;
+    #[inline]
+    fn to_little_endian(self) -> i8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: i8) -> Self {
+        let b = i8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Encoding {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        i8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Encoding {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_TIME_UNIT: i8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_TIME_UNIT: i8 = 3;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TIME_UNIT: [TimeUnit; 4] = [
+    TimeUnit::SECOND,
+    TimeUnit::MILLISECOND,
+    TimeUnit::MICROSECOND,
+    TimeUnit::NANOSECOND,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TimeUnit(pub i8);
+#[allow(non_upper_case_globals)]
+impl TimeUnit {
+    pub const SECOND: Self = Self(0);
+    pub const MILLISECOND: Self = Self(1);
+    pub const MICROSECOND: Self = Self(2);
+    pub const NANOSECOND: Self = Self(3);
+
+    pub const ENUM_MIN: i8 = 0;
+    pub const ENUM_MAX: i8 = 3;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::SECOND,
+        Self::MILLISECOND,
+        Self::MICROSECOND,
+        Self::NANOSECOND,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::SECOND => Some("SECOND"),
+            Self::MILLISECOND => Some("MILLISECOND"),
+            Self::MICROSECOND => Some("MICROSECOND"),
+            Self::NANOSECOND => Some("NANOSECOND"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for TimeUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for TimeUnit {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for TimeUnit {
+    type Output = TimeUnit;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for TimeUnit {
+    type Scalar = i8;
+    #[inline]
+    fn to_little_endian(self) -> i8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: i8) -> Self {
+        let b = i8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for TimeUnit {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        i8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for TimeUnit {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_TYPE_METADATA: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_TYPE_METADATA: u8 = 4;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TYPE_METADATA: [TypeMetadata; 5] = [
+    TypeMetadata::NONE,
+    TypeMetadata::CategoryMetadata,
+    TypeMetadata::TimestampMetadata,
+    TypeMetadata::DateMetadata,
+    TypeMetadata::TimeMetadata,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TypeMetadata(pub u8);
+#[allow(non_upper_case_globals)]
+impl TypeMetadata {
+    pub const NONE: Self = Self(0);
+    pub const CategoryMetadata: Self = Self(1);
+    pub const TimestampMetadata: Self = Self(2);
+    pub const DateMetadata: Self = Self(3);
+    pub const TimeMetadata: Self = Self(4);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 4;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::NONE,
+        Self::CategoryMetadata,
+        Self::TimestampMetadata,
+        Self::DateMetadata,
+        Self::TimeMetadata,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::NONE => Some("NONE"),
+            Self::CategoryMetadata => Some("CategoryMetadata"),
+            Self::TimestampMetadata => Some("TimestampMetadata"),
+            Self::DateMetadata => Some("DateMetadata"),
+            Self::TimeMetadata => Some("TimeMetadata"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for TypeMetadata {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for TypeMetadata {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = flatbuffers::read_scalar_at::<u8>(buf, loc);
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for TypeMetadata {
+    type Output = TypeMetadata;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<u8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for TypeMetadata {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for TypeMetadata {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for TypeMetadata {}
+pub struct TypeMetadataUnionTableOffset {}
+
+pub enum PrimitiveArrayOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PrimitiveArray<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PrimitiveArray<'a> {
+    type Inner = PrimitiveArray<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> PrimitiveArray<'a> {
+    pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
+    pub const VT_ENCODING: flatbuffers::VOffsetT = 6;
+    pub const VT_OFFSET: flatbuffers::VOffsetT = 8;
+    pub const VT_LENGTH: flatbuffers::VOffsetT = 10;
+    pub const VT_NULL_COUNT: flatbuffers::VOffsetT = 12;
+    pub const VT_TOTAL_BYTES: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        PrimitiveArray { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PrimitiveArrayArgs,
+    ) -> flatbuffers::WIPOffset<PrimitiveArray<'bldr>> {
+        let mut builder = PrimitiveArrayBuilder::new(_fbb);
+        builder.add_total_bytes(args.total_bytes);
+        builder.add_null_count(args.null_count);
+        builder.add_length(args.length);
+        builder.add_offset(args.offset);
+        builder.add_encoding(args.encoding);
+        builder.add_type_(args.type_);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn type_(&self) -> Type {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<Type>(PrimitiveArray::VT_TYPE_, Some(Type::BOOL))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn encoding(&self) -> Encoding {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<Encoding>(PrimitiveArray::VT_ENCODING, Some(Encoding::PLAIN))
+                .unwrap()
+        }
+    }
+    /// Relative memory offset of the start of the array data excluding the size
+    /// of the metadata
+    #[inline]
+    pub fn offset(&self) -> i64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i64>(PrimitiveArray::VT_OFFSET, Some(0))
+                .unwrap()
+        }
+    }
+    /// The number of logical values in the array
+    #[inline]
+    pub fn length(&self) -> i64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i64>(PrimitiveArray::VT_LENGTH, Some(0))
+                .unwrap()
+        }
+    }
+    /// The number of observed nulls
+    #[inline]
+    pub fn null_count(&self) -> i64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i64>(PrimitiveArray::VT_NULL_COUNT, Some(0))
+                .unwrap()
+        }
+    }
+    /// The total size of the actual data in the file
+    #[inline]
+    pub fn total_bytes(&self) -> i64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i64>(PrimitiveArray::VT_TOTAL_BYTES, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for PrimitiveArray<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<Type>("type_", Self::VT_TYPE_, false)?
+            .visit_field::<Encoding>("encoding", Self::VT_ENCODING, false)?
+            .visit_field::<i64>("offset", Self::VT_OFFSET, false)?
+            .visit_field::<i64>("length", Self::VT_LENGTH, false)?
+            .visit_field::<i64>("null_count", Self::VT_NULL_COUNT, false)?
+            .visit_field::<i64>("total_bytes", Self::VT_TOTAL_BYTES, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct PrimitiveArrayArgs {
+    pub type_: Type,
+    pub encoding: Encoding,
+    pub offset: i64,
+    pub length: i64,
+    pub null_count: i64,
+    pub total_bytes: i64,
+}
+impl<'a> Default for PrimitiveArrayArgs {
+    #[inline]
+    fn default() -> Self {
+        PrimitiveArrayArgs {
+            type_: Type::BOOL,
+            encoding: Encoding::PLAIN,
+            offset: 0,
+            length: 0,
+            null_count: 0,
+            total_bytes: 0,
+        }
+    }
+}
+
+pub struct PrimitiveArrayBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> PrimitiveArrayBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_type_(&mut self, type_: Type) {
+        self.fbb_
+            .push_slot::<Type>(PrimitiveArray::VT_TYPE_, type_, Type::BOOL);
+    }
+    #[inline]
+    pub fn add_encoding(&mut self, encoding: Encoding) {
+        self.fbb_
+            .push_slot::<Encoding>(PrimitiveArray::VT_ENCODING, encoding, Encoding::PLAIN);
+    }
+    #[inline]
+    pub fn add_offset(&mut self, offset: i64) {
+        self.fbb_
+            .push_slot::<i64>(PrimitiveArray::VT_OFFSET, offset, 0);
+    }
+    #[inline]
+    pub fn add_length(&mut self, length: i64) {
+        self.fbb_
+            .push_slot::<i64>(PrimitiveArray::VT_LENGTH, length, 0);
+    }
+    #[inline]
+    pub fn add_null_count(&mut self, null_count: i64) {
+        self.fbb_
+            .push_slot::<i64>(PrimitiveArray::VT_NULL_COUNT, null_count, 0);
+    }
+    #[inline]
+    pub fn add_total_bytes(&mut self, total_bytes: i64) {
+        self.fbb_
+            .push_slot::<i64>(PrimitiveArray::VT_TOTAL_BYTES, total_bytes, 0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> PrimitiveArrayBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        PrimitiveArrayBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<PrimitiveArray<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for PrimitiveArray<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("PrimitiveArray");
+        ds.field("type_", &self.type_());
+        ds.field("encoding", &self.encoding());
+        ds.field("offset", &self.offset());
+        ds.field("length", &self.length());
+        ds.field("null_count", &self.null_count());
+        ds.field("total_bytes", &self.total_bytes());
+        ds.finish()
+    }
+}
+pub enum CategoryMetadataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CategoryMetadata<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CategoryMetadata<'a> {
+    type Inner = CategoryMetadata<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> CategoryMetadata<'a> {
+    pub const VT_LEVELS: flatbuffers::VOffsetT = 4;
+    pub const VT_ORDERED: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        CategoryMetadata { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args CategoryMetadataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CategoryMetadata<'bldr>> {
+        let mut builder = CategoryMetadataBuilder::new(_fbb);
+        if let Some(x) = args.levels {
+            builder.add_levels(x);
+        }
+        builder.add_ordered(args.ordered);
+        builder.finish()
+    }
+
+    /// The category codes are presumed to be integers that are valid indexes into
+    /// the levels array
+    #[inline]
+    pub fn levels(&self) -> Option<PrimitiveArray<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<PrimitiveArray>>(
+                    CategoryMetadata::VT_LEVELS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn ordered(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(CategoryMetadata::VT_ORDERED, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for CategoryMetadata<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<PrimitiveArray>>(
+                "levels",
+                Self::VT_LEVELS,
+                false,
+            )?
+            .visit_field::<bool>("ordered", Self::VT_ORDERED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct CategoryMetadataArgs<'a> {
+    pub levels: Option<flatbuffers::WIPOffset<PrimitiveArray<'a>>>,
+    pub ordered: bool,
+}
+impl<'a> Default for CategoryMetadataArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        CategoryMetadataArgs {
+            levels: None,
+            ordered: false,
+        }
+    }
+}
+
+pub struct CategoryMetadataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CategoryMetadataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_levels(&mut self, levels: flatbuffers::WIPOffset<PrimitiveArray<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<PrimitiveArray>>(
+                CategoryMetadata::VT_LEVELS,
+                levels,
+            );
+    }
+    #[inline]
+    pub fn add_ordered(&mut self, ordered: bool) {
+        self.fbb_
+            .push_slot::<bool>(CategoryMetadata::VT_ORDERED, ordered, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> CategoryMetadataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        CategoryMetadataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<CategoryMetadata<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for CategoryMetadata<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("CategoryMetadata");
+        ds.field("levels", &self.levels());
+        ds.field("ordered", &self.ordered());
+        ds.finish()
+    }
+}
+pub enum TimestampMetadataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TimestampMetadata<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TimestampMetadata<'a> {
+    type Inner = TimestampMetadata<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> TimestampMetadata<'a> {
+    pub const VT_UNIT: flatbuffers::VOffsetT = 4;
+    pub const VT_TIMEZONE: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TimestampMetadata { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args TimestampMetadataArgs<'args>,
+    ) -> flatbuffers::WIPOffset<TimestampMetadata<'bldr>> {
+        let mut builder = TimestampMetadataBuilder::new(_fbb);
+        if let Some(x) = args.timezone {
+            builder.add_timezone(x);
+        }
+        builder.add_unit(args.unit);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn unit(&self) -> TimeUnit {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<TimeUnit>(TimestampMetadata::VT_UNIT, Some(TimeUnit::SECOND))
+                .unwrap()
+        }
+    }
+    /// Timestamp data is assumed to be UTC, but the time zone is stored here for
+    /// presentation as localized
+    #[inline]
+    pub fn timezone(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(TimestampMetadata::VT_TIMEZONE, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TimestampMetadata<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<TimeUnit>("unit", Self::VT_UNIT, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "timezone",
+                Self::VT_TIMEZONE,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TimestampMetadataArgs<'a> {
+    pub unit: TimeUnit,
+    pub timezone: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for TimestampMetadataArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        TimestampMetadataArgs {
+            unit: TimeUnit::SECOND,
+            timezone: None,
+        }
+    }
+}
+
+pub struct TimestampMetadataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TimestampMetadataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_unit(&mut self, unit: TimeUnit) {
+        self.fbb_
+            .push_slot::<TimeUnit>(TimestampMetadata::VT_UNIT, unit, TimeUnit::SECOND);
+    }
+    #[inline]
+    pub fn add_timezone(&mut self, timezone: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            TimestampMetadata::VT_TIMEZONE,
+            timezone,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    ) -> TimestampMetadataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        TimestampMetadataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TimestampMetadata<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TimestampMetadata<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TimestampMetadata");
+        ds.field("unit", &self.unit());
+        ds.field("timezone", &self.timezone());
+        ds.finish()
+    }
+}
+pub enum DateMetadataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct DateMetadata<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for DateMetadata<'a> {
+    type Inner = DateMetadata<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> DateMetadata<'a> {
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        DateMetadata { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        _args: &'args DateMetadataArgs,
+    ) -> flatbuffers::WIPOffset<DateMetadata<'bldr>> {
+        let mut builder = DateMetadataBuilder::new(_fbb);
+        builder.finish()
+    }
+}
+
+impl flatbuffers::Verifiable for DateMetadata<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        v.visit_table(pos)?.finish();
+        Ok(())
+    }
+}
+pub struct DateMetadataArgs {}
+impl<'a> Default for DateMetadataArgs {
+    #[inline]
+    fn default() -> Self {
+        DateMetadataArgs {}
+    }
+}
+
+pub struct DateMetadataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> DateMetadataBuilder<'a, 'b> {
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> DateMetadataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        DateMetadataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<DateMetadata<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for DateMetadata<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("DateMetadata");
+        ds.finish()
+    }
+}
+pub enum TimeMetadataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TimeMetadata<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TimeMetadata<'a> {
+    type Inner = TimeMetadata<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> TimeMetadata<'a> {
+    pub const VT_UNIT: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TimeMetadata { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args TimeMetadataArgs,
+    ) -> flatbuffers::WIPOffset<TimeMetadata<'bldr>> {
+        let mut builder = TimeMetadataBuilder::new(_fbb);
+        builder.add_unit(args.unit);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn unit(&self) -> TimeUnit {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<TimeUnit>(TimeMetadata::VT_UNIT, Some(TimeUnit::SECOND))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TimeMetadata<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<TimeUnit>("unit", Self::VT_UNIT, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TimeMetadataArgs {
+    pub unit: TimeUnit,
+}
+impl<'a> Default for TimeMetadataArgs {
+    #[inline]
+    fn default() -> Self {
+        TimeMetadataArgs {
+            unit: TimeUnit::SECOND,
+        }
+    }
+}
+
+pub struct TimeMetadataBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> TimeMetadataBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_unit(&mut self, unit: TimeUnit) {
+        self.fbb_
+            .push_slot::<TimeUnit>(TimeMetadata::VT_UNIT, unit, TimeUnit::SECOND);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> TimeMetadataBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        TimeMetadataBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TimeMetadata<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TimeMetadata<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TimeMetadata");
+        ds.field("unit", &self.unit());
+        ds.finish()
+    }
+}
+pub enum ColumnOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Column<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Column<'a> {
+    type Inner = Column<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> Column<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_VALUES: flatbuffers::VOffsetT = 6;
+    pub const VT_METADATA_TYPE: flatbuffers::VOffsetT = 8;
+    pub const VT_METADATA: flatbuffers::VOffsetT = 10;
+    pub const VT_USER_METADATA: flatbuffers::VOffsetT = 12;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        Column { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ColumnArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Column<'bldr>> {
+        let mut builder = ColumnBuilder::new(_fbb);
+        if let Some(x) = args.user_metadata {
+            builder.add_user_metadata(x);
+        }
+        if let Some(x) = args.metadata {
+            builder.add_metadata(x);
+        }
+        if let Some(x) = args.values {
+            builder.add_values(x);
+        }
+        if let Some(x) = args.name {
+            builder.add_name(x);
+        }
+        builder.add_metadata_type(args.metadata_type);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn name(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Column::VT_NAME, None)
+        }
+    }
+    #[inline]
+    pub fn values(&self) -> Option<PrimitiveArray<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<PrimitiveArray>>(Column::VT_VALUES, None)
+        }
+    }
+    #[inline]
+    pub fn metadata_type(&self) -> TypeMetadata {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<TypeMetadata>(Column::VT_METADATA_TYPE, Some(TypeMetadata::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn metadata(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    Column::VT_METADATA,
+                    None,
+                )
+        }
+    }
+    /// This should (probably) be JSON
+    #[inline]
+    pub fn user_metadata(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(Column::VT_USER_METADATA, None)
+        }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn metadata_as_category_metadata(&self) -> Option<CategoryMetadata<'a>> {
+        if self.metadata_type() == TypeMetadata::CategoryMetadata {
+            self.metadata().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { CategoryMetadata::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn metadata_as_timestamp_metadata(&self) -> Option<TimestampMetadata<'a>> {
+        if self.metadata_type() == TypeMetadata::TimestampMetadata {
+            self.metadata().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TimestampMetadata::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn metadata_as_date_metadata(&self) -> Option<DateMetadata<'a>> {
+        if self.metadata_type() == TypeMetadata::DateMetadata {
+            self.metadata().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { DateMetadata::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn metadata_as_time_metadata(&self) -> Option<TimeMetadata<'a>> {
+        if self.metadata_type() == TypeMetadata::TimeMetadata {
+            self.metadata().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TimeMetadata::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for Column<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<PrimitiveArray>>(
+                "values",
+                Self::VT_VALUES,
+                false,
+            )?
+            .visit_union::<TypeMetadata, _>(
+                "metadata_type",
+                Self::VT_METADATA_TYPE,
+                "metadata",
+                Self::VT_METADATA,
+                false,
+                |key, v, pos| match key {
+                    TypeMetadata::CategoryMetadata => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<CategoryMetadata>>(
+                            "TypeMetadata::CategoryMetadata",
+                            pos,
+                        ),
+                    TypeMetadata::TimestampMetadata => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<TimestampMetadata>>(
+                            "TypeMetadata::TimestampMetadata",
+                            pos,
+                        ),
+                    TypeMetadata::DateMetadata => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<DateMetadata>>(
+                            "TypeMetadata::DateMetadata",
+                            pos,
+                        ),
+                    TypeMetadata::TimeMetadata => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<TimeMetadata>>(
+                            "TypeMetadata::TimeMetadata",
+                            pos,
+                        ),
+                    _ => Ok(()),
+                },
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "user_metadata",
+                Self::VT_USER_METADATA,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ColumnArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub values: Option<flatbuffers::WIPOffset<PrimitiveArray<'a>>>,
+    pub metadata_type: TypeMetadata,
+    pub metadata: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub user_metadata: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ColumnArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ColumnArgs {
+            name: None,
+            values: None,
+            metadata_type: TypeMetadata::NONE,
+            metadata: None,
+            user_metadata: None,
+        }
+    }
+}
+
+pub struct ColumnBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ColumnBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Column::VT_NAME, name);
+    }
+    #[inline]
+    pub fn add_values(&mut self, values: flatbuffers::WIPOffset<PrimitiveArray<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<PrimitiveArray>>(Column::VT_VALUES, values);
+    }
+    #[inline]
+    pub fn add_metadata_type(&mut self, metadata_type: TypeMetadata) {
+        self.fbb_.push_slot::<TypeMetadata>(
+            Column::VT_METADATA_TYPE,
+            metadata_type,
+            TypeMetadata::NONE,
+        );
+    }
+    #[inline]
+    pub fn add_metadata(&mut self, metadata: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Column::VT_METADATA, metadata);
+    }
+    #[inline]
+    pub fn add_user_metadata(&mut self, user_metadata: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Column::VT_USER_METADATA, user_metadata);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ColumnBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ColumnBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<Column<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for Column<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("Column");
+        ds.field("name", &self.name());
+        ds.field("values", &self.values());
+        ds.field("metadata_type", &self.metadata_type());
+        match self.metadata_type() {
+            TypeMetadata::CategoryMetadata => {
+                if let Some(x) = self.metadata_as_category_metadata() {
+                    ds.field("metadata", &x)
+                } else {
+                    ds.field(
+                        "metadata",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            TypeMetadata::TimestampMetadata => {
+                if let Some(x) = self.metadata_as_timestamp_metadata() {
+                    ds.field("metadata", &x)
+                } else {
+                    ds.field(
+                        "metadata",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            TypeMetadata::DateMetadata => {
+                if let Some(x) = self.metadata_as_date_metadata() {
+                    ds.field("metadata", &x)
+                } else {
+                    ds.field(
+                        "metadata",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            TypeMetadata::TimeMetadata => {
+                if let Some(x) = self.metadata_as_time_metadata() {
+                    ds.field("metadata", &x)
+                } else {
+                    ds.field(
+                        "metadata",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("metadata", &x)
+            }
+        };
+        ds.field("user_metadata", &self.user_metadata());
+        ds.finish()
+    }
+}
+pub enum CTableOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CTable<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CTable<'a> {
+    type Inner = CTable<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> CTable<'a> {
+    pub const VT_DESCRIPTION: flatbuffers::VOffsetT = 4;
+    pub const VT_NUM_ROWS: flatbuffers::VOffsetT = 6;
+    pub const VT_COLUMNS: flatbuffers::VOffsetT = 8;
+    pub const VT_VERSION: flatbuffers::VOffsetT = 10;
+    pub const VT_METADATA: flatbuffers::VOffsetT = 12;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        CTable { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args CTableArgs<'args>,
+    ) -> flatbuffers::WIPOffset<CTable<'bldr>> {
+        let mut builder = CTableBuilder::new(_fbb);
+        builder.add_num_rows(args.num_rows);
+        if let Some(x) = args.metadata {
+            builder.add_metadata(x);
+        }
+        builder.add_version(args.version);
+        if let Some(x) = args.columns {
+            builder.add_columns(x);
+        }
+        if let Some(x) = args.description {
+            builder.add_description(x);
+        }
+        builder.finish()
+    }
+
+    /// Some text (or a name) metadata about what the file is, optional
+    #[inline]
+    pub fn description(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CTable::VT_DESCRIPTION, None)
+        }
+    }
+    #[inline]
+    pub fn num_rows(&self) -> i64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i64>(CTable::VT_NUM_ROWS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn columns(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Column<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Column>>,
+            >>(CTable::VT_COLUMNS, None)
+        }
+    }
+    /// Version number of the Feather format
+    ///
+    /// Internal versions 0, 1, and 2: Implemented in Apache Arrow <= 0.16.0 and
+    /// wesm/feather. Uses "custom" metadata defined in this file.
+    #[inline]
+    pub fn version(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(CTable::VT_VERSION, Some(0)).unwrap() }
+    }
+    /// Table metadata (likely JSON), not yet used
+    #[inline]
+    pub fn metadata(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(CTable::VT_METADATA, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for CTable<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "description",
+                Self::VT_DESCRIPTION,
+                false,
+            )?
+            .visit_field::<i64>("num_rows", Self::VT_NUM_ROWS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Column>>,
+            >>("columns", Self::VT_COLUMNS, false)?
+            .visit_field::<i32>("version", Self::VT_VERSION, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "metadata",
+                Self::VT_METADATA,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct CTableArgs<'a> {
+    pub description: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub num_rows: i64,
+    pub columns: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Column<'a>>>>,
+    >,
+    pub version: i32,
+    pub metadata: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for CTableArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        CTableArgs {
+            description: None,
+            num_rows: 0,
+            columns: None,
+            version: 0,
+            metadata: None,
+        }
+    }
+}
+
+pub struct CTableBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> CTableBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_description(&mut self, description: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CTable::VT_DESCRIPTION, description);
+    }
+    #[inline]
+    pub fn add_num_rows(&mut self, num_rows: i64) {
+        self.fbb_.push_slot::<i64>(CTable::VT_NUM_ROWS, num_rows, 0);
+    }
+    #[inline]
+    pub fn add_columns(
+        &mut self,
+        columns: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<Column<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CTable::VT_COLUMNS, columns);
+    }
+    #[inline]
+    pub fn add_version(&mut self, version: i32) {
+        self.fbb_.push_slot::<i32>(CTable::VT_VERSION, version, 0);
+    }
+    #[inline]
+    pub fn add_metadata(&mut self, metadata: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(CTable::VT_METADATA, metadata);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> CTableBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        CTableBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<CTable<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for CTable<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("CTable");
+        ds.field("description", &self.description());
+        ds.field("num_rows", &self.num_rows());
+        ds.field("columns", &self.columns());
+        ds.field("version", &self.version());
+        ds.field("metadata", &self.metadata());
+        ds.finish()
+    }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `CTable`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_ctable_unchecked`.
+pub fn root_as_ctable(buf: &[u8]) -> Result<CTable, flatbuffers::InvalidFlatbuffer> {
+    flatbuffers::root::<CTable>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `CTable` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_ctable_unchecked`.
+pub fn size_prefixed_root_as_ctable(buf: &[u8]) -> Result<CTable, flatbuffers::InvalidFlatbuffer> {
+    flatbuffers::size_prefixed_root::<CTable>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `CTable` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_ctable_unchecked`.
+pub fn root_as_ctable_with_opts<'b, 'o>(
+    opts: &'o flatbuffers::VerifierOptions,
+    buf: &'b [u8],
+) -> Result<CTable<'b>, flatbuffers::InvalidFlatbuffer> {
+    flatbuffers::root_with_opts::<CTable<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `CTable` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_ctable_unchecked`.
+pub fn size_prefixed_root_as_ctable_with_opts<'b, 'o>(
+    opts: &'o flatbuffers::VerifierOptions,
+    buf: &'b [u8],
+) -> Result<CTable<'b>, flatbuffers::InvalidFlatbuffer> {
+    flatbuffers::size_prefixed_root_with_opts::<CTable<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a CTable and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `CTable`.
+pub unsafe fn root_as_ctable_unchecked(buf: &[u8]) -> CTable {
+    flatbuffers::root_unchecked::<CTable>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed CTable and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `CTable`.
+pub unsafe fn size_prefixed_root_as_ctable_unchecked(buf: &[u8]) -> CTable {
+    flatbuffers::size_prefixed_root_unchecked::<CTable>(buf)
+}
+#[inline]
+pub fn finish_ctable_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<CTable<'a>>,
+) {
+    fbb.finish(root, None);
+}
+
+#[inline]
+pub fn finish_size_prefixed_ctable_buffer<'a, 'b>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    root: flatbuffers::WIPOffset<CTable<'a>>,
+) {
+    fbb.finish_size_prefixed(root, None);
+}
@@ -29,3 +29,5 @@ pub mod Schema;
 pub mod SparseTensor;
 #[allow(clippy::all)]
 pub mod Tensor;
+#[allow(clippy::all)]
+pub mod feather;
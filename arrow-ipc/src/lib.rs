@@ -19,11 +19,23 @@
 //!
 //! [Arrow IPC Format]: https://arrow.apache.org/docs/format/Columnar.html#serialization-and-interprocess-communication-ipc
 
+#[cfg(feature = "async")]
+pub mod async_reader;
+#[cfg(feature = "async")]
+pub mod async_writer;
 pub mod convert;
+#[cfg(feature = "dataset")]
+pub mod dataset;
+pub mod feather;
+#[cfg(feature = "partition")]
+pub mod partition;
+pub mod raw;
 pub mod reader;
 pub mod writer;
 
+mod checksum;
 mod compression;
+mod endian;
 
 #[allow(clippy::redundant_closure)]
 #[allow(clippy::needless_lifetimes)]
@@ -0,0 +1,304 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Write record batches to a set of Arrow IPC files, partitioned by the hash of a key
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+//! # use arrow_ipc::partition::PartitionedFileWriter;
+//! # use arrow_ipc::reader::FileReader;
+//! # use arrow_ipc::writer::IpcWriteOptions;
+//! let batch = RecordBatch::try_from_iter([
+//!     ("key", Arc::new(StringArray::from(vec!["a", "b", "a", "c"])) as ArrayRef),
+//!     ("value", Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef),
+//! ]).unwrap();
+//!
+//! let files = vec![vec![]; 3];
+//! let options = IpcWriteOptions::default();
+//! let mut writer = PartitionedFileWriter::try_new(files, &batch.schema(), vec![0], options).unwrap();
+//! writer.write(&batch).unwrap();
+//! let files = writer.into_inner().unwrap();
+//!
+//! // Each file contains all the rows with a given key
+//! let mut rows = 0;
+//! for file in files {
+//!     let reader = FileReader::try_new(std::io::Cursor::new(file), None).unwrap();
+//!     for batch in reader {
+//!         rows += batch.unwrap().num_rows();
+//!     }
+//! }
+//! assert_eq!(rows, 4);
+//! ```
+
+use std::io::Write;
+
+use arrow_array::{RecordBatch, UInt32Array};
+use arrow_row::{RowConverter, SortField};
+use arrow_schema::{ArrowError, Schema};
+use arrow_select::take::take_record_batch;
+
+use crate::writer::{FileWriter, IpcWriteOptions};
+
+/// Writes record batches to a set of IPC files, routing each row to a file according
+/// to the hash of its key columns
+///
+/// The key of each row is encoded in the [row format](arrow_row), and hashed with
+/// CRC32, so a given key is always written to the same partition, for a given number
+/// of partitions, by any process or system using the same version of arrow-rs. This
+/// simplifies writing the shuffle files of a distributed query engine, where rows
+/// with the same key must be read by the same consumer.
+///
+/// The row format is not a stable encoding, and so the partition of a key may change
+/// between versions of arrow-rs. Writers that must agree on partitions should use
+/// the same version, and partitions should not be persisted across upgrades.
+///
+/// Each partition is written by its own [`FileWriter`], with its own dictionaries
+/// and footer, and only receives the rows of each batch routed to it. Null keys are
+/// hashed like any other value.
+pub struct PartitionedFileWriter<W: Write> {
+    writers: Vec<FileWriter<W>>,
+    converter: RowConverter,
+    key_columns: Vec<usize>,
+}
+
+impl<W: Write> PartitionedFileWriter<W> {
+    /// Try create a new writer, partitioning by the `key_columns` of `schema`, with a
+    /// partition per writer in `writers`
+    ///
+    /// Returns an error if `writers` or `key_columns` is empty, or a key column is out
+    /// of bounds or not supported by the row format
+    pub fn try_new(
+        writers: Vec<W>,
+        schema: &Schema,
+        key_columns: Vec<usize>,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        if writers.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "PartitionedFileWriter requires at least one partition".to_string(),
+            ));
+        }
+        if key_columns.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "PartitionedFileWriter requires at least one key column".to_string(),
+            ));
+        }
+        let fields = key_columns
+            .iter()
+            .map(|idx| match schema.fields().get(*idx) {
+                Some(f) => Ok(SortField::new(f.data_type().clone())),
+                None => Err(ArrowError::InvalidArgumentError(format!(
+                    "Key column {idx} out of bounds for schema with {} fields",
+                    schema.fields().len()
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let converter = RowConverter::new(fields)?;
+        let writers = writers
+            .into_iter()
+            .map(|w| FileWriter::try_new_with_options(w, schema, write_options.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            writers,
+            converter,
+            key_columns,
+        })
+    }
+
+    /// Returns the number of partitions
+    pub fn num_partitions(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Returns the partition of each row of `batch`
+    pub fn partitions(&self, batch: &RecordBatch) -> Result<Vec<usize>, ArrowError> {
+        let keys: Vec<_> = self
+            .key_columns
+            .iter()
+            .map(|idx| batch.column(*idx).clone())
+            .collect();
+        let rows = self.converter.convert_columns(&keys)?;
+        let n = self.writers.len() as u64;
+        Ok(rows
+            .iter()
+            .map(|row| (crc32fast::hash(row.as_ref()) as u64 % n) as usize)
+            .collect())
+    }
+
+    /// Write the rows of `batch` to their partitions
+    ///
+    /// Partitions without any rows in `batch` are not written to
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        let mut indices = vec![vec![]; self.writers.len()];
+        for (row, partition) in self.partitions(batch)?.into_iter().enumerate() {
+            indices[partition].push(row as u32);
+        }
+        for (writer, indices) in self.writers.iter_mut().zip(indices) {
+            if indices.is_empty() {
+                continue;
+            }
+            let indices = UInt32Array::from(indices);
+            writer.write(&take_record_batch(batch, &indices)?)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the writer of each partition
+    pub fn writers(&self) -> &[FileWriter<W>] {
+        &self.writers
+    }
+
+    /// Returns a mutable reference to the writer of each partition
+    ///
+    /// This can be used to write custom metadata to the footer of each partition
+    pub fn writers_mut(&mut self) -> &mut [FileWriter<W>] {
+        &mut self.writers
+    }
+
+    /// Write the footer of every partition, completing the files
+    pub fn finish(&mut self) -> Result<(), ArrowError> {
+        self.writers.iter_mut().try_for_each(|w| w.finish())
+    }
+
+    /// Finishes any partitions that have not been finished, returning the underlying
+    /// writers in partition order
+    pub fn into_inner(self) -> Result<Vec<W>, ArrowError> {
+        self.writers.into_iter().map(|w| w.into_inner()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::FileReader;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, Int64Type};
+    use arrow_array::{ArrayRef, DictionaryArray, Int64Array, StringArray};
+    use std::collections::HashMap;
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_partitioned_file_writer() {
+        let batch = |keys: Vec<Option<i64>>, tag: &str| {
+            let values: DictionaryArray<Int32Type> = keys.iter().map(|_| tag).collect();
+            RecordBatch::try_from_iter([
+                ("key", Arc::new(Int64Array::from(keys)) as ArrayRef),
+                ("tag", Arc::new(values) as ArrayRef),
+            ])
+            .unwrap()
+        };
+        let a = batch((0..100).map(Some).chain([None]).collect(), "a");
+        let b = batch((50..150).map(Some).collect(), "a");
+
+        let options = IpcWriteOptions::default();
+        let mut writer =
+            PartitionedFileWriter::try_new(vec![vec![]; 4], &a.schema(), vec![0], options).unwrap();
+        assert_eq!(writer.num_partitions(), 4);
+        let partitions = writer.partitions(&a).unwrap();
+        assert_eq!(partitions, writer.partitions(&a).unwrap());
+        writer.write(&a).unwrap();
+        writer.write(&b).unwrap();
+        writer.finish().unwrap();
+        let files = writer.into_inner().unwrap();
+
+        // Every row is written exactly once, and each key to a single partition
+        let mut key_partitions = HashMap::new();
+        let mut rows = 0;
+        for (partition, file) in files.into_iter().enumerate() {
+            let reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+            for batch in reader {
+                let batch = batch.unwrap();
+                rows += batch.num_rows();
+                let keys = batch.column(0).as_primitive::<Int64Type>();
+                for key in keys.iter() {
+                    let existing = key_partitions.insert(key, partition);
+                    assert!(existing.map_or(true, |p| p == partition));
+                }
+                assert_eq!(batch.column(1).null_count(), 0);
+            }
+        }
+        assert_eq!(rows, 201);
+        assert_eq!(key_partitions.len(), 151);
+        assert_eq!(key_partitions[&Some(3)], partitions[3]);
+        // All four partitions are used
+        let used: std::collections::HashSet<_> = key_partitions.values().collect();
+        assert_eq!(used.len(), 4);
+    }
+
+    #[test]
+    fn test_partitioned_file_writer_invalid() {
+        let batch =
+            RecordBatch::try_from_iter([("s", Arc::new(StringArray::from(vec!["a"])) as ArrayRef)])
+                .unwrap();
+        let schema = batch.schema();
+
+        let err = PartitionedFileWriter::<Vec<u8>>::try_new(
+            vec![],
+            &schema,
+            vec![0],
+            IpcWriteOptions::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: PartitionedFileWriter requires at least one partition"
+        );
+
+        let err = PartitionedFileWriter::try_new(
+            vec![vec![]],
+            &schema,
+            vec![1],
+            IpcWriteOptions::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Key column 1 out of bounds for schema with 1 fields"
+        );
+
+        let err = PartitionedFileWriter::try_new(
+            vec![vec![]],
+            &schema,
+            vec![],
+            IpcWriteOptions::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: PartitionedFileWriter requires at least one key column"
+        );
+
+        // A single partition receives every row
+        let mut writer = PartitionedFileWriter::try_new(
+            vec![vec![]],
+            &schema,
+            vec![0],
+            IpcWriteOptions::default(),
+        )
+        .unwrap();
+        writer.write(&batch).unwrap();
+        let files = writer.into_inner().unwrap();
+        let mut reader = FileReader::try_new(Cursor::new(&files[0]), None).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read.column(0).as_string::<i32>().value(0), "a");
+    }
+}
@@ -0,0 +1,521 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A compact binary serialization of [`ArrayData`] and [`RecordBatch`], for caching
+//!
+//! Unlike the IPC format, only the schema is encoded using flatbuffers, with the
+//! layout of the arrays encoded as a flat list of integers. The buffers of the arrays
+//! are stored as is, aligned to 64 bytes, and decoding slices them from the
+//! encoded [`Buffer`] without copying.
+//!
+//! The encoding is versioned, but is specific to this crate and not intended for
+//! exchanging data with other implementations, for which the IPC format should be
+//! used instead. Decoded arrays are validated, and so decoding untrusted input is
+//! safe, although not recommended.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+//! # use arrow_ipc::raw::{decode_record_batch, encode_record_batch};
+//! let batch = RecordBatch::try_from_iter([
+//!     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+//!     ("b", Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef),
+//! ]).unwrap();
+//!
+//! let encoded = encode_record_batch(&batch);
+//! let decoded = decode_record_batch(&encoded).unwrap();
+//! assert_eq!(decoded, batch);
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{make_array, RecordBatch, RecordBatchOptions};
+use arrow_buffer::{BooleanBuffer, Buffer, MutableBuffer, NullBuffer};
+use arrow_data::{ArrayData, ArrayDataBuilder};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+
+use crate::convert::NATIVE_ENDIANNESS;
+use crate::reader::read_schema_message;
+use crate::writer::{IpcDataGenerator, IpcWriteOptions};
+
+/// Magic bytes identifying the encoding
+const MAGIC: [u8; 8] = *b"ARROWRAW";
+
+/// The version of the encoding, incremented on incompatible changes
+const VERSION: u32 = 1;
+
+/// The alignment of the buffers relative to the start of the encoding
+const ALIGNMENT: usize = 64;
+
+/// Sentinel indicating the absence of a null buffer
+const NO_NULLS: u64 = u64::MAX;
+
+/// Encodes `batch` in the compact binary format, see the [module docs](self)
+pub fn encode_record_batch(batch: &RecordBatch) -> Buffer {
+    let columns: Vec<_> = batch.columns().iter().map(|c| c.to_data()).collect();
+    encode(&batch.schema(), &columns, batch.num_rows())
+}
+
+/// Decodes a [`RecordBatch`] encoded by [`encode_record_batch`]
+///
+/// The arrays of the returned batch reference `buf` without copying, unless it is
+/// not sufficiently aligned for the types of the arrays
+pub fn decode_record_batch(buf: &Buffer) -> Result<RecordBatch, ArrowError> {
+    let (schema, columns, num_rows) = decode(buf)?;
+    let columns = columns.into_iter().map(make_array).collect();
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    RecordBatch::try_new_with_options(schema, columns, &options)
+}
+
+/// Encodes `data` in the compact binary format, see the [module docs](self)
+pub fn encode_array_data(data: &ArrayData) -> Buffer {
+    let schema = Schema::new(vec![Field::new("", data.data_type().clone(), true)]);
+    encode(&schema, std::slice::from_ref(data), data.len())
+}
+
+/// Decodes an [`ArrayData`] encoded by [`encode_array_data`]
+///
+/// The returned [`ArrayData`] references `buf` without copying, unless it is not
+/// sufficiently aligned for the type of the array
+pub fn decode_array_data(buf: &Buffer) -> Result<ArrayData, ArrowError> {
+    let (_, mut columns, _) = decode(buf)?;
+    match columns.len() {
+        1 => Ok(columns.pop().unwrap()),
+        n => Err(ArrowError::InvalidArgumentError(format!(
+            "Expected encoded array, found {n} columns"
+        ))),
+    }
+}
+
+/// Encodes the layout of arrays as a list of integers, and collects their buffers
+#[derive(Default)]
+struct Encoder {
+    /// The layout of the arrays
+    words: Vec<u64>,
+    /// The distinct buffers, keyed by their address and length
+    offsets: HashMap<(usize, usize), usize>,
+    /// The buffers to write, in order of their offset relative to the data section
+    buffers: Vec<Buffer>,
+    /// The length of the data section
+    data_len: usize,
+}
+
+impl Encoder {
+    /// Appends the offset and length of `buffer` to the layout
+    fn push_buffer(&mut self, buffer: &Buffer) {
+        let key = (buffer.as_ptr() as usize, buffer.len());
+        let offset = match self.offsets.get(&key) {
+            Some(offset) => *offset,
+            None => {
+                let offset = self.data_len;
+                self.data_len += padded_len(buffer.len());
+                self.offsets.insert(key, offset);
+                self.buffers.push(buffer.clone());
+                offset
+            }
+        };
+        self.words.extend([offset as u64, buffer.len() as u64]);
+    }
+
+    /// Appends the layout of `data` and its children
+    ///
+    /// `[len, offset, null offset | NO_NULLS, (null buffer), num buffers, (buffers),
+    /// num children, (children)]`
+    fn push_array(&mut self, data: &ArrayData) {
+        self.words.extend([data.len() as u64, data.offset() as u64]);
+        match data.nulls() {
+            Some(nulls) => {
+                self.words.push(nulls.offset() as u64);
+                self.push_buffer(nulls.buffer());
+            }
+            None => self.words.push(NO_NULLS),
+        }
+        self.words.push(data.buffers().len() as u64);
+        for buffer in data.buffers() {
+            self.push_buffer(buffer);
+        }
+        self.words.push(data.child_data().len() as u64);
+        for child in data.child_data() {
+            self.push_array(child);
+        }
+    }
+}
+
+/// Returns `len` rounded up to a multiple of [`ALIGNMENT`]
+fn padded_len(len: usize) -> usize {
+    (len + ALIGNMENT - 1) / ALIGNMENT * ALIGNMENT
+}
+
+/// The encoding comprises
+///
+/// ```text
+/// [8 bytes]:  MAGIC
+/// [4 bytes]:  VERSION, as a little-endian u32
+/// [4 bytes]:  schema length, as a little-endian u32
+/// [n bytes]:  schema, as an IPC schema message, padded to 8 bytes
+/// [8 bytes]:  number of layout words
+/// [n words]:  number of rows, followed by the layout of each column, as little-endian u64
+/// [padding]:  to a multiple of ALIGNMENT
+/// [n bytes]:  the buffers, each padded to a multiple of ALIGNMENT
+/// ```
+fn encode(schema: &Schema, columns: &[ArrayData], num_rows: usize) -> Buffer {
+    let schema = IpcDataGenerator::default()
+        .schema_to_bytes(schema, &IpcWriteOptions::default())
+        .ipc_message;
+
+    let mut encoder = Encoder::default();
+    encoder.words.push(num_rows as u64);
+    for column in columns {
+        encoder.push_array(column);
+    }
+
+    let header_len = MAGIC.len() + 8 + (schema.len() + 7) / 8 * 8 + 8 + encoder.words.len() * 8;
+    let header_len = padded_len(header_len);
+    let mut out = MutableBuffer::with_capacity(header_len + encoder.data_len);
+    out.extend_from_slice(&MAGIC);
+    out.extend_from_slice(&VERSION.to_le_bytes());
+    out.extend_from_slice(&(schema.len() as u32).to_le_bytes());
+    out.extend_from_slice(&schema);
+    out.extend_zeros((schema.len() + 7) / 8 * 8 - schema.len());
+    out.extend_from_slice(&(encoder.words.len() as u64).to_le_bytes());
+    for word in &encoder.words {
+        out.extend_from_slice(&word.to_le_bytes());
+    }
+    out.extend_zeros(header_len - out.len());
+    for buffer in &encoder.buffers {
+        out.extend_from_slice(buffer.as_slice());
+        out.extend_zeros(padded_len(buffer.len()) - buffer.len());
+    }
+    out.into()
+}
+
+/// Decodes the layout of arrays, slicing their buffers from `data`
+struct Decoder<'a> {
+    words: std::slice::ChunksExact<'a, u8>,
+    data: Buffer,
+}
+
+impl<'a> Decoder<'a> {
+    fn next_u64(&mut self) -> Result<u64, ArrowError> {
+        let word = self.words.next().ok_or_else(|| truncated("layout"))?;
+        Ok(u64::from_le_bytes(word.try_into().unwrap()))
+    }
+
+    fn next_word(&mut self) -> Result<usize, ArrowError> {
+        usize::try_from(self.next_u64()?)
+            .map_err(|_| ArrowError::IpcError("Invalid encoded array layout".to_string()))
+    }
+
+    fn next_buffer(&mut self) -> Result<Buffer, ArrowError> {
+        let offset = self.next_word()?;
+        let len = self.next_word()?;
+        match offset.checked_add(len) {
+            Some(end) if end <= self.data.len() => Ok(self.data.slice_with_length(offset, len)),
+            _ => Err(truncated("buffer")),
+        }
+    }
+
+    fn next_array(&mut self, data_type: &DataType) -> Result<ArrayData, ArrowError> {
+        let len = self.next_word()?;
+        let offset = self.next_word()?;
+        let nulls = match self.next_u64()? {
+            NO_NULLS => None,
+            null_offset => {
+                let null_offset = usize::try_from(null_offset).map_err(|_| {
+                    ArrowError::IpcError("Invalid encoded array layout".to_string())
+                })?;
+                let buffer = self.next_buffer()?;
+                if null_offset.saturating_add(len) > buffer.len().saturating_mul(8) {
+                    return Err(truncated("null buffer"));
+                }
+                Some(NullBuffer::new(BooleanBuffer::new(
+                    buffer,
+                    null_offset,
+                    len,
+                )))
+            }
+        };
+
+        let num_buffers = self.next_word()?;
+        let buffers = (0..num_buffers)
+            .map(|_| self.next_buffer())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let child_types = child_types(data_type);
+        let num_children = self.next_word()?;
+        if num_children != child_types.len() {
+            return Err(ArrowError::IpcError(format!(
+                "Expected {} children for encoded array of type {data_type}, found {num_children}",
+                child_types.len()
+            )));
+        }
+        let child_data = child_types
+            .iter()
+            .map(|t| self.next_array(t))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ArrayDataBuilder::new(data_type.clone())
+            .len(len)
+            .offset(offset)
+            .nulls(nulls)
+            .buffers(buffers)
+            .child_data(child_data)
+            .build_aligned()
+    }
+}
+
+/// The data types of the children of an [`ArrayData`] of type `data_type`
+fn child_types(data_type: &DataType) -> Vec<&DataType> {
+    match data_type {
+        DataType::List(f)
+        | DataType::LargeList(f)
+        | DataType::FixedSizeList(f, _)
+        | DataType::Map(f, _) => vec![f.data_type()],
+        DataType::Struct(fields) => fields.iter().map(|f| f.data_type()).collect(),
+        DataType::Union(fields, _) => fields.iter().map(|(_, f)| f.data_type()).collect(),
+        DataType::Dictionary(_, values) => vec![values.as_ref()],
+        DataType::RunEndEncoded(run_ends, values) => {
+            vec![run_ends.data_type(), values.data_type()]
+        }
+        _ => vec![],
+    }
+}
+
+fn truncated(what: &str) -> ArrowError {
+    ArrowError::IpcError(format!("Encoded data is truncated, unable to read {what}"))
+}
+
+fn decode(buf: &Buffer) -> Result<(SchemaRef, Vec<ArrayData>, usize), ArrowError> {
+    let read_u32 = |offset: usize| -> Result<usize, ArrowError> {
+        let bytes = buf
+            .get(offset..offset + 4)
+            .ok_or_else(|| truncated("header"))?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+    };
+
+    if buf.get(..MAGIC.len()) != Some(&MAGIC[..]) {
+        return Err(ArrowError::IpcError(
+            "Encoded data does not start with the expected magic bytes".to_string(),
+        ));
+    }
+    let version = read_u32(8)?;
+    if version != VERSION as usize {
+        return Err(ArrowError::IpcError(format!(
+            "Unsupported encoding version {version}, expected {VERSION}"
+        )));
+    }
+
+    let schema_len = read_u32(12)?;
+    let schema = buf
+        .get(16..16 + schema_len)
+        .ok_or_else(|| truncated("schema"))?;
+    let (schema, endianness) = read_schema_message(schema, &Default::default())?;
+    if endianness != NATIVE_ENDIANNESS {
+        return Err(ArrowError::IpcError(format!(
+            "Cannot decode data encoded with endianness {endianness:?} on a system with endianness {NATIVE_ENDIANNESS:?}"
+        )));
+    }
+    let schema = Arc::new(schema);
+
+    let words_start = 16 + (schema_len + 7) / 8 * 8;
+    let num_words = buf
+        .get(words_start..words_start + 8)
+        .ok_or_else(|| truncated("layout"))?;
+    let num_words = u64::from_le_bytes(num_words.try_into().unwrap()) as usize;
+    let words_end = num_words
+        .checked_mul(8)
+        .and_then(|len| len.checked_add(words_start + 8))
+        .filter(|end| *end <= buf.len())
+        .ok_or_else(|| truncated("layout"))?;
+    let words = buf[words_start + 8..words_end].chunks_exact(8);
+
+    let data_start = padded_len(words_end);
+    if data_start > buf.len() {
+        return Err(truncated("buffers"));
+    }
+    let mut decoder = Decoder {
+        words,
+        data: buf.slice(data_start),
+    };
+
+    let num_rows = decoder.next_word()?;
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|f| decoder.next_array(f.data_type()))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((schema, columns, num_rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, ListBuilder, StringDictionaryBuilder};
+    use arrow_array::types::{Int16Type, Int32Type};
+    use arrow_array::{
+        Array, ArrayRef, Float64Array, Int64Array, RunArray, StringArray, StructArray, UnionArray,
+    };
+
+    fn batch() -> RecordBatch {
+        let ints = Int64Array::from(vec![Some(1), None, Some(3), Some(4), None]);
+        let strings = StringArray::from(vec![Some("a"), Some("bb"), None, Some(""), Some("e")]);
+
+        let mut list = ListBuilder::new(Int32Builder::new());
+        list.append_value([Some(1), None]);
+        list.append_null();
+        list.append_value([]);
+        list.append_value([Some(4)]);
+        list.append_value([Some(5), Some(6)]);
+
+        let mut dict = StringDictionaryBuilder::<Int32Type>::new();
+        dict.extend([Some("x"), Some("y"), None, Some("x"), Some("z")]);
+
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("f", DataType::Float64, true)),
+                Arc::new(Float64Array::from(vec![1., 2., 3., 4., 5.])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("s", DataType::Utf8, true)),
+                Arc::new(strings.clone()) as ArrayRef,
+            ),
+        ]);
+
+        let union = UnionArray::try_new(
+            &[0, 1],
+            Buffer::from_slice_ref([0_i8, 1, 0, 1, 0]),
+            Some(Buffer::from_slice_ref([0_i32, 0, 1, 1, 2])),
+            vec![
+                (
+                    Field::new("i", DataType::Int32, true),
+                    Arc::new(arrow_array::Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+                ),
+                (
+                    Field::new("f", DataType::Float64, true),
+                    Arc::new(Float64Array::from(vec![1.5, 2.5])),
+                ),
+            ],
+        )
+        .unwrap();
+
+        let sliced = ints.slice(1, 4);
+        let batch = RecordBatch::try_from_iter([
+            ("ints", Arc::new(ints) as ArrayRef),
+            ("strings", Arc::new(strings)),
+            ("list", Arc::new(list.finish())),
+            ("dict", Arc::new(dict.finish())),
+            ("struct", Arc::new(structs)),
+            ("union", Arc::new(union)),
+        ])
+        .unwrap();
+        let batch = batch.slice(1, 4);
+        assert_eq!(batch.column(0).as_ref(), &sliced);
+        batch
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let batch = batch();
+        let encoded = encode_record_batch(&batch);
+        let decoded = decode_record_batch(&encoded).unwrap();
+        assert_eq!(decoded, batch);
+
+        // The buffers of the decoded arrays reference the encoded data
+        let range = encoded.as_ptr() as usize..encoded.as_ptr() as usize + encoded.len();
+        for column in decoded.columns() {
+            for buffer in column.to_data().buffers() {
+                assert!(range.contains(&(buffer.as_ptr() as usize)));
+            }
+        }
+
+        let empty = RecordBatch::new_empty(batch.schema());
+        assert_eq!(
+            decode_record_batch(&encode_record_batch(&empty)).unwrap(),
+            empty
+        );
+
+        let options = RecordBatchOptions::new().with_row_count(Some(3));
+        let no_columns =
+            RecordBatch::try_new_with_options(Arc::new(Schema::empty()), vec![], &options).unwrap();
+        let decoded = decode_record_batch(&encode_record_batch(&no_columns)).unwrap();
+        assert_eq!(decoded.num_rows(), 3);
+    }
+
+    #[test]
+    fn test_roundtrip_array_data() {
+        // Sliced array data retains its offset
+        let ints = Int64Array::from(vec![Some(1), None, Some(3), None]).into_data();
+        let sliced = ints.slice(1, 3);
+        assert_eq!(sliced.offset(), 1);
+        let decoded = decode_array_data(&encode_array_data(&sliced)).unwrap();
+        assert_eq!(decoded.offset(), 1);
+        assert_eq!(decoded, sliced);
+
+        let run = RunArray::<Int16Type>::try_new(
+            &arrow_array::Int16Array::from(vec![2, 5]),
+            &StringArray::from(vec!["p", "q"]),
+        )
+        .unwrap()
+        .into_data();
+        assert_eq!(decode_array_data(&encode_array_data(&run)).unwrap(), run);
+
+        let dict = batch().column(3).to_data();
+        let encoded = encode_array_data(&dict);
+        assert_eq!(decode_array_data(&encoded).unwrap(), dict);
+
+        // Unaligned input is copied
+        let mut unaligned = MutableBuffer::new(encoded.len() + 1);
+        unaligned.push(0_u8);
+        unaligned.extend_from_slice(encoded.as_slice());
+        let unaligned = Buffer::from(unaligned).slice(1);
+        assert_eq!(decode_array_data(&unaligned).unwrap(), dict);
+
+        let err = decode_array_data(&encode_record_batch(&batch())).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected encoded array, found 6 columns"
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let encoded = encode_record_batch(&batch());
+
+        let err = decode_record_batch(&Buffer::from_slice_ref(b"ARROW1")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Encoded data does not start with the expected magic bytes"
+        );
+
+        let mut bad_version = encoded.as_slice().to_vec();
+        bad_version[8] = 2;
+        let err = decode_record_batch(&Buffer::from_vec(bad_version)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Unsupported encoding version 2, expected 1"
+        );
+
+        for len in [20, 200, encoded.len() - 100] {
+            let err = decode_record_batch(&encoded.slice_with_length(0, len)).unwrap_err();
+            assert!(
+                err.to_string().contains("Encoded data is truncated"),
+                "{len}: {err}"
+            );
+        }
+    }
+}
@@ -20,21 +20,37 @@
 //! The `FileReader` and `StreamReader` have similar interfaces,
 //! however the `FileReader` expects a reader that supports `Seek`ing
 
-use flatbuffers::{VectorIter, VerifierOptions};
-use std::collections::HashMap;
+use flatbuffers::{ForwardsUOffset, Vector, VectorIter, VerifierOptions};
+use std::collections::{HashMap, HashSet};
 use std::fmt;
-use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
 use std::sync::Arc;
 
 use arrow_array::*;
-use arrow_buffer::{ArrowNativeType, Buffer, MutableBuffer};
-use arrow_data::ArrayData;
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_data::transform::MutableArrayData;
+use arrow_data::{layout, ArrayData, ArrayDataBuilder};
 use arrow_schema::*;
 
+use crate::checksum::{
+    check_verifiable, parse_checksums, verify_checksum, verify_message_checksum,
+    DICTIONARY_CHECKSUMS_KEY, MESSAGE_CHECKSUM_KEY, RECORD_BATCH_CHECKSUMS_KEY,
+};
 use crate::compression::CompressionCodec;
-use crate::{Block, FieldNode, Message, MetadataVersion, CONTINUATION_MARKER};
+use crate::convert::NATIVE_ENDIANNESS;
+use crate::endian::{buffer_components, check_endianness, swap_components};
+use crate::{Block, FieldNode, KeyValue, Message, MetadataVersion, CONTINUATION_MARKER};
 use DataType::*;
 
+mod dictionary;
+mod projection;
+mod stream;
+use dictionary::load_dictionaries;
+pub use dictionary::{DictionaryProvider, SharedDictionaries};
+use projection::FieldProjection;
+pub use projection::ProjectionMask;
+pub use stream::*;
+
 /// Read a buffer based on offset and length
 /// From <https://github.com/apache/arrow/blob/6a936c4ff5007045e86f65f1a6b6c3c955ad5103/format/Message.fbs#L58>
 /// Each constituent buffer is first compressed with the indicated
@@ -58,235 +74,112 @@ fn read_buffer(
     }
 }
 
-/// Coordinates reading arrays based on data types.
-///
-/// Notes:
-/// * In the IPC format, null buffers are always set, but may be empty. We discard them if an array has 0 nulls
-/// * Numeric values inside list arrays are often stored as 64-bit values regardless of their data type size.
-///   We thus:
-///     - check if the bit width of non-64-bit numbers is 64, and
-///     - read the buffer as 64-bit (signed integer or float), and
-///     - cast the 64-bit array to the appropriate data type
+/// Reads the array for `field` from `reader`
 fn create_array(reader: &mut ArrayReader, field: &Field) -> Result<ArrayRef, ArrowError> {
-    let data_type = field.data_type();
+    let data = reader.read_field(field, &FieldProjection::All)?;
+    Ok(make_array(data.expect("materialized field")))
+}
+
+/// Returns the child fields of `data_type` that are encoded as separate field nodes
+///
+/// Dictionary values are encoded in separate dictionary batches, and so are not included
+fn child_fields(data_type: &DataType) -> Vec<&Field> {
     match data_type {
-        Utf8 | Binary | LargeBinary | LargeUtf8 => create_primitive_array(
-            reader.next_node(field)?,
-            data_type,
-            &[
-                reader.next_buffer()?,
-                reader.next_buffer()?,
-                reader.next_buffer()?,
-            ],
-        ),
-        FixedSizeBinary(_) => create_primitive_array(
-            reader.next_node(field)?,
-            data_type,
-            &[reader.next_buffer()?, reader.next_buffer()?],
-        ),
-        List(ref list_field) | LargeList(ref list_field) | Map(ref list_field, _) => {
-            let list_node = reader.next_node(field)?;
-            let list_buffers = [reader.next_buffer()?, reader.next_buffer()?];
-            let values = create_array(reader, list_field)?;
-            create_list_array(list_node, data_type, &list_buffers, values)
-        }
-        FixedSizeList(ref list_field, _) => {
-            let list_node = reader.next_node(field)?;
-            let list_buffers = [reader.next_buffer()?];
-            let values = create_array(reader, list_field)?;
-            create_list_array(list_node, data_type, &list_buffers, values)
-        }
-        Struct(struct_fields) => {
-            let struct_node = reader.next_node(field)?;
-            let null_buffer = reader.next_buffer()?;
-
-            // read the arrays for each field
-            let mut struct_arrays = vec![];
-            // TODO investigate whether just knowing the number of buffers could
-            // still work
-            for struct_field in struct_fields {
-                let child = create_array(reader, struct_field)?;
-                struct_arrays.push((struct_field.clone(), child));
-            }
-            let null_count = struct_node.null_count() as usize;
-            let struct_array = if null_count > 0 {
-                // create struct array from fields, arrays and null data
-                StructArray::from((struct_arrays, null_buffer))
-            } else {
-                StructArray::from(struct_arrays)
-            };
-            Ok(Arc::new(struct_array))
-        }
-        RunEndEncoded(run_ends_field, values_field) => {
-            let run_node = reader.next_node(field)?;
-            let run_ends = create_array(reader, run_ends_field)?;
-            let values = create_array(reader, values_field)?;
+        List(f) | LargeList(f) | Map(f, _) | FixedSizeList(f, _) => vec![f.as_ref()],
+        Struct(fields) => fields.iter().map(|f| f.as_ref()).collect(),
+        Union(fields, _) => fields.iter().map(|(_, f)| f.as_ref()).collect(),
+        RunEndEncoded(run_ends, values) => vec![run_ends.as_ref(), values.as_ref()],
+        _ => vec![],
+    }
+}
 
-            let run_array_length = run_node.length() as usize;
-            let data = ArrayData::builder(data_type.clone())
-                .len(run_array_length)
-                .offset(0)
-                .add_child_data(run_ends.into_data())
-                .add_child_data(values.into_data())
-                .build_aligned()?;
+/// Collects the dictionary ids used by `field` and any of its descendants,
+/// including those nested within dictionary values
+pub(crate) fn collect_dictionary_ids(field: &Field, ids: &mut HashSet<i64>) {
+    if let Some(id) = field.dict_id() {
+        ids.insert(id);
+    }
+    let data_type = match field.data_type() {
+        Dictionary(_, values) => values.as_ref(),
+        d => d,
+    };
+    for child in child_fields(data_type) {
+        collect_dictionary_ids(child, ids);
+    }
+}
 
-            Ok(make_array(data))
+/// Builds the [`ArrayData`], aligning its buffers and, unless `skip_validation`
+/// is set, validating its contents
+fn build_array_data(
+    builder: ArrayDataBuilder,
+    skip_validation: bool,
+) -> Result<ArrayData, ArrowError> {
+    match skip_validation {
+        true => {
+            // SAFETY: the caller opted out of validation for this column, see
+            // FileDecoder::with_skip_validation
+            let mut data = unsafe { builder.build_unchecked() };
+            data.align_buffers();
+            Ok(data)
         }
-        // Create dictionary array from RecordBatch
-        Dictionary(_, _) => {
-            let index_node = reader.next_node(field)?;
-            let index_buffers = [reader.next_buffer()?, reader.next_buffer()?];
+        false => builder.build_aligned(),
+    }
+}
 
-            let dict_id = field.dict_id().ok_or_else(|| {
-                ArrowError::ParseError(format!("Field {field} does not have dict id"))
-            })?;
+/// Returns the dictionary values `existing` followed by the values of a delta dictionary
+fn append_delta(existing: &ArrayRef, delta: &ArrayRef) -> Result<ArrayRef, ArrowError> {
+    if existing.data_type() != delta.data_type() {
+        return Err(ArrowError::IpcError(format!(
+            "Cannot apply delta of type {} to dictionary of type {}",
+            delta.data_type(),
+            existing.data_type()
+        )));
+    }
+    let (existing, delta) = (existing.to_data(), delta.to_data());
+    let len = existing.len() + delta.len();
+    let mut values = MutableArrayData::new(vec![&existing, &delta], false, len);
+    values.extend(0, 0, existing.len());
+    values.extend(1, 0, delta.len());
+    Ok(make_array(values.freeze()))
+}
 
-            let value_array = reader.dictionaries_by_id.get(&dict_id).ok_or_else(|| {
-                ArrowError::ParseError(format!(
-                    "Cannot find a dictionary batch with dict id: {dict_id}"
+/// Validates the type ids and value offsets of a union, which are not checked by
+/// [`ArrayData::validate_full`]
+fn validate_union(
+    data: &ArrayData,
+    fields: &UnionFields,
+    mode: UnionMode,
+) -> Result<(), ArrowError> {
+    let type_ids = &data.buffer::<i8>(0)[..data.len()];
+    let mut child_lens = [None; 128];
+    for ((type_id, _), child) in fields.iter().zip(data.child_data()) {
+        child_lens[type_id as u8 as usize] = Some(child.len());
+    }
+    let child_len = |type_id: i8| {
+        usize::try_from(type_id)
+            .ok()
+            .and_then(|idx| child_lens[idx])
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Type Id {type_id} is not one of the union's type ids"
                 ))
-            })?;
-
-            create_dictionary_array(index_node, data_type, &index_buffers, value_array.clone())
-        }
-        Union(fields, mode) => {
-            let union_node = reader.next_node(field)?;
-            let len = union_node.length() as usize;
-
-            // In V4, union types has validity bitmap
-            // In V5 and later, union types have no validity bitmap
-            if reader.version < MetadataVersion::V5 {
-                reader.next_buffer()?;
-            }
-
-            let type_ids: Buffer = reader.next_buffer()?[..len].into();
+            })
+    };
 
-            let value_offsets = match mode {
-                UnionMode::Dense => {
-                    let buffer = reader.next_buffer()?;
-                    Some(buffer[..len * 4].into())
+    match mode {
+        UnionMode::Sparse => type_ids.iter().try_for_each(|t| child_len(*t).map(|_| ())),
+        UnionMode::Dense => {
+            let offsets = &data.buffer::<i32>(1)[..data.len()];
+            type_ids.iter().zip(offsets).try_for_each(|(type_id, offset)| {
+                let len = child_len(*type_id)?;
+                match usize::try_from(*offset) {
+                    Ok(offset) if offset < len => Ok(()),
+                    _ => Err(ArrowError::InvalidArgumentError(format!(
+                        "Offset {offset} of type Id {type_id} is out of bounds for a child of length {len}"
+                    ))),
                 }
-                UnionMode::Sparse => None,
-            };
-
-            let mut children = Vec::with_capacity(fields.len());
-            let mut ids = Vec::with_capacity(fields.len());
-
-            for (id, field) in fields.iter() {
-                let child = create_array(reader, field)?;
-                children.push((field.as_ref().clone(), child));
-                ids.push(id);
-            }
-
-            let array = UnionArray::try_new(&ids, type_ids, value_offsets, children)?;
-            Ok(Arc::new(array))
+            })
         }
-        Null => {
-            let node = reader.next_node(field)?;
-            let length = node.length();
-            let null_count = node.null_count();
-
-            if length != null_count {
-                return Err(ArrowError::SchemaError(format!(
-                    "Field {field} of NullArray has unequal null_count {null_count} and len {length}"
-                )));
-            }
-
-            let data = ArrayData::builder(data_type.clone())
-                .len(length as usize)
-                .offset(0)
-                .build_aligned()
-                .unwrap();
-            // no buffer increases
-            Ok(Arc::new(NullArray::from(data)))
-        }
-        _ => create_primitive_array(
-            reader.next_node(field)?,
-            data_type,
-            &[reader.next_buffer()?, reader.next_buffer()?],
-        ),
-    }
-}
-
-/// Reads the correct number of buffers based on data type and null_count, and creates a
-/// primitive array ref
-fn create_primitive_array(
-    field_node: &FieldNode,
-    data_type: &DataType,
-    buffers: &[Buffer],
-) -> Result<ArrayRef, ArrowError> {
-    let length = field_node.length() as usize;
-    let null_buffer = (field_node.null_count() > 0).then_some(buffers[0].clone());
-    let array_data = match data_type {
-        Utf8 | Binary | LargeBinary | LargeUtf8 => {
-            // read 3 buffers: null buffer (optional), offsets buffer and data buffer
-            ArrayData::builder(data_type.clone())
-                .len(length)
-                .buffers(buffers[1..3].to_vec())
-                .null_bit_buffer(null_buffer)
-                .build_aligned()?
-        }
-        _ if data_type.is_primitive() || matches!(data_type, Boolean | FixedSizeBinary(_)) => {
-            // read 2 buffers: null buffer (optional) and data buffer
-            ArrayData::builder(data_type.clone())
-                .len(length)
-                .add_buffer(buffers[1].clone())
-                .null_bit_buffer(null_buffer)
-                .build_aligned()?
-        }
-        t => unreachable!("Data type {:?} either unsupported or not primitive", t),
-    };
-
-    Ok(make_array(array_data))
-}
-
-/// Reads the correct number of buffers based on list type and null_count, and creates a
-/// list array ref
-fn create_list_array(
-    field_node: &FieldNode,
-    data_type: &DataType,
-    buffers: &[Buffer],
-    child_array: ArrayRef,
-) -> Result<ArrayRef, ArrowError> {
-    let null_buffer = (field_node.null_count() > 0).then_some(buffers[0].clone());
-    let length = field_node.length() as usize;
-    let child_data = child_array.into_data();
-    let builder = match data_type {
-        List(_) | LargeList(_) | Map(_, _) => ArrayData::builder(data_type.clone())
-            .len(length)
-            .add_buffer(buffers[1].clone())
-            .add_child_data(child_data)
-            .null_bit_buffer(null_buffer),
-
-        FixedSizeList(_, _) => ArrayData::builder(data_type.clone())
-            .len(length)
-            .add_child_data(child_data)
-            .null_bit_buffer(null_buffer),
-
-        _ => unreachable!("Cannot create list or map array from {:?}", data_type),
-    };
-    Ok(make_array(builder.build_aligned()?))
-}
-
-/// Reads the correct number of buffers based on list type and null_count, and creates a
-/// list array ref
-fn create_dictionary_array(
-    field_node: &FieldNode,
-    data_type: &DataType,
-    buffers: &[Buffer],
-    value_array: ArrayRef,
-) -> Result<ArrayRef, ArrowError> {
-    if let Dictionary(_, _) = *data_type {
-        let null_buffer = (field_node.null_count() > 0).then_some(buffers[0].clone());
-        let builder = ArrayData::builder(data_type.clone())
-            .len(field_node.length() as usize)
-            .add_buffer(buffers[1].clone())
-            .add_child_data(value_array.into_data())
-            .null_bit_buffer(null_buffer);
-
-        Ok(make_array(builder.build_aligned()?))
-    } else {
-        unreachable!("Cannot create dictionary array from {:?}", data_type)
     }
 }
 
@@ -304,15 +197,29 @@ struct ArrayReader<'a> {
     nodes: VectorIter<'a, FieldNode>,
     /// The buffers comprising this array
     buffers: VectorIter<'a, crate::Buffer>,
+    /// Whether to skip validation of the column currently being decoded
+    skip_validation: bool,
+    /// Whether the data has non-native endianness, and must be byte-swapped
+    swap_endianness: bool,
 }
 
 impl<'a> ArrayReader<'a> {
-    fn next_buffer(&mut self) -> Result<Buffer, ArrowError> {
-        read_buffer(self.buffers.next().unwrap(), self.data, self.compression)
+    fn next_buffer(&mut self, field: &Field) -> Result<Buffer, ArrowError> {
+        let buffer = self.buffers.next().ok_or_else(|| {
+            ArrowError::IpcError(format!(
+                "Invalid data for schema. {field} refers to buffer not found in record batch"
+            ))
+        })?;
+        read_buffer(buffer, self.data, self.compression)
     }
 
-    fn skip_buffer(&mut self) {
-        self.buffers.next().unwrap();
+    fn skip_buffer(&mut self, field: &Field) -> Result<(), ArrowError> {
+        self.buffers.next().ok_or_else(|| {
+            ArrowError::IpcError(format!(
+                "Invalid data for schema. {field} refers to buffer not found in record batch"
+            ))
+        })?;
+        Ok(())
     }
 
     fn next_node(&mut self, field: &Field) -> Result<&'a FieldNode, ArrowError> {
@@ -324,63 +231,111 @@ impl<'a> ArrayReader<'a> {
         })
     }
 
-    fn skip_field(&mut self, field: &Field) -> Result<(), ArrowError> {
-        self.next_node(field)?;
-
-        match field.data_type() {
-            Utf8 | Binary | LargeBinary | LargeUtf8 => {
-                for _ in 0..3 {
-                    self.skip_buffer()
-                }
-            }
-            FixedSizeBinary(_) => {
-                self.skip_buffer();
-                self.skip_buffer();
+    /// Walks the field node, buffers and children of `field`, returning the decoded
+    /// [`ArrayData`] with `projection` applied, or skipping over them if the field
+    /// is not projected
+    ///
+    /// The buffers are determined from the [`layout`] of the data type, so that reading
+    /// and skipping cannot disagree on the encoding of any type
+    fn read_field(
+        &mut self,
+        field: &Field,
+        projection: &FieldProjection,
+    ) -> Result<Option<ArrayData>, ArrowError> {
+        let node = self.next_node(field)?;
+        let data_type = field.data_type();
+        let materialize = !matches!(projection, FieldProjection::Skip);
+        let layout = layout(data_type);
+
+        // In V4, union types have a validity bitmap, which is ignored
+        // In V5 and later, union types have no validity bitmap
+        let legacy_union_nulls =
+            matches!(data_type, Union(_, _)) && self.version < MetadataVersion::V5;
+        let has_nulls = layout.can_contain_null_mask || legacy_union_nulls;
+        let num_buffers = usize::from(has_nulls) + layout.buffers.len();
+
+        let mut buffers = Vec::with_capacity(num_buffers);
+        for _ in 0..num_buffers {
+            match materialize {
+                true => buffers.push(self.next_buffer(field)?),
+                false => self.skip_buffer(field)?,
             }
-            List(list_field) | LargeList(list_field) | Map(list_field, _) => {
-                self.skip_buffer();
-                self.skip_buffer();
-                self.skip_field(list_field)?;
+        }
+
+        let mut child_data = vec![];
+        for (idx, child) in child_fields(data_type).into_iter().enumerate() {
+            if let Some(data) = self.read_field(child, projection.child(idx))? {
+                child_data.push(data);
             }
-            FixedSizeList(list_field, _) => {
-                self.skip_buffer();
-                self.skip_field(list_field)?;
+        }
+
+        if !materialize {
+            return Ok(None);
+        }
+
+        let length = node.length() as usize;
+        let null_count = node.null_count() as usize;
+
+        if let Dictionary(_, _) = data_type {
+            let dict_id = field.dict_id().ok_or_else(|| {
+                ArrowError::ParseError(format!("Field {field} does not have dict id"))
+            })?;
+
+            let value_array = self.dictionaries_by_id.get(&dict_id).ok_or_else(|| {
+                ArrowError::ParseError(format!(
+                    "Cannot find a dictionary batch with dict id: {dict_id}"
+                ))
+            })?;
+            child_data.push(value_array.to_data());
+        }
+
+        if let Null = data_type {
+            if length != null_count {
+                return Err(ArrowError::SchemaError(format!(
+                    "Field {field} of NullArray has unequal null_count {null_count} and len {length}"
+                )));
             }
-            Struct(struct_fields) => {
-                self.skip_buffer();
+        }
 
-                // skip for each field
-                for struct_field in struct_fields {
-                    self.skip_field(struct_field)?
+        if self.swap_endianness {
+            let values = &mut buffers[usize::from(has_nulls)..];
+            for (buffer, spec) in values.iter_mut().zip(&layout.buffers) {
+                let components = buffer_components(data_type, spec);
+                if !components.is_empty() {
+                    *buffer = Buffer::from_vec(swap_components(buffer, &components));
                 }
             }
-            RunEndEncoded(run_ends_field, values_field) => {
-                self.skip_field(run_ends_field)?;
-                self.skip_field(values_field)?;
-            }
-            Dictionary(_, _) => {
-                self.skip_buffer(); // Nulls
-                self.skip_buffer(); // Indices
-            }
-            Union(fields, mode) => {
-                self.skip_buffer(); // Nulls
+        }
 
-                match mode {
-                    UnionMode::Dense => self.skip_buffer(),
-                    UnionMode::Sparse => {}
-                };
+        let mut buffers = buffers.into_iter();
+        let nulls = match has_nulls {
+            true => buffers.next(),
+            false => None,
+        };
+        let nulls = match layout.can_contain_null_mask && null_count > 0 {
+            true => nulls,
+            false => None,
+        };
 
-                for (_, field) in fields.iter() {
-                    self.skip_field(field)?
-                }
-            }
-            Null => {} // No buffer increases
-            _ => {
-                self.skip_buffer();
-                self.skip_buffer();
-            }
+        let projected_type = match projection {
+            FieldProjection::Children(_) => projection.project_data_type(data_type),
+            _ => None,
         };
-        Ok(())
+        let builder = ArrayData::builder(projected_type.unwrap_or_else(|| data_type.clone()))
+            .len(length)
+            .buffers(buffers.collect())
+            .child_data(child_data)
+            .null_bit_buffer(nulls);
+
+        let data = build_array_data(builder, self.skip_validation)?;
+        if let (Union(fields, mode), false) = (data.data_type(), self.skip_validation) {
+            validate_union(&data, fields, *mode)?;
+        }
+        Ok(Some(data))
+    }
+
+    fn skip_field(&mut self, field: &Field) -> Result<(), ArrowError> {
+        self.read_field(field, &FieldProjection::Skip).map(|_| ())
     }
 }
 
@@ -392,6 +347,35 @@ pub fn read_record_batch(
     dictionaries_by_id: &HashMap<i64, ArrayRef>,
     projection: Option<&[usize]>,
     metadata: &MetadataVersion,
+) -> Result<RecordBatch, ArrowError> {
+    read_record_batch_impl(
+        buf,
+        batch,
+        schema,
+        dictionaries_by_id,
+        projection,
+        None,
+        metadata,
+        &[],
+        NATIVE_ENDIANNESS,
+    )
+}
+
+/// Creates a record batch as [`read_record_batch`], applying the nested projection
+/// `mask` to the projected columns, skipping validation of the columns with indices
+/// in `skip_validation`, and byte-swapping the buffers if `endianness` is not that
+/// of the system
+#[allow(clippy::too_many_arguments)]
+fn read_record_batch_impl(
+    buf: &Buffer,
+    batch: crate::RecordBatch,
+    schema: SchemaRef,
+    dictionaries_by_id: &HashMap<i64, ArrayRef>,
+    projection: Option<&[usize]>,
+    mask: Option<&ProjectionMask>,
+    metadata: &MetadataVersion,
+    skip_validation: &[usize],
+    endianness: crate::Endianness,
 ) -> Result<RecordBatch, ArrowError> {
     let buffers = batch.buffers().ok_or_else(|| {
         ArrowError::IpcError("Unable to get buffers from IPC RecordBatch".to_string())
@@ -411,17 +395,46 @@ pub fn read_record_batch(
         data: buf,
         nodes: field_nodes.iter(),
         buffers: buffers.iter(),
+        skip_validation: false,
+        swap_endianness: endianness != NATIVE_ENDIANNESS,
     };
 
     let options = RecordBatchOptions::new().with_row_count(Some(batch.length() as usize));
 
-    if let Some(projection) = projection {
+    if let Some(mask) = mask {
+        let field_projections = mask.field_projections(&schema);
+        let mut columns = vec![];
+        for (idx, field) in schema.fields().iter().enumerate() {
+            let position = match projection {
+                Some(projection) => projection.iter().position(|p| p == &idx),
+                None => Some(idx),
+            };
+            let field_projection = &field_projections[idx];
+            match position.zip(field_projection.project_field(field)) {
+                Some((position, projected)) => {
+                    reader.skip_validation = skip_validation.contains(&idx);
+                    let data = reader
+                        .read_field(field, field_projection)
+                        .map_err(|e| column_context(e, field))?;
+                    let array = make_array(data.expect("materialized field"));
+                    columns.push((position, projected, array));
+                }
+                None => reader.skip_field(field)?,
+            }
+        }
+        columns.sort_by_key(|t| t.0);
+        let (fields, arrays): (Vec<_>, Vec<_>) = columns.into_iter().map(|t| (t.1, t.2)).unzip();
+        let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+        RecordBatch::try_new_with_options(Arc::new(schema), arrays, &options)
+    } else if let Some(projection) = projection {
         let mut arrays = vec![];
         // project fields
         for (idx, field) in schema.fields().iter().enumerate() {
             // Create array for projected field
             if let Some(proj_idx) = projection.iter().position(|p| p == &idx) {
-                let child = create_array(&mut reader, field)?;
+                reader.skip_validation = skip_validation.contains(&idx);
+                let child =
+                    create_array(&mut reader, field).map_err(|e| column_context(e, field))?;
                 arrays.push((proj_idx, child));
             } else {
                 reader.skip_field(field)?;
@@ -436,16 +449,25 @@ pub fn read_record_batch(
     } else {
         let mut children = vec![];
         // keep track of index as lists require more than one node
-        for field in schema.fields() {
-            let child = create_array(&mut reader, field)?;
+        for (idx, field) in schema.fields().iter().enumerate() {
+            reader.skip_validation = skip_validation.contains(&idx);
+            let child = create_array(&mut reader, field).map_err(|e| column_context(e, field))?;
             children.push(child);
         }
         RecordBatch::try_new_with_options(schema, children, &options)
     }
 }
 
+/// Adds the name of the column `field` to the context of `err`
+fn column_context(err: ArrowError, field: &Field) -> ArrowError {
+    err.with_context(ErrorContext::new("reading").with_column_path([field.name().as_str()]))
+}
+
 /// Read the dictionary from the buffer and provided metadata,
 /// updating the `dictionaries_by_id` with the resulting dictionary
+///
+/// If the dictionary batch is a delta, its values are appended to the existing
+/// values of the dictionary with the same id
 pub fn read_dictionary(
     buf: &Buffer,
     batch: crate::DictionaryBatch,
@@ -453,12 +475,26 @@ pub fn read_dictionary(
     dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
     metadata: &MetadataVersion,
 ) -> Result<(), ArrowError> {
-    if batch.isDelta() {
-        return Err(ArrowError::InvalidArgumentError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
+    read_dictionary_impl(
+        buf,
+        batch,
+        schema,
+        dictionaries_by_id,
+        metadata,
+        NATIVE_ENDIANNESS,
+    )
+}
 
+/// Reads a dictionary as [`read_dictionary`], byte-swapping the buffers if
+/// `endianness` is not that of the system
+fn read_dictionary_impl(
+    buf: &Buffer,
+    batch: crate::DictionaryBatch,
+    schema: &Schema,
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+    metadata: &MetadataVersion,
+    endianness: crate::Endianness,
+) -> Result<(), ArrowError> {
     let id = batch.id();
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
     let first_field = fields_using_this_dictionary.first().ok_or_else(|| {
@@ -474,13 +510,16 @@ pub fn read_dictionary(
             let value = value_type.as_ref().clone();
             let schema = Schema::new(vec![Field::new("", value, true)]);
             // Read a single column
-            let record_batch = read_record_batch(
+            let record_batch = read_record_batch_impl(
                 buf,
                 batch.data().unwrap(),
                 Arc::new(schema),
                 dictionaries_by_id,
                 None,
+                None,
                 metadata,
+                &[],
+                endianness,
             )?;
             Some(record_batch.column(0).clone())
         }
@@ -490,24 +529,105 @@ pub fn read_dictionary(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    let dictionary_values = match batch.isDelta() {
+        true => {
+            let existing = dictionaries_by_id.get(&id).ok_or_else(|| {
+                ArrowError::IpcError(format!(
+                    "Cannot apply delta to dictionary with id {id} as it has not been read"
+                ))
+            })?;
+            append_delta(existing, &dictionary_values)?
+        }
+        false => dictionary_values,
+    };
+
     // We don't currently record the isOrdered field. This could be general
     // attributes of arrays.
     // Add (possibly multiple) array refs to the dictionaries array.
-    dictionaries_by_id.insert(id, dictionary_values.clone());
+    dictionaries_by_id.insert(id, dictionary_values);
 
     Ok(())
 }
 
 /// Read the data for a given block
-fn read_block<R: Read + Seek>(mut reader: R, block: &Block) -> Result<Buffer, ArrowError> {
+fn read_block<R: Read + Seek>(
+    mut reader: R,
+    block: &Block,
+    limits: &ReadLimits,
+) -> Result<Buffer, ArrowError> {
+    let (metadata_len, body_len) = limits.check_block(block)?;
+    reader.seek(SeekFrom::Start(block.offset() as u64))?;
+    Ok(read_exact_buffer(&mut reader, metadata_len + body_len)?.into())
+}
+
+/// Reads `len` bytes from `reader` into a [`MutableBuffer`]
+///
+/// As `len` is typically read from untrusted metadata, the buffer is grown as data
+/// is read, rather than allocated upfront, so that a corrupt length results in an
+/// error once the reader is exhausted, instead of an excessive allocation
+pub(crate) fn read_exact_buffer<R: Read>(
+    reader: &mut R,
+    len: usize,
+) -> Result<MutableBuffer, ArrowError> {
+    const INITIAL_CAPACITY: usize = 1024 * 1024;
+    let mut buf = MutableBuffer::new(0);
+    while buf.len() < len {
+        let start = buf.len();
+        let to_read = (len - start).min(start.max(INITIAL_CAPACITY));
+        buf.resize(start + to_read, 0);
+        reader.read_exact(&mut buf[start..])?;
+    }
+    Ok(buf)
+}
+
+/// Slice the data for a given block from `buffer`, without copying
+fn slice_block(buffer: &Buffer, block: &Block) -> Result<Buffer, ArrowError> {
+    let range = usize::try_from(block.offset()).ok().and_then(|offset| {
+        let body_len = usize::try_from(block.bodyLength()).ok()?;
+        let metadata_len = usize::try_from(block.metaDataLength()).ok()?;
+        let len = body_len.checked_add(metadata_len)?;
+        let end = offset.checked_add(len)?;
+        (end <= buffer.len()).then_some((offset, len))
+    });
+    match range {
+        Some((offset, len)) => Ok(buffer.slice_with_length(offset, len)),
+        None => Err(ArrowError::IpcError(format!(
+            "Block at offset {} is out of bounds of the {} byte file",
+            block.offset(),
+            buffer.len()
+        ))),
+    }
+}
+
+/// Read the dictionary batch for a given block, returning `None` without reading
+/// the body if `required` returns `false` for its dictionary id
+fn read_dictionary_block<R: Read + Seek>(
+    mut reader: R,
+    block: &Block,
+    limits: &ReadLimits,
+    required: impl FnOnce(i64) -> bool,
+) -> Result<Option<Buffer>, ArrowError> {
+    let (metadata_len, body_len) = limits.check_block(block)?;
     reader.seek(SeekFrom::Start(block.offset() as u64))?;
-    let body_len = block.bodyLength().to_usize().unwrap();
-    let metadata_len = block.metaDataLength().to_usize().unwrap();
-    let total_len = body_len.checked_add(metadata_len).unwrap();
+    let mut buf = read_exact_buffer(&mut reader, metadata_len)?;
+
+    if matches!(dictionary_block_id(&buf), Some(id) if !required(id)) {
+        return Ok(None);
+    }
+
+    buf.extend_from_slice(&read_exact_buffer(&mut reader, body_len)?);
+    Ok(Some(buf.into()))
+}
 
-    let mut buf = MutableBuffer::from_len_zeroed(total_len);
-    reader.read_exact(&mut buf)?;
-    Ok(buf.into())
+/// Returns the id of the dictionary batch with the given message metadata, or `None`
+/// if the message is not a valid dictionary batch
+///
+/// Messages that are not dictionary batches are reported by [`FileDecoder::read_dictionary`]
+pub(crate) fn dictionary_block_id(metadata: &[u8]) -> Option<i64> {
+    parse_message(metadata)
+        .ok()
+        .and_then(|m| m.header_as_dictionary_batch())
+        .map(|b| b.id())
 }
 
 /// Parse an encapsulated message
@@ -522,6 +642,80 @@ fn parse_message(buf: &[u8]) -> Result<Message, ArrowError> {
         .map_err(|err| ArrowError::ParseError(format!("Unable to get root as message: {err:?}")))
 }
 
+/// A [`RecordBatch`] along with the custom metadata of its IPC message
+pub type RecordBatchWithMetadata = (RecordBatch, HashMap<String, String>);
+
+/// The undecoded contents of a [`Block`] of an Arrow IPC file, consisting of an
+/// encapsulated [`Message`] followed by its body
+///
+/// See [`FileReader::read_block`] and [`BufferFileReader::read_block`]
+#[derive(Debug, Clone)]
+pub struct RawBlock {
+    block: Block,
+    buffer: Buffer,
+}
+
+impl RawBlock {
+    /// Returns the [`Block`] from which this was read
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Parses the [`Message`] header of this block, without decoding its body
+    pub fn message(&self) -> Result<Message<'_>, ArrowError> {
+        match self.metadata() {
+            m if m.len() < 8 => Err(ArrowError::IpcError(format!(
+                "Block metadata of {} bytes is too small to contain a message",
+                m.len()
+            ))),
+            m => parse_message(m),
+        }
+    }
+
+    /// Returns the encapsulated message metadata of this block, including the
+    /// continuation marker and length prefix
+    pub fn metadata(&self) -> &[u8] {
+        &self.buffer[..self.block.metaDataLength() as usize]
+    }
+
+    /// Returns the body of this block, containing the buffers of the message
+    pub fn body(&self) -> Buffer {
+        self.buffer.slice(self.block.metaDataLength() as usize)
+    }
+
+    /// Returns the byte range of the body of this block within the file
+    pub fn body_range(&self) -> std::ops::Range<u64> {
+        let start = self.block.offset() as u64 + self.block.metaDataLength() as u64;
+        start..start + self.block.bodyLength() as u64
+    }
+
+    /// Returns the contents of this block, the metadata followed by the body, as
+    /// expected by [`FileDecoder::read_record_batch`] and [`FileDecoder::read_dictionary`]
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+}
+
+/// Converts flatbuffers custom metadata into a [`HashMap`]
+fn custom_metadata_from_fb(
+    fb_custom_metadata: Option<Vector<'_, ForwardsUOffset<KeyValue<'_>>>>,
+) -> HashMap<String, String> {
+    let mut custom_metadata = HashMap::new();
+    for kv in fb_custom_metadata.into_iter().flatten() {
+        if let (Some(key), Some(value)) = (kv.key(), kv.value()) {
+            custom_metadata.insert(key.to_string(), value.to_string());
+        }
+    }
+    custom_metadata
+}
+
+/// Returns the custom metadata of `message`, excluding any message checksum
+fn message_custom_metadata(message: &Message<'_>) -> HashMap<String, String> {
+    let mut custom_metadata = custom_metadata_from_fb(message.custom_metadata());
+    custom_metadata.remove(MESSAGE_CHECKSUM_KEY);
+    custom_metadata
+}
+
 /// Read the footer length from the last 10 bytes of an Arrow IPC file
 ///
 /// Expects a 4 byte footer length followed by `b"ARROW1"`
@@ -599,12 +793,18 @@ pub fn read_footer_length(buf: [u8; 10]) -> Result<usize, ArrowError> {
 ///
 /// assert_eq!(batch, back);
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FileDecoder {
     schema: SchemaRef,
     dictionaries: HashMap<i64, ArrayRef>,
     version: MetadataVersion,
     projection: Option<Vec<usize>>,
+    projection_mask: Option<ProjectionMask>,
+    /// The dictionary ids used by the projected fields, `None` if all are required
+    projected_dictionaries: Option<HashSet<i64>>,
+    skip_validation: Vec<usize>,
+    endianness: crate::Endianness,
+    verify_checksums: bool,
 }
 
 impl FileDecoder {
@@ -615,12 +815,127 @@ impl FileDecoder {
             version,
             dictionaries: Default::default(),
             projection: None,
+            projection_mask: None,
+            projected_dictionaries: None,
+            skip_validation: vec![],
+            endianness: NATIVE_ENDIANNESS,
+            verify_checksums: true,
         }
     }
 
     /// Specify a projection
+    ///
+    /// Dictionary batches for dictionaries not used by any of the projected
+    /// fields are ignored by [`Self::read_dictionary`]
     pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
         self.projection = Some(projection);
+        self.projected_dictionaries = Some(self.projected_dictionary_ids());
+        self
+    }
+
+    /// Specify a [`ProjectionMask`] selecting the nested fields to read
+    ///
+    /// The leaf indices of `mask` refer to the schema of the file, prior to any
+    /// projection. If combined with [`Self::with_projection`], only the selected
+    /// leaves of the projected columns are read
+    pub fn with_projection_mask(mut self, mask: ProjectionMask) -> Self {
+        self.projection_mask = Some(mask);
+        self.projected_dictionaries = Some(self.projected_dictionary_ids());
+        self
+    }
+
+    /// Returns the dictionary ids used by the projected fields
+    fn projected_dictionary_ids(&self) -> HashSet<i64> {
+        let field_projections = self
+            .projection_mask
+            .as_ref()
+            .map(|mask| mask.field_projections(&self.schema));
+        let mut ids = HashSet::new();
+        for (idx, field) in self.schema.fields().iter().enumerate() {
+            if let Some(projection) = &self.projection {
+                if !projection.contains(&idx) {
+                    continue;
+                }
+            }
+            let field = match &field_projections {
+                Some(p) => p[idx].project_field(field),
+                None => Some(field.as_ref().clone()),
+            };
+            if let Some(field) = field {
+                collect_dictionary_ids(&field, &mut ids);
+            }
+        }
+        ids
+    }
+
+    /// Pre-seed the dictionary memo with already decoded dictionary values,
+    /// keyed by dictionary id
+    ///
+    /// This allows reusing dictionaries decoded by another reader. Dictionary batches
+    /// subsequently read by [`Self::read_dictionary`] replace any existing entry
+    pub fn with_dictionaries(mut self, dictionaries: HashMap<i64, ArrayRef>) -> Self {
+        self.dictionaries = dictionaries;
+        self
+    }
+
+    /// Specify the endianness of the encoded data, as recorded in the schema of
+    /// the file, defaulting to that of the system
+    ///
+    /// If this differs from the endianness of the system, the decoded buffers are
+    /// byte-swapped, allowing files written on systems of either endianness to be read
+    pub fn with_endianness(mut self, endianness: crate::Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
+    /// Configures whether to verify the checksums of messages that contain one,
+    /// defaults to `true`
+    ///
+    /// Verifying checksums requires the `checksum` feature, without which decoding a
+    /// message containing a checksum returns an error unless this is `false`
+    ///
+    /// See [`IpcWriteOptions::with_message_checksums`](crate::writer::IpcWriteOptions::with_message_checksums)
+    pub fn with_verify_checksums(mut self, verify_checksums: bool) -> Self {
+        self.verify_checksums = verify_checksums;
+        self
+    }
+
+    /// Returns the schema of the file, prior to any projection
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the dictionary memo, containing the decoded dictionary values
+    /// keyed by dictionary id
+    pub fn dictionaries(&self) -> &HashMap<i64, ArrayRef> {
+        &self.dictionaries
+    }
+
+    /// Returns `true` if the dictionary with the given id is used by the projected
+    /// fields, and therefore needs to be read
+    pub fn requires_dictionary(&self, id: i64) -> bool {
+        match &self.projected_dictionaries {
+            Some(ids) => ids.contains(&id),
+            None => true,
+        }
+    }
+
+    /// Skip validation of the decoded arrays for the columns with the given
+    /// (zero-based) indices in the schema
+    ///
+    /// By default every decoded array is fully validated, including checking that
+    /// offsets are in bounds and that string data is valid UTF-8. This can be
+    /// expensive for large string columns, and may be unnecessary for columns
+    /// produced by a trusted writer, whilst still validating the remaining columns.
+    ///
+    /// Dictionary values are always validated.
+    ///
+    /// # Safety
+    ///
+    /// The IPC data for the listed columns must be valid, as described in
+    /// [`ArrayData::validate_full`], otherwise undefined behaviour may result
+    pub unsafe fn with_skip_validation(mut self, columns: Vec<usize>) -> Self {
+        self.skip_validation = columns;
         self
     }
 
@@ -637,17 +952,28 @@ impl FileDecoder {
     }
 
     /// Read the dictionary with the given block and data buffer
+    ///
+    /// Dictionaries not used by the projected fields are skipped, see
+    /// [`Self::requires_dictionary`]
     pub fn read_dictionary(&mut self, block: &Block, buf: &Buffer) -> Result<(), ArrowError> {
         let message = self.read_message(buf)?;
         match message.header_type() {
             crate::MessageHeader::DictionaryBatch => {
                 let batch = message.header_as_dictionary_batch().unwrap();
-                read_dictionary(
-                    &buf.slice(block.metaDataLength() as _),
+                if !self.requires_dictionary(batch.id()) {
+                    return Ok(());
+                }
+                let body = buf.slice(block.metaDataLength() as _);
+                if self.verify_checksums {
+                    verify_message_checksum(&message, &body)?;
+                }
+                read_dictionary_impl(
+                    &body,
                     batch,
                     &self.schema,
                     &mut self.dictionaries,
                     &message.version(),
+                    self.endianness,
                 )
             }
             t => Err(ArrowError::ParseError(format!(
@@ -662,6 +988,17 @@ impl FileDecoder {
         block: &Block,
         buf: &Buffer,
     ) -> Result<Option<RecordBatch>, ArrowError> {
+        let batch = self.read_record_batch_with_metadata(block, buf)?;
+        Ok(batch.map(|(batch, _)| batch))
+    }
+
+    /// Read the RecordBatch with the given block and data buffer, along with the
+    /// custom metadata of the record batch message
+    pub fn read_record_batch_with_metadata(
+        &self,
+        block: &Block,
+        buf: &Buffer,
+    ) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
         let message = self.read_message(buf)?;
         match message.header_type() {
             crate::MessageHeader::Schema => Err(ArrowError::IpcError(
@@ -672,15 +1009,22 @@ impl FileDecoder {
                     ArrowError::IpcError("Unable to read IPC message as record batch".to_string())
                 })?;
                 // read the block that makes up the record batch into a buffer
-                read_record_batch(
-                    &buf.slice(block.metaDataLength() as _),
+                let body = buf.slice(block.metaDataLength() as _);
+                if self.verify_checksums {
+                    verify_message_checksum(&message, &body)?;
+                }
+                let batch = read_record_batch_impl(
+                    &body,
                     batch,
                     self.schema.clone(),
                     &self.dictionaries,
                     self.projection.as_deref(),
+                    self.projection_mask.as_ref(),
                     &message.version(),
-                )
-                .map(Some)
+                    &self.skip_validation,
+                    self.endianness,
+                )?;
+                Ok(Some((batch, message_custom_metadata(&message))))
             }
             crate::MessageHeader::NONE => Ok(None),
             t => Err(ArrowError::InvalidArgumentError(format!(
@@ -690,30 +1034,200 @@ impl FileDecoder {
     }
 }
 
-/// Build an Arrow [`FileReader`] with custom options.
-#[derive(Debug)]
-pub struct FileReaderBuilder {
-    /// Optional projection for which columns to load (zero-based column indices)
-    projection: Option<Vec<usize>>,
-    /// Passed through to construct [`VerifierOptions`]
-    max_footer_fb_tables: usize,
-    /// Passed through to construct [`VerifierOptions`]
-    max_footer_fb_depth: usize,
+/// Limits on the sizes of the data read by [`FileReader`] and [`StreamReader`]
+///
+/// The sizes of messages are read from the metadata of the input, and so a corrupt
+/// or malicious input could otherwise trigger arbitrarily large allocations. If any
+/// size exceeds the configured limit, the reader returns an error instead of
+/// allocating. By default no limits are enforced.
+///
+/// ```
+/// # use arrow_ipc::reader::{FileReaderBuilder, ReadLimits};
+/// let limits = ReadLimits::new()
+///     .with_max_message_size(1024 * 1024)
+///     .with_max_body_size(1024 * 1024 * 1024)
+///     .with_max_fields(1000);
+/// let builder = FileReaderBuilder::new().with_limits(limits);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadLimits {
+    max_message_size: usize,
+    max_body_size: usize,
+    max_fields: usize,
 }
 
-impl Default for FileReaderBuilder {
+impl Default for ReadLimits {
     fn default() -> Self {
-        let verifier_options = VerifierOptions::default();
         Self {
-            max_footer_fb_tables: verifier_options.max_tables,
-            max_footer_fb_depth: verifier_options.max_depth,
-            projection: None,
+            max_message_size: usize::MAX,
+            max_body_size: usize::MAX,
+            max_fields: usize::MAX,
         }
     }
 }
 
-impl FileReaderBuilder {
-    /// Options for creating a new [`FileReader`].
+impl ReadLimits {
+    /// Create a new [`ReadLimits`] with no limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size in bytes of the metadata of a message, including the
+    /// footer of a file
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Sets the maximum size in bytes of the body of a message, containing the
+    /// buffers of a record batch or dictionary batch
+    pub fn with_max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Sets the maximum number of fields in the schema, including nested fields
+    pub fn with_max_fields(mut self, max_fields: usize) -> Self {
+        self.max_fields = max_fields;
+        self
+    }
+
+    /// Returns the length of message metadata read from an IPC stream, returning an
+    /// error if it is negative or exceeds the limit
+    pub(crate) fn check_message_size(&self, size: i64) -> Result<usize, ArrowError> {
+        match usize::try_from(size) {
+            Ok(size) if size <= self.max_message_size => Ok(size),
+            Ok(_) => Err(ArrowError::IpcError(format!(
+                "IPC message metadata of {size} bytes exceeds the limit of {} bytes",
+                self.max_message_size
+            ))),
+            Err(_) => Err(ArrowError::IpcError(format!(
+                "Invalid IPC message metadata length {size}"
+            ))),
+        }
+    }
+
+    /// Returns the length of a message body, returning an error if it is negative
+    /// or exceeds the limit
+    fn check_body_size(&self, size: i64) -> Result<usize, ArrowError> {
+        match usize::try_from(size) {
+            Ok(size) if size <= self.max_body_size => Ok(size),
+            Ok(_) => Err(ArrowError::IpcError(format!(
+                "IPC message body of {size} bytes exceeds the limit of {} bytes",
+                self.max_body_size
+            ))),
+            Err(_) => Err(ArrowError::IpcError(format!(
+                "Invalid IPC message body length {size}"
+            ))),
+        }
+    }
+
+    /// Returns the lengths of the metadata and body of `block`, see
+    /// [`Self::check_message_size`] and [`Self::check_body_size`]
+    pub(crate) fn check_block(&self, block: &Block) -> Result<(usize, usize), ArrowError> {
+        let metadata_len = self.check_message_size(block.metaDataLength().into())?;
+        let body_len = self.check_body_size(block.bodyLength())?;
+        Ok((metadata_len, body_len))
+    }
+
+    /// Returns an error if `schema` has more fields than the limit
+    fn check_fields(&self, schema: crate::Schema<'_>) -> Result<(), ArrowError> {
+        fn count(fields: Option<Vector<'_, ForwardsUOffset<crate::Field<'_>>>>) -> usize {
+            fields
+                .iter()
+                .flatten()
+                .map(|f| 1 + count(f.children()))
+                .sum()
+        }
+        if self.max_fields == usize::MAX {
+            return Ok(());
+        }
+        match count(schema.fields()) {
+            n if n > self.max_fields => Err(ArrowError::IpcError(format!(
+                "IPC schema with {n} fields exceeds the limit of {} fields",
+                self.max_fields
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Resolves the column `names` against `schema`, returning their indices
+///
+/// Returns an error listing the names not present in `schema`
+///
+/// If `skip_missing` is set, names not present in `schema` are ignored rather than
+/// returning an error
+fn resolve_projection_names(
+    schema: &Schema,
+    names: &[String],
+    skip_missing: bool,
+) -> Result<Vec<usize>, ArrowError> {
+    let mut missing = vec![];
+    let mut indices = Vec::with_capacity(names.len());
+    for name in names {
+        match schema.fields().find(name) {
+            Some((idx, _)) => indices.push(idx),
+            None => missing.push(name.as_str()),
+        }
+    }
+    if !missing.is_empty() && !skip_missing {
+        let available: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        return Err(ArrowError::SchemaError(format!(
+            "Projected columns {missing:?} not found in schema, available columns are {available:?}"
+        )));
+    }
+    Ok(indices)
+}
+
+/// Build an Arrow [`FileReader`] with custom options.
+#[derive(Debug)]
+pub struct FileReaderBuilder {
+    /// Optional projection for which columns to load (zero-based column indices)
+    projection: Option<Vec<usize>>,
+    /// Optional projection for which columns to load, by column name
+    projection_names: Option<Vec<String>>,
+    /// Whether to ignore names in `projection_names` not present in the schema
+    skip_missing_names: bool,
+    /// Optional projection of the nested fields to load
+    projection_mask: Option<ProjectionMask>,
+    /// Passed through to construct [`VerifierOptions`]
+    max_footer_fb_tables: usize,
+    /// Passed through to construct [`VerifierOptions`]
+    max_footer_fb_depth: usize,
+    /// Columns for which to skip validation of the decoded arrays
+    skip_validation: Vec<usize>,
+    /// Already decoded dictionaries, keyed by dictionary id
+    dictionaries: HashMap<i64, ArrayRef>,
+    /// Whether to verify block checksums, if present
+    verify_checksums: bool,
+    /// Limits on the sizes of the data read
+    limits: ReadLimits,
+    /// Whether to recover the complete blocks of files without a valid footer
+    recover_truncated: bool,
+}
+
+impl Default for FileReaderBuilder {
+    fn default() -> Self {
+        let verifier_options = VerifierOptions::default();
+        Self {
+            max_footer_fb_tables: verifier_options.max_tables,
+            max_footer_fb_depth: verifier_options.max_depth,
+            projection: None,
+            projection_names: None,
+            skip_missing_names: false,
+            projection_mask: None,
+            skip_validation: vec![],
+            dictionaries: HashMap::new(),
+            verify_checksums: true,
+            limits: ReadLimits::default(),
+            recover_truncated: false,
+        }
+    }
+}
+
+impl FileReaderBuilder {
+    /// Options for creating a new [`FileReader`].
     ///
     /// To convert a builder into a reader, call [`FileReaderBuilder::build`].
     pub fn new() -> Self {
@@ -721,8 +1235,41 @@ impl FileReaderBuilder {
     }
 
     /// Optional projection for which columns to load (zero-based column indices).
+    ///
+    /// Replaces any projection set by [`Self::with_projection_names`]
     pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
         self.projection = Some(projection);
+        self.projection_names = None;
+        self
+    }
+
+    /// Optional projection for which columns to load, by column name
+    ///
+    /// The names are resolved against the schema read from the input when building
+    /// the reader, returning an error listing any names not present in the schema.
+    /// Replaces any projection set by [`Self::with_projection`]
+    pub fn with_projection_names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.projection_names = Some(names.into_iter().map(Into::into).collect());
+        self.projection = None;
+        self
+    }
+
+    /// Ignore names passed to [`Self::with_projection_names`] that are not present
+    /// in the schema, instead of returning an error
+    #[cfg(feature = "dataset")]
+    pub(crate) fn with_skip_missing_names(mut self, skip_missing_names: bool) -> Self {
+        self.skip_missing_names = skip_missing_names;
+        self
+    }
+
+    /// Optional projection of the nested fields to load, see [`ProjectionMask`]
+    /// and [`FileDecoder::with_projection_mask`]
+    pub fn with_projection_mask(mut self, mask: ProjectionMask) -> Self {
+        self.projection_mask = Some(mask);
         self
     }
 
@@ -760,28 +1307,163 @@ impl FileReaderBuilder {
         self
     }
 
+    /// Skip validation of the decoded arrays for the columns with the given
+    /// (zero-based) indices in the file's schema.
+    ///
+    /// See [`FileDecoder::with_skip_validation`] for details.
+    ///
+    /// # Safety
+    ///
+    /// The IPC data for the listed columns must be valid, as described in
+    /// [`ArrayData::validate_full`], otherwise undefined behaviour may result
+    pub unsafe fn with_skip_validation(mut self, columns: Vec<usize>) -> Self {
+        self.skip_validation = columns;
+        self
+    }
+
+    /// Pre-seed the dictionary memo with already decoded dictionary values, keyed
+    /// by dictionary id.
+    ///
+    /// Dictionary batches in the file with an id present in `dictionaries` are
+    /// not read, allowing dictionaries to be shared across files written with
+    /// the same dictionaries.
+    pub fn with_dictionaries(mut self, dictionaries: HashMap<i64, ArrayRef>) -> Self {
+        self.dictionaries = dictionaries;
+        self
+    }
+
+    /// Configures whether to verify the checksums of the blocks and messages read,
+    /// if the file contains checksums, defaults to `true`
+    ///
+    /// Verifying checksums requires the `checksum` feature, without which reading a
+    /// file containing checksums returns an error unless this is `false`
+    ///
+    /// See [`FileWriter::try_with_block_checksums`](crate::writer::FileWriter::try_with_block_checksums)
+    pub fn with_verify_checksums(mut self, verify_checksums: bool) -> Self {
+        self.verify_checksums = verify_checksums;
+        self
+    }
+
+    /// Sets limits on the sizes of the data read, see [`ReadLimits`]
+    pub fn with_limits(mut self, limits: ReadLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the limits on the sizes of the data read
+    #[cfg(feature = "async")]
+    pub(crate) fn limits(&self) -> &ReadLimits {
+        &self.limits
+    }
+
+    /// Recover the complete blocks of files that do not end with a valid footer, such
+    /// as those being written when their writer crashed, defaults to `false`
+    ///
+    /// The blocks are found with [`recover`], and the reader returns the record
+    /// batches they contain. If the file ends part way through a block, the reader
+    /// then returns a single [`ArrowError::Truncated`] error
+    pub fn with_recover_truncated(mut self, recover_truncated: bool) -> Self {
+        self.recover_truncated = recover_truncated;
+        self
+    }
+
     /// Build [`FileReader`] with given reader.
     pub fn build<R: Read + Seek>(self, mut reader: R) -> Result<FileReader<R>, ArrowError> {
+        if self.recover_truncated && read_footer_trailer(&mut reader)?.is_none() {
+            let recovered = recover(&mut reader)?;
+            let truncated = recovered.is_truncated().then(|| recovered.end());
+            return self.build_with_footer(reader, &recovered.footer(), truncated);
+        }
+
         // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
         let mut buffer = [0; 10];
         reader.seek(SeekFrom::End(-10))?;
         reader.read_exact(&mut buffer)?;
 
         let footer_len = read_footer_length(buffer)?;
+        self.limits.check_message_size(footer_len as i64)?;
 
         // read footer
-        let mut footer_data = vec![0; footer_len];
         reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
-        reader.read_exact(&mut footer_data)?;
+        let footer_data = read_exact_buffer(&mut reader, footer_len)?;
+        self.build_with_footer(reader, &footer_data, None)
+    }
+
+    /// Build [`FileReader`] with given reader and the contents of its footer,
+    /// `truncated` is the offset of the incomplete block of a recovered file, if any
+    fn build_with_footer<R: Read + Seek>(
+        self,
+        mut reader: R,
+        footer_data: &[u8],
+        truncated: Option<u64>,
+    ) -> Result<FileReader<R>, ArrowError> {
+        let mut footer = self.read_footer(footer_data)?;
+
+        // Read the dictionaries used by the projected fields that have not been provided
+        for idx in 0..footer.dictionary_blocks.len() {
+            let block = footer.dictionary_blocks[idx];
+            let buf = read_dictionary_block(&mut reader, &block, &footer.limits, |id| {
+                footer.requires_dictionary(id)
+            })?;
+            if let Some(buf) = buf {
+                footer.read_dictionary(idx, &buf)?;
+            }
+        }
+
+        Ok(FileReader {
+            reader,
+            total_blocks: footer.blocks.len(),
+            footer,
+            current_block: 0,
+            truncated,
+        })
+    }
+
+    /// Build [`BufferFileReader`] with the contents of an entire file, such as a
+    /// memory-mapped file
+    pub fn build_from_buffer(self, buffer: Buffer) -> Result<BufferFileReader, ArrowError> {
+        let trailer_start = buffer.len().checked_sub(10).ok_or_else(|| {
+            ArrowError::IpcError("File is too small to be an Arrow IPC file".to_string())
+        })?;
+        let footer_len = read_footer_length(buffer[trailer_start..].try_into().unwrap())?;
+        let footer_start = trailer_start.checked_sub(footer_len).ok_or_else(|| {
+            ArrowError::IpcError(format!(
+                "Footer length {footer_len} exceeds the size of the file"
+            ))
+        })?;
+
+        let mut footer = self.read_footer(&buffer[footer_start..trailer_start])?;
 
+        // Decode the dictionaries used by the projected fields that have not been provided
+        for idx in 0..footer.dictionary_blocks.len() {
+            let block = footer.dictionary_blocks[idx];
+            let buf = slice_block(&buffer, &block)?;
+            let metadata = &buf[..(block.metaDataLength() as usize)];
+            if !matches!(dictionary_block_id(metadata), Some(id) if !footer.requires_dictionary(id))
+            {
+                footer.read_dictionary(idx, &buf)?;
+            }
+        }
+
+        Ok(BufferFileReader {
+            buffer,
+            footer,
+            current_block: 0,
+        })
+    }
+
+    /// Parses the footer of a file, returning a [`FileFooter`] with a [`FileDecoder`]
+    /// configured with the options of this builder
+    pub(crate) fn read_footer(self, footer_data: &[u8]) -> Result<FileFooter, ArrowError> {
         let verifier_options = VerifierOptions {
             max_tables: self.max_footer_fb_tables,
             max_depth: self.max_footer_fb_depth,
             ..Default::default()
         };
-        let footer = crate::root_as_footer_with_opts(&verifier_options, &footer_data[..]).map_err(
-            |err| ArrowError::ParseError(format!("Unable to get root as footer: {err:?}")),
-        )?;
+        let footer =
+            crate::root_as_footer_with_opts(&verifier_options, footer_data).map_err(|err| {
+                ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+            })?;
 
         let blocks = footer.recordBatches().ok_or_else(|| {
             ArrowError::ParseError("Unable to get record batches from IPC Footer".to_string())
@@ -790,366 +1472,735 @@ impl FileReaderBuilder {
         let total_blocks = blocks.len();
 
         let ipc_schema = footer.schema().unwrap();
-        if !ipc_schema.endianness().equals_to_target_endianness() {
-            return Err(ArrowError::IpcError(
-                "the endianness of the source system does not match the endianness of the target system.".to_owned()
-            ));
-        }
+        self.limits.check_fields(ipc_schema)?;
+        let endianness = check_endianness(ipc_schema.endianness())?;
+
+        let schema = crate::convert::try_fb_to_schema(ipc_schema)?;
+        let projection = match &self.projection_names {
+            Some(names) => {
+                let skip_missing = self.skip_missing_names;
+                Some(resolve_projection_names(&schema, names, skip_missing)?)
+            }
+            None => self.projection,
+        };
 
-        let schema = crate::convert::fb_to_schema(ipc_schema);
+        let custom_metadata = custom_metadata_from_fb(footer.custom_metadata());
 
-        let mut custom_metadata = HashMap::new();
-        if let Some(fb_custom_metadata) = footer.custom_metadata() {
-            for kv in fb_custom_metadata.into_iter() {
-                custom_metadata.insert(
-                    kv.key().unwrap().to_string(),
-                    kv.value().unwrap().to_string(),
-                );
-            }
+        let dictionary_blocks: Vec<Block> = footer
+            .dictionaries()
+            .map(|d| d.iter().copied().collect())
+            .unwrap_or_default();
+        let dictionary_checksums = parse_checksums(
+            &custom_metadata,
+            DICTIONARY_CHECKSUMS_KEY,
+            dictionary_blocks.len(),
+        )?;
+        let record_batch_checksums =
+            parse_checksums(&custom_metadata, RECORD_BATCH_CHECKSUMS_KEY, total_blocks)?;
+        if self.verify_checksums
+            && (dictionary_checksums.is_some() || record_batch_checksums.is_some())
+        {
+            check_verifiable()?;
         }
 
-        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version());
-        if let Some(projection) = self.projection {
+        let provided: HashSet<i64> = self.dictionaries.keys().copied().collect();
+        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version())
+            .with_dictionaries(self.dictionaries)
+            .with_endianness(endianness)
+            .with_verify_checksums(self.verify_checksums);
+        if let Some(projection) = projection {
             decoder = decoder.with_projection(projection)
         }
-
-        // Create an array of optional dictionary value arrays, one per field.
-        if let Some(dictionaries) = footer.dictionaries() {
-            for block in dictionaries {
-                let buf = read_block(&mut reader, block)?;
-                decoder.read_dictionary(block, &buf)?;
-            }
+        if let Some(mask) = self.projection_mask {
+            decoder = decoder.with_projection_mask(mask)
         }
+        // SAFETY: the caller of FileReaderBuilder::with_skip_validation upholds the contract
+        decoder = unsafe { decoder.with_skip_validation(self.skip_validation) };
 
-        Ok(FileReader {
-            reader,
-            blocks: blocks.iter().copied().collect(),
-            current_block: 0,
-            total_blocks,
+        Ok(FileFooter {
             decoder,
+            blocks: blocks.iter().copied().collect(),
+            dictionary_blocks,
             custom_metadata,
+            dictionary_checksums,
+            record_batch_checksums,
+            provided,
+            verify_checksums: self.verify_checksums,
+            limits: self.limits,
         })
     }
 }
 
-/// Arrow File reader
-pub struct FileReader<R: Read + Seek> {
-    /// Buffered file reader that supports reading and seeking
-    reader: R,
+/// The decoded footer of an Arrow IPC file, see [`FileReaderBuilder::read_footer`]
+#[derive(Clone)]
+pub(crate) struct FileFooter {
+    /// The decoder for the blocks of the file
+    pub(crate) decoder: FileDecoder,
+    /// The record batch blocks in the file
+    pub(crate) blocks: Vec<Block>,
+    /// The dictionary blocks in the file
+    pub(crate) dictionary_blocks: Vec<Block>,
+    /// User defined metadata
+    pub(crate) custom_metadata: HashMap<String, String>,
+    /// Checksums of the dictionary blocks, if present
+    pub(crate) dictionary_checksums: Option<Vec<u32>>,
+    /// Checksums of the record batch blocks, if present
+    pub(crate) record_batch_checksums: Option<Vec<u32>>,
+    /// The ids of the dictionaries provided to the [`FileReaderBuilder`]
+    provided: HashSet<i64>,
+    /// Whether to verify block checksums, if present
+    pub(crate) verify_checksums: bool,
+    /// Limits on the sizes of the blocks read
+    pub(crate) limits: ReadLimits,
+}
 
-    /// The decoder
-    decoder: FileDecoder,
+impl FileFooter {
+    /// Returns `true` if the dictionary with the given id must be read from the file
+    pub(crate) fn requires_dictionary(&self, id: i64) -> bool {
+        self.decoder.requires_dictionary(id) && !self.provided.contains(&id)
+    }
 
-    /// The blocks in the file
-    ///
-    /// A block indicates the regions in the file to read to get data
-    blocks: Vec<Block>,
+    /// Decodes the dictionary block with index `idx` from `buf`, verifying its checksum
+    pub(crate) fn read_dictionary(&mut self, idx: usize, buf: &Buffer) -> Result<(), ArrowError> {
+        if let (true, Some(checksums)) = (self.verify_checksums, &self.dictionary_checksums) {
+            verify_checksum(buf, checksums[idx], "dictionary", idx)?;
+        }
+        self.decoder
+            .read_dictionary(&self.dictionary_blocks[idx], buf)
+    }
 
-    /// A counter to keep track of the current block that should be read
-    current_block: usize,
+    /// Decodes the record batch block with index `idx` from `buf`, verifying its checksum
+    pub(crate) fn read_record_batch(
+        &self,
+        idx: usize,
+        buf: &Buffer,
+    ) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
+        if let (true, Some(checksums)) = (self.verify_checksums, &self.record_batch_checksums) {
+            verify_checksum(buf, checksums[idx], "record batch", idx)?;
+        }
+        self.decoder
+            .read_record_batch_with_metadata(&self.blocks[idx], buf)
+    }
 
-    /// The total number of blocks, which may contain record batches and other types
-    total_blocks: usize,
+    /// Returns an error if `range` is not a valid range of record batch blocks
+    #[cfg(feature = "parallel")]
+    fn check_range(&self, range: &std::ops::Range<usize>) -> Result<(), ArrowError> {
+        if range.start > range.end || range.end > self.blocks.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot read batches {range:?} from {} total batches",
+                self.blocks.len()
+            )));
+        }
+        Ok(())
+    }
 
-    /// User defined metadata
-    custom_metadata: HashMap<String, String>,
+    /// Decodes the record batch blocks starting at index `start` from `buffers` on
+    /// a rayon thread pool of `pool_size` threads, returning the batches in order
+    #[cfg(feature = "parallel")]
+    fn read_record_batches_parallel(
+        &self,
+        start: usize,
+        buffers: &[Buffer],
+        pool_size: usize,
+    ) -> Result<Vec<RecordBatch>, ArrowError> {
+        use rayon::prelude::*;
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(pool_size)
+            .build()
+            .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+        let decoded = pool.install(|| {
+            buffers
+                .par_iter()
+                .enumerate()
+                .map(|(idx, buf)| self.read_record_batch(start + idx, buf))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        Ok(decoded
+            .into_iter()
+            .flatten()
+            .map(|(batch, _)| batch)
+            .collect())
+    }
 }
 
-impl<R: Read + Seek> fmt::Debug for FileReader<R> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        f.debug_struct("FileReader<R>")
-            .field("decoder", &self.decoder)
-            .field("blocks", &self.blocks)
-            .field("current_block", &self.current_block)
-            .field("total_blocks", &self.total_blocks)
-            .finish_non_exhaustive()
+/// Returns the footer length from the trailer of an Arrow IPC file, or `None` if
+/// the file is too small, or does not end with `b"ARROW1"`
+fn read_footer_trailer<R: Read + Seek>(reader: &mut R) -> Result<Option<usize>, ArrowError> {
+    // Space for the leading ARROW_MAGIC and padding, and for the trailing length and ARROW_MAGIC
+    if reader.seek(SeekFrom::End(0))? < 18 {
+        return Ok(None);
     }
+    let mut buffer = [0; 10];
+    reader.seek(SeekFrom::End(-10))?;
+    reader.read_exact(&mut buffer)?;
+    Ok(read_footer_length(buffer).ok())
 }
 
-impl<R: Read + Seek> FileReader<R> {
-    /// Try to create a new file reader
-    ///
-    /// Returns errors if the file does not meet the Arrow Format footer requirements
-    pub fn try_new(reader: R, projection: Option<Vec<usize>>) -> Result<Self, ArrowError> {
-        let builder = FileReaderBuilder {
-            projection,
-            ..Default::default()
-        };
-        builder.build(reader)
-    }
+/// The complete blocks of a possibly truncated Arrow IPC file, see [`recover`]
+#[derive(Debug, Clone)]
+pub struct RecoveredFile {
+    schema: Schema,
+    endianness: crate::Endianness,
+    version: MetadataVersion,
+    legacy: bool,
+    dictionary_blocks: Vec<Block>,
+    record_blocks: Vec<Block>,
+    end: u64,
+    truncated: bool,
+}
 
-    /// Return user defined customized metadata
-    pub fn custom_metadata(&self) -> &HashMap<String, String> {
-        &self.custom_metadata
+impl RecoveredFile {
+    /// Returns the schema of the file
+    pub fn schema(&self) -> &Schema {
+        &self.schema
     }
 
-    /// Return the number of batches in the file
-    pub fn num_batches(&self) -> usize {
-        self.total_blocks
+    /// Returns the complete dictionary blocks of the file
+    pub fn dictionary_blocks(&self) -> &[Block] {
+        &self.dictionary_blocks
     }
 
-    /// Return the schema of the file
-    pub fn schema(&self) -> SchemaRef {
-        self.decoder.schema.clone()
+    /// Returns the complete record batch blocks of the file
+    pub fn record_blocks(&self) -> &[Block] {
+        &self.record_blocks
     }
 
-    /// Read a specific record batch
-    ///
-    /// Sets the current block to the index, allowing random reads
-    pub fn set_index(&mut self, index: usize) -> Result<(), ArrowError> {
-        if index >= self.total_blocks {
-            Err(ArrowError::InvalidArgumentError(format!(
-                "Cannot set batch to index {} from {} total batches",
-                index, self.total_blocks
-            )))
-        } else {
-            self.current_block = index;
-            Ok(())
-        }
+    /// Returns the offset of the end of the last complete message of the file
+    pub fn end(&self) -> u64 {
+        self.end
     }
 
-    fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
-        let block = &self.blocks[self.current_block];
-        self.current_block += 1;
-
-        // read length
-        let buffer = read_block(&mut self.reader, block)?;
-        self.decoder.read_record_batch(block, &buffer)
+    /// Returns `true` if the file contains an incomplete message after [`Self::end`]
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
     }
 
-    /// Gets a reference to the underlying reader.
+    /// Writes the end-of-stream marker and footer of the recovered blocks to `writer`
     ///
-    /// It is inadvisable to directly read from the underlying reader.
-    pub fn get_ref(&self) -> &R {
-        &self.reader
+    /// Truncating the file at [`Self::end`], and then appending the output, produces
+    /// a valid Arrow IPC file containing the recovered record batches
+    pub fn write_footer<W: Write>(&self, mut writer: W) -> Result<(), ArrowError> {
+        if !self.legacy {
+            writer.write_all(&CONTINUATION_MARKER)?;
+        }
+        writer.write_all(&0_i32.to_le_bytes())?;
+        let footer = self.footer();
+        writer.write_all(&footer)?;
+        writer.write_all(&(footer.len() as i32).to_le_bytes())?;
+        writer.write_all(&super::ARROW_MAGIC)?;
+        writer.flush()?;
+        Ok(())
     }
 
-    /// Gets a mutable reference to the underlying reader.
-    ///
-    /// It is inadvisable to directly read from the underlying reader.
-    pub fn get_mut(&mut self) -> &mut R {
-        &mut self.reader
+    /// Encodes the footer of the recovered blocks
+    fn footer(&self) -> Vec<u8> {
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let dictionaries = fbb.create_vector(&self.dictionary_blocks);
+        let record_batches = fbb.create_vector(&self.record_blocks);
+        let schema = crate::convert::schema_to_fb_offset_with_endianness(
+            &mut fbb,
+            &self.schema,
+            self.endianness,
+        );
+        let mut footer = crate::FooterBuilder::new(&mut fbb);
+        footer.add_version(self.version);
+        footer.add_schema(schema);
+        footer.add_dictionaries(dictionaries);
+        footer.add_recordBatches(record_batches);
+        let root = footer.finish();
+        fbb.finish(root, None);
+        fbb.finished_data().to_vec()
     }
 }
 
-impl<R: Read + Seek> Iterator for FileReader<R> {
-    type Item = Result<RecordBatch, ArrowError>;
+/// Scans the messages of a possibly truncated Arrow IPC file, such as one being
+/// written when its writer crashed, returning its complete blocks
+///
+/// This does not read the footer of the file, and stops at the end-of-stream
+/// marker, or at the first incomplete message. The recovered blocks can be read
+/// with [`FileReaderBuilder::with_recover_truncated`], or the file repaired with
+/// [`RecoveredFile::write_footer`]
+///
+/// ```
+/// # use std::io::Cursor;
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use arrow_ipc::reader::{recover, FileReader};
+/// # use arrow_ipc::writer::FileWriter;
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as _),
+/// ]).unwrap();
+/// let mut writer = FileWriter::try_new(vec![], &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.finish().unwrap();
+///
+/// // Lose the footer and part of the second batch
+/// let mut file = writer.into_inner().unwrap();
+/// file.truncate(file.len() / 2);
+///
+/// let recovered = recover(Cursor::new(&file)).unwrap();
+/// assert_eq!(recovered.record_blocks().len(), 1);
+/// assert!(recovered.is_truncated());
+///
+/// file.truncate(recovered.end() as usize);
+/// recovered.write_footer(&mut file).unwrap();
+/// let reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+/// assert_eq!(reader.collect::<Result<Vec<_>, _>>().unwrap(), vec![batch]);
+/// ```
+pub fn recover<R: Read + Seek>(mut reader: R) -> Result<RecoveredFile, ArrowError> {
+    let limits = ReadLimits::default();
+    let file_len = reader.seek(SeekFrom::End(0))?;
+    let truncated = |offset: u64| {
+        ArrowError::Truncated(format!(
+            "Arrow file ends part way through its schema at offset {offset}"
+        ))
+    };
 
-    fn next(&mut self) -> Option<Self::Item> {
-        // get current block
-        if self.current_block < self.total_blocks {
-            self.maybe_next().transpose()
-        } else {
-            None
-        }
+    // ARROW_MAGIC (6 bytes) followed by 2 bytes of padding
+    let mut magic = [0; 8];
+    reader.seek(SeekFrom::Start(0))?;
+    if file_len >= 8 {
+        reader.read_exact(&mut magic)?;
+    }
+    if magic[..6] != super::ARROW_MAGIC {
+        return Err(ArrowError::ParseError(
+            "Arrow file does not contain correct header".to_string(),
+        ));
     }
-}
 
-impl<R: Read + Seek> RecordBatchReader for FileReader<R> {
-    fn schema(&self) -> SchemaRef {
-        self.schema()
+    let mut recovered: Option<RecoveredFile> = None;
+    let mut offset = 8;
+    loop {
+        let mut prefix = [0; 8];
+        let available = file_len - offset;
+        if available < 4 {
+            break;
+        }
+        reader.read_exact(&mut prefix[..4])?;
+        let (prefix_len, meta_len) = match prefix[..4] == CONTINUATION_MARKER {
+            true if available < 8 => break,
+            true => {
+                reader.read_exact(&mut prefix[4..])?;
+                (8, i32::from_le_bytes(prefix[4..].try_into().unwrap()))
+            }
+            false => (4, i32::from_le_bytes(prefix[..4].try_into().unwrap())),
+        };
+        if meta_len == 0 {
+            // End-of-stream marker, followed by the footer, if any
+            return recovered.ok_or_else(|| truncated(offset));
+        }
+        let meta_len = limits.check_message_size(meta_len as i64)?;
+        if available < (prefix_len + meta_len) as u64 {
+            break;
+        }
+        let meta = read_exact_buffer(&mut reader, meta_len)?;
+        let message = crate::root_as_message(&meta).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
+        })?;
+        let body_len = limits.check_body_size(message.bodyLength())?;
+        let block_len = (prefix_len + meta_len + body_len) as u64;
+        if available < block_len {
+            break;
+        }
+        let block = Block::new(
+            offset as i64,
+            (prefix_len + meta_len) as i32,
+            body_len as i64,
+        );
+
+        match (&mut recovered, message.header_type()) {
+            (None, crate::MessageHeader::Schema) => {
+                let ipc_schema = message.header_as_schema().unwrap();
+                limits.check_fields(ipc_schema)?;
+                recovered = Some(RecoveredFile {
+                    schema: crate::convert::try_fb_to_schema(ipc_schema)?,
+                    endianness: check_endianness(ipc_schema.endianness())?,
+                    version: message.version(),
+                    legacy: prefix_len == 4,
+                    dictionary_blocks: vec![],
+                    record_blocks: vec![],
+                    end: offset + block_len,
+                    truncated: false,
+                });
+            }
+            (None, t) => {
+                return Err(ArrowError::IpcError(format!(
+                    "Expected schema message at offset {offset}, got {t:?}"
+                )))
+            }
+            (Some(recovered), crate::MessageHeader::DictionaryBatch) => {
+                recovered.dictionary_blocks.push(block);
+                recovered.end = offset + block_len;
+            }
+            (Some(recovered), crate::MessageHeader::RecordBatch) => {
+                recovered.record_blocks.push(block);
+                recovered.end = offset + block_len;
+            }
+            (Some(_), t) => {
+                return Err(ArrowError::IpcError(format!(
+                    "Unexpected message {t:?} at offset {offset}"
+                )))
+            }
+        }
+        offset += block_len;
+        reader.seek(SeekFrom::Start(offset))?;
     }
-}
 
-/// Arrow Stream reader
-pub struct StreamReader<R: Read> {
-    /// Stream reader
-    reader: R,
+    let mut recovered = recovered.ok_or_else(|| truncated(offset))?;
+    recovered.truncated = offset != file_len;
+    Ok(recovered)
+}
 
-    /// The schema that is read from the stream's first message
-    schema: SchemaRef,
+/// A [`Read`] + [`Seek`] restricted to the window of `len` bytes starting at `offset`
+/// of an underlying reader, such as an Arrow file embedded within a larger container
+///
+/// Positions are relative to the start of the window, and reads stop at its end, so
+/// the window can be read by [`FileReader`] as if it were an entire file, see
+/// [`FileReader::try_new_with_range`]
+#[derive(Debug)]
+pub struct RangeReader<R> {
+    inner: R,
+    offset: u64,
+    len: u64,
+    pos: u64,
+}
 
-    /// Optional dictionaries for each schema field.
+impl<R: Read + Seek> RangeReader<R> {
+    /// Create a new reader of the `len` bytes starting at `offset` of `inner`
     ///
-    /// Dictionaries may be appended to in the streaming format.
-    dictionaries_by_id: HashMap<i64, ArrayRef>,
+    /// Returns an error if the window extends beyond the end of `inner`
+    pub fn try_new(mut inner: R, offset: u64, len: u64) -> Result<Self, ArrowError> {
+        let inner_len = inner.seek(SeekFrom::End(0))?;
+        if offset.checked_add(len).map_or(true, |end| end > inner_len) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Range of {len} bytes at offset {offset} exceeds input of {inner_len} bytes"
+            )));
+        }
+        inner.seek(SeekFrom::Start(offset))?;
+        Ok(Self {
+            inner,
+            offset,
+            len,
+            pos: 0,
+        })
+    }
 
-    /// An indicator of whether the stream is complete.
-    ///
-    /// This value is set to `true` the first time the reader's `next()` returns `None`.
-    finished: bool,
+    /// Returns a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
 
-    /// Optional projection
-    projection: Option<(Vec<usize>, Schema)>,
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
 }
 
-impl<R: Read> fmt::Debug for StreamReader<R> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
-        f.debug_struct("StreamReader<R>")
-            .field("reader", &"BufReader<..>")
-            .field("schema", &self.schema)
-            .field("dictionaries_by_id", &self.dictionaries_by_id)
-            .field("finished", &self.finished)
-            .field("projection", &self.projection)
-            .finish()
+impl<R: Read> Read for RangeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let remaining = self.len.saturating_sub(self.pos);
+        let len = (buf.len() as u64).min(remaining) as usize;
+        let read = self.inner.read(&mut buf[..len])?;
+        self.pos += read as u64;
+        Ok(read)
     }
 }
 
-impl<R: Read> StreamReader<BufReader<R>> {
-    /// Try to create a new stream reader with the reader wrapped in a BufReader
-    ///
-    /// The first message in the stream is the schema, the reader will fail if it does not
-    /// encounter a schema.
-    /// To check if the reader is done, use `is_finished(self)`
-    pub fn try_new(reader: R, projection: Option<Vec<usize>>) -> Result<Self, ArrowError> {
-        Self::try_new_unbuffered(BufReader::new(reader), projection)
+impl<R: Seek> Seek for RangeReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => Some(pos),
+            SeekFrom::End(delta) => offset_position(self.len, delta),
+            SeekFrom::Current(delta) => offset_position(self.pos, delta),
+        };
+        let pos = pos.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        self.inner.seek(SeekFrom::Start(self.offset + pos))?;
+        self.pos = pos;
+        Ok(pos)
     }
 }
 
-impl<R: Read> StreamReader<R> {
-    /// Try to create a new stream reader but do not wrap the reader in a BufReader.
+/// Returns `base` offset by `delta`, or `None` if negative or overflowing
+fn offset_position(base: u64, delta: i64) -> Option<u64> {
+    match delta < 0 {
+        true => base.checked_sub(delta.unsigned_abs()),
+        false => base.checked_add(delta as u64),
+    }
+}
+
+/// Arrow File reader
+pub struct FileReader<R: Read + Seek> {
+    /// Buffered file reader that supports reading and seeking
+    reader: R,
+
+    /// The decoded footer, containing the decoder and the blocks in the file
     ///
-    /// Unless you need the StreamReader to be unbuffered you likely want to use `StreamReader::try_new` instead.
-    pub fn try_new_unbuffered(
-        mut reader: R,
-        projection: Option<Vec<usize>>,
-    ) -> Result<StreamReader<R>, ArrowError> {
-        // determine metadata length
-        let mut meta_size: [u8; 4] = [0; 4];
-        reader.read_exact(&mut meta_size)?;
-        let meta_len = {
-            // If a continuation marker is encountered, skip over it and read
-            // the size from the next four bytes.
-            if meta_size == CONTINUATION_MARKER {
-                reader.read_exact(&mut meta_size)?;
-            }
-            i32::from_le_bytes(meta_size)
-        };
+    /// A block indicates the regions in the file to read to get data
+    footer: FileFooter,
 
-        let mut meta_buffer = vec![0; meta_len as usize];
-        reader.read_exact(&mut meta_buffer)?;
+    /// A counter to keep track of the current block that should be read
+    current_block: usize,
 
-        let message = crate::root_as_message(meta_buffer.as_slice()).map_err(|err| {
-            ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
-        })?;
-        // message header is a Schema, so read it
-        let ipc_schema: crate::Schema = message.header_as_schema().ok_or_else(|| {
-            ArrowError::ParseError("Unable to read IPC message as schema".to_string())
-        })?;
-        let schema = crate::convert::fb_to_schema(ipc_schema);
+    /// The total number of blocks, which may contain record batches and other types
+    total_blocks: usize,
 
-        // Create an array of optional dictionary value arrays, one per field.
-        let dictionaries_by_id = HashMap::new();
+    /// The offset of the incomplete block of a recovered file, returned as an error
+    /// after the last complete block, see [`FileReaderBuilder::with_recover_truncated`]
+    truncated: Option<u64>,
+}
 
-        let projection = match projection {
-            Some(projection_indices) => {
-                let schema = schema.project(&projection_indices)?;
-                Some((projection_indices, schema))
-            }
-            _ => None,
-        };
-        Ok(Self {
-            reader,
-            schema: Arc::new(schema),
-            finished: false,
-            dictionaries_by_id,
+impl<R: Read + Seek> FileReader<RangeReader<R>> {
+    /// Try to create a new file reader of an Arrow file embedded in `reader`, occupying
+    /// the `len` bytes starting at `offset`
+    ///
+    /// The magic and footer are validated at the boundaries of this window, rather than
+    /// those of `reader`, allowing files appended to or embedded within a custom
+    /// container to be read without copying. Use [`FileReaderBuilder::build`] with a
+    /// [`RangeReader`] to configure other options
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use arrow_ipc::reader::FileReader;
+    /// # use arrow_ipc::writer::FileWriter;
+    /// let batch = RecordBatch::try_from_iter([
+    ///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+    /// ]).unwrap();
+    ///
+    /// let mut container = b"HEADER".to_vec();
+    /// let mut writer = FileWriter::try_new(&mut container, &batch.schema()).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.finish().unwrap();
+    /// drop(writer);
+    /// let len = container.len() as u64 - 6;
+    /// container.extend_from_slice(b"TRAILER");
+    ///
+    /// let mut reader = FileReader::try_new_with_range(Cursor::new(container), 6, len).unwrap();
+    /// assert_eq!(reader.next().unwrap().unwrap(), batch);
+    /// ```
+    pub fn try_new_with_range(reader: R, offset: u64, len: u64) -> Result<Self, ArrowError> {
+        FileReaderBuilder::new().build(RangeReader::try_new(reader, offset, len)?)
+    }
+}
+
+impl<R: Read + Seek> fmt::Debug for FileReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("FileReader<R>")
+            .field("decoder", &self.footer.decoder)
+            .field("blocks", &self.footer.blocks)
+            .field("current_block", &self.current_block)
+            .field("total_blocks", &self.total_blocks)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Read + Seek> FileReader<R> {
+    /// Try to create a new file reader
+    ///
+    /// Returns errors if the file does not meet the Arrow Format footer requirements
+    pub fn try_new(reader: R, projection: Option<Vec<usize>>) -> Result<Self, ArrowError> {
+        let builder = FileReaderBuilder {
             projection,
-        })
+            ..Default::default()
+        };
+        builder.build(reader)
     }
 
-    /// Return the schema of the stream
+    /// Return user defined customized metadata
+    ///
+    /// This is the custom metadata of the file footer, as written by
+    /// [`FileWriter::write_metadata`](crate::writer::FileWriter::write_metadata)
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.footer.custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.total_blocks
+    }
+
+    /// Return the schema of the file
     pub fn schema(&self) -> SchemaRef {
-        self.schema.clone()
+        self.footer.decoder.schema.clone()
     }
 
-    /// Check if the stream is finished
-    pub fn is_finished(&self) -> bool {
-        self.finished
+    /// Return the dictionary memo, containing the decoded dictionary values keyed by
+    /// dictionary id
+    ///
+    /// Only the dictionaries used by the projected fields are decoded
+    pub fn dictionaries(&self) -> &HashMap<i64, ArrayRef> {
+        self.footer.decoder.dictionaries()
     }
 
-    fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
-        if self.finished {
-            return Ok(None);
+    /// Read a specific record batch
+    ///
+    /// Sets the current block to the index, allowing random reads
+    pub fn set_index(&mut self, index: usize) -> Result<(), ArrowError> {
+        if index >= self.total_blocks {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot set batch to index {} from {} total batches",
+                index, self.total_blocks
+            )))
+        } else {
+            self.current_block = index;
+            Ok(())
         }
-        // determine metadata length
-        let mut meta_size: [u8; 4] = [0; 4];
+    }
 
-        match self.reader.read_exact(&mut meta_size) {
-            Ok(()) => (),
-            Err(e) => {
-                return if e.kind() == std::io::ErrorKind::UnexpectedEof {
-                    // Handle EOF without the "0xFFFFFFFF 0x00000000"
-                    // valid according to:
-                    // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
-                    self.finished = true;
-                    Ok(None)
-                } else {
-                    Err(ArrowError::from(e))
-                };
-            }
+    /// Read the next record batch, along with its custom metadata
+    ///
+    /// The metadata is empty if none was written for the batch, see
+    /// [`FileWriter::write_with_metadata`](crate::writer::FileWriter::write_with_metadata)
+    pub fn next_with_metadata(&mut self) -> Option<Result<RecordBatchWithMetadata, ArrowError>> {
+        if self.current_block < self.total_blocks {
+            self.maybe_next().transpose()
+        } else {
+            let offset = self.truncated.take()?;
+            Some(Err(ArrowError::Truncated(format!(
+                "Arrow file ends part way through the block at offset {offset}"
+            ))))
         }
+    }
 
-        let meta_len = {
-            // If a continuation marker is encountered, skip over it and read
-            // the size from the next four bytes.
-            if meta_size == CONTINUATION_MARKER {
-                self.reader.read_exact(&mut meta_size)?;
-            }
-            i32::from_le_bytes(meta_size)
-        };
+    /// Returns an iterator over the remaining record batches, along with their
+    /// custom metadata, see [`Self::next_with_metadata`]
+    pub fn batches_with_metadata(
+        &mut self,
+    ) -> impl Iterator<Item = Result<RecordBatchWithMetadata, ArrowError>> + '_ {
+        std::iter::from_fn(move || self.next_with_metadata())
+    }
 
-        if meta_len == 0 {
-            // the stream has ended, mark the reader as finished
-            self.finished = true;
-            return Ok(None);
-        }
+    /// Reads the record batches with indices in `range`, decoding them concurrently
+    /// on a rayon thread pool of `pool_size` threads, and returns them in order
+    ///
+    /// This bounds the number of threads used, independently of the rayon global
+    /// thread pool. If `pool_size` is `0`, the number of threads is chosen by rayon,
+    /// defaulting to the number of CPUs
+    ///
+    /// The blocks are first read sequentially from the underlying reader, and so the
+    /// contents of all blocks in `range` are held in memory at once. This does not
+    /// change the current index of the reader, see [`Self::set_index`]
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use arrow_ipc::reader::FileReader;
+    /// # use arrow_ipc::writer::FileWriter;
+    /// let batch = RecordBatch::try_from_iter([
+    ///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+    /// ]).unwrap();
+    /// let mut writer = FileWriter::try_new(vec![], &batch.schema()).unwrap();
+    /// for _ in 0..4 {
+    ///     writer.write(&batch).unwrap();
+    /// }
+    /// let file = writer.into_inner().unwrap();
+    ///
+    /// let mut reader = FileReader::try_new(std::io::Cursor::new(file), None).unwrap();
+    /// let batches = reader.read_batches_parallel(1..4, 2).unwrap();
+    /// assert_eq!(batches, vec![batch.clone(), batch.clone(), batch]);
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn read_batches_parallel(
+        &mut self,
+        range: std::ops::Range<usize>,
+        pool_size: usize,
+    ) -> Result<Vec<RecordBatch>, ArrowError> {
+        self.footer.check_range(&range)?;
+        let buffers = self.footer.blocks[range.clone()]
+            .iter()
+            .map(|block| read_block(&mut self.reader, block, &self.footer.limits))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.footer
+            .read_record_batches_parallel(range.start, &buffers, pool_size)
+    }
 
-        let mut meta_buffer = vec![0; meta_len as usize];
-        self.reader.read_exact(&mut meta_buffer)?;
+    /// Returns the blocks of the record batches in the file, in the order written
+    ///
+    /// These can be read without decoding with [`Self::read_block`], allowing
+    /// custom caching, parallel decoding, or skipping of record batches
+    pub fn blocks(&self) -> &[Block] {
+        &self.footer.blocks
+    }
 
-        let vecs = &meta_buffer.to_vec();
-        let message = crate::root_as_message(vecs).map_err(|err| {
-            ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
-        })?;
+    /// Returns the blocks of the dictionary batches in the file
+    pub fn dictionary_blocks(&self) -> &[Block] {
+        &self.footer.dictionary_blocks
+    }
 
-        match message.header_type() {
-            crate::MessageHeader::Schema => Err(ArrowError::IpcError(
-                "Not expecting a schema when messages are read".to_string(),
-            )),
-            crate::MessageHeader::RecordBatch => {
-                let batch = message.header_as_record_batch().ok_or_else(|| {
-                    ArrowError::IpcError("Unable to read IPC message as record batch".to_string())
-                })?;
-                // read the block that makes up the record batch into a buffer
-                let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                self.reader.read_exact(&mut buf)?;
+    /// Reads the contents of `block` from the file, without decoding it
+    ///
+    /// `block` is typically one of [`Self::blocks`] or [`Self::dictionary_blocks`].
+    /// The checksum of the block, if any, is not verified
+    pub fn read_block(&mut self, block: &Block) -> Result<RawBlock, ArrowError> {
+        let buffer = read_block(&mut self.reader, block, &self.footer.limits)?;
+        Ok(RawBlock {
+            block: *block,
+            buffer,
+        })
+    }
 
-                read_record_batch(
-                    &buf.into(),
-                    batch,
-                    self.schema(),
-                    &self.dictionaries_by_id,
-                    self.projection.as_ref().map(|x| x.0.as_ref()),
-                    &message.version(),
-                )
-                .map(Some)
-            }
-            crate::MessageHeader::DictionaryBatch => {
-                let batch = message.header_as_dictionary_batch().ok_or_else(|| {
-                    ArrowError::IpcError(
-                        "Unable to read IPC message as dictionary batch".to_string(),
-                    )
-                })?;
-                // read the block that makes up the dictionary batch into a buffer
-                let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                self.reader.read_exact(&mut buf)?;
+    /// Decodes the record batch contained in `block`, as read by [`Self::read_block`],
+    /// with the projection and dictionaries of this reader
+    ///
+    /// Returns an error if `block` does not contain a record batch
+    pub fn decode_block(
+        &self,
+        block: &RawBlock,
+    ) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
+        self.footer
+            .decoder
+            .read_record_batch_with_metadata(&block.block, &block.buffer)
+    }
 
-                read_dictionary(
-                    &buf.into(),
-                    batch,
-                    &self.schema,
-                    &mut self.dictionaries_by_id,
-                    &message.version(),
-                )?;
+    fn maybe_next(&mut self) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
+        let idx = self.current_block;
+        self.current_block += 1;
 
-                // read the next message until we encounter a RecordBatch
-                self.maybe_next()
-            }
-            crate::MessageHeader::NONE => Ok(None),
-            t => Err(ArrowError::InvalidArgumentError(format!(
-                "Reading types other than record batches not yet supported, unable to read {t:?} "
-            ))),
+        let block = &self.footer.blocks[idx];
+        let context = |e: ArrowError| {
+            let context = ErrorContext::new(format!("reading record batch {idx}"));
+            e.with_context(context.with_offset(block.offset() as u64))
+        };
+        // read length
+        let buffer = read_block(&mut self.reader, block, &self.footer.limits).map_err(context)?;
+        self.footer.read_record_batch(idx, &buffer).map_err(context)
+    }
+
+    /// Verifies the checksums of all dictionary and record batch blocks in the file,
+    /// without decoding them
+    ///
+    /// Returns `Ok(false)` if the file does not contain checksums, and an error if
+    /// any block does not match its checksum, or the `checksum` feature is not enabled
+    pub fn verify_integrity(&mut self) -> Result<bool, ArrowError> {
+        let footer = &self.footer;
+        let (Some(dictionaries), Some(record_batches)) =
+            (&footer.dictionary_checksums, &footer.record_batch_checksums)
+        else {
+            return Ok(false);
+        };
+        check_verifiable()?;
+        for (idx, (block, checksum)) in footer
+            .dictionary_blocks
+            .iter()
+            .zip(dictionaries)
+            .enumerate()
+        {
+            let buffer = read_block(&mut self.reader, block, &footer.limits)?;
+            verify_checksum(&buffer, *checksum, "dictionary", idx)?;
         }
+        for (idx, (block, checksum)) in footer.blocks.iter().zip(record_batches).enumerate() {
+            let buffer = read_block(&mut self.reader, block, &footer.limits)?;
+            verify_checksum(&buffer, *checksum, "record batch", idx)?;
+        }
+        Ok(true)
     }
 
     /// Gets a reference to the underlying reader.
@@ -1167,11 +2218,699 @@ impl<R: Read> StreamReader<R> {
     }
 }
 
-impl<R: Read> Iterator for StreamReader<R> {
+impl<R: Read + Seek> Iterator for FileReader<R> {
     type Item = Result<RecordBatch, ArrowError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.maybe_next().transpose()
+        self.next_with_metadata().map(|r| r.map(|(batch, _)| batch))
+    }
+}
+
+impl<R: Read + Seek> RecordBatchReader for FileReader<R> {
+    fn schema(&self) -> SchemaRef {
+        self.schema()
+    }
+}
+
+/// Arrow File reader over the contents of an entire file held in a [`Buffer`]
+///
+/// Unlike [`FileReader`], which reads each block into a newly allocated buffer,
+/// the arrays of the returned record batches slice `buffer` without copying,
+/// provided it is sufficiently aligned and the file is not compressed. This makes
+/// it well suited to reading memory-mapped files.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_buffer::Buffer;
+/// # use arrow_ipc::reader::BufferFileReader;
+/// # use arrow_ipc::writer::FileWriter;
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+/// ]).unwrap();
+///
+/// let mut writer = FileWriter::try_new(vec![], &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.finish().unwrap();
+///
+/// // A memory-mapped file can be converted to a Buffer with `Buffer::from_custom_allocation`
+/// let buffer = Buffer::from_vec(writer.into_inner().unwrap());
+/// let reader = BufferFileReader::try_new(buffer, None).unwrap();
+/// let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(batches, vec![batch]);
+/// ```
+pub struct BufferFileReader {
+    /// The contents of the file
+    buffer: Buffer,
+
+    /// The decoded footer, containing the decoder and the blocks in the file
+    footer: FileFooter,
+
+    /// A counter to keep track of the current block that should be read
+    current_block: usize,
+}
+
+impl fmt::Debug for BufferFileReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("BufferFileReader")
+            .field("decoder", &self.footer.decoder)
+            .field("blocks", &self.footer.blocks)
+            .field("current_block", &self.current_block)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BufferFileReader {
+    /// Try to create a new file reader over the contents of an entire file
+    ///
+    /// Returns errors if the file does not meet the Arrow Format footer requirements
+    pub fn try_new(buffer: Buffer, projection: Option<Vec<usize>>) -> Result<Self, ArrowError> {
+        let builder = FileReaderBuilder {
+            projection,
+            ..Default::default()
+        };
+        builder.build_from_buffer(buffer)
+    }
+
+    /// Return user defined customized metadata
+    ///
+    /// This is the custom metadata of the file footer, as written by
+    /// [`FileWriter::write_metadata`](crate::writer::FileWriter::write_metadata)
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.footer.custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.footer.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.footer.decoder.schema()
+    }
+
+    /// Return the dictionary memo, containing the decoded dictionary values keyed by
+    /// dictionary id
+    pub fn dictionaries(&self) -> &HashMap<i64, ArrayRef> {
+        self.footer.decoder.dictionaries()
+    }
+
+    /// Read a specific record batch
+    ///
+    /// Sets the current block to the index, allowing random reads
+    pub fn set_index(&mut self, index: usize) -> Result<(), ArrowError> {
+        if index >= self.num_batches() {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot set batch to index {} from {} total batches",
+                index,
+                self.num_batches()
+            )))
+        } else {
+            self.current_block = index;
+            Ok(())
+        }
+    }
+
+    /// Read the next record batch, along with its custom metadata, see
+    /// [`FileReader::next_with_metadata`]
+    pub fn next_with_metadata(&mut self) -> Option<Result<RecordBatchWithMetadata, ArrowError>> {
+        if self.current_block >= self.num_batches() {
+            return None;
+        }
+        let idx = self.current_block;
+        self.current_block += 1;
+        slice_block(&self.buffer, &self.footer.blocks[idx])
+            .and_then(|buf| self.footer.read_record_batch(idx, &buf))
+            .transpose()
+    }
+
+    /// Reads the record batches with indices in `range`, decoding them concurrently
+    /// on a rayon thread pool of `pool_size` threads, see
+    /// [`FileReader::read_batches_parallel`]
+    #[cfg(feature = "parallel")]
+    pub fn read_batches_parallel(
+        &self,
+        range: std::ops::Range<usize>,
+        pool_size: usize,
+    ) -> Result<Vec<RecordBatch>, ArrowError> {
+        self.footer.check_range(&range)?;
+        let buffers = self.footer.blocks[range.clone()]
+            .iter()
+            .map(|block| slice_block(&self.buffer, block))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.footer
+            .read_record_batches_parallel(range.start, &buffers, pool_size)
+    }
+
+    /// Returns the blocks of the record batches in the file, see [`FileReader::blocks`]
+    pub fn blocks(&self) -> &[Block] {
+        &self.footer.blocks
+    }
+
+    /// Returns the blocks of the dictionary batches in the file
+    pub fn dictionary_blocks(&self) -> &[Block] {
+        &self.footer.dictionary_blocks
+    }
+
+    /// Slices the contents of `block` from the file without copying or decoding it,
+    /// see [`FileReader::read_block`]
+    pub fn read_block(&self, block: &Block) -> Result<RawBlock, ArrowError> {
+        self.footer.limits.check_block(block)?;
+        let buffer = slice_block(&self.buffer, block)?;
+        Ok(RawBlock {
+            block: *block,
+            buffer,
+        })
+    }
+
+    /// Decodes the record batch contained in `block`, see [`FileReader::decode_block`]
+    pub fn decode_block(
+        &self,
+        block: &RawBlock,
+    ) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
+        self.footer
+            .decoder
+            .read_record_batch_with_metadata(&block.block, &block.buffer)
+    }
+
+    /// Returns the contents of the file
+    pub fn get_ref(&self) -> &Buffer {
+        &self.buffer
+    }
+}
+
+impl Iterator for BufferFileReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_metadata().map(|r| r.map(|(batch, _)| batch))
+    }
+}
+
+impl RecordBatchReader for BufferFileReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema()
+    }
+}
+
+/// Build an Arrow [`StreamReader`] with custom options.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_ipc::reader::{ReadLimits, StreamReaderBuilder};
+/// # use arrow_ipc::writer::StreamWriter;
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+/// #     ("b", Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef),
+/// # ]).unwrap();
+/// # let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # let stream = writer.into_inner().unwrap();
+/// let mut reader = StreamReaderBuilder::new()
+///     .with_projection(vec![1])
+///     .with_limits(ReadLimits::new().with_max_message_size(1024 * 1024))
+///     .build(stream.as_slice())
+///     .unwrap();
+/// let batch = reader.next().unwrap().unwrap();
+/// assert_eq!(batch.num_columns(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct StreamReaderBuilder {
+    /// Optional projection for which columns to load (zero-based column indices)
+    projection: Option<Vec<usize>>,
+    /// Optional projection for which columns to load, by column name
+    projection_names: Option<Vec<String>>,
+    /// Optional projection of the nested fields to load
+    projection_mask: Option<ProjectionMask>,
+    /// Columns for which to skip validation of the decoded arrays
+    skip_validation: Vec<usize>,
+    /// Limits on the sizes of the messages read
+    limits: ReadLimits,
+    /// Dictionaries shared with other readers, if any
+    dictionary_provider: Option<Arc<dyn DictionaryProvider>>,
+}
+
+impl StreamReaderBuilder {
+    /// Options for creating a new [`StreamReader`].
+    ///
+    /// To convert a builder into a reader, call [`StreamReaderBuilder::build`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Optional projection for which columns to load (zero-based column indices).
+    ///
+    /// Replaces any projection set by [`Self::with_projection_names`]
+    pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
+        self.projection = Some(projection);
+        self.projection_names = None;
+        self
+    }
+
+    /// Optional projection for which columns to load, by column name
+    ///
+    /// The names are resolved against the schema read from the input when building
+    /// the reader, returning an error listing any names not present in the schema.
+    /// Replaces any projection set by [`Self::with_projection`]
+    pub fn with_projection_names<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.projection_names = Some(names.into_iter().map(Into::into).collect());
+        self.projection = None;
+        self
+    }
+
+    /// Optional projection of the nested fields to load, see [`ProjectionMask`]
+    ///
+    /// The leaf indices of `mask` refer to the schema of the stream, prior to any
+    /// projection. If combined with [`Self::with_projection`], only the selected
+    /// leaves of the projected columns are read
+    pub fn with_projection_mask(mut self, mask: ProjectionMask) -> Self {
+        self.projection_mask = Some(mask);
+        self
+    }
+
+    /// Skip validation of the decoded arrays for the columns with the given
+    /// (zero-based) indices in the stream's schema.
+    ///
+    /// See [`FileDecoder::with_skip_validation`] for details.
+    ///
+    /// # Safety
+    ///
+    /// The IPC data for the listed columns must be valid, as described in
+    /// [`ArrayData::validate_full`], otherwise undefined behaviour may result
+    pub unsafe fn with_skip_validation(mut self, columns: Vec<usize>) -> Self {
+        self.skip_validation = columns;
+        self
+    }
+
+    /// Sets limits on the sizes of the messages read, see [`ReadLimits`]
+    pub fn with_limits(mut self, limits: ReadLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Share decoded dictionaries with other readers through `provider`, see
+    /// [`DictionaryProvider`]
+    pub fn with_dictionary_provider(mut self, provider: Arc<dyn DictionaryProvider>) -> Self {
+        self.dictionary_provider = Some(provider);
+        self
+    }
+
+    /// Build a [`StreamReader`] with the given reader, wrapped in a [`BufReader`]
+    ///
+    /// The first message in the stream is the schema, the reader will fail if it
+    /// does not encounter a schema.
+    pub fn build<R: Read>(self, reader: R) -> Result<StreamReader<BufReader<R>>, ArrowError> {
+        self.build_unbuffered(BufReader::new(reader))
+    }
+
+    /// Build a [`StreamReader`] with the given reader, without wrapping it in a
+    /// [`BufReader`]
+    pub fn build_unbuffered<R: Read>(self, reader: R) -> Result<StreamReader<R>, ArrowError> {
+        StreamReader::try_new_with_builder(reader, self)
+    }
+}
+
+/// Arrow Stream reader
+pub struct StreamReader<R: Read> {
+    /// Stream reader
+    reader: R,
+
+    /// The schema that is read from the stream's first message
+    schema: SchemaRef,
+
+    /// Optional dictionaries for each schema field.
+    ///
+    /// Dictionaries may be appended to in the streaming format.
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
+
+    /// An indicator of whether the stream is complete.
+    ///
+    /// This value is set to `true` the first time the reader's `next()` returns `None`.
+    finished: bool,
+
+    /// Optional projection
+    projection: Option<(Vec<usize>, Schema)>,
+
+    /// Optional projection of the nested fields
+    projection_mask: Option<ProjectionMask>,
+
+    /// Columns for which to skip validation of the decoded arrays
+    skip_validation: Vec<usize>,
+
+    /// Limits on the sizes of the messages read
+    limits: ReadLimits,
+
+    /// The endianness of the stream, as recorded in its schema
+    endianness: crate::Endianness,
+
+    /// Dictionaries shared with other readers, if any
+    dictionary_provider: Option<Arc<dyn DictionaryProvider>>,
+}
+
+impl<R: Read> fmt::Debug for StreamReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
+        f.debug_struct("StreamReader<R>")
+            .field("reader", &"BufReader<..>")
+            .field("schema", &self.schema)
+            .field("dictionaries_by_id", &self.dictionaries_by_id)
+            .field("finished", &self.finished)
+            .field("projection", &self.projection)
+            .field("projection_mask", &self.projection_mask)
+            .field("skip_validation", &self.skip_validation)
+            .field("limits", &self.limits)
+            .field("endianness", &self.endianness)
+            .field("dictionary_provider", &self.dictionary_provider)
+            .finish()
+    }
+}
+
+impl<R: Read> StreamReader<BufReader<R>> {
+    /// Try to create a new stream reader with the reader wrapped in a BufReader
+    ///
+    /// The first message in the stream is the schema, the reader will fail if it does not
+    /// encounter a schema.
+    /// To check if the reader is done, use `is_finished(self)`
+    pub fn try_new(reader: R, projection: Option<Vec<usize>>) -> Result<Self, ArrowError> {
+        Self::try_new_unbuffered(BufReader::new(reader), projection)
+    }
+
+    /// Try to create a new stream reader with the reader wrapped in a BufReader,
+    /// returning an error for any message exceeding `limits`, see [`ReadLimits`]
+    pub fn try_new_with_limits(
+        reader: R,
+        projection: Option<Vec<usize>>,
+        limits: ReadLimits,
+    ) -> Result<Self, ArrowError> {
+        let mut builder = StreamReaderBuilder::new().with_limits(limits);
+        if let Some(projection) = projection {
+            builder = builder.with_projection(projection);
+        }
+        builder.build(reader)
+    }
+}
+
+impl<R: Read> StreamReader<R> {
+    /// Try to create a new stream reader but do not wrap the reader in a BufReader.
+    ///
+    /// Unless you need the StreamReader to be unbuffered you likely want to use `StreamReader::try_new` instead.
+    pub fn try_new_unbuffered(
+        reader: R,
+        projection: Option<Vec<usize>>,
+    ) -> Result<StreamReader<R>, ArrowError> {
+        let mut builder = StreamReaderBuilder::new();
+        if let Some(projection) = projection {
+            builder = builder.with_projection(projection);
+        }
+        builder.build_unbuffered(reader)
+    }
+
+    /// Creates a new stream reader from `reader` with the options of `builder`
+    fn try_new_with_builder(
+        mut reader: R,
+        builder: StreamReaderBuilder,
+    ) -> Result<StreamReader<R>, ArrowError> {
+        let StreamReaderBuilder {
+            projection,
+            projection_names,
+            projection_mask,
+            skip_validation,
+            limits,
+            dictionary_provider,
+        } = builder;
+
+        // determine metadata length
+        let mut meta_size: [u8; 4] = [0; 4];
+        reader.read_exact(&mut meta_size)?;
+        let meta_len = {
+            // If a continuation marker is encountered, skip over it and read
+            // the size from the next four bytes.
+            if meta_size == CONTINUATION_MARKER {
+                reader.read_exact(&mut meta_size)?;
+            }
+            i32::from_le_bytes(meta_size)
+        };
+        let meta_len = limits.check_message_size(meta_len.into())?;
+        let meta_buffer = read_exact_buffer(&mut reader, meta_len)?;
+
+        let (schema, endianness) = read_schema_message(&meta_buffer, &limits)?;
+
+        // Create an array of optional dictionary value arrays, one per field.
+        let dictionaries_by_id = HashMap::new();
+
+        let projection = match projection_names {
+            Some(names) => Some(resolve_projection_names(&schema, &names, false)?),
+            None => projection,
+        };
+        let projection = match projection {
+            Some(projection_indices) => {
+                let schema = schema.project(&projection_indices)?;
+                Some((projection_indices, schema))
+            }
+            _ => None,
+        };
+        Ok(Self {
+            reader,
+            schema: Arc::new(schema),
+            finished: false,
+            dictionaries_by_id,
+            projection,
+            projection_mask,
+            skip_validation,
+            limits,
+            endianness,
+            dictionary_provider,
+        })
+    }
+
+    /// Skip validation of the decoded arrays for the columns with the given
+    /// (zero-based) indices in the stream's schema.
+    ///
+    /// See [`FileDecoder::with_skip_validation`] for details.
+    ///
+    /// # Safety
+    ///
+    /// The IPC data for the listed columns must be valid, as described in
+    /// [`ArrayData::validate_full`], otherwise undefined behaviour may result
+    pub unsafe fn with_skip_validation(mut self, columns: Vec<usize>) -> Self {
+        self.skip_validation = columns;
+        self
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Check if the stream is finished
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Read the next record batch, along with its custom metadata
+    ///
+    /// The metadata is empty if none was written for the batch, see
+    /// [`StreamWriter::write_with_metadata`](crate::writer::StreamWriter::write_with_metadata)
+    pub fn next_with_metadata(&mut self) -> Option<Result<RecordBatchWithMetadata, ArrowError>> {
+        self.maybe_next().transpose()
+    }
+
+    /// Returns an iterator over the remaining record batches, along with their
+    /// custom metadata, see [`Self::next_with_metadata`]
+    pub fn batches_with_metadata(
+        &mut self,
+    ) -> impl Iterator<Item = Result<RecordBatchWithMetadata, ArrowError>> + '_ {
+        std::iter::from_fn(move || self.next_with_metadata())
+    }
+
+    fn maybe_next(&mut self) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
+        if self.finished {
+            return Ok(None);
+        }
+        // determine metadata length
+        let mut meta_size: [u8; 4] = [0; 4];
+
+        match self.reader.read_exact(&mut meta_size) {
+            Ok(()) => (),
+            Err(e) => {
+                return if e.kind() == std::io::ErrorKind::UnexpectedEof {
+                    // Handle EOF without the "0xFFFFFFFF 0x00000000"
+                    // valid according to:
+                    // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+                    self.finished = true;
+                    Ok(None)
+                } else {
+                    Err(ArrowError::from(e))
+                };
+            }
+        }
+
+        let meta_len = {
+            // If a continuation marker is encountered, skip over it and read
+            // the size from the next four bytes.
+            if meta_size == CONTINUATION_MARKER {
+                self.reader.read_exact(&mut meta_size)?;
+            }
+            i32::from_le_bytes(meta_size)
+        };
+
+        if meta_len == 0 {
+            // the stream has ended, mark the reader as finished
+            self.finished = true;
+            return Ok(None);
+        }
+
+        let meta_len = self.limits.check_message_size(meta_len.into())?;
+        let meta_buffer = read_exact_buffer(&mut self.reader, meta_len)?;
+
+        let message = crate::root_as_message(&meta_buffer).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
+        })?;
+
+        // read the block that makes up the message body into a buffer
+        let body_len = stream_message_body_len(&message);
+        let body_len = self.limits.check_body_size(body_len as i64)?;
+        let buf = read_exact_buffer(&mut self.reader, body_len)?;
+
+        let batch = decode_stream_message(
+            &message,
+            buf.into(),
+            &self.schema,
+            &mut self.dictionaries_by_id,
+            self.projection.as_ref().map(|x| x.0.as_ref()),
+            self.projection_mask.as_ref(),
+            &self.skip_validation,
+            self.endianness,
+            self.dictionary_provider.as_deref(),
+        )?;
+        match message.header_type() {
+            // read the next message until we encounter a RecordBatch
+            crate::MessageHeader::DictionaryBatch => self.maybe_next(),
+            _ => Ok(batch),
+        }
+    }
+
+    /// Gets a reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+}
+
+/// Decodes the [`Schema`] from the first message of an IPC stream, along with the
+/// endianness of the stream, returning an error if it has more fields than
+/// permitted by `limits`
+pub(crate) fn read_schema_message(
+    meta_buffer: &[u8],
+    limits: &ReadLimits,
+) -> Result<(Schema, crate::Endianness), ArrowError> {
+    let message = crate::root_as_message(meta_buffer)
+        .map_err(|err| ArrowError::ParseError(format!("Unable to get root as message: {err:?}")))?;
+    // message header is a Schema, so read it
+    let ipc_schema: crate::Schema = message.header_as_schema().ok_or_else(|| {
+        ArrowError::ParseError("Unable to read IPC message as schema".to_string())
+    })?;
+    limits.check_fields(ipc_schema)?;
+    let endianness = check_endianness(ipc_schema.endianness())?;
+    Ok((crate::convert::try_fb_to_schema(ipc_schema)?, endianness))
+}
+
+/// Returns the length of the body that must be read following `message` in an
+/// IPC stream, before decoding it with [`decode_stream_message`]
+pub(crate) fn stream_message_body_len(message: &Message<'_>) -> usize {
+    match message.header_type() {
+        crate::MessageHeader::RecordBatch | crate::MessageHeader::DictionaryBatch => {
+            message.bodyLength() as usize
+        }
+        _ => 0,
+    }
+}
+
+/// Decodes a message read from an IPC stream with the given `body`, where
+/// `endianness` is that recorded in the schema of the stream
+///
+/// Dictionary batches are added to `dictionaries_by_id`, returning `None`, in which
+/// case the caller should proceed to the next message
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_stream_message(
+    message: &Message<'_>,
+    body: Buffer,
+    schema: &SchemaRef,
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+    projection: Option<&[usize]>,
+    mask: Option<&ProjectionMask>,
+    skip_validation: &[usize],
+    endianness: crate::Endianness,
+    dictionary_provider: Option<&dyn DictionaryProvider>,
+) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
+    verify_message_checksum(message, &body)?;
+    if let Some(provider) = dictionary_provider {
+        load_dictionaries(provider, message, schema, dictionaries_by_id);
+    }
+    match message.header_type() {
+        crate::MessageHeader::Schema => Err(ArrowError::IpcError(
+            "Not expecting a schema when messages are read".to_string(),
+        )),
+        crate::MessageHeader::RecordBatch => {
+            let batch = message.header_as_record_batch().ok_or_else(|| {
+                ArrowError::IpcError("Unable to read IPC message as record batch".to_string())
+            })?;
+            let batch = read_record_batch_impl(
+                &body,
+                batch,
+                schema.clone(),
+                dictionaries_by_id,
+                projection,
+                mask,
+                &message.version(),
+                skip_validation,
+                endianness,
+            )?;
+            Ok(Some((batch, message_custom_metadata(message))))
+        }
+        crate::MessageHeader::DictionaryBatch => {
+            let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                ArrowError::IpcError("Unable to read IPC message as dictionary batch".to_string())
+            })?;
+            read_dictionary_impl(
+                &body,
+                batch,
+                schema,
+                dictionaries_by_id,
+                &message.version(),
+                endianness,
+            )?;
+            if let Some(provider) = dictionary_provider {
+                let values = dictionaries_by_id[&batch.id()].clone();
+                dictionaries_by_id.insert(batch.id(), provider.insert(batch.id(), values));
+            }
+            Ok(None)
+        }
+        crate::MessageHeader::NONE => Ok(None),
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "Reading types other than record batches not yet supported, unable to read {t:?} "
+        ))),
+    }
+}
+
+impl<R: Read> Iterator for StreamReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_metadata().map(|r| r.map(|(batch, _)| batch))
     }
 }
 
@@ -1179,189 +2918,1161 @@ impl<R: Read> RecordBatchReader for StreamReader<R> {
     fn schema(&self) -> SchemaRef {
         self.schema.clone()
     }
-}
+}
+
+/// Creates a [`RecordBatchReader`] for `reader`, which may contain either the IPC file
+/// or the IPC stream format, detected from the `ARROW1` magic that starts IPC files
+///
+/// Returns a [`FileReader`] or a [`StreamReader`] accordingly, reading from the
+/// current position of `reader`
+///
+/// ```
+/// # use std::io::Cursor;
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_ipc::reader::try_new_auto;
+/// # use arrow_ipc::writer::{FileWriter, StreamWriter};
+/// let a = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
+/// let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+///
+/// let mut file = FileWriter::try_new(vec![], &batch.schema()).unwrap();
+/// file.write(&batch).unwrap();
+/// file.finish().unwrap();
+///
+/// let mut stream = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+/// stream.write(&batch).unwrap();
+/// stream.finish().unwrap();
+///
+/// for buf in [file.into_inner().unwrap(), stream.into_inner().unwrap()] {
+///     let reader = try_new_auto(Cursor::new(buf), None).unwrap();
+///     assert_eq!(reader.schema(), batch.schema());
+///     let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+///     assert_eq!(batches, vec![batch.clone()]);
+/// }
+/// ```
+pub fn try_new_auto<'a, R: Read + Seek + Send + 'a>(
+    mut reader: R,
+    projection: Option<Vec<usize>>,
+) -> Result<Box<dyn RecordBatchReader + Send + 'a>, ArrowError> {
+    let start = reader.stream_position()?;
+    let mut magic = Vec::with_capacity(super::ARROW_MAGIC.len());
+    reader
+        .by_ref()
+        .take(super::ARROW_MAGIC.len() as u64)
+        .read_to_end(&mut magic)?;
+    reader.seek(SeekFrom::Start(start))?;
+
+    Ok(match magic == super::ARROW_MAGIC {
+        true => Box::new(FileReader::try_new(reader, projection)?),
+        false => Box::new(StreamReader::try_new(reader, projection)?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::writer::{unslice_run_array, DictionaryTracker, IpcDataGenerator};
+
+    use super::*;
+
+    use crate::root_as_message;
+    use arrow_array::builder::{PrimitiveRunBuilder, UnionBuilder};
+    use arrow_array::types::*;
+    use arrow_buffer::{i256, ArrowNativeType};
+    use arrow_data::ArrayDataBuilder;
+
+    fn create_test_projection_schema() -> Schema {
+        // define field types
+        let list_data_type = DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
+
+        let fixed_size_list_data_type =
+            DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int32, false)), 3);
+
+        let union_fields = UnionFields::new(
+            vec![0, 1],
+            vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Float64, false),
+            ],
+        );
+
+        let union_data_type = DataType::Union(union_fields, UnionMode::Dense);
+
+        let struct_fields = Fields::from(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new_list("list", Field::new("item", DataType::Int8, true), false),
+        ]);
+        let struct_data_type = DataType::Struct(struct_fields);
+
+        let run_encoded_data_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int16, false)),
+            Arc::new(Field::new("values", DataType::Int32, true)),
+        );
+
+        // define schema
+        Schema::new(vec![
+            Field::new("f0", DataType::UInt32, false),
+            Field::new("f1", DataType::Utf8, false),
+            Field::new("f2", DataType::Boolean, false),
+            Field::new("f3", union_data_type, true),
+            Field::new("f4", DataType::Null, true),
+            Field::new("f5", DataType::Float64, true),
+            Field::new("f6", list_data_type, false),
+            Field::new("f7", DataType::FixedSizeBinary(3), true),
+            Field::new("f8", fixed_size_list_data_type, false),
+            Field::new("f9", struct_data_type, false),
+            Field::new("f10", run_encoded_data_type, false),
+            Field::new("f11", DataType::Boolean, false),
+            Field::new_dictionary("f12", DataType::Int8, DataType::Utf8, false),
+            Field::new("f13", DataType::Utf8, false),
+        ])
+    }
+
+    fn create_test_projection_batch_data(schema: &Schema) -> RecordBatch {
+        // set test data for each column
+        let array0 = UInt32Array::from(vec![1, 2, 3]);
+        let array1 = StringArray::from(vec!["foo", "bar", "baz"]);
+        let array2 = BooleanArray::from(vec![true, false, true]);
+
+        let mut union_builder = UnionBuilder::new_dense();
+        union_builder.append::<Int32Type>("a", 1).unwrap();
+        union_builder.append::<Float64Type>("b", 10.1).unwrap();
+        union_builder.append_null::<Float64Type>("b").unwrap();
+        let array3 = union_builder.build().unwrap();
+
+        let array4 = NullArray::new(3);
+        let array5 = Float64Array::from(vec![Some(1.1), None, Some(3.3)]);
+        let array6_values = vec![
+            Some(vec![Some(10), Some(10), Some(10)]),
+            Some(vec![Some(20), Some(20), Some(20)]),
+            Some(vec![Some(30), Some(30)]),
+        ];
+        let array6 = ListArray::from_iter_primitive::<Int32Type, _, _>(array6_values);
+        let array7_values = vec![vec![11, 12, 13], vec![22, 23, 24], vec![33, 34, 35]];
+        let array7 = FixedSizeBinaryArray::try_from_iter(array7_values.into_iter()).unwrap();
+
+        let array8_values = ArrayData::builder(DataType::Int32)
+            .len(9)
+            .add_buffer(Buffer::from_slice_ref([40, 41, 42, 43, 44, 45, 46, 47, 48]))
+            .build()
+            .unwrap();
+        let array8_data = ArrayData::builder(schema.field(8).data_type().clone())
+            .len(3)
+            .add_child_data(array8_values)
+            .build()
+            .unwrap();
+        let array8 = FixedSizeListArray::from(array8_data);
+
+        let array9_id: ArrayRef = Arc::new(Int32Array::from(vec![1001, 1002, 1003]));
+        let array9_list: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int8Type, _, _>(vec![
+                Some(vec![Some(-10)]),
+                Some(vec![Some(-20), Some(-20), Some(-20)]),
+                Some(vec![Some(-30)]),
+            ]));
+        let array9 = ArrayDataBuilder::new(schema.field(9).data_type().clone())
+            .add_child_data(array9_id.into_data())
+            .add_child_data(array9_list.into_data())
+            .len(3)
+            .build()
+            .unwrap();
+        let array9: ArrayRef = Arc::new(StructArray::from(array9));
+
+        let array10_input = vec![Some(1_i32), None, None];
+        let mut array10_builder = PrimitiveRunBuilder::<Int16Type, Int32Type>::new();
+        array10_builder.extend(array10_input);
+        let array10 = array10_builder.finish();
+
+        let array11 = BooleanArray::from(vec![false, false, true]);
+
+        let array12_values = StringArray::from(vec!["x", "yy", "zzz"]);
+        let array12_keys = Int8Array::from_iter_values([1, 1, 2]);
+        let array12 = DictionaryArray::new(array12_keys, Arc::new(array12_values));
+
+        let array13 = StringArray::from(vec!["a", "bb", "ccc"]);
+
+        // create record batch
+        RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![
+                Arc::new(array0),
+                Arc::new(array1),
+                Arc::new(array2),
+                Arc::new(array3),
+                Arc::new(array4),
+                Arc::new(array5),
+                Arc::new(array6),
+                Arc::new(array7),
+                Arc::new(array8),
+                Arc::new(array9),
+                Arc::new(array10),
+                Arc::new(array11),
+                Arc::new(array12),
+                Arc::new(array13),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_projection_array_values() {
+        // define schema
+        let schema = create_test_projection_schema();
+
+        // create record batch with test data
+        let batch = create_test_projection_batch_data(&schema);
+
+        // write record batch in IPC format
+        let mut buf = Vec::new();
+        {
+            let mut writer = crate::writer::FileWriter::try_new(&mut buf, &schema).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        // read record batch with projection
+        for index in 0..12 {
+            let projection = vec![index];
+            let reader = FileReader::try_new(std::io::Cursor::new(buf.clone()), Some(projection));
+            let read_batch = reader.unwrap().next().unwrap().unwrap();
+            let projected_column = read_batch.column(0);
+            let expected_column = batch.column(index);
+
+            // check the projected column equals the expected column
+            assert_eq!(projected_column.as_ref(), expected_column.as_ref());
+        }
+
+        {
+            // read record batch with reversed projection
+            let reader =
+                FileReader::try_new(std::io::Cursor::new(buf.clone()), Some(vec![3, 2, 1]));
+            let read_batch = reader.unwrap().next().unwrap().unwrap();
+            let expected_batch = batch.project(&[3, 2, 1]).unwrap();
+            assert_eq!(read_batch, expected_batch);
+        }
+    }
+
+    #[test]
+    fn test_projection_nested_types() {
+        use arrow_array::builder::{ListBuilder, StringDictionaryBuilder};
+
+        let mut list_builder = ListBuilder::new(StringDictionaryBuilder::<Int32Type>::new());
+        list_builder.values().append_value("a");
+        list_builder.values().append_value("b");
+        list_builder.append(true);
+        list_builder.append(false);
+        list_builder.values().append_value("a");
+        list_builder.append(true);
+        let list_dict = list_builder.finish();
+
+        let mut union_builder = UnionBuilder::new_dense();
+        union_builder.append::<Int32Type>("a", 1).unwrap();
+        union_builder.append::<Float64Type>("b", 3.4).unwrap();
+        union_builder.append_null::<Int32Type>("a").unwrap();
+        let union = union_builder.build().unwrap();
+
+        let mut run_builder = PrimitiveRunBuilder::<Int16Type, Int32Type>::new();
+        run_builder.extend([Some(1), Some(1), None]);
+        let run = run_builder.finish();
+
+        let strings = StringArray::from(vec![Some("x"), None, Some("z")]);
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("run", run.data_type().clone(), true)),
+                Arc::new(run) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("s", DataType::Utf8, true)),
+                Arc::new(strings) as ArrayRef,
+            ),
+        ]);
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("list_dict", Arc::new(list_dict) as ArrayRef),
+            ("union", Arc::new(union) as ArrayRef),
+            ("struct", Arc::new(structs) as ArrayRef),
+            ("int", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        for projection in [vec![0], vec![1], vec![2], vec![3], vec![3, 0], vec![2, 1]] {
+            let reader =
+                FileReader::try_new(std::io::Cursor::new(buf.clone()), Some(projection.clone()));
+            let read_batch = reader.unwrap().next().unwrap().unwrap();
+            assert_eq!(read_batch, batch.project(&projection).unwrap());
+        }
+
+        let mut stream = Vec::new();
+        {
+            let mut writer =
+                crate::writer::StreamWriter::try_new(&mut stream, &batch.schema()).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+        let reader = StreamReader::try_new(stream.as_slice(), Some(vec![3, 1])).unwrap();
+        for read_batch in reader {
+            assert_eq!(read_batch.unwrap(), batch.project(&[3, 1]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_read_raw_blocks() {
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter(vec![
+            ("d", Arc::new(dict) as ArrayRef),
+            ("i", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+        ])
+        .unwrap();
+        let mut file = Vec::new();
+        {
+            let mut writer =
+                crate::writer::FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+            writer.write(&batch).unwrap();
+            writer.write(&batch.slice(1, 2)).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(file.clone()), None).unwrap();
+        assert_eq!(reader.dictionary_blocks().len(), 1);
+        let dictionary_block = reader.dictionary_blocks()[0];
+        let raw = reader.read_block(&dictionary_block).unwrap();
+        let message = raw.message().unwrap();
+        assert!(message.header_as_dictionary_batch().is_some());
+        reader.decode_block(&raw).unwrap_err();
+
+        let blocks = reader.blocks().to_vec();
+        assert_eq!(blocks.len(), 2);
+        let buffer_reader =
+            BufferFileReader::try_new(Buffer::from_vec(file.clone()), None).unwrap();
+        assert_eq!(buffer_reader.blocks(), blocks.as_slice());
+
+        // Read the blocks in reverse order
+        for (block, expected) in blocks.iter().zip([batch.clone(), batch.slice(1, 2)]).rev() {
+            let raw = reader.read_block(block).unwrap();
+            let message = raw.message().unwrap();
+            let header = message.header_as_record_batch().unwrap();
+            assert_eq!(header.length() as usize, expected.num_rows());
+            assert_eq!(message.bodyLength(), block.bodyLength());
+
+            let range = raw.body_range();
+            let body = &file[range.start as usize..range.end as usize];
+            assert_eq!(raw.body().as_slice(), body);
+
+            let (read, _) = reader.decode_block(&raw).unwrap().unwrap();
+            assert_eq!(read, expected);
+
+            let raw = buffer_reader.read_block(block).unwrap();
+            let (read, _) = buffer_reader.decode_block(&raw).unwrap().unwrap();
+            assert_eq!(read, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_read_batches_parallel() {
+        let values = Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef;
+        let batches: Vec<_> = (0..10)
+            .map(|i| {
+                let keys = Int32Array::from_iter_values((0..i).map(|k| k % 3));
+                let dict = DictionaryArray::new(keys, values.clone());
+                RecordBatch::try_from_iter(vec![
+                    ("d", Arc::new(dict) as ArrayRef),
+                    (
+                        "i",
+                        Arc::new(Int32Array::from_iter_values(0..i)) as ArrayRef,
+                    ),
+                ])
+                .unwrap()
+            })
+            .collect();
+        let mut file = Vec::new();
+        {
+            let mut writer =
+                crate::writer::FileWriter::try_new(&mut file, &batches[0].schema()).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(file.clone()), None).unwrap();
+        let buffer_reader = BufferFileReader::try_new(Buffer::from_vec(file), None).unwrap();
+        for (range, pool_size) in [(0..10, 4), (2..7, 1), (5..6, 2), (4..4, 0)] {
+            let expected = &batches[range.clone()];
+            let read = reader.read_batches_parallel(range.clone(), pool_size);
+            assert_eq!(read.unwrap(), expected);
+            let read = buffer_reader.read_batches_parallel(range, pool_size);
+            assert_eq!(read.unwrap(), expected);
+        }
+
+        // The current index is unchanged
+        assert_eq!(reader.next().unwrap().unwrap(), batches[0]);
+
+        let err = reader.read_batches_parallel(8..11, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot read batches 8..11 from 10 total batches"
+        );
+        let err = buffer_reader.read_batches_parallel(5..11, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot read batches 5..11 from 10 total batches"
+        );
+    }
 
-#[cfg(test)]
-mod tests {
-    use crate::writer::{unslice_run_array, DictionaryTracker, IpcDataGenerator};
+    #[test]
+    fn test_projection_names() {
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            ("b", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+            ("c", Arc::new(Int64Array::from(vec![3, 4])) as ArrayRef),
+        ])
+        .unwrap();
 
-    use super::*;
+        let mut file = Vec::new();
+        {
+            let mut writer =
+                crate::writer::FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+        let mut stream = Vec::new();
+        {
+            let mut writer =
+                crate::writer::StreamWriter::try_new(&mut stream, &batch.schema()).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
 
-    use crate::root_as_message;
-    use arrow_array::builder::{PrimitiveRunBuilder, UnionBuilder};
-    use arrow_array::types::*;
-    use arrow_buffer::ArrowNativeType;
-    use arrow_data::ArrayDataBuilder;
+        let expected = batch.project(&[2, 0]).unwrap();
+        let mut reader = FileReaderBuilder::new()
+            .with_projection_names(["c", "a"])
+            .build(std::io::Cursor::new(file.clone()))
+            .unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), expected);
 
-    fn create_test_projection_schema() -> Schema {
-        // define field types
-        let list_data_type = DataType::List(Arc::new(Field::new("item", DataType::Int32, true)));
+        let mut reader = StreamReaderBuilder::new()
+            .with_projection_names(["c", "a"])
+            .build(stream.as_slice())
+            .unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), expected);
 
-        let fixed_size_list_data_type =
-            DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int32, false)), 3);
+        // The most recent projection takes precedence
+        let mut reader = StreamReaderBuilder::new()
+            .with_projection_names(["c", "a"])
+            .with_projection(vec![1])
+            .build(stream.as_slice())
+            .unwrap();
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            batch.project(&[1]).unwrap()
+        );
 
-        let union_fields = UnionFields::new(
-            vec![0, 1],
+        let expected = "Schema error: Projected columns [\"d\", \"e\"] not found in schema, \
+            available columns are [\"a\", \"b\", \"c\"]";
+        let err = FileReaderBuilder::new()
+            .with_projection_names(["d", "a", "e"])
+            .build(std::io::Cursor::new(file))
+            .unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        let err = StreamReaderBuilder::new()
+            .with_projection_names(["d", "a", "e"])
+            .build(stream.as_slice())
+            .unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn test_projection_mask() {
+        use arrow_array::cast::AsArray;
+        use arrow_buffer::{NullBuffer, OffsetBuffer};
+
+        let p = StringArray::from(vec![Some("p1"), None, Some("p3")]);
+        let q = Int64Array::from(vec![1, 2, 3]);
+        let items = StructArray::try_from(vec![
+            ("p", Arc::new(p.clone()) as ArrayRef),
+            ("q", Arc::new(q) as ArrayRef),
+        ])
+        .unwrap();
+        let offsets = OffsetBuffer::new(vec![0, 2, 2, 3].into());
+        let list_nulls = Some(NullBuffer::from(vec![true, false, true]));
+        let item_field = Arc::new(Field::new("item", items.data_type().clone(), true));
+        let list = ListArray::new(
+            item_field,
+            offsets.clone(),
+            Arc::new(items),
+            list_nulls.clone(),
+        );
+        let dict: DictionaryArray<Int8Type> = vec!["x", "y", "x"].into_iter().collect();
+        let s = StructArray::try_from(vec![
+            ("x", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("l", Arc::new(list) as ArrayRef),
+            ("d", Arc::new(dict) as ArrayRef),
+        ])
+        .unwrap();
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int32Array::from(vec![4, 5, 6])) as ArrayRef),
+            ("s", Arc::new(s) as ArrayRef),
+            (
+                "z",
+                Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let mut file = Vec::new();
+        {
+            let mut writer =
+                crate::writer::FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+        let mut stream = Vec::new();
+        {
+            let mut writer =
+                crate::writer::StreamWriter::try_new(&mut stream, &batch.schema()).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+        let read = |mask: ProjectionMask, projection: Option<Vec<usize>>| {
+            let mut builder = FileReaderBuilder::new().with_projection_mask(mask.clone());
+            let mut stream_builder = StreamReaderBuilder::new().with_projection_mask(mask);
+            if let Some(projection) = projection {
+                builder = builder.with_projection(projection.clone());
+                stream_builder = stream_builder.with_projection(projection);
+            }
+            let mut reader = builder.build(std::io::Cursor::new(file.clone())).unwrap();
+            let from_file = reader.next().unwrap().unwrap();
+            let mut reader = stream_builder.build(stream.as_slice()).unwrap();
+            let from_stream = reader.next().unwrap().unwrap();
+            assert_eq!(from_file, from_stream);
+            from_file
+        };
+
+        // Leaves are a, s.x, s.l.item.p, s.l.item.q, s.d and z
+        let schema = batch.schema();
+        let all = read(ProjectionMask::all(), None);
+        assert_eq!(all, batch);
+        let roots = read(ProjectionMask::roots(&schema, [0, 2]), None);
+        assert_eq!(roots, batch.project(&[0, 2]).unwrap());
+
+        let read_batch = read(ProjectionMask::leaves(&schema, [2, 5]), None);
+        let items = StructArray::try_from(vec![("p", Arc::new(p) as ArrayRef)]).unwrap();
+        let item_field = Arc::new(Field::new("item", items.data_type().clone(), true));
+        let list = ListArray::new(item_field, offsets, Arc::new(items), list_nulls);
+        let s = StructArray::try_from(vec![("l", Arc::new(list) as ArrayRef)]).unwrap();
+        let expected = RecordBatch::try_from_iter(vec![
+            ("s", Arc::new(s) as ArrayRef),
+            ("z", batch.column(2).clone()),
+        ])
+        .unwrap();
+        assert_eq!(read_batch, expected);
+
+        // Combined with a projection, which determines the order of the columns
+        let read_batch = read(ProjectionMask::leaves(&schema, [2, 5]), Some(vec![2, 1]));
+        assert_eq!(read_batch, expected.project(&[1, 0]).unwrap());
+
+        let read_batch = read(ProjectionMask::leaves(&schema, [1, 4]), Some(vec![0, 1]));
+        let s = batch.column(1).as_struct();
+        let expected =
+            StructArray::try_from(vec![("x", s.column(0).clone()), ("d", s.column(2).clone())])
+                .unwrap();
+        assert_eq!(read_batch.num_columns(), 1);
+        assert_eq!(read_batch.column(0).as_struct(), &expected);
+
+        let read_batch = read(ProjectionMask::leaves(&schema, []), None);
+        assert_eq!(read_batch.num_columns(), 0);
+        assert_eq!(read_batch.num_rows(), 3);
+    }
+
+    #[test]
+    fn test_projected_dictionaries() {
+        use arrow_array::cast::AsArray;
+
+        let dict_type = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new_dict("a", dict_type.clone(), true, 1, false),
+            Field::new("b", DataType::Int32, true),
+            Field::new_dict("c", dict_type, true, 2, false),
+        ]));
+        let a: DictionaryArray<Int8Type> = vec!["x", "y", "x"].into_iter().collect();
+        let c: DictionaryArray<Int8Type> = vec!["p", "p", "q"].into_iter().collect();
+        let batch = RecordBatch::try_new(
+            schema.clone(),
             vec![
-                Field::new("a", DataType::Int32, false),
-                Field::new("b", DataType::Float64, false),
+                Arc::new(a) as ArrayRef,
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(c),
             ],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = crate::writer::FileWriter::try_new(&mut buf, &schema).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let reader = FileReader::try_new(std::io::Cursor::new(buf.clone()), None).unwrap();
+        let mut ids: Vec<_> = reader.dictionaries().keys().copied().collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 2]);
+
+        // No dictionaries are required
+        let mut reader =
+            FileReader::try_new(std::io::Cursor::new(buf.clone()), Some(vec![1])).unwrap();
+        assert!(reader.dictionaries().is_empty());
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read, batch.project(&[1]).unwrap());
+
+        // Only the dictionary for "c" is required
+        let mut reader =
+            FileReader::try_new(std::io::Cursor::new(buf.clone()), Some(vec![2, 1])).unwrap();
+        let ids: Vec<_> = reader.dictionaries().keys().copied().collect();
+        assert_eq!(ids, vec![2]);
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read, batch.project(&[2, 1]).unwrap());
+
+        // Pre-seeded dictionaries are not read from the file
+        let seeded: ArrayRef = Arc::new(StringArray::from(vec!["u", "v"]));
+        let mut reader = FileReaderBuilder::new()
+            .with_dictionaries(HashMap::from([(1, seeded.clone())]))
+            .build(std::io::Cursor::new(buf))
+            .unwrap();
+        assert_eq!(reader.dictionaries().len(), 2);
+        assert_eq!(reader.dictionaries()[&1].as_ref(), seeded.as_ref());
+        let read = reader.next().unwrap().unwrap();
+        let a = read.column(0).as_dictionary::<Int8Type>();
+        assert_eq!(a.values().as_ref(), seeded.as_ref());
+        assert_eq!(read.column(2), batch.column(2));
+    }
+
+    /// Rewrite an encoded dictionary batch as a delta dictionary batch
+    fn to_delta(encoded: &crate::writer::EncodedData) -> crate::writer::EncodedData {
+        let message = root_as_message(&encoded.ipc_message).unwrap();
+        let dictionary = message.header_as_dictionary_batch().unwrap();
+        let data = dictionary.data().unwrap();
+
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let nodes: Vec<_> = data.nodes().unwrap().iter().copied().collect();
+        let nodes = fbb.create_vector(&nodes);
+        let buffers: Vec<_> = data.buffers().unwrap().iter().copied().collect();
+        let buffers = fbb.create_vector(&buffers);
+        let mut batch = crate::RecordBatchBuilder::new(&mut fbb);
+        batch.add_length(data.length());
+        batch.add_nodes(nodes);
+        batch.add_buffers(buffers);
+        let batch = batch.finish();
+
+        let mut delta = crate::DictionaryBatchBuilder::new(&mut fbb);
+        delta.add_id(dictionary.id());
+        delta.add_data(batch);
+        delta.add_isDelta(true);
+        let delta = delta.finish();
+
+        let mut builder = crate::MessageBuilder::new(&mut fbb);
+        builder.add_version(message.version());
+        builder.add_header_type(crate::MessageHeader::DictionaryBatch);
+        builder.add_bodyLength(message.bodyLength());
+        builder.add_header(delta.as_union_value());
+        let root = builder.finish();
+        fbb.finish(root, None);
+
+        crate::writer::EncodedData {
+            ipc_message: fbb.finished_data().to_vec(),
+            arrow_data: encoded.arrow_data.clone(),
+        }
+    }
+
+    #[test]
+    fn test_delta_dictionary() {
+        use crate::writer::{write_message, IpcWriteOptions};
+
+        let dict_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![Field::new_dict(
+            "d", dict_type, true, 0, false,
+        )]));
+        let batch = |keys: Vec<i32>, values: Vec<&str>| {
+            let array =
+                DictionaryArray::new(Int32Array::from(keys), Arc::new(StringArray::from(values)));
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+        };
+        let encode = |batch: &RecordBatch| {
+            let mut tracker = DictionaryTracker::new(false);
+            IpcDataGenerator::default()
+                .encoded_batch(batch, &mut tracker, &Default::default())
+                .unwrap()
+        };
+
+        let first = batch(vec![0, 1], vec!["a", "b"]);
+        let second = batch(vec![2, 0], vec!["a", "b", "c"]);
+        let (mut first_dicts, first_batch) = encode(&first);
+        let (_, second_batch) = encode(&second);
+        let (delta_dicts, _) = encode(&batch(vec![0], vec!["c"]));
+
+        let options = IpcWriteOptions::default();
+        let mut buf = vec![];
+        let schema_message = IpcDataGenerator::default().schema_to_bytes(&schema, &options);
+        write_message(&mut buf, schema_message, &options).unwrap();
+        write_message(&mut buf, first_dicts.remove(0), &options).unwrap();
+        write_message(&mut buf, first_batch, &options).unwrap();
+        write_message(&mut buf, to_delta(&delta_dicts[0]), &options).unwrap();
+        write_message(&mut buf, second_batch, &options).unwrap();
+
+        let reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![first, second]);
+
+        // A delta for a dictionary that has not been read is an error
+        let mut buf = vec![];
+        let schema_message = IpcDataGenerator::default().schema_to_bytes(&schema, &options);
+        write_message(&mut buf, schema_message, &options).unwrap();
+        write_message(&mut buf, to_delta(&delta_dicts[0]), &options).unwrap();
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Cannot apply delta to dictionary with id 0 as it has not been read"
         );
+    }
 
-        let union_data_type = DataType::Union(union_fields, UnionMode::Dense);
+    #[test]
+    fn test_try_new_auto() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            ("b", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+        ])
+        .unwrap();
+        let projected = batch.project(&[1]).unwrap();
+
+        let mut file = crate::writer::FileWriter::try_new(vec![], &batch.schema()).unwrap();
+        file.write(&batch).unwrap();
+        file.finish().unwrap();
+        let file = file.into_inner().unwrap();
+
+        let mut stream = crate::writer::StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+        stream.write(&batch).unwrap();
+        stream.finish().unwrap();
+        let stream = stream.into_inner().unwrap();
+
+        for buf in [file, stream] {
+            let reader = try_new_auto(std::io::Cursor::new(buf), Some(vec![1])).unwrap();
+            let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(batches, vec![projected.clone()]);
+        }
 
-        let struct_fields = Fields::from(vec![
-            Field::new("id", DataType::Int32, false),
-            Field::new_list("list", Field::new("item", DataType::Int8, true), false),
-        ]);
-        let struct_data_type = DataType::Struct(struct_fields);
+        // Inputs shorter than the magic are read as streams
+        let err = try_new_auto(std::io::Cursor::new(b"ARR"), None)
+            .err()
+            .unwrap();
+        assert!(matches!(err, ArrowError::IoError(_, _)), "{err}");
+    }
 
-        let run_encoded_data_type = DataType::RunEndEncoded(
-            Arc::new(Field::new("run_ends", DataType::Int16, false)),
-            Arc::new(Field::new("values", DataType::Int32, true)),
+    #[test]
+    fn test_file_reader_with_range() {
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            ("i", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("d", Arc::new(dict) as ArrayRef),
+        ])
+        .unwrap();
+        let mut writer = crate::writer::FileWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        let file = writer.into_inner().unwrap();
+
+        // Embed the file between a prefix and a suffix
+        let mut container = vec![0xAB; 13];
+        container.extend_from_slice(&file);
+        container.extend_from_slice(b"suffix");
+        let len = file.len() as u64;
+
+        let mut reader =
+            FileReader::try_new_with_range(std::io::Cursor::new(&container), 13, len).unwrap();
+        assert_eq!(reader.num_batches(), 2);
+        reader.set_index(1).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert!(reader.next().is_none());
+
+        let reader = FileReaderBuilder::new()
+            .with_projection(vec![1])
+            .build(RangeReader::try_new(std::io::Cursor::new(&container), 13, len).unwrap())
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![batch.project(&[1]).unwrap(); 2]);
+
+        // The magic is validated at the boundaries of the range
+        let err =
+            FileReader::try_new_with_range(std::io::Cursor::new(&container), 12, len).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Arrow file does not contain correct"),
+            "{err}"
+        );
+        assert!(FileReader::try_new(std::io::Cursor::new(&container), None).is_err());
+
+        let err = FileReader::try_new_with_range(std::io::Cursor::new(&container), 13, len + 7)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Invalid argument error: Range of {} bytes at offset 13 exceeds input of {} bytes",
+                len + 7,
+                container.len()
+            )
         );
+    }
 
-        // define schema
-        Schema::new(vec![
-            Field::new("f0", DataType::UInt32, false),
-            Field::new("f1", DataType::Utf8, false),
-            Field::new("f2", DataType::Boolean, false),
-            Field::new("f3", union_data_type, true),
-            Field::new("f4", DataType::Null, true),
-            Field::new("f5", DataType::Float64, true),
-            Field::new("f6", list_data_type, false),
-            Field::new("f7", DataType::FixedSizeBinary(3), true),
-            Field::new("f8", fixed_size_list_data_type, false),
-            Field::new("f9", struct_data_type, false),
-            Field::new("f10", run_encoded_data_type, false),
-            Field::new("f11", DataType::Boolean, false),
-            Field::new_dictionary("f12", DataType::Int8, DataType::Utf8, false),
-            Field::new("f13", DataType::Utf8, false),
+    #[test]
+    fn test_recover_truncated() {
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            ("i", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("d", Arc::new(dict) as ArrayRef),
         ])
+        .unwrap();
+        let mut writer = crate::writer::FileWriter::try_new(vec![], &batch.schema()).unwrap();
+        for _ in 0..3 {
+            writer.write(&batch).unwrap();
+        }
+        writer.finish().unwrap();
+        let file = writer.into_inner().unwrap();
+
+        let reader = FileReader::try_new(std::io::Cursor::new(&file), None).unwrap();
+        let blocks = reader.blocks().to_vec();
+        let block_end =
+            |b: &Block| (b.offset() + b.metaDataLength() as i64 + b.bodyLength()) as usize;
+        let builder = || FileReaderBuilder::new().with_recover_truncated(true);
+
+        // Files with a valid footer are read as normal
+        let reader = builder().build(std::io::Cursor::new(&file)).unwrap();
+        assert_eq!(reader.count(), 3);
+
+        // Truncated part way through the second batch
+        let truncated = &file[..block_end(&blocks[1]) - 5];
+        assert!(FileReader::try_new(std::io::Cursor::new(truncated), None).is_err());
+        let mut reader = builder().build(std::io::Cursor::new(truncated)).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Truncated input: Arrow file ends part way through the block at offset {}",
+                blocks[1].offset()
+            )
+        );
+        assert!(reader.next().is_none());
+
+        // Truncated at the end of the third batch, before the end-of-stream marker
+        let truncated = &file[..block_end(&blocks[2])];
+        let recovered = recover(std::io::Cursor::new(truncated)).unwrap();
+        assert!(!recovered.is_truncated());
+        assert_eq!(recovered.end(), truncated.len() as u64);
+        assert_eq!(recovered.dictionary_blocks().len(), 1);
+        assert_eq!(recovered.record_blocks(), blocks.as_slice());
+        let reader = builder().build(std::io::Cursor::new(truncated)).unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![batch.clone(); 3]);
+
+        // Repairing the file
+        let truncated = &file[..block_end(&blocks[2]) - 1];
+        let recovered = recover(std::io::Cursor::new(truncated)).unwrap();
+        assert!(recovered.is_truncated());
+        let mut repaired = truncated[..recovered.end() as usize].to_vec();
+        recovered.write_footer(&mut repaired).unwrap();
+        let reader = FileReader::try_new(std::io::Cursor::new(repaired), None).unwrap();
+        assert_eq!(reader.schema(), batch.schema());
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![batch.clone(); 2]);
+
+        // Truncated part way through the schema
+        let err = recover(std::io::Cursor::new(&file[..20])).unwrap_err();
+        assert!(matches!(err, ArrowError::Truncated(_)), "{err}");
+        let err = recover(std::io::Cursor::new(b"ARROW")).unwrap_err();
+        assert!(matches!(err, ArrowError::ParseError(_)), "{err}");
     }
 
-    fn create_test_projection_batch_data(schema: &Schema) -> RecordBatch {
-        // set test data for each column
-        let array0 = UInt32Array::from(vec![1, 2, 3]);
-        let array1 = StringArray::from(vec!["foo", "bar", "baz"]);
-        let array2 = BooleanArray::from(vec![true, false, true]);
+    #[test]
+    fn test_error_context() {
+        let strings = StringArray::from(vec!["valid", "xyzzy"]);
+        let batch = RecordBatch::try_from_iter([
+            ("i", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            ("s", Arc::new(strings) as ArrayRef),
+        ])
+        .unwrap();
 
-        let mut union_builder = UnionBuilder::new_dense();
-        union_builder.append::<Int32Type>("a", 1).unwrap();
-        union_builder.append::<Float64Type>("b", 10.1).unwrap();
-        union_builder.append_null::<Float64Type>("b").unwrap();
-        let array3 = union_builder.build().unwrap();
+        let mut buf = vec![];
+        let mut writer = crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
 
-        let array4 = NullArray::new(3);
-        let array5 = Float64Array::from(vec![Some(1.1), None, Some(3.3)]);
-        let array6_values = vec![
-            Some(vec![Some(10), Some(10), Some(10)]),
-            Some(vec![Some(20), Some(20), Some(20)]),
-            Some(vec![Some(30), Some(30)]),
-        ];
-        let array6 = ListArray::from_iter_primitive::<Int32Type, _, _>(array6_values);
-        let array7_values = vec![vec![11, 12, 13], vec![22, 23, 24], vec![33, 34, 35]];
-        let array7 = FixedSizeBinaryArray::try_from_iter(array7_values.into_iter()).unwrap();
+        // Replace a string with invalid UTF-8
+        let offset = buf.windows(5).position(|w| w == b"xyzzy").unwrap();
+        buf[offset] = 0xFF;
 
-        let array8_values = ArrayData::builder(DataType::Int32)
-            .len(9)
-            .add_buffer(Buffer::from_slice_ref([40, 41, 42, 43, 44, 45, 46, 47, 48]))
-            .build()
-            .unwrap();
-        let array8_data = ArrayData::builder(schema.field(8).data_type().clone())
-            .len(3)
-            .add_child_data(array8_values)
-            .build()
-            .unwrap();
-        let array8 = FixedSizeListArray::from(array8_data);
+        let mut reader = FileReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+        let offset = reader.blocks()[0].offset();
+        let err = reader.next().unwrap().unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.starts_with(&format!(
+                "reading record batch 0 at offset {offset}: reading column \"s\": "
+            )),
+            "{message}"
+        );
+        assert!(matches!(
+            err.root_cause(),
+            ArrowError::InvalidArgumentError(_)
+        ));
+        let contexts: Vec<_> = err.contexts().collect();
+        assert_eq!(contexts[0].offset, Some(offset as u64));
+        assert_eq!(contexts[1].column_path, ["s"]);
+    }
 
-        let array9_id: ArrayRef = Arc::new(Int32Array::from(vec![1001, 1002, 1003]));
-        let array9_list: ArrayRef =
-            Arc::new(ListArray::from_iter_primitive::<Int8Type, _, _>(vec![
-                Some(vec![Some(-10)]),
-                Some(vec![Some(-20), Some(-20), Some(-20)]),
-                Some(vec![Some(-30)]),
-            ]));
-        let array9 = ArrayDataBuilder::new(schema.field(9).data_type().clone())
-            .add_child_data(array9_id.into_data())
-            .add_child_data(array9_list.into_data())
-            .len(3)
-            .build()
+    #[test]
+    #[cfg(feature = "checksum")]
+    fn test_block_checksums() {
+        let dict: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            ("d", Arc::new(dict) as ArrayRef),
+            (
+                "i",
+                Arc::new(Int32Array::from(vec![0x0badcafe, 2, 3])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+
+        let write = |checksums: bool| {
+            let mut buf = vec![];
+            let mut writer = crate::writer::FileWriter::try_new(&mut buf, &batch.schema())
+                .unwrap()
+                .try_with_block_checksums(checksums)
+                .unwrap();
+            writer.write(&batch).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+            drop(writer);
+            buf
+        };
+
+        let buf = write(true);
+        let mut reader = FileReader::try_new(std::io::Cursor::new(buf.clone()), None).unwrap();
+        assert!(reader.verify_integrity().unwrap());
+        for read in reader {
+            assert_eq!(read.unwrap(), batch);
+        }
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(write(false)), None).unwrap();
+        assert!(!reader.verify_integrity().unwrap());
+
+        // Corrupt a value in the second record batch
+        let mut corrupted = buf.clone();
+        let pattern = 0x0badcafe_i32.to_le_bytes();
+        let offset = corrupted
+            .windows(4)
+            .enumerate()
+            .filter(|(_, w)| *w == pattern)
+            .nth(1)
+            .unwrap()
+            .0;
+        corrupted[offset] ^= 1;
+
+        let mut reader =
+            FileReader::try_new(std::io::Cursor::new(corrupted.clone()), None).unwrap();
+        let err = reader.verify_integrity().unwrap_err().to_string();
+        assert!(
+            err.contains("Checksum mismatch for record batch block 1"),
+            "{err}"
+        );
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        let err = reader.next().unwrap().unwrap_err().to_string();
+        assert!(
+            err.contains("Checksum mismatch for record batch block 1"),
+            "{err}"
+        );
+
+        let reader = FileReaderBuilder::new()
+            .with_verify_checksums(false)
+            .build(std::io::Cursor::new(corrupted))
             .unwrap();
-        let array9: ArrayRef = Arc::new(StructArray::from(array9));
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_ne!(batches[1], batch);
 
-        let array10_input = vec![Some(1_i32), None, None];
-        let mut array10_builder = PrimitiveRunBuilder::<Int16Type, Int32Type>::new();
-        array10_builder.extend(array10_input);
-        let array10 = array10_builder.finish();
+        // Checksums must be enabled before writing
+        let mut writer = crate::writer::FileWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        let err = writer.try_with_block_checksums(true).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Block checksums must be configured before writing record batches"
+        );
+    }
 
-        let array11 = BooleanArray::from(vec![false, false, true]);
+    #[test]
+    #[cfg(feature = "checksum")]
+    fn test_message_checksums() {
+        let dict: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            ("d", Arc::new(dict) as ArrayRef),
+            (
+                "i",
+                Arc::new(Int32Array::from(vec![0x0badcafe, 2, 3])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let options = crate::writer::IpcWriteOptions::default().with_message_checksums(true);
+        let metadata = HashMap::from([("key".to_string(), "value".to_string())]);
+
+        let mut buf = vec![];
+        let mut writer = crate::writer::StreamWriter::try_new_with_options(
+            &mut buf,
+            &batch.schema(),
+            options.clone(),
+        )
+        .unwrap();
+        writer.write(&batch).unwrap();
+        writer.write_with_metadata(&batch, &metadata).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
 
-        let array12_values = StringArray::from(vec!["x", "yy", "zzz"]);
-        let array12_keys = Int8Array::from_iter_values([1, 1, 2]);
-        let array12 = DictionaryArray::new(array12_keys, Arc::new(array12_values));
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        assert_eq!(
+            reader.next_with_metadata().unwrap().unwrap(),
+            (batch.clone(), HashMap::new())
+        );
+        assert_eq!(
+            reader.next_with_metadata().unwrap().unwrap(),
+            (batch.clone(), metadata)
+        );
+        assert!(reader.next().is_none());
+
+        // Corrupt a value in the second record batch
+        let pattern = 0x0badcafe_i32.to_le_bytes();
+        let offset = buf
+            .windows(4)
+            .enumerate()
+            .filter(|(_, w)| *w == pattern)
+            .nth(1)
+            .unwrap()
+            .0;
+        buf[offset] ^= 1;
+
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        let err = reader.next().unwrap().unwrap_err().to_string();
+        assert!(
+            err.contains("Checksum mismatch for RecordBatch message"),
+            "{err}"
+        );
 
-        let array13 = StringArray::from(vec!["a", "bb", "ccc"]);
+        // Files also store and verify message checksums
+        let mut buf = vec![];
+        let mut writer =
+            crate::writer::FileWriter::try_new_with_options(&mut buf, &batch.schema(), options)
+                .unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
 
-        // create record batch
-        RecordBatch::try_new(
-            Arc::new(schema.clone()),
-            vec![
-                Arc::new(array0),
-                Arc::new(array1),
-                Arc::new(array2),
-                Arc::new(array3),
-                Arc::new(array4),
-                Arc::new(array5),
-                Arc::new(array6),
-                Arc::new(array7),
-                Arc::new(array8),
-                Arc::new(array9),
-                Arc::new(array10),
-                Arc::new(array11),
-                Arc::new(array12),
-                Arc::new(array13),
-            ],
-        )
-        .unwrap()
+        let reader = FileReader::try_new(std::io::Cursor::new(buf.clone()), None).unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches, vec![batch]);
+
+        // Corrupt the dictionary values, which are read when opening the file
+        let offset = buf.windows(2).position(|w| w == b"ab").unwrap();
+        buf[offset] = b'c';
+        let err = FileReader::try_new(std::io::Cursor::new(buf), None)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(
+            err.contains("Checksum mismatch for DictionaryBatch message"),
+            "{err}"
+        );
     }
 
     #[test]
-    fn test_projection_array_values() {
-        // define schema
-        let schema = create_test_projection_schema();
+    #[cfg(not(feature = "checksum"))]
+    fn test_checksums_unverifiable() {
+        let batch = RecordBatch::try_from_iter([(
+            "i",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let expected =
+            "Not yet implemented: Unable to verify IPC checksums: checksum feature disabled";
 
-        // create record batch with test data
-        let batch = create_test_projection_batch_data(&schema);
+        // A file containing block checksums
+        let mut buf = vec![];
+        let mut writer = crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write_metadata(DICTIONARY_CHECKSUMS_KEY, "");
+        writer.write_metadata(RECORD_BATCH_CHECKSUMS_KEY, "00000000");
+        writer.finish().unwrap();
+        drop(writer);
 
-        // write record batch in IPC format
-        let mut buf = Vec::new();
-        {
-            let mut writer = crate::writer::FileWriter::try_new(&mut buf, &schema).unwrap();
-            writer.write(&batch).unwrap();
-            writer.finish().unwrap();
-        }
+        let err = FileReader::try_new(std::io::Cursor::new(buf.clone()), None).unwrap_err();
+        assert_eq!(err.to_string(), expected);
 
-        // read record batch with projection
-        for index in 0..12 {
-            let projection = vec![index];
-            let reader = FileReader::try_new(std::io::Cursor::new(buf.clone()), Some(projection));
-            let read_batch = reader.unwrap().next().unwrap().unwrap();
-            let projected_column = read_batch.column(0);
-            let expected_column = batch.column(index);
+        let mut reader = FileReaderBuilder::new()
+            .with_verify_checksums(false)
+            .build(std::io::Cursor::new(buf))
+            .unwrap();
+        assert_eq!(reader.verify_integrity().unwrap_err().to_string(), expected);
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
 
-            // check the projected column equals the expected column
-            assert_eq!(projected_column.as_ref(), expected_column.as_ref());
-        }
+        // A file containing message checksums
+        let metadata = HashMap::from([(MESSAGE_CHECKSUM_KEY.to_string(), "00000000".to_string())]);
+        let mut buf = vec![];
+        let mut writer = crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write_with_metadata(&batch, &metadata).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
 
-        {
-            // read record batch with reversed projection
-            let reader =
-                FileReader::try_new(std::io::Cursor::new(buf.clone()), Some(vec![3, 2, 1]));
-            let read_batch = reader.unwrap().next().unwrap().unwrap();
-            let expected_batch = batch.project(&[3, 2, 1]).unwrap();
-            assert_eq!(read_batch, expected_batch);
-        }
+        let mut reader = FileReader::try_new(std::io::Cursor::new(buf.clone()), None).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.root_cause().to_string(), expected);
+
+        let mut reader = FileReaderBuilder::new()
+            .with_verify_checksums(false)
+            .build(std::io::Cursor::new(buf))
+            .unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+    }
+
+    #[test]
+    #[cfg(not(feature = "checksum"))]
+    fn test_checksums_unavailable() {
+        let batch = RecordBatch::try_from_iter([(
+            "i",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let writer = crate::writer::FileWriter::try_new(vec![], &batch.schema()).unwrap();
+        let err = writer.try_with_block_checksums(true).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: IPC checksums require the checksum feature"
+        );
+
+        let options = crate::writer::IpcWriteOptions::default().with_message_checksums(true);
+        let mut writer =
+            crate::writer::StreamWriter::try_new_with_options(vec![], &batch.schema(), options)
+                .unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: IPC checksums require the checksum feature"
+        );
     }
 
     #[test]
@@ -1427,6 +4138,56 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_buffer_file_reader() {
+        let schema = create_test_projection_schema();
+        let batch = create_test_projection_batch_data(&schema);
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = crate::writer::FileWriter::try_new(&mut buf, &schema).unwrap();
+            writer.write(&batch).unwrap();
+            writer.write(&batch.slice(1, 2)).unwrap();
+            writer.finish().unwrap();
+        }
+        let buffer = Buffer::from_vec(buf.clone());
+
+        let reader = BufferFileReader::try_new(buffer.clone(), None).unwrap();
+        assert_eq!(reader.num_batches(), 2);
+        let read: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+        let expected: Vec<_> = FileReader::try_new(std::io::Cursor::new(buf), None)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read, expected);
+        assert_eq!(read[0], batch);
+
+        // The decoded arrays slice the buffer without copying
+        let range = buffer.as_ptr() as usize..buffer.as_ptr() as usize + buffer.len();
+        for column in read[0].columns() {
+            for b in column.to_data().buffers() {
+                assert!(range.contains(&(b.as_ptr() as usize)));
+            }
+        }
+
+        let mut reader = BufferFileReader::try_new(buffer.clone(), Some(vec![3, 2, 1])).unwrap();
+        reader.set_index(1).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read, batch.slice(1, 2).project(&[3, 2, 1]).unwrap());
+        assert!(reader.next().is_none());
+
+        let err = BufferFileReader::try_new(buffer.slice(buffer.len() - 4), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: File is too small to be an Arrow IPC file"
+        );
+        let err = BufferFileReader::try_new(buffer.slice(buffer.len() - 12), None).unwrap_err();
+        assert!(
+            err.to_string().contains("exceeds the size of the file"),
+            "{err}"
+        );
+    }
+
     fn roundtrip_ipc(rb: &RecordBatch) -> RecordBatch {
         let mut buf = Vec::new();
         let mut writer = crate::writer::FileWriter::try_new(&mut buf, &rb.schema()).unwrap();
@@ -1468,6 +4229,61 @@ mod tests {
         assert_eq!(reader.custom_metadata(), &test_metadata);
     }
 
+    #[test]
+    fn test_roundtrip_batch_custom_metadata() {
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([("d", Arc::new(dict) as ArrayRef)]).unwrap();
+        let metadata: Vec<HashMap<String, String>> = vec![
+            [("watermark".to_string(), "10".to_string())].into(),
+            HashMap::new(),
+            [("offset".to_string(), "42".to_string())].into(),
+        ];
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        for m in &metadata {
+            writer.write_with_metadata(&batch, m).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+        let read: Vec<_> = reader
+            .batches_with_metadata()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read.len(), 3);
+        for ((b, m), expected) in read.iter().zip(&metadata) {
+            assert_eq!(b, &batch);
+            assert_eq!(m, expected);
+        }
+        reader.set_index(2).unwrap();
+        let (_, m) = reader.next_with_metadata().unwrap().unwrap();
+        assert_eq!(m, metadata[2]);
+        assert!(reader.next_with_metadata().is_none());
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::StreamWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        for m in &metadata {
+            writer.write_with_metadata(&batch, m).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(&buf), None).unwrap();
+        let (b, m) = reader.next_with_metadata().unwrap().unwrap();
+        assert_eq!((&b, &m), (&batch, &metadata[0]));
+        let read: Vec<_> = reader
+            .batches_with_metadata()
+            .map(|r| r.unwrap().1)
+            .collect();
+        assert_eq!(read, &metadata[1..]);
+
+        // The plain iterator ignores the metadata
+        let reader = StreamReader::try_new(std::io::Cursor::new(&buf), None).unwrap();
+        assert_eq!(reader.count(), 3);
+    }
+
     #[test]
     fn test_roundtrip_nested_dict() {
         let inner: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
@@ -1529,6 +4345,106 @@ mod tests {
         check_union_with_builder(UnionBuilder::new_sparse());
     }
 
+    #[test]
+    fn test_read_invalid_union() {
+        // Reads a union of type ids 0 and 3, whose dense children are written with a
+        // length of 1, after applying `patch` to the encoded type ids and offsets
+        type Patch<'a> = &'a dyn Fn(&mut [i8], &mut [i32]);
+        let read = |mode: UnionMode, patch: Patch| {
+            let children = vec![
+                (
+                    Field::new("a", DataType::Int32, true),
+                    Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+                ),
+                (
+                    Field::new("b", DataType::Float64, true),
+                    Arc::new(Float64Array::from(vec![3.0, 4.0])) as ArrayRef,
+                ),
+            ];
+            let offsets = match mode {
+                UnionMode::Dense => Some(Buffer::from_vec(vec![0_i32, 0])),
+                UnionMode::Sparse => None,
+            };
+            let type_ids = Buffer::from_vec(vec![0_i8, 3]);
+            let union = UnionArray::try_new(&[0, 3], type_ids, offsets, children).unwrap();
+            let batch = RecordBatch::try_from_iter([("u", Arc::new(union) as ArrayRef)]).unwrap();
+
+            let mut tracker = DictionaryTracker::new(false);
+            let (_, encoded) = IpcDataGenerator {}
+                .encoded_batch(&batch, &mut tracker, &Default::default())
+                .unwrap();
+            let message = root_as_message(&encoded.ipc_message).unwrap();
+            let ipc_batch = message.header_as_record_batch().unwrap();
+
+            let mut data = encoded.arrow_data.clone();
+            let buffers = ipc_batch.buffers().unwrap();
+            let mut type_ids = data[buffers.get(0).offset() as usize..][..2]
+                .iter()
+                .map(|b| *b as i8)
+                .collect::<Vec<_>>();
+            let mut offsets = vec![0; 2];
+            patch(&mut type_ids, &mut offsets);
+            let start = buffers.get(0).offset() as usize;
+            for (idx, type_id) in type_ids.iter().enumerate() {
+                data[start + idx] = *type_id as u8;
+            }
+            if mode == UnionMode::Dense {
+                let start = buffers.get(1).offset() as usize;
+                let bytes = offsets.iter().flat_map(|o| o.to_le_bytes());
+                data[start..start + 8].copy_from_slice(&bytes.collect::<Vec<_>>());
+            }
+
+            let buffer = Buffer::from_vec(data);
+            let version = message.version();
+            read_record_batch(
+                &buffer,
+                ipc_batch,
+                batch.schema(),
+                &HashMap::new(),
+                None,
+                &version,
+            )
+        };
+
+        assert!(read(UnionMode::Dense, &|_, _| {}).is_ok());
+        assert!(read(UnionMode::Sparse, &|t, _| t.swap(0, 1)).is_ok());
+
+        let cases: [(UnionMode, Patch, &str); 5] = [
+            (
+                UnionMode::Dense,
+                &|t, _| t[1] = 1,
+                "Type Id 1 is not one of the union's type ids",
+            ),
+            (
+                UnionMode::Sparse,
+                &|t, _| t[0] = 2,
+                "Type Id 2 is not one of the union's type ids",
+            ),
+            (
+                UnionMode::Sparse,
+                &|t, _| t[0] = -1,
+                "Type Id -1 is not one of the union's type ids",
+            ),
+            (
+                UnionMode::Dense,
+                &|_, o| o[1] = 1,
+                "Offset 1 of type Id 3 is out of bounds for a child of length 1",
+            ),
+            (
+                UnionMode::Dense,
+                &|_, o| o[0] = -1,
+                "Offset -1 of type Id 0 is out of bounds for a child of length 1",
+            ),
+        ];
+        for (mode, patch, expected) in cases {
+            let err = read(mode, patch).unwrap_err();
+            assert_eq!(
+                err.root_cause().to_string(),
+                format!("Invalid argument error: {expected}")
+            );
+        }
+    }
+
     #[test]
     fn test_roundtrip_stream_run_array_sliced() {
         let run_array_1: Int32RunArray = vec!["a", "a", "a", "b", "b", "c", "c", "c"]
@@ -1855,4 +4771,312 @@ mod tests {
 
         assert_eq!(batch, roundtrip_batch);
     }
+
+    /// Writes a batch with an integer column and two string columns, replacing the
+    /// string data of the last column with invalid UTF-8
+    fn invalid_utf8_file() -> Vec<u8> {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from(vec![1, 2])) as _),
+            ("b", Arc::new(StringArray::from(vec!["foo", "bar"])) as _),
+            (
+                "c",
+                Arc::new(StringArray::from(vec!["xyzzy", "plugh"])) as _,
+            ),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let start = buf.windows(5).position(|w| w == b"xyzzy").unwrap();
+        buf[start] = 0xFF;
+        buf
+    }
+
+    #[test]
+    fn test_skip_validation() {
+        use arrow_array::cast::AsArray;
+
+        let buf = invalid_utf8_file();
+
+        let err = FileReader::try_new(std::io::Cursor::new(buf.clone()), None)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid UTF8 sequence"), "{err}");
+
+        // Skipping validation of other columns still validates column "c"
+        let builder = unsafe { FileReaderBuilder::new().with_skip_validation(vec![0, 1]) };
+        let mut reader = builder.build(std::io::Cursor::new(buf.clone())).unwrap();
+        assert!(reader.next().unwrap().is_err());
+
+        let builder = unsafe { FileReaderBuilder::new().with_skip_validation(vec![2]) };
+        let mut reader = builder.build(std::io::Cursor::new(buf)).unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.column(1).as_string::<i32>().value(1), "bar");
+    }
+
+    #[test]
+    fn test_read_limits() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![Some(2), Some(3)]),
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+            ("b", Arc::new(list) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut file = Vec::new();
+        let mut writer = crate::writer::FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut stream = Vec::new();
+        let mut writer =
+            crate::writer::StreamWriter::try_new(&mut stream, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let read_file = |limits: ReadLimits| {
+            FileReaderBuilder::new()
+                .with_limits(limits)
+                .build(std::io::Cursor::new(&file))
+                .and_then(|mut reader| reader.next().unwrap())
+        };
+        let read_stream = |limits: ReadLimits| {
+            StreamReader::try_new_with_limits(stream.as_slice(), None, limits)
+                .and_then(|mut reader| reader.next().unwrap())
+        };
+
+        // Limits that are not exceeded do not affect reading
+        let limits = ReadLimits::new()
+            .with_max_message_size(1024)
+            .with_max_body_size(1024)
+            .with_max_fields(3);
+        assert_eq!(read_file(limits).unwrap(), batch);
+        assert_eq!(read_stream(limits).unwrap(), batch);
+
+        let limits = ReadLimits::new().with_max_fields(2);
+        for err in [
+            read_file(limits).unwrap_err(),
+            read_stream(limits).unwrap_err(),
+        ] {
+            assert_eq!(
+                err.to_string(),
+                "Ipc error: IPC schema with 3 fields exceeds the limit of 2 fields"
+            );
+        }
+
+        let limits = ReadLimits::new().with_max_body_size(64);
+        for err in [
+            read_file(limits).unwrap_err(),
+            read_stream(limits).unwrap_err(),
+        ] {
+            assert!(
+                err.to_string()
+                    .contains("bytes exceeds the limit of 64 bytes"),
+                "{err}"
+            );
+        }
+
+        let limits = ReadLimits::new().with_max_message_size(64);
+        for err in [
+            read_file(limits).unwrap_err(),
+            read_stream(limits).unwrap_err(),
+        ] {
+            assert!(
+                err.to_string()
+                    .starts_with("Ipc error: IPC message metadata of "),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_corrupt_message_length() {
+        // A message claiming to be i32::MAX bytes fails once the input is exhausted,
+        // rather than allocating the claimed size upfront
+        let mut stream = CONTINUATION_MARKER.to_vec();
+        stream.extend_from_slice(&i32::MAX.to_le_bytes());
+        stream.extend_from_slice(&[0; 16]);
+        let err = StreamReader::try_new(stream.as_slice(), None).unwrap_err();
+        assert_eq!(err.to_string(), "Io error: failed to fill whole buffer");
+
+        let mut stream = CONTINUATION_MARKER.to_vec();
+        stream.extend_from_slice(&(-8_i32).to_le_bytes());
+        let err = StreamReader::try_new(stream.as_slice(), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Invalid IPC message metadata length -8"
+        );
+    }
+
+    #[test]
+    fn test_stream_skip_validation() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(StringArray::from(vec!["xyzzy"])) as _),
+            ("b", Arc::new(Int32Array::from(vec![1])) as _),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::StreamWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let start = buf.windows(5).position(|w| w == b"xyzzy").unwrap();
+        buf[start] = 0xFF;
+
+        let mut reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        assert!(reader.next().unwrap().is_err());
+
+        let reader = StreamReader::try_new(buf.as_slice(), Some(vec![0])).unwrap();
+        let mut reader = unsafe { reader.with_skip_validation(vec![0]) };
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_columns(), 1);
+        assert_eq!(batch.num_rows(), 1);
+    }
+
+    #[test]
+    fn test_read_non_native_endianness() {
+        let other = match NATIVE_ENDIANNESS {
+            crate::Endianness::Little => crate::Endianness::Big,
+            _ => crate::Endianness::Little,
+        };
+        let options = crate::writer::IpcWriteOptions::default()
+            .try_with_endianness(other)
+            .unwrap();
+
+        let schema = create_test_projection_schema();
+        let nested = create_test_projection_batch_data(&schema);
+
+        let dictionary: DictionaryArray<Int16Type> =
+            vec![Some("a"), None, Some("bc")].into_iter().collect();
+        let primitive = RecordBatch::try_from_iter([
+            (
+                "i64",
+                Arc::new(Int64Array::from(vec![1, -2, 3])) as ArrayRef,
+            ),
+            (
+                "decimal128",
+                Arc::new(
+                    Decimal128Array::from(vec![Some(i128::MAX), None, Some(-1)])
+                        .with_precision_and_scale(38, 2)
+                        .unwrap(),
+                ),
+            ),
+            (
+                "decimal256",
+                Arc::new(Decimal256Array::from(vec![
+                    i256::from_i128(-12345),
+                    i256::MAX,
+                    i256::ZERO,
+                ])),
+            ),
+            (
+                "day_time",
+                Arc::new(IntervalDayTimeArray::from(vec![
+                    IntervalDayTimeType::make_value(1, -2),
+                    IntervalDayTimeType::make_value(3, 4),
+                    IntervalDayTimeType::make_value(-5, 6),
+                ])),
+            ),
+            (
+                "month_day_nano",
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    IntervalMonthDayNanoType::make_value(1, 2, 3),
+                    IntervalMonthDayNanoType::make_value(-4, 5, -6),
+                    IntervalMonthDayNanoType::make_value(7, -8, 9),
+                ])),
+            ),
+            (
+                "large_utf8",
+                Arc::new(LargeStringArray::from(vec!["a", "", "bcd"])),
+            ),
+            ("dictionary", Arc::new(dictionary)),
+        ])
+        .unwrap();
+
+        for batch in [nested, primitive] {
+            let mut writer = crate::writer::FileWriter::try_new_with_options(
+                vec![],
+                &batch.schema(),
+                options.clone(),
+            )
+            .unwrap();
+            writer.write(&batch).unwrap();
+            let file = writer.into_inner().unwrap();
+
+            let mut native = crate::writer::FileWriter::try_new(vec![], &batch.schema()).unwrap();
+            native.write(&batch).unwrap();
+            assert_ne!(file, native.into_inner().unwrap());
+
+            let mut reader = FileReader::try_new(std::io::Cursor::new(file), None).unwrap();
+            assert_eq!(reader.next().unwrap().unwrap(), batch);
+            assert!(reader.next().is_none());
+
+            let mut writer = crate::writer::StreamWriter::try_new_with_options(
+                vec![],
+                &batch.schema(),
+                options.clone(),
+            )
+            .unwrap();
+            writer.write(&batch).unwrap();
+            let stream = writer.into_inner().unwrap();
+
+            let mut reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+            assert_eq!(reader.next().unwrap().unwrap(), batch);
+            assert!(reader.next().is_none());
+
+            let mut decoder = StreamDecoder::new();
+            let mut buffer = Buffer::from_vec(stream);
+            let decoded = decoder.decode(&mut buffer).unwrap().unwrap();
+            assert_eq!(decoded, batch);
+        }
+    }
+
+    #[test]
+    fn test_stream_reader_builder() {
+        let schema = create_test_projection_schema();
+        let batch = create_test_projection_batch_data(&schema);
+        let mut writer = crate::writer::StreamWriter::try_new(vec![], &schema).unwrap();
+        writer.write(&batch).unwrap();
+        let stream = writer.into_inner().unwrap();
+
+        // SAFETY: the data was written by StreamWriter and so is valid
+        let builder = unsafe { StreamReaderBuilder::new().with_skip_validation(vec![1, 13]) };
+        let mut reader = builder
+            .with_projection(vec![13, 1])
+            .build(stream.as_slice())
+            .unwrap();
+        assert_eq!(reader.skip_validation, vec![1, 13]);
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read, batch.project(&[13, 1]).unwrap());
+        assert!(reader.next().is_none());
+
+        let mut reader = StreamReaderBuilder::new()
+            .build_unbuffered(stream.as_slice())
+            .unwrap();
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+
+        let err = StreamReaderBuilder::new()
+            .with_limits(ReadLimits::new().with_max_fields(2))
+            .build(stream.as_slice())
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("exceeds the limit of 2 fields"),
+            "{err}"
+        );
+    }
 }
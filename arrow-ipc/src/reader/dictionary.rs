@@ -0,0 +1,226 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::sync::RwLock;
+
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::Schema;
+
+use crate::reader::collect_dictionary_ids;
+use crate::Message;
+
+/// A store of decoded dictionaries, keyed by dictionary id, that can be shared by
+/// several [`StreamReader`] or [`StreamDecoder`]
+///
+/// Readers decoding shards of the same dataset typically decode identical
+/// dictionaries. By sharing a [`DictionaryProvider`], such as [`SharedDictionaries`],
+/// each distinct dictionary is held in memory once, and a shard that does not contain
+/// a dictionary batch for a given id can use the dictionary decoded by another reader.
+///
+/// Dictionaries decoded by a reader take precedence over those in the provider, so
+/// that each reader still observes the dictionary batches of its own stream.
+///
+/// [`StreamReader`]: crate::reader::StreamReader
+/// [`StreamDecoder`]: crate::reader::StreamDecoder
+pub trait DictionaryProvider: Debug + Send + Sync {
+    /// Returns the values of the dictionary with the given id, if any
+    fn get(&self, id: i64) -> Option<ArrayRef>;
+
+    /// Stores the values of the dictionary with the given id, decoded by a reader,
+    /// returning the values the reader should use for subsequent record batches
+    ///
+    /// Implementations may return previously stored values equal to `values`,
+    /// allowing the decoded copy to be dropped
+    fn insert(&self, id: i64, values: ArrayRef) -> ArrayRef;
+}
+
+/// A thread-safe [`DictionaryProvider`] that deduplicates equal dictionaries
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::{ArrayRef, DictionaryArray, RecordBatch};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ipc::reader::{SharedDictionaries, StreamReaderBuilder};
+/// # use arrow_ipc::writer::StreamWriter;
+/// let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+/// let batch = RecordBatch::try_from_iter([("d", Arc::new(dict) as ArrayRef)]).unwrap();
+///
+/// let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// let shard = writer.into_inner().unwrap();
+///
+/// let dictionaries = Arc::new(SharedDictionaries::new());
+/// let mut decoded = vec![];
+/// for _ in 0..2 {
+///     let reader = StreamReaderBuilder::new()
+///         .with_dictionary_provider(dictionaries.clone())
+///         .build(shard.as_slice())
+///         .unwrap();
+///     for batch in reader {
+///         decoded.push(batch.unwrap());
+///     }
+/// }
+///
+/// // Both shards reference the same dictionary values
+/// let values = |batch: &RecordBatch| {
+///     let values = batch.column(0).as_any_dictionary().values();
+///     values.as_string::<i32>().values().as_ptr()
+/// };
+/// assert_eq!(values(&decoded[0]), values(&decoded[1]));
+/// assert_eq!(dictionaries.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct SharedDictionaries {
+    dictionaries: RwLock<HashMap<i64, ArrayRef>>,
+}
+
+impl SharedDictionaries {
+    /// Create a new, empty, [`SharedDictionaries`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of dictionaries stored
+    pub fn len(&self) -> usize {
+        self.dictionaries.read().unwrap().len()
+    }
+
+    /// Returns `true` if no dictionaries are stored
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all stored dictionaries
+    pub fn clear(&self) {
+        self.dictionaries.write().unwrap().clear()
+    }
+}
+
+impl DictionaryProvider for SharedDictionaries {
+    fn get(&self, id: i64) -> Option<ArrayRef> {
+        self.dictionaries.read().unwrap().get(&id).cloned()
+    }
+
+    fn insert(&self, id: i64, values: ArrayRef) -> ArrayRef {
+        let mut dictionaries = self.dictionaries.write().unwrap();
+        match dictionaries.get(&id) {
+            Some(existing) if existing.to_data() == values.to_data() => existing.clone(),
+            _ => {
+                dictionaries.insert(id, values.clone());
+                values
+            }
+        }
+    }
+}
+
+/// Adds the dictionaries from `provider` needed to decode `message`, and not already
+/// decoded by the reader, to `dictionaries_by_id`
+pub(crate) fn load_dictionaries(
+    provider: &dyn DictionaryProvider,
+    message: &Message<'_>,
+    schema: &Schema,
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+) {
+    let ids = match message.header_type() {
+        crate::MessageHeader::DictionaryBatch => match message.header_as_dictionary_batch() {
+            Some(batch) => HashSet::from([batch.id()]),
+            None => return,
+        },
+        crate::MessageHeader::RecordBatch => {
+            let mut ids = HashSet::new();
+            for field in schema.fields() {
+                collect_dictionary_ids(field, &mut ids);
+            }
+            ids
+        }
+        _ => return,
+    };
+    for id in ids {
+        if dictionaries_by_id.contains_key(&id) {
+            continue;
+        }
+        if let Some(values) = provider.get(id) {
+            dictionaries_by_id.insert(id, values);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::{StreamDecoder, StreamReaderBuilder};
+    use crate::writer::StreamWriter;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{DictionaryArray, RecordBatch};
+    use arrow_buffer::Buffer;
+    use std::sync::Arc;
+
+    fn shard(values: Vec<&str>) -> (RecordBatch, Vec<u8>) {
+        let dict: DictionaryArray<Int32Type> = values.into_iter().collect();
+        let batch = RecordBatch::try_from_iter([("d", Arc::new(dict) as ArrayRef)]).unwrap();
+        let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        (batch, writer.into_inner().unwrap())
+    }
+
+    fn values_ptr(batch: &RecordBatch) -> *const u8 {
+        let values = batch.column(0).as_any_dictionary().values();
+        values.as_string::<i32>().values().as_ptr()
+    }
+
+    #[test]
+    fn test_shared_dictionaries() {
+        let (a, a_bytes) = shard(vec!["a", "b", "a"]);
+        let (b, b_bytes) = shard(vec!["x", "y"]);
+        let provider = Arc::new(SharedDictionaries::new());
+        assert!(provider.is_empty());
+
+        let read = |bytes: &[u8]| {
+            let reader = StreamReaderBuilder::new()
+                .with_dictionary_provider(provider.clone())
+                .build(bytes)
+                .unwrap();
+            reader.collect::<Result<Vec<_>, _>>().unwrap()
+        };
+
+        let first = read(&a_bytes);
+        assert_eq!(first, vec![a.clone(), a.clone()]);
+        let second = read(&a_bytes);
+        assert_eq!(values_ptr(&first[0]), values_ptr(&second[1]));
+        assert_eq!(provider.len(), 1);
+
+        // Each reader observes the dictionaries of its own stream
+        let third = read(&b_bytes);
+        assert_eq!(third, vec![b.clone(), b]);
+        assert_ne!(values_ptr(&first[0]), values_ptr(&third[0]));
+        assert_eq!(provider.len(), 1);
+
+        // StreamDecoder shares the same provider
+        let mut decoder = StreamDecoder::new().with_dictionary_provider(provider.clone());
+        let mut buffer = Buffer::from_vec(b_bytes);
+        let decoded = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(values_ptr(&decoded), values_ptr(&third[0]));
+
+        provider.clear();
+        assert!(provider.is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Field, Fields, Schema};
+
+/// A mask selecting the leaf fields of a [`Schema`] to read, allowing only some
+/// of the children of struct and list columns to be decoded
+///
+/// The leaves are numbered depth-first, with struct, list, large list and fixed size
+/// list fields containing the leaves of their children, and all other fields,
+/// including maps, unions and dictionaries, being leaves themselves. For example, the
+/// schema `a: Int32, b: Struct<c: Utf8, d: List<Int64>>, e: Map<..>` has the leaves
+/// `a`, `b.c`, `b.d.item` and `e`, with indices 0 to 3 respectively.
+///
+/// Struct fields with none of their leaves selected are omitted, as are list fields
+/// whose values are not selected. The buffers of omitted fields are skipped without
+/// being decoded.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray, StructArray};
+/// # use arrow_ipc::reader::{ProjectionMask, StreamReaderBuilder};
+/// # use arrow_ipc::writer::StreamWriter;
+/// let s = StructArray::try_from(vec![
+///     ("b", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+///     ("c", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+/// ]).unwrap();
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef),
+///     ("s", Arc::new(s) as ArrayRef),
+/// ]).unwrap();
+/// # let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # let stream = writer.into_inner().unwrap();
+///
+/// // Select `s.c`, the third leaf
+/// let mask = ProjectionMask::leaves(&batch.schema(), [2]);
+/// let mut reader = StreamReaderBuilder::new()
+///     .with_projection_mask(mask)
+///     .build(stream.as_slice())
+///     .unwrap();
+///
+/// let read = reader.next().unwrap().unwrap();
+/// assert_eq!(read.num_columns(), 1);
+/// assert_eq!(read.schema().field(0).name(), "s");
+/// assert_eq!(read.column(0).as_any().downcast_ref::<StructArray>().unwrap().num_columns(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectionMask {
+    /// Whether each leaf is selected, `None` if all leaves are selected
+    mask: Option<Vec<bool>>,
+}
+
+impl ProjectionMask {
+    /// Create a [`ProjectionMask`] which selects all leaves
+    pub fn all() -> Self {
+        Self { mask: None }
+    }
+
+    /// Create a [`ProjectionMask`] which selects the leaves of `schema` with the
+    /// given depth-first `indices`
+    ///
+    /// Indices out of range of the leaves of `schema` are ignored
+    pub fn leaves(schema: &Schema, indices: impl IntoIterator<Item = usize>) -> Self {
+        let num_leaves = schema.fields().iter().map(|f| num_leaves(f)).sum();
+        let mut mask = vec![false; num_leaves];
+        for idx in indices {
+            if let Some(selected) = mask.get_mut(idx) {
+                *selected = true;
+            }
+        }
+        Self { mask: Some(mask) }
+    }
+
+    /// Create a [`ProjectionMask`] which selects all leaves of the top-level fields
+    /// of `schema` with the given `indices`
+    ///
+    /// Indices out of range of the fields of `schema` are ignored
+    pub fn roots(schema: &Schema, indices: impl IntoIterator<Item = usize>) -> Self {
+        let counts: Vec<usize> = schema.fields().iter().map(|f| num_leaves(f)).collect();
+        let mut roots = vec![false; counts.len()];
+        for idx in indices {
+            if let Some(selected) = roots.get_mut(idx) {
+                *selected = true;
+            }
+        }
+        let mask = roots
+            .iter()
+            .zip(&counts)
+            .flat_map(|(selected, count)| std::iter::repeat(*selected).take(*count))
+            .collect();
+        Self { mask: Some(mask) }
+    }
+
+    /// Returns true if the leaf with depth-first index `idx` is selected
+    pub fn leaf_included(&self, idx: usize) -> bool {
+        match &self.mask {
+            Some(mask) => mask.get(idx).copied().unwrap_or(false),
+            None => true,
+        }
+    }
+
+    /// Returns the [`FieldProjection`] of each top-level field of `schema`
+    pub(crate) fn field_projections(&self, schema: &Schema) -> Vec<FieldProjection> {
+        let mut leaf = 0;
+        schema
+            .fields()
+            .iter()
+            .map(|f| self.field_projection(f, &mut leaf))
+            .collect()
+    }
+
+    fn field_projection(&self, field: &Field, leaf: &mut usize) -> FieldProjection {
+        let children = match nested_children(field.data_type()) {
+            Some(children) => children,
+            None => {
+                let included = self.leaf_included(*leaf);
+                *leaf += 1;
+                return match included {
+                    true => FieldProjection::All,
+                    false => FieldProjection::Skip,
+                };
+            }
+        };
+
+        let children: Vec<_> = children
+            .iter()
+            .map(|c| self.field_projection(c, leaf))
+            .collect();
+
+        if children.iter().all(|c| matches!(c, FieldProjection::All)) {
+            FieldProjection::All
+        } else if children.iter().all(|c| matches!(c, FieldProjection::Skip)) {
+            FieldProjection::Skip
+        } else {
+            FieldProjection::Children(children)
+        }
+    }
+}
+
+/// The projection of a field, see [`ProjectionMask::field_projections`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FieldProjection {
+    /// The field is not read
+    Skip,
+    /// The field and all its descendants are read
+    All,
+    /// The field is read with the given projection of each of its children
+    Children(Vec<FieldProjection>),
+}
+
+impl FieldProjection {
+    /// Returns the projection of the child with index `idx`
+    pub(crate) fn child(&self, idx: usize) -> &Self {
+        match self {
+            Self::Children(children) => &children[idx],
+            _ => self,
+        }
+    }
+
+    /// Returns `data_type` with this projection applied, or `None` if skipped
+    pub(crate) fn project_data_type(&self, data_type: &DataType) -> Option<DataType> {
+        let children = match self {
+            Self::Skip => return None,
+            Self::All => return Some(data_type.clone()),
+            Self::Children(children) => children,
+        };
+        let project = |f: &Arc<Field>, p: &Self| {
+            let data_type = p.project_data_type(f.data_type())?;
+            Some(Arc::new(f.as_ref().clone().with_data_type(data_type)))
+        };
+        Some(match data_type {
+            DataType::Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .zip(children)
+                    .filter_map(|(f, p)| project(f, p))
+                    .collect::<Fields>(),
+            ),
+            DataType::List(f) => DataType::List(project(f, &children[0])?),
+            DataType::LargeList(f) => DataType::LargeList(project(f, &children[0])?),
+            DataType::FixedSizeList(f, size) => {
+                DataType::FixedSizeList(project(f, &children[0])?, *size)
+            }
+            d => d.clone(),
+        })
+    }
+
+    /// Returns `field` with this projection applied, or `None` if skipped
+    pub(crate) fn project_field(&self, field: &Field) -> Option<Field> {
+        let data_type = self.project_data_type(field.data_type())?;
+        Some(field.clone().with_data_type(data_type))
+    }
+}
+
+/// Returns the children of `data_type` that may be projected, or `None` for a leaf
+fn nested_children(data_type: &DataType) -> Option<Vec<&Field>> {
+    match data_type {
+        DataType::Struct(fields) => Some(fields.iter().map(|f| f.as_ref()).collect()),
+        DataType::List(f) | DataType::LargeList(f) | DataType::FixedSizeList(f, _) => {
+            Some(vec![f.as_ref()])
+        }
+        _ => None,
+    }
+}
+
+/// Returns the number of leaves of `field`, see [`ProjectionMask`]
+fn num_leaves(field: &Field) -> usize {
+    match nested_children(field.data_type()) {
+        Some(children) => children.into_iter().map(num_leaves).sum(),
+        None => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> Schema {
+        let item = Field::new("item", DataType::Int64, true);
+        let b = Fields::from(vec![
+            Field::new("c", DataType::Utf8, true),
+            Field::new_list("d", item, true),
+        ]);
+        let key = Field::new("key", DataType::Utf8, false);
+        let value = Field::new("value", DataType::Int32, true);
+        Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new_struct("b", b, true),
+            Field::new_map("e", "entries", key, value, false, true),
+        ])
+    }
+
+    #[test]
+    fn test_field_projections() {
+        let schema = schema();
+        let leaves: Vec<_> = schema.fields().iter().map(|f| num_leaves(f)).collect();
+        assert_eq!(leaves, vec![1, 2, 1]);
+
+        let all = ProjectionMask::all().field_projections(&schema);
+        assert!(all.iter().all(|p| p == &FieldProjection::All));
+
+        let roots = ProjectionMask::roots(&schema, [1, 5]);
+        assert_eq!(roots, ProjectionMask::leaves(&schema, [1, 2]));
+        let projections = roots.field_projections(&schema);
+        use FieldProjection::*;
+        assert_eq!(projections, vec![Skip, All, Skip]);
+
+        let mask = ProjectionMask::leaves(&schema, [2, 3, 10]);
+        let projections = mask.field_projections(&schema);
+        assert_eq!(projections, vec![Skip, Children(vec![Skip, All]), All]);
+
+        let b = projections[1].project_field(schema.field(1)).unwrap();
+        let item = Field::new("item", DataType::Int64, true);
+        let expected = Field::new_struct("b", vec![Field::new_list("d", item, true)], true);
+        assert_eq!(b, expected);
+        assert_eq!(projections[0].project_field(schema.field(0)), None);
+    }
+}
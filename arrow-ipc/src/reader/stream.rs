@@ -0,0 +1,432 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::{ArrowError, SchemaRef};
+
+use crate::reader::{
+    decode_stream_message, read_schema_message, stream_message_body_len, DictionaryProvider,
+    RecordBatchWithMetadata,
+};
+use crate::CONTINUATION_MARKER;
+
+/// A low-level interface for reading [`RecordBatch`] data from a stream of bytes
+///
+/// Unlike [`StreamReader`](crate::reader::StreamReader), which reads from a blocking
+/// [`Read`](std::io::Read), bytes are pushed into a [`StreamDecoder`] as they become
+/// available, for example from a network socket, making it suitable for async or
+/// event-driven ingestion. Messages contained entirely within a pushed [`Buffer`]
+/// are decoded without copying.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_buffer::Buffer;
+/// # use arrow_ipc::reader::StreamDecoder;
+/// # use arrow_ipc::writer::StreamWriter;
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+/// ]).unwrap();
+///
+/// let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.finish().unwrap();
+/// let bytes = writer.into_inner().unwrap();
+///
+/// let mut decoder = StreamDecoder::new();
+/// let mut batches = vec![];
+/// // Push the data in chunks of 100 bytes, as it might be received from a socket
+/// for chunk in bytes.chunks(100) {
+///     let mut buffer = Buffer::from_slice_ref(chunk);
+///     while let Some(batch) = decoder.decode(&mut buffer).unwrap() {
+///         batches.push(batch);
+///     }
+/// }
+/// decoder.finish().unwrap();
+/// assert_eq!(batches, vec![batch.clone(), batch]);
+/// ```
+#[derive(Debug, Default)]
+pub struct StreamDecoder {
+    /// The schema of the stream, decoded from its first message
+    schema: Option<SchemaRef>,
+    /// The endianness of the stream, as recorded in its schema
+    endianness: crate::Endianness,
+    /// The dictionaries decoded so far, keyed by dictionary id
+    dictionaries: HashMap<i64, ArrayRef>,
+    /// The state of the decoder
+    state: DecoderState,
+    /// Partially received data of the current message
+    buf: MutableBuffer,
+    /// Columns for which to skip validation of the decoded arrays
+    skip_validation: Vec<usize>,
+    /// Dictionaries shared with other readers, if any
+    dictionary_provider: Option<Arc<dyn DictionaryProvider>>,
+}
+
+#[derive(Debug)]
+enum DecoderState {
+    /// Decoding the length of the message metadata, optionally preceded by a
+    /// continuation marker
+    Header {
+        buf: [u8; 4],
+        read: u8,
+        continuation: bool,
+    },
+    /// Decoding the message metadata of the given length
+    Message { size: usize },
+    /// Decoding the message body of the given length, following the given metadata
+    Body { message: Buffer, size: usize },
+    /// The end of stream marker has been decoded
+    Finished,
+}
+
+impl Default for DecoderState {
+    fn default() -> Self {
+        Self::Header {
+            buf: [0; 4],
+            read: 0,
+            continuation: false,
+        }
+    }
+}
+
+impl StreamDecoder {
+    /// Create a new [`StreamDecoder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip validation of the decoded arrays for the columns with the given
+    /// (zero-based) indices in the stream's schema
+    ///
+    /// By default every decoded array is fully validated, as the data may come from
+    /// an untrusted source. See [`FileDecoder::with_skip_validation`] for details.
+    ///
+    /// # Safety
+    ///
+    /// The IPC data for the listed columns must be valid, as described in
+    /// [`ArrayData::validate_full`], otherwise undefined behaviour may result
+    ///
+    /// [`FileDecoder::with_skip_validation`]: crate::reader::FileDecoder::with_skip_validation
+    /// [`ArrayData::validate_full`]: arrow_data::ArrayData::validate_full
+    pub unsafe fn with_skip_validation(mut self, columns: Vec<usize>) -> Self {
+        self.skip_validation = columns;
+        self
+    }
+
+    /// Share decoded dictionaries with other readers through `provider`, see
+    /// [`DictionaryProvider`]
+    pub fn with_dictionary_provider(mut self, provider: Arc<dyn DictionaryProvider>) -> Self {
+        self.dictionary_provider = Some(provider);
+        self
+    }
+
+    /// Returns the schema of the stream, or `None` if it has not yet been decoded
+    pub fn schema(&self) -> Option<SchemaRef> {
+        self.schema.clone()
+    }
+
+    /// Decodes the next [`RecordBatch`] from `buffer`, if any
+    ///
+    /// The bytes consumed are removed from the start of `buffer`. Returns `None` if
+    /// all of `buffer` was consumed without completing a record batch, in which case
+    /// the partial message is retained until more data is provided. Callers should
+    /// call this repeatedly until it returns `None`, as a single [`Buffer`] may
+    /// contain multiple record batches.
+    pub fn decode(&mut self, buffer: &mut Buffer) -> Result<Option<RecordBatch>, ArrowError> {
+        let batch = self.decode_with_metadata(buffer)?;
+        Ok(batch.map(|(batch, _)| batch))
+    }
+
+    /// Decodes the next [`RecordBatch`] from `buffer`, along with its custom
+    /// metadata, see [`Self::decode`]
+    pub fn decode_with_metadata(
+        &mut self,
+        buffer: &mut Buffer,
+    ) -> Result<Option<RecordBatchWithMetadata>, ArrowError> {
+        loop {
+            match &mut self.state {
+                DecoderState::Header {
+                    buf,
+                    read,
+                    continuation,
+                } => {
+                    if buffer.is_empty() {
+                        return Ok(None);
+                    }
+                    let remaining = &mut buf[*read as usize..];
+                    let to_read = remaining.len().min(buffer.len());
+                    remaining[..to_read].copy_from_slice(&buffer[..to_read]);
+                    *buffer = buffer.slice(to_read);
+                    *read += to_read as u8;
+                    if *read < 4 {
+                        continue;
+                    }
+                    // If a continuation marker is encountered, skip over it and
+                    // read the size from the next four bytes
+                    if *buf == CONTINUATION_MARKER && !*continuation {
+                        *read = 0;
+                        *continuation = true;
+                        continue;
+                    }
+                    let size = i32::from_le_bytes(*buf);
+                    self.state = match size {
+                        0 => DecoderState::Finished,
+                        _ => DecoderState::Message {
+                            size: usize::try_from(size).map_err(|_| {
+                                ArrowError::IpcError(format!(
+                                    "Invalid IPC message metadata length {size}"
+                                ))
+                            })?,
+                        },
+                    };
+                }
+                DecoderState::Message { size } => {
+                    let size = *size;
+                    let Some(message) = self.read_exact(buffer, size) else {
+                        return Ok(None);
+                    };
+                    let parsed = crate::root_as_message(&message).map_err(|err| {
+                        ArrowError::ParseError(format!("Unable to get root as message: {err:?}"))
+                    })?;
+                    if self.schema.is_none() {
+                        let (schema, endianness) =
+                            read_schema_message(&message, &Default::default())?;
+                        self.schema = Some(Arc::new(schema));
+                        self.endianness = endianness;
+                        self.state = DecoderState::default();
+                        continue;
+                    }
+                    let size = stream_message_body_len(&parsed);
+                    self.state = DecoderState::Body { message, size };
+                }
+                DecoderState::Body { message, size } => {
+                    let (message, size) = (message.clone(), *size);
+                    let Some(body) = self.read_exact(buffer, size) else {
+                        return Ok(None);
+                    };
+                    self.state = DecoderState::default();
+
+                    // The message was validated when transitioning to this state
+                    let message = crate::root_as_message(&message).unwrap();
+                    let batch = decode_stream_message(
+                        &message,
+                        body,
+                        self.schema.as_ref().unwrap(),
+                        &mut self.dictionaries,
+                        None,
+                        None,
+                        &self.skip_validation,
+                        self.endianness,
+                        self.dictionary_provider.as_deref(),
+                    )?;
+                    if let Some(batch) = batch {
+                        return Ok(Some(batch));
+                    }
+                }
+                DecoderState::Finished => {
+                    return match buffer.is_empty() {
+                        true => Ok(None),
+                        false => Err(ArrowError::IpcError(
+                            "Unexpected data following the end of the IPC stream".to_string(),
+                        )),
+                    };
+                }
+            }
+        }
+    }
+
+    /// Signal the end of the data, returning an error if the last message is
+    /// incomplete
+    ///
+    /// A stream may end either with an end of stream marker, or at the end of a
+    /// complete message, as permitted by the specification
+    pub fn finish(&mut self) -> Result<(), ArrowError> {
+        match &self.state {
+            DecoderState::Finished | DecoderState::Header { read: 0, .. } => Ok(()),
+            _ => Err(ArrowError::IpcError(
+                "Unexpected end of IPC stream, the last message is incomplete".to_string(),
+            )),
+        }
+    }
+
+    /// Reads `len` bytes from `buffer`, returning `None` if fewer are available, in
+    /// which case they are retained in `self.buf` until more data is provided
+    ///
+    /// If `len` bytes are available in `buffer`, and there is no partially received
+    /// data, the returned [`Buffer`] is a slice of `buffer`
+    fn read_exact(&mut self, buffer: &mut Buffer, len: usize) -> Option<Buffer> {
+        if self.buf.is_empty() && buffer.len() >= len {
+            let data = buffer.slice_with_length(0, len);
+            *buffer = buffer.slice(len);
+            return Some(data);
+        }
+
+        let to_read = buffer.len().min(len - self.buf.len());
+        self.buf.extend_from_slice(&buffer[..to_read]);
+        *buffer = buffer.slice(to_read);
+        match self.buf.len() == len {
+            true => Some(std::mem::take(&mut self.buf).into()),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{IpcWriteOptions, StreamWriter};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{DictionaryArray, Int64Array, StringArray};
+
+    fn batches() -> Vec<RecordBatch> {
+        (0..3)
+            .map(|i| {
+                let dict: DictionaryArray<Int32Type> =
+                    vec![Some("a"), None, Some("b")].into_iter().collect();
+                let ints = Int64Array::from(vec![i, i + 1, i + 2]);
+                let strings = StringArray::from(vec!["x".repeat(i as usize); 3]);
+                RecordBatch::try_from_iter([
+                    ("dict", Arc::new(dict) as ArrayRef),
+                    ("ints", Arc::new(ints) as _),
+                    ("strings", Arc::new(strings) as _),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    fn write_stream(batches: &[RecordBatch], options: IpcWriteOptions) -> Vec<u8> {
+        let mut writer =
+            StreamWriter::try_new_with_options(vec![], &batches[0].schema(), options).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_stream_decoder() {
+        let batches = batches();
+        let legacy = IpcWriteOptions::try_new(8, true, crate::MetadataVersion::V4).unwrap();
+        for options in [IpcWriteOptions::default(), legacy] {
+            let bytes = write_stream(&batches, options);
+            for chunk_size in [1, 3, 8, 100, bytes.len()] {
+                let mut decoder = StreamDecoder::new();
+                let mut decoded = vec![];
+                for chunk in bytes.chunks(chunk_size) {
+                    let mut buffer = Buffer::from_slice_ref(chunk);
+                    while let Some(batch) = decoder.decode(&mut buffer).unwrap() {
+                        decoded.push(batch);
+                    }
+                    assert!(buffer.is_empty());
+                }
+                decoder.finish().unwrap();
+                assert_eq!(decoder.schema().unwrap(), batches[0].schema());
+                assert_eq!(decoded, batches, "{chunk_size}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_decoder_zero_copy() {
+        let batches = batches();
+        let bytes = Buffer::from_vec(write_stream(&batches, IpcWriteOptions::default()));
+
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = bytes.clone();
+        let batch = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(batch, batches[0]);
+
+        // The record batch is decoded from a slice of the input
+        let range = bytes.as_ptr() as usize..bytes.as_ptr() as usize + bytes.len();
+        let ints = batch.column(1).to_data();
+        assert!(range.contains(&(ints.buffers()[0].as_ptr() as usize)));
+    }
+
+    #[test]
+    fn test_stream_decoder_validation() {
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(StringArray::from(vec!["xyzzy"])) as ArrayRef),
+            ("b", Arc::new(StringArray::from(vec!["plugh"])) as ArrayRef),
+        ])
+        .unwrap();
+        let mut bytes = write_stream(&[batch], IpcWriteOptions::default());
+        let start = bytes.windows(5).position(|w| w == b"xyzzy").unwrap();
+        bytes[start] = 0xFF;
+
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from_vec(bytes.clone());
+        let err = decoder.decode(&mut buffer).unwrap_err();
+        assert!(err.to_string().contains("Invalid UTF8 sequence"), "{err}");
+
+        // Skipping validation of other columns still validates column "a"
+        let mut decoder = unsafe { StreamDecoder::new().with_skip_validation(vec![1]) };
+        let mut buffer = Buffer::from_vec(bytes.clone());
+        assert!(decoder.decode(&mut buffer).is_err());
+
+        let mut decoder = unsafe { StreamDecoder::new().with_skip_validation(vec![0]) };
+        let mut buffer = Buffer::from_vec(bytes);
+        let batch = decoder.decode(&mut buffer).unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+    }
+
+    #[test]
+    fn test_stream_decoder_errors() {
+        let bytes = write_stream(&batches(), IpcWriteOptions::default());
+
+        // Data truncated mid-message
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from_slice_ref(&bytes[..bytes.len() / 2]);
+        while decoder.decode(&mut buffer).unwrap().is_some() {}
+        let err = decoder.finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Unexpected end of IPC stream, the last message is incomplete"
+        );
+
+        // Data following the end of stream marker
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from_slice_ref(&bytes);
+        while decoder.decode(&mut buffer).unwrap().is_some() {}
+        decoder.finish().unwrap();
+        let err = decoder
+            .decode(&mut Buffer::from_slice_ref([1_u8]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Unexpected data following the end of the IPC stream"
+        );
+
+        // The first message must be a schema
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from_slice_ref(&bytes);
+        decoder.decode(&mut buffer).unwrap();
+        let mut decoder = StreamDecoder::new();
+        let err = decoder.decode(&mut buffer).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Unable to read IPC message as schema"
+        );
+    }
+}
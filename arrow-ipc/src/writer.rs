@@ -20,6 +20,7 @@
 //! The `FileWriter` and `StreamWriter` have similar interfaces,
 //! however the `FileWriter` expects a reader that supports `Seek`ing
 
+use std::borrow::Cow;
 use std::cmp::min;
 use std::collections::HashMap;
 use std::io::{BufWriter, Write};
@@ -36,14 +37,20 @@ use arrow_buffer::{ArrowNativeType, Buffer, MutableBuffer};
 use arrow_data::{layout, ArrayData, ArrayDataBuilder, BufferSpec};
 use arrow_schema::*;
 
+use crate::checksum::{
+    check_available, format_checksums, message_checksum, ChecksumWriter, DICTIONARY_CHECKSUMS_KEY,
+    MESSAGE_CHECKSUM_KEY, RECORD_BATCH_CHECKSUMS_KEY,
+};
 use crate::compression::CompressionCodec;
+use crate::convert::{schema_to_fb_offset_with_endianness, NATIVE_ENDIANNESS};
+use crate::endian::{swap_components, value_components};
 use crate::CONTINUATION_MARKER;
 
 /// IPC write options used to control the behaviour of the [`IpcDataGenerator`]
 #[derive(Debug, Clone)]
 pub struct IpcWriteOptions {
     /// Write padding after memory buffers to this multiple of bytes.
-    /// Generally 8 or 64, defaults to 64
+    /// Must be a power of two and a multiple of 8, generally 8 or 64, defaults to 64
     alignment: usize,
     /// The legacy format is for releases before 0.15.0, and uses metadata V4
     write_legacy_ipc_format: bool,
@@ -56,42 +63,142 @@ pub struct IpcWriteOptions {
     /// version 2.0.0: V4, with legacy format enabled
     /// version 4.0.0: V5
     metadata_version: crate::MetadataVersion,
-    /// Compression, if desired. Will result in a runtime error
-    /// if the corresponding feature is not enabled
+    /// Compression, if desired
     batch_compression_type: Option<crate::CompressionType>,
+    /// The endianness of the written data, defaults to that of the system
+    endianness: crate::Endianness,
+    /// How to write dictionaries that change between batches
+    dictionary_handling: DictionaryHandling,
+    /// How to assign the ids of dictionaries in the written schema
+    dictionary_id_strategy: DictionaryIdStrategy,
+    /// The maximum size of an encoded record batch message, if any
+    max_encoded_message_size: Option<usize>,
+    /// Whether to store a CRC32C checksum of the body of each message
+    message_checksums: bool,
 }
 
 impl IpcWriteOptions {
     /// Configures compression when writing IPC files.
     ///
-    /// Will result in a runtime error if the corresponding feature
-    /// is not enabled
+    /// Returns an error if the metadata version is less than V5, or if the
+    /// feature of the corresponding codec is not enabled
     pub fn try_with_compression(
         mut self,
         batch_compression_type: Option<crate::CompressionType>,
     ) -> Result<Self, ArrowError> {
         self.batch_compression_type = batch_compression_type;
 
-        if self.batch_compression_type.is_some()
-            && self.metadata_version < crate::MetadataVersion::V5
-        {
+        if let Some(compression) = self.batch_compression_type {
+            if self.metadata_version < crate::MetadataVersion::V5 {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Compression only supported in metadata v5 and above".to_string(),
+                ));
+            }
+            CompressionCodec::try_from(compression)?.check_available()?;
+        }
+        Ok(self)
+    }
+
+    /// Configures the alignment of buffers when writing IPC data
+    ///
+    /// Each buffer within a message body, and each message body within a file
+    /// or stream, will be padded with zeros such that it starts at a multiple of
+    /// `alignment` bytes. The default of 64 allows consumers to memory map the
+    /// output and use it directly with SIMD instructions, whereas 8, the minimum
+    /// required by the specification, produces smaller output.
+    ///
+    /// Returns an error if `alignment` is not a power of two and a multiple of 8.
+    /// [`Self::try_new`] also accepts alignments that are a multiple of 8 but not
+    /// a power of two, such as 24
+    pub fn try_with_alignment(mut self, alignment: usize) -> Result<Self, ArrowError> {
+        validate_alignment(alignment)?;
+        if !alignment.is_power_of_two() {
             return Err(ArrowError::InvalidArgumentError(
-                "Compression only supported in metadata v5 and above".to_string(),
+                "Alignment should be a power of two multiple of 8".to_string(),
             ));
         }
+        self.alignment = alignment;
+        Ok(self)
+    }
+
+    /// Configures the endianness of the written data, defaulting to that of the system
+    ///
+    /// If this differs from the endianness of the system, the values of the written
+    /// arrays are byte-swapped, for exchange with systems of the given endianness.
+    /// The endianness is recorded in the schema, as required by the specification.
+    ///
+    /// Returns an error if `endianness` is neither little nor big endian
+    pub fn try_with_endianness(
+        mut self,
+        endianness: crate::Endianness,
+    ) -> Result<Self, ArrowError> {
+        if !matches!(
+            endianness,
+            crate::Endianness::Little | crate::Endianness::Big
+        ) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid endianness {endianness:?}"
+            )));
+        }
+        self.endianness = endianness;
         Ok(self)
     }
+
+    /// Configures how dictionaries that change between batches are written, see
+    /// [`DictionaryHandling`]
+    ///
+    /// [`FileWriter`] never writes replacement dictionaries, as these are not
+    /// supported by the IPC file format, and instead returns an error for any
+    /// change that cannot be written as a delta
+    pub fn with_dictionary_handling(mut self, dictionary_handling: DictionaryHandling) -> Self {
+        self.dictionary_handling = dictionary_handling;
+        self
+    }
+
+    /// Configures how [`FileWriter`] and [`StreamWriter`] assign the ids of the
+    /// dictionary fields in the written schema, see [`DictionaryIdStrategy`]
+    pub fn with_dictionary_id_strategy(
+        mut self,
+        dictionary_id_strategy: DictionaryIdStrategy,
+    ) -> Self {
+        self.dictionary_id_strategy = dictionary_id_strategy;
+        self
+    }
+
+    /// Configures the maximum size in bytes of each record batch message written by
+    /// [`FileWriter`] and [`StreamWriter`], including its metadata and body
+    ///
+    /// Record batches that encode to a larger message are repeatedly split in half by
+    /// row, and written as multiple smaller record batches, for consumers, such as
+    /// gRPC, that limit the size of the messages they accept. A single row that
+    /// exceeds the limit is written on its own, and dictionaries are not split.
+    /// Defaults to `None`, writing each record batch as a single message
+    pub fn with_max_encoded_message_size(mut self, max_encoded_message_size: usize) -> Self {
+        self.max_encoded_message_size = Some(max_encoded_message_size);
+        self
+    }
+
+    /// Configures whether to store a CRC32C checksum of the body of each record batch
+    /// and dictionary message in its custom metadata, defaults to `false`
+    ///
+    /// Readers verify the checksum of any message that contains one, returning an
+    /// error instead of decoding a corrupted body. Unlike
+    /// [`FileWriter::try_with_block_checksums`], this applies to streams as well as
+    /// files, and other implementations ignore the checksums
+    ///
+    /// Writing returns an error if enabled without the `checksum` feature
+    pub fn with_message_checksums(mut self, message_checksums: bool) -> Self {
+        self.message_checksums = message_checksums;
+        self
+    }
+
     /// Try create IpcWriteOptions, checking for incompatible settings
     pub fn try_new(
         alignment: usize,
         write_legacy_ipc_format: bool,
         metadata_version: crate::MetadataVersion,
     ) -> Result<Self, ArrowError> {
-        if alignment == 0 || alignment % 8 != 0 {
-            return Err(ArrowError::InvalidArgumentError(
-                "Alignment should be greater than 0 and be a multiple of 8".to_string(),
-            ));
-        }
+        validate_alignment(alignment)?;
         match metadata_version {
             crate::MetadataVersion::V1
             | crate::MetadataVersion::V2
@@ -103,6 +210,11 @@ impl IpcWriteOptions {
                 write_legacy_ipc_format,
                 metadata_version,
                 batch_compression_type: None,
+                endianness: NATIVE_ENDIANNESS,
+                dictionary_handling: DictionaryHandling::default(),
+                dictionary_id_strategy: DictionaryIdStrategy::default(),
+                max_encoded_message_size: None,
+                message_checksums: false,
             }),
             crate::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -115,6 +227,11 @@ impl IpcWriteOptions {
                         write_legacy_ipc_format,
                         metadata_version,
                         batch_compression_type: None,
+                        endianness: NATIVE_ENDIANNESS,
+                        dictionary_handling: DictionaryHandling::default(),
+                        dictionary_id_strategy: DictionaryIdStrategy::default(),
+                        max_encoded_message_size: None,
+                        message_checksums: false,
                     })
                 }
             }
@@ -125,6 +242,15 @@ impl IpcWriteOptions {
     }
 }
 
+fn validate_alignment(alignment: usize) -> Result<(), ArrowError> {
+    if alignment == 0 || alignment % 8 != 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Alignment should be greater than 0 and be a multiple of 8".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 impl Default for IpcWriteOptions {
     fn default() -> Self {
         Self {
@@ -132,6 +258,11 @@ impl Default for IpcWriteOptions {
             write_legacy_ipc_format: false,
             metadata_version: crate::MetadataVersion::V5,
             batch_compression_type: None,
+            endianness: NATIVE_ENDIANNESS,
+            dictionary_handling: DictionaryHandling::default(),
+            dictionary_id_strategy: DictionaryIdStrategy::default(),
+            max_encoded_message_size: None,
+            message_checksums: false,
         }
     }
 }
@@ -175,7 +306,8 @@ impl IpcDataGenerator {
     pub fn schema_to_bytes(&self, schema: &Schema, write_options: &IpcWriteOptions) -> EncodedData {
         let mut fbb = FlatBufferBuilder::new();
         let schema = {
-            let fb = crate::convert::schema_to_fb_offset(&mut fbb, schema);
+            let fb =
+                schema_to_fb_offset_with_endianness(&mut fbb, schema, write_options.endianness);
             fb.as_union_value()
         };
 
@@ -195,14 +327,17 @@ impl IpcDataGenerator {
         }
     }
 
+    /// Encodes the dictionaries nested within `column`, using the fields of `data_type`,
+    /// which may differ from those of `column` in their dictionary ids
     fn _encode_dictionaries(
         &self,
+        data_type: &DataType,
         column: &ArrayRef,
         encoded_dictionaries: &mut Vec<EncodedData>,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(), ArrowError> {
-        match column.data_type() {
+        match data_type {
             DataType::Struct(fields) => {
                 let s = as_struct_array(column);
                 for (field, column) in fields.iter().zip(s.columns()) {
@@ -320,8 +455,8 @@ impl IpcDataGenerator {
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(), ArrowError> {
-        match column.data_type() {
-            DataType::Dictionary(_key_type, _value_type) => {
+        match field.data_type() {
+            DataType::Dictionary(_key_type, value_type) => {
                 let dict_id = field
                     .dict_id()
                     .expect("All Dictionary types have `dict_id`");
@@ -331,23 +466,35 @@ impl IpcDataGenerator {
                 let values = make_array(dict_data.child_data()[0].clone());
 
                 self._encode_dictionaries(
+                    value_type,
                     &values,
                     encoded_dictionaries,
                     dictionary_tracker,
                     write_options,
                 )?;
 
-                let emit = dictionary_tracker.insert(dict_id, column)?;
-
-                if emit {
-                    encoded_dictionaries.push(self.dictionary_batch_to_bytes(
-                        dict_id,
-                        dict_values,
-                        write_options,
-                    )?);
+                match dictionary_tracker.insert_column(dict_id, column)? {
+                    DictionaryUpdate::None => {}
+                    DictionaryUpdate::New | DictionaryUpdate::Replaced => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            dict_values,
+                            false,
+                            write_options,
+                        )?);
+                    }
+                    DictionaryUpdate::Delta(delta) => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            &delta,
+                            true,
+                            write_options,
+                        )?);
+                    }
                 }
             }
-            _ => self._encode_dictionaries(
+            data_type => self._encode_dictionaries(
+                data_type,
                 column,
                 encoded_dictionaries,
                 dictionary_tracker,
@@ -366,6 +513,18 @@ impl IpcDataGenerator {
         batch: &RecordBatch,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
+    ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
+        self.encoded_batch_with_metadata(batch, dictionary_tracker, write_options, &HashMap::new())
+    }
+
+    /// Encodes a batch as [`Self::encoded_batch`], storing `custom_metadata` in the
+    /// custom metadata of the record batch message
+    pub fn encoded_batch_with_metadata(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+        custom_metadata: &HashMap<String, String>,
     ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
         let schema = batch.schema();
         let mut encoded_dictionaries = Vec::with_capacity(schema.all_fields().len());
@@ -381,16 +540,83 @@ impl IpcDataGenerator {
             )?;
         }
 
-        let encoded_message = self.record_batch_to_bytes(batch, write_options)?;
+        let encoded_message = self.record_batch_to_bytes(batch, write_options, custom_metadata)?;
         Ok((encoded_dictionaries, encoded_message))
     }
 
+    /// Encodes a dense tensor of fixed-width `data_type` values as an IPC Tensor message
+    ///
+    /// `shape` and `strides` give the length of each dimension, and the number of bytes
+    /// between consecutive values along it, `names` optionally names each dimension,
+    /// and `data` contains the values, in the native endianness of the system
+    pub fn encoded_tensor(
+        &self,
+        data_type: &DataType,
+        shape: &[usize],
+        strides: &[usize],
+        names: Option<&[&str]>,
+        data: &[u8],
+        write_options: &IpcWriteOptions,
+    ) -> Result<EncodedData, ArrowError> {
+        if strides.len() != shape.len() || names.map_or(false, |n| n.len() != shape.len()) {
+            return Err(ArrowError::InvalidArgumentError(
+                "Tensor shape, strides and names must have the same number of dimensions"
+                    .to_string(),
+            ));
+        }
+
+        let mut fbb = FlatBufferBuilder::new();
+        let (type_type, type_) = crate::convert::tensor_type_to_fb(&mut fbb, data_type)?;
+        let dims: Vec<_> = shape
+            .iter()
+            .enumerate()
+            .map(|(i, size)| {
+                let name = names.map(|n| fbb.create_string(n[i]));
+                let mut builder = crate::TensorDimBuilder::new(&mut fbb);
+                builder.add_size_(*size as i64);
+                if let Some(name) = name {
+                    builder.add_name(name);
+                }
+                builder.finish()
+            })
+            .collect();
+        let shape = fbb.create_vector(&dims);
+        let strides: Vec<i64> = strides.iter().map(|s| *s as i64).collect();
+        let strides = fbb.create_vector(&strides);
+
+        let mut arrow_data = data.to_vec();
+        let pad_len = pad_to_alignment(write_options.alignment, arrow_data.len());
+        arrow_data.extend_from_slice(&vec![0u8; pad_len][..]);
+
+        let mut tensor = crate::TensorBuilder::new(&mut fbb);
+        tensor.add_type_type(type_type);
+        tensor.add_type_(type_);
+        tensor.add_shape(shape);
+        tensor.add_strides(strides);
+        tensor.add_data(&crate::Buffer::new(0, data.len() as i64));
+        let tensor = tensor.finish();
+
+        let mut message = crate::MessageBuilder::new(&mut fbb);
+        message.add_version(write_options.metadata_version);
+        message.add_header_type(crate::MessageHeader::Tensor);
+        message.add_bodyLength(arrow_data.len() as i64);
+        message.add_header(tensor.as_union_value());
+        let root = message.finish();
+        fbb.finish(root, None);
+
+        Ok(EncodedData {
+            ipc_message: fbb.finished_data().to_vec(),
+            arrow_data,
+        })
+    }
+
     /// Write a `RecordBatch` into two sets of bytes, one for the header (crate::Message) and the
     /// other for the batch's data
     fn record_batch_to_bytes(
         &self,
         batch: &RecordBatch,
         write_options: &IpcWriteOptions,
+        custom_metadata: &HashMap<String, String>,
     ) -> Result<EncodedData, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
 
@@ -428,7 +654,7 @@ impl IpcDataGenerator {
         }
         // pad the tail of body data
         let len = arrow_data.len();
-        let pad_len = pad_to_8(len as u32);
+        let pad_len = pad_to_alignment(write_options.alignment, len);
         arrow_data.extend_from_slice(&vec![0u8; pad_len][..]);
 
         // write data
@@ -445,12 +671,18 @@ impl IpcDataGenerator {
             let b = batch_builder.finish();
             b.as_union_value()
         };
+        let custom_metadata = with_message_checksum(custom_metadata, &arrow_data, write_options)?;
+        let fb_custom_metadata = (!custom_metadata.is_empty())
+            .then(|| crate::convert::metadata_to_fb(&mut fbb, &custom_metadata));
         // create an crate::Message
         let mut message = crate::MessageBuilder::new(&mut fbb);
         message.add_version(write_options.metadata_version);
         message.add_header_type(crate::MessageHeader::RecordBatch);
         message.add_bodyLength(arrow_data.len() as i64);
         message.add_header(root);
+        if let Some(fb_custom_metadata) = fb_custom_metadata {
+            message.add_custom_metadata(fb_custom_metadata);
+        }
         let root = message.finish();
         fbb.finish(root, None);
         let finished_data = fbb.finished_data();
@@ -463,10 +695,13 @@ impl IpcDataGenerator {
 
     /// Write dictionary values into two sets of bytes, one for the header (crate::Message) and the
     /// other for the data
+    ///
+    /// If `is_delta` is true, the values are appended to those previously written
     fn dictionary_batch_to_bytes(
         &self,
         dict_id: i64,
         array_data: &ArrayData,
+        is_delta: bool,
         write_options: &IpcWriteOptions,
     ) -> Result<EncodedData, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
@@ -503,7 +738,7 @@ impl IpcDataGenerator {
 
         // pad the tail of body data
         let len = arrow_data.len();
-        let pad_len = pad_to_8(len as u32);
+        let pad_len = pad_to_alignment(write_options.alignment, len);
         arrow_data.extend_from_slice(&vec![0u8; pad_len][..]);
 
         // write data
@@ -525,15 +760,24 @@ impl IpcDataGenerator {
             let mut batch_builder = crate::DictionaryBatchBuilder::new(&mut fbb);
             batch_builder.add_id(dict_id);
             batch_builder.add_data(root);
+            batch_builder.add_isDelta(is_delta);
             batch_builder.finish().as_union_value()
         };
 
+        let custom_metadata = HashMap::new();
+        let custom_metadata = with_message_checksum(&custom_metadata, &arrow_data, write_options)?;
+        let fb_custom_metadata = (!custom_metadata.is_empty())
+            .then(|| crate::convert::metadata_to_fb(&mut fbb, &custom_metadata));
+
         let root = {
             let mut message_builder = crate::MessageBuilder::new(&mut fbb);
             message_builder.add_version(write_options.metadata_version);
             message_builder.add_header_type(crate::MessageHeader::DictionaryBatch);
             message_builder.add_bodyLength(arrow_data.len() as i64);
             message_builder.add_header(root);
+            if let Some(fb_custom_metadata) = fb_custom_metadata {
+                message_builder.add_custom_metadata(fb_custom_metadata);
+            }
             message_builder.finish()
         };
 
@@ -547,6 +791,24 @@ impl IpcDataGenerator {
     }
 }
 
+/// Returns `custom_metadata` with the checksum of the message `body` added, if
+/// configured by `write_options`
+fn with_message_checksum<'a>(
+    custom_metadata: &'a HashMap<String, String>,
+    body: &[u8],
+    write_options: &IpcWriteOptions,
+) -> Result<Cow<'a, HashMap<String, String>>, ArrowError> {
+    Ok(match write_options.message_checksums {
+        true => {
+            let mut custom_metadata = custom_metadata.clone();
+            let checksum = format_checksums(&[message_checksum(body)?]);
+            custom_metadata.insert(MESSAGE_CHECKSUM_KEY.to_string(), checksum);
+            Cow::Owned(custom_metadata)
+        }
+        false => Cow::Borrowed(custom_metadata),
+    })
+}
+
 pub(crate) fn unslice_run_array(arr: ArrayData) -> Result<ArrayData, ArrowError> {
     match arr.data_type() {
         DataType::RunEndEncoded(k, _) => match k.data_type() {
@@ -619,12 +881,144 @@ fn into_zero_offset_run_array<R: RunEndIndexType>(
     Ok(array_data.into())
 }
 
+/// How to write a dictionary whose values change between batches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DictionaryHandling {
+    /// Write the new values as a replacement dictionary
+    #[default]
+    Replace,
+    /// If the new values start with the values previously written, write only the
+    /// additional values as a delta dictionary, otherwise write a replacement
+    ///
+    /// This allows a dictionary to grow over a long-running stream without
+    /// resending the values already written
+    Delta,
+    /// Return an error
+    Error,
+}
+
+/// How [`FileWriter`] and [`StreamWriter`] assign the ids of dictionary fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DictionaryIdStrategy {
+    /// Write the [`Field::dict_id`] of each dictionary field unchanged
+    ///
+    /// Dictionary fields with the same id share a single dictionary, and so
+    /// must have the same values
+    #[default]
+    Preserve,
+    /// Assign sequential ids, starting at zero, to the dictionary fields in
+    /// depth-first order, ignoring their [`Field::dict_id`]
+    ///
+    /// This ensures each dictionary field is written with its own dictionary, such
+    /// as for schemas created with [`Field::new`], which gives every dictionary
+    /// field an id of zero
+    Assign,
+}
+
+/// Returns `schema` with its dictionary ids assigned according to `strategy`, along
+/// with the original and written id of each dictionary field in depth-first order
+fn assign_dictionary_ids(
+    schema: &Schema,
+    strategy: DictionaryIdStrategy,
+) -> (Schema, Vec<(i64, i64)>) {
+    let mut ids = vec![];
+    let assign = strategy == DictionaryIdStrategy::Assign;
+    let fields: Vec<_> = schema
+        .fields()
+        .iter()
+        .map(|f| assign_field_dictionary_ids(f, assign, &mut ids))
+        .collect();
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    (schema, ids)
+}
+
+fn assign_field_dictionary_ids(field: &Field, assign: bool, ids: &mut Vec<(i64, i64)>) -> Field {
+    let Some(dict_id) = field.dict_id() else {
+        let data_type = assign_data_type_dictionary_ids(field.data_type(), assign, ids);
+        return field.clone().with_data_type(data_type);
+    };
+    let written_id = match assign {
+        true => ids.len() as i64,
+        false => dict_id,
+    };
+    ids.push((dict_id, written_id));
+    let data_type = assign_data_type_dictionary_ids(field.data_type(), assign, ids);
+    Field::new_dict(
+        field.name(),
+        data_type,
+        field.is_nullable(),
+        written_id,
+        field.dict_is_ordered().unwrap_or_default(),
+    )
+    .with_metadata(field.metadata().clone())
+}
+
+fn assign_data_type_dictionary_ids(
+    data_type: &DataType,
+    assign: bool,
+    ids: &mut Vec<(i64, i64)>,
+) -> DataType {
+    let mut child = |f: &FieldRef| Arc::new(assign_field_dictionary_ids(f, assign, ids));
+    match data_type {
+        DataType::Struct(fields) => DataType::Struct(fields.iter().map(child).collect()),
+        DataType::List(f) => DataType::List(child(f)),
+        DataType::LargeList(f) => DataType::LargeList(child(f)),
+        DataType::FixedSizeList(f, size) => DataType::FixedSizeList(child(f), *size),
+        DataType::Map(f, sorted) => DataType::Map(child(f), *sorted),
+        DataType::RunEndEncoded(run_ends, values) => {
+            DataType::RunEndEncoded(run_ends.clone(), child(values))
+        }
+        DataType::Union(fields, mode) => {
+            let (type_ids, fields): (Vec<_>, Vec<_>) =
+                fields.iter().map(|(id, f)| (id, child(f))).unzip();
+            DataType::Union(UnionFields::new(type_ids, fields), *mode)
+        }
+        DataType::Dictionary(key, value) => DataType::Dictionary(
+            key.clone(),
+            Box::new(assign_data_type_dictionary_ids(value, assign, ids)),
+        ),
+        d => d.clone(),
+    }
+}
+
+/// Returns `batch` with the `schema` written by a writer, if its dictionary ids
+/// have been assigned, so that they are used when encoding the dictionaries
+fn with_written_schema<'a>(
+    batch: &'a RecordBatch,
+    schema: &SchemaRef,
+    write_options: &IpcWriteOptions,
+) -> Result<Cow<'a, RecordBatch>, ArrowError> {
+    if write_options.dictionary_id_strategy != DictionaryIdStrategy::Assign {
+        return Ok(Cow::Borrowed(batch));
+    }
+    let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+    let batch =
+        RecordBatch::try_new_with_options(schema.clone(), batch.columns().to_vec(), &options)?;
+    Ok(Cow::Owned(batch))
+}
+
+/// The result of [`DictionaryTracker::insert_column`], describing the dictionary
+/// batch, if any, to write
+#[derive(Debug, Clone, PartialEq)]
+pub enum DictionaryUpdate {
+    /// The dictionary has already been written
+    None,
+    /// The dictionary has not been written before
+    New,
+    /// The dictionary replaces one previously written with the same id
+    Replaced,
+    /// The values of the dictionary start with those previously written with
+    /// the same id, followed by the contained additional values
+    Delta(ArrayData),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
 pub struct DictionaryTracker {
     written: HashMap<i64, ArrayData>,
     error_on_replacement: bool,
+    delta: bool,
 }
 
 impl DictionaryTracker {
@@ -635,6 +1029,17 @@ impl DictionaryTracker {
         Self {
             written: HashMap::new(),
             error_on_replacement,
+            delta: false,
+        }
+    }
+
+    /// Create a new [`DictionaryTracker`] handling updates to existing dictionaries
+    /// according to `handling`, see [`Self::insert_column`]
+    pub fn new_with_handling(handling: DictionaryHandling) -> Self {
+        Self {
+            written: HashMap::new(),
+            error_on_replacement: handling == DictionaryHandling::Error,
+            delta: handling == DictionaryHandling::Delta,
         }
     }
 
@@ -647,33 +1052,64 @@ impl DictionaryTracker {
     /// * If the tracker has not been configured to error on replacement or this dictionary
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
+    ///
+    /// Deltas are reported as replacements, see [`Self::insert_column`] to distinguish them
     pub fn insert(&mut self, dict_id: i64, column: &ArrayRef) -> Result<bool, ArrowError> {
+        let update = self.insert_column(dict_id, column)?;
+        Ok(update != DictionaryUpdate::None)
+    }
+
+    /// Keep track of the dictionary with the given ID and values, returning the
+    /// [`DictionaryUpdate`] to write
+    ///
+    /// If this ID has been written already with different data, and this tracker
+    /// was created with [`DictionaryHandling::Delta`], returns the values following
+    /// those previously written as a [`DictionaryUpdate::Delta`]. Otherwise, returns
+    /// an error if the tracker is configured to error on replacement, or
+    /// [`DictionaryUpdate::Replaced`]
+    pub fn insert_column(
+        &mut self,
+        dict_id: i64,
+        column: &ArrayRef,
+    ) -> Result<DictionaryUpdate, ArrowError> {
         let dict_data = column.to_data();
         let dict_values = &dict_data.child_data()[0];
 
         // If a dictionary with this id was already emitted, check if it was the same.
-        if let Some(last) = self.written.get(&dict_id) {
-            if ArrayData::ptr_eq(&last.child_data()[0], dict_values) {
-                // Same dictionary values => no need to emit it again
-                return Ok(false);
-            }
-            if self.error_on_replacement {
-                // If error on replacement perform a logical comparison
-                if last.child_data()[0] == *dict_values {
-                    // Same dictionary values => no need to emit it again
-                    return Ok(false);
-                }
-                return Err(ArrowError::InvalidArgumentError(
-                    "Dictionary replacement detected when writing IPC file format. \
-                     Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
-                        .to_string(),
-                ));
+        let last = match self.written.get(&dict_id) {
+            Some(last) => &last.child_data()[0],
+            None => {
+                self.written.insert(dict_id, dict_data);
+                return Ok(DictionaryUpdate::New);
             }
+        };
+        if ArrayData::ptr_eq(last, dict_values) {
+            // Same dictionary values => no need to emit it again
+            return Ok(DictionaryUpdate::None);
         }
 
+        let update = if (self.delta || self.error_on_replacement) && last == dict_values {
+            // Same dictionary values => no need to emit it again
+            return Ok(DictionaryUpdate::None);
+        } else if self.delta
+            && last.len() < dict_values.len()
+            && *last == dict_values.slice(0, last.len())
+        {
+            let delta_len = dict_values.len() - last.len();
+            DictionaryUpdate::Delta(dict_values.slice(last.len(), delta_len))
+        } else if self.error_on_replacement {
+            return Err(ArrowError::InvalidArgumentError(
+                "Dictionary replacement detected when writing IPC file format. \
+                 Arrow IPC files only support a single dictionary for a given field \
+                 across all batches."
+                    .to_string(),
+            ));
+        } else {
+            DictionaryUpdate::Replaced
+        };
+
         self.written.insert(dict_id, dict_data);
-        Ok(true)
+        Ok(update)
     }
 }
 
@@ -696,6 +1132,10 @@ pub struct FileWriter<W: Write> {
     dictionary_tracker: DictionaryTracker,
     /// User level customized metadata
     custom_metadata: HashMap<String, String>,
+    /// CRC32 checksums of the dictionary and record batch blocks, if enabled
+    block_checksums: Option<(Vec<u32>, Vec<u32>)>,
+    /// The original and written id of each dictionary field
+    dictionary_ids: Vec<(i64, i64)>,
 
     data_gen: IpcDataGenerator,
 }
@@ -721,58 +1161,149 @@ impl<W: Write> FileWriter<W> {
         writer.write_all(&super::ARROW_MAGIC[..])?;
         writer.write_all(&[0, 0])?;
         // write the schema, set the written bytes to the schema + header
-        let encoded_message = data_gen.schema_to_bytes(schema, &write_options);
-        let (meta, data) = write_message(&mut writer, encoded_message, &write_options)?;
+        let (schema, dictionary_ids) =
+            assign_dictionary_ids(schema, write_options.dictionary_id_strategy);
+        let encoded_message = data_gen.schema_to_bytes(&schema, &write_options);
+        let (meta, data) =
+            write_message_at(&mut writer, encoded_message, &write_options, header_size)?;
+        // Files may contain delta dictionaries, but not replacements
+        let dictionary_tracker = DictionaryTracker {
+            written: HashMap::new(),
+            error_on_replacement: true,
+            delta: write_options.dictionary_handling == DictionaryHandling::Delta,
+        };
         Ok(Self {
             writer,
             write_options,
-            schema: Arc::new(schema.clone()),
+            schema: Arc::new(schema),
             block_offsets: meta + data + header_size,
             dictionary_blocks: vec![],
             record_blocks: vec![],
             finished: false,
-            dictionary_tracker: DictionaryTracker::new(true),
+            dictionary_tracker,
             custom_metadata: HashMap::new(),
+            block_checksums: None,
+            dictionary_ids,
             data_gen,
         })
     }
 
+    /// Configures whether to compute a CRC32 checksum of each dictionary and record
+    /// batch block, stored in the custom metadata of the footer
+    ///
+    /// The checksums are verified by [`FileReader`](crate::reader::FileReader) when
+    /// reading the blocks, allowing corruption of the file to be detected.
+    ///
+    /// Returns an error if any record batches have already been written, or if
+    /// enabled without the `checksum` feature
+    pub fn try_with_block_checksums(mut self, enabled: bool) -> Result<Self, ArrowError> {
+        if enabled {
+            check_available()?;
+        }
+        if !self.record_blocks.is_empty() {
+            return Err(ArrowError::IpcError(
+                "Block checksums must be configured before writing record batches".to_string(),
+            ));
+        }
+        self.block_checksums = enabled.then(Default::default);
+        Ok(self)
+    }
+
+    /// Returns the original and written id of each dictionary field of the schema,
+    /// in depth-first order, see [`IpcWriteOptions::with_dictionary_id_strategy`]
+    pub fn dictionary_ids(&self) -> &[(i64, i64)] {
+        &self.dictionary_ids
+    }
+
+    /// Writes `encoded` at the current offset, returning its [`crate::Block`]
+    /// and checksum if enabled
+    fn write_block(
+        &mut self,
+        encoded: EncodedData,
+    ) -> Result<(crate::Block, Option<u32>), ArrowError> {
+        let (meta, data, checksum) = match self.block_checksums.is_some() {
+            true => {
+                let mut writer = ChecksumWriter::new(&mut self.writer);
+                let (meta, data) = write_message_at(
+                    &mut writer,
+                    encoded,
+                    &self.write_options,
+                    self.block_offsets,
+                )?;
+                (meta, data, Some(writer.finalize()?))
+            }
+            false => {
+                let (meta, data) = write_message_at(
+                    &mut self.writer,
+                    encoded,
+                    &self.write_options,
+                    self.block_offsets,
+                )?;
+                (meta, data, None)
+            }
+        };
+        let block = crate::Block::new(self.block_offsets as i64, meta as i32, data as i64);
+        self.block_offsets += meta + data;
+        Ok((block, checksum))
+    }
+
+    /// Adds a key-value pair to the custom metadata of the file footer, written by
+    /// [`Self::finish`] and returned by [`FileReader::custom_metadata`]
+    ///
+    /// This is distinct from the metadata of the schema, and of each record batch, and
+    /// is useful for file-level information, such as provenance, only known once all
+    /// batches have been written. Writing a key that already exists replaces its value
+    ///
+    /// [`FileReader::custom_metadata`]: crate::reader::FileReader::custom_metadata
     pub fn write_metadata(&mut self, key: impl Into<String>, value: impl Into<String>) {
         self.custom_metadata.insert(key.into(), value.into());
     }
 
     /// Write a record batch to the file
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, &HashMap::new())
+    }
+
+    /// Write a record batch to the file, along with custom metadata for the batch
+    ///
+    /// The metadata is stored in the record batch message, and can be read back with
+    /// [`FileReader::next_with_metadata`](crate::reader::FileReader::next_with_metadata)
+    pub fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IpcError(
                 "Cannot write record batch to file writer as it is closed".to_string(),
             ));
         }
 
-        let (encoded_dictionaries, encoded_message) = self.data_gen.encoded_batch(
-            batch,
+        let batch = with_written_schema(batch, &self.schema, &self.write_options)?;
+        let (encoded_dictionaries, encoded_messages) = encoded_batch_messages(
+            &self.data_gen,
+            &batch,
             &mut self.dictionary_tracker,
             &self.write_options,
+            custom_metadata,
         )?;
 
         for encoded_dictionary in encoded_dictionaries {
-            let (meta, data) =
-                write_message(&mut self.writer, encoded_dictionary, &self.write_options)?;
-
-            let block = crate::Block::new(self.block_offsets as i64, meta as i32, data as i64);
+            let (block, checksum) = self.write_block(encoded_dictionary)?;
             self.dictionary_blocks.push(block);
-            self.block_offsets += meta + data;
+            if let (Some((checksums, _)), Some(checksum)) = (&mut self.block_checksums, checksum) {
+                checksums.push(checksum);
+            }
         }
 
-        let (meta, data) = write_message(&mut self.writer, encoded_message, &self.write_options)?;
         // add a record block for the footer
-        let block = crate::Block::new(
-            self.block_offsets as i64,
-            meta as i32, // TODO: is this still applicable?
-            data as i64,
-        );
-        self.record_blocks.push(block);
-        self.block_offsets += meta + data;
+        for encoded_message in encoded_messages {
+            let (block, checksum) = self.write_block(encoded_message)?;
+            self.record_blocks.push(block);
+            if let (Some((_, checksums)), Some(checksum)) = (&mut self.block_checksums, checksum) {
+                checksums.push(checksum);
+            }
+        }
         Ok(())
     }
 
@@ -787,10 +1318,25 @@ impl<W: Write> FileWriter<W> {
         // write EOS
         write_continuation(&mut self.writer, &self.write_options, 0)?;
 
+        if let Some((dictionaries, record_batches)) = &self.block_checksums {
+            self.custom_metadata.insert(
+                DICTIONARY_CHECKSUMS_KEY.to_string(),
+                format_checksums(dictionaries),
+            );
+            self.custom_metadata.insert(
+                RECORD_BATCH_CHECKSUMS_KEY.to_string(),
+                format_checksums(record_batches),
+            );
+        }
+
         let mut fbb = FlatBufferBuilder::new();
         let dictionaries = fbb.create_vector(&self.dictionary_blocks);
         let record_batches = fbb.create_vector(&self.record_blocks);
-        let schema = crate::convert::schema_to_fb_offset(&mut fbb, &self.schema);
+        let schema = schema_to_fb_offset_with_endianness(
+            &mut fbb,
+            &self.schema,
+            self.write_options.endianness,
+        );
         let fb_custom_metadata = (!self.custom_metadata.is_empty())
             .then(|| crate::convert::metadata_to_fb(&mut fbb, &self.custom_metadata));
 
@@ -817,6 +1363,12 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Flushes the data written so far to the underlying writer
+    pub fn flush(&mut self) -> Result<(), ArrowError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
     /// Returns the arrow [`SchemaRef`] for this arrow file.
     pub fn schema(&self) -> &SchemaRef {
         &self.schema
@@ -862,10 +1414,14 @@ pub struct StreamWriter<W: Write> {
     writer: BufWriter<W>,
     /// IPC write options
     write_options: IpcWriteOptions,
+    /// The written schema
+    schema: SchemaRef,
     /// Whether the writer footer has been written, and the writer is finished
     finished: bool,
     /// Keeps track of dictionaries that have been written
     dictionary_tracker: DictionaryTracker,
+    /// The original and written id of each dictionary field
+    dictionary_ids: Vec<(i64, i64)>,
 
     data_gen: IpcDataGenerator,
 }
@@ -885,35 +1441,65 @@ impl<W: Write> StreamWriter<W> {
         let data_gen = IpcDataGenerator::default();
         let mut writer = BufWriter::new(writer);
         // write the schema, set the written bytes to the schema
-        let encoded_message = data_gen.schema_to_bytes(schema, &write_options);
+        let (schema, dictionary_ids) =
+            assign_dictionary_ids(schema, write_options.dictionary_id_strategy);
+        let encoded_message = data_gen.schema_to_bytes(&schema, &write_options);
         write_message(&mut writer, encoded_message, &write_options)?;
+        let dictionary_tracker =
+            DictionaryTracker::new_with_handling(write_options.dictionary_handling);
         Ok(Self {
             writer,
             write_options,
+            schema: Arc::new(schema),
             finished: false,
-            dictionary_tracker: DictionaryTracker::new(false),
+            dictionary_tracker,
+            dictionary_ids,
             data_gen,
         })
     }
 
+    /// Returns the original and written id of each dictionary field of the schema,
+    /// in depth-first order, see [`IpcWriteOptions::with_dictionary_id_strategy`]
+    pub fn dictionary_ids(&self) -> &[(i64, i64)] {
+        &self.dictionary_ids
+    }
+
     /// Write a record batch to the stream
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, &HashMap::new())
+    }
+
+    /// Write a record batch to the stream, along with custom metadata for the batch
+    ///
+    /// The metadata is stored in the record batch message, and can be read back with
+    /// [`StreamReader::next_with_metadata`](crate::reader::StreamReader::next_with_metadata)
+    pub fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IpcError(
                 "Cannot write record batch to stream writer as it is closed".to_string(),
             ));
         }
 
-        let (encoded_dictionaries, encoded_message) = self
-            .data_gen
-            .encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)
-            .expect("StreamWriter is configured to not error on dictionary replacement");
+        let batch = with_written_schema(batch, &self.schema, &self.write_options)?;
+        let (encoded_dictionaries, encoded_messages) = encoded_batch_messages(
+            &self.data_gen,
+            &batch,
+            &mut self.dictionary_tracker,
+            &self.write_options,
+            custom_metadata,
+        )?;
 
         for encoded_dictionary in encoded_dictionaries {
             write_message(&mut self.writer, encoded_dictionary, &self.write_options)?;
         }
 
-        write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        for encoded_message in encoded_messages {
+            write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        }
         Ok(())
     }
 
@@ -1008,11 +1594,70 @@ pub struct EncodedData {
     /// Arrow buffers to be written, should be an empty vec for schema messages
     pub arrow_data: Vec<u8>,
 }
+/// Encodes `batch` as [`IpcDataGenerator::encoded_batch_with_metadata`], returning the
+/// encoded dictionaries, and one or more record batch messages no larger than
+/// [`IpcWriteOptions::with_max_encoded_message_size`], where possible
+fn encoded_batch_messages(
+    data_gen: &IpcDataGenerator,
+    batch: &RecordBatch,
+    dictionary_tracker: &mut DictionaryTracker,
+    write_options: &IpcWriteOptions,
+    custom_metadata: &HashMap<String, String>,
+) -> Result<(Vec<EncodedData>, Vec<EncodedData>), ArrowError> {
+    let (encoded_dictionaries, encoded_message) = data_gen.encoded_batch_with_metadata(
+        batch,
+        dictionary_tracker,
+        write_options,
+        custom_metadata,
+    )?;
+    let max_size = match write_options.max_encoded_message_size {
+        Some(max_size) => max_size,
+        None => return Ok((encoded_dictionaries, vec![encoded_message])),
+    };
+
+    let mut encoded_messages = vec![];
+    // Slices of `batch`, in reverse order, along with their encoded message
+    let mut pending = vec![(batch.clone(), encoded_message)];
+    while let Some((batch, encoded_message)) = pending.pop() {
+        let size = encoded_message.ipc_message.len() + encoded_message.arrow_data.len();
+        if size <= max_size || batch.num_rows() <= 1 {
+            encoded_messages.push(encoded_message);
+            continue;
+        }
+        let mid = batch.num_rows() / 2;
+        for slice in [
+            batch.slice(mid, batch.num_rows() - mid),
+            batch.slice(0, mid),
+        ] {
+            // The slices share the dictionaries of `batch`, which have already been encoded
+            let (_, encoded_message) = data_gen.encoded_batch_with_metadata(
+                &slice,
+                dictionary_tracker,
+                write_options,
+                custom_metadata,
+            )?;
+            pending.push((slice, encoded_message));
+        }
+    }
+    Ok((encoded_dictionaries, encoded_messages))
+}
+
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
 pub fn write_message<W: Write>(
+    writer: W,
+    encoded: EncodedData,
+    write_options: &IpcWriteOptions,
+) -> Result<(usize, usize), ArrowError> {
+    write_message_at(writer, encoded, write_options, 0)
+}
+
+/// Write a message's IPC data and buffers at byte `position` of the output, padding the
+/// metadata such that the message body starts at a multiple of the configured alignment
+fn write_message_at<W: Write>(
     mut writer: W,
     encoded: EncodedData,
     write_options: &IpcWriteOptions,
+    position: usize,
 ) -> Result<(usize, usize), ArrowError> {
     let arrow_data_len = encoded.arrow_data.len();
     if arrow_data_len % 8 != 0 {
@@ -1021,7 +1666,6 @@ pub fn write_message<W: Write>(
         ));
     }
 
-    let a = write_options.alignment - 1;
     let buffer = encoded.ipc_message;
     let flatbuf_size = buffer.len();
     let prefix_size = if write_options.write_legacy_ipc_format {
@@ -1029,7 +1673,9 @@ pub fn write_message<W: Write>(
     } else {
         8
     };
-    let aligned_size = (flatbuf_size + prefix_size + a) & !a;
+    let unpadded_size = flatbuf_size + prefix_size;
+    let aligned_size =
+        unpadded_size + pad_to_alignment(write_options.alignment, position + unpadded_size);
     let padding_bytes = aligned_size - flatbuf_size - prefix_size;
 
     write_continuation(
@@ -1047,7 +1693,7 @@ pub fn write_message<W: Write>(
 
     // write arrow data
     let body_len = if arrow_data_len > 0 {
-        write_body_buffers(&mut writer, &encoded.arrow_data)?
+        write_body_buffers(&mut writer, &encoded.arrow_data, write_options.alignment)?
     } else {
         0
     };
@@ -1055,19 +1701,23 @@ pub fn write_message<W: Write>(
     Ok((aligned_size, body_len))
 }
 
-fn write_body_buffers<W: Write>(mut writer: W, data: &[u8]) -> Result<usize, ArrowError> {
-    let len = data.len() as u32;
-    let pad_len = pad_to_8(len) as u32;
+fn write_body_buffers<W: Write>(
+    mut writer: W,
+    data: &[u8],
+    alignment: usize,
+) -> Result<usize, ArrowError> {
+    let len = data.len();
+    let pad_len = pad_to_alignment(alignment, len);
     let total_len = len + pad_len;
 
     // write body buffer
     writer.write_all(data)?;
     if pad_len > 0 {
-        writer.write_all(&vec![0u8; pad_len as usize][..])?;
+        writer.write_all(&vec![0u8; pad_len][..])?;
     }
 
     writer.flush()?;
-    Ok(total_len as usize)
+    Ok(total_len)
 }
 
 /// Write a record batch to the writer, writing the message size before the message
@@ -1192,6 +1842,71 @@ fn get_list_array_buffers<O: OffsetSizeTrait>(data: &ArrayData) -> (Buffer, Arra
     (offsets, child_data)
 }
 
+/// Returns the type ids and, for dense unions, the offsets [`Buffer`] of a UnionArray,
+/// along with its children sliced to the values it references
+///
+/// The offsets of a dense union are re-encoded relative to the first value of each
+/// child referenced by the array, so that values of the children that have been
+/// sliced away are not encoded
+fn get_union_array_buffers(data: &ArrayData, mode: UnionMode) -> (Vec<Buffer>, Vec<ArrayData>) {
+    let type_ids = data.buffers()[0].slice_with_length(data.offset(), data.len());
+    let fields = match data.data_type() {
+        DataType::Union(fields, _) => fields,
+        _ => unreachable!(),
+    };
+
+    if mode == UnionMode::Sparse {
+        let children = data
+            .child_data()
+            .iter()
+            .map(|child| child.slice(data.offset(), data.len()))
+            .collect();
+        return (vec![type_ids], children);
+    }
+
+    let offsets = &data.buffer::<i32>(1)[..data.len()];
+    let max_id = fields.iter().map(|(id, _)| id).max().unwrap_or_default() as usize;
+    let mut ranges = vec![(i32::MAX, -1); max_id + 1];
+    for (id, offset) in type_ids.iter().zip(offsets) {
+        let (start, end) = &mut ranges[*id as usize];
+        *start = (*start).min(*offset);
+        *end = (*end).max(*offset);
+    }
+
+    let offsets = match ranges.iter().all(|(start, end)| *start == 0 || *end < 0) {
+        true => data.buffers()[1].slice_with_length(data.offset() * 4, data.len() * 4),
+        false => type_ids
+            .iter()
+            .zip(offsets)
+            .map(|(id, offset)| offset - ranges[*id as usize].0)
+            .collect(),
+    };
+
+    let children = data
+        .child_data()
+        .iter()
+        .zip(fields.iter())
+        .map(|(child, (id, _))| match ranges[id as usize] {
+            (_, -1) => child.slice(0, 0),
+            (start, end) => child.slice(start as usize, (end - start) as usize + 1),
+        })
+        .collect();
+    (vec![type_ids, offsets], children)
+}
+
+/// Converts `buffer` of elements with the given components, see [`value_components`],
+/// to the endianness of `write_options`
+fn to_endianness<'a>(
+    buffer: &'a [u8],
+    components: &[usize],
+    write_options: &IpcWriteOptions,
+) -> Cow<'a, [u8]> {
+    if components.is_empty() || write_options.endianness == NATIVE_ENDIANNESS {
+        return Cow::Borrowed(buffer);
+    }
+    Cow::Owned(swap_components(buffer, components))
+}
+
 /// Write array data to a vector of bytes
 #[allow(clippy::too_many_arguments)]
 fn write_array_data(
@@ -1232,30 +1947,35 @@ fn write_array_data(
             arrow_data,
             offset,
             compression_codec,
+            write_options.alignment,
         )?;
     }
 
     let data_type = array_data.data_type();
     if matches!(data_type, DataType::Binary | DataType::Utf8) {
         let (offsets, values) = get_byte_array_buffers::<i32>(array_data);
-        for buffer in [offsets, values] {
+        let offsets = to_endianness(offsets.as_slice(), &[4], write_options);
+        for buffer in [offsets.as_ref(), values.as_slice()] {
             offset = write_buffer(
-                buffer.as_slice(),
+                buffer,
                 buffers,
                 arrow_data,
                 offset,
                 compression_codec,
+                write_options.alignment,
             )?;
         }
     } else if matches!(data_type, DataType::LargeBinary | DataType::LargeUtf8) {
         let (offsets, values) = get_byte_array_buffers::<i64>(array_data);
-        for buffer in [offsets, values] {
+        let offsets = to_endianness(offsets.as_slice(), &[8], write_options);
+        for buffer in [offsets.as_ref(), values.as_slice()] {
             offset = write_buffer(
-                buffer.as_slice(),
+                buffer,
                 buffers,
                 arrow_data,
                 offset,
                 compression_codec,
+                write_options.alignment,
             )?;
         }
     } else if DataType::is_numeric(data_type)
@@ -1281,7 +2001,15 @@ fn write_array_data(
         } else {
             buffer.as_slice()
         };
-        offset = write_buffer(buffer_slice, buffers, arrow_data, offset, compression_codec)?;
+        let components = value_components(data_type);
+        offset = write_buffer(
+            &to_endianness(buffer_slice, &components, write_options),
+            buffers,
+            arrow_data,
+            offset,
+            compression_codec,
+            write_options.alignment,
+        )?;
     } else if matches!(data_type, DataType::Boolean) {
         // Bools are special because the payload (= 1 bit) is smaller than the physical container elements (= bytes).
         // The array data may not start at the physical boundary of the underlying buffer, so we need to shift bits around.
@@ -1289,7 +2017,14 @@ fn write_array_data(
 
         let buffer = &array_data.buffers()[0];
         let buffer = buffer.bit_slice(array_data.offset(), array_data.len());
-        offset = write_buffer(&buffer, buffers, arrow_data, offset, compression_codec)?;
+        offset = write_buffer(
+            &buffer,
+            buffers,
+            arrow_data,
+            offset,
+            compression_codec,
+            write_options.alignment,
+        )?;
     } else if matches!(
         data_type,
         DataType::List(_) | DataType::LargeList(_) | DataType::Map(_, _)
@@ -1304,12 +2039,17 @@ fn write_array_data(
             DataType::LargeList(_) => get_list_array_buffers::<i64>(array_data),
             _ => unreachable!(),
         };
+        let offset_width = match data_type {
+            DataType::LargeList(_) => 8,
+            _ => 4,
+        };
         offset = write_buffer(
-            offsets.as_slice(),
+            &to_endianness(offsets.as_slice(), &[offset_width], write_options),
             buffers,
             arrow_data,
             offset,
             compression_codec,
+            write_options.alignment,
         )?;
         offset = write_array_data(
             &sliced_child_data,
@@ -1323,14 +2063,67 @@ fn write_array_data(
             write_options,
         )?;
         return Ok(offset);
+    } else if let DataType::Union(_, mode) = data_type {
+        // Truncate the type ids, offsets and children to avoid writing unnecessary data
+        let (union_buffers, children) = get_union_array_buffers(array_data, *mode);
+        for (idx, buffer) in union_buffers.iter().enumerate() {
+            // The second buffer of a dense union contains 32-bit offsets
+            let components: &[usize] = if idx == 1 { &[4] } else { &[] };
+            offset = write_buffer(
+                &to_endianness(buffer, components, write_options),
+                buffers,
+                arrow_data,
+                offset,
+                compression_codec,
+                write_options.alignment,
+            )?;
+        }
+        for child in &children {
+            offset = write_array_data(
+                child,
+                buffers,
+                arrow_data,
+                nodes,
+                offset,
+                child.len(),
+                child.null_count(),
+                compression_codec,
+                write_options,
+            )?;
+        }
+        return Ok(offset);
     } else {
         for buffer in array_data.buffers() {
-            offset = write_buffer(buffer, buffers, arrow_data, offset, compression_codec)?;
+            offset = write_buffer(
+                buffer,
+                buffers,
+                arrow_data,
+                offset,
+                compression_codec,
+                write_options.alignment,
+            )?;
         }
     }
 
     match array_data.data_type() {
         DataType::Dictionary(_, _) => {}
+        DataType::FixedSizeList(_, size) => {
+            // Truncate the child data to the values of the sliced lists
+            let size = *size as usize;
+            let child = array_data.child_data()[0]
+                .slice(array_data.offset() * size, array_data.len() * size);
+            offset = write_array_data(
+                &child,
+                buffers,
+                arrow_data,
+                nodes,
+                offset,
+                child.len(),
+                child.null_count(),
+                compression_codec,
+                write_options,
+            )?;
+        }
         DataType::RunEndEncoded(_, _) => {
             // unslice the run encoded array.
             let arr = unslice_run_array(array_data.clone())?;
@@ -1389,6 +2182,7 @@ fn write_buffer(
     arrow_data: &mut Vec<u8>,         // output stream
     offset: i64,                      // current output stream offset
     compression_codec: Option<CompressionCodec>,
+    alignment: usize,
 ) -> Result<i64, ArrowError> {
     let len: i64 = match compression_codec {
         Some(compressor) => compressor.compress_to_vec(buffer, arrow_data)?,
@@ -1404,17 +2198,23 @@ fn write_buffer(
 
     // make new index entry
     buffers.push(crate::Buffer::new(offset, len));
-    // padding and make offset 8 bytes aligned
-    let pad_len = pad_to_8(len as u32) as i64;
+    // padding and make offset aligned
+    let pad_len = pad_to_alignment(alignment, len as usize) as i64;
     arrow_data.extend_from_slice(&vec![0u8; pad_len as usize][..]);
 
     Ok(offset + len + pad_len)
 }
 
-/// Calculate an 8-byte boundary and return the number of bytes needed to pad to 8 bytes
+/// Returns the number of bytes needed to pad `len` to a multiple of `alignment`
 #[inline]
-fn pad_to_8(len: u32) -> usize {
-    (((len + 7) & !7) - len) as usize
+fn pad_to_alignment(alignment: usize, len: usize) -> usize {
+    match alignment.is_power_of_two() {
+        true => {
+            let a = alignment - 1;
+            ((len + a) & !a) - len
+        }
+        false => (alignment - len % alignment) % alignment,
+    }
 }
 
 #[cfg(test)]
@@ -1448,7 +2248,11 @@ mod tests {
     }
 
     fn serialize_stream(record: &RecordBatch) -> Vec<u8> {
-        let mut stream_writer = StreamWriter::try_new(vec![], &record.schema()).unwrap();
+        // Use 8-byte alignment so that the `truncate_*` tests can use small arrays
+        // without all of the buffers being padded up to the default of 64 bytes
+        let options = IpcWriteOptions::default().try_with_alignment(8).unwrap();
+        let mut stream_writer =
+            StreamWriter::try_new_with_options(vec![], &record.schema(), options).unwrap();
         stream_writer.write(record).unwrap();
         stream_writer.finish().unwrap();
         stream_writer.into_inner().unwrap()
@@ -1459,6 +2263,168 @@ mod tests {
         stream_reader.next().unwrap().unwrap()
     }
 
+    #[test]
+    fn test_try_with_compression() {
+        let options = IpcWriteOptions::try_new(8, false, crate::MetadataVersion::V4).unwrap();
+        let err = options
+            .try_with_compression(Some(crate::CompressionType::ZSTD))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Compression only supported in metadata v5 and above"
+        );
+
+        let options = IpcWriteOptions::default();
+        for (compression, name, enabled) in [
+            (
+                crate::CompressionType::LZ4_FRAME,
+                "lz4",
+                cfg!(feature = "lz4"),
+            ),
+            (crate::CompressionType::ZSTD, "zstd", cfg!(feature = "zstd")),
+        ] {
+            let result = options.clone().try_with_compression(Some(compression));
+            match enabled {
+                true => assert!(result.is_ok()),
+                false => assert_eq!(
+                    result.unwrap_err().to_string(),
+                    format!("Invalid argument error: {name} IPC compression requires the {name} feature")
+                ),
+            }
+        }
+        assert!(options.try_with_compression(None).is_ok());
+    }
+
+    #[test]
+    fn test_write_endianness() {
+        let other = match NATIVE_ENDIANNESS {
+            crate::Endianness::Little => crate::Endianness::Big,
+            _ => crate::Endianness::Little,
+        };
+        let swapped = IpcWriteOptions::default()
+            .try_with_endianness(other)
+            .unwrap();
+        let err = IpcWriteOptions::default()
+            .try_with_endianness(crate::Endianness(3))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid endianness <UNKNOWN 3>"
+        );
+
+        // The endianness is recorded in the schema
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        for (options, expected) in [
+            (IpcWriteOptions::default(), NATIVE_ENDIANNESS),
+            (swapped.clone(), other),
+        ] {
+            let encoded = IpcDataGenerator::default().schema_to_bytes(&schema, &options);
+            let message = crate::root_as_message(&encoded.ipc_message).unwrap();
+            assert_eq!(message.header_as_schema().unwrap().endianness(), expected);
+        }
+        let mut writer =
+            FileWriter::try_new_with_options(vec![], &schema, swapped.clone()).unwrap();
+        writer.finish().unwrap();
+        let reader = FileReader::try_new(Cursor::new(writer.into_inner().unwrap()), None).unwrap();
+        assert_eq!(reader.schema().as_ref(), &schema);
+
+        // Compares the buffers written with native and swapped endianness, where
+        // `components` are the components of the elements of each buffer
+        let check = |array: ArrayRef, components: &[&[usize]]| {
+            let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+            let encode = |options: &IpcWriteOptions| {
+                let mut tracker = DictionaryTracker::new(false);
+                IpcDataGenerator::default()
+                    .encoded_batch(&batch, &mut tracker, options)
+                    .unwrap()
+                    .1
+            };
+            let native = encode(&IpcWriteOptions::default());
+            let encoded = encode(&swapped);
+            assert_eq!(native.ipc_message, encoded.ipc_message);
+
+            let message = crate::root_as_message(&native.ipc_message).unwrap();
+            let buffers = message.header_as_record_batch().unwrap().buffers().unwrap();
+            assert_eq!(buffers.len(), components.len());
+            for (buffer, components) in buffers.iter().zip(components) {
+                let range = buffer.offset() as usize..(buffer.offset() + buffer.length()) as usize;
+                let mut expected = native.arrow_data[range.clone()].to_vec();
+                if !components.is_empty() {
+                    let width: usize = components.iter().sum();
+                    for element in expected.chunks_exact_mut(width) {
+                        let mut start = 0;
+                        for w in components.iter() {
+                            element[start..start + w].reverse();
+                            start += w;
+                        }
+                    }
+                }
+                assert_eq!(&encoded.arrow_data[range], expected.as_slice());
+            }
+        };
+
+        check(
+            Arc::new(Int32Array::from(vec![Some(0x01020304), None, Some(-1)])),
+            &[&[], &[4]],
+        );
+        check(
+            Arc::new(StringArray::from(vec!["a", "bcd", ""]).slice(1, 2)),
+            &[&[], &[4], &[]],
+        );
+        check(
+            Arc::new(LargeBinaryArray::from_vec(vec![b"ab", b"c"])),
+            &[&[], &[8], &[]],
+        );
+        check(
+            Arc::new(IntervalMonthDayNanoArray::from(vec![
+                IntervalMonthDayNanoType::make_value(1, 2, 3),
+            ])),
+            &[&[], &[4, 4, 8]],
+        );
+        check(
+            Arc::new(IntervalDayTimeArray::from(vec![
+                IntervalDayTimeType::make_value(1, -2),
+            ])),
+            &[&[], &[4, 4]],
+        );
+        check(
+            Arc::new(Decimal128Array::from(vec![1234, -5])),
+            &[&[], &[16]],
+        );
+        check(Arc::new(BooleanArray::from(vec![true, false])), &[&[], &[]]);
+        check(
+            Arc::new(FixedSizeBinaryArray::from(vec![b"abcd".as_slice()])),
+            &[&[], &[]],
+        );
+        check(
+            Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+                Some(vec![Some(1), None]),
+                None,
+                Some(vec![Some(3)]),
+            ])),
+            &[&[], &[4], &[], &[8]],
+        );
+        let dict: DictionaryArray<Int16Type> = vec!["x", "y", "x"].into_iter().collect();
+        check(Arc::new(dict), &[&[], &[2]]);
+        let union = UnionArray::try_new(
+            &[0, 1],
+            Buffer::from_slice_ref([0_i8, 1, 0]),
+            Some(Buffer::from_slice_ref([0_i32, 0, 1])),
+            vec![
+                (
+                    Field::new("a", DataType::Int32, false),
+                    Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+                ),
+                (
+                    Field::new("b", DataType::Float64, false),
+                    Arc::new(Float64Array::from(vec![1.5])) as ArrayRef,
+                ),
+            ],
+        )
+        .unwrap();
+        check(Arc::new(union), &[&[], &[4], &[], &[4], &[], &[8]]);
+    }
+
     #[test]
     #[cfg(feature = "lz4")]
     fn test_write_empty_record_batch_lz4_compression() {
@@ -1996,6 +2962,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_write_sliced_nested() {
+        let len = 100_000;
+        let mut dense = UnionBuilder::with_capacity_dense(len);
+        let mut sparse = UnionBuilder::with_capacity_sparse(len);
+        for i in 0..len {
+            if i % 3 == 0 {
+                dense.append::<Float64Type>("b", i as f64).unwrap();
+                sparse.append::<Float64Type>("b", i as f64).unwrap();
+            } else {
+                dense.append::<Int32Type>("a", i as i32).unwrap();
+                sparse.append::<Int32Type>("a", i as i32).unwrap();
+            }
+        }
+        let dense = dense.build().unwrap();
+        let sparse = sparse.build().unwrap();
+
+        let values = Int32Array::from_iter_values(0..len as i32 * 4);
+        let item = Arc::new(Field::new("item", DataType::Int32, false));
+        let fixed = FixedSizeListArray::new(item, 4, Arc::new(values), None);
+
+        let bools = BooleanArray::from_iter((0..len).map(|i| Some(i % 5 == 0)));
+        let strings = StringArray::from_iter_values((0..len).map(|i| format!("value{i}")));
+        let structs = StructArray::from(vec![
+            (
+                Arc::new(Field::new("bool", DataType::Boolean, false)),
+                Arc::new(bools) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("string", DataType::Utf8, false)),
+                Arc::new(strings) as ArrayRef,
+            ),
+        ]);
+
+        let batch = RecordBatch::try_from_iter([
+            ("dense", Arc::new(dense) as ArrayRef),
+            ("sparse", Arc::new(sparse) as ArrayRef),
+            ("fixed", Arc::new(fixed) as ArrayRef),
+            ("struct", Arc::new(structs) as ArrayRef),
+        ])
+        .unwrap();
+        let full = serialize_stream(&batch).len();
+
+        for (offset, length) in [(50_001, 1000), (0, 1000), (len - 1000, 1000), (7, 0)] {
+            let slice = batch.slice(offset, length);
+            let bytes = serialize_stream(&slice);
+            assert!(bytes.len() < full / 50, "{} >= {}", bytes.len(), full / 50);
+            assert_eq!(deserialize_stream(bytes), slice);
+
+            // Arrays sliced through ArrayData retain an offset into their buffers
+            let columns = ["dense", "fixed", "struct"].map(|name| {
+                let data = batch.column_by_name(name).unwrap().to_data();
+                (name, make_array(data.slice(offset, length)))
+            });
+            let slice = RecordBatch::try_from_iter(columns).unwrap();
+            let bytes = serialize_stream(&slice);
+            assert!(bytes.len() < full / 50, "{} >= {}", bytes.len(), full / 50);
+            assert_eq!(deserialize_stream(bytes), slice);
+        }
+    }
+
     #[test]
     fn encode_bools_slice() {
         // Test case for https://github.com/apache/arrow-rs/issues/3496
@@ -2234,4 +3261,325 @@ mod tests {
         let in_batch = RecordBatch::try_new(schema, vec![values]).unwrap();
         roundtrip_ensure_sliced_smaller(in_batch, 1000);
     }
+
+    fn assert_file_alignment(bytes: &[u8], alignment: usize) {
+        let footer_len_start = bytes.len() - 10;
+        let footer_len = i32::from_le_bytes(
+            bytes[footer_len_start..footer_len_start + 4]
+                .try_into()
+                .unwrap(),
+        );
+        let footer_start = footer_len_start - footer_len as usize;
+        let footer = crate::root_as_footer(&bytes[footer_start..footer_len_start]).unwrap();
+
+        let blocks = footer.recordBatches().unwrap().iter();
+        let blocks = blocks.chain(footer.dictionaries().unwrap().iter());
+        for block in blocks {
+            let body_start = block.offset() as usize + block.metaDataLength() as usize;
+            assert_eq!(body_start % alignment, 0);
+            assert_eq!(block.bodyLength() as usize % alignment, 0);
+
+            let meta_start = block.offset() as usize + 8;
+            let message = crate::root_as_message(&bytes[meta_start..body_start]).unwrap();
+            let buffers = match message.header_as_record_batch() {
+                Some(batch) => batch.buffers().unwrap(),
+                None => {
+                    let dictionary = message.header_as_dictionary_batch().unwrap();
+                    dictionary.data().unwrap().buffers().unwrap()
+                }
+            };
+            for buffer in buffers {
+                assert_eq!(buffer.offset() as usize % alignment, 0);
+            }
+        }
+    }
+
+    fn alignment_batch() -> RecordBatch {
+        let a = Int8Array::from(vec![Some(1), None, Some(3)]);
+        let b = StringArray::from(vec!["a", "bb", "ccc"]);
+        let c: DictionaryArray<Int32Type> = vec!["x", "y", "x"].into_iter().collect();
+        RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+            ("c", Arc::new(c) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    fn serialize_file_with_alignment(batch: &RecordBatch, alignment: usize) -> Vec<u8> {
+        let options = IpcWriteOptions::default()
+            .try_with_alignment(alignment)
+            .unwrap();
+        let mut writer =
+            FileWriter::try_new_with_options(vec![], &batch.schema(), options).unwrap();
+        writer.write(batch).unwrap();
+        writer.write(batch).unwrap();
+        writer.finish().unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_file_alignment() {
+        let batch = alignment_batch();
+
+        let aligned_64 = serialize_file_with_alignment(&batch, 64);
+        assert_file_alignment(&aligned_64, 64);
+
+        let aligned_8 = serialize_file_with_alignment(&batch, 8);
+        assert_file_alignment(&aligned_8, 8);
+        assert!(aligned_8.len() < aligned_64.len());
+
+        // Alignments that are not a power of two are still accepted by try_new
+        let options = IpcWriteOptions::try_new(24, false, crate::MetadataVersion::V5).unwrap();
+        let mut writer =
+            FileWriter::try_new_with_options(vec![], &batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        let aligned_24 = writer.into_inner().unwrap();
+        assert_file_alignment(&aligned_24, 24);
+
+        for bytes in [aligned_8, aligned_24, aligned_64] {
+            let reader = FileReader::try_new(Cursor::new(bytes), None).unwrap();
+            for read in reader {
+                assert_eq!(read.unwrap(), batch);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_alignment() {
+        let batch = alignment_batch();
+        let options = IpcWriteOptions::default().try_with_alignment(64).unwrap();
+        let mut writer =
+            StreamWriter::try_new_with_options(vec![], &batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        let bytes = writer.into_inner().unwrap();
+        // the stream is terminated by an 8 byte end-of-stream marker
+        assert_eq!((bytes.len() - 8) % 64, 0);
+
+        let reader = StreamReader::try_new(Cursor::new(bytes), None).unwrap();
+        for read in reader {
+            assert_eq!(read.unwrap(), batch);
+        }
+    }
+
+    #[test]
+    fn test_invalid_alignment() {
+        for alignment in [0, 4, 12, 24, 48] {
+            let err = IpcWriteOptions::default()
+                .try_with_alignment(alignment)
+                .unwrap_err();
+            let err = err.to_string();
+            assert!(err.contains("power of two") || err.contains("multiple of 8"));
+        }
+        for alignment in [0, 4, 12] {
+            assert!(
+                IpcWriteOptions::try_new(alignment, false, crate::MetadataVersion::V5).is_err()
+            );
+        }
+        IpcWriteOptions::default().try_with_alignment(16).unwrap();
+    }
+
+    #[test]
+    fn test_dictionary_handling() {
+        let batch = |keys: Vec<i32>, values: Vec<&str>| {
+            let dict =
+                DictionaryArray::new(Int32Array::from(keys), Arc::new(StringArray::from(values)));
+            RecordBatch::try_from_iter([("d", Arc::new(dict) as ArrayRef)]).unwrap()
+        };
+        // Returns the logical string values of the dictionary column of `batch`
+        let strings = |batch: &RecordBatch| -> Vec<String> {
+            let dict = batch.column(0).as_dictionary::<Int32Type>();
+            let values = dict.downcast_dict::<StringArray>().unwrap();
+            values.into_iter().map(|v| v.unwrap().to_string()).collect()
+        };
+        let batches = [
+            batch(vec![0, 1], vec!["a", "b"]),
+            batch(vec![2, 0], vec!["a", "b", "c"]),
+            batch(vec![3], vec!["a", "b", "c", "d"]),
+            // Not an extension of the previous values
+            batch(vec![0, 1], vec!["x", "y"]),
+        ];
+        let write_stream = |handling, batches: &[RecordBatch]| {
+            // Use the minimum alignment, so that the sizes of the messages differ
+            let options = IpcWriteOptions::default()
+                .try_with_alignment(8)?
+                .with_dictionary_handling(handling);
+            let schema = batches[0].schema();
+            let mut writer = StreamWriter::try_new_with_options(vec![], &schema, options)?;
+            for batch in batches {
+                writer.write(batch)?;
+            }
+            writer.into_inner()
+        };
+        let read_stream = |stream: Vec<u8>| {
+            let reader = StreamReader::try_new(Cursor::new(stream), None).unwrap();
+            reader.map(|b| strings(&b.unwrap())).collect::<Vec<_>>()
+        };
+        let expected: Vec<_> = batches.iter().map(strings).collect();
+
+        let replace = write_stream(DictionaryHandling::Replace, &batches).unwrap();
+        let delta = write_stream(DictionaryHandling::Delta, &batches).unwrap();
+        assert!(delta.len() < replace.len());
+        assert_eq!(read_stream(replace), expected);
+        assert_eq!(read_stream(delta), expected);
+
+        let err = write_stream(DictionaryHandling::Error, &batches).unwrap_err();
+        assert!(err.to_string().contains("Dictionary replacement detected"));
+
+        // Files only support deltas
+        let schema = batches[0].schema();
+        let options =
+            IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Delta);
+        let mut writer = FileWriter::try_new_with_options(vec![], &schema, options).unwrap();
+        for batch in &batches[..3] {
+            writer.write(batch).unwrap();
+        }
+        let err = writer.write(&batches[3]).unwrap_err();
+        assert!(err.to_string().contains("Dictionary replacement detected"));
+        writer.finish().unwrap();
+
+        let file = writer.into_inner().unwrap();
+        let reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+        let read: Vec<_> = reader.map(|b| strings(&b.unwrap())).collect();
+        assert_eq!(read, &expected[..3]);
+
+        let mut tracker = DictionaryTracker::new_with_handling(DictionaryHandling::Delta);
+        let column = batches[1].column(0);
+        assert_eq!(
+            tracker.insert_column(0, batches[0].column(0)).unwrap(),
+            DictionaryUpdate::New
+        );
+        let expected = StringArray::from(vec!["c"]).into_data();
+        assert_eq!(
+            tracker.insert_column(0, column).unwrap(),
+            DictionaryUpdate::Delta(expected)
+        );
+        assert_eq!(
+            tracker.insert_column(0, column).unwrap(),
+            DictionaryUpdate::None
+        );
+        let column = batches[3].column(0);
+        assert_eq!(
+            tracker.insert_column(0, column).unwrap(),
+            DictionaryUpdate::Replaced
+        );
+    }
+
+    #[test]
+    fn test_dictionary_id_strategy() {
+        let a: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let b: DictionaryArray<Int8Type> = vec!["x", "y", "z"].into_iter().collect();
+        let s = StructArray::from(vec![(
+            Arc::new(Field::new("c", b.data_type().clone(), true)),
+            Arc::new(b.clone()) as ArrayRef,
+        )]);
+        // All dictionary fields have an id of zero
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+            ("s", Arc::new(s) as ArrayRef),
+        ])
+        .unwrap();
+
+        let options =
+            IpcWriteOptions::default().with_dictionary_id_strategy(DictionaryIdStrategy::Assign);
+        let mut writer =
+            FileWriter::try_new_with_options(vec![], &batch.schema(), options.clone()).unwrap();
+        assert_eq!(writer.dictionary_ids(), &[(0, 0), (0, 1), (0, 2)]);
+        writer.write(&batch).unwrap();
+        let file = writer.into_inner().unwrap();
+
+        let mut reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+        let schema = reader.schema();
+        assert_eq!(schema.field(0).dict_id(), Some(0));
+        assert_eq!(schema.field(1).dict_id(), Some(1));
+        let DataType::Struct(fields) = schema.field(2).data_type() else {
+            unreachable!()
+        };
+        assert_eq!(fields[0].dict_id(), Some(2));
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read.columns(), batch.columns());
+
+        let mut writer =
+            StreamWriter::try_new_with_options(vec![], &batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        let stream = writer.into_inner().unwrap();
+        let mut reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+        assert_eq!(reader.schema().field(1).dict_id(), Some(1));
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read.columns(), batch.columns());
+
+        // The ids of the fields are preserved by default
+        let schema = Schema::new(vec![
+            Field::new_dict("a", batch.column(0).data_type().clone(), true, 7, false),
+            Field::new_dict("b", batch.column(1).data_type().clone(), true, 3, true),
+        ]);
+        let batch = batch.project(&[0, 1]).unwrap();
+        let batch = RecordBatch::try_new(Arc::new(schema), batch.columns().to_vec()).unwrap();
+        let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+        assert_eq!(writer.dictionary_ids(), &[(7, 7), (3, 3)]);
+        writer.write(&batch).unwrap();
+        let stream = writer.into_inner().unwrap();
+        let mut reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+        assert_eq!(reader.schema(), batch.schema());
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+    }
+
+    #[test]
+    fn test_max_encoded_message_size() {
+        let values: DictionaryArray<Int32Type> =
+            (0..1000).map(|x| ["a", "b", "c"][x % 3]).collect();
+        let batch = RecordBatch::try_from_iter([
+            (
+                "i",
+                Arc::new(Int32Array::from_iter_values(0..1000)) as ArrayRef,
+            ),
+            ("d", Arc::new(values) as ArrayRef),
+        ])
+        .unwrap();
+
+        let options = IpcWriteOptions::default().with_max_encoded_message_size(2048);
+        let mut writer =
+            StreamWriter::try_new_with_options(vec![], &batch.schema(), options.clone()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        let stream = writer.into_inner().unwrap();
+
+        let reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read.len(), 8);
+        let mut offset = 0;
+        for read in read {
+            assert_eq!(read, batch.slice(offset, read.num_rows()));
+            offset += read.num_rows();
+        }
+        assert_eq!(offset, batch.num_rows());
+
+        let mut writer =
+            FileWriter::try_new_with_options(vec![], &batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        let file = writer.into_inner().unwrap();
+
+        let reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+        assert_eq!(reader.num_batches(), 8);
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read.iter().map(|b| b.num_rows()).sum::<usize>(), 1000);
+        assert_eq!(read[0], batch.slice(0, 125));
+
+        // A single row larger than the limit is written on its own
+        let options = IpcWriteOptions::default().with_max_encoded_message_size(1);
+        let batch = batch.slice(0, 3);
+        let mut writer =
+            StreamWriter::try_new_with_options(vec![], &batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        let stream = writer.into_inner().unwrap();
+        let reader = StreamReader::try_new(stream.as_slice(), None).unwrap();
+        assert_eq!(
+            reader.map(|b| b.unwrap().num_rows()).collect::<Vec<_>>(),
+            vec![1, 1, 1]
+        );
+    }
 }
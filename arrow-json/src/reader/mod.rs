@@ -143,6 +143,7 @@ use arrow_array::timezone::Tz;
 use arrow_array::types::Float32Type;
 use arrow_array::types::*;
 use arrow_array::{downcast_integer, make_array, RecordBatch, RecordBatchReader, StructArray};
+use arrow_cast::date64::{apply_date64_policy_batch, Date64Policy};
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, FieldRef, Schema, SchemaRef, TimeUnit};
 pub use schema::*;
@@ -177,6 +178,7 @@ pub struct ReaderBuilder {
     coerce_primitive: bool,
     strict_mode: bool,
     is_field: bool,
+    date64_policy: Date64Policy,
 
     schema: SchemaRef,
 }
@@ -196,6 +198,7 @@ impl ReaderBuilder {
             coerce_primitive: false,
             strict_mode: false,
             is_field: false,
+            date64_policy: Date64Policy::Preserve,
             schema,
         }
     }
@@ -236,6 +239,7 @@ impl ReaderBuilder {
             coerce_primitive: false,
             strict_mode: false,
             is_field: true,
+            date64_policy: Date64Policy::Preserve,
             schema: Arc::new(Schema::new([field.into()])),
         }
     }
@@ -270,6 +274,15 @@ impl ReaderBuilder {
         }
     }
 
+    /// Sets how the decoder should handle [`DataType::Date64`] values that are not
+    /// aligned to midnight, defaults to [`Date64Policy::Preserve`]
+    pub fn with_date64_policy(self, date64_policy: Date64Policy) -> Self {
+        Self {
+            date64_policy,
+            ..self
+        }
+    }
+
     /// Create a [`Reader`] with the provided [`BufRead`]
     pub fn build<R: BufRead>(self, reader: R) -> Result<Reader<R>, ArrowError> {
         Ok(Reader {
@@ -297,6 +310,7 @@ impl ReaderBuilder {
             is_field: self.is_field,
             tape_decoder: TapeDecoder::new(self.batch_size, num_fields),
             batch_size: self.batch_size,
+            date64_policy: self.date64_policy,
             schema: self.schema,
         })
     }
@@ -397,6 +411,7 @@ pub struct Decoder {
     decoder: Box<dyn ArrayDecoder>,
     batch_size: usize,
     is_field: bool,
+    date64_policy: Date64Policy,
     schema: SchemaRef,
 }
 
@@ -631,7 +646,7 @@ impl Decoder {
             }
         };
 
-        Ok(Some(batch))
+        apply_date64_policy_batch(&batch, self.date64_policy).map(Some)
     }
 }
 
@@ -2047,6 +2062,44 @@ mod tests {
         assert_eq!(5, aa.value(7));
     }
 
+    #[test]
+    fn test_date64_policy() {
+        let schema = Arc::new(Schema::new(vec![Field::new_list(
+            "a",
+            Field::new("item", DataType::Date64, true),
+            true,
+        )]));
+        let buf = r#"{"a": [86400000, null, 86400001]}"#;
+        let read = |policy| {
+            ReaderBuilder::new(schema.clone())
+                .with_date64_policy(policy)
+                .build(Cursor::new(buf.as_bytes()))
+                .unwrap()
+                .next()
+                .unwrap()
+        };
+
+        let batch = read(Date64Policy::Preserve).unwrap();
+        let list = batch.column(0).as_list::<i32>();
+        assert_eq!(
+            list.values().as_primitive::<Date64Type>().value(2),
+            86400001
+        );
+
+        let batch = read(Date64Policy::Truncate).unwrap();
+        let list = batch.column(0).as_list::<i32>();
+        let values = list.values().as_primitive::<Date64Type>();
+        assert_eq!(values.value(0), 86400000);
+        assert!(values.is_null(1));
+        assert_eq!(values.value(2), 86400000);
+
+        let err = read(Date64Policy::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Date64 value 86400001 at index 2 is not a multiple of 86400000 milliseconds"
+        );
+    }
+
     #[test]
     fn test_time_from_json_nanoseconds() {
         let schema = Schema::new(vec![Field::new(
@@ -0,0 +1,477 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Approximate comparison of floating point arrays
+//!
+//! Floating point results of different computations, such as aggregations performed
+//! in a different order, rarely compare exactly equal. These kernels instead compare
+//! values within a [`Tolerance`].
+
+use std::collections::HashMap;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float16Type, Float32Type, Float64Type};
+use arrow_array::*;
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use arrow_schema::ArrowError;
+use half::f16;
+
+/// The maximum difference between two values considered equal by [`approx_eq`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// Values are equal if their absolute difference is at most this value
+    Absolute(f64),
+    /// Values are equal if their absolute difference is at most this value multiplied
+    /// by the larger of their magnitudes
+    Relative(f64),
+    /// Values are equal if there are at most this many representable values of their
+    /// type between them, known as units in the last place
+    Ulps(u64),
+}
+
+impl Tolerance {
+    fn validate(&self) -> Result<(), ArrowError> {
+        match self {
+            Self::Absolute(e) | Self::Relative(e) if e.is_nan() || *e < 0. => Err(
+                ArrowError::InvalidArgumentError(format!("Invalid tolerance {self:?}")),
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Perform `left ≈ right` operation on two [`Datum`], with the given [`Tolerance`]
+///
+/// Both sides must be floating point arrays of the same type. Values equal according to
+/// `==` are always considered equal, as are two `NaN`, whereas infinities are only
+/// equal to infinities of the same sign.
+///
+/// Nulls are handled as by [`crate::cmp::eq`], with the result null if either side is null
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Float64Array};
+/// # use arrow_ord::approx::{approx_eq, Tolerance};
+/// let a = Float64Array::from(vec![Some(1.0), Some(0.1 + 0.2), Some(f64::NAN), None]);
+/// let b = Float64Array::from(vec![Some(1.1), Some(0.3), Some(f64::NAN), Some(1.0)]);
+///
+/// let result = approx_eq(&a, &b, Tolerance::Absolute(1e-9)).unwrap();
+/// let expected = BooleanArray::from(vec![Some(false), Some(true), Some(true), None]);
+/// assert_eq!(result, expected);
+///
+/// let result = approx_eq(&a, &b, Tolerance::Ulps(1)).unwrap();
+/// assert_eq!(result, expected);
+/// ```
+pub fn approx_eq(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    tolerance: Tolerance,
+) -> Result<BooleanArray, ArrowError> {
+    use arrow_schema::DataType::*;
+    tolerance.validate()?;
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+
+    if l.len() != r.len() && !l_s && !r_s {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare arrays of different lengths, got {} vs {}",
+            l.len(),
+            r.len()
+        )));
+    }
+
+    match (l.data_type(), r.data_type()) {
+        (Float16, Float16) => Ok(apply(
+            l.as_primitive::<Float16Type>(),
+            l_s,
+            r.as_primitive::<Float16Type>(),
+            r_s,
+            tolerance,
+        )),
+        (Float32, Float32) => Ok(apply(
+            l.as_primitive::<Float32Type>(),
+            l_s,
+            r.as_primitive::<Float32Type>(),
+            r_s,
+            tolerance,
+        )),
+        (Float64, Float64) => Ok(apply(
+            l.as_primitive::<Float64Type>(),
+            l_s,
+            r.as_primitive::<Float64Type>(),
+            r_s,
+            tolerance,
+        )),
+        (l_t, r_t) => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid approximate comparison operation: {l_t} ≈ {r_t}"
+        ))),
+    }
+}
+
+/// The tolerances used by [`batches_approx_eq`] for each column
+///
+/// ```
+/// # use arrow_ord::approx::{BatchTolerance, Tolerance};
+/// let tolerance = BatchTolerance::new()
+///     .with_default(Tolerance::Ulps(4))
+///     .with_column("total", Tolerance::Relative(1e-6));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchTolerance {
+    default: Option<Tolerance>,
+    columns: HashMap<String, Tolerance>,
+}
+
+impl BatchTolerance {
+    /// Create a new [`BatchTolerance`], comparing all columns exactly
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the tolerance of floating point columns without a tolerance of their own
+    pub fn with_default(mut self, tolerance: Tolerance) -> Self {
+        self.default = Some(tolerance);
+        self
+    }
+
+    /// Set the tolerance of the floating point column `name`
+    pub fn with_column(mut self, name: impl Into<String>, tolerance: Tolerance) -> Self {
+        self.columns.insert(name.into(), tolerance);
+        self
+    }
+}
+
+/// Returns true if `left` and `right` are equal, comparing floating point columns with
+/// the tolerances of `tolerance`
+///
+/// The batches must have equal schemas and the same number of rows, and the nulls of
+/// each column must match. Columns without a tolerance, including nested columns
+/// containing floating point values, are compared exactly.
+///
+/// Returns an error if `tolerance` contains a column that is not present in `left`,
+/// or is not a floating point column
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Float32Array, Int32Array, RecordBatch};
+/// # use arrow_ord::approx::{batches_approx_eq, BatchTolerance, Tolerance};
+/// let id: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+/// let a: ArrayRef = Arc::new(Float32Array::from(vec![0.5, 1.0]));
+/// let left = RecordBatch::try_from_iter([("id", id.clone()), ("value", a)]).unwrap();
+///
+/// let b: ArrayRef = Arc::new(Float32Array::from(vec![0.5, 1.001]));
+/// let right = RecordBatch::try_from_iter([("id", id), ("value", b)]).unwrap();
+///
+/// assert!(!batches_approx_eq(&left, &right, &BatchTolerance::new()).unwrap());
+///
+/// let tolerance = BatchTolerance::new().with_column("value", Tolerance::Absolute(0.01));
+/// assert!(batches_approx_eq(&left, &right, &tolerance).unwrap());
+/// ```
+pub fn batches_approx_eq(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    tolerance: &BatchTolerance,
+) -> Result<bool, ArrowError> {
+    let schema = left.schema();
+    for name in tolerance.columns.keys() {
+        let field = schema.field_with_name(name)?;
+        if !field.data_type().is_floating() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Tolerance specified for column {name} of non-floating point type {}",
+                field.data_type()
+            )));
+        }
+    }
+
+    if schema != right.schema() || left.num_rows() != right.num_rows() {
+        return Ok(false);
+    }
+
+    for (idx, field) in schema.fields().iter().enumerate() {
+        let (l, r) = (left.column(idx), right.column(idx));
+        let column = tolerance.columns.get(field.name());
+        let column = column.or(tolerance.default.as_ref());
+        let equal = match column {
+            Some(t) if field.data_type().is_floating() => {
+                let eq = approx_eq(l, r, *t)?;
+                (0..l.len()).all(|i| match (l.is_null(i), r.is_null(i)) {
+                    (false, false) => eq.value(i),
+                    (l, r) => l == r,
+                })
+            }
+            _ => l.as_ref() == r.as_ref(),
+        };
+        if !equal {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// A floating point type supported by [`approx_eq`]
+trait ApproxFloat: Copy {
+    fn to_f64(self) -> f64;
+
+    /// Returns the bits of this value mapped to an integer with the same ordering,
+    /// such that adjacent representable values differ by one
+    fn ordered_bits(self) -> i64;
+}
+
+impl ApproxFloat for f16 {
+    fn to_f64(self) -> f64 {
+        f16::to_f64(self)
+    }
+
+    fn ordered_bits(self) -> i64 {
+        let bits = self.to_bits() as i16 as i64;
+        match bits < 0 {
+            true => i16::MIN as i64 - bits,
+            false => bits,
+        }
+    }
+}
+
+impl ApproxFloat for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn ordered_bits(self) -> i64 {
+        let bits = self.to_bits() as i32 as i64;
+        match bits < 0 {
+            true => i32::MIN as i64 - bits,
+            false => bits,
+        }
+    }
+}
+
+impl ApproxFloat for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn ordered_bits(self) -> i64 {
+        let bits = self.to_bits() as i64;
+        match bits < 0 {
+            true => i64::MIN - bits,
+            false => bits,
+        }
+    }
+}
+
+fn is_approx_eq<T: ApproxFloat>(l: T, r: T, tolerance: Tolerance) -> bool {
+    let (a, b) = (l.to_f64(), r.to_f64());
+    if a == b || (a.is_nan() && b.is_nan()) {
+        return true;
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return false;
+    }
+    match tolerance {
+        Tolerance::Absolute(e) => (a - b).abs() <= e,
+        Tolerance::Relative(e) => (a - b).abs() <= e * a.abs().max(b.abs()),
+        Tolerance::Ulps(n) => {
+            let distance = l.ordered_bits() as i128 - r.ordered_bits() as i128;
+            distance.unsigned_abs() <= n as u128
+        }
+    }
+}
+
+fn apply<T>(
+    l: &PrimitiveArray<T>,
+    l_s: bool,
+    r: &PrimitiveArray<T>,
+    r_s: bool,
+    tolerance: Tolerance,
+) -> BooleanArray
+where
+    T: ArrowPrimitiveType,
+    T::Native: ApproxFloat,
+{
+    let len = match l_s {
+        true => r.len(),
+        false => l.len(),
+    };
+    if (l_s && l.is_null(0)) || (r_s && r.is_null(0)) {
+        return BooleanArray::new_null(len);
+    }
+
+    let (l_v, r_v) = (l.values(), r.values());
+    let values = BooleanBuffer::collect_bool(len, |idx| {
+        let l = l_v[if l_s { 0 } else { idx }];
+        let r = r_v[if r_s { 0 } else { idx }];
+        is_approx_eq(l, r, tolerance)
+    });
+    let l_nulls = l.nulls().filter(|_| !l_s);
+    let r_nulls = r.nulls().filter(|_| !r_s);
+    BooleanArray::new(values, NullBuffer::union(l_nulls, r_nulls))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Float64Array::from(vec![
+            Some(1.0),
+            Some(1.0),
+            Some(-0.0),
+            Some(f64::INFINITY),
+            Some(f64::INFINITY),
+            Some(f64::NAN),
+            Some(f64::MAX),
+            None,
+            Some(100.0),
+        ]);
+        let b = Float64Array::from(vec![
+            Some(1.0 + f64::EPSILON),
+            Some(1.5),
+            Some(0.0),
+            Some(f64::INFINITY),
+            Some(f64::NEG_INFINITY),
+            Some(1.0),
+            Some(f64::INFINITY),
+            Some(1.0),
+            Some(101.0),
+        ]);
+
+        let cases = [
+            (Tolerance::Absolute(0.), [false, false, false]),
+            (Tolerance::Absolute(1e-6), [true, false, false]),
+            (Tolerance::Absolute(1.), [true, true, true]),
+            (Tolerance::Relative(1e-6), [true, false, false]),
+            (Tolerance::Relative(0.01), [true, false, true]),
+            (Tolerance::Ulps(0), [false, false, false]),
+            (Tolerance::Ulps(1), [true, false, false]),
+            (Tolerance::Ulps(u64::MAX), [true, true, true]),
+        ];
+        for (tolerance, [first, second, last]) in cases {
+            let result = approx_eq(&a, &b, tolerance).unwrap();
+            let expected = BooleanArray::from(vec![
+                Some(first),
+                Some(second),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false),
+                None,
+                Some(last),
+            ]);
+            assert_eq!(result, expected, "{tolerance:?}");
+        }
+
+        // Adjacent values around zero
+        let a = Float32Array::from(vec![-f32::from_bits(1), f32::from_bits(1), 0.0]);
+        let b = Float32Array::new_scalar(-0.0);
+        let result = approx_eq(&a, &b, Tolerance::Ulps(1)).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, true, true]));
+        let result = approx_eq(
+            &b,
+            &Float32Array::from(vec![f32::from_bits(2)]),
+            Tolerance::Ulps(1),
+        );
+        assert_eq!(result.unwrap(), BooleanArray::from(vec![false]));
+
+        let a = Float16Array::from(vec![f16::ONE, f16::from_bits(f16::ONE.to_bits() + 2)]);
+        let b = Float16Array::new_scalar(f16::from_bits(f16::ONE.to_bits() + 1));
+        let result = approx_eq(&a, &b, Tolerance::Ulps(1)).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, true]));
+        let result = approx_eq(&a, &b, Tolerance::Absolute(1e-6)).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false, false]));
+
+        let null = Scalar::new(Float16Array::from(vec![None]));
+        let result = approx_eq(&null, &a, Tolerance::Ulps(1)).unwrap();
+        assert_eq!(result, BooleanArray::new_null(2));
+    }
+
+    #[test]
+    fn test_approx_eq_errors() {
+        let a = Float64Array::from(vec![1.0, 2.0]);
+        let cases: [(&dyn Datum, Tolerance, &str); 4] = [
+            (
+                &Float32Array::from(vec![1.0, 2.0]),
+                Tolerance::Ulps(1),
+                "Invalid approximate comparison operation: Float64 ≈ Float32",
+            ),
+            (
+                &Float64Array::from(vec![1.0]),
+                Tolerance::Ulps(1),
+                "Cannot compare arrays of different lengths, got 2 vs 1",
+            ),
+            (
+                &Float64Array::new_scalar(1.0),
+                Tolerance::Absolute(-1.),
+                "Invalid tolerance Absolute(-1.0)",
+            ),
+            (
+                &Float64Array::new_scalar(1.0),
+                Tolerance::Relative(f64::NAN),
+                "Invalid tolerance Relative(NaN)",
+            ),
+        ];
+        for (b, tolerance, expected) in cases {
+            let err = approx_eq(&a, b, tolerance).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Invalid argument error: {expected}")
+            );
+        }
+    }
+
+    #[test]
+    fn test_batches_approx_eq() {
+        let id: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![Some(1.0), None, Some(3.0)]));
+        let b: ArrayRef = Arc::new(Float32Array::from(vec![1.0, 2.0, 3.0]));
+        let left = RecordBatch::try_from_iter([("id", id.clone()), ("a", a), ("b", b)]).unwrap();
+
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![Some(1.01), None, Some(3.0)]));
+        let b: ArrayRef = Arc::new(Float32Array::from(vec![1.0, 2.0, 3.000001]));
+        let right = RecordBatch::try_from_iter([("id", id.clone()), ("a", a), ("b", b)]).unwrap();
+
+        assert!(batches_approx_eq(&left, &left, &BatchTolerance::new()).unwrap());
+        assert!(!batches_approx_eq(&left, &right, &BatchTolerance::new()).unwrap());
+
+        let tolerance = BatchTolerance::new().with_default(Tolerance::Ulps(16));
+        assert!(!batches_approx_eq(&left, &right, &tolerance).unwrap());
+        let tolerance = tolerance.with_column("a", Tolerance::Absolute(0.1));
+        assert!(batches_approx_eq(&left, &right, &tolerance).unwrap());
+        assert!(!batches_approx_eq(&left, &left.slice(0, 2), &tolerance).unwrap());
+
+        // Nulls must match
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![Some(1.0), Some(2.0), Some(3.0)]));
+        let b = left.column(2).clone();
+        let other = RecordBatch::try_from_iter([("id", id), ("a", a), ("b", b)]).unwrap();
+        assert!(!batches_approx_eq(&left, &other, &tolerance).unwrap());
+
+        let tolerance = BatchTolerance::new().with_column("id", Tolerance::Ulps(1));
+        let err = batches_approx_eq(&left, &right, &tolerance).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Tolerance specified for column id of non-floating point type Int32"
+        );
+
+        let tolerance = BatchTolerance::new().with_column("c", Tolerance::Ulps(1));
+        let err = batches_approx_eq(&left, &right, &tolerance).unwrap_err();
+        assert!(
+            err.to_string().contains("Unable to get field named \"c\""),
+            "{err}"
+        );
+    }
+}
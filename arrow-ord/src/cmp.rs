@@ -22,6 +22,11 @@
 //! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
 //! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
 //!
+//! Timestamps with different timezones, such as `Timestamp(Second, Some("UTC"))` and
+//! `Timestamp(Second, Some("+01:00"))`, are compared on the instant they represent, as
+//! both store the time elapsed since the UNIX epoch in UTC. Timestamps with different
+//! units must first be cast to a common unit.
+//!
 
 use arrow_array::cast::AsArray;
 use arrow_array::types::ByteArrayType;
@@ -198,31 +203,60 @@ fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray,
     let r = r_v.map(|x| x.values().as_ref()).unwrap_or(r);
     let r_t = r.data_type();
 
-    if l_t != r_t || l_t.is_nested() {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "Invalid comparison operation: {l_t} {op} {r_t}"
-        )));
+    match (l_t, r_t) {
+        // Timestamps are compared on the instant, regardless of timezone
+        (Timestamp(l_unit, _), Timestamp(r_unit, _)) if l_unit != r_unit => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid comparison operation: {l_t} {op} {r_t}, timestamps must have the same unit"
+            )));
+        }
+        (Timestamp(_, _), Timestamp(_, _)) => {}
+        _ if l_t != r_t || l_t.is_nested() => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid comparison operation: {l_t} {op} {r_t}"
+            )));
+        }
+        _ => {}
     }
 
+    let l_nulls = l_nulls.filter(|n| n.null_count() > 0);
+    let r_nulls = r_nulls.filter(|n| n.null_count() > 0);
+
+    // The values are only used where neither non-scalar side is null, allowing
+    // the comparison of values to be skipped elsewhere
+    let valid = NullBuffer::union(
+        l_nulls.as_ref().filter(|_| !l_s),
+        r_nulls.as_ref().filter(|_| !r_s),
+    );
+    let v = valid.as_ref().map(|n| n.inner());
+
     // Defer computation as may not be necessary
     let values = || -> BooleanBuffer {
         let d = downcast_primitive_array! {
-            (l, r) => apply(op, l.values().as_ref(), l_s, l_v, r.values().as_ref(), r_s, r_v),
-            (Boolean, Boolean) => apply(op, l.as_boolean(), l_s, l_v, r.as_boolean(), r_s, r_v),
-            (Utf8, Utf8) => apply(op, l.as_string::<i32>(), l_s, l_v, r.as_string::<i32>(), r_s, r_v),
-            (LargeUtf8, LargeUtf8) => apply(op, l.as_string::<i64>(), l_s, l_v, r.as_string::<i64>(), r_s, r_v),
-            (Binary, Binary) => apply(op, l.as_binary::<i32>(), l_s, l_v, r.as_binary::<i32>(), r_s, r_v),
-            (LargeBinary, LargeBinary) => apply(op, l.as_binary::<i64>(), l_s, l_v, r.as_binary::<i64>(), r_s, r_v),
-            (FixedSizeBinary(_), FixedSizeBinary(_)) => apply(op, l.as_fixed_size_binary(), l_s, l_v, r.as_fixed_size_binary(), r_s, r_v),
+            (l, r) => apply(op, l.values().as_ref(), l_s, l_v, r.values().as_ref(), r_s, r_v, v),
+            (Boolean, Boolean) => apply(op, l.as_boolean(), l_s, l_v, r.as_boolean(), r_s, r_v, v),
+            (Utf8, Utf8) => apply(op, l.as_string::<i32>(), l_s, l_v, r.as_string::<i32>(), r_s, r_v, v),
+            (LargeUtf8, LargeUtf8) => apply(op, l.as_string::<i64>(), l_s, l_v, r.as_string::<i64>(), r_s, r_v, v),
+            (Binary, Binary) => apply(op, l.as_binary::<i32>(), l_s, l_v, r.as_binary::<i32>(), r_s, r_v, v),
+            (LargeBinary, LargeBinary) => apply(op, l.as_binary::<i64>(), l_s, l_v, r.as_binary::<i64>(), r_s, r_v, v),
+            (FixedSizeBinary(_), FixedSizeBinary(_)) => apply(op, l.as_fixed_size_binary(), l_s, l_v, r.as_fixed_size_binary(), r_s, r_v, v),
             (Null, Null) => None,
             _ => unreachable!(),
         };
         d.unwrap_or_else(|| BooleanBuffer::new_unset(len))
     };
 
-    let l_nulls = l_nulls.filter(|n| n.null_count() > 0);
-    let r_nulls = r_nulls.filter(|n| n.null_count() > 0);
+    // Every row has a null on at least one side, and so the values are not needed
+    let all_null = valid
+        .as_ref()
+        .map(|n| n.null_count() == len)
+        .unwrap_or(false);
     Ok(match (l_nulls, l_s, r_nulls, r_s) {
+        (Some(l), false, Some(r), false) if all_null => match op {
+            Op::Distinct => (l.inner() ^ r.inner()).into(),
+            Op::NotDistinct => (!&(l.inner() | r.inner())).into(),
+            _ => BooleanArray::new_null(len),
+        },
         (Some(l), true, Some(r), true) | (Some(l), false, Some(r), false) => {
             // Either both sides are scalar or neither side is scalar
             match op {
@@ -266,6 +300,12 @@ fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray,
                     Op::NotDistinct => BooleanBuffer::new_unset(len).into(),
                     _ => BooleanArray::new_null(len),
                 },
+                false if all_null => match op {
+                    // Non-scalar side is entirely null, other side is not nullable
+                    Op::Distinct => BooleanBuffer::new_set(len).into(),
+                    Op::NotDistinct => BooleanBuffer::new_unset(len).into(),
+                    _ => BooleanArray::new_null(len),
+                },
                 false => match op {
                     Op::Distinct => {
                         let values = values();
@@ -286,6 +326,9 @@ fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray,
 }
 
 /// Perform a potentially vectored `op` on the provided `ArrayOrd`
+///
+/// If `valid` is provided, the result is only computed for the rows it contains
+#[allow(clippy::too_many_arguments)]
 fn apply<T: ArrayOrd>(
     op: Op,
     l: T,
@@ -294,6 +337,7 @@ fn apply<T: ArrayOrd>(
     r: T,
     r_s: bool,
     r_v: Option<&dyn AnyDictionaryArray>,
+    valid: Option<&BooleanBuffer>,
 ) -> Option<BooleanBuffer> {
     if l.len() == 0 || r.len() == 0 {
         return None; // Handle empty dictionaries
@@ -311,25 +355,29 @@ fn apply<T: ArrayOrd>(
 
         assert_eq!(l_v.len(), r_v.len()); // Sanity check
 
+        let v = valid;
         Some(match op {
-            Op::Equal | Op::NotDistinct => apply_op_vectored(l, &l_v, r, &r_v, false, T::is_eq),
-            Op::NotEqual | Op::Distinct => apply_op_vectored(l, &l_v, r, &r_v, true, T::is_eq),
-            Op::Less => apply_op_vectored(l, &l_v, r, &r_v, false, T::is_lt),
-            Op::LessEqual => apply_op_vectored(r, &r_v, l, &l_v, true, T::is_lt),
-            Op::Greater => apply_op_vectored(r, &r_v, l, &l_v, false, T::is_lt),
-            Op::GreaterEqual => apply_op_vectored(l, &l_v, r, &r_v, true, T::is_lt),
+            Op::Equal | Op::NotDistinct => apply_op_vectored(l, &l_v, r, &r_v, v, false, T::is_eq),
+            Op::NotEqual | Op::Distinct => apply_op_vectored(l, &l_v, r, &r_v, v, true, T::is_eq),
+            Op::Less => apply_op_vectored(l, &l_v, r, &r_v, v, false, T::is_lt),
+            Op::LessEqual => apply_op_vectored(r, &r_v, l, &l_v, v, true, T::is_lt),
+            Op::Greater => apply_op_vectored(r, &r_v, l, &l_v, v, false, T::is_lt),
+            Op::GreaterEqual => apply_op_vectored(l, &l_v, r, &r_v, v, true, T::is_lt),
         })
     } else {
         let l_s = l_s.then(|| l_v.map(|x| x.normalized_keys()[0]).unwrap_or_default());
         let r_s = r_s.then(|| r_v.map(|x| x.normalized_keys()[0]).unwrap_or_default());
 
+        // A non-scalar dictionary is compared on its values, whose rows differ from `valid`
+        let dictionary = (l_v.is_some() && l_s.is_none()) || (r_v.is_some() && r_s.is_none());
+        let v = valid.filter(|_| !dictionary);
         let buffer = match op {
-            Op::Equal | Op::NotDistinct => apply_op(l, l_s, r, r_s, false, T::is_eq),
-            Op::NotEqual | Op::Distinct => apply_op(l, l_s, r, r_s, true, T::is_eq),
-            Op::Less => apply_op(l, l_s, r, r_s, false, T::is_lt),
-            Op::LessEqual => apply_op(r, r_s, l, l_s, true, T::is_lt),
-            Op::Greater => apply_op(r, r_s, l, l_s, false, T::is_lt),
-            Op::GreaterEqual => apply_op(l, l_s, r, r_s, true, T::is_lt),
+            Op::Equal | Op::NotDistinct => apply_op(l, l_s, r, r_s, v, false, T::is_eq),
+            Op::NotEqual | Op::Distinct => apply_op(l, l_s, r, r_s, v, true, T::is_eq),
+            Op::Less => apply_op(l, l_s, r, r_s, v, false, T::is_lt),
+            Op::LessEqual => apply_op(r, r_s, l, l_s, v, true, T::is_lt),
+            Op::Greater => apply_op(r, r_s, l, l_s, v, false, T::is_lt),
+            Op::GreaterEqual => apply_op(l, l_s, r, r_s, v, true, T::is_lt),
         };
 
         // If a side had a dictionary, and was not scalar, we need to materialize this
@@ -351,12 +399,31 @@ fn take_bits(v: &dyn AnyDictionaryArray, buffer: BooleanBuffer) -> BooleanBuffer
 ///
 /// This is similar to [`MutableBuffer::collect_bool`] but with
 /// the option to efficiently negate the result
-fn collect_bool(len: usize, neg: bool, f: impl Fn(usize) -> bool) -> BooleanBuffer {
+///
+/// If `valid` is provided, `f` is not invoked for chunks of 64 values without any
+/// set bits in `valid`, whose results are instead unset
+fn collect_bool(
+    len: usize,
+    valid: Option<&BooleanBuffer>,
+    neg: bool,
+    f: impl Fn(usize) -> bool,
+) -> BooleanBuffer {
     let mut buffer = MutableBuffer::new(ceil(len, 64) * 8);
+    let mut valid = valid.map(|v| {
+        assert_eq!(v.len(), len);
+        v.bit_chunks().iter_padded()
+    });
+    let mut skip = || valid.as_mut().map(|v| v.next() == Some(0)).unwrap_or(false);
 
     let chunks = len / 64;
     let remainder = len % 64;
     for chunk in 0..chunks {
+        if skip() {
+            // SAFETY: Already allocated sufficient capacity
+            unsafe { buffer.push_unchecked(0_u64) }
+            continue;
+        }
+
         let mut packed = 0;
         for bit_idx in 0..64 {
             let i = bit_idx + chunk * 64;
@@ -370,7 +437,10 @@ fn collect_bool(len: usize, neg: bool, f: impl Fn(usize) -> bool) -> BooleanBuff
         unsafe { buffer.push_unchecked(packed) }
     }
 
-    if remainder != 0 {
+    if remainder != 0 && skip() {
+        // SAFETY: Already allocated sufficient capacity
+        unsafe { buffer.push_unchecked(0_u64) }
+    } else if remainder != 0 {
         let mut packed = 0;
         for bit_idx in 0..remainder {
             let i = bit_idx + chunks * 64;
@@ -397,13 +467,14 @@ fn apply_op<T: ArrayOrd>(
     l_s: Option<usize>,
     r: T,
     r_s: Option<usize>,
+    valid: Option<&BooleanBuffer>,
     neg: bool,
     op: impl Fn(T::Item, T::Item) -> bool,
 ) -> BooleanBuffer {
     match (l_s, r_s) {
         (None, None) => {
             assert_eq!(l.len(), r.len());
-            collect_bool(l.len(), neg, |idx| unsafe {
+            collect_bool(l.len(), valid, neg, |idx| unsafe {
                 op(l.value_unchecked(idx), r.value_unchecked(idx))
             })
         }
@@ -414,11 +485,15 @@ fn apply_op<T: ArrayOrd>(
         }
         (Some(l_s), None) => {
             let v = l.value(l_s);
-            collect_bool(r.len(), neg, |idx| op(v, unsafe { r.value_unchecked(idx) }))
+            collect_bool(r.len(), valid, neg, |idx| {
+                op(v, unsafe { r.value_unchecked(idx) })
+            })
         }
         (None, Some(r_s)) => {
             let v = r.value(r_s);
-            collect_bool(l.len(), neg, |idx| op(unsafe { l.value_unchecked(idx) }, v))
+            collect_bool(l.len(), valid, neg, |idx| {
+                op(unsafe { l.value_unchecked(idx) }, v)
+            })
         }
     }
 }
@@ -429,11 +504,12 @@ fn apply_op_vectored<T: ArrayOrd>(
     l_v: &[usize],
     r: T,
     r_v: &[usize],
+    valid: Option<&BooleanBuffer>,
     neg: bool,
     op: impl Fn(T::Item, T::Item) -> bool,
 ) -> BooleanBuffer {
     assert_eq!(l_v.len(), r_v.len());
-    collect_bool(l_v.len(), neg, |idx| unsafe {
+    collect_bool(l_v.len(), valid, neg, |idx| unsafe {
         let l_idx = *l_v.get_unchecked(idx);
         let r_idx = *r_v.get_unchecked(idx);
         op(l.value_unchecked(l_idx), r.value_unchecked(r_idx))
@@ -544,7 +620,10 @@ impl<'a> ArrayOrd for &'a FixedSizeBinaryArray {
 mod tests {
     use std::sync::Arc;
 
-    use arrow_array::{DictionaryArray, Int32Array, Scalar, StringArray};
+    use arrow_array::{
+        DictionaryArray, Int32Array, Scalar, StringArray, TimestampMillisecondArray,
+        TimestampSecondArray,
+    };
 
     use super::*;
 
@@ -691,6 +770,91 @@ mod tests {
         assert_eq!(r.len(), 0);
     }
 
+    #[test]
+    fn test_timestamp_mixed_timezones() {
+        let a = TimestampSecondArray::from(vec![Some(1), Some(5), None]).with_timezone("+01:00");
+        let b = TimestampSecondArray::from(vec![2, 5, 3]).with_timezone("UTC");
+
+        let r = eq(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(false), Some(true), None]));
+        let r = lt(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(true), Some(false), None]));
+
+        let scalar = Scalar::new(TimestampSecondArray::from(vec![5]));
+        let r = gt_eq(&a, &scalar).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(false), Some(true), None]));
+
+        let keys = Int32Array::from(vec![1, 0, 1]);
+        let dict = DictionaryArray::new(keys, Arc::new(b.clone()));
+        let r = neq(&dict, &a).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(true), Some(true), None]));
+
+        let c = TimestampMillisecondArray::from(vec![1, 2, 3]).with_timezone("+01:00");
+        let err = eq(&a, &c).unwrap_err().to_string();
+        assert_eq!(err, "Invalid argument error: Invalid comparison operation: Timestamp(Second, Some(\"+01:00\")) == Timestamp(Millisecond, Some(\"+01:00\")), timestamps must have the same unit");
+    }
+
+    #[test]
+    fn test_null_density() {
+        type Kernel = fn(&dyn Datum, &dyn Datum) -> Result<BooleanArray, ArrowError>;
+        type Reference = fn(i32, i32) -> bool;
+        let kernels: [(Kernel, Reference); 6] = [
+            (eq, |a, b| a == b),
+            (neq, |a, b| a != b),
+            (lt, |a, b| a < b),
+            (lt_eq, |a, b| a <= b),
+            (gt, |a, b| a > b),
+            (gt_eq, |a, b| a >= b),
+        ];
+
+        // Chunks of 64 rows that are entirely null, partially null, and not null
+        let a: Int32Array = (0..300)
+            .map(|x| (x >= 128 && x % 3 != 0).then_some(x % 7))
+            .collect();
+        let b: Int32Array = (0..300)
+            .map(|x| (x < 64 || x >= 150 && x % 5 != 0).then_some(x % 5))
+            .collect();
+        let all_null = Int32Array::new_null(300);
+        let no_null = Int32Array::from_iter_values((0..300).map(|x| x % 3));
+        let cases = [
+            (&a, &b),
+            (&all_null, &no_null),
+            (&a, &all_null),
+            (&no_null, &b),
+        ];
+
+        for (l, r) in cases {
+            for (kernel, op) in kernels {
+                let expected: BooleanArray = l
+                    .iter()
+                    .zip(r.iter())
+                    .map(|(l, r)| Some(op(l?, r?)))
+                    .collect();
+                assert_eq!(kernel(l, r).unwrap(), expected);
+                assert_eq!(
+                    kernel(&l.slice(7, 200), &r.slice(7, 200)).unwrap(),
+                    expected.slice(7, 200)
+                );
+
+                let dict =
+                    DictionaryArray::new(Int32Array::from_iter_values(0..300), Arc::new(l.clone()));
+                assert_eq!(kernel(&dict, r).unwrap(), expected);
+
+                let scalar = Scalar::new(r.slice(64, 1));
+                let s = r.is_valid(64).then(|| r.value(64));
+                let expected: BooleanArray = l.iter().map(|l| Some(op(l?, s?))).collect();
+                assert_eq!(kernel(l, &scalar).unwrap(), expected);
+            }
+
+            let expected: BooleanArray =
+                l.iter().zip(r.iter()).map(|(l, r)| Some(l != r)).collect();
+            assert_eq!(distinct(l, r).unwrap(), expected);
+            let expected: BooleanArray =
+                l.iter().zip(r.iter()).map(|(l, r)| Some(l == r)).collect();
+            assert_eq!(not_distinct(l, r).unwrap(), expected);
+        }
+    }
+
     #[test]
     fn test_dictionary_nulls() {
         let values = StringArray::from(vec![Some("us-west"), Some("us-east")]);
@@ -0,0 +1,451 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Comparison and sort kernels for string arrays ordered by a [`Collator`]
+//!
+//! The kernels in [`cmp`](crate::cmp) and [`sort`](crate::sort) order strings by their
+//! UTF-8 bytes, equivalent to ordering by code point. This module provides equivalent
+//! kernels that instead order strings according to a [`Collator`], such as
+//! [`CaseInsensitive`], or the locale-aware `IcuCollator` with the `icu` feature.
+//!
+//! ```
+//! # use arrow_array::{BooleanArray, Scalar, StringArray, UInt32Array};
+//! # use arrow_ord::collation::{self, CaseInsensitive};
+//! let array = StringArray::from(vec!["b", "A", "a", "C"]);
+//!
+//! let indices = collation::sort_to_indices(&array, None, None, &CaseInsensitive).unwrap();
+//! assert_eq!(indices, UInt32Array::from(vec![1, 2, 0, 3]));
+//!
+//! let scalar = Scalar::new(StringArray::from(vec!["a"]));
+//! let eq = collation::eq(&array, &scalar, &CaseInsensitive).unwrap();
+//! assert_eq!(eq, BooleanArray::from(vec![false, true, true, false]));
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType, SortOptions};
+use arrow_select::take::take;
+
+use crate::ord::DynComparator;
+use crate::sort::{partition_validity, sort_impl};
+
+/// Defines the order of strings for the kernels of this module
+pub trait Collator: Debug + Send + Sync {
+    /// Compares `a` and `b`
+    ///
+    /// This must define a total order, consistent with [`Ord`]
+    fn compare(&self, a: &str, b: &str) -> Ordering;
+}
+
+/// A [`Collator`] comparing strings by their lowercase code points
+///
+/// Strings are lowercased using the unicode lowercase mapping of each character,
+/// without allocating. Strings differing only by case compare equal.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CaseInsensitive;
+
+impl Collator for CaseInsensitive {
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        if a.is_ascii() && b.is_ascii() {
+            let a = a.bytes().map(|x| x.to_ascii_lowercase());
+            return a.cmp(b.bytes().map(|x| x.to_ascii_lowercase()));
+        }
+        let a = a.chars().flat_map(char::to_lowercase);
+        a.cmp(b.chars().flat_map(char::to_lowercase))
+    }
+}
+
+/// A locale-aware [`Collator`] using the [Unicode Collation Algorithm] as implemented
+/// by [ICU4X](icu_collator), with the collation data compiled into the binary
+///
+/// This requires the `icu` feature
+///
+/// [Unicode Collation Algorithm]: https://www.unicode.org/reports/tr10/
+#[cfg(feature = "icu")]
+pub struct IcuCollator {
+    collator: icu_collator::Collator,
+    locale: icu_locid::Locale,
+}
+
+#[cfg(feature = "icu")]
+impl IcuCollator {
+    /// Create a new [`IcuCollator`] for the BCP-47 `locale`, such as `"de"` or `"sv-SE"`,
+    /// with the default options of the locale
+    pub fn try_new(locale: &str) -> Result<Self, ArrowError> {
+        Self::try_new_with_options(locale, Default::default())
+    }
+
+    /// Create a new [`IcuCollator`] for the BCP-47 `locale`, with the given `options`,
+    /// for example a [`Strength`](icu_collator::Strength) ignoring case or accents
+    pub fn try_new_with_options(
+        locale: &str,
+        options: icu_collator::CollatorOptions,
+    ) -> Result<Self, ArrowError> {
+        let locale: icu_locid::Locale = locale.parse().map_err(|e| {
+            ArrowError::InvalidArgumentError(format!("Invalid locale \"{locale}\": {e}"))
+        })?;
+        let collator =
+            icu_collator::Collator::try_new(&(&locale).into(), options).map_err(|e| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Failed to create collator for locale \"{locale}\": {e}"
+                ))
+            })?;
+        Ok(Self { collator, locale })
+    }
+}
+
+#[cfg(feature = "icu")]
+impl Debug for IcuCollator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IcuCollator")
+            .field("locale", &self.locale.to_string())
+            .field("options", &self.collator.resolved_options())
+            .finish()
+    }
+}
+
+#[cfg(feature = "icu")]
+impl Collator for IcuCollator {
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        self.collator.compare(a, b)
+    }
+}
+
+/// Perform `left == right` operation on two [`Datum`] of strings, ordered by `collator`
+pub fn eq(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    collator: &dyn Collator,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op("==", lhs, rhs, collator, Ordering::is_eq)
+}
+
+/// Perform `left != right` operation on two [`Datum`] of strings, ordered by `collator`
+pub fn neq(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    collator: &dyn Collator,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op("!=", lhs, rhs, collator, Ordering::is_ne)
+}
+
+/// Perform `left < right` operation on two [`Datum`] of strings, ordered by `collator`
+pub fn lt(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    collator: &dyn Collator,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op("<", lhs, rhs, collator, Ordering::is_lt)
+}
+
+/// Perform `left <= right` operation on two [`Datum`] of strings, ordered by `collator`
+pub fn lt_eq(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    collator: &dyn Collator,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op("<=", lhs, rhs, collator, Ordering::is_le)
+}
+
+/// Perform `left > right` operation on two [`Datum`] of strings, ordered by `collator`
+pub fn gt(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    collator: &dyn Collator,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op(">", lhs, rhs, collator, Ordering::is_gt)
+}
+
+/// Perform `left >= right` operation on two [`Datum`] of strings, ordered by `collator`
+pub fn gt_eq(
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    collator: &dyn Collator,
+) -> Result<BooleanArray, ArrowError> {
+    compare_op(">=", lhs, rhs, collator, Ordering::is_ge)
+}
+
+fn compare_op(
+    op: &str,
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    collator: &dyn Collator,
+    predicate: fn(Ordering) -> bool,
+) -> Result<BooleanArray, ArrowError> {
+    use arrow_schema::DataType::*;
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+
+    let l_len = l.len();
+    let r_len = r.len();
+
+    if l_len != r_len && !l_s && !r_s {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compare arrays of different lengths, got {l_len} vs {r_len}"
+        )));
+    }
+
+    let len = match l_s {
+        true => r_len,
+        false => l_len,
+    };
+
+    let l = (l, l_s);
+    let r = (r, r_s);
+    let args = (len, collator, predicate);
+    match (l.0.data_type(), r.0.data_type()) {
+        (Utf8, Utf8) => Ok(collate_op::<i32, i32>(l, r, args)),
+        (Utf8, LargeUtf8) => Ok(collate_op::<i32, i64>(l, r, args)),
+        (LargeUtf8, Utf8) => Ok(collate_op::<i64, i32>(l, r, args)),
+        (LargeUtf8, LargeUtf8) => Ok(collate_op::<i64, i64>(l, r, args)),
+        (l_t, r_t) => Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid collated comparison operation: {l_t} {op} {r_t}"
+        ))),
+    }
+}
+
+fn collate_op<L: OffsetSizeTrait, R: OffsetSizeTrait>(
+    (l, l_s): (&dyn Array, bool),
+    (r, r_s): (&dyn Array, bool),
+    (len, collator, predicate): (usize, &dyn Collator, fn(Ordering) -> bool),
+) -> BooleanArray {
+    let l = l.as_string::<L>();
+    let r = r.as_string::<R>();
+    (0..len)
+        .map(|idx| {
+            let l_idx = if l_s { 0 } else { idx };
+            let r_idx = if r_s { 0 } else { idx };
+            let valid = l.is_valid(l_idx) && r.is_valid(r_idx);
+            valid.then(|| predicate(collator.compare(l.value(l_idx), r.value(r_idx))))
+        })
+        .collect()
+}
+
+/// Sort the string `values` ordered by `collator`, see [`sort_to_indices`]
+pub fn sort(
+    values: &dyn Array,
+    options: Option<SortOptions>,
+    collator: &dyn Collator,
+) -> Result<ArrayRef, ArrowError> {
+    let indices = sort_to_indices(values, options, None, collator)?;
+    take(values, &indices, None)
+}
+
+/// Sort the string `array` ordered by `collator`, returning the indices of the sorted
+/// values, in the manner of [`sort_to_indices`](crate::sort::sort_to_indices)
+///
+/// Values comparing equal, such as `"a"` and `"A"` with [`CaseInsensitive`], are not
+/// guaranteed to retain their relative order.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8
+pub fn sort_to_indices(
+    array: &dyn Array,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+    collator: &dyn Collator,
+) -> Result<UInt32Array, ArrowError> {
+    let options = options.unwrap_or_default();
+    match array.data_type() {
+        DataType::Utf8 => Ok(sort_strings(
+            array.as_string::<i32>(),
+            options,
+            limit,
+            collator,
+        )),
+        DataType::LargeUtf8 => Ok(sort_strings(
+            array.as_string::<i64>(),
+            options,
+            limit,
+            collator,
+        )),
+        t => Err(ArrowError::ComputeError(format!(
+            "Collated sort not supported for data type {t:?}"
+        ))),
+    }
+}
+
+fn sort_strings<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    options: SortOptions,
+    limit: Option<usize>,
+    collator: &dyn Collator,
+) -> UInt32Array {
+    let (valids, nulls) = partition_validity(array);
+    let mut valids: Vec<_> = valids
+        .into_iter()
+        .map(|idx| (idx, array.value(idx as usize)))
+        .collect();
+    sort_impl(options, &mut valids, &nulls, limit, |a, b| {
+        collator.compare(a, b)
+    })
+    .into()
+}
+
+/// Returns a comparison function that compares two values at two different positions
+/// of the string arrays `left` and `right`, ordered by `collator`
+///
+/// This can be combined with the comparators of other columns, as returned by
+/// [`build_compare`](crate::ord::build_compare), to implement a lexicographical sort
+/// with collated columns. As with [`build_compare`](crate::ord::build_compare), nulls
+/// are not considered.
+pub fn build_compare(
+    left: &dyn Array,
+    right: &dyn Array,
+    collator: Arc<dyn Collator>,
+) -> Result<DynComparator, ArrowError> {
+    fn compare<L: OffsetSizeTrait, R: OffsetSizeTrait>(
+        left: &dyn Array,
+        right: &dyn Array,
+        collator: Arc<dyn Collator>,
+    ) -> DynComparator {
+        let left = left.as_string::<L>().clone();
+        let right = right.as_string::<R>().clone();
+        Box::new(move |i, j| collator.compare(left.value(i), right.value(j)))
+    }
+
+    use arrow_schema::DataType::*;
+    match (left.data_type(), right.data_type()) {
+        (Utf8, Utf8) => Ok(compare::<i32, i32>(left, right, collator)),
+        (Utf8, LargeUtf8) => Ok(compare::<i32, i64>(left, right, collator)),
+        (LargeUtf8, Utf8) => Ok(compare::<i64, i32>(left, right, collator)),
+        (LargeUtf8, LargeUtf8) => Ok(compare::<i64, i64>(left, right, collator)),
+        (l, r) => Err(ArrowError::InvalidArgumentError(format!(
+            "The data type type {l:?} has no collated comparison with {r:?}",
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_insensitive() {
+        let c = CaseInsensitive;
+        assert_eq!(c.compare("abc", "ABC"), Ordering::Equal);
+        assert_eq!(c.compare("abc", "ABD"), Ordering::Less);
+        assert_eq!(c.compare("B", "a"), Ordering::Greater);
+        assert_eq!(c.compare("ÄPFEL", "äpfel"), Ordering::Equal);
+        assert_eq!(c.compare("Ä", "b"), Ordering::Greater);
+        assert_eq!(c.compare("", "a"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_collated_comparison() {
+        let a = StringArray::from(vec![Some("a"), Some("B"), None, Some("c")]);
+        let b = LargeStringArray::from(vec![Some("A"), Some("a"), Some("x"), Some("D")]);
+        let c = &CaseInsensitive;
+
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+        assert_eq!(eq(&a, &b, c).unwrap(), expected);
+        let expected = BooleanArray::from(vec![Some(false), Some(true), None, Some(true)]);
+        assert_eq!(neq(&a, &b, c).unwrap(), expected);
+        let expected = BooleanArray::from(vec![Some(false), Some(false), None, Some(true)]);
+        assert_eq!(lt(&a, &b, c).unwrap(), expected);
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        assert_eq!(lt_eq(&a, &b, c).unwrap(), expected);
+        let expected = BooleanArray::from(vec![Some(false), Some(true), None, Some(false)]);
+        assert_eq!(gt(&a, &b, c).unwrap(), expected);
+        let expected = BooleanArray::from(vec![Some(true), Some(true), None, Some(false)]);
+        assert_eq!(gt_eq(&a, &b, c).unwrap(), expected);
+
+        let scalar = Scalar::new(StringArray::from(vec!["b"]));
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+        assert_eq!(lt(&a, &scalar, c).unwrap(), expected);
+        let expected = BooleanArray::from(vec![Some(false), Some(false), None, Some(true)]);
+        assert_eq!(lt(&scalar, &a, c).unwrap(), expected);
+
+        let null = Scalar::new(StringArray::new_null(1));
+        assert_eq!(eq(&a, &null, c).unwrap().null_count(), 4);
+
+        let err = eq(&a, &StringArray::from(vec!["a"]), c).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare arrays of different lengths, got 4 vs 1"
+        );
+        let err = lt(&a, &Int32Array::from(vec![1; 4]), c).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid collated comparison operation: Utf8 < Int32"
+        );
+    }
+
+    #[test]
+    fn test_collated_sort() {
+        let array = StringArray::from(vec![Some("b"), None, Some("A"), Some("C"), Some("a2")]);
+        let c = &CaseInsensitive;
+
+        let indices = sort_to_indices(&array, None, None, c).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![1, 2, 4, 0, 3]));
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let indices = sort_to_indices(&array, Some(options), None, c).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![3, 0, 4, 2, 1]));
+        let indices = sort_to_indices(&array, Some(options), Some(2), c).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![3, 0]));
+
+        let large = LargeStringArray::from(vec!["b", "A", "C"]);
+        let sorted = sort(&large, None, c).unwrap();
+        assert_eq!(
+            sorted.as_string::<i64>(),
+            &LargeStringArray::from(vec!["A", "b", "C"])
+        );
+
+        let err = sort_to_indices(&Int32Array::from(vec![1]), None, None, c).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Collated sort not supported for data type Int32"
+        );
+
+        let cmp = build_compare(&array, &large, Arc::new(CaseInsensitive)).unwrap();
+        assert_eq!(cmp(0, 0), Ordering::Equal);
+        assert_eq!(cmp(2, 1), Ordering::Equal);
+        assert_eq!(cmp(3, 0), Ordering::Greater);
+    }
+
+    #[test]
+    #[cfg(feature = "icu")]
+    fn test_icu_collator() {
+        let array = StringArray::from(vec!["z", "ä", "a", "b", "Ä"]);
+
+        let german = IcuCollator::try_new("de").unwrap();
+        let indices = sort_to_indices(&array, None, None, &german).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2, 1, 4, 3, 0]));
+
+        // Swedish sorts ä after z
+        let swedish = IcuCollator::try_new("sv").unwrap();
+        let indices = sort_to_indices(&array, None, None, &swedish).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2, 3, 0, 1, 4]));
+
+        let mut options = icu_collator::CollatorOptions::new();
+        options.strength = Some(icu_collator::Strength::Primary);
+        let primary = IcuCollator::try_new_with_options("de", options).unwrap();
+        let scalar = Scalar::new(StringArray::from(vec!["A"]));
+        let expected = BooleanArray::from(vec![false, true, true, false, true]);
+        assert_eq!(eq(&array, &scalar, &primary).unwrap(), expected);
+
+        let err = IcuCollator::try_new("not a locale").unwrap_err();
+        assert!(err.to_string().contains("Invalid locale"), "{err}");
+    }
+}
@@ -43,9 +43,12 @@
 //! ```
 //!
 
+pub mod approx;
 pub mod cmp;
+pub mod collation;
 #[doc(hidden)]
 pub mod comparison;
+pub mod map;
 pub mod ord;
 pub mod partition;
 pub mod rank;
@@ -0,0 +1,201 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernel to sort the entries of a [`MapArray`] by key
+
+use std::cmp::Ordering;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, MapArray, UInt32Array};
+use arrow_buffer::OffsetBufferBuilder;
+use arrow_schema::ArrowError;
+use arrow_select::take::take;
+
+use crate::ord::build_compare;
+
+/// How [`sort_map_keys`] handles entries with the same key within a map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DuplicateKeys {
+    /// Keep all entries, ordered as in the original map
+    #[default]
+    Keep,
+    /// Keep only the first entry with each key
+    FirstWins,
+    /// Keep only the last entry with each key
+    LastWins,
+    /// Return an error
+    Error,
+}
+
+/// Sorts the entries of each map in `array` by ascending key, handling entries with
+/// the same key according to `duplicates`
+///
+/// The returned array is marked as having sorted keys, as required by consumers
+/// expecting canonical maps
+///
+/// ```
+/// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+/// # use arrow_array::Array;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_ord::map::{sort_map_keys, DuplicateKeys};
+/// # use arrow_schema::DataType;
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+/// for (key, value) in [("b", 1), ("a", 2), ("b", 3)] {
+///     builder.keys().append_value(key);
+///     builder.values().append_value(value);
+/// }
+/// builder.append(true).unwrap();
+/// let map = builder.finish();
+///
+/// let sorted = sort_map_keys(&map, DuplicateKeys::LastWins).unwrap();
+/// assert!(matches!(sorted.data_type(), DataType::Map(_, true)));
+///
+/// let keys = sorted.keys().as_string::<i32>();
+/// assert_eq!(keys.iter().collect::<Vec<_>>(), vec![Some("a"), Some("b")]);
+/// let values = sorted.values().as_primitive::<Int32Type>();
+/// assert_eq!(values.values(), &[2, 3]);
+///
+/// assert!(sort_map_keys(&map, DuplicateKeys::Error).is_err());
+/// ```
+pub fn sort_map_keys(array: &MapArray, duplicates: DuplicateKeys) -> Result<MapArray, ArrowError> {
+    let keys = array.keys();
+    let cmp = build_compare(keys.as_ref(), keys.as_ref())?;
+
+    let mut offsets = OffsetBufferBuilder::new(array.len());
+    let mut indices = Vec::with_capacity(keys.len());
+    let mut row = Vec::new();
+    for w in array.value_offsets().windows(2) {
+        row.clear();
+        row.extend(w[0] as usize..w[1] as usize);
+        // Stable, so entries with the same key remain in their original order
+        row.sort_by(|a, b| cmp(*a, *b));
+
+        let start = indices.len();
+        for &idx in &row {
+            let duplicate = indices.len() > start
+                && cmp(*indices.last().unwrap() as usize, idx) == Ordering::Equal;
+            match (duplicate, duplicates) {
+                (false, _) | (true, DuplicateKeys::Keep) => indices.push(idx as u32),
+                (true, DuplicateKeys::FirstWins) => {}
+                (true, DuplicateKeys::LastWins) => *indices.last_mut().unwrap() = idx as u32,
+                (true, DuplicateKeys::Error) => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Duplicate key at index {idx} of map entries"
+                    )))
+                }
+            }
+        }
+        offsets.push_length(indices.len() - start);
+    }
+
+    let indices = UInt32Array::from(indices);
+    let entries = take(array.entries(), &indices, None)?;
+    let (field, _, _, nulls, _) = array.clone().into_parts();
+    MapArray::try_new(
+        field,
+        offsets.finish(),
+        entries.as_struct().clone(),
+        nulls,
+        true,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_array::types::Int32Type;
+
+    fn map(rows: &[Option<&[(i32, &str)]>]) -> MapArray {
+        let mut builder = MapBuilder::new(None, Int32Builder::new(), StringBuilder::new());
+        for row in rows {
+            for (k, v) in row.unwrap_or_default() {
+                builder.keys().append_value(*k);
+                builder.values().append_value(*v);
+            }
+            builder.append(row.is_some()).unwrap();
+        }
+        builder.finish()
+    }
+
+    fn entries(map: &MapArray) -> Vec<Option<Vec<(i32, String)>>> {
+        (0..map.len())
+            .map(|i| {
+                map.is_valid(i).then(|| {
+                    let entries = map.value(i);
+                    let keys = entries.column(0).as_primitive::<Int32Type>();
+                    let values = entries.column(1).as_string::<i32>();
+                    keys.values()
+                        .iter()
+                        .zip(values.iter())
+                        .map(|(k, v)| (*k, v.unwrap().to_string()))
+                        .collect()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_map_keys() {
+        let array = map(&[
+            Some(&[(3, "a"), (1, "b"), (3, "c"), (2, "d"), (1, "e")]),
+            None,
+            Some(&[]),
+            Some(&[(5, "f"), (4, "g")]),
+        ]);
+        let expected =
+            |row: &[(i32, &str)]| Some(row.iter().map(|(k, v)| (*k, v.to_string())).collect());
+
+        let sorted = sort_map_keys(&array, DuplicateKeys::Keep).unwrap();
+        assert_eq!(
+            entries(&sorted),
+            vec![
+                expected(&[(1, "b"), (1, "e"), (2, "d"), (3, "a"), (3, "c")]),
+                None,
+                expected(&[]),
+                expected(&[(4, "g"), (5, "f")]),
+            ]
+        );
+
+        let sorted = sort_map_keys(&array, DuplicateKeys::FirstWins).unwrap();
+        assert_eq!(sorted.value_offsets(), &[0, 3, 3, 3, 5]);
+        assert_eq!(
+            entries(&sorted)[0],
+            expected(&[(1, "b"), (2, "d"), (3, "a")])
+        );
+
+        let sorted = sort_map_keys(&array, DuplicateKeys::LastWins).unwrap();
+        assert_eq!(
+            entries(&sorted)[0],
+            expected(&[(1, "e"), (2, "d"), (3, "c")])
+        );
+        assert_eq!(sorted.null_count(), 1);
+
+        let err = sort_map_keys(&array, DuplicateKeys::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Duplicate key at index 4 of map entries"
+        );
+
+        // Sliced arrays only sort the entries within the slice
+        let sliced = array.slice(3, 1);
+        let sliced = sort_map_keys(&sliced, DuplicateKeys::Error).unwrap();
+        assert_eq!(sliced.value_offsets(), &[0, 2]);
+        assert_eq!(entries(&sliced), vec![expected(&[(4, "g"), (5, "f")])]);
+    }
+}
@@ -75,7 +75,8 @@ fn compare_dict<K: ArrowDictionaryKeyType>(
 
 /// returns a comparison function that compares two values at two different positions
 /// between the two arrays.
-/// The arrays' types must be equal.
+/// The arrays' types must be equal, except for the timezone of timestamps, which are
+/// compared on the instant they represent.
 /// # Example
 /// ```
 /// use arrow_array::Int32Array;
@@ -121,6 +122,9 @@ pub fn build_compare(left: &dyn Array, right: &dyn Array) -> Result<DynComparato
                  _ => unreachable!()
              }
         },
+        (Timestamp(l_unit, _), Timestamp(r_unit, _)) => Err(ArrowError::InvalidArgumentError(
+            format!("Can't compare timestamps with different units, got {l_unit:?} and {r_unit:?}")
+        )),
         (lhs, rhs) => Err(ArrowError::InvalidArgumentError(match lhs == rhs {
             true => format!("The data type type {lhs:?} has no natural order"),
             false => "Can't compare arrays of different types".to_string(),
@@ -377,6 +381,23 @@ pub mod tests {
         assert_eq!(Ordering::Greater, cmp(3, 2));
     }
 
+    #[test]
+    fn test_timestamp_mixed_timezones() {
+        let array1 = TimestampSecondArray::from(vec![1, 5]).with_timezone("+01:00");
+        let array2 = TimestampSecondArray::from(vec![2, 5]).with_timezone("America/New_York");
+
+        let cmp = build_compare(&array1, &array2).unwrap();
+        assert_eq!(Ordering::Less, cmp(0, 0));
+        assert_eq!(Ordering::Equal, cmp(1, 1));
+
+        let array3 = TimestampMillisecondArray::from(vec![2]).with_timezone("+01:00");
+        let err = build_compare(&array1, &array3).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Can't compare timestamps with different units, got Second and Millisecond"
+        );
+    }
+
     #[test]
     fn test_timestamp_dict() {
         let values = TimestampSecondArray::from(vec![1, 0, 2, 5]);
@@ -179,7 +179,7 @@ where
 }
 
 // partition indices into valid and null indices
-fn partition_validity(array: &dyn Array) -> (Vec<u32>, Vec<u32>) {
+pub(crate) fn partition_validity(array: &dyn Array) -> (Vec<u32>, Vec<u32>) {
     match array.null_count() {
         // faster path
         0 => ((0..(array.len() as u32)).collect(), vec![]),
@@ -234,6 +234,55 @@ pub fn sort_to_indices(
     })
 }
 
+/// Sort elements from `ArrayRef` into an unsigned integer (`UInt32Array`) of indices,
+/// sorting arrays of at least `min_parallel_len` elements on the rayon global thread pool
+///
+/// Returns the same order as [`sort_to_indices`], except that the order of equal
+/// values may differ, as neither sort is stable. Arrays shorter than `min_parallel_len`,
+/// and arrays of nested or run-end encoded types, are sorted with [`sort_to_indices`]
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::sort::{sort_to_indices_parallel, SortOptions};
+/// let array = Int32Array::from(vec![Some(5), None, Some(1), Some(3)]);
+/// let options = SortOptions { descending: false, nulls_first: false };
+/// let indices = sort_to_indices_parallel(&array, Some(options), None, 2).unwrap();
+/// assert_eq!(indices.values(), &[2, 3, 0, 1]);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn sort_to_indices_parallel(
+    array: &dyn Array,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+    min_parallel_len: usize,
+) -> Result<UInt32Array, ArrowError> {
+    use rayon::slice::ParallelSliceMut;
+
+    if array.len() < min_parallel_len {
+        return sort_to_indices(array, options, limit);
+    }
+    let cmp = match build_compare(array, array) {
+        Ok(cmp) => cmp,
+        Err(_) => return sort_to_indices(array, options, limit),
+    };
+    let options = options.unwrap_or_default();
+    let (mut valids, nulls) = partition_validity(array);
+
+    match options.descending {
+        false => valids.par_sort_unstable_by(|a, b| cmp(*a as usize, *b as usize)),
+        true => valids.par_sort_unstable_by(|a, b| cmp(*a as usize, *b as usize).reverse()),
+    }
+
+    let len = valids.len() + nulls.len();
+    let limit = limit.unwrap_or(len).min(len);
+    let mut out = Vec::with_capacity(limit);
+    match options.nulls_first {
+        true => out.extend(nulls.into_iter().chain(valids).take(limit)),
+        false => out.extend(valids.into_iter().chain(nulls).take(limit)),
+    }
+    Ok(UInt32Array::from(out))
+}
+
 fn sort_boolean(
     values: &BooleanArray,
     value_indices: Vec<u32>,
@@ -354,7 +403,7 @@ fn sort_fixed_size_list(
 }
 
 #[inline(never)]
-fn sort_impl<T: ?Sized + Copy>(
+pub(crate) fn sort_impl<T: ?Sized + Copy>(
     options: SortOptions,
     valids: &mut [(u32, T)],
     nulls: &[u32],
@@ -3941,4 +3990,40 @@ mod tests {
         let sort_indices = sort_to_indices(&a, None, None).unwrap();
         assert_eq!(sort_indices.values(), &[1, 2, 0]);
     }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_sort_to_indices_parallel() {
+        // Distinct values, so that the unstable sorts agree
+        let values = (0..1009).map(|i| (i % 5 != 0).then_some((i * 7919) % 1009));
+        let ints: ArrayRef = Arc::new(values.clone().collect::<Int32Array>());
+        let strings = values.map(|v| v.map(|v| format!("{v:05}")));
+        let strings: ArrayRef = Arc::new(strings.collect::<StringArray>());
+
+        for array in [ints, strings] {
+            for (descending, nulls_first) in [(false, false), (false, true), (true, false)] {
+                let options = Some(SortOptions {
+                    descending,
+                    nulls_first,
+                });
+                for limit in [None, Some(10), Some(2000)] {
+                    let expected = sort_to_indices(&array, options, limit).unwrap();
+                    let actual = sort_to_indices_parallel(&array, options, limit, 100).unwrap();
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+
+        // Falls back to the serial sort for types without a comparator
+        let list = {
+            let mut builder = ListBuilder::new(Int64Builder::new());
+            for value in [[1, 5], [0, 3], [1, 3]] {
+                builder.values().append_slice(&value);
+                builder.append(true);
+            }
+            builder.finish()
+        };
+        let indices = sort_to_indices_parallel(&list, None, None, 0).unwrap();
+        assert_eq!(indices.values(), &[1, 2, 0]);
+    }
 }
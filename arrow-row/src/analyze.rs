@@ -0,0 +1,390 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Statistics describing the values of an array, to inform the choice of encoding
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, StringArray};
+//! # use arrow_row::analyze::analyze;
+//! let array: ArrayRef = Arc::new(StringArray::from(vec![
+//!     Some("b"), Some("b"), None, Some("a"), Some("a"), Some("a"),
+//! ]));
+//! let stats = analyze(&array).unwrap();
+//!
+//! assert_eq!(stats.null_count, 1);
+//! assert_eq!(stats.run_count, 3);
+//! assert_eq!(stats.distinct_count, 2);
+//! assert_eq!(stats.min.unwrap().as_ref(), &StringArray::from(vec!["a"]));
+//! assert_eq!(stats.max.unwrap().as_ref(), &StringArray::from(vec!["b"]));
+//! ```
+//!
+//! The statistics of every column of a [`RecordBatch`] can be computed with
+//! [`batch_statistics`], for example to populate the metadata of a file or a
+//! pruning index
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow_array::{new_null_array, Array, ArrayRef, RecordBatch, StructArray, UInt64Array};
+use arrow_schema::{ArrowError, DataType, Field};
+
+use crate::{Row, RowConverter, SortField};
+
+/// Statistics of an array, computed by [`analyze`]
+///
+/// These can be used to choose an encoding for the array, for example a low
+/// [`Self::distinct_count`] relative to the length suggests dictionary encoding,
+/// whereas a high [`Self::average_run_length`] suggests run-end encoding.
+#[derive(Debug, Clone)]
+pub struct ArrayStatistics {
+    /// The number of values, including nulls
+    pub len: usize,
+    /// The number of null values
+    pub null_count: usize,
+    /// The number of runs of consecutive equal values, where consecutive nulls
+    /// are considered equal
+    pub run_count: usize,
+    /// The number of distinct non-null values
+    ///
+    /// This is an estimate, as values are compared by a 64-bit hash
+    pub distinct_count: usize,
+    /// The minimum non-null value as an array of length 1, or `None` if all values are null
+    pub min: Option<ArrayRef>,
+    /// The maximum non-null value as an array of length 1, or `None` if all values are null
+    pub max: Option<ArrayRef>,
+}
+
+impl ArrayStatistics {
+    /// Returns the fraction of values that are null, `0` for an empty array
+    pub fn null_density(&self) -> f64 {
+        match self.len {
+            0 => 0.,
+            len => self.null_count as f64 / len as f64,
+        }
+    }
+
+    /// Returns the average length of a run of equal values, `0` for an empty array
+    pub fn average_run_length(&self) -> f64 {
+        match self.run_count {
+            0 => 0.,
+            runs => self.len as f64 / runs as f64,
+        }
+    }
+}
+
+/// Computes [`ArrayStatistics`] for `array`
+///
+/// Values are compared using the [row format](crate), and so any type supported
+/// by [`RowConverter`] can be analyzed. As with the row format, floating point
+/// values are compared using a total order, with NaN greater than all other values.
+///
+/// Returns an error if the type of `array` is not supported by [`RowConverter`]
+pub fn analyze(array: &ArrayRef) -> Result<ArrayStatistics, ArrowError> {
+    let converter = RowConverter::new(vec![SortField::new(array.data_type().clone())])?;
+    let rows = converter.convert_columns(std::slice::from_ref(array))?;
+    let state = ahash::RandomState::default();
+    let nulls = array.logical_nulls();
+    let is_valid = |idx: usize| nulls.as_ref().map_or(true, |n| n.is_valid(idx));
+
+    let mut run_count = 0;
+    let mut previous: Option<Row<'_>> = None;
+    let mut hashes = HashSet::new();
+    let mut min: Option<Row<'_>> = None;
+    let mut max: Option<Row<'_>> = None;
+    for (idx, row) in rows.iter().enumerate() {
+        if previous != Some(row) {
+            run_count += 1;
+        }
+        previous = Some(row);
+
+        if !is_valid(idx) {
+            continue;
+        }
+        hashes.insert(state.hash_one(row.as_ref()));
+        if min.map_or(true, |m| row < m) {
+            min = Some(row);
+        }
+        if max.map_or(true, |m| row > m) {
+            max = Some(row);
+        }
+    }
+
+    let value = |row: Option<Row<'_>>| -> Result<Option<ArrayRef>, ArrowError> {
+        match row {
+            Some(row) => Ok(converter.convert_rows([row])?.pop()),
+            None => Ok(None),
+        }
+    };
+
+    Ok(ArrayStatistics {
+        len: array.len(),
+        null_count: nulls.as_ref().map_or(0, |n| n.null_count()),
+        run_count,
+        distinct_count: hashes.len(),
+        min: value(min)?,
+        max: value(max)?,
+    })
+}
+
+/// Computes the statistics of each column of `batch`
+///
+/// Returns a [`StructArray`] of length 1, with a field for each column of `batch`
+/// in the same order and with the same name. Each of these is itself a
+/// [`StructArray`] with the fields
+///
+/// * `min`: the minimum non-null value, null if all values are null
+/// * `max`: the maximum non-null value, null if all values are null
+/// * `null_count`: the number of null values, as [`UInt64Array`]
+/// * `distinct_count`: an estimate of the number of distinct non-null values,
+///   as [`UInt64Array`]
+///
+/// The values are computed by [`analyze`], and the data types of `min` and `max`
+/// are those of the values it returns, for example the value type of a dictionary.
+///
+/// Returns an error if the type of any column is not supported by [`RowConverter`]
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Array, ArrayRef, Int32Array, RecordBatch, StringArray, UInt64Array};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_row::analyze::batch_statistics;
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![Some(3), None, Some(1)])) as ArrayRef),
+///     ("b", Arc::new(StringArray::from(vec!["x", "y", "x"])) as ArrayRef),
+/// ]).unwrap();
+///
+/// let stats = batch_statistics(&batch).unwrap();
+/// let a = stats.column_by_name("a").unwrap().as_struct();
+/// assert_eq!(a.column_by_name("min").unwrap().as_ref(), &Int32Array::from(vec![1]));
+/// assert_eq!(a.column_by_name("max").unwrap().as_ref(), &Int32Array::from(vec![3]));
+/// assert_eq!(a.column_by_name("null_count").unwrap().as_ref(), &UInt64Array::from(vec![1]));
+///
+/// let b = stats.column_by_name("b").unwrap().as_struct();
+/// assert_eq!(b.column_by_name("distinct_count").unwrap().as_ref(), &UInt64Array::from(vec![2]));
+/// ```
+pub fn batch_statistics(batch: &RecordBatch) -> Result<StructArray, ArrowError> {
+    let schema = batch.schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, column)| {
+            let stats = column_statistics(column)?;
+            let field = Field::new(field.name(), stats.data_type().clone(), false);
+            Ok((Arc::new(field), Arc::new(stats) as ArrayRef))
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    match columns.is_empty() {
+        true => Ok(StructArray::new_empty_fields(1, None)),
+        false => Ok(StructArray::from(columns)),
+    }
+}
+
+/// Returns the statistics of `array` as a [`StructArray`] of length 1,
+/// see [`batch_statistics`]
+fn column_statistics(array: &ArrayRef) -> Result<StructArray, ArrowError> {
+    let stats = analyze(array)?;
+    let count = |c: usize| Arc::new(UInt64Array::from(vec![c as u64])) as ArrayRef;
+
+    let (min, max) = match (stats.min, stats.max) {
+        (Some(min), Some(max)) => (min, max),
+        _ => {
+            // Use the data type of values decoded from rows, consistent with the
+            // non-null case, e.g. the value type of a dictionary
+            let converter = RowConverter::new(vec![SortField::new(array.data_type().clone())])?;
+            let empty = converter.convert_rows(std::iter::empty())?.pop().unwrap();
+            let null = new_null_array(empty.data_type(), 1);
+            (null.clone(), null)
+        }
+    };
+    Ok(StructArray::from(vec![
+        (
+            Arc::new(Field::new("min", min.data_type().clone(), true)),
+            min,
+        ),
+        (
+            Arc::new(Field::new("max", max.data_type().clone(), true)),
+            max,
+        ),
+        (
+            Arc::new(Field::new("null_count", DataType::UInt64, false)),
+            count(stats.null_count),
+        ),
+        (
+            Arc::new(Field::new("distinct_count", DataType::UInt64, false)),
+            count(stats.distinct_count),
+        ),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{
+        DictionaryArray, Float64Array, Int32Array, ListArray, NullArray, StringArray,
+    };
+    use std::sync::Arc;
+
+    #[test]
+    fn test_analyze_primitive() {
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(3),
+            Some(3),
+            None,
+            None,
+            Some(-1),
+            Some(3),
+            Some(7),
+        ]));
+        let stats = analyze(&array).unwrap();
+        assert_eq!(stats.len, 7);
+        assert_eq!(stats.null_count, 2);
+        assert_eq!(stats.run_count, 5);
+        assert_eq!(stats.distinct_count, 3);
+        assert_eq!(stats.min.as_deref().unwrap(), &Int32Array::from(vec![-1]));
+        assert_eq!(stats.max.as_deref().unwrap(), &Int32Array::from(vec![7]));
+        assert!((stats.null_density() - 2. / 7.).abs() < f64::EPSILON);
+        assert!((stats.average_run_length() - 7. / 5.).abs() < f64::EPSILON);
+
+        let array: ArrayRef = Arc::new(Float64Array::from(vec![1.5, f64::NAN, -0.]));
+        let stats = analyze(&array).unwrap();
+        assert_eq!(
+            stats.min.as_deref().unwrap(),
+            &Float64Array::from(vec![-0.])
+        );
+        let max = stats.max.unwrap();
+        assert!(max
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap()
+            .value(0)
+            .is_nan());
+    }
+
+    #[test]
+    fn test_analyze_nested_and_dictionary() {
+        let list: ArrayRef = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1)]),
+            None,
+        ]));
+        let stats = analyze(&list).unwrap();
+        assert_eq!((stats.run_count, stats.distinct_count), (3, 2));
+        let min = stats.min.unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        assert_eq!(min.as_ref(), &expected);
+
+        let dict: DictionaryArray<Int32Type> = vec!["z", "a", "z"].into_iter().collect();
+        let dict: ArrayRef = Arc::new(dict);
+        let stats = analyze(&dict).unwrap();
+        assert_eq!((stats.run_count, stats.distinct_count), (3, 2));
+        let min = arrow_cast::cast(&stats.min.unwrap(), &arrow_schema::DataType::Utf8).unwrap();
+        assert_eq!(min.as_ref(), &StringArray::from(vec!["a"]));
+    }
+
+    #[test]
+    fn test_batch_statistics() {
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some("z"), None, Some("a")].into_iter().collect();
+        let null_dict: DictionaryArray<Int32Type> =
+            vec![None::<&str>, None, None].into_iter().collect();
+        let batch = RecordBatch::try_from_iter([
+            (
+                "ints",
+                Arc::new(Int32Array::from(vec![5, 2, 9])) as ArrayRef,
+            ),
+            ("dict", Arc::new(dict)),
+            ("null_dict", Arc::new(null_dict)),
+        ])
+        .unwrap();
+
+        let stats = batch_statistics(&batch).unwrap();
+        assert_eq!(stats.len(), 1);
+        let names: Vec<_> = stats.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, ["ints", "dict", "null_dict"]);
+
+        let column = |name: &str, stat: &str| -> ArrayRef {
+            let column = stats.column_by_name(name).unwrap();
+            let column = column.as_any().downcast_ref::<StructArray>().unwrap();
+            column.column_by_name(stat).unwrap().clone()
+        };
+        assert_eq!(column("ints", "min").as_ref(), &Int32Array::from(vec![2]));
+        assert_eq!(column("ints", "max").as_ref(), &Int32Array::from(vec![9]));
+        assert_eq!(
+            column("ints", "null_count").as_ref(),
+            &UInt64Array::from(vec![0])
+        );
+        assert_eq!(
+            column("dict", "min").as_ref(),
+            &StringArray::from(vec!["a"])
+        );
+        assert_eq!(
+            column("dict", "max").as_ref(),
+            &StringArray::from(vec!["z"])
+        );
+        assert_eq!(
+            column("dict", "null_count").as_ref(),
+            &UInt64Array::from(vec![1])
+        );
+        assert_eq!(
+            column("dict", "distinct_count").as_ref(),
+            &UInt64Array::from(vec![2])
+        );
+
+        // All null columns have null min and max of the same type
+        let min = column("null_dict", "min");
+        assert_eq!(min.data_type(), &DataType::Utf8);
+        assert!(min.is_null(0));
+        assert_eq!(
+            column("null_dict", "null_count").as_ref(),
+            &UInt64Array::from(vec![3])
+        );
+        assert_eq!(
+            column("null_dict", "distinct_count").as_ref(),
+            &UInt64Array::from(vec![0])
+        );
+
+        let empty = RecordBatch::new_empty(Arc::new(arrow_schema::Schema::empty()));
+        let stats = batch_statistics(&empty).unwrap();
+        assert_eq!((stats.len(), stats.num_columns()), (1, 0));
+    }
+
+    #[test]
+    fn test_analyze_all_null() {
+        for array in [
+            Arc::new(Int32Array::from(vec![None, None])) as ArrayRef,
+            Arc::new(NullArray::new(2)),
+        ] {
+            let stats = analyze(&array).unwrap();
+            assert_eq!(stats.null_count, 2);
+            assert_eq!(stats.run_count, 1);
+            assert_eq!(stats.distinct_count, 0);
+            assert!(stats.min.is_none() && stats.max.is_none());
+            assert_eq!(stats.null_density(), 1.);
+        }
+
+        let empty: ArrayRef = Arc::new(Int32Array::from(Vec::<i32>::new()));
+        let stats = analyze(&empty).unwrap();
+        assert_eq!((stats.len, stats.run_count), (0, 0));
+        assert_eq!(stats.null_density(), 0.);
+        assert_eq!(stats.average_run_length(), 0.);
+    }
+}
@@ -0,0 +1,339 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A hash table mapping keys, encoded in the [row format](crate), to group ids
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, StringArray};
+//! # use arrow_row::SortField;
+//! # use arrow_row::group::GroupedHashTable;
+//! # use arrow_schema::DataType;
+//! let mut table = GroupedHashTable::try_new(vec![
+//!     SortField::new(DataType::Utf8),
+//!     SortField::new(DataType::Int32),
+//! ]).unwrap();
+//!
+//! let columns = [
+//!     Arc::new(StringArray::from(vec!["a", "b", "a", "a"])) as ArrayRef,
+//!     Arc::new(Int32Array::from(vec![1, 1, 1, 2])) as ArrayRef,
+//! ];
+//! let mut groups = vec![];
+//! table.insert(&columns, &mut groups).unwrap();
+//! assert_eq!(groups, vec![0, 1, 0, 2]);
+//!
+//! let probe = [
+//!     Arc::new(StringArray::from(vec!["a", "c"])) as ArrayRef,
+//!     Arc::new(Int32Array::from(vec![2, 1])) as ArrayRef,
+//! ];
+//! let mut matches = vec![];
+//! table.probe(&probe, &mut matches).unwrap();
+//! assert_eq!(matches, vec![Some(2), None]);
+//!
+//! // The distinct keys, in group id order
+//! let keys = table.keys().unwrap();
+//! assert_eq!(keys[0].as_ref(), &StringArray::from(vec!["a", "b", "a"]));
+//! ```
+
+use arrow_array::ArrayRef;
+use arrow_schema::ArrowError;
+use hashbrown::hash_map::RawEntryMut;
+use hashbrown::HashMap;
+
+use crate::{DictionaryEncoding, Row, RowConverter, Rows, SortField};
+
+/// A hash table assigning a sequential group id to each distinct key
+///
+/// Keys are encoded using a [`RowConverter`], allowing any combination of columns
+/// supported by the row format to be used as a key with a single hash table,
+/// forming the basis for hash aggregation and hash joins.
+///
+/// As the row format encodes nulls, null values compare equal to each other,
+/// consistent with SQL `GROUP BY` semantics. Callers implementing SQL equi-joins
+/// should exclude rows with null keys.
+#[derive(Debug)]
+pub struct GroupedHashTable {
+    converter: RowConverter,
+    state: ahash::RandomState,
+    /// Set of group ids, hashed by the key of the group
+    map: HashMap<usize, (), ()>,
+    /// The key of each group, indexed by group id
+    keys: Rows,
+    /// The hash of each group's key, indexed by group id
+    hashes: Vec<u64>,
+}
+
+impl GroupedHashTable {
+    /// Create a new [`GroupedHashTable`] for keys with the given fields
+    ///
+    /// Returns an error if the fields are not supported by the [`RowConverter`]
+    pub fn try_new(fields: Vec<SortField>) -> Result<Self, ArrowError> {
+        Self::try_new_with_dictionary_encoding(fields, DictionaryEncoding::Values)
+    }
+
+    /// Create a new [`GroupedHashTable`] for keys with the given fields, encoding
+    /// dictionary columns with `encoding`
+    ///
+    /// [`DictionaryEncoding::Interned`] reduces the size of the keys, and the cost of
+    /// hashing and comparing them, for dictionaries with long values
+    pub fn try_new_with_dictionary_encoding(
+        fields: Vec<SortField>,
+        encoding: DictionaryEncoding,
+    ) -> Result<Self, ArrowError> {
+        let converter = RowConverter::new_with_dictionary_encoding(fields, encoding)?;
+        let keys = converter.empty_rows(0, 0);
+        Ok(Self {
+            converter,
+            state: ahash::RandomState::default(),
+            map: HashMap::with_hasher(()),
+            keys,
+            hashes: vec![],
+        })
+    }
+
+    /// Returns the [`RowConverter`] used to encode the keys
+    ///
+    /// This can be used to convert columns once, and pass the resulting [`Rows`]
+    /// to [`Self::insert_rows`] and [`Self::probe_rows`]
+    pub fn converter(&self) -> &RowConverter {
+        &self.converter
+    }
+
+    /// Returns the number of groups
+    pub fn num_groups(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// Returns `true` if there are no groups
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    /// Returns the key of each group, in the row format, indexed by group id
+    pub fn rows(&self) -> &Rows {
+        &self.keys
+    }
+
+    /// Returns the key of each group as arrays, indexed by group id
+    pub fn keys(&self) -> Result<Vec<ArrayRef>, ArrowError> {
+        self.converter.convert_rows(&self.keys)
+    }
+
+    /// Inserts the keys in `columns`, setting `groups` to the group id of each row
+    ///
+    /// Rows with a key not previously seen are assigned the next group id
+    pub fn insert(
+        &mut self,
+        columns: &[ArrayRef],
+        groups: &mut Vec<usize>,
+    ) -> Result<(), ArrowError> {
+        let rows = self.converter.convert_columns(columns)?;
+        self.insert_rows(&rows, groups);
+        Ok(())
+    }
+
+    /// Inserts the keys in `rows`, setting `groups` to the group id of each row
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows` were not produced by [`Self::converter`]
+    pub fn insert_rows(&mut self, rows: &Rows, groups: &mut Vec<usize>) {
+        groups.clear();
+        groups.reserve(rows.num_rows());
+
+        let hashes = self.hash_rows(rows);
+        for (row, hash) in rows.iter().zip(hashes) {
+            let keys = &self.keys;
+            let entry = self
+                .map
+                .raw_entry_mut()
+                .from_hash(hash, |g| keys.row(*g) == row);
+
+            let group = match entry {
+                RawEntryMut::Occupied(entry) => *entry.into_key(),
+                RawEntryMut::Vacant(entry) => {
+                    let group = self.hashes.len();
+                    let hashes = &self.hashes;
+                    entry.insert_with_hasher(hash, group, (), |g| hashes[*g]);
+                    self.keys.push(row);
+                    self.hashes.push(hash);
+                    group
+                }
+            };
+            groups.push(group);
+        }
+    }
+
+    /// Looks up the keys in `columns`, setting `groups` to the group id of each row,
+    /// or `None` if the key is not present
+    pub fn probe(
+        &self,
+        columns: &[ArrayRef],
+        groups: &mut Vec<Option<usize>>,
+    ) -> Result<(), ArrowError> {
+        let rows = self.converter.convert_columns(columns)?;
+        self.probe_rows(&rows, groups);
+        Ok(())
+    }
+
+    /// Looks up the keys in `rows`, setting `groups` to the group id of each row,
+    /// or `None` if the key is not present
+    pub fn probe_rows(&self, rows: &Rows, groups: &mut Vec<Option<usize>>) {
+        groups.clear();
+        groups.reserve(rows.num_rows());
+
+        let hashes = self.hash_rows(rows);
+        groups.extend(
+            rows.iter()
+                .zip(hashes)
+                .map(|(row, hash)| self.find(row, hash)),
+        );
+    }
+
+    fn find(&self, row: Row<'_>, hash: u64) -> Option<usize> {
+        self.map
+            .raw_entry()
+            .from_hash(hash, |g| self.keys.row(*g) == row)
+            .map(|(g, _)| *g)
+    }
+
+    fn hash_rows(&self, rows: &Rows) -> Vec<u64> {
+        rows.iter()
+            .map(|row| self.state.hash_one(row.as_ref()))
+            .collect()
+    }
+
+    /// Removes all groups, retaining the allocated memory
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.keys.clear();
+        self.hashes.clear();
+    }
+
+    /// Returns the size of this instance in bytes
+    ///
+    /// Includes the size of `Self`.
+    pub fn size(&self) -> usize {
+        std::mem::size_of::<Self>() - std::mem::size_of::<Rows>()
+            + self.keys.size()
+            + self.map.capacity() * std::mem::size_of::<usize>()
+            + self.hashes.capacity() * std::mem::size_of::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Array, DictionaryArray, Int64Array, StringArray};
+    use arrow_schema::DataType;
+    use rand::{Rng, SeedableRng};
+    use std::collections::HashMap as StdHashMap;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_nulls_and_dictionaries() {
+        for encoding in [DictionaryEncoding::Values, DictionaryEncoding::Interned] {
+            let dict_type =
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+            let mut table = GroupedHashTable::try_new_with_dictionary_encoding(
+                vec![SortField::new(dict_type), SortField::new(DataType::Int64)],
+                encoding,
+            )
+            .unwrap();
+
+            let a: DictionaryArray<Int32Type> =
+                vec![Some("x"), None, Some("x"), None].into_iter().collect();
+            let b = Int64Array::from(vec![Some(1), None, Some(1), None]);
+            let mut groups = vec![];
+            table
+                .insert(&[Arc::new(a) as _, Arc::new(b) as _], &mut groups)
+                .unwrap();
+            assert_eq!(groups, vec![0, 1, 0, 1]);
+
+            // Dictionaries with different values are compared by value
+            let a: DictionaryArray<Int32Type> = vec![Some("y"), Some("x")].into_iter().collect();
+            let b = Int64Array::from(vec![Some(1), Some(1)]);
+            table
+                .insert(&[Arc::new(a) as _, Arc::new(b) as _], &mut groups)
+                .unwrap();
+            assert_eq!(groups, vec![2, 0]);
+            assert_eq!(table.num_groups(), 3);
+
+            let keys = table.keys().unwrap();
+            let values = arrow_cast::cast(&keys[0], &DataType::Utf8).unwrap();
+            assert_eq!(
+                values.as_ref(),
+                &StringArray::from(vec![Some("x"), None, Some("y")])
+            );
+            assert_eq!(keys[1].null_count(), 1);
+
+            table.clear();
+            assert!(table.is_empty());
+            let mut matches = vec![];
+            let a: DictionaryArray<Int32Type> = vec![Some("x")].into_iter().collect();
+            let b = Int64Array::from(vec![1]);
+            table
+                .probe(&[Arc::new(a) as _, Arc::new(b) as _], &mut matches)
+                .unwrap();
+            assert_eq!(matches, vec![None]);
+        }
+    }
+
+    #[test]
+    fn test_fuzz() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let mut table = GroupedHashTable::try_new(vec![
+            SortField::new(DataType::Utf8),
+            SortField::new(DataType::Int64),
+        ])
+        .unwrap();
+        let mut expected: StdHashMap<(Option<String>, Option<i64>), usize> = StdHashMap::new();
+        let mut groups = vec![];
+
+        for _ in 0..10 {
+            let len = rng.gen_range(0..500);
+            let a: StringArray = (0..len)
+                .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..20).to_string()))
+                .collect();
+            let b: Int64Array = (0..len)
+                .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..20)))
+                .collect();
+            let columns = [Arc::new(a.clone()) as ArrayRef, Arc::new(b.clone()) as _];
+
+            let mut probed = vec![];
+            table.probe(&columns, &mut probed).unwrap();
+            table.insert(&columns, &mut groups).unwrap();
+            assert_eq!(groups.len(), len);
+
+            let existing = expected.len();
+            for (idx, group) in groups.iter().enumerate() {
+                let key = (
+                    a.is_valid(idx).then(|| a.value(idx).to_string()),
+                    b.is_valid(idx).then(|| b.value(idx)),
+                );
+                let next = expected.len();
+                let expected_group = *expected.entry(key).or_insert(next);
+                assert_eq!(*group, expected_group);
+                let expected_probe = (expected_group < existing).then_some(expected_group);
+                assert_eq!(probed[idx], expected_probe);
+            }
+        }
+        assert_eq!(table.num_groups(), expected.len());
+        assert!(table.size() > 0);
+    }
+}
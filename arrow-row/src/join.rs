@@ -0,0 +1,304 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Kernels computing the indices of the rows matched by an equi-join
+//!
+//! The returned indices can be used with the `take` or `interleave` kernels
+//! to materialize the joined columns.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, UInt32Array};
+//! # use arrow_row::join::{join_indices, JoinType};
+//! let left = [Arc::new(Int32Array::from(vec![Some(1), Some(2), None, Some(3)])) as ArrayRef];
+//! let right = [Arc::new(Int32Array::from(vec![Some(3), Some(1), Some(1), None])) as ArrayRef];
+//!
+//! let (l, r) = join_indices(&left, &right, JoinType::Inner).unwrap();
+//! assert_eq!(l, UInt32Array::from(vec![0, 0, 3]));
+//! assert_eq!(r, UInt32Array::from(vec![1, 2, 0]));
+//!
+//! let (l, r) = join_indices(&left, &right, JoinType::Left).unwrap();
+//! assert_eq!(l, UInt32Array::from(vec![0, 0, 1, 2, 3]));
+//! assert_eq!(r, UInt32Array::from(vec![Some(1), Some(2), None, None, Some(0)]));
+//!
+//! let (l, _) = join_indices(&left, &right, JoinType::LeftAnti).unwrap();
+//! assert_eq!(l, UInt32Array::from(vec![1, 2]));
+//! ```
+
+use arrow_array::builder::UInt32Builder;
+use arrow_array::{Array, ArrayRef, UInt32Array};
+use arrow_buffer::NullBuffer;
+use arrow_schema::ArrowError;
+
+use crate::group::GroupedHashTable;
+use crate::SortField;
+
+/// The type of join for which to compute indices, see [`join_indices`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JoinType {
+    /// Pairs of left and right rows with equal keys
+    Inner,
+    /// Pairs of left and right rows with equal keys, along with the left rows
+    /// without a match, paired with a null right index
+    Left,
+    /// The left rows with at least one matching right row
+    LeftSemi,
+    /// The left rows without a matching right row
+    LeftAnti,
+}
+
+/// A hash table of the keys of the build side of a join, that can be probed
+/// multiple times
+///
+/// Rows with a null in any key column never match, consistent with SQL semantics
+#[derive(Debug)]
+pub struct JoinHashTable {
+    table: GroupedHashTable,
+    /// The build rows with key group `g` are `rows[offsets[g]..offsets[g + 1]]`
+    offsets: Vec<usize>,
+    /// The build row indices, ordered by group and then by index
+    rows: Vec<u32>,
+}
+
+impl JoinHashTable {
+    /// Create a new [`JoinHashTable`] from the key columns of the build side
+    pub fn try_new(build: &[ArrayRef]) -> Result<Self, ArrowError> {
+        let fields = build
+            .iter()
+            .map(|c| SortField::new(c.data_type().clone()))
+            .collect();
+        let mut table = GroupedHashTable::try_new(fields)?;
+
+        let num_rows = build.first().map(|c| c.len()).unwrap_or_default();
+        check_len(num_rows)?;
+
+        let mut groups = vec![];
+        table.insert(build, &mut groups)?;
+        let nulls = key_nulls(build);
+        let valid = |idx: usize| nulls.as_ref().map_or(true, |n| n.is_valid(idx));
+
+        // Counting sort of the build rows by group
+        let mut offsets = vec![0; table.num_groups() + 1];
+        for (idx, group) in groups.iter().enumerate() {
+            if valid(idx) {
+                offsets[*group + 1] += 1;
+            }
+        }
+        for g in 0..table.num_groups() {
+            offsets[g + 1] += offsets[g];
+        }
+        let mut next = offsets.clone();
+        let mut rows = vec![0; offsets[table.num_groups()]];
+        for (idx, group) in groups.iter().enumerate() {
+            if valid(idx) {
+                rows[next[*group]] = idx as u32;
+                next[*group] += 1;
+            }
+        }
+
+        Ok(Self {
+            table,
+            offsets,
+            rows,
+        })
+    }
+
+    /// Returns the number of build rows with a non-null key
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Probes the table with the key columns of the probe side, returning the
+    /// indices of the matched `(probe, build)` rows for the given [`JoinType`]
+    ///
+    /// Indices are ordered by probe row, and then by build row. For
+    /// [`JoinType::LeftSemi`] and [`JoinType::LeftAnti`] the build indices are
+    /// all null.
+    pub fn probe(
+        &self,
+        probe: &[ArrayRef],
+        join_type: JoinType,
+    ) -> Result<(UInt32Array, UInt32Array), ArrowError> {
+        let num_rows = probe.first().map(|c| c.len()).unwrap_or_default();
+        check_len(num_rows)?;
+
+        let mut groups = vec![];
+        self.table.probe(probe, &mut groups)?;
+        let nulls = key_nulls(probe);
+
+        let mut left = UInt32Builder::with_capacity(num_rows);
+        let mut right = UInt32Builder::with_capacity(num_rows);
+        for (idx, group) in groups.iter().enumerate() {
+            let matches = match group {
+                Some(g) if nulls.as_ref().map_or(true, |n| n.is_valid(idx)) => {
+                    &self.rows[self.offsets[*g]..self.offsets[*g + 1]]
+                }
+                _ => &[],
+            };
+            let idx = idx as u32;
+            match join_type {
+                JoinType::Inner | JoinType::Left => {
+                    for m in matches {
+                        left.append_value(idx);
+                        right.append_value(*m);
+                    }
+                    if matches.is_empty() && join_type == JoinType::Left {
+                        left.append_value(idx);
+                        right.append_null();
+                    }
+                }
+                JoinType::LeftSemi | JoinType::LeftAnti => {
+                    if matches.is_empty() == (join_type == JoinType::LeftAnti) {
+                        left.append_value(idx);
+                        right.append_null();
+                    }
+                }
+            }
+        }
+        Ok((left.finish(), right.finish()))
+    }
+}
+
+/// Computes the indices of the rows of `left` and `right` matched by an equi-join
+/// on the given key columns
+///
+/// Builds a [`JoinHashTable`] from `right`, and probes it with `left`, see
+/// [`JoinHashTable::probe`]. Rows with a null in any key column never match.
+///
+/// Returns an error if the key columns of `left` and `right` do not have the
+/// same types, or there are more than `u32::MAX` rows
+pub fn join_indices(
+    left: &[ArrayRef],
+    right: &[ArrayRef],
+    join_type: JoinType,
+) -> Result<(UInt32Array, UInt32Array), ArrowError> {
+    if left.len() != right.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Join requires the same number of key columns on each side, got {} and {}",
+            left.len(),
+            right.len()
+        )));
+    }
+    for (l, r) in left.iter().zip(right) {
+        if l.data_type() != r.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Join key types must match, got {} and {}",
+                l.data_type(),
+                r.data_type()
+            )));
+        }
+    }
+    JoinHashTable::try_new(right)?.probe(left, join_type)
+}
+
+fn check_len(len: usize) -> Result<(), ArrowError> {
+    match u32::try_from(len) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot compute join indices for {len} rows, exceeds u32::MAX"
+        ))),
+    }
+}
+
+/// The rows with a null in any of `columns`
+fn key_nulls(columns: &[ArrayRef]) -> Option<NullBuffer> {
+    columns.iter().fold(None, |acc, c| {
+        NullBuffer::union(acc.as_ref(), c.logical_nulls().as_ref())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Int32Array, StringArray};
+    use std::sync::Arc;
+
+    fn keys(a: Vec<Option<i32>>, b: Vec<Option<&str>>) -> Vec<ArrayRef> {
+        vec![
+            Arc::new(Int32Array::from(a)),
+            Arc::new(StringArray::from(b)),
+        ]
+    }
+
+    #[test]
+    fn test_join_indices() {
+        let left = keys(
+            vec![Some(1), Some(1), Some(2), None, Some(3)],
+            vec![Some("a"), Some("b"), Some("a"), Some("a"), None],
+        );
+        let right = keys(
+            vec![Some(1), Some(2), Some(1), None, Some(3), Some(1)],
+            vec![Some("a"), Some("a"), Some("a"), Some("a"), None, Some("c")],
+        );
+
+        let (l, r) = join_indices(&left, &right, JoinType::Inner).unwrap();
+        assert_eq!(l, UInt32Array::from(vec![0, 0, 2]));
+        assert_eq!(r, UInt32Array::from(vec![0, 2, 1]));
+
+        let (l, r) = join_indices(&left, &right, JoinType::Left).unwrap();
+        assert_eq!(l, UInt32Array::from(vec![0, 0, 1, 2, 3, 4]));
+        assert_eq!(
+            r,
+            UInt32Array::from(vec![Some(0), Some(2), None, Some(1), None, None])
+        );
+
+        let (l, r) = join_indices(&left, &right, JoinType::LeftSemi).unwrap();
+        assert_eq!(l, UInt32Array::from(vec![0, 2]));
+        assert_eq!(r.null_count(), 2);
+
+        let (l, r) = join_indices(&left, &right, JoinType::LeftAnti).unwrap();
+        assert_eq!(l, UInt32Array::from(vec![1, 3, 4]));
+        assert_eq!(r.null_count(), 3);
+    }
+
+    #[test]
+    fn test_probe_multiple() {
+        let table = JoinHashTable::try_new(&keys(
+            vec![Some(1), None, Some(1)],
+            vec![Some("a"), Some("a"), Some("a")],
+        ))
+        .unwrap();
+        assert_eq!(table.num_rows(), 2);
+
+        for _ in 0..2 {
+            let probe = keys(vec![Some(1), None], vec![Some("a"), Some("a")]);
+            let (l, r) = table.probe(&probe, JoinType::Inner).unwrap();
+            assert_eq!(l, UInt32Array::from(vec![0, 0]));
+            assert_eq!(r, UInt32Array::from(vec![0, 2]));
+        }
+
+        let (l, r) = table.probe(&keys(vec![], vec![]), JoinType::Left).unwrap();
+        assert!(l.is_empty() && r.is_empty());
+    }
+
+    #[test]
+    fn test_join_errors() {
+        let left = keys(vec![Some(1)], vec![Some("a")]);
+        let err = join_indices(&left, &left[..1], JoinType::Inner).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Join requires the same number of key columns on each side, got 2 and 1"
+        );
+
+        let right = vec![left[1].clone(), left[0].clone()];
+        let err = join_indices(&left, &right, JoinType::Inner).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Join key types must match, got Int32 and Utf8"
+        );
+    }
+}
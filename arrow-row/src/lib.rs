@@ -127,20 +127,26 @@
 
 use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 use arrow_array::cast::*;
-use arrow_array::types::ArrowDictionaryKeyType;
+use arrow_array::types::{ArrowDictionaryKeyType, UInt32Type};
 use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
 use arrow_data::ArrayDataBuilder;
 use arrow_schema::*;
 
 use crate::fixed::{decode_bool, decode_fixed_size_binary, decode_primitive};
+use crate::group::GroupedHashTable;
 use crate::variable::{decode_binary, decode_string};
 
+pub mod analyze;
 mod fixed;
+pub mod group;
+pub mod join;
 mod list;
+#[cfg(feature = "merge")]
+pub mod merge;
 mod variable;
 
 /// Converts [`ArrayRef`] columns into a [row-oriented](self) format.
@@ -377,6 +383,9 @@ enum Codec {
     /// A row converter for the dictionary values
     /// and the encoding of a row containing only nulls
     Dictionary(RowConverter, OwnedRow),
+    /// The interned encodings of the dictionary values, identified by group id,
+    /// and the encoding of a row containing only nulls
+    InternedDictionary(Mutex<GroupedHashTable>, OwnedRow),
     /// A row converter for the child fields
     /// and the encoding of a row containing only nulls
     Struct(RowConverter, OwnedRow),
@@ -384,9 +393,35 @@ enum Codec {
     List(RowConverter),
 }
 
+/// Locks the interned values of a [`Codec::InternedDictionary`]
+///
+/// A panic while the lock is held may leave the table partially updated,
+/// so a poisoned lock is returned as an error instead of being recovered
+fn lock_interned(
+    table: &Mutex<GroupedHashTable>,
+) -> Result<MutexGuard<'_, GroupedHashTable>, ArrowError> {
+    table.lock().map_err(|_| {
+        ArrowError::ComputeError(
+            "Interned dictionary values poisoned by a panic in another conversion".to_string(),
+        )
+    })
+}
+
 impl Codec {
-    fn new(sort_field: &SortField) -> Result<Self, ArrowError> {
+    fn new(sort_field: &SortField, encoding: DictionaryEncoding) -> Result<Self, ArrowError> {
         match &sort_field.data_type {
+            DataType::Dictionary(_, values) if encoding == DictionaryEncoding::Interned => {
+                let table =
+                    GroupedHashTable::try_new(vec![SortField::new(values.as_ref().clone())])?;
+                let null_array = new_null_array(values.as_ref(), 1);
+                let nulls = table.converter().convert_columns(&[null_array])?;
+
+                let owned = OwnedRow {
+                    data: nulls.buffer.into(),
+                    config: nulls.config,
+                };
+                Ok(Self::InternedDictionary(Mutex::new(table), owned))
+            }
             DataType::Dictionary(_, values) => {
                 let sort_field =
                     SortField::new_with_options(values.as_ref().clone(), sort_field.options);
@@ -412,7 +447,7 @@ impl Codec {
                 };
 
                 let field = SortField::new_with_options(f.data_type().clone(), options);
-                let converter = RowConverter::new(vec![field])?;
+                let converter = RowConverter::new_with_dictionary_encoding(vec![field], encoding)?;
                 Ok(Self::List(converter))
             }
             DataType::Struct(f) => {
@@ -421,7 +456,7 @@ impl Codec {
                     .map(|x| SortField::new_with_options(x.data_type().clone(), sort_field.options))
                     .collect();
 
-                let converter = RowConverter::new(sort_fields)?;
+                let converter = RowConverter::new_with_dictionary_encoding(sort_fields, encoding)?;
                 let nulls: Vec<_> = f.iter().map(|x| new_null_array(x.data_type(), 1)).collect();
 
                 let nulls = converter.convert_columns(&nulls)?;
@@ -447,6 +482,36 @@ impl Codec {
                 let rows = converter.convert_columns(&[values])?;
                 Ok(Encoder::Dictionary(rows, nulls.row()))
             }
+            Codec::InternedDictionary(table, _) => {
+                let dict = array.as_any_dictionary();
+                let mut table = lock_interned(table)?;
+                let rows = table
+                    .converter()
+                    .convert_columns(&[dict.values().clone()])?;
+                let mut groups = Vec::with_capacity(rows.num_rows());
+                table.insert_rows(&rows, &mut groups);
+                if table.num_groups() > u32::MAX as usize + 1 {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Interned dictionary values exceed the maximum of {}",
+                        u32::MAX as usize + 1
+                    )));
+                }
+
+                let ids = match dict.values().is_empty() {
+                    // All keys are null
+                    true => vec![None; dict.len()],
+                    false => {
+                        let nulls = dict.logical_nulls();
+                        let keys = dict.normalized_keys().into_iter().enumerate();
+                        keys.map(|(idx, key)| match &nulls {
+                            Some(n) if n.is_null(idx) => None,
+                            _ => Some(groups[key] as u32),
+                        })
+                        .collect()
+                    }
+                };
+                Ok(Encoder::InternedDictionary(ids))
+            }
             Codec::Struct(converter, null) => {
                 let v = as_struct_array(array);
                 let rows = converter.convert_columns(v.columns())?;
@@ -468,6 +533,11 @@ impl Codec {
         match self {
             Codec::Stateless => 0,
             Codec::Dictionary(converter, nulls) => converter.size() + nulls.data.len(),
+            Codec::InternedDictionary(table, nulls) => {
+                // Sizing only reads the table, so a poisoned lock can be recovered
+                let table = table.lock().unwrap_or_else(PoisonError::into_inner);
+                table.size() + nulls.data.len()
+            }
             Codec::Struct(converter, nulls) => converter.size() + nulls.data.len(),
             Codec::List(converter) => converter.size(),
         }
@@ -480,6 +550,8 @@ enum Encoder<'a> {
     Stateless,
     /// The encoding of the child array and the encoding of a null row
     Dictionary(Rows, Row<'a>),
+    /// The interned id of the value of each row, or `None` if null
+    InternedDictionary(Vec<Option<u32>>),
     /// The row encoding of the child arrays and the encoding of a null row
     ///
     /// It is necessary to encode to a temporary [`Rows`] to avoid serializing
@@ -490,6 +562,33 @@ enum Encoder<'a> {
     List(Rows),
 }
 
+/// How a [`RowConverter`] encodes the values of dictionary columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DictionaryEncoding {
+    /// Encode the value of each row, such that [`Row`] order by the dictionary values
+    #[default]
+    Values,
+    /// Encode a fixed width id interned for each distinct value
+    ///
+    /// The [`RowConverter`] retains each distinct value it has encoded, in a mapping used
+    /// to decode the ids, producing [`Row`] that are smaller for long values, and faster to
+    /// hash and compare. [`Row`] containing the same values compare equal, but ordering
+    /// of the ids corresponds to the order in which values were first encoded, rather than
+    /// the ordering of the values, and so this is suited to grouping and joining, but not
+    /// sorting
+    Interned,
+}
+
+/// Statistics of the dictionary values interned by a [`RowConverter`],
+/// see [`DictionaryEncoding::Interned`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InternerStatistics {
+    /// The number of distinct values interned
+    pub num_values: usize,
+    /// The memory used by the interned values, in bytes
+    pub memory_size: usize,
+}
+
 /// Configure the data type and sort order for a given column
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SortField {
@@ -521,13 +620,46 @@ impl SortField {
 impl RowConverter {
     /// Create a new [`RowConverter`] with the provided schema
     pub fn new(fields: Vec<SortField>) -> Result<Self, ArrowError> {
+        Self::new_with_dictionary_encoding(fields, DictionaryEncoding::Values)
+    }
+
+    /// Create a new [`RowConverter`] with the provided schema, encoding dictionary
+    /// columns, including those nested within other columns, with `encoding`
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, DictionaryArray};
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_row::{DictionaryEncoding, RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    /// let converter = RowConverter::new_with_dictionary_encoding(
+    ///     vec![SortField::new(data_type)],
+    ///     DictionaryEncoding::Interned,
+    /// ).unwrap();
+    ///
+    /// let long = "a long value repeated in many rows";
+    /// let array: DictionaryArray<Int32Type> = vec![long, "b", long].into_iter().collect();
+    /// let rows = converter.convert_columns(&[Arc::new(array) as ArrayRef]).unwrap();
+    /// assert_eq!(rows.row(0), rows.row(2));
+    /// assert!(rows.row(0).as_ref().len() < long.len());
+    /// assert_eq!(converter.interner_statistics().num_values, 2);
+    /// ```
+    pub fn new_with_dictionary_encoding(
+        fields: Vec<SortField>,
+        encoding: DictionaryEncoding,
+    ) -> Result<Self, ArrowError> {
         if !Self::supports_fields(&fields) {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Row format support not yet implemented for: {fields:?}"
             )));
         }
 
-        let codecs = fields.iter().map(Codec::new).collect::<Result<_, _>>()?;
+        let codecs = fields
+            .iter()
+            .map(|f| Codec::new(f, encoding))
+            .collect::<Result<_, _>>()?;
         Ok(Self {
             fields: fields.into(),
             codecs,
@@ -564,6 +696,58 @@ impl RowConverter {
         Ok(rows)
     }
 
+    /// Convert [`ArrayRef`] columns into [`Rows`], converting slices of `chunk_len` rows
+    /// on the rayon global thread pool
+    ///
+    /// Returns the same [`Rows`] as [`Self::convert_columns`], which is used for columns
+    /// of at most `chunk_len` rows
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_len` is zero, or the schema of `columns` does not match that
+    /// provided to [`RowConverter::new`]
+    #[cfg(feature = "parallel")]
+    pub fn convert_columns_parallel(
+        &self,
+        columns: &[ArrayRef],
+        chunk_len: usize,
+    ) -> Result<Rows, ArrowError> {
+        use rayon::prelude::*;
+
+        assert_ne!(chunk_len, 0, "chunk_len must be non-zero");
+        let num_rows = columns.first().map(|x| x.len()).unwrap_or(0);
+        if num_rows <= chunk_len {
+            return self.convert_columns(columns);
+        }
+        if let Some(c) = columns.iter().find(|c| c.len() != num_rows) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "RowConverter columns must have the same length, expected {num_rows} got {}",
+                c.len()
+            )));
+        }
+
+        let chunks = (0..num_rows)
+            .step_by(chunk_len)
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|offset| {
+                let len = chunk_len.min(num_rows - offset);
+                let sliced: Vec<_> = columns.iter().map(|c| c.slice(offset, len)).collect();
+                self.convert_columns(&sliced)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let data_len = chunks.iter().map(|c| c.buffer.len()).sum();
+        let mut rows = self.empty_rows(num_rows, data_len);
+        for chunk in chunks {
+            let base = rows.buffer.len();
+            rows.buffer.extend_from_slice(&chunk.buffer);
+            rows.offsets
+                .extend(chunk.offsets[1..].iter().map(|o| base + o));
+        }
+        Ok(rows)
+    }
+
     /// Convert [`ArrayRef`] columns appending to an existing [`Rows`]
     ///
     /// See [`Row`] for information on when [`Row`] can be compared
@@ -600,30 +784,7 @@ impl RowConverter {
             "rows were not produced by this RowConverter"
         );
 
-        if columns.len() != self.fields.len() {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "Incorrect number of arrays provided to RowConverter, expected {} got {}",
-                self.fields.len(),
-                columns.len()
-            )));
-        }
-
-        let encoders = columns
-            .iter()
-            .zip(&self.codecs)
-            .zip(self.fields.iter())
-            .map(|((column, codec), field)| {
-                if !column.data_type().equals_datatype(&field.data_type) {
-                    return Err(ArrowError::InvalidArgumentError(format!(
-                        "RowConverter column schema mismatch, expected {} got {}",
-                        field.data_type,
-                        column.data_type()
-                    )));
-                }
-                codec.encoder(column.as_ref())
-            })
-            .collect::<Result<Vec<_>, _>>()?;
-
+        let encoders = self.encoders(columns)?;
         let write_offset = rows.num_rows();
         let lengths = row_lengths(columns, &encoders);
 
@@ -675,6 +836,60 @@ impl RowConverter {
         Ok(())
     }
 
+    /// Returns the number of bytes [`Self::append`] would add to the data of a [`Rows`]
+    /// when converting `columns`, excluding its offsets
+    ///
+    /// This can be used to budget memory, or to reserve capacity in a [`Rows`] with
+    /// [`Rows::reserve`] or [`Self::empty_rows`], before converting `columns`. Computing
+    /// the size requires converting the values of dictionary and nested columns, and
+    /// so is not significantly cheaper than [`Self::append`] for such columns
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, StringArray};
+    /// # use arrow_row::{RowConverter, SortField};
+    /// # use arrow_schema::DataType;
+    /// #
+    /// let converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap();
+    /// let array: ArrayRef = Arc::new(StringArray::from(vec!["hello", "world"]));
+    ///
+    /// let size = converter.encoded_size(&[array.clone()]).unwrap();
+    /// let mut rows = converter.empty_rows(2, size);
+    /// converter.append(&mut rows, &[array]).unwrap();
+    /// assert_eq!(rows.row(0).as_ref().len() + rows.row(1).as_ref().len(), size);
+    /// ```
+    pub fn encoded_size(&self, columns: &[ArrayRef]) -> Result<usize, ArrowError> {
+        let encoders = self.encoders(columns)?;
+        Ok(row_lengths(columns, &encoders).into_iter().sum())
+    }
+
+    /// Returns the [`Encoder`] for each of `columns`, validating them against the schema
+    fn encoders<'a>(&'a self, columns: &[ArrayRef]) -> Result<Vec<Encoder<'a>>, ArrowError> {
+        if columns.len() != self.fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Incorrect number of arrays provided to RowConverter, expected {} got {}",
+                self.fields.len(),
+                columns.len()
+            )));
+        }
+
+        columns
+            .iter()
+            .zip(&self.codecs)
+            .zip(self.fields.iter())
+            .map(|((column, codec), field)| {
+                if !column.data_type().equals_datatype(&field.data_type) {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "RowConverter column schema mismatch, expected {} got {}",
+                        field.data_type,
+                        column.data_type()
+                    )));
+                }
+                codec.encoder(column.as_ref())
+            })
+            .collect()
+    }
+
     /// Convert [`Rows`] columns into [`ArrayRef`]
     ///
     /// # Panics
@@ -776,6 +991,33 @@ impl RowConverter {
             + self.codecs.capacity() * std::mem::size_of::<Codec>()
             + self.codecs.iter().map(Codec::size).sum::<usize>()
     }
+
+    /// Returns the [`InternerStatistics`] of the dictionary values interned by this
+    /// [`RowConverter`], summed across all columns
+    ///
+    /// Returns the default, empty, statistics unless created with
+    /// [`DictionaryEncoding::Interned`]
+    pub fn interner_statistics(&self) -> InternerStatistics {
+        let mut stats = InternerStatistics::default();
+        for codec in &self.codecs {
+            match codec {
+                Codec::Stateless => {}
+                Codec::InternedDictionary(table, _) => {
+                    let table = table.lock().unwrap_or_else(PoisonError::into_inner);
+                    stats.num_values += table.num_groups();
+                    stats.memory_size += table.size();
+                }
+                Codec::Dictionary(converter, _)
+                | Codec::Struct(converter, _)
+                | Codec::List(converter) => {
+                    let child = converter.interner_statistics();
+                    stats.num_values += child.num_values;
+                    stats.memory_size += child.memory_size;
+                }
+            }
+        }
+        stats
+    }
 }
 
 /// A [`RowParser`] can be created from a [`RowConverter`] and used to parse bytes to [`Row`]
@@ -866,14 +1108,24 @@ impl Rows {
         self.into_iter()
     }
 
-    /// Returns the size of this instance in bytes
+    /// Reserves capacity for at least `additional_rows` more rows, with a total length
+    /// of at least `additional_data` bytes
+    ///
+    /// See [`RowConverter::encoded_size`] to compute the length of the rows of columns
+    pub fn reserve(&mut self, additional_rows: usize, additional_data: usize) {
+        self.offsets.reserve(additional_rows);
+        self.buffer.reserve(additional_data);
+    }
+
+    /// Returns the size of this instance in bytes, including the capacity
+    /// of its buffers that is allocated but not yet used
     ///
     /// Includes the size of `Self`.
     pub fn size(&self) -> usize {
         // Size of fields is accounted for as part of RowConverter
         std::mem::size_of::<Self>()
-            + self.buffer.len()
-            + self.offsets.len() * std::mem::size_of::<usize>()
+            + self.buffer.capacity()
+            + self.offsets.capacity() * std::mem::size_of::<usize>()
     }
 }
 
@@ -1119,6 +1371,9 @@ fn row_lengths(cols: &[ArrayRef], encoders: &[Encoder]) -> Vec<usize> {
                     _ => unreachable!(),
                 }
             }
+            Encoder::InternedDictionary(_) => {
+                lengths.iter_mut().for_each(|x| *x += u32::ENCODED_LEN)
+            }
             Encoder::Struct(rows, null) => {
                 let array = as_struct_array(array);
                 lengths.iter_mut().enumerate().for_each(|(idx, length)| {
@@ -1188,6 +1443,7 @@ fn encode_column(
                 _ => unreachable!()
             }
         }
+        Encoder::InternedDictionary(ids) => fixed::encode(data, offsets, ids.iter().copied(), opts),
         Encoder::Struct(rows, null) => {
             let array = as_struct_array(column);
             let null_sentinel = null_sentinel(opts);
@@ -1274,6 +1530,16 @@ unsafe fn decode_column(
             let cols = converter.convert_raw(rows, validate_utf8)?;
             cols.into_iter().next().unwrap()
         }
+        Codec::InternedDictionary(table, null) => {
+            let ids = decode_primitive::<UInt32Type>(rows, DataType::UInt32, options);
+            let table = lock_interned(table)?;
+            let values = ids.iter().map(|id| match id {
+                Some(id) => table.rows().row(id as usize),
+                None => null.row(),
+            });
+            let cols = table.converter().convert_rows(values)?;
+            cols.into_iter().next().unwrap()
+        }
         Codec::Struct(converter, _) => {
             let (null_count, nulls) = fixed::decode_nulls(rows);
             rows.iter_mut().for_each(|row| *row = &row[1..]);
@@ -2273,4 +2539,174 @@ mod tests {
 
         dictionary_eq(&back[0], &array);
     }
+
+    #[test]
+    fn test_interned_dictionary() {
+        let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let field = SortField::new_with_options(data_type.clone(), options);
+        let converter =
+            RowConverter::new_with_dictionary_encoding(vec![field], DictionaryEncoding::Interned)
+                .unwrap();
+        assert_eq!(converter.interner_statistics().num_values, 0);
+
+        let long = "a value long enough to be worth interning";
+        let a = DictionaryArray::<Int32Type>::from_iter([Some(long), None, Some("b"), Some(long)]);
+        let values = StringArray::from(vec![Some("b"), None, Some(long), Some("c")]);
+        let keys = Int32Array::from(vec![Some(2), Some(1), Some(0), None, Some(3)]);
+        let b = DictionaryArray::new(keys, Arc::new(values));
+        let a = Arc::new(a) as ArrayRef;
+        let b = Arc::new(b) as ArrayRef;
+
+        let rows_a = converter.convert_columns(std::slice::from_ref(&a)).unwrap();
+        let rows_b = converter.convert_columns(std::slice::from_ref(&b)).unwrap();
+        assert!(rows_a.iter().all(|r| r.as_ref().len() == 5));
+
+        // Equal values are encoded identically across batches
+        assert_eq!(rows_a.row(0), rows_a.row(3));
+        assert_eq!(rows_a.row(0), rows_b.row(0));
+        assert_eq!(rows_a.row(2), rows_b.row(2));
+        assert_ne!(rows_a.row(0), rows_a.row(2));
+        // Null keys and null values are both null
+        assert_eq!(rows_a.row(1), rows_b.row(1));
+        assert_eq!(rows_a.row(1), rows_b.row(3));
+
+        let stats = converter.interner_statistics();
+        assert_eq!(stats.num_values, 4);
+        assert!(stats.memory_size > long.len());
+        assert!(converter.size() > stats.memory_size);
+
+        let back = converter.convert_rows(&rows_a).unwrap();
+        dictionary_eq(&back[0], &a);
+        let back = converter.convert_rows(&rows_b).unwrap();
+        dictionary_eq(&back[0], &b);
+
+        // Dictionaries nested within other columns are interned
+        let dict = Arc::new(DictionaryArray::<Int32Type>::from_iter([long, "x"])) as ArrayRef;
+        let child = Arc::new(Field::new("d", data_type, false));
+        let structs = Arc::new(StructArray::from(vec![(child, dict)])) as ArrayRef;
+        let field = SortField::new(structs.data_type().clone());
+        let converter =
+            RowConverter::new_with_dictionary_encoding(vec![field], DictionaryEncoding::Interned)
+                .unwrap();
+        let rows = converter.convert_columns(&[structs]).unwrap();
+        assert_eq!(converter.interner_statistics().num_values, 2);
+        let back = converter.convert_rows(&rows).unwrap();
+        assert_eq!(back[0].len(), 2);
+        let back = back[0].as_struct().column(0).as_string::<i32>();
+        assert_eq!(back, &StringArray::from(vec![long, "x"]));
+    }
+
+    #[test]
+    fn test_interned_dictionary_poisoned() {
+        let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let converter = RowConverter::new_with_dictionary_encoding(
+            vec![SortField::new(data_type)],
+            DictionaryEncoding::Interned,
+        )
+        .unwrap();
+        let a = Arc::new(DictionaryArray::<Int32Type>::from_iter(["a", "b", "a"])) as ArrayRef;
+        let rows = converter.convert_columns(std::slice::from_ref(&a)).unwrap();
+
+        // Panic whilst holding the lock, as a failed conversion on another thread would
+        let table = match &converter.codecs[0] {
+            Codec::InternedDictionary(table, _) => table,
+            _ => unreachable!(),
+        };
+        let r = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _guard = table.lock().unwrap();
+            panic!("conversion failed");
+        }));
+        assert!(r.is_err());
+        assert!(table.is_poisoned());
+
+        let err = converter.convert_columns(&[a]).unwrap_err();
+        assert!(err.to_string().contains("poisoned"), "{err}");
+        let err = converter.convert_rows(&rows).unwrap_err();
+        assert!(err.to_string().contains("poisoned"), "{err}");
+
+        // Statistics only read the table and so remain available
+        assert_eq!(converter.interner_statistics().num_values, 2);
+        assert!(converter.size() > 0);
+    }
+
+    #[test]
+    fn test_encoded_size() {
+        let ints = Int32Array::from_iter((0..100).map(|i| (i % 3 != 0).then_some(i)));
+        let strings = StringArray::from_iter((0..100).map(|i| (i % 5 != 0).then(|| "a".repeat(i))));
+        let dict: DictionaryArray<Int32Type> =
+            (0..100).map(|i| ["a", "bb", "ccc"][i % 3]).collect();
+        let columns: Vec<ArrayRef> = vec![Arc::new(ints), Arc::new(strings), Arc::new(dict)];
+        let converter = RowConverter::new(
+            columns
+                .iter()
+                .map(|c| SortField::new(c.data_type().clone()))
+                .collect(),
+        )
+        .unwrap();
+
+        let size = converter.encoded_size(&columns).unwrap();
+        let mut rows = converter.empty_rows(0, 0);
+        rows.reserve(100, size);
+        let retained = rows.size();
+        assert!(retained >= size + 100 * std::mem::size_of::<usize>());
+
+        converter.append(&mut rows, &columns).unwrap();
+        let data: usize = rows.iter().map(|r| r.as_ref().len()).sum();
+        assert_eq!(data, size);
+        // The reserved capacity is sufficient to append the columns
+        assert_eq!(rows.size(), retained);
+
+        // The capacity is retained after clearing the rows
+        rows.clear();
+        assert_eq!(rows.size(), retained);
+
+        let sliced: Vec<_> = columns.iter().map(|c| c.slice(10, 20)).collect();
+        let size = converter.encoded_size(&sliced).unwrap();
+        converter.append(&mut rows, &sliced).unwrap();
+        assert_eq!(rows.iter().map(|r| r.as_ref().len()).sum::<usize>(), size);
+
+        let err = converter.encoded_size(&columns[..1]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Incorrect number of arrays provided to RowConverter, expected 3 got 1"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_convert_columns_parallel() {
+        let ints = Int32Array::from_iter((0..1000).map(|i| (i % 3 != 0).then_some(i % 17)));
+        let strings =
+            StringArray::from_iter((0..1000).map(|i| (i % 5 != 0).then(|| i.to_string())));
+        let dict: DictionaryArray<Int32Type> = (0..1000).map(|i| ["a", "b", "c"][i % 3]).collect();
+        let columns: Vec<ArrayRef> = vec![Arc::new(ints), Arc::new(strings), Arc::new(dict)];
+        let converter = RowConverter::new(
+            columns
+                .iter()
+                .map(|c| SortField::new(c.data_type().clone()))
+                .collect(),
+        )
+        .unwrap();
+
+        let expected = converter.convert_columns(&columns).unwrap();
+        let actual = converter.convert_columns_parallel(&columns, 64).unwrap();
+        assert_eq!(actual.num_rows(), 1000);
+        assert!(actual.iter().eq(expected.iter()));
+        let back = converter.convert_rows(&actual).unwrap();
+        assert_eq!(back[1].as_ref(), columns[1].as_ref());
+
+        let mut columns = columns;
+        columns[1] = columns[1].slice(0, 999);
+        let err = converter
+            .convert_columns_parallel(&columns, 64)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: RowConverter columns must have the same length, expected 1000 got 999"
+        );
+    }
 }
@@ -0,0 +1,481 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A streaming k-way merge of sorted [`RecordBatchReader`]s
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch, RecordBatchIterator, RecordBatchReader};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_array::types::Int32Type;
+//! # use arrow_row::merge::{SortKey, SortedMergeReader};
+//! fn reader(values: Vec<i32>) -> Box<dyn RecordBatchReader + Send> {
+//!     let batch = RecordBatch::try_from_iter([
+//!         ("v", Arc::new(Int32Array::from(values)) as ArrayRef),
+//!     ]).unwrap();
+//!     let schema = batch.schema();
+//!     Box::new(RecordBatchIterator::new([Ok(batch)], schema))
+//! }
+//!
+//! let readers = vec![reader(vec![1, 4, 7]), reader(vec![2, 3, 9]), reader(vec![5])];
+//! let merged = SortedMergeReader::try_new(readers, vec![SortKey::new(0)]).unwrap();
+//!
+//! let values: Vec<i32> = merged
+//!     .flat_map(|b| b.unwrap().column(0).as_primitive::<Int32Type>().values().to_vec())
+//!     .collect();
+//! assert_eq!(values, vec![1, 2, 3, 4, 5, 7, 9]);
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use arrow_array::{Array, ArrayRef, RecordBatch, RecordBatchReader};
+use arrow_schema::{ArrowError, SchemaRef, SortOptions};
+use arrow_select::interleave::interleave;
+
+use crate::{RowConverter, Rows, SortField};
+
+/// The default number of rows in each [`RecordBatch`] yielded by [`SortedMergeReader`]
+const DEFAULT_BATCH_SIZE: usize = 8192;
+
+/// A column to sort by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    /// The (zero-based) index of the column
+    pub column: usize,
+    /// The sort options of the column
+    pub options: SortOptions,
+}
+
+impl SortKey {
+    /// Create a new [`SortKey`] for the column with the given index, using the default
+    /// [`SortOptions`]
+    pub fn new(column: usize) -> Self {
+        Self::new_with_options(column, SortOptions::default())
+    }
+
+    /// Create a new [`SortKey`] for the column with the given index and [`SortOptions`]
+    pub fn new_with_options(column: usize, options: SortOptions) -> Self {
+        Self { column, options }
+    }
+}
+
+/// The position within the current batch of an input
+struct Cursor {
+    /// The index of the batch in [`SortedMergeReader::batches`]
+    batch: usize,
+    /// The sort keys of the batch in the row format
+    rows: Rows,
+    /// The offset of the next row to yield
+    offset: usize,
+}
+
+/// Merges [`RecordBatchReader`]s, each sorted by the same [`SortKey`]s, into a
+/// single globally sorted stream of [`RecordBatch`]
+///
+/// The sort keys are converted to the [row format](crate) to compare rows across
+/// inputs, and the output batches assembled with [`interleave`]. Rows with equal
+/// keys are yielded in the order of their inputs, making the merge stable.
+///
+/// This is useful for external sorts, where sorted runs are spilled and merged, or
+/// for compacting sorted files.
+pub struct SortedMergeReader {
+    schema: SchemaRef,
+    /// The columns to sort by
+    columns: Vec<usize>,
+    converter: RowConverter,
+    batch_size: usize,
+    inputs: Vec<Box<dyn RecordBatchReader + Send>>,
+    /// The current position of each input, `None` once exhausted
+    cursors: Vec<Option<Cursor>>,
+    /// The batches referenced by the cursors or by pending output rows
+    batches: Vec<RecordBatch>,
+    /// A binary min-heap of the indices of the inputs with remaining rows
+    heap: Vec<usize>,
+    initialized: bool,
+}
+
+impl fmt::Debug for SortedMergeReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedMergeReader")
+            .field("schema", &self.schema)
+            .field("columns", &self.columns)
+            .field("converter", &self.converter)
+            .field("batch_size", &self.batch_size)
+            .field("inputs", &self.inputs.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SortedMergeReader {
+    /// Create a new [`SortedMergeReader`] merging `inputs` by `keys`
+    ///
+    /// Returns an error if there are no inputs, if the inputs do not share the same
+    /// schema or if the key columns cannot be converted to the row format.
+    ///
+    /// The inputs must each be sorted by `keys`, otherwise the output is not sorted
+    pub fn try_new(
+        inputs: Vec<Box<dyn RecordBatchReader + Send>>,
+        keys: Vec<SortKey>,
+    ) -> Result<Self, ArrowError> {
+        let schema = match inputs.first() {
+            Some(input) => input.schema(),
+            None => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "SortedMergeReader requires at least one input".to_string(),
+                ))
+            }
+        };
+        if let Some(input) = inputs
+            .iter()
+            .find(|i| i.schema().fields() != schema.fields())
+        {
+            return Err(ArrowError::SchemaError(format!(
+                "Cannot merge inputs with different schemas, expected {schema:?} got {:?}",
+                input.schema()
+            )));
+        }
+
+        let fields = keys
+            .iter()
+            .map(|key| {
+                let field = schema.fields().get(key.column).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Sort key column {} out of bounds for schema with {} fields",
+                        key.column,
+                        schema.fields().len()
+                    ))
+                })?;
+                Ok(SortField::new_with_options(
+                    field.data_type().clone(),
+                    key.options,
+                ))
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+
+        let cursors = inputs.iter().map(|_| None).collect();
+        Ok(Self {
+            schema,
+            columns: keys.iter().map(|k| k.column).collect(),
+            converter: RowConverter::new(fields)?,
+            batch_size: DEFAULT_BATCH_SIZE,
+            inputs,
+            cursors,
+            batches: vec![],
+            heap: vec![],
+            initialized: false,
+        })
+    }
+
+    /// Set the maximum number of rows in each yielded [`RecordBatch`], defaults to `8192`
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Advance the input `idx` to its next non-empty batch, setting its cursor to
+    /// `None` if it is exhausted
+    fn advance_input(&mut self, idx: usize) -> Result<(), ArrowError> {
+        self.cursors[idx] = None;
+        while let Some(batch) = self.inputs[idx].next().transpose()? {
+            if batch.num_rows() == 0 {
+                continue;
+            }
+            let keys: Vec<ArrayRef> = self
+                .columns
+                .iter()
+                .map(|c| batch.column(*c).clone())
+                .collect();
+            let rows = self.converter.convert_columns(&keys)?;
+            self.cursors[idx] = Some(Cursor {
+                batch: self.batches.len(),
+                rows,
+                offset: 0,
+            });
+            self.batches.push(batch);
+            break;
+        }
+        Ok(())
+    }
+
+    fn initialize(&mut self) -> Result<(), ArrowError> {
+        for idx in 0..self.inputs.len() {
+            self.advance_input(idx)?;
+        }
+        let mut heap: Vec<usize> = (0..self.inputs.len())
+            .filter(|i| self.cursors[*i].is_some())
+            .collect();
+        // A sorted slice is a valid binary heap
+        heap.sort_unstable_by(|a, b| compare(&self.cursors, *a, *b));
+        self.heap = heap;
+        self.initialized = true;
+        Ok(())
+    }
+
+    fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        if !self.initialized {
+            self.initialize()?;
+        }
+
+        let mut indices = Vec::with_capacity(self.batch_size);
+        while indices.len() < self.batch_size {
+            let Some(&idx) = self.heap.first() else {
+                break;
+            };
+            let cursor = self.cursors[idx].as_mut().unwrap();
+            indices.push((cursor.batch, cursor.offset));
+            cursor.offset += 1;
+
+            if cursor.offset == cursor.rows.num_rows() {
+                self.advance_input(idx)?;
+                if self.cursors[idx].is_none() {
+                    let last = self.heap.pop().unwrap();
+                    if self.heap.is_empty() {
+                        break;
+                    }
+                    self.heap[0] = last;
+                }
+            }
+            sift_down(&mut self.heap, &self.cursors);
+        }
+
+        if indices.is_empty() {
+            return Ok(None);
+        }
+
+        let columns = (0..self.schema.fields().len())
+            .map(|c| {
+                let arrays: Vec<&dyn Array> =
+                    self.batches.iter().map(|b| b.column(c).as_ref()).collect();
+                interleave(&arrays, &indices)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let batch = RecordBatch::try_new(self.schema.clone(), columns)?;
+
+        self.release_batches();
+        Ok(Some(batch))
+    }
+
+    /// Drop the batches no longer referenced by any cursor
+    fn release_batches(&mut self) {
+        let mut retained = Vec::with_capacity(self.cursors.len());
+        for cursor in self.cursors.iter_mut().flatten() {
+            retained.push(self.batches[cursor.batch].clone());
+            cursor.batch = retained.len() - 1;
+        }
+        self.batches = retained;
+    }
+}
+
+/// Compare the current rows of the inputs `a` and `b`, breaking ties by input index
+fn compare(cursors: &[Option<Cursor>], a: usize, b: usize) -> Ordering {
+    let row = |idx: usize| {
+        let cursor = cursors[idx].as_ref().unwrap();
+        cursor.rows.row(cursor.offset)
+    };
+    row(a).cmp(&row(b)).then(a.cmp(&b))
+}
+
+/// Restore the heap property after the root of `heap` has changed
+fn sift_down(heap: &mut [usize], cursors: &[Option<Cursor>]) {
+    let mut pos = 0;
+    loop {
+        let left = 2 * pos + 1;
+        let right = left + 1;
+        let mut smallest = pos;
+        if left < heap.len() && compare(cursors, heap[left], heap[smallest]).is_lt() {
+            smallest = left;
+        }
+        if right < heap.len() && compare(cursors, heap[right], heap[smallest]).is_lt() {
+            smallest = right;
+        }
+        if smallest == pos {
+            return;
+        }
+        heap.swap(pos, smallest);
+        pos = smallest;
+    }
+}
+
+impl Iterator for SortedMergeReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.maybe_next().transpose()
+    }
+}
+
+impl RecordBatchReader for SortedMergeReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, RecordBatchIterator, StringArray};
+    use arrow_ord::sort::{lexsort_to_indices, SortColumn};
+    use arrow_schema::{DataType, Field, Schema};
+    use arrow_select::concat::concat_batches;
+    use arrow_select::take::take_record_batch;
+    use rand::{Rng, SeedableRng};
+    use std::sync::Arc;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("k1", DataType::Int32, true),
+            Field::new("k2", DataType::Utf8, true),
+            Field::new("input", DataType::Int32, false),
+        ]))
+    }
+
+    fn sort(batch: &RecordBatch, keys: &[SortKey]) -> RecordBatch {
+        let columns: Vec<_> = keys
+            .iter()
+            .map(|k| SortColumn {
+                values: batch.column(k.column).clone(),
+                options: Some(k.options),
+            })
+            .collect();
+        let indices = lexsort_to_indices(&columns, None).unwrap();
+        take_record_batch(batch, &indices).unwrap()
+    }
+
+    /// Split `batch` into a reader of randomly sized batches, including empty batches
+    fn reader(batch: RecordBatch, rng: &mut impl Rng) -> Box<dyn RecordBatchReader + Send> {
+        let mut batches = vec![];
+        let mut offset = 0;
+        while offset < batch.num_rows() {
+            let len = rng.gen_range(0..=(batch.num_rows() - offset).min(20));
+            batches.push(Ok(batch.slice(offset, len)));
+            offset += len;
+        }
+        Box::new(RecordBatchIterator::new(batches, batch.schema()))
+    }
+
+    #[test]
+    fn test_merge_fuzz() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let keys = vec![SortKey::new(0), SortKey::new_with_options(1, options)];
+
+        for _ in 0..20 {
+            let num_inputs = rng.gen_range(1..6);
+            let inputs: Vec<_> = (0..num_inputs)
+                .map(|input| {
+                    let len = rng.gen_range(0..100);
+                    let k1: Int32Array = (0..len)
+                        .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..10)))
+                        .collect();
+                    let k2: StringArray = (0..len)
+                        .map(|_| {
+                            rng.gen_bool(0.9)
+                                .then(|| ["a", "b", "c"][rng.gen_range(0..3)])
+                        })
+                        .collect();
+                    let batch = RecordBatch::try_new(
+                        schema(),
+                        vec![
+                            Arc::new(k1),
+                            Arc::new(k2),
+                            Arc::new(Int32Array::from(vec![input; len])),
+                        ],
+                    )
+                    .unwrap();
+                    sort(&batch, &keys)
+                })
+                .collect();
+
+            let all = concat_batches(&schema(), &inputs).unwrap();
+            let readers = inputs.into_iter().map(|b| reader(b, &mut rng)).collect();
+            let batch_size = rng.gen_range(1..50);
+            let merged = SortedMergeReader::try_new(readers, keys.clone())
+                .unwrap()
+                .with_batch_size(batch_size);
+            let batches = merged.collect::<Result<Vec<_>, _>>().unwrap();
+            assert!(batches.iter().all(|b| b.num_rows() <= batch_size));
+            let merged = concat_batches(&schema(), &batches).unwrap();
+
+            // lexsort is not stable, so sort by the input as a final tie-breaker
+            let mut expected_keys = keys.clone();
+            expected_keys.push(SortKey::new(2));
+            let expected = sort(&all, &expected_keys);
+            assert_eq!(merged.num_rows(), expected.num_rows());
+            for c in 0..3 {
+                assert_eq!(merged.column(c), expected.column(c));
+            }
+        }
+    }
+
+    #[test]
+    fn test_merge_stable() {
+        let batch = |input: i32| {
+            RecordBatch::try_new(
+                schema(),
+                vec![
+                    Arc::new(Int32Array::from(vec![1, 1, 2])),
+                    Arc::new(StringArray::from(vec!["a", "a", "a"])),
+                    Arc::new(Int32Array::from(vec![input; 3])),
+                ],
+            )
+            .unwrap()
+        };
+        let readers: Vec<Box<dyn RecordBatchReader + Send>> = (0..3)
+            .map(|i| Box::new(RecordBatchIterator::new([Ok(batch(i))], schema())) as _)
+            .collect();
+        let merged = SortedMergeReader::try_new(readers, vec![SortKey::new(0)])
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        let inputs = merged.column(2).as_primitive::<Int32Type>();
+        assert_eq!(inputs.values(), &[0, 0, 1, 1, 2, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_merge_errors() {
+        let err = SortedMergeReader::try_new(vec![], vec![SortKey::new(0)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: SortedMergeReader requires at least one input"
+        );
+
+        let other = Arc::new(Schema::new(vec![Field::new("k1", DataType::Int64, true)]));
+        let readers: Vec<Box<dyn RecordBatchReader + Send>> = vec![
+            Box::new(RecordBatchIterator::new([], schema())),
+            Box::new(RecordBatchIterator::new([], other)),
+        ];
+        let err = SortedMergeReader::try_new(readers, vec![SortKey::new(0)]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Cannot merge inputs with different schemas"));
+
+        let readers: Vec<Box<dyn RecordBatchReader + Send>> =
+            vec![Box::new(RecordBatchIterator::new([], schema()))];
+        let err = SortedMergeReader::try_new(readers, vec![SortKey::new(3)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Sort key column 3 out of bounds for schema with 3 fields"
+        );
+    }
+}
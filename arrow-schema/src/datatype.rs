@@ -15,8 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::fmt;
-use std::sync::Arc;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::fmt;
 
 use crate::{Field, FieldRef, Fields, UnionFields};
 
@@ -531,7 +532,7 @@ impl DataType {
     ///
     /// Includes the size of `Self`.
     pub fn size(&self) -> usize {
-        std::mem::size_of_val(self)
+        core::mem::size_of_val(self)
             + match self {
                 DataType::Null
                 | DataType::Boolean
@@ -568,8 +569,8 @@ impl DataType {
                 DataType::Union(fields, _) => fields.size(),
                 DataType::Dictionary(dt1, dt2) => dt1.size() + dt2.size(),
                 DataType::RunEndEncoded(run_ends, values) => {
-                    run_ends.size() - std::mem::size_of_val(run_ends) + values.size()
-                        - std::mem::size_of_val(values)
+                    run_ends.size() - core::mem::size_of_val(run_ends) + values.size()
+                        - core::mem::size_of_val(values)
                 }
             }
     }
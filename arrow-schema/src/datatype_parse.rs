@@ -0,0 +1,474 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parsing of a compact textual representation of [`DataType`] and [`Schema`]
+//!
+//! The syntax consists of a type name optionally followed by a list of
+//! parameters enclosed in angle brackets, for example
+//!
+//! ```text
+//! Int64
+//! Timestamp<ns, "+00:00">
+//! Decimal128<38, 10>
+//! List<Struct<a: Int64, "b c": Utf8 not null>>
+//! Dictionary<Int32, Utf8>
+//! ```
+//!
+//! Fields of a `Struct`, and of a [`Schema`], are written as `name: Type`,
+//! optionally followed by `not null`. Names containing characters other than
+//! alphanumerics and `_` must be enclosed in double quotes.
+//!
+//! The JSON representation used by the Arrow integration tests can be parsed
+//! with the `arrow-integration-test` crate.
+
+use crate::{ArrowError, DataType, Field, Fields, IntervalUnit, Schema, TimeUnit};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
+use core::str::FromStr;
+
+/// Parses a [`DataType`] from its compact textual representation
+///
+/// ```
+/// # use arrow_schema::{DataType, Field, TimeUnit};
+/// # use std::sync::Arc;
+/// let data_type: DataType = "List<Timestamp<ms, \"UTC\">>".parse().unwrap();
+/// let item = Field::new("item", DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())), true);
+/// assert_eq!(data_type, DataType::List(Arc::new(item)));
+/// ```
+impl FromStr for DataType {
+    type Err = ArrowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let data_type = parser.parse_data_type()?;
+        parser.expect_end()?;
+        Ok(data_type)
+    }
+}
+
+/// Parses a [`Schema`] from a comma separated list of fields using the
+/// compact textual representation of [`DataType`]
+///
+/// ```
+/// # use arrow_schema::{DataType, Field, Schema};
+/// let schema: Schema = "id: Int64 not null, name: Utf8".parse().unwrap();
+/// assert_eq!(schema, Schema::new(vec![
+///     Field::new("id", DataType::Int64, false),
+///     Field::new("name", DataType::Utf8, true),
+/// ]));
+/// ```
+impl FromStr for Schema {
+    type Err = ArrowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let mut fields = vec![];
+        if !parser.is_end() {
+            loop {
+                fields.push(parser.parse_field()?);
+                if !parser.consume(',') {
+                    break;
+                }
+            }
+        }
+        parser.expect_end()?;
+        Ok(Schema::new(fields))
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn error(&self, msg: impl core::fmt::Display) -> ArrowError {
+        ArrowError::ParseError(format!(
+            "Error parsing data type \"{}\" at position {}: {msg}",
+            self.input, self.pos
+        ))
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remaining = &self.input[self.pos..];
+        self.pos += remaining.len() - remaining.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.pos..].chars().next()
+    }
+
+    fn is_end(&mut self) -> bool {
+        self.peek().is_none()
+    }
+
+    fn expect_end(&mut self) -> Result<(), ArrowError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(c) => Err(self.error(format!("unexpected trailing '{c}'"))),
+        }
+    }
+
+    /// Consumes `c` if it is the next non-whitespace character
+    fn consume(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        if found {
+            self.pos += c.len_utf8();
+        }
+        found
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ArrowError> {
+        match self.consume(c) {
+            true => Ok(()),
+            false => Err(self.error(format!("expected '{c}'"))),
+        }
+    }
+
+    /// Parses an identifier consisting of alphanumerics, `_`, `+` and `-`
+    fn parse_word(&mut self) -> Result<&'a str, ArrowError> {
+        self.skip_whitespace();
+        let remaining = &self.input[self.pos..];
+        let len = remaining
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '+' | '-')))
+            .unwrap_or(remaining.len());
+        if len == 0 {
+            return Err(self.error("expected identifier"));
+        }
+        self.pos += len;
+        Ok(&remaining[..len])
+    }
+
+    /// Parses a double quoted string, with `\"` and `\\` escapes
+    fn parse_quoted(&mut self) -> Result<String, ArrowError> {
+        self.expect('"')?;
+        let mut out = String::new();
+        let mut chars = self.input[self.pos..].char_indices();
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos += idx + 1;
+                    return Ok(out);
+                }
+                '\\' => match chars.next() {
+                    Some((_, c)) => out.push(c),
+                    None => break,
+                },
+                c => out.push(c),
+            }
+        }
+        self.pos = self.input.len();
+        Err(self.error("unterminated string"))
+    }
+
+    /// Parses a field name, either an identifier or a quoted string
+    fn parse_name(&mut self) -> Result<String, ArrowError> {
+        match self.peek() {
+            Some('"') => self.parse_quoted(),
+            _ => self.parse_word().map(ToString::to_string),
+        }
+    }
+
+    fn parse_integer<T: FromStr>(&mut self) -> Result<T, ArrowError> {
+        let word = self.parse_word()?;
+        word.parse()
+            .map_err(|_| self.error(format!("expected integer got \"{word}\"")))
+    }
+
+    /// Parses `name: DataType [not null]`
+    fn parse_field(&mut self) -> Result<Field, ArrowError> {
+        let name = self.parse_name()?;
+        self.expect(':')?;
+        let data_type = self.parse_data_type()?;
+        let nullable = !self.parse_not_null()?;
+        Ok(Field::new(name, data_type, nullable))
+    }
+
+    /// Parses an optional `not null` suffix, returning true if present
+    fn parse_not_null(&mut self) -> Result<bool, ArrowError> {
+        let start = self.pos;
+        if !matches!(self.peek(), Some('n' | 'N')) {
+            return Ok(false);
+        }
+        match self.parse_word() {
+            Ok(w) if w.eq_ignore_ascii_case("not") => {}
+            _ => {
+                self.pos = start;
+                return Ok(false);
+            }
+        }
+        match self.parse_word() {
+            Ok(w) if w.eq_ignore_ascii_case("null") => Ok(true),
+            _ => Err(self.error("expected \"null\" after \"not\"")),
+        }
+    }
+
+    /// Parses a list item, which is either a bare data type or a field
+    fn parse_item(&mut self) -> Result<Field, ArrowError> {
+        let start = self.pos;
+        if self.peek() == Some('"') || (self.parse_word().is_ok() && self.peek() == Some(':')) {
+            self.pos = start;
+            return self.parse_field();
+        }
+        self.pos = start;
+        let data_type = self.parse_data_type()?;
+        let nullable = !self.parse_not_null()?;
+        Ok(Field::new("item", data_type, nullable))
+    }
+
+    fn parse_time_unit(&mut self) -> Result<TimeUnit, ArrowError> {
+        let word = self.parse_word()?;
+        Ok(match word {
+            "s" | "Second" => TimeUnit::Second,
+            "ms" | "Millisecond" => TimeUnit::Millisecond,
+            "us" | "Microsecond" => TimeUnit::Microsecond,
+            "ns" | "Nanosecond" => TimeUnit::Nanosecond,
+            _ => return Err(self.error(format!("unknown time unit \"{word}\""))),
+        })
+    }
+
+    fn parse_data_type(&mut self) -> Result<DataType, ArrowError> {
+        let start = self.pos;
+        let name = self.parse_word()?;
+        let has_params = self.consume('<');
+
+        let data_type = match (name, has_params) {
+            ("Null", false) => DataType::Null,
+            ("Boolean", false) => DataType::Boolean,
+            ("Int8", false) => DataType::Int8,
+            ("Int16", false) => DataType::Int16,
+            ("Int32", false) => DataType::Int32,
+            ("Int64", false) => DataType::Int64,
+            ("UInt8", false) => DataType::UInt8,
+            ("UInt16", false) => DataType::UInt16,
+            ("UInt32", false) => DataType::UInt32,
+            ("UInt64", false) => DataType::UInt64,
+            ("Float16", false) => DataType::Float16,
+            ("Float32", false) => DataType::Float32,
+            ("Float64", false) => DataType::Float64,
+            ("Utf8", false) => DataType::Utf8,
+            ("LargeUtf8", false) => DataType::LargeUtf8,
+            ("Binary", false) => DataType::Binary,
+            ("LargeBinary", false) => DataType::LargeBinary,
+            ("Date32", false) => DataType::Date32,
+            ("Date64", false) => DataType::Date64,
+            ("Timestamp", true) => {
+                let unit = self.parse_time_unit()?;
+                let tz = match self.consume(',') {
+                    true => Some(self.parse_quoted()?.into()),
+                    false => None,
+                };
+                DataType::Timestamp(unit, tz)
+            }
+            ("Time32", true) => DataType::Time32(self.parse_time_unit()?),
+            ("Time64", true) => DataType::Time64(self.parse_time_unit()?),
+            ("Duration", true) => DataType::Duration(self.parse_time_unit()?),
+            ("Interval", true) => {
+                let word = self.parse_word()?;
+                DataType::Interval(match word {
+                    "YearMonth" => IntervalUnit::YearMonth,
+                    "DayTime" => IntervalUnit::DayTime,
+                    "MonthDayNano" => IntervalUnit::MonthDayNano,
+                    _ => return Err(self.error(format!("unknown interval unit \"{word}\""))),
+                })
+            }
+            ("Decimal128" | "Decimal256", true) => {
+                let precision = self.parse_integer()?;
+                self.expect(',')?;
+                let scale = self.parse_integer()?;
+                match name {
+                    "Decimal128" => DataType::Decimal128(precision, scale),
+                    _ => DataType::Decimal256(precision, scale),
+                }
+            }
+            ("FixedSizeBinary", true) => DataType::FixedSizeBinary(self.parse_integer()?),
+            ("List", true) => DataType::List(Arc::new(self.parse_item()?)),
+            ("LargeList", true) => DataType::LargeList(Arc::new(self.parse_item()?)),
+            ("FixedSizeList", true) => {
+                let item = self.parse_item()?;
+                self.expect(',')?;
+                DataType::FixedSizeList(Arc::new(item), self.parse_integer()?)
+            }
+            ("Struct", true) => {
+                let mut fields = vec![];
+                if self.peek() != Some('>') {
+                    loop {
+                        fields.push(self.parse_field()?);
+                        if !self.consume(',') {
+                            break;
+                        }
+                    }
+                }
+                DataType::Struct(Fields::from(fields))
+            }
+            ("Map", true) => {
+                let key = self.parse_data_type()?;
+                self.expect(',')?;
+                let value = self.parse_data_type()?;
+                let nullable = !self.parse_not_null()?;
+                let entries = Fields::from(vec![
+                    Field::new("keys", key, false),
+                    Field::new("values", value, nullable),
+                ]);
+                let entries = Field::new("entries", DataType::Struct(entries), false);
+                DataType::Map(Arc::new(entries), false)
+            }
+            ("Dictionary", true) => {
+                let key = self.parse_data_type()?;
+                self.expect(',')?;
+                let value = self.parse_data_type()?;
+                DataType::Dictionary(Box::new(key), Box::new(value))
+            }
+            ("RunEndEncoded", true) => {
+                let run_ends = self.parse_data_type()?;
+                self.expect(',')?;
+                let values = self.parse_data_type()?;
+                DataType::RunEndEncoded(
+                    Arc::new(Field::new("run_ends", run_ends, false)),
+                    Arc::new(Field::new("values", values, true)),
+                )
+            }
+            _ => {
+                self.pos = start;
+                let suffix = if has_params { "<...>" } else { "" };
+                return Err(self.error(format!("unsupported data type \"{name}{suffix}\"")));
+            }
+        };
+
+        if has_params {
+            self.expect('>')?;
+        }
+        Ok(data_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> DataType {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_primitive() {
+        assert_eq!(parse("Int64"), DataType::Int64);
+        assert_eq!(parse("  Utf8 "), DataType::Utf8);
+        assert_eq!(parse("Decimal128<38, 10>"), DataType::Decimal128(38, 10));
+        assert_eq!(parse("Decimal256<76,-2>"), DataType::Decimal256(76, -2));
+        assert_eq!(parse("FixedSizeBinary<16>"), DataType::FixedSizeBinary(16));
+        assert_eq!(
+            parse("Timestamp<Nanosecond>"),
+            DataType::Timestamp(TimeUnit::Nanosecond, None)
+        );
+        assert_eq!(
+            parse("Timestamp<us, \"+01:00\">"),
+            DataType::Timestamp(TimeUnit::Microsecond, Some("+01:00".into()))
+        );
+        assert_eq!(parse("Time32<s>"), DataType::Time32(TimeUnit::Second));
+        assert_eq!(
+            parse("Duration<ms>"),
+            DataType::Duration(TimeUnit::Millisecond)
+        );
+        assert_eq!(
+            parse("Interval<MonthDayNano>"),
+            DataType::Interval(IntervalUnit::MonthDayNano)
+        );
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        let s = Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b c", DataType::Utf8, false),
+        ]);
+        assert_eq!(
+            parse("List<Struct<a: Int64, \"b c\": Utf8 not null>>"),
+            DataType::List(Arc::new(Field::new("item", DataType::Struct(s), true)))
+        );
+        assert_eq!(
+            parse("LargeList<element: Int32 NOT NULL>"),
+            DataType::LargeList(Arc::new(Field::new("element", DataType::Int32, false)))
+        );
+        assert_eq!(
+            parse("FixedSizeList<Float32 not null, 3>"),
+            DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, false)), 3)
+        );
+        assert_eq!(parse("Struct<>"), DataType::Struct(Fields::empty()));
+        assert_eq!(
+            parse("Dictionary<Int32, Utf8>"),
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+        let map = parse("Map<Utf8, List<Int8>>");
+        let DataType::Map(entries, false) = &map else {
+            panic!("{map}")
+        };
+        let DataType::Struct(fields) = entries.data_type() else {
+            panic!("{map}")
+        };
+        assert_eq!(fields[0].data_type(), &DataType::Utf8);
+        assert!(!fields[0].is_nullable());
+        assert!(fields[1].is_nullable());
+        assert_eq!(
+            parse("RunEndEncoded<Int32, Utf8>"),
+            DataType::RunEndEncoded(
+                Arc::new(Field::new("run_ends", DataType::Int32, false)),
+                Arc::new(Field::new("values", DataType::Utf8, true)),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_schema() {
+        let schema: Schema = "a: Int32 not null, \"b\\\"\": List<Utf8>".parse().unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b\"", DataType::new_list(DataType::Utf8, true), true),
+        ]);
+        assert_eq!(schema, expected);
+
+        let schema: Schema = "".parse().unwrap();
+        assert_eq!(schema, Schema::empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("", "expected identifier"),
+            ("Int65", "unsupported data type \"Int65\""),
+            ("Int64<8>", "unsupported data type \"Int64<...>\""),
+            ("List<Int64", "expected '>'"),
+            ("Timestamp<minutes>", "unknown time unit \"minutes\""),
+            ("Decimal128<a, 2>", "expected integer got \"a\""),
+            ("Int64 Int64", "unexpected trailing 'I'"),
+            ("Struct<a: Int64 not>", "expected \"null\" after \"not\""),
+            ("Timestamp<s, \"UTC>", "unterminated string"),
+        ];
+        for (input, expected) in cases {
+            let err = input.parse::<DataType>().unwrap_err().to_string();
+            assert!(err.contains(expected), "{input}: {err}");
+        }
+    }
+}
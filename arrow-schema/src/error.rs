@@ -16,16 +16,29 @@
 // under the License.
 
 //! Defines `ArrowError` for representing failures in various Arrow operations.
-use std::fmt::{Debug, Display, Formatter};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "std")]
 use std::io::Write;
 
+#[cfg(feature = "std")]
 use std::error::Error;
 
 /// Many different operations in the `arrow` crate return this error type.
+///
+/// New variants may be added in future releases, and so matching on this type
+/// requires a wildcard arm. Errors may be wrapped in [`ArrowError::Context`],
+/// use [`ArrowError::root_cause`] to match on the kind of error regardless
+///
+/// The variants wrapping `std` errors require the `std` feature
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ArrowError {
     /// Returned when functionality is not yet available.
     NotYetImplemented(String),
+    #[cfg(feature = "std")]
     ExternalError(Box<dyn Error + Send + Sync>),
     CastError(String),
     MemoryError(String),
@@ -35,6 +48,7 @@ pub enum ArrowError {
     DivideByZero,
     CsvError(String),
     JsonError(String),
+    #[cfg(feature = "std")]
     IoError(String, std::io::Error),
     IpcError(String),
     InvalidArgumentError(String),
@@ -43,33 +57,152 @@ pub enum ArrowError {
     CDataInterface(String),
     DictionaryKeyOverflowError,
     RunEndIndexOverflowError,
+    /// An error with additional context describing where it occurred, such as the
+    /// column or file offset being read, see [`ArrowError::with_context`]
+    Context(ErrorContext, Box<ArrowError>),
+    /// Returned when the input ends part way through its data, such as an Arrow IPC
+    /// file truncated by a crash during writing
+    Truncated(String),
 }
 
 impl ArrowError {
     /// Wraps an external error in an `ArrowError`.
+    #[cfg(feature = "std")]
     pub fn from_external_error(error: Box<dyn Error + Send + Sync>) -> Self {
         Self::ExternalError(error)
     }
+
+    /// Wraps this error with `context` describing where it occurred, such as the
+    /// column or file offset being read
+    ///
+    /// The wrapped error is returned by [`std::error::Error::source`], and [`Self::root_cause`]
+    /// returns the innermost error, skipping any context
+    ///
+    /// ```
+    /// # use arrow_schema::{ArrowError, ErrorContext};
+    /// let err = ArrowError::ParseError("invalid digit".to_string())
+    ///     .with_context(ErrorContext::new("reading").with_column_path(["a", "b"]))
+    ///     .with_context(ErrorContext::new("reading record batch").with_offset(128));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "reading record batch at offset 128: reading column \"a.b\": Parser error: invalid digit"
+    /// );
+    /// assert!(matches!(err.root_cause(), ArrowError::ParseError(_)));
+    ///
+    /// let offsets: Vec<_> = err.contexts().filter_map(|c| c.offset).collect();
+    /// assert_eq!(offsets, [128]);
+    /// ```
+    pub fn with_context(self, context: impl Into<ErrorContext>) -> Self {
+        Self::Context(context.into(), Box::new(self))
+    }
+
+    /// Returns the innermost error, skipping any context added by [`Self::with_context`]
+    ///
+    /// This allows matching on the kind of error, regardless of where it occurred
+    pub fn root_cause(&self) -> &ArrowError {
+        match self {
+            Self::Context(_, source) => source.root_cause(),
+            e => e,
+        }
+    }
+
+    /// Returns the context added by [`Self::with_context`], from the outermost to
+    /// the innermost
+    pub fn contexts(&self) -> impl Iterator<Item = &ErrorContext> {
+        let mut next = Some(self);
+        core::iter::from_fn(move || match next? {
+            Self::Context(context, source) => {
+                next = Some(source);
+                Some(context)
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Describes where an [`ArrowError`] occurred, see [`ArrowError::with_context`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ErrorContext {
+    /// A description of the operation being performed, such as `"reading"`
+    pub description: String,
+    /// The byte offset within the file or stream being read, if any
+    pub offset: Option<u64>,
+    /// The path of the column, from its top-level field, if any
+    pub column_path: Vec<String>,
+}
+
+impl ErrorContext {
+    /// Create a new [`ErrorContext`] with the given description
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the byte offset within the file or stream being read
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the path of the column, from its top-level field
+    pub fn with_column_path<I, S>(mut self, path: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.column_path = path.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl From<String> for ErrorContext {
+    fn from(description: String) -> Self {
+        Self::new(description)
+    }
+}
+
+impl From<&str> for ErrorContext {
+    fn from(description: &str) -> Self {
+        Self::new(description)
+    }
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.description)?;
+        if !self.column_path.is_empty() {
+            write!(f, " column {:?}", self.column_path.join("."))?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " at offset {offset}")?;
+        }
+        Ok(())
+    }
 }
 
+#[cfg(feature = "std")]
 impl From<std::io::Error> for ArrowError {
     fn from(error: std::io::Error) -> Self {
         ArrowError::IoError(error.to_string(), error)
     }
 }
 
-impl From<std::str::Utf8Error> for ArrowError {
-    fn from(error: std::str::Utf8Error) -> Self {
+impl From<core::str::Utf8Error> for ArrowError {
+    fn from(error: core::str::Utf8Error) -> Self {
         ArrowError::ParseError(error.to_string())
     }
 }
 
-impl From<std::string::FromUtf8Error> for ArrowError {
-    fn from(error: std::string::FromUtf8Error) -> Self {
+impl From<alloc::string::FromUtf8Error> for ArrowError {
+    fn from(error: alloc::string::FromUtf8Error) -> Self {
         ArrowError::ParseError(error.to_string())
     }
 }
 
+#[cfg(feature = "std")]
 impl<W: Write> From<std::io::IntoInnerError<W>> for ArrowError {
     fn from(error: std::io::IntoInnerError<W>) -> Self {
         ArrowError::IoError(error.to_string(), error.into())
@@ -77,11 +210,12 @@ impl<W: Write> From<std::io::IntoInnerError<W>> for ArrowError {
 }
 
 impl Display for ArrowError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             ArrowError::NotYetImplemented(source) => {
                 write!(f, "Not yet implemented: {}", &source)
             }
+            #[cfg(feature = "std")]
             ArrowError::ExternalError(source) => write!(f, "External error: {}", &source),
             ArrowError::CastError(desc) => write!(f, "Cast error: {desc}"),
             ArrowError::MemoryError(desc) => write!(f, "Memory error: {desc}"),
@@ -91,8 +225,10 @@ impl Display for ArrowError {
             ArrowError::DivideByZero => write!(f, "Divide by zero error"),
             ArrowError::CsvError(desc) => write!(f, "Csv error: {desc}"),
             ArrowError::JsonError(desc) => write!(f, "Json error: {desc}"),
+            #[cfg(feature = "std")]
             ArrowError::IoError(desc, _) => write!(f, "Io error: {desc}"),
             ArrowError::IpcError(desc) => write!(f, "Ipc error: {desc}"),
+            ArrowError::Truncated(desc) => write!(f, "Truncated input: {desc}"),
             ArrowError::InvalidArgumentError(desc) => {
                 write!(f, "Invalid argument error: {desc}")
             }
@@ -108,21 +244,25 @@ impl Display for ArrowError {
             ArrowError::RunEndIndexOverflowError => {
                 write!(f, "Run end encoded array index overflow error")
             }
+            ArrowError::Context(context, source) => write!(f, "{context}: {source}"),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for ArrowError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        if let Self::ExternalError(e) = self {
-            Some(e.as_ref())
-        } else {
-            None
+        match self {
+            Self::ExternalError(e) => Some(e.as_ref()),
+            Self::IoError(_, e) => Some(e),
+            Self::Context(_, e) => Some(e.as_ref()),
+            _ => None,
         }
     }
 }
 
 #[cfg(test)]
+#[cfg(feature = "std")]
 mod test {
     use super::*;
 
@@ -150,4 +290,43 @@ mod test {
 
         assert!(matches!(source, ArrowError::DivideByZero));
     }
+
+    #[test]
+    fn error_context() {
+        let io = std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "eof");
+        let e1 = ArrowError::from(io);
+        let source = e1
+            .source()
+            .unwrap()
+            .downcast_ref::<std::io::Error>()
+            .unwrap();
+        assert_eq!(source.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let column = ErrorContext::new("reading").with_column_path(["a", "b"]);
+        let e2 = e1.with_context(column.clone()).with_context("batch 2");
+        assert_eq!(
+            e2.to_string(),
+            "batch 2: reading column \"a.b\": Io error: eof"
+        );
+        assert!(matches!(e2.root_cause(), ArrowError::IoError(_, _)));
+
+        let contexts: Vec<_> = e2.contexts().cloned().collect();
+        assert_eq!(contexts, vec![ErrorContext::new("batch 2"), column]);
+
+        // The source chain passes through the context to the underlying error
+        let source = e2.source().unwrap().downcast_ref::<ArrowError>().unwrap();
+        assert!(matches!(source, ArrowError::Context(_, _)));
+        let source = source
+            .source()
+            .unwrap()
+            .downcast_ref::<ArrowError>()
+            .unwrap();
+        assert!(matches!(source, ArrowError::IoError(_, _)));
+        let source = source.source().unwrap();
+        let source = source.downcast_ref::<std::io::Error>().unwrap();
+        assert_eq!(source.kind(), std::io::ErrorKind::UnexpectedEof);
+
+        let e3 = ArrowError::DivideByZero;
+        assert!(matches!(e3.root_cause(), ArrowError::DivideByZero));
+    }
 }
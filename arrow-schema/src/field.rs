@@ -16,10 +16,15 @@
 // under the License.
 
 use crate::error::ArrowError;
-use std::cmp::Ordering;
-use std::collections::HashMap;
-use std::hash::{Hash, Hasher};
-use std::sync::Arc;
+use crate::HashMap;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 
 use crate::datatype::DataType;
 use crate::schema::SchemaBuilder;
@@ -541,10 +546,10 @@ impl Field {
     ///
     /// Includes the size of `Self`.
     pub fn size(&self) -> usize {
-        std::mem::size_of_val(self) - std::mem::size_of_val(&self.data_type)
+        core::mem::size_of_val(self) - core::mem::size_of_val(&self.data_type)
             + self.data_type.size()
             + self.name.capacity()
-            + (std::mem::size_of::<(String, String)>() * self.metadata.capacity())
+            + (core::mem::size_of::<(String, String)>() * self.metadata.capacity())
             + self
                 .metadata
                 .iter()
@@ -554,8 +559,8 @@ impl Field {
 }
 
 // TODO: improve display with crate https://crates.io/crates/derive_more ?
-impl std::fmt::Display for Field {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Field {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{self:?}")
     }
 }
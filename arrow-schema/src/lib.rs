@@ -16,9 +16,30 @@
 // under the License.
 
 //! Arrow logical types
+//!
+//! # Crate Features
+//!
+//! * `std` (default): depend on the standard library. Disabling this allows the
+//!   crate to be used in `no_std` environments that provide a global allocator.
+//!   The metadata of [`Field`] and [`Schema`] is then a [`hashbrown::HashMap`],
+//!   and the [`ArrowError`] variants wrapping `std` errors are not available
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(all(feature = "serde", not(feature = "std")))]
+compile_error!("the serde feature requires the std feature");
+
+#[cfg(not(feature = "std"))]
+pub(crate) use hashbrown::HashMap;
+// The map type used for the metadata of `Field` and `Schema`
+#[cfg(feature = "std")]
+pub(crate) use std::collections::HashMap;
 
 mod datatype;
 pub use datatype::*;
+mod datatype_parse;
 mod error;
 pub use error::*;
 mod field;
@@ -26,8 +47,8 @@ pub use field::*;
 mod fields;
 pub use fields::*;
 mod schema;
+use core::ops;
 pub use schema::*;
-use std::ops;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
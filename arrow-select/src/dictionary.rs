@@ -203,7 +203,7 @@ pub fn merge_dictionary_values<K: ArrowDictionaryKeyType>(
 
 /// Return a mask identifying the values that are referenced by keys in `dictionary`
 /// at the positions indicated by `selection`
-fn compute_values_mask<K: ArrowNativeType>(
+pub(crate) fn compute_values_mask<K: ArrowNativeType>(
     keys: &ScalarBuffer<K>,
     mask: Option<&BooleanBuffer>,
     max_key: usize,
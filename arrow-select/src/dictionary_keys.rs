@@ -0,0 +1,334 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to narrow the keys of [`DictionaryArray`] to the smallest
+//! integer type able to index their values
+//!
+//! Dictionaries are frequently created with [`Int32Type`] keys, despite containing
+//! far fewer values, wasting memory on every key. [`dictionary_key_statistics`]
+//! computes the number of values referenced by the keys of a dictionary, along with
+//! the smallest key type able to index them, and [`narrow_dictionary_keys`] re-keys
+//! the dictionary columns of a [`RecordBatch`] into this type.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, DictionaryArray, RecordBatch};
+//! # use arrow_array::types::Int32Type;
+//! # use arrow_schema::DataType;
+//! # use arrow_select::dictionary_keys::{analyze_dictionary_keys, narrow_dictionary_keys};
+//! let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a", "c"].into_iter().collect();
+//! let batch = RecordBatch::try_from_iter([("d", Arc::new(dict) as ArrayRef)]).unwrap();
+//!
+//! let stats = analyze_dictionary_keys(&batch);
+//! let stats = stats[0].as_ref().unwrap();
+//! assert_eq!(stats.num_referenced_values, 3);
+//! assert_eq!(stats.recommended_key_type, DataType::Int8);
+//!
+//! let narrowed = narrow_dictionary_keys(&batch).unwrap();
+//! let expected = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+//! assert_eq!(narrowed.schema().field(0).data_type(), &expected);
+//! ```
+
+use std::sync::Arc;
+
+use arrow_array::types::*;
+use arrow_array::{
+    downcast_dictionary_array, Array, ArrayRef, DictionaryArray, PrimitiveArray, RecordBatch,
+    UInt64Array,
+};
+use arrow_buffer::{ArrowNativeType, BooleanBuffer, NullBuffer};
+use arrow_schema::{ArrowError, DataType, Schema};
+
+use crate::dictionary::compute_values_mask;
+use crate::take::take;
+
+/// Statistics of the keys of a [`DictionaryArray`], see [`dictionary_key_statistics`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictionaryKeyStatistics {
+    /// The current key type of the dictionary
+    pub key_type: DataType,
+    /// The number of values in the dictionary
+    pub num_values: usize,
+    /// The number of distinct values referenced by the non-null keys of the dictionary
+    pub num_referenced_values: usize,
+    /// The smallest key type, of the same signedness as `key_type`, able to index
+    /// the referenced values
+    pub recommended_key_type: DataType,
+}
+
+impl DictionaryKeyStatistics {
+    /// Returns true if the dictionary can be re-keyed with a narrower key type
+    pub fn can_narrow(&self) -> bool {
+        self.recommended_key_type != self.key_type
+    }
+}
+
+/// Computes the [`DictionaryKeyStatistics`] of `array`, returning `None` if
+/// `array` is not a [`DictionaryArray`]
+pub fn dictionary_key_statistics(array: &dyn Array) -> Option<DictionaryKeyStatistics> {
+    downcast_dictionary_array!(
+        array => {
+            let referenced = referenced_values(array);
+            Some(statistics(array, &referenced))
+        },
+        _ => None
+    )
+}
+
+/// Computes the [`DictionaryKeyStatistics`] of each column of `batch`, with `None`
+/// for the columns that are not dictionary encoded
+pub fn analyze_dictionary_keys(batch: &RecordBatch) -> Vec<Option<DictionaryKeyStatistics>> {
+    batch
+        .columns()
+        .iter()
+        .map(|c| dictionary_key_statistics(c.as_ref()))
+        .collect()
+}
+
+/// Re-keys `array` with the recommended key type of its [`DictionaryKeyStatistics`]
+///
+/// If the dictionary contains more values than can be indexed by the recommended key
+/// type, the values not referenced by any key are removed. Arrays that are not
+/// dictionary encoded, or whose keys cannot be narrowed, are returned unchanged.
+pub fn narrow_dictionary_key_array(array: &ArrayRef) -> Result<ArrayRef, ArrowError> {
+    downcast_dictionary_array!(
+        array => narrow_dictionary(array),
+        _ => Ok(Arc::clone(array))
+    )
+}
+
+/// Re-keys the dictionary columns of `batch` with the smallest key type able to
+/// index their referenced values, see [`narrow_dictionary_key_array`]
+///
+/// Only top-level columns are narrowed, dictionaries nested within other types
+/// are left unchanged
+pub fn narrow_dictionary_keys(batch: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(narrow_dictionary_key_array)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let schema = batch.schema();
+    let fields: Vec<_> = schema
+        .fields()
+        .iter()
+        .zip(&columns)
+        .map(|(f, c)| match f.data_type() == c.data_type() {
+            true => Arc::clone(f),
+            false => Arc::new(f.as_ref().clone().with_data_type(c.data_type().clone())),
+        })
+        .collect();
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+/// Returns a mask of the values of `dict` referenced by its non-null keys
+fn referenced_values<K: ArrowDictionaryKeyType>(dict: &DictionaryArray<K>) -> BooleanBuffer {
+    let keys = dict.keys();
+    let mask = keys.nulls().map(NullBuffer::inner);
+    compute_values_mask(keys.values(), mask, dict.values().len())
+}
+
+fn statistics<K: ArrowDictionaryKeyType>(
+    dict: &DictionaryArray<K>,
+    referenced: &BooleanBuffer,
+) -> DictionaryKeyStatistics {
+    let num_referenced_values = referenced.count_set_bits();
+    let signed = K::DATA_TYPE.is_signed_integer();
+    let recommended_key_type = [8, 16, 32, 64]
+        .into_iter()
+        .map(|bits| key_type(signed, bits))
+        .find(|t| num_referenced_values <= key_capacity(t))
+        .unwrap();
+
+    // Never recommend a wider key type than the current one
+    let recommended_key_type =
+        match key_capacity(&recommended_key_type) < key_capacity(&K::DATA_TYPE) {
+            true => recommended_key_type,
+            false => K::DATA_TYPE,
+        };
+
+    DictionaryKeyStatistics {
+        key_type: K::DATA_TYPE,
+        num_values: dict.values().len(),
+        num_referenced_values,
+        recommended_key_type,
+    }
+}
+
+/// Returns the integer key type with the given signedness and number of bits
+fn key_type(signed: bool, bits: usize) -> DataType {
+    match (signed, bits) {
+        (true, 8) => DataType::Int8,
+        (true, 16) => DataType::Int16,
+        (true, 32) => DataType::Int32,
+        (true, _) => DataType::Int64,
+        (false, 8) => DataType::UInt8,
+        (false, 16) => DataType::UInt16,
+        (false, 32) => DataType::UInt32,
+        (false, _) => DataType::UInt64,
+    }
+}
+
+/// Returns the number of values that can be indexed by keys of `key_type`
+fn key_capacity(key_type: &DataType) -> usize {
+    match key_type {
+        DataType::Int8 => i8::MAX as usize + 1,
+        DataType::Int16 => i16::MAX as usize + 1,
+        DataType::Int32 => i32::MAX as usize + 1,
+        DataType::UInt8 => u8::MAX as usize + 1,
+        DataType::UInt16 => u16::MAX as usize + 1,
+        DataType::UInt32 => (u32::MAX as usize).saturating_add(1),
+        _ => usize::MAX,
+    }
+}
+
+fn narrow_dictionary<K: ArrowDictionaryKeyType>(
+    dict: &DictionaryArray<K>,
+) -> Result<ArrayRef, ArrowError> {
+    let referenced = referenced_values(dict);
+    let stats = statistics(dict, &referenced);
+    if !stats.can_narrow() {
+        return Ok(Arc::new(dict.clone()));
+    }
+
+    // Only remove the unreferenced values if required to fit the new key type
+    let (mapping, values) = match stats.num_values <= key_capacity(&stats.recommended_key_type) {
+        true => ((0..stats.num_values).collect(), Arc::clone(dict.values())),
+        false => {
+            let mut mapping = vec![0; stats.num_values];
+            for (new, old) in referenced.set_indices().enumerate() {
+                mapping[old] = new;
+            }
+            let indices = UInt64Array::from_iter_values(referenced.set_indices().map(|i| i as u64));
+            (mapping, take(dict.values().as_ref(), &indices, None)?)
+        }
+    };
+
+    // The keys of null slots may be arbitrary, and so are mapped to 0
+    let keys = dict.keys();
+    let new_keys = keys
+        .values()
+        .iter()
+        .map(|k| mapping.get(k.as_usize()).copied().unwrap_or_default());
+    let nulls = keys.nulls().cloned();
+
+    match stats.recommended_key_type {
+        DataType::Int8 => rekey::<Int8Type>(new_keys, nulls, values),
+        DataType::Int16 => rekey::<Int16Type>(new_keys, nulls, values),
+        DataType::Int32 => rekey::<Int32Type>(new_keys, nulls, values),
+        DataType::UInt8 => rekey::<UInt8Type>(new_keys, nulls, values),
+        DataType::UInt16 => rekey::<UInt16Type>(new_keys, nulls, values),
+        DataType::UInt32 => rekey::<UInt32Type>(new_keys, nulls, values),
+        d => unreachable!("cannot narrow dictionary keys to {d}"),
+    }
+}
+
+fn rekey<K: ArrowDictionaryKeyType>(
+    keys: impl Iterator<Item = usize>,
+    nulls: Option<NullBuffer>,
+    values: ArrayRef,
+) -> Result<ArrayRef, ArrowError> {
+    let keys = PrimitiveArray::<K>::new(keys.map(K::Native::usize_as).collect(), nulls);
+    Ok(Arc::new(DictionaryArray::try_new(keys, values)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Int32Array, StringArray, UInt32Array};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_narrow_dictionary_keys() {
+        let values = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let keys = Int32Array::from(vec![Some(2), None, Some(0), Some(2)]);
+        let dict = DictionaryArray::new(keys, values);
+        let stats = dictionary_key_statistics(&dict).unwrap();
+        assert_eq!(
+            stats,
+            DictionaryKeyStatistics {
+                key_type: DataType::Int32,
+                num_values: 3,
+                num_referenced_values: 2,
+                recommended_key_type: DataType::Int8,
+            }
+        );
+
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let batch = RecordBatch::try_from_iter([
+            ("d", Arc::new(dict.clone()) as ArrayRef),
+            (
+                "i",
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let schema = batch.schema().as_ref().clone().with_metadata(metadata);
+        let batch = batch.with_schema(Arc::new(schema)).unwrap();
+
+        let stats = analyze_dictionary_keys(&batch);
+        assert!(stats[0].as_ref().unwrap().can_narrow());
+        assert_eq!(stats[1], None);
+
+        let narrowed = narrow_dictionary_keys(&batch).unwrap();
+        assert_eq!(narrowed.schema().metadata(), batch.schema().metadata());
+        assert_eq!(narrowed.column(1), batch.column(1));
+
+        let d = narrowed.column(0).as_dictionary::<Int8Type>();
+        assert_eq!(d.values().as_ref(), dict.values().as_ref());
+        let keys: Vec<_> = d.keys().iter().collect();
+        assert_eq!(keys, vec![Some(2), None, Some(0), Some(2)]);
+
+        // Narrowing again leaves the batch unchanged
+        let again = narrow_dictionary_keys(&narrowed).unwrap();
+        assert_eq!(again, narrowed);
+    }
+
+    #[test]
+    fn test_narrow_unreferenced_values() {
+        // 300 values, of which only 3 are referenced
+        let values = Arc::new(StringArray::from_iter_values(
+            (0..300).map(|i| i.to_string()),
+        ));
+        let keys = UInt32Array::from(vec![299, 5, 299, 150]);
+        let dict = DictionaryArray::new(keys, values);
+        let stats = dictionary_key_statistics(&dict).unwrap();
+        assert_eq!(stats.num_values, 300);
+        assert_eq!(stats.num_referenced_values, 3);
+        assert_eq!(stats.recommended_key_type, DataType::UInt8);
+
+        let narrowed = narrow_dictionary_key_array(&(Arc::new(dict) as ArrayRef)).unwrap();
+        let d = narrowed.as_dictionary::<UInt8Type>();
+        assert_eq!(d.values().len(), 3);
+        let strings = d.downcast_dict::<StringArray>().unwrap();
+        let actual: Vec<_> = strings.into_iter().map(Option::unwrap).collect();
+        assert_eq!(actual, vec!["299", "5", "299", "150"]);
+
+        // Cannot narrow to Int8 with 200 referenced values
+        let values = Arc::new(StringArray::from_iter_values(
+            (0..200).map(|i| i.to_string()),
+        ));
+        let dict = DictionaryArray::new(Int32Array::from_iter_values(0..200), values);
+        let stats = dictionary_key_statistics(&dict).unwrap();
+        assert_eq!(stats.recommended_key_type, DataType::Int16);
+
+        assert_eq!(dictionary_key_statistics(&Int32Array::from(vec![1])), None);
+    }
+}
@@ -340,6 +340,9 @@ fn filter_array(values: &dyn Array, predicate: &FilterPredicate) -> Result<Array
                 values => Ok(Arc::new(filter_dict(values, predicate))),
                 t => unimplemented!("Filter not supported for dictionary type {:?}", t)
             }
+            DataType::FixedSizeList(_, _) => {
+                Ok(Arc::new(filter_fixed_size_list(values.as_fixed_size_list(), predicate)?))
+            }
             _ => {
                 let data = values.to_data();
                 // fallback to using MutableArrayData
@@ -615,6 +618,37 @@ where
     GenericByteArray::from(data)
 }
 
+/// `filter` implementation for fixed size lists
+///
+/// Filters the values with the ranges of values belonging to the selected lists,
+/// allowing them to use their own specialized `filter` implementation
+fn filter_fixed_size_list(
+    array: &FixedSizeListArray,
+    predicate: &FilterPredicate,
+) -> Result<FixedSizeListArray, ArrowError> {
+    let size = array.value_length() as usize;
+    let mut values_filter = BooleanBufferBuilder::new(predicate.filter.len() * size);
+    let mut last_end = 0;
+    for (start, end) in SlicesIterator::new(&predicate.filter) {
+        values_filter.append_n((start - last_end) * size, false);
+        values_filter.append_n((end - start) * size, true);
+        last_end = end;
+    }
+    let values_filter = BooleanArray::new(values_filter.finish(), None);
+    let values = filter(array.values().as_ref(), &values_filter)?;
+
+    let mut builder = ArrayDataBuilder::new(array.data_type().clone())
+        .len(predicate.count)
+        .add_child_data(values.into_data());
+
+    if let Some((null_count, nulls)) = filter_null_mask(array.nulls(), predicate) {
+        builder = builder.null_count(null_count).null_bit_buffer(Some(nulls));
+    }
+
+    let data = unsafe { builder.build_unchecked() };
+    Ok(FixedSizeListArray::from(data))
+}
+
 /// `filter` implementation for dictionaries
 fn filter_dict<T>(array: &DictionaryArray<T>, predicate: &FilterPredicate) -> DictionaryArray<T>
 where
@@ -1348,6 +1382,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_filter_fixed_size_list_sliced() {
+        let values = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("d"),
+            Some("e"),
+            Some("f"),
+            Some("g"),
+            Some("h"),
+        ]));
+        let nulls = NullBuffer::from(vec![true, true, false, true]);
+        let list = FixedSizeListArray::try_from_flat(values, 2, Some(nulls)).unwrap();
+        let list = list.slice(1, 3);
+
+        let predicate = BooleanArray::from(vec![true, true, true, false]).slice(1, 3);
+        let filtered = filter(&list, &predicate).unwrap();
+        let filtered = filtered.as_fixed_size_list();
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.is_null(1));
+
+        let values = filtered.values().as_string::<i32>();
+        let values: Vec<_> = values.iter().collect();
+        assert_eq!(values, vec![None, Some("d"), Some("e"), Some("f")]);
+    }
+
     fn test_filter_union_array(array: UnionArray) {
         let filter_array = BooleanArray::from(vec![true, false, false]);
         let c = filter(&array, &filter_array).unwrap();
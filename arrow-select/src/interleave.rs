@@ -64,6 +64,11 @@ macro_rules! dict_helper {
 /// ```
 ///
 /// For selecting values by index from a single array see [`crate::interleave`]
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds, unless the `no_panic` feature is enabled, in
+/// which case an error is returned
 pub fn interleave(
     values: &[&dyn Array],
     indices: &[(usize, usize)],
@@ -89,6 +94,10 @@ pub fn interleave(
         return Ok(new_empty_array(data_type));
     }
 
+    if cfg!(feature = "no_panic") {
+        check_bounds(values, indices)?;
+    }
+
     downcast_primitive! {
         data_type => (primitive_helper, values, indices, data_type),
         DataType::Utf8 => interleave_bytes::<Utf8Type>(values, indices),
@@ -103,6 +112,24 @@ pub fn interleave(
     }
 }
 
+/// Verifies that each of `indices` refers to a value of an array in `values`
+fn check_bounds(values: &[&dyn Array], indices: &[(usize, usize)]) -> Result<(), ArrowError> {
+    indices.iter().try_for_each(|(array, row)| {
+        let len = values.get(*array).map(|a| a.len()).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Array index out of bounds, cannot get array {array} from {} arrays",
+                values.len()
+            ))
+        })?;
+        match *row < len {
+            true => Ok(()),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "Array index out of bounds, cannot get item at index {row} from {len} entries"
+            ))),
+        }
+    })
+}
+
 /// Common functionality for interleaving arrays
 ///
 /// T is the concrete Array type
@@ -272,6 +299,26 @@ mod tests {
     use arrow_array::{Int32Array, ListArray, StringArray};
     use arrow_schema::DataType;
 
+    #[test]
+    fn test_check_bounds() {
+        let a = Int32Array::from_iter_values([1, 2, 3, 4]);
+        let b = Int32Array::from_iter_values([5]);
+        let values: &[&dyn Array] = &[&a, &b];
+        check_bounds(values, &[(0, 3), (1, 0)]).unwrap();
+
+        let err = check_bounds(values, &[(0, 1), (1, 1)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Array index out of bounds, cannot get item at index 1 from 1 entries"
+        );
+
+        let err = check_bounds(values, &[(2, 0)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Array index out of bounds, cannot get array 2 from 2 arrays"
+        );
+    }
+
     #[test]
     fn test_primitive() {
         let a = Int32Array::from_iter_values([1, 2, 3, 4]);
@@ -19,8 +19,10 @@
 
 pub mod concat;
 mod dictionary;
+pub mod dictionary_keys;
 pub mod filter;
 pub mod interleave;
+pub mod normalize;
 pub mod nullif;
 pub mod take;
 pub mod window;
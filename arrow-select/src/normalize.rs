@@ -0,0 +1,229 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernel to rewrite the offsets of variable-length arrays into canonical form
+//!
+//! Slicing a list, string or binary array is zero-copy, and so yields an array whose
+//! offsets do not start at zero, and whose values contain data outside the slice.
+//! Whilst valid, such arrays are needlessly large when exported via FFI or written
+//! to IPC, and some consumers incorrectly assume the first offset is zero.
+//! [`normalize_offsets`] rewrites these arrays so that their offsets start at zero
+//! and their values contain only the data referenced by the offsets.
+//!
+//! ```
+//! # use arrow_array::{Array, StringArray};
+//! # use arrow_array::cast::AsArray;
+//! # use arrow_select::normalize::normalize_offsets;
+//! let array = StringArray::from(vec!["hello", "arrow", "world"]);
+//! let sliced = array.slice(1, 1);
+//! assert_eq!(sliced.value_offsets(), &[5, 10]);
+//!
+//! let normalized = normalize_offsets(&sliced);
+//! let normalized = normalized.as_string::<i32>();
+//! assert_eq!(normalized.value_offsets(), &[0, 5]);
+//! assert_eq!(normalized.values().len(), 5);
+//! assert_eq!(normalized.value(0), "arrow");
+//! ```
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, Buffer, OffsetBuffer};
+use arrow_schema::DataType;
+
+/// Rewrites `array` so that its offsets start at zero, and its values contain only
+/// the data referenced by its offsets
+///
+/// Supports string, binary, list and map arrays, recursing into their values, along
+/// with the children of struct arrays. The values of string and binary arrays are
+/// copied into new buffers, whereas the values of list and map arrays are sliced, and
+/// then normalized. Arrays that are already in canonical form are returned without
+/// copying, as are arrays of all other types.
+pub fn normalize_offsets(array: &dyn Array) -> ArrayRef {
+    match array.data_type() {
+        DataType::Utf8 => Arc::new(normalize_bytes(array.as_string::<i32>())),
+        DataType::LargeUtf8 => Arc::new(normalize_bytes(array.as_string::<i64>())),
+        DataType::Binary => Arc::new(normalize_bytes(array.as_binary::<i32>())),
+        DataType::LargeBinary => Arc::new(normalize_bytes(array.as_binary::<i64>())),
+        DataType::List(_) => Arc::new(normalize_list(array.as_list::<i32>())),
+        DataType::LargeList(_) => Arc::new(normalize_list(array.as_list::<i64>())),
+        DataType::Map(_, _) => Arc::new(normalize_map(array.as_map())),
+        DataType::Struct(_) => Arc::new(normalize_struct(array.as_struct())),
+        _ => make_array(array.to_data()),
+    }
+}
+
+/// Returns the range of the values referenced by `offsets`
+fn offsets_range<O: ArrowNativeType>(offsets: &OffsetBuffer<O>) -> (usize, usize) {
+    let start = offsets.first().unwrap().as_usize();
+    let end = offsets.last().unwrap().as_usize();
+    (start, end)
+}
+
+/// Returns `offsets` shifted to start at zero
+fn shift_offsets<O: ArrowNativeType>(offsets: &OffsetBuffer<O>) -> OffsetBuffer<O> {
+    let start = offsets.first().unwrap().as_usize();
+    if start == 0 {
+        return offsets.clone();
+    }
+    let shifted: Vec<O> = offsets
+        .iter()
+        .map(|o| O::usize_as(o.as_usize() - start))
+        .collect();
+    // SAFETY: shifting monotonically increasing offsets by their first value yields
+    // monotonically increasing offsets starting at zero
+    unsafe { OffsetBuffer::new_unchecked(shifted.into()) }
+}
+
+fn normalize_bytes<T: ByteArrayType>(array: &GenericByteArray<T>) -> GenericByteArray<T> {
+    let (start, end) = offsets_range(array.offsets());
+    if start == 0 && end == array.values().len() {
+        return array.clone();
+    }
+    let offsets = shift_offsets(array.offsets());
+    let values = Buffer::from_slice_ref(&array.values()[start..end]);
+    // SAFETY: the values referenced by the shifted offsets are unchanged
+    unsafe { GenericByteArray::new_unchecked(offsets, values, array.nulls().cloned()) }
+}
+
+fn normalize_list<O: OffsetSizeTrait>(array: &GenericListArray<O>) -> GenericListArray<O> {
+    let (start, end) = offsets_range(array.offsets());
+    let values = normalize_offsets(array.values().slice(start, end - start).as_ref());
+    let offsets = shift_offsets(array.offsets());
+    let (field, _, _, nulls) = array.clone().into_parts();
+    GenericListArray::new(field, offsets, values, nulls)
+}
+
+fn normalize_map(array: &MapArray) -> MapArray {
+    let (start, end) = offsets_range(array.offsets());
+    let entries = normalize_struct(&array.entries().slice(start, end - start));
+    let offsets = shift_offsets(array.offsets());
+    let (field, _, _, nulls, ordered) = array.clone().into_parts();
+    MapArray::new(field, offsets, entries, nulls, ordered)
+}
+
+fn normalize_struct(array: &StructArray) -> StructArray {
+    let columns = array
+        .columns()
+        .iter()
+        .map(|c| normalize_offsets(c.as_ref()))
+        .collect();
+    let (fields, _, nulls) = array.clone().into_parts();
+    StructArray::new(fields, columns, nulls)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, ListBuilder, MapBuilder, StringBuilder};
+    use arrow_schema::Field;
+
+    #[test]
+    fn test_normalize_bytes() {
+        let array = BinaryArray::from(vec![Some(b"ab".as_ref()), None, Some(b"cde"), Some(b"")]);
+        let normalized = normalize_offsets(&array);
+        let normalized = normalized.as_binary::<i32>();
+        assert_eq!(normalized, &array);
+        // Canonical arrays are not copied
+        assert_eq!(normalized.values().as_ptr(), array.values().as_ptr());
+
+        let sliced = array.slice(1, 2);
+        let normalized = normalize_offsets(&sliced);
+        let normalized = normalized.as_binary::<i32>();
+        assert_eq!(normalized, &sliced);
+        assert_eq!(normalized.value_offsets(), &[0, 0, 3]);
+        assert_eq!(normalized.values().as_slice(), b"cde");
+        assert_eq!(normalized.null_count(), 1);
+
+        // Offsets starting at zero, but with trailing values
+        let array = LargeStringArray::from(vec!["a", "bc", "def"]).slice(0, 2);
+        let normalized = normalize_offsets(&array);
+        let normalized = normalized.as_string::<i64>();
+        assert_eq!(normalized, &array);
+        assert_eq!(normalized.values().as_slice(), b"abc");
+    }
+
+    #[test]
+    fn test_normalize_list() {
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        builder.append_value([Some("a"), Some("b")]);
+        builder.append_null();
+        builder.append_value([Some("c"), None, Some("d")]);
+        builder.append_value([Some("e")]);
+        let array = builder.finish();
+
+        let sliced = array.slice(1, 2);
+        let normalized = normalize_offsets(&sliced);
+        let normalized = normalized.as_list::<i32>();
+        assert_eq!(normalized, &sliced);
+        assert_eq!(normalized.value_offsets(), &[0, 0, 3]);
+
+        let values = normalized.values().as_string::<i32>();
+        assert_eq!(values.value_offsets(), &[0, 1, 1, 2]);
+        assert_eq!(values.values().as_slice(), b"cd");
+    }
+
+    #[test]
+    fn test_normalize_nested() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        builder.keys().append_value("bb");
+        builder.values().append_value(2);
+        builder.keys().append_value("ccc");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        let strings = StringArray::from(vec!["x", "yy"]);
+        let array = StructArray::from(vec![
+            (
+                Arc::new(Field::new("m", map.data_type().clone(), true)),
+                Arc::new(map) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("s", DataType::Utf8, true)),
+                Arc::new(strings) as ArrayRef,
+            ),
+        ]);
+
+        let sliced = array.slice(1, 1);
+        let normalized = normalize_offsets(&sliced);
+        let normalized = normalized.as_struct();
+        assert_eq!(normalized, &sliced);
+
+        let map = normalized.column(0).as_map();
+        assert_eq!(map.value_offsets(), &[0, 2]);
+        let keys = map.keys().as_string::<i32>();
+        assert_eq!(keys.value_offsets(), &[0, 2, 5]);
+        assert_eq!(keys.values().as_slice(), b"bbccc");
+
+        let strings = normalized.column(1).as_string::<i32>();
+        assert_eq!(strings.value_offsets(), &[0, 2]);
+        assert_eq!(strings.values().as_slice(), b"yy");
+    }
+
+    #[test]
+    fn test_normalize_other() {
+        let array = Int32Array::from(vec![1, 2, 3]).slice(1, 2);
+        let normalized = normalize_offsets(&array);
+        assert_eq!(normalized.as_primitive::<Int32Type>(), &array);
+    }
+}
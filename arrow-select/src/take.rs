@@ -19,7 +19,7 @@
 
 use std::sync::Arc;
 
-use arrow_array::builder::{BufferBuilder, UInt32Builder};
+use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder, UInt32Builder};
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::*;
@@ -55,9 +55,10 @@ use num::{One, Zero};
 /// * An index cannot be casted to `usize` (typically 32 bit architectures)
 /// * An index is out of bounds and `options` is set to check bounds.
 ///
-/// # Safety
+/// # Panics
 ///
-/// When `options` is not set to check bounds, taking indexes after `len` will panic.
+/// When `options` is not set to check bounds, taking indexes after `len` will panic,
+/// unless the `no_panic` feature is enabled, in which case bounds are always checked
 ///
 /// # Examples
 /// ```
@@ -81,7 +82,7 @@ pub fn take(
     macro_rules! helper {
         ($t:ty, $values:expr, $indices:expr, $options:expr) => {{
             let indices = indices.as_primitive::<$t>();
-            if $options.check_bounds {
+            if $options.check_bounds || cfg!(feature = "no_panic") {
                 check_bounds($values.len(), indices)?;
             }
             let indices = indices.to_indices();
@@ -192,11 +193,11 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
         }
         DataType::Dictionary(_, _) => downcast_dictionary_array! {
             values => Ok(Arc::new(take_dict(values, indices)?)),
-            t => unimplemented!("Take not supported for dictionary type {:?}", t)
+            t => Err(ArrowError::NotYetImplemented(format!("Take not supported for dictionary type {t:?}")))
         }
         DataType::RunEndEncoded(_, _) => downcast_run_array! {
             values => Ok(Arc::new(take_run(values, indices)?)),
-            t => unimplemented!("Take not supported for run type {:?}", t)
+            t => Err(ArrowError::NotYetImplemented(format!("Take not supported for run type {t:?}")))
         }
         DataType::Binary => {
             Ok(Arc::new(take_bytes(values.as_binary::<i32>(), indices)?))
@@ -237,7 +238,7 @@ fn take_impl<IndexType: ArrowPrimitiveType>(
             let array = UnionArray::try_new(field_type_ids.as_slice(), type_ids, None, children)?;
             Ok(Arc::new(array))
         }
-        t => unimplemented!("Take not supported for data type {:?}", t)
+        t => Err(ArrowError::NotYetImplemented(format!("Take not supported for data type {t:?}")))
     }
 }
 
@@ -247,6 +248,8 @@ pub struct TakeOptions {
     /// Perform bounds check before taking indices from values.
     /// If enabled, an `ArrowError` is returned if the indices are out of bounds.
     /// If not enabled, and indices exceed bounds, the kernel will panic.
+    ///
+    /// This is always enabled if the `no_panic` feature is enabled
     pub check_bounds: bool,
 }
 
@@ -473,13 +476,21 @@ where
 /// Calculates the index and indexed offset for the inner array,
 /// applying `take` on the inner array, then reconstructing a list array
 /// with the indexed offsets
+///
+/// Primitive inner arrays instead copy the values of each list as a single range
 fn take_fixed_size_list<IndexType: ArrowPrimitiveType>(
     values: &FixedSizeListArray,
     indices: &PrimitiveArray<IndexType>,
     length: <UInt32Type as ArrowPrimitiveType>::Native,
 ) -> Result<FixedSizeListArray, ArrowError> {
-    let list_indices = take_value_indices_from_fixed_size_list(values, indices, length)?;
-    let taken = take_impl::<UInt32Type>(values.values().as_ref(), &list_indices)?;
+    let child = values.values().as_ref();
+    let taken: ArrayRef = downcast_primitive_array! {
+        child => Arc::new(take_fixed_size_list_values(child, indices, length as usize)),
+        _ => {
+            let list_indices = take_value_indices_from_fixed_size_list(values, indices, length)?;
+            take_impl::<UInt32Type>(child, &list_indices)?
+        }
+    };
 
     // determine null count and null buffer, which are a function of `values` and `indices`
     let num_bytes = bit_util::ceil(indices.len(), 8);
@@ -507,6 +518,44 @@ fn take_fixed_size_list<IndexType: ArrowPrimitiveType>(
     Ok(FixedSizeListArray::from(list_data))
 }
 
+/// `take` implementation for the primitive values of a `FixedSizeListArray` with lists
+/// of `size` values, copying the values of each list as a single range
+fn take_fixed_size_list_values<T: ArrowPrimitiveType, I: ArrowPrimitiveType>(
+    values: &PrimitiveArray<T>,
+    indices: &PrimitiveArray<I>,
+    size: usize,
+) -> PrimitiveArray<T> {
+    let capacity = indices.len() * size;
+    let mut buffer = Vec::with_capacity(capacity);
+    let mut nulls = (values.null_count() != 0 || indices.null_count() != 0)
+        .then(|| BooleanBufferBuilder::new(capacity));
+
+    for (i, index) in indices.values().iter().enumerate() {
+        if indices.is_null(i) {
+            buffer.resize(buffer.len() + size, T::Native::default());
+            if let Some(nulls) = nulls.as_mut() {
+                nulls.append_n(size, false);
+            }
+            continue;
+        }
+
+        let start = index.as_usize() * size;
+        let end = start + size;
+        buffer.extend_from_slice(&values.values()[start..end]);
+        if let Some(nulls) = nulls.as_mut() {
+            match values.nulls() {
+                Some(n) => {
+                    nulls.append_packed_range(n.offset() + start..n.offset() + end, n.validity())
+                }
+                None => nulls.append_n(size, true),
+            }
+        }
+    }
+
+    let nulls = nulls.map(|mut n| NullBuffer::new(n.finish()));
+    PrimitiveArray::new(buffer.into(), nulls).with_data_type(values.data_type().clone())
+}
+
 fn take_fixed_size_binary<IndexType: ArrowPrimitiveType>(
     values: &FixedSizeBinaryArray,
     indices: &PrimitiveArray<IndexType>,
@@ -1720,6 +1769,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "no_panic"))]
     #[should_panic(expected = "index out of bounds: the len is 4 but the index is 1000")]
     fn test_take_list_out_of_bounds() {
         // Construct a value array, [[0,0,0], [-1,-2,-1], [2,3]]
@@ -1836,6 +1886,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "no_panic"))]
     #[should_panic(expected = "index out of bounds: the len is 4 but the index is 1000")]
     fn test_take_out_of_bounds_panic() {
         let index = UInt32Array::from(vec![Some(1000)]);
@@ -1850,6 +1901,33 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "no_panic")]
+    fn test_take_out_of_bounds_no_panic() {
+        let values = Int64Array::from(vec![0, 1, 2, 3]);
+        let index = UInt32Array::from(vec![Some(1000)]);
+        let err = take(&values, &index, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 1000 from 4 entries"
+        );
+    }
+
+    #[test]
+    fn test_take_unsupported() {
+        let mut builder = arrow_array::builder::UnionBuilder::new_dense();
+        builder.append::<Int32Type>("a", 1).unwrap();
+        let union = builder.build().unwrap();
+
+        let err = take(&union, &UInt32Array::from(vec![0]), None).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Not yet implemented: Take not supported for data type Union("),
+            "{err}"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no_panic"))]
     fn test_null_array_smaller_than_indices() {
         let values = NullArray::new(2);
         let indices = UInt32Array::from(vec![Some(0), None, Some(15)]);
@@ -1860,6 +1938,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "no_panic"))]
     fn test_null_array_larger_than_indices() {
         let values = NullArray::new(5);
         let indices = UInt32Array::from(vec![Some(0), None, Some(15)]);
@@ -2041,6 +2120,45 @@ mod tests {
         assert_eq!(&values, &[Some(23), Some(4), None, None])
     }
 
+    #[test]
+    fn test_take_fixed_size_list_sliced() {
+        let list = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(0), Some(1)]),
+                Some(vec![Some(2), None]),
+                None,
+                Some(vec![None, Some(7)]),
+                Some(vec![Some(8), Some(9)]),
+            ],
+            2,
+        );
+        let list = list.slice(1, 4);
+
+        let indices = UInt32Array::from(vec![Some(3), None, Some(0), Some(1), Some(2)]);
+        let taken = take(&list, &indices, None).unwrap();
+        let taken = taken.as_fixed_size_list();
+
+        let expected = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(8), Some(9)]),
+                None,
+                Some(vec![Some(2), None]),
+                None,
+                Some(vec![None, Some(7)]),
+            ],
+            2,
+        );
+        assert_eq!(taken, &expected);
+
+        // Non-primitive values are taken by index
+        let values = Arc::new(StringArray::from(vec!["a", "b", "c", "d"]));
+        let list = FixedSizeListArray::try_from_flat(values, 2, None).unwrap();
+        let taken = take(&list, &UInt32Array::from(vec![1, 0, 1]), None).unwrap();
+        let values = taken.as_fixed_size_list().values().as_string::<i32>();
+        let values: Vec<_> = values.iter().flatten().collect();
+        assert_eq!(values, vec!["c", "d", "a", "b", "c", "d"]);
+    }
+
     #[test]
     fn test_take_fixed_size_list_null_indices() {
         let indices = Int32Array::from_iter([Some(0), None]);
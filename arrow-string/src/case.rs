@@ -0,0 +1,296 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels for converting the case of string arrays
+//!
+//! These perform full unicode case mapping, in which a character may map to several
+//! characters, such as `"ß"` to `"SS"`, and so the length of a value may change.
+//! Arrays containing only ASCII values are converted without decoding their values.
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use arrow_array::builder::GenericStringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_buffer::{Buffer, OffsetBuffer};
+use arrow_schema::{ArrowError, DataType};
+
+/// Returns `array` with each value converted to upper case
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8,
+///   or DictionaryArray with above Arrays as values
+/// * upper of null is null.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::case::upper;
+/// let array = StringArray::from(vec![Some("arrow"), Some("straße"), None]);
+/// let result = upper(&array).unwrap();
+/// let expected = StringArray::from(vec![Some("ARROW"), Some("STRASSE"), None]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn upper(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    convert_case(array, Case::Upper)
+}
+
+/// Returns `array` with each value converted to lower case
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8,
+///   or DictionaryArray with above Arrays as values
+/// * lower of null is null.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::case::lower;
+/// let array = StringArray::from(vec![Some("ARROW"), Some("ΟΔΟΣ"), None]);
+/// let result = lower(&array).unwrap();
+/// let expected = StringArray::from(vec![Some("arrow"), Some("οδος"), None]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn lower(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    convert_case(array, Case::Lower)
+}
+
+/// Returns `array` with the first character of each word of each value converted to
+/// upper case, and the remaining characters to lower case
+///
+/// Words are sequences of alphanumeric characters, separated by any other character.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8,
+///   or DictionaryArray with above Arrays as values
+/// * initcap of null is null.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::case::initcap;
+/// let array = StringArray::from(vec![Some("hello WORLD"), Some("élan-vital"), None]);
+/// let result = initcap(&array).unwrap();
+/// let expected = StringArray::from(vec![Some("Hello World"), Some("Élan-Vital"), None]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn initcap(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    convert_case(array, Case::Initcap)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Case {
+    Upper,
+    Lower,
+    Initcap,
+}
+
+impl Case {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Upper => "upper",
+            Self::Lower => "lower",
+            Self::Initcap => "initcap",
+        }
+    }
+
+    /// Converts the ASCII characters of `value` in place
+    fn convert_ascii(&self, value: &mut [u8]) {
+        match self {
+            Self::Upper => value.make_ascii_uppercase(),
+            Self::Lower => value.make_ascii_lowercase(),
+            Self::Initcap => {
+                let mut in_word = false;
+                for b in value {
+                    match in_word {
+                        true => b.make_ascii_lowercase(),
+                        false => b.make_ascii_uppercase(),
+                    }
+                    in_word = b.is_ascii_alphanumeric();
+                }
+            }
+        }
+    }
+
+    fn write_converted(&self, value: &str, out: &mut impl Write) -> std::fmt::Result {
+        match self {
+            Self::Upper => value
+                .chars()
+                .flat_map(char::to_uppercase)
+                .try_for_each(|c| out.write_char(c)),
+            // Lower case mapping depends on context, such as a final sigma
+            Self::Lower => out.write_str(&value.to_lowercase()),
+            Self::Initcap => {
+                let mut rest = value;
+                while let Some(start) = rest.find(char::is_alphanumeric) {
+                    out.write_str(&rest[..start])?;
+                    let word = &rest[start..];
+                    let end = word.find(|c: char| !c.is_alphanumeric());
+                    let end = end.unwrap_or(word.len());
+                    let mut chars = word[..end].chars();
+                    let first = chars.next().unwrap();
+                    first.to_uppercase().try_for_each(|c| out.write_char(c))?;
+                    out.write_str(&chars.as_str().to_lowercase())?;
+                    rest = &word[end..];
+                }
+                out.write_str(rest)
+            }
+        }
+    }
+}
+
+fn convert_case(array: &dyn Array, case: Case) -> Result<ArrayRef, ArrowError> {
+    if let Some(d) = array.as_any_dictionary_opt() {
+        let values = convert_case(d.values().as_ref(), case)?;
+        return Ok(d.with_values(values));
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => Ok(Arc::new(convert_case_impl(array.as_string::<i32>(), case))),
+        DataType::LargeUtf8 => Ok(Arc::new(convert_case_impl(array.as_string::<i64>(), case))),
+        other => Err(ArrowError::ComputeError(format!(
+            "{} not supported for {other:?}",
+            case.name()
+        ))),
+    }
+}
+
+fn convert_case_impl<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    case: Case,
+) -> GenericStringArray<O> {
+    let offsets = array.offsets();
+    let start = offsets[0];
+    let end = offsets[array.len()];
+    let values = &array.value_data()[start.as_usize()..end.as_usize()];
+
+    if values.is_ascii() {
+        let offsets = match start.as_usize() {
+            0 => offsets.clone(),
+            _ => OffsetBuffer::new(offsets.iter().map(|o| *o - start).collect()),
+        };
+        let mut values = values.to_vec();
+        match case {
+            Case::Initcap => offsets
+                .windows(2)
+                .for_each(|w| case.convert_ascii(&mut values[w[0].as_usize()..w[1].as_usize()])),
+            _ => case.convert_ascii(&mut values),
+        }
+        // SAFETY: converting the case of ASCII characters preserves their byte length,
+        // and therefore the validity of the offsets and UTF-8 values
+        return unsafe {
+            GenericStringArray::new_unchecked(
+                offsets,
+                Buffer::from_vec(values),
+                array.nulls().cloned(),
+            )
+        };
+    }
+
+    let mut builder = GenericStringBuilder::with_capacity(array.len(), values.len());
+    for value in array.iter() {
+        match value {
+            Some(s) => {
+                case.write_converted(s, &mut builder).unwrap();
+                builder.append_value("")
+            }
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    #[test]
+    fn test_convert_case() {
+        let input = vec![
+            Some("hello wORLD"),
+            None,
+            Some("straße"),
+            Some("ΌΣΟΣ καλός"),
+            Some("ǆemal o'neil-smith 2nd"),
+            Some(""),
+        ];
+        type Kernel = fn(&dyn Array) -> Result<ArrayRef, ArrowError>;
+        let cases: [(Kernel, _); 3] = [
+            (
+                upper,
+                [
+                    "HELLO WORLD",
+                    "STRASSE",
+                    "ΌΣΟΣ ΚΑΛΌΣ",
+                    "ǄEMAL O'NEIL-SMITH 2ND",
+                ],
+            ),
+            (
+                lower,
+                [
+                    "hello world",
+                    "straße",
+                    "όσος καλός",
+                    "ǆemal o'neil-smith 2nd",
+                ],
+            ),
+            (
+                initcap,
+                [
+                    "Hello World",
+                    "Straße",
+                    "Όσος Καλός",
+                    "Ǆemal O'Neil-Smith 2nd",
+                ],
+            ),
+        ];
+        for (kernel, [a, b, c, d]) in cases {
+            let expected = vec![Some(a), None, Some(b), Some(c), Some(d), Some("")];
+            let array = StringArray::from(input.clone());
+            let result = kernel(&array).unwrap();
+            assert_eq!(
+                result.as_string::<i32>(),
+                &StringArray::from(expected.clone())
+            );
+
+            let large = LargeStringArray::from(input.clone());
+            let result = kernel(&large).unwrap();
+            assert_eq!(result.as_string::<i64>(), &LargeStringArray::from(expected));
+        }
+
+        // ASCII values of sliced arrays
+        let array = StringArray::from(input);
+        let result = initcap(&array.slice(4, 2)).unwrap();
+        assert_eq!(result.len(), 2);
+        let result = upper(&array.slice(0, 2)).unwrap();
+        let expected = StringArray::from(vec![Some("HELLO WORLD"), None]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let result = initcap(&StringArray::from(vec!["a1b c_d", "", "x"])).unwrap();
+        let expected = StringArray::from(vec!["A1b C_D", "", "X"]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let result = upper(&dict).unwrap();
+        let result = result.as_dictionary::<Int32Type>();
+        assert_eq!(result.keys(), dict.keys());
+        let values = result.values().as_string::<i32>();
+        assert_eq!(values, &StringArray::from(vec!["A", "B"]));
+
+        let err = lower(&Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: lower not supported for Int32"
+        );
+    }
+}
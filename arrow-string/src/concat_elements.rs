@@ -18,9 +18,11 @@
 use std::sync::Arc;
 
 use arrow_array::builder::BufferBuilder;
+use arrow_array::cast::AsArray;
 use arrow_array::types::ByteArrayType;
 use arrow_array::*;
-use arrow_buffer::{ArrowNativeType, NullBuffer};
+use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer};
+use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayDataBuilder;
 use arrow_schema::{ArrowError, DataType};
 
@@ -45,11 +47,12 @@ pub fn concat_elements_bytes<T: ByteArrayType>(
     let left_values = left.value_data();
     let right_values = right.value_data();
 
-    let mut output_values = BufferBuilder::<u8>::new(
-        left_values.len() + right_values.len()
-            - left_offsets[0].as_usize()
-            - right_offsets[0].as_usize(),
-    );
+    // The exact length of the output values, excluding any values outside the
+    // range of the offsets, e.g. if the arrays are sliced
+    let value_len =
+        |offsets: &[T::Offset]| offsets[offsets.len() - 1].as_usize() - offsets[0].as_usize();
+    let mut output_values =
+        BufferBuilder::<u8>::new(value_len(left_offsets) + value_len(right_offsets));
 
     let mut output_offsets = BufferBuilder::<T::Offset>::new(left_offsets.len());
     output_offsets.append(T::Offset::usize_as(0));
@@ -167,6 +170,77 @@ pub fn concat_elements_utf8_many<Offset: OffsetSizeTrait>(
     Ok(unsafe { builder.build_unchecked() }.into())
 }
 
+/// Returns the elementwise concatenation of a [`GenericListArray`], appending the
+/// elements of each list in `right` to those of the corresponding list in `left`
+///
+/// An index of the resulting [`GenericListArray`] is null if either of the lists
+/// are null at that location.
+///
+/// ```text
+/// e.g:
+///
+///   [[1, 2], [3]] + [[4], None] = [[1, 2, 4], None]
+/// ```
+///
+/// An error will be returned if `left` and `right` have different lengths or
+/// value types
+pub fn concat_elements_list<O: OffsetSizeTrait>(
+    left: &GenericListArray<O>,
+    right: &GenericListArray<O>,
+) -> Result<GenericListArray<O>, ArrowError> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length: {} != {}",
+            left.len(),
+            right.len()
+        )));
+    }
+    if left.value_type() != right.value_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot concat lists of different value types: {} != {}",
+            left.value_type(),
+            right.value_type()
+        )));
+    }
+
+    let nulls = NullBuffer::union(left.nulls(), right.nulls());
+
+    let left_offsets = left.value_offsets();
+    let right_offsets = right.value_offsets();
+    let value_len = |offsets: &[O]| offsets[offsets.len() - 1].as_usize() - offsets[0].as_usize();
+    let capacity = value_len(left_offsets) + value_len(right_offsets);
+    if O::from_usize(capacity).is_none() {
+        return Err(ArrowError::ComputeError(format!(
+            "Concatenated lists with {capacity} values overflow the offset type"
+        )));
+    }
+
+    let left_values = left.values().to_data();
+    let right_values = right.values().to_data();
+    let mut values = MutableArrayData::new(vec![&left_values, &right_values], false, capacity);
+
+    let mut output_offsets = BufferBuilder::<O>::new(left_offsets.len());
+    output_offsets.append(O::usize_as(0));
+    let mut len = 0;
+    for (left_idx, right_idx) in left_offsets.windows(2).zip(right_offsets.windows(2)) {
+        let (left_start, left_end) = (left_idx[0].as_usize(), left_idx[1].as_usize());
+        let (right_start, right_end) = (right_idx[0].as_usize(), right_idx[1].as_usize());
+        values.extend(0, left_start, left_end);
+        values.extend(1, right_start, right_end);
+        len += (left_end - left_start) + (right_end - right_start);
+        output_offsets.append(O::usize_as(len));
+    }
+
+    let offsets = OffsetBuffer::new(output_offsets.finish().into());
+    let field = match left.data_type() {
+        DataType::List(f) | DataType::LargeList(f) => f.clone(),
+        _ => unreachable!(),
+    };
+    GenericListArray::try_new(field, offsets, make_array(values.freeze()), nulls)
+}
+
+/// Returns the elementwise concatenation of two arrays of the same type, see
+/// [`concat_elements_bytes`] and [`concat_elements_list`]
 pub fn concat_elements_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef, ArrowError> {
     if left.data_type() != right.data_type() {
         return Err(ArrowError::ComputeError(format!(
@@ -196,6 +270,14 @@ pub fn concat_elements_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayR
             let right = right.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
             Ok(Arc::new(concat_element_binary(left, right).unwrap()))
         }
+        (DataType::List(_), DataType::List(_)) => Ok(Arc::new(concat_elements_list(
+            left.as_list::<i32>(),
+            right.as_list::<i32>(),
+        )?)),
+        (DataType::LargeList(_), DataType::LargeList(_)) => Ok(Arc::new(concat_elements_list(
+            left.as_list::<i64>(),
+            right.as_list::<i64>(),
+        )?)),
         // unimplemented
         _ => Err(ArrowError::NotYetImplemented(format!(
             "concat not supported for {}",
@@ -204,6 +286,145 @@ pub fn concat_elements_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayR
     }
 }
 
+/// Returns the elementwise concatenation of `arrays`, separated by `separator`, as
+/// SQL `CONCAT_WS`
+///
+/// Unlike [`concat_elements_utf8_many`], null values are skipped, along with their
+/// separator. An index of the result is only null if `separator` is null at that location.
+///
+/// ```text
+/// e.g:
+///
+///   concat_ws(",", ["a", None, None], ["b", "c", None]) = ["a,b", "c", ""]
+/// ```
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8, with `separator`
+///   and `arrays` all of the same type
+/// * `separator` and `arrays` may be either a [`Scalar`] or an array, with arrays of the
+///   same length
+///
+/// An error will be returned if `arrays` is empty
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::concat_elements::concat_ws;
+/// let a = StringArray::from(vec![Some("a"), None, None]);
+/// let b = StringArray::from(vec![Some("b"), Some("c"), None]);
+/// let separator = StringArray::new_scalar(",");
+/// let result = concat_ws(&separator, &[&a, &b]).unwrap();
+/// assert_eq!(result.as_ref(), &StringArray::from(vec!["a,b", "c", ""]));
+/// ```
+pub fn concat_ws(separator: &dyn Datum, arrays: &[&dyn Datum]) -> Result<ArrayRef, ArrowError> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat_ws requires input of at least one array".to_string(),
+        ));
+    }
+
+    let separator = separator.get();
+    let arrays: Vec<_> = arrays.iter().map(|a| a.get()).collect();
+    let data_type = separator.0.data_type();
+    let mut len = None;
+    for (array, scalar) in std::iter::once(separator).chain(arrays.iter().copied()) {
+        if array.data_type() != data_type {
+            return Err(ArrowError::ComputeError(format!(
+                "Cannot concat arrays of different types: {} != {}",
+                data_type,
+                array.data_type()
+            )));
+        }
+        match len {
+            Some(len) if !scalar && len != array.len() => {
+                return Err(ArrowError::ComputeError(format!(
+                    "Arrays must have the same length: {} != {}",
+                    len,
+                    array.len()
+                )))
+            }
+            _ if !scalar => len = Some(array.len()),
+            _ => {}
+        }
+    }
+    let len = len.unwrap_or(1);
+
+    match data_type {
+        DataType::Utf8 => {
+            let arrays: Vec<_> = arrays.iter().map(|(a, s)| (a.as_string(), *s)).collect();
+            let separator = (separator.0.as_string(), separator.1);
+            Ok(Arc::new(concat_ws_impl::<i32>(len, separator, &arrays)?))
+        }
+        DataType::LargeUtf8 => {
+            let arrays: Vec<_> = arrays.iter().map(|(a, s)| (a.as_string(), *s)).collect();
+            let separator = (separator.0.as_string(), separator.1);
+            Ok(Arc::new(concat_ws_impl::<i64>(len, separator, &arrays)?))
+        }
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "concat_ws not supported for {data_type}"
+        ))),
+    }
+}
+
+/// A possibly scalar [`GenericStringArray`]
+type StringDatum<'a, O> = (&'a GenericStringArray<O>, bool);
+
+fn concat_ws_impl<O: OffsetSizeTrait>(
+    len: usize,
+    separator: StringDatum<'_, O>,
+    arrays: &[StringDatum<'_, O>],
+) -> Result<GenericStringArray<O>, ArrowError> {
+    let index = |(_, scalar): StringDatum<'_, O>, idx: usize| if scalar { 0 } else { idx };
+    let is_valid = |a: StringDatum<'_, O>, idx: usize| a.0.is_valid(index(a, idx));
+
+    let nulls = match separator {
+        (s, true) if s.is_null(0) => return Ok(GenericStringArray::new_null(len)),
+        (_, true) => None,
+        (s, false) => s.nulls().cloned(),
+    };
+
+    // Compute the exact length of the output values, to allocate them once
+    let mut capacity = 0;
+    for idx in (0..len).filter(|idx| is_valid(separator, *idx)) {
+        let valid = arrays.iter().filter(|a| is_valid(**a, idx));
+        let (count, bytes) = valid.fold((0_usize, 0), |(count, bytes), a| {
+            (
+                count + 1,
+                bytes + a.0.value_length(index(*a, idx)).as_usize(),
+            )
+        });
+        let separator_len = separator.0.value_length(index(separator, idx)).as_usize();
+        capacity += bytes + count.saturating_sub(1) * separator_len;
+    }
+    if O::from_usize(capacity).is_none() {
+        return Err(ArrowError::ComputeError(format!(
+            "Concatenated strings with {capacity} bytes overflow the offset type"
+        )));
+    }
+
+    let mut values = BufferBuilder::<u8>::new(capacity);
+    let mut offsets = BufferBuilder::<O>::new(len + 1);
+    offsets.append(O::usize_as(0));
+    for idx in 0..len {
+        if is_valid(separator, idx) {
+            let separator = separator.0.value(index(separator, idx));
+            let valid = arrays.iter().filter(|a| is_valid(**a, idx));
+            for (i, a) in valid.enumerate() {
+                if i != 0 {
+                    values.append_slice(separator.as_bytes());
+                }
+                values.append_slice(a.0.value(index(*a, idx)).as_bytes());
+            }
+        }
+        offsets.append(O::usize_as(values.len()));
+    }
+
+    // SAFETY: offsets are monotonic and within the values, which are the concatenation
+    // of valid UTF-8 strings
+    Ok(unsafe {
+        let offsets = OffsetBuffer::new_unchecked(offsets.finish().into());
+        GenericStringArray::new_unchecked(offsets, values.finish(), nulls)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -350,6 +571,78 @@ mod tests {
         assert_eq!(output, expected);
     }
 
+    #[test]
+    fn test_concat_ws() {
+        let a = StringArray::from(vec![Some("a"), None, None, Some(""), Some("é")]);
+        let b = StringArray::from(vec![Some("b"), Some("c"), None, Some(""), None]);
+        let separator = StringArray::new_scalar("::");
+        let x = StringArray::new_scalar("x");
+
+        let output = concat_ws(&separator, &[&a, &b, &x]).unwrap();
+        let expected = StringArray::from(vec!["a::b::x", "c::x", "x", "::::x", "é::x"]);
+        assert_eq!(output.as_string::<i32>(), &expected);
+
+        let output = concat_ws(&separator, &[&a.slice(1, 3), &b.slice(1, 3)]).unwrap();
+        let expected = StringArray::from(vec!["c", "", "::"]);
+        assert_eq!(output.as_string::<i32>(), &expected);
+
+        // Null separators produce nulls
+        let separator = StringArray::from(vec![Some("-"), None, Some(""), Some("-"), Some("-")]);
+        let output = concat_ws(&separator, &[&a, &b]).unwrap();
+        let expected = StringArray::from(vec![Some("a-b"), None, Some(""), Some("-"), Some("é")]);
+        assert_eq!(output.as_string::<i32>(), &expected);
+
+        let null = Scalar::new(StringArray::from(vec![None::<&str>]));
+        let output = concat_ws(&null, &[&a, &b]).unwrap();
+        assert_eq!(output.as_ref(), &StringArray::new_null(5));
+
+        // All scalar arguments produce a single value
+        let output = concat_ws(
+            &LargeStringArray::new_scalar(", "),
+            &[
+                &LargeStringArray::new_scalar("a"),
+                &LargeStringArray::new_scalar("b"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            output.as_string::<i64>(),
+            &LargeStringArray::from(vec!["a, b"])
+        );
+    }
+
+    #[test]
+    fn test_concat_ws_error() {
+        let a = StringArray::from(vec!["a", "b"]);
+        let separator = StringArray::new_scalar(",");
+
+        let err = concat_ws(&separator, &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: concat_ws requires input of at least one array"
+        );
+
+        let err = concat_ws(&separator, &[&a, &StringArray::from(vec!["c"])]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Arrays must have the same length: 2 != 1"
+        );
+
+        let err =
+            concat_ws(&separator, &[&a, &LargeStringArray::from(vec!["c", "d"])]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot concat arrays of different types: Utf8 != LargeUtf8"
+        );
+
+        let b = BinaryArray::from_vec(vec![b"a", b"b"]);
+        let err = concat_ws(&BinaryArray::new_scalar(b","), &[&b]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: concat_ws not supported for Binary"
+        );
+    }
+
     #[test]
     fn test_concat_dyn_same_type() {
         // test for StringArray
@@ -406,4 +699,72 @@ mod tests {
             "Compute error: Cannot concat arrays of different types: Utf8 != LargeUtf8".to_string()
         );
     }
+
+    #[test]
+    fn test_list_concat() {
+        use arrow_array::types::Int32Type;
+
+        let left = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(3)]),
+            Some(vec![Some(4), None]),
+        ]);
+        let right = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(5)]),
+            Some(vec![Some(6), Some(7)]),
+            Some(vec![Some(8)]),
+            None,
+            Some(vec![]),
+        ]);
+
+        let output = concat_elements_list(&left, &right).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(5)]),
+            Some(vec![Some(6), Some(7)]),
+            None,
+            None,
+            Some(vec![Some(4), None]),
+        ]);
+        assert_eq!(output, expected);
+
+        // Only the values of the sliced lists are copied
+        let output = concat_elements_list(&left.slice(3, 2), &right.slice(0, 2)).unwrap();
+        assert_eq!(output.values().len(), 6);
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(3), Some(5)]),
+            Some(vec![Some(4), None, Some(6), Some(7)]),
+        ]);
+        assert_eq!(output, expected);
+
+        let large_left =
+            LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        let large_right =
+            LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(2)])]);
+        let output = concat_elements_dyn(&large_left, &large_right).unwrap();
+        let expected = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![
+            Some(1),
+            Some(2),
+        ])]);
+        assert_eq!(output.as_list::<i64>(), &expected);
+
+        let err = concat_elements_list(&left, &right.slice(0, 2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Arrays must have the same length: 5 != 2"
+        );
+
+        let strings = ListArray::new(
+            Arc::new(arrow_schema::Field::new("item", DataType::Utf8, true)),
+            OffsetBuffer::new(vec![0, 0, 0, 0, 0, 0].into()),
+            Arc::new(StringArray::new_null(0)),
+            None,
+        );
+        let err = concat_elements_list(&left, &strings).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot concat lists of different value types: Int32 != Utf8"
+        );
+    }
 }
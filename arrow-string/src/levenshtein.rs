@@ -0,0 +1,245 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernel for the edit distance between string arrays
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, NullBuffer};
+use arrow_schema::{ArrowError, DataType};
+
+/// Returns an array of Int32/Int64 denoting the Levenshtein distance between the values
+/// of `left` and `right`
+///
+/// This is the minimum number of single character insertions, deletions or substitutions
+/// required to change one value into the other, with characters being unicode scalar
+/// values rather than bytes.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8, returning Int32 and
+///   Int64 respectively
+/// * `left` and `right` may be either a [`Scalar`] or an array, with arrays of the same length
+/// * levenshtein of null is null.
+///
+/// ```
+/// # use arrow_array::{Int32Array, StringArray};
+/// # use arrow_string::levenshtein::levenshtein;
+/// let array = StringArray::from(vec![Some("kitten"), Some("sitting"), Some("café"), None]);
+/// let result = levenshtein(&array, &StringArray::new_scalar("kitten")).unwrap();
+/// let expected = Int32Array::from(vec![Some(0), Some(3), Some(6), None]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn levenshtein(left: &dyn Datum, right: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let (l, l_s) = left.get();
+    let (r, r_s) = right.get();
+    if l.len() != r.len() && !l_s && !r_s {
+        return Err(ArrowError::ComputeError(format!(
+            "levenshtein() requires arguments of the same length, got {} and {}",
+            l.len(),
+            r.len()
+        )));
+    }
+
+    match (l.data_type(), r.data_type()) {
+        (DataType::Utf8, DataType::Utf8) => Ok(Arc::new(levenshtein_impl::<_, Int32Type>(
+            l.as_string::<i32>(),
+            l_s,
+            r.as_string::<i32>(),
+            r_s,
+        ))),
+        (DataType::LargeUtf8, DataType::LargeUtf8) => Ok(Arc::new(
+            levenshtein_impl::<_, Int64Type>(l.as_string::<i64>(), l_s, r.as_string::<i64>(), r_s),
+        )),
+        (l_t, r_t) => Err(ArrowError::ComputeError(format!(
+            "levenshtein() not supported for {l_t:?} and {r_t:?}"
+        ))),
+    }
+}
+
+fn levenshtein_impl<O: OffsetSizeTrait, P: ArrowPrimitiveType>(
+    l: &GenericStringArray<O>,
+    l_s: bool,
+    r: &GenericStringArray<O>,
+    r_s: bool,
+) -> PrimitiveArray<P> {
+    let len = match l_s {
+        true => r.len(),
+        false => l.len(),
+    };
+    if (l_s && l.is_null(0)) || (r_s && r.is_null(0)) {
+        return PrimitiveArray::new_null(len);
+    }
+    let l_nulls = l.nulls().filter(|_| !l_s);
+    let r_nulls = r.nulls().filter(|_| !r_s);
+    let nulls = NullBuffer::union(l_nulls, r_nulls);
+
+    let mut scratch = Scratch::default();
+    let values = (0..len).map(|idx| match nulls.as_ref().map(|n| n.is_null(idx)) {
+        Some(true) => P::Native::default(),
+        _ => {
+            let a = l.value(if l_s { 0 } else { idx });
+            let b = r.value(if r_s { 0 } else { idx });
+            // The distance is at most the length of the longer value
+            P::Native::usize_as(scratch.distance(a, b))
+        }
+    });
+    PrimitiveArray::new(values.collect(), nulls)
+}
+
+/// Buffers reused across the values of an array
+#[derive(Debug, Default)]
+struct Scratch {
+    row: Vec<usize>,
+    a: Vec<char>,
+    b: Vec<char>,
+}
+
+impl Scratch {
+    fn distance(&mut self, a: &str, b: &str) -> usize {
+        if a.is_ascii() && b.is_ascii() {
+            return edit_distance(a.as_bytes(), b.as_bytes(), &mut self.row);
+        }
+        self.a.clear();
+        self.a.extend(a.chars());
+        self.b.clear();
+        self.b.extend(b.chars());
+        edit_distance(&self.a, &self.b, &mut self.row)
+    }
+}
+
+/// Computes the edit distance between `a` and `b`, retaining a single row of the
+/// dynamic programming matrix in `row`
+fn edit_distance<T: PartialEq>(a: &[T], b: &[T], row: &mut Vec<usize>) -> usize {
+    // Common prefixes and suffixes do not contribute to the distance
+    let prefix = a.iter().zip(b).take_while(|(a, b)| a == b).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+    if a.is_empty() || b.is_empty() {
+        return a.len().max(b.len());
+    }
+
+    // row[j] is the distance between the first i characters of a and j + 1 of b
+    row.clear();
+    row.extend(1..=b.len());
+    for (i, a) in a.iter().enumerate() {
+        let mut diagonal = i;
+        let mut left = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitute = diagonal + (a != b) as usize;
+            diagonal = row[j];
+            left = substitute.min(diagonal + 1).min(left + 1);
+            row[j] = left;
+        }
+    }
+    row[b.len() - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        let left = StringArray::from(vec![
+            Some("kitten"),
+            Some("flaw"),
+            Some(""),
+            Some("abc"),
+            None,
+            Some("café"),
+            Some("日本"),
+            Some("abcdef"),
+            Some("abc"),
+        ]);
+        let right = StringArray::from(vec![
+            Some("sitting"),
+            Some("lawn"),
+            Some("abc"),
+            Some(""),
+            Some("abc"),
+            Some("cafe"),
+            Some("日本語"),
+            Some("azcdxf"),
+            None,
+        ]);
+        let result = levenshtein(&left, &right).unwrap();
+        let expected = Int32Array::from(vec![
+            Some(3),
+            Some(2),
+            Some(3),
+            Some(3),
+            None,
+            Some(1),
+            Some(1),
+            Some(2),
+            None,
+        ]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        // The distance is symmetric
+        let result = levenshtein(&right, &left).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let result = levenshtein(&StringArray::new_scalar("abc"), &left).unwrap();
+        let expected = Int32Array::from(vec![
+            Some(6),
+            Some(4),
+            Some(3),
+            Some(0),
+            None,
+            Some(3),
+            Some(3),
+            Some(3),
+            Some(0),
+        ]);
+        assert_eq!(result.as_primitive::<Int32Type>(), &expected);
+
+        let null = Scalar::new(StringArray::from(vec![None::<&str>]));
+        let result = levenshtein(&left, &null).unwrap();
+        assert_eq!(result.as_ref(), &Int32Array::new_null(left.len()));
+
+        let left = LargeStringArray::from(vec!["saturday", "ß"]);
+        let right = LargeStringArray::new_scalar("sunday");
+        let result = levenshtein(&left, &right).unwrap();
+        let expected = Int64Array::from(vec![3, 6]);
+        assert_eq!(result.as_primitive::<Int64Type>(), &expected);
+    }
+
+    #[test]
+    fn test_levenshtein_errors() {
+        let left = StringArray::from(vec!["a", "b"]);
+        let err = levenshtein(&left, &StringArray::from(vec!["a"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: levenshtein() requires arguments of the same length, got 2 and 1"
+        );
+
+        let err = levenshtein(&left, &LargeStringArray::from(vec!["a", "b"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: levenshtein() not supported for Utf8 and LargeUtf8"
+        );
+    }
+}
@@ -17,9 +17,16 @@
 
 //! Arrow string kernels
 
+pub mod case;
 pub mod concat_elements;
 pub mod length;
+pub mod levenshtein;
 pub mod like;
+#[cfg(feature = "normalize")]
+pub mod normalize;
+pub mod pad;
 mod predicate;
 pub mod regexp;
+pub mod replace;
+pub mod split;
 pub mod substring;
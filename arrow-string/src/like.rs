@@ -92,16 +92,38 @@ pub fn nilike(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray, Arrow
 }
 
 /// Perform SQL `STARTSWITH(left, right)`
+///
+/// Unlike [`like`], `right` is not interpreted as a pattern, and so need not be escaped.
+/// Either side may be a [`Scalar`], which is compared with every row of the other
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Scalar, StringArray};
+/// # use arrow_string::like::starts_with;
+/// let strings = StringArray::from(vec![Some("arrow"), Some("parquet"), None, Some("50%")]);
+///
+/// let result = starts_with(&strings, &StringArray::new_scalar("ar")).unwrap();
+/// let expected = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+/// assert_eq!(result, expected);
+///
+/// let prefixes = StringArray::from(vec!["a", "par", "x", "50%"]);
+/// let result = starts_with(&strings, &prefixes).unwrap();
+/// let expected = BooleanArray::from(vec![Some(true), Some(true), None, Some(true)]);
+/// assert_eq!(result, expected);
+/// ```
 pub fn starts_with(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     like_op(Op::StartsWith, left, right)
 }
 
 /// Perform SQL `ENDSWITH(left, right)`
+///
+/// See the documentation on [`starts_with`] for more details
 pub fn ends_with(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     like_op(Op::EndsWith, left, right)
 }
 
 /// Perform SQL `CONTAINS(left, right)`
+///
+/// See the documentation on [`starts_with`] for more details
 pub fn contains(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     like_op(Op::Contains, left, right)
 }
@@ -1552,4 +1574,59 @@ mod tests {
         assert_eq!(r.null_count(), 1);
         assert!(r.is_null(0));
     }
+
+    #[test]
+    fn test_starts_ends_contains_datum() {
+        let strings = StringArray::from(vec![Some("arrow"), None, Some("a_b%"), Some("")]);
+        let scalar = StringArray::new_scalar("a");
+        let kernels = [
+            (
+                starts_with as fn(&dyn Datum, &dyn Datum) -> _,
+                [true, true, false],
+            ),
+            (ends_with, [false, false, false]),
+            (contains, [true, true, false]),
+        ];
+        for (kernel, [a, b, c]) in kernels {
+            let expected = BooleanArray::from(vec![Some(a), None, Some(b), Some(c)]);
+            assert_eq!(kernel(&strings, &scalar).unwrap(), expected);
+
+            let dict: DictionaryArray<Int8Type> = strings.iter().collect();
+            assert_eq!(kernel(&dict, &scalar).unwrap(), expected);
+
+            let large = LargeStringArray::from_iter(strings.iter());
+            let large_scalar = LargeStringArray::new_scalar("a");
+            assert_eq!(kernel(&large, &large_scalar).unwrap(), expected);
+
+            let null = Scalar::new(StringArray::new_null(1));
+            assert_eq!(kernel(&strings, &null).unwrap().null_count(), 4);
+        }
+
+        // Patterns are matched literally
+        let patterns = StringArray::from(vec![Some("ar"), Some("x"), Some("_b%"), None]);
+        let result = contains(&strings, &patterns).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), None, Some(true), None]);
+        assert_eq!(result, expected);
+
+        // Scalar haystack, with an array of needles
+        let haystack = StringArray::new_scalar("arrow");
+        let needles = StringArray::from(vec!["ar", "ow", "rr", "x"]);
+        let result = starts_with(&haystack, &needles).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, false, false, false]));
+        let result = ends_with(&haystack, &needles).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false, true, false, false]));
+        let result = contains(&haystack, &needles).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, true, true, false]));
+
+        let err = contains(&strings, &needles.slice(0, 2)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot compare arrays of different lengths, got 4 vs 2"
+        );
+        let err = contains(&strings, &LargeStringArray::new_scalar("a")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid string operation: Utf8 CONTAINS LargeUtf8"
+        );
+    }
 }
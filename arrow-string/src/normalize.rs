@@ -0,0 +1,311 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels for unicode normalization and ASCII folding of string arrays
+//!
+//! User-entered text may encode the same string in several ways, for example `"é"` as
+//! the single code point `U+00E9` or as `"e"` followed by the combining acute accent
+//! `U+0301`. These kernels can be used to normalize such strings before comparing,
+//! deduplicating or joining on them.
+//!
+//! This requires the `normalize` feature
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use arrow_array::builder::GenericStringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::{is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick};
+use unicode_normalization::{IsNormalized, UnicodeNormalization};
+
+/// A unicode normalization form, see [UAX #15](https://www.unicode.org/reports/tr15/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical decomposition, followed by canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility decomposition, followed by canonical composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Returns `true` if `s` is known to be in this form without normalizing it
+    fn is_normalized_quick(&self, s: &str) -> bool {
+        let result = match self {
+            Self::Nfc => is_nfc_quick(s.chars()),
+            Self::Nfd => is_nfd_quick(s.chars()),
+            Self::Nfkc => is_nfkc_quick(s.chars()),
+            Self::Nfkd => is_nfkd_quick(s.chars()),
+        };
+        result == IsNormalized::Yes
+    }
+
+    fn write_normalized(&self, s: &str, out: &mut impl Write) -> std::fmt::Result {
+        match self {
+            Self::Nfc => s.nfc().try_for_each(|c| out.write_char(c)),
+            Self::Nfd => s.nfd().try_for_each(|c| out.write_char(c)),
+            Self::Nfkc => s.nfkc().try_for_each(|c| out.write_char(c)),
+            Self::Nfkd => s.nfkd().try_for_each(|c| out.write_char(c)),
+        }
+    }
+}
+
+/// Returns `array` with each value converted to the unicode normalization `form`
+///
+/// Values that are already normalized, including all ASCII values, are copied
+/// without being decomposed.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8,
+///   or DictionaryArray with above Arrays as values
+/// * normalize of null is null.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::normalize::{normalize, NormalizationForm};
+/// let array = StringArray::from(vec![Some("caf\u{e9}"), Some("cafe\u{301}"), None]);
+/// let result = normalize(&array, NormalizationForm::Nfc).unwrap();
+/// let expected = StringArray::from(vec![Some("caf\u{e9}"), Some("caf\u{e9}"), None]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn normalize(array: &dyn Array, form: NormalizationForm) -> Result<ArrayRef, ArrowError> {
+    if let Some(d) = array.as_any_dictionary_opt() {
+        let values = normalize(d.values().as_ref(), form)?;
+        return Ok(d.with_values(values));
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => Ok(Arc::new(normalize_impl(array.as_string::<i32>(), form))),
+        DataType::LargeUtf8 => Ok(Arc::new(normalize_impl(array.as_string::<i64>(), form))),
+        other => Err(ArrowError::ComputeError(format!(
+            "normalize not supported for {other:?}"
+        ))),
+    }
+}
+
+/// Returns `array` with accents removed from each value, and other characters with a
+/// common ASCII equivalent replaced by it, such as `"Ærøskøbing"` to `"AEroskobing"`
+///
+/// Values are decomposed to [`NormalizationForm::Nfkd`], combining marks removed, and
+/// letters without a decomposition, such as `ß`, `ø` and `ł`, and typographic quotes
+/// and dashes replaced by their ASCII equivalents. Any other character is retained,
+/// so the result is not guaranteed to be ASCII.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8,
+///   or DictionaryArray with above Arrays as values
+/// * ascii_fold of null is null.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::normalize::ascii_fold;
+/// let array = StringArray::from(vec!["Crème Brûlée", "Straße", "日本"]);
+/// let result = ascii_fold(&array).unwrap();
+/// let expected = StringArray::from(vec!["Creme Brulee", "Strasse", "日本"]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn ascii_fold(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    if let Some(d) = array.as_any_dictionary_opt() {
+        let values = ascii_fold(d.values().as_ref())?;
+        return Ok(d.with_values(values));
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => Ok(Arc::new(ascii_fold_impl(array.as_string::<i32>()))),
+        DataType::LargeUtf8 => Ok(Arc::new(ascii_fold_impl(array.as_string::<i64>()))),
+        other => Err(ArrowError::ComputeError(format!(
+            "ascii_fold not supported for {other:?}"
+        ))),
+    }
+}
+
+fn normalize_impl<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    form: NormalizationForm,
+) -> GenericStringArray<O> {
+    map_non_ascii(array, |s, out| {
+        match form.is_normalized_quick(s) {
+            true => out.write_str(s),
+            false => form.write_normalized(s, out),
+        }
+        .unwrap()
+    })
+}
+
+fn ascii_fold_impl<O: OffsetSizeTrait>(array: &GenericStringArray<O>) -> GenericStringArray<O> {
+    map_non_ascii(array, |s, out| {
+        for c in s.nfkd().filter(|c| !is_combining_mark(*c)) {
+            match fold_char(c) {
+                Some(folded) => out.write_str(folded),
+                None => out.write_char(c),
+            }
+            .unwrap()
+        }
+    })
+}
+
+/// Returns the ASCII equivalent of characters not folded by compatibility decomposition
+fn fold_char(c: char) -> Option<&'static str> {
+    Some(match c {
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ø' => "O",
+        'ø' => "o",
+        'Ł' => "L",
+        'ł' => "l",
+        'Đ' | 'Ð' => "D",
+        'đ' | 'ð' => "d",
+        'Þ' => "TH",
+        'þ' => "th",
+        'ß' => "ss",
+        'ı' => "i",
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' => "\"",
+        '\u{2010}'..='\u{2015}' => "-",
+        _ => return None,
+    })
+}
+
+/// Applies `f` to each non-null, non-ASCII value of `array`, copying ASCII values
+fn map_non_ascii<O, F>(array: &GenericStringArray<O>, mut f: F) -> GenericStringArray<O>
+where
+    O: OffsetSizeTrait,
+    F: FnMut(&str, &mut GenericStringBuilder<O>),
+{
+    let offsets = array.value_offsets();
+    let start = offsets[0].as_usize();
+    let end = offsets[array.len()].as_usize();
+    if array.value_data()[start..end].is_ascii() {
+        return array.clone();
+    }
+
+    let mut builder = GenericStringBuilder::with_capacity(array.len(), end - start);
+    for value in array.iter() {
+        match value {
+            Some(s) if s.is_ascii() => builder.append_value(s),
+            Some(s) => {
+                f(s, &mut builder);
+                builder.append_value("")
+            }
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    #[test]
+    fn test_normalize() {
+        let input = vec![
+            Some("caf\u{e9}"),
+            Some("cafe\u{301}"),
+            None,
+            Some("\u{fb01}le"),
+            Some("plain"),
+            Some(""),
+        ];
+        let array = StringArray::from(input.clone());
+        let cases = [
+            (
+                NormalizationForm::Nfc,
+                ["caf\u{e9}", "caf\u{e9}", "\u{fb01}le"],
+            ),
+            (
+                NormalizationForm::Nfd,
+                ["cafe\u{301}", "cafe\u{301}", "\u{fb01}le"],
+            ),
+            (NormalizationForm::Nfkc, ["caf\u{e9}", "caf\u{e9}", "file"]),
+            (
+                NormalizationForm::Nfkd,
+                ["cafe\u{301}", "cafe\u{301}", "file"],
+            ),
+        ];
+        for (form, [a, b, c]) in cases {
+            let expected = vec![Some(a), Some(b), None, Some(c), Some("plain"), Some("")];
+            let result = normalize(&array, form).unwrap();
+            assert_eq!(
+                result.as_string::<i32>(),
+                &StringArray::from(expected.clone())
+            );
+
+            let large = LargeStringArray::from(input.clone());
+            let result = normalize(&large, form).unwrap();
+            assert_eq!(result.as_string::<i64>(), &LargeStringArray::from(expected));
+        }
+
+        // Sliced arrays only consider their own values
+        let sliced = array.slice(4, 2);
+        let result = normalize(&sliced, NormalizationForm::Nfd).unwrap();
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec!["plain", ""])
+        );
+
+        let err = normalize(&Int32Array::from(vec![1]), NormalizationForm::Nfc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: normalize not supported for Int32"
+        );
+    }
+
+    #[test]
+    fn test_ascii_fold() {
+        let array = StringArray::from(vec![
+            Some("Ærøskøbing"),
+            None,
+            Some("Łódź"),
+            Some("\u{201C}na\u{ef}ve\u{201D} \u{2014} fa\u{e7}ade"),
+            Some("\u{2460} \u{fb01}"),
+            Some("Ελλάδα"),
+            Some("ascii"),
+        ]);
+        let result = ascii_fold(&array).unwrap();
+        let expected = StringArray::from(vec![
+            Some("AEroskobing"),
+            None,
+            Some("Lodz"),
+            Some("\"naive\" - facade"),
+            Some("1 fi"),
+            Some("Ελλαδα"),
+            Some("ascii"),
+        ]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let dict: DictionaryArray<Int32Type> = vec!["é", "e", "é"].into_iter().collect();
+        let result = ascii_fold(&dict).unwrap();
+        let result = result.as_dictionary::<Int32Type>();
+        assert_eq!(result.keys(), dict.keys());
+        let values = result.values().as_string::<i32>();
+        assert_eq!(values, &StringArray::from(vec!["e", "e"]));
+
+        let err = ascii_fold(&Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: ascii_fold not supported for Int32"
+        );
+    }
+}
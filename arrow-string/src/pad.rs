@@ -0,0 +1,390 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to pad string arrays to a length, as SQL `LPAD` and `RPAD`
+//!
+//! Lengths are measured in characters, rather than bytes
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use arrow_array::builder::GenericStringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+
+/// Returns `array` with each value padded on the left to `length` characters, by
+/// repeating `fill`, or a space if `None`
+///
+/// Values longer than `length` are truncated to their first `length` characters, and
+/// values are truncated, but not padded, if `fill` is empty. A negative `length` is
+/// treated as `0`.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8,
+///   or DictionaryArray with above Arrays as values
+/// * `length` must be Int64, and `fill` the same type as `array`, or its values
+/// * `length` and `fill` may be either a [`Scalar`] or an array of the same length as `array`
+/// * lpad of null, or with a null `length` or `fill`, is null.
+///
+/// ```
+/// # use arrow_array::{Int64Array, StringArray};
+/// # use arrow_string::pad::lpad;
+/// let array = StringArray::from(vec![Some("7"), Some("1234"), Some("héllo"), None]);
+/// let length = Int64Array::new_scalar(3);
+/// let fill = StringArray::new_scalar("0");
+/// let result = lpad(&array, &length, Some(&fill)).unwrap();
+/// let expected = StringArray::from(vec![Some("007"), Some("123"), Some("hél"), None]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn lpad(
+    array: &dyn Array,
+    length: &dyn Datum,
+    fill: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    pad(Side::Left, array, length, fill)
+}
+
+/// Returns `array` with each value padded on the right to `length` characters, by
+/// repeating `fill`, or a space if `None`
+///
+/// See the documentation on [`lpad`] for more details
+///
+/// ```
+/// # use arrow_array::{Int64Array, StringArray};
+/// # use arrow_string::pad::rpad;
+/// let array = StringArray::from(vec!["a", "ab", "abcd"]);
+/// let length = Int64Array::from(vec![5, 3, 2]);
+/// let fill = StringArray::new_scalar("xy");
+/// let result = rpad(&array, &length, Some(&fill)).unwrap();
+/// let expected = StringArray::from(vec!["axyxy", "abx", "ab"]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn rpad(
+    array: &dyn Array,
+    length: &dyn Datum,
+    fill: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    pad(Side::Right, array, length, fill)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Left => "lpad",
+            Self::Right => "rpad",
+        }
+    }
+}
+
+fn pad(
+    side: Side,
+    array: &dyn Array,
+    length: &dyn Datum,
+    fill: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    let name = side.name();
+    let (length, length_scalar) = length.get();
+    let fill = fill.map(|f| f.get());
+    if length.data_type() != &DataType::Int64 {
+        return Err(ArrowError::ComputeError(format!(
+            "{name}() requires length to be Int64, got {}",
+            length.data_type()
+        )));
+    }
+
+    if let Some(d) = array.as_any_dictionary_opt() {
+        let scalar_args = length_scalar && fill.map(|(_, s)| s).unwrap_or(true);
+        return match scalar_args {
+            true => {
+                let values = pad_args(side, d.values().as_ref(), length, true, fill)?;
+                Ok(d.with_values(values))
+            }
+            false => {
+                let values = take(d.values().as_ref(), d.keys(), None)?;
+                pad_args(side, values.as_ref(), length, false, fill)
+            }
+        };
+    }
+
+    pad_args(side, array, length, length_scalar, fill)
+}
+
+fn pad_args(
+    side: Side,
+    array: &dyn Array,
+    length: &dyn Array,
+    length_scalar: bool,
+    fill: Option<(&dyn Array, bool)>,
+) -> Result<ArrayRef, ArrowError> {
+    let name = side.name();
+    if let Some((fill, _)) = fill {
+        if fill.data_type() != array.data_type() {
+            return Err(ArrowError::ComputeError(format!(
+                "{name}() requires fill to be {}, got {}",
+                array.data_type(),
+                fill.data_type()
+            )));
+        }
+    }
+    let lengths = [Some((length, length_scalar)), fill];
+    for (arg, _) in lengths.into_iter().flatten().filter(|(_, s)| !s) {
+        if arg.len() != array.len() {
+            return Err(ArrowError::ComputeError(format!(
+                "{name}() requires arguments of the same length, got {} and {}",
+                array.len(),
+                arg.len()
+            )));
+        }
+    }
+
+    let length = length.as_primitive::<Int64Type>();
+    match array.data_type() {
+        DataType::Utf8 => {
+            let fill = fill.map(|(f, s)| (f.as_string::<i32>(), s));
+            let array = array.as_string::<i32>();
+            Ok(Arc::new(pad_impl(
+                side,
+                array,
+                length,
+                length_scalar,
+                fill,
+            )?))
+        }
+        DataType::LargeUtf8 => {
+            let fill = fill.map(|(f, s)| (f.as_string::<i64>(), s));
+            let array = array.as_string::<i64>();
+            Ok(Arc::new(pad_impl(
+                side,
+                array,
+                length,
+                length_scalar,
+                fill,
+            )?))
+        }
+        other => Err(ArrowError::ComputeError(format!(
+            "{name}() not supported for {other:?}"
+        ))),
+    }
+}
+
+fn pad_impl<O: OffsetSizeTrait>(
+    side: Side,
+    array: &GenericStringArray<O>,
+    length: &Int64Array,
+    length_scalar: bool,
+    fill: Option<(&GenericStringArray<O>, bool)>,
+) -> Result<GenericStringArray<O>, ArrowError> {
+    fn value_at<O: OffsetSizeTrait>(
+        a: &GenericStringArray<O>,
+        scalar: bool,
+        idx: usize,
+    ) -> Option<&str> {
+        let idx = if scalar { 0 } else { idx };
+        a.is_valid(idx).then(|| a.value(idx))
+    }
+
+    let mut builder = GenericStringBuilder::with_capacity(array.len(), array.value_data().len());
+    for (idx, value) in array.iter().enumerate() {
+        let length_idx = if length_scalar { 0 } else { idx };
+        let target = length
+            .is_valid(length_idx)
+            .then(|| length.value(length_idx));
+        let fill = match fill {
+            Some((fill, scalar)) => value_at(fill, scalar, idx),
+            None => Some(" "),
+        };
+
+        match (value, target, fill) {
+            (Some(value), Some(target), Some(fill)) => {
+                if target > i32::MAX as i64 {
+                    return Err(ArrowError::ComputeError(format!(
+                        "{}() length {target} exceeds the maximum of {}",
+                        side.name(),
+                        i32::MAX
+                    )));
+                }
+                let target = target.max(0) as usize;
+                write_padded(side, value, target, fill, &mut builder).unwrap();
+                builder.append_value("")
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Writes `value` padded or truncated to `target` characters to `out`
+fn write_padded(
+    side: Side,
+    value: &str,
+    target: usize,
+    fill: &str,
+    out: &mut impl Write,
+) -> std::fmt::Result {
+    let (chars, truncated) = match value.is_ascii() {
+        true => (value.len(), &value[..value.len().min(target)]),
+        false => match value.char_indices().nth(target) {
+            Some((end, _)) => (target + 1, &value[..end]),
+            None => (value.chars().count(), value),
+        },
+    };
+    if chars >= target || fill.is_empty() {
+        return out.write_str(truncated);
+    }
+
+    let padding = target - chars;
+    match side {
+        Side::Left => {
+            write_fill(fill, padding, out)?;
+            out.write_str(value)
+        }
+        Side::Right => {
+            out.write_str(value)?;
+            write_fill(fill, padding, out)
+        }
+    }
+}
+
+/// Writes `n` characters of `fill`, repeated as necessary, to `out`
+fn write_fill(fill: &str, n: usize, out: &mut impl Write) -> std::fmt::Result {
+    if fill.is_ascii() {
+        for _ in 0..n / fill.len() {
+            out.write_str(fill)?;
+        }
+        return out.write_str(&fill[..n % fill.len()]);
+    }
+    fill.chars()
+        .cycle()
+        .take(n)
+        .try_for_each(|c| out.write_char(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    #[test]
+    fn test_pad() {
+        let array = StringArray::from(vec![
+            Some("abc"),
+            Some("héllo"),
+            None,
+            Some(""),
+            Some("日本語"),
+        ]);
+        let length = Int64Array::new_scalar(4);
+        let fill = StringArray::new_scalar("ñx");
+
+        let result = lpad(&array, &length, Some(&fill)).unwrap();
+        let expected = StringArray::from(vec![
+            Some("ñabc"),
+            Some("héll"),
+            None,
+            Some("ñxñx"),
+            Some("ñ日本語"),
+        ]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let result = rpad(&array, &length, None).unwrap();
+        let expected = StringArray::from(vec![
+            Some("abc "),
+            Some("héll"),
+            None,
+            Some("    "),
+            Some("日本語 "),
+        ]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let large = LargeStringArray::from(vec![Some("ab"), None]);
+        let fill = LargeStringArray::new_scalar("-");
+        let result = lpad(&large, &length, Some(&fill)).unwrap();
+        let expected = LargeStringArray::from(vec![Some("--ab"), None]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+
+        // Arrays of lengths and fills, with nulls, negative lengths and empty fills
+        let array = StringArray::from(vec!["ab", "ab", "ab", "ab", "abc"]);
+        let length = Int64Array::from(vec![Some(5), None, Some(-1), Some(4), Some(2)]);
+        let fill = StringArray::from(vec![Some("12"), Some("1"), Some("1"), None, Some("")]);
+        let result = rpad(&array, &length, Some(&fill)).unwrap();
+        let expected = StringArray::from(vec![Some("ab121"), None, Some(""), None, Some("ab")]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        // Dictionaries with scalar arguments pad their values
+        let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let result = lpad(&dict, &Int64Array::new_scalar(2), None).unwrap();
+        let result = result.as_dictionary::<Int32Type>();
+        assert_eq!(result.keys(), dict.keys());
+        let values = result.values().as_string::<i32>();
+        assert_eq!(values, &StringArray::from(vec![" a", " b"]));
+
+        let length = Int64Array::from(vec![1, 2, 3]);
+        let result = lpad(&dict, &length, None).unwrap();
+        let expected = StringArray::from(vec!["a", " b", "  a"]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_pad_errors() {
+        let array = StringArray::from(vec!["a", "b"]);
+        let cases: [(&dyn Datum, Option<&dyn Datum>, &str); 5] = [
+            (
+                &Int32Array::new_scalar(1),
+                None,
+                "lpad() requires length to be Int64, got Int32",
+            ),
+            (
+                &Int64Array::new_scalar(1),
+                Some(&LargeStringArray::new_scalar("x")),
+                "lpad() requires fill to be Utf8, got LargeUtf8",
+            ),
+            (
+                &Int64Array::from(vec![1]),
+                None,
+                "lpad() requires arguments of the same length, got 2 and 1",
+            ),
+            (
+                &Int64Array::new_scalar(1),
+                Some(&StringArray::from(vec!["x"])),
+                "lpad() requires arguments of the same length, got 2 and 1",
+            ),
+            (
+                &Int64Array::new_scalar(i64::MAX),
+                None,
+                "lpad() length 9223372036854775807 exceeds the maximum of 2147483647",
+            ),
+        ];
+        for (length, fill, expected) in cases {
+            let err = lpad(&array, length, fill).unwrap_err();
+            assert_eq!(err.to_string(), format!("Compute error: {expected}"));
+        }
+
+        let err = rpad(&Int32Array::from(vec![1]), &Int64Array::new_scalar(1), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: rpad() not supported for Int32"
+        );
+    }
+}
@@ -18,13 +18,18 @@
 //! Defines kernel to extract substrings based on a regular
 //! expression of a \[Large\]StringArray
 
-use arrow_array::builder::{BooleanBufferBuilder, GenericStringBuilder, ListBuilder};
+use arrow_array::builder::{
+    BooleanBufferBuilder, BooleanBuilder, GenericStringBuilder, ListBuilder,
+};
 use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
 use arrow_array::*;
-use arrow_buffer::NullBuffer;
-use arrow_data::{ArrayData, ArrayDataBuilder};
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use arrow_data::ArrayDataBuilder;
 use arrow_schema::{ArrowError, DataType, Field};
+use arrow_select::take::take;
 use regex::Regex;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -119,38 +124,7 @@ pub fn regexp_is_match_utf8_scalar<OffsetSize: OffsetSizeTrait>(
     regex: &str,
     flag: Option<&str>,
 ) -> Result<BooleanArray, ArrowError> {
-    let null_bit_buffer = array.nulls().map(|x| x.inner().sliced());
-    let mut result = BooleanBufferBuilder::new(array.len());
-
-    let pattern = match flag {
-        Some(flag) => format!("(?{flag}){regex}"),
-        None => regex.to_string(),
-    };
-    if pattern.is_empty() {
-        result.append_n(array.len(), true);
-    } else {
-        let re = Regex::new(pattern.as_str()).map_err(|e| {
-            ArrowError::ComputeError(format!("Regular expression did not compile: {e:?}"))
-        })?;
-        for i in 0..array.len() {
-            let value = array.value(i);
-            result.append(re.is_match(value));
-        }
-    }
-
-    let buffer = result.into();
-    let data = unsafe {
-        ArrayData::new_unchecked(
-            DataType::Boolean,
-            array.len(),
-            None,
-            null_bit_buffer,
-            0,
-            vec![buffer],
-            vec![],
-        )
-    };
-    Ok(BooleanArray::from(data))
+    Ok(RegexpMatcher::try_new(regex, flag)?.is_match(array))
 }
 
 fn regexp_array_match<OffsetSize: OffsetSizeTrait>(
@@ -303,12 +277,21 @@ fn regexp_scalar_match<OffsetSize: OffsetSizeTrait>(
 /// The flags parameter is an optional text string containing zero or more single-letter flags
 /// that change the function's behavior.
 ///
+/// `array` may also be a dictionary of strings, in which case a scalar pattern is
+/// matched once per dictionary value, see [`regexp_is_match`].
+///
 /// [regexp_match]: https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP
 pub fn regexp_match(
     array: &dyn Array,
     regex_array: &dyn Datum,
     flags_array: Option<&dyn Datum>,
 ) -> Result<ArrayRef, ArrowError> {
+    if let Some(dict) = array.as_any_dictionary_opt() {
+        return regexp_dictionary(dict, regex_array, |values| {
+            regexp_match(values, regex_array, flags_array)
+        });
+    }
+
     let (rhs, is_rhs_scalar) = regex_array.get();
 
     if array.data_type() != rhs.data_type() {
@@ -401,10 +384,597 @@ pub fn regexp_match(
     }
 }
 
+/// Appends the substrings of `value` split by `regex` to `list_builder`
+///
+/// Zero-length matches at the start or end of `value`, or immediately after a previous
+/// match, are ignored, such that an empty pattern splits `value` into its characters
+fn append_split<OffsetSize: OffsetSizeTrait>(
+    list_builder: &mut ListBuilder<GenericStringBuilder<OffsetSize>>,
+    regex: &Regex,
+    value: &str,
+) {
+    let mut start = 0;
+    let mut last_end = 0;
+    for m in regex.find_iter(value) {
+        if m.start() == m.end()
+            && (m.start() == 0 || m.start() == value.len() || m.start() == last_end)
+        {
+            continue;
+        }
+        list_builder.values().append_value(&value[start..m.start()]);
+        start = m.end();
+        last_end = m.end();
+    }
+    list_builder.values().append_value(&value[start..]);
+    list_builder.append(true);
+}
+
+/// The regular expression of each row of a kernel accepting a scalar or array
+/// pattern, with optional flags
+enum Patterns<'a, OffsetSize: OffsetSizeTrait> {
+    /// A regex shared by all rows, or `None` if the pattern is null
+    Scalar(Option<Cow<'a, Regex>>),
+    /// A pattern per row
+    Array {
+        patterns: &'a GenericStringArray<OffsetSize>,
+        flags: Option<&'a GenericStringArray<OffsetSize>>,
+        /// The compiled regexes, keyed by pattern including flags
+        cache: HashMap<String, Regex>,
+    },
+}
+
+impl<'a, OffsetSize: OffsetSizeTrait> Patterns<'a, OffsetSize> {
+    fn try_new(
+        patterns: &'a dyn Array,
+        flags: Option<&'a dyn Array>,
+        is_scalar: bool,
+    ) -> Result<Self, ArrowError> {
+        let patterns = patterns.as_string();
+        let flags = flags.map(|f| f.as_string());
+        if !is_scalar {
+            let cache = HashMap::new();
+            return Ok(Self::Array {
+                patterns,
+                flags,
+                cache,
+            });
+        }
+        match row_pattern(patterns, flags, 0) {
+            Some(pattern) => Ok(Self::Scalar(Some(Cow::Owned(compile_regex(&pattern)?)))),
+            None => Ok(Self::Scalar(None)),
+        }
+    }
+
+    /// Returns the regex of row `idx`, if not null
+    fn get(&mut self, idx: usize) -> Result<Option<&Regex>, ArrowError> {
+        match self {
+            Self::Scalar(regex) => Ok(regex.as_deref()),
+            Self::Array {
+                patterns,
+                flags,
+                cache,
+            } => {
+                let pattern = match row_pattern(patterns, *flags, idx) {
+                    Some(pattern) => pattern,
+                    None => return Ok(None),
+                };
+                if !cache.contains_key(&pattern) {
+                    let regex = compile_regex(&pattern)?;
+                    cache.insert(pattern.clone(), regex);
+                }
+                Ok(cache.get(&pattern))
+            }
+        }
+    }
+}
+
+/// Returns the pattern of row `idx` including its flags, if not null
+fn row_pattern<OffsetSize: OffsetSizeTrait>(
+    patterns: &GenericStringArray<OffsetSize>,
+    flags: Option<&GenericStringArray<OffsetSize>>,
+    idx: usize,
+) -> Option<String> {
+    let pattern = patterns.is_valid(idx).then(|| patterns.value(idx))?;
+    let flags = flags.and_then(|f| f.is_valid(idx).then(|| f.value(idx)));
+    Some(match flags {
+        Some(flags) => format!("(?{flags}){pattern}"),
+        None => pattern.to_string(),
+    })
+}
+
+fn compile_regex(pattern: &str) -> Result<Regex, ArrowError> {
+    Regex::new(pattern)
+        .map_err(|e| ArrowError::ComputeError(format!("Regular expression did not compile: {e:?}")))
+}
+
+/// The pattern and flags arrays of a kernel, and whether they are scalars
+type RegexpArgs<'a> = (&'a dyn Array, Option<&'a dyn Array>, bool);
+
+/// Validates the arguments of the kernel `name`, returning the pattern and flags
+/// arrays, and whether they are scalars
+fn regexp_args<'a>(
+    name: &str,
+    array: &dyn Array,
+    regex_array: &'a dyn Datum,
+    flags_array: Option<&'a dyn Datum>,
+) -> Result<RegexpArgs<'a>, ArrowError> {
+    let (rhs, is_rhs_scalar) = regex_array.get();
+
+    if !matches!(array.data_type(), DataType::Utf8 | DataType::LargeUtf8) {
+        return Err(ArrowError::ComputeError(format!(
+            "{name}() requires array to be either Utf8 or LargeUtf8"
+        )));
+    }
+
+    if array.data_type() != rhs.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "{name}() requires both array and pattern to be either Utf8 or LargeUtf8"
+        )));
+    }
+
+    let flags = match flags_array {
+        Some(flags) => {
+            let (flags, is_flags_scalar) = flags.get();
+            if is_rhs_scalar != is_flags_scalar {
+                return Err(ArrowError::ComputeError(format!(
+                    "{name}() requires both pattern and flags to be either scalar or array"
+                )));
+            }
+            if rhs.data_type() != flags.data_type() {
+                return Err(ArrowError::ComputeError(format!(
+                    "{name}() requires both pattern and flags to be either string or largestring"
+                )));
+            }
+            Some(flags)
+        }
+        None => None,
+    };
+
+    if !is_rhs_scalar && rhs.len() != array.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "{name}() requires pattern array of length {} to match array of length {}",
+            rhs.len(),
+            array.len()
+        )));
+    }
+
+    Ok((rhs, flags, is_rhs_scalar))
+}
+
+/// Split each string of a String array into the substrings separated by a regular
+/// expression.
+///
+/// Modelled after the Postgres [regexp_split_to_array].
+///
+/// Returns a ListArray of [`GenericStringArray`] with each element containing the
+/// substrings of the string in `array` between the matches of the corresponding
+/// pattern in `regex_array`, which may be a scalar or an array of the same length
+/// as `array`. If there is no match, the list element contains the whole string.
+///
+/// Zero-length matches at the start or end of the string, or immediately after a
+/// previous match, are ignored, so an empty pattern splits the string into its
+/// characters. If either the string or the pattern is NULL, the list element is NULL.
+///
+/// The flags parameter is an optional text string containing zero or more single-letter flags
+/// that change the function's behavior.
+///
+/// ```
+/// # use arrow_array::{Array, Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::regexp::regexp_split;
+/// let array = StringArray::from(vec![Some("a, b,c"), None, Some("d")]);
+/// let pattern = Scalar::new(StringArray::from(vec![r",\s*"]));
+/// let result = regexp_split(&array, &pattern, None).unwrap();
+/// let result = result.as_list::<i32>();
+/// assert_eq!(result.value(0).as_string::<i32>(), &StringArray::from(vec!["a", "b", "c"]));
+/// assert!(result.is_null(1));
+/// assert_eq!(result.value(2).as_string::<i32>(), &StringArray::from(vec!["d"]));
+/// ```
+///
+/// [regexp_split_to_array]: https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP
+pub fn regexp_split(
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+    flags_array: Option<&dyn Datum>,
+) -> Result<ArrayRef, ArrowError> {
+    if let Some(dict) = array.as_any_dictionary_opt() {
+        return regexp_dictionary(dict, regex_array, |values| {
+            regexp_split(values, regex_array, flags_array)
+        });
+    }
+    let (regex, flags, is_scalar) = regexp_args("regexp_split", array, regex_array, flags_array)?;
+    match array.data_type() {
+        DataType::Utf8 => regexp_split_impl(
+            array.as_string::<i32>(),
+            Patterns::try_new(regex, flags, is_scalar)?,
+        ),
+        _ => regexp_split_impl(
+            array.as_string::<i64>(),
+            Patterns::try_new(regex, flags, is_scalar)?,
+        ),
+    }
+}
+
+fn regexp_split_impl<OffsetSize: OffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    mut patterns: Patterns<'_, OffsetSize>,
+) -> Result<ArrayRef, ArrowError> {
+    let builder: GenericStringBuilder<OffsetSize> =
+        GenericStringBuilder::with_capacity(array.len(), array.value_data().len());
+    let mut list_builder = ListBuilder::with_capacity(builder, array.len());
+    for (idx, value) in array.iter().enumerate() {
+        match (value, patterns.get(idx)?) {
+            (Some(value), Some(regex)) => append_split(&mut list_builder, regex, value),
+            _ => list_builder.append(false),
+        }
+    }
+    Ok(Arc::new(list_builder.finish()))
+}
+
+/// Count the number of non-overlapping matches of a regular expression in each string
+/// of a String array.
+///
+/// Modelled after the Postgres [regexp_count].
+///
+/// The pattern in `regex_array` may be a scalar or an array of the same length as
+/// `array`. If either the string or the pattern is NULL, the result is NULL.
+///
+/// The flags parameter is an optional text string containing zero or more single-letter flags
+/// that change the function's behavior.
+///
+/// ```
+/// # use arrow_array::{Int64Array, Scalar, StringArray};
+/// # use arrow_string::regexp::regexp_count;
+/// let array = StringArray::from(vec![Some("ABCabcAXYaxy"), None, Some("xyz")]);
+/// let pattern = Scalar::new(StringArray::from(vec!["A."]));
+/// let flags = Scalar::new(StringArray::from(vec!["i"]));
+/// let result = regexp_count(&array, &pattern, Some(&flags)).unwrap();
+/// assert_eq!(result, Int64Array::from(vec![Some(4), None, Some(0)]));
+/// ```
+///
+/// [regexp_count]: https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP
+pub fn regexp_count(
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+    flags_array: Option<&dyn Datum>,
+) -> Result<Int64Array, ArrowError> {
+    if let Some(dict) = array.as_any_dictionary_opt() {
+        let result = regexp_dictionary(dict, regex_array, |values| {
+            Ok(Arc::new(regexp_count(values, regex_array, flags_array)?))
+        })?;
+        return Ok(result.as_primitive::<Int64Type>().clone());
+    }
+    let (regex, flags, is_scalar) = regexp_args("regexp_count", array, regex_array, flags_array)?;
+    let count = |value: &str, regex: &Regex| regex.find_iter(value).count() as i64;
+    match array.data_type() {
+        DataType::Utf8 => regexp_map(
+            array.as_string::<i32>(),
+            Patterns::try_new(regex, flags, is_scalar)?,
+            count,
+        ),
+        _ => regexp_map(
+            array.as_string::<i64>(),
+            Patterns::try_new(regex, flags, is_scalar)?,
+            count,
+        ),
+    }
+}
+
+/// The unit of positions within a string returned by [`regexp_instr`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PositionUnit {
+    /// Positions count unicode characters, as in SQL
+    #[default]
+    Chars,
+    /// Positions count UTF-8 bytes
+    Bytes,
+}
+
+/// Returns the position of the start of the `n`-th non-overlapping match of a regular
+/// expression in each string of a String array.
+///
+/// Modelled after the Postgres [regexp_instr].
+///
+/// Positions are 1-based, counted in characters or bytes according to `unit`, and are
+/// 0 if the string contains fewer than `n` matches. The pattern in `regex_array` may be
+/// a scalar or an array of the same length as `array`. If either the string or the
+/// pattern is NULL, the result is NULL.
+///
+/// The flags parameter is an optional text string containing zero or more single-letter flags
+/// that change the function's behavior.
+///
+/// Returns an error if `n` is 0.
+///
+/// ```
+/// # use arrow_array::{Int64Array, Scalar, StringArray};
+/// # use arrow_string::regexp::{regexp_instr, PositionUnit};
+/// let array = StringArray::from(vec!["número 12 y 345", "ninguno"]);
+/// let pattern = Scalar::new(StringArray::from(vec![r"\d+"]));
+///
+/// let result = regexp_instr(&array, &pattern, None, 2, PositionUnit::Chars).unwrap();
+/// assert_eq!(result, Int64Array::from(vec![13, 0]));
+///
+/// let result = regexp_instr(&array, &pattern, None, 2, PositionUnit::Bytes).unwrap();
+/// assert_eq!(result, Int64Array::from(vec![14, 0]));
+/// ```
+///
+/// [regexp_instr]: https://www.postgresql.org/docs/current/functions-matching.html#FUNCTIONS-POSIX-REGEXP
+pub fn regexp_instr(
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+    flags_array: Option<&dyn Datum>,
+    n: usize,
+    unit: PositionUnit,
+) -> Result<Int64Array, ArrowError> {
+    if n == 0 {
+        return Err(ArrowError::ComputeError(
+            "regexp_instr() requires n to be greater than 0".to_string(),
+        ));
+    }
+    if let Some(dict) = array.as_any_dictionary_opt() {
+        let result = regexp_dictionary(dict, regex_array, |values| {
+            Ok(Arc::new(regexp_instr(
+                values,
+                regex_array,
+                flags_array,
+                n,
+                unit,
+            )?))
+        })?;
+        return Ok(result.as_primitive::<Int64Type>().clone());
+    }
+    let (regex, flags, is_scalar) = regexp_args("regexp_instr", array, regex_array, flags_array)?;
+    let position = |value: &str, regex: &Regex| match_position(value, regex, n, unit);
+    match array.data_type() {
+        DataType::Utf8 => regexp_map(
+            array.as_string::<i32>(),
+            Patterns::try_new(regex, flags, is_scalar)?,
+            position,
+        ),
+        _ => regexp_map(
+            array.as_string::<i64>(),
+            Patterns::try_new(regex, flags, is_scalar)?,
+            position,
+        ),
+    }
+}
+
+/// Perform SQL `array ~ regex_array` operation on a String array, or a dictionary of
+/// strings, and a scalar or array pattern.
+///
+/// Equivalent to [`regexp_is_match_utf8`] and [`regexp_is_match_utf8_scalar`], with
+/// the pattern and flags as [`Datum`]. If either the string or the pattern is NULL,
+/// the result is NULL.
+///
+/// For a dictionary and scalar pattern, the pattern is matched once per dictionary
+/// value, and the results mapped through the keys, as are the other kernels of this
+/// module accepting a [`Datum`] pattern.
+///
+/// ```
+/// # use arrow_array::{BooleanArray, DictionaryArray, Scalar, StringArray};
+/// # use arrow_array::types::Int8Type;
+/// # use arrow_string::regexp::regexp_is_match;
+/// let array: DictionaryArray<Int8Type> = vec!["apple", "kiwi", "apple"].into_iter().collect();
+/// let pattern = Scalar::new(StringArray::from(vec!["^a"]));
+/// let result = regexp_is_match(&array, &pattern, None).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![true, false, true]));
+/// ```
+pub fn regexp_is_match(
+    array: &dyn Array,
+    regex_array: &dyn Datum,
+    flags_array: Option<&dyn Datum>,
+) -> Result<BooleanArray, ArrowError> {
+    if let Some(dict) = array.as_any_dictionary_opt() {
+        let result = regexp_dictionary(dict, regex_array, |values| {
+            Ok(Arc::new(regexp_is_match(values, regex_array, flags_array)?))
+        })?;
+        return Ok(result.as_boolean().clone());
+    }
+
+    let (regex, flags, is_scalar) =
+        regexp_args("regexp_is_match", array, regex_array, flags_array)?;
+    match array.data_type() {
+        DataType::Utf8 => regexp_is_match_impl(
+            array.as_string::<i32>(),
+            Patterns::try_new(regex, flags, is_scalar)?,
+        ),
+        _ => regexp_is_match_impl(
+            array.as_string::<i64>(),
+            Patterns::try_new(regex, flags, is_scalar)?,
+        ),
+    }
+}
+
+fn regexp_is_match_impl<OffsetSize: OffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    mut patterns: Patterns<'_, OffsetSize>,
+) -> Result<BooleanArray, ArrowError> {
+    if let Patterns::Scalar(Some(regex)) = &patterns {
+        return Ok(regexp_scalar_is_match(array, regex));
+    }
+    let mut builder = BooleanBuilder::with_capacity(array.len());
+    for (idx, value) in array.iter().enumerate() {
+        match (value, patterns.get(idx)?) {
+            (Some(value), Some(regex)) => builder.append_value(regex.is_match(value)),
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+fn regexp_scalar_is_match<OffsetSize: OffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex: &Regex,
+) -> BooleanArray {
+    let values = BooleanBuffer::collect_bool(array.len(), |i| regex.is_match(array.value(i)));
+    BooleanArray::new(values, array.nulls().cloned())
+}
+
+/// Evaluates `kernel` for the dictionary `array`
+///
+/// For a scalar pattern, `kernel` is evaluated once on the values of the dictionary,
+/// and the results mapped through the keys. Otherwise the pattern differs per row,
+/// and `kernel` is evaluated on the unpacked dictionary
+fn regexp_dictionary(
+    array: &dyn AnyDictionaryArray,
+    regex_array: &dyn Datum,
+    kernel: impl Fn(&dyn Array) -> Result<ArrayRef, ArrowError>,
+) -> Result<ArrayRef, ArrowError> {
+    let (_, is_scalar) = regex_array.get();
+    match is_scalar {
+        true => take(
+            kernel(array.values().as_ref())?.as_ref(),
+            array.keys(),
+            None,
+        ),
+        false => kernel(take(array.values().as_ref(), array.keys(), None)?.as_ref()),
+    }
+}
+
+/// Applies `f` to each string of `array` and its regex, returning null if either is null
+fn regexp_map<OffsetSize: OffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    mut patterns: Patterns<'_, OffsetSize>,
+    f: impl Fn(&str, &Regex) -> i64,
+) -> Result<Int64Array, ArrowError> {
+    let mut builder = Int64Array::builder(array.len());
+    for (idx, value) in array.iter().enumerate() {
+        match (value, patterns.get(idx)?) {
+            (Some(value), Some(regex)) => builder.append_value(f(value, regex)),
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Returns the 1-based position of the start of the `n`-th match of `regex` in `value`,
+/// or 0 if there are fewer than `n` matches
+fn match_position(value: &str, regex: &Regex, n: usize, unit: PositionUnit) -> i64 {
+    match regex.find_iter(value).nth(n - 1) {
+        Some(m) => match unit {
+            PositionUnit::Bytes => m.start() as i64 + 1,
+            PositionUnit::Chars => value[..m.start()].chars().count() as i64 + 1,
+        },
+        None => 0,
+    }
+}
+
+/// A regular expression compiled once, and evaluated against many arrays
+///
+/// The kernels of this module taking a pattern argument compile the pattern on every
+/// invocation. [`RegexpMatcher`] instead compiles the pattern up front, allowing the
+/// same pattern to be evaluated against many batches without recompiling it, or
+/// looking it up per row.
+///
+/// The methods of [`RegexpMatcher`] are equivalent to calling the corresponding kernel
+/// with a scalar pattern.
+///
+/// ```
+/// # use arrow_array::{BooleanArray, Int64Array, StringArray};
+/// # use arrow_string::regexp::RegexpMatcher;
+/// let matcher = RegexpMatcher::try_new(r"\d+", None).unwrap();
+///
+/// for batch in [vec!["a1", "b"], vec!["22 33", "c"]] {
+///     let array = StringArray::from(batch);
+///     let matches = matcher.is_match(&array);
+///     assert_eq!(matches, BooleanArray::from(vec![true, false]));
+/// }
+///
+/// let array = StringArray::from(vec![Some("1 2 3"), None]);
+/// assert_eq!(matcher.count(&array), Int64Array::from(vec![Some(3), None]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegexpMatcher {
+    regex: Regex,
+}
+
+impl RegexpMatcher {
+    /// Compile `pattern`, with optional `flags`, such as `"i"` for case insensitive
+    /// matching, see the documentation [here](https://docs.rs/regex/latest/regex/#grouping-and-flags)
+    pub fn try_new(pattern: &str, flags: Option<&str>) -> Result<Self, ArrowError> {
+        let pattern = match flags {
+            Some(flags) => format!("(?{flags}){pattern}"),
+            None => pattern.to_string(),
+        };
+        Ok(Self {
+            regex: compile_regex(&pattern)?,
+        })
+    }
+
+    /// Create a new [`RegexpMatcher`] from a compiled [`Regex`]
+    pub fn new(regex: Regex) -> Self {
+        Self { regex }
+    }
+
+    /// Returns the compiled [`Regex`]
+    pub fn regex(&self) -> &Regex {
+        &self.regex
+    }
+
+    /// Returns whether each value of `array` matches, see [`regexp_is_match_utf8_scalar`]
+    pub fn is_match<OffsetSize: OffsetSizeTrait>(
+        &self,
+        array: &GenericStringArray<OffsetSize>,
+    ) -> BooleanArray {
+        regexp_scalar_is_match(array, &self.regex)
+    }
+
+    /// Returns the groups of the first match in each value of `array`, see [`regexp_match`]
+    pub fn match_groups<OffsetSize: OffsetSizeTrait>(
+        &self,
+        array: &GenericStringArray<OffsetSize>,
+    ) -> ArrayRef {
+        regexp_scalar_match(array, &self.regex).unwrap()
+    }
+
+    /// Returns the substrings of each value of `array` between the matches, see
+    /// [`regexp_split`]
+    pub fn split<OffsetSize: OffsetSizeTrait>(
+        &self,
+        array: &GenericStringArray<OffsetSize>,
+    ) -> ArrayRef {
+        regexp_split_impl(array, self.patterns()).unwrap()
+    }
+
+    /// Returns the number of matches in each value of `array`, see [`regexp_count`]
+    pub fn count<OffsetSize: OffsetSizeTrait>(
+        &self,
+        array: &GenericStringArray<OffsetSize>,
+    ) -> Int64Array {
+        let count = |value: &str, regex: &Regex| regex.find_iter(value).count() as i64;
+        regexp_map(array, self.patterns(), count).unwrap()
+    }
+
+    /// Returns the position of the `n`-th match in each value of `array`, see
+    /// [`regexp_instr`]
+    ///
+    /// Returns an error if `n` is 0.
+    pub fn instr<OffsetSize: OffsetSizeTrait>(
+        &self,
+        array: &GenericStringArray<OffsetSize>,
+        n: usize,
+        unit: PositionUnit,
+    ) -> Result<Int64Array, ArrowError> {
+        if n == 0 {
+            return Err(ArrowError::ComputeError(
+                "regexp_instr() requires n to be greater than 0".to_string(),
+            ));
+        }
+        let position = |value: &str, regex: &Regex| match_position(value, regex, n, unit);
+        regexp_map(array, self.patterns(), position)
+    }
+
+    fn patterns<OffsetSize: OffsetSizeTrait>(&self) -> Patterns<'_, OffsetSize> {
+        Patterns::Scalar(Some(Cow::Borrowed(&self.regex)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow_array::{ListArray, StringArray};
+    use arrow_array::{
+        DictionaryArray, Int64Array, Int8Array, LargeStringArray, ListArray, StringArray,
+    };
 
     #[test]
     fn match_single_group() {
@@ -514,6 +1084,294 @@ mod tests {
         assert_eq!(&expected, result);
     }
 
+    fn split_values(array: &ArrayRef) -> Vec<Option<Vec<String>>> {
+        let list = array.as_list::<i32>();
+        list.iter()
+            .map(|v| {
+                v.map(|v| {
+                    let v = v.as_string::<i32>();
+                    v.iter().map(|s| s.unwrap().to_string()).collect()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_regexp_split_scalar() {
+        let array = StringArray::from(vec![
+            Some("hello world  foo"),
+            Some(""),
+            None,
+            Some("nomatch"),
+            Some(" leading"),
+        ]);
+        let pattern = Scalar::new(StringArray::from(vec![r"\s+"]));
+        let actual = regexp_split(&array, &pattern, None).unwrap();
+        let expected = vec![
+            Some(vec![
+                "hello".to_string(),
+                "world".to_string(),
+                "foo".to_string(),
+            ]),
+            Some(vec!["".to_string()]),
+            None,
+            Some(vec!["nomatch".to_string()]),
+            Some(vec!["".to_string(), "leading".to_string()]),
+        ];
+        assert_eq!(split_values(&actual), expected);
+
+        // An empty pattern splits into characters
+        let array = StringArray::from(vec!["abc", "é!"]);
+        let pattern = Scalar::new(StringArray::from(vec![""]));
+        let actual = regexp_split(&array, &pattern, None).unwrap();
+        let expected = vec![
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+            Some(vec!["é".to_string(), "!".to_string()]),
+        ];
+        assert_eq!(split_values(&actual), expected);
+
+        // Flags
+        let array = StringArray::from(vec!["aXbxc"]);
+        let pattern = Scalar::new(StringArray::from(vec!["x"]));
+        let flags = Scalar::new(StringArray::from(vec!["i"]));
+        let actual = regexp_split(&array, &pattern, Some(&flags)).unwrap();
+        let expected = vec![Some(vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+        ])];
+        assert_eq!(split_values(&actual), expected);
+
+        // Null pattern
+        let pattern = Scalar::new(new_null_array(&DataType::Utf8, 1));
+        let actual = regexp_split(&array, &pattern, None).unwrap();
+        assert_eq!(actual.null_count(), 1);
+
+        // LargeUtf8
+        let array = LargeStringArray::from(vec!["a1b22c"]);
+        let pattern = Scalar::new(LargeStringArray::from(vec![r"\d+"]));
+        let actual = regexp_split(&array, &pattern, None).unwrap();
+        let list = actual.as_list::<i32>();
+        let values = list.value(0);
+        assert_eq!(
+            values.as_string::<i64>(),
+            &LargeStringArray::from(vec!["a", "b", "c"])
+        );
+    }
+
+    #[test]
+    fn test_regexp_split_array() {
+        let array = StringArray::from(vec![Some("a,b;c"), Some("a,b;c"), Some("x"), None]);
+        let pattern = StringArray::from(vec![Some(","), Some("[,;]"), None, Some(",")]);
+        let actual = regexp_split(&array, &pattern, None).unwrap();
+        let expected = vec![
+            Some(vec!["a".to_string(), "b;c".to_string()]),
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
+            None,
+            None,
+        ];
+        assert_eq!(split_values(&actual), expected);
+
+        let flags = StringArray::from(vec![None, None, None, Some("i")]);
+        let actual = regexp_split(&array, &pattern, Some(&flags)).unwrap();
+        assert_eq!(split_values(&actual), expected);
+
+        let pattern = StringArray::from(vec![","]);
+        let err = regexp_split(&array, &pattern, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_split() requires pattern array of length 1 to match array of length 4"
+        );
+
+        let pattern = StringArray::from(vec!["(", "(", "(", "("]);
+        let err = regexp_split(&array, &pattern, None).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Regular expression did not compile"));
+    }
+
+    #[test]
+    fn test_regexp_count() {
+        let array = StringArray::from(vec![Some("abcabc"), Some("xyz"), None, Some("")]);
+        let pattern = Scalar::new(StringArray::from(vec!["b."]));
+        let actual = regexp_count(&array, &pattern, None).unwrap();
+        assert_eq!(
+            actual,
+            Int64Array::from(vec![Some(2), Some(0), None, Some(0)])
+        );
+
+        // Per-row patterns and flags
+        let pattern = StringArray::from(vec![Some("A"), Some("[xz]"), Some("a"), None]);
+        let flags = StringArray::from(vec![Some("i"), None, None, None]);
+        let actual = regexp_count(&array, &pattern, Some(&flags)).unwrap();
+        assert_eq!(actual, Int64Array::from(vec![Some(2), Some(2), None, None]));
+
+        let array = LargeStringArray::from(vec!["aaaa"]);
+        let pattern = Scalar::new(LargeStringArray::from(vec!["aa"]));
+        let actual = regexp_count(&array, &pattern, None).unwrap();
+        assert_eq!(actual, Int64Array::from(vec![2]));
+
+        let pattern = Scalar::new(StringArray::from(vec!["aa"]));
+        let err = regexp_count(&array, &pattern, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_count() requires both array and pattern to be either Utf8 or LargeUtf8"
+        );
+    }
+
+    #[test]
+    fn test_regexp_instr() {
+        let array = StringArray::from(vec![Some("ab ab ab"), Some("éab"), None, Some("none")]);
+        let pattern = Scalar::new(StringArray::from(vec!["ab"]));
+        let instr = |n, unit| regexp_instr(&array, &pattern, None, n, unit).unwrap();
+
+        let expected = Int64Array::from(vec![Some(1), Some(2), None, Some(0)]);
+        assert_eq!(instr(1, PositionUnit::Chars), expected);
+        let expected = Int64Array::from(vec![Some(1), Some(3), None, Some(0)]);
+        assert_eq!(instr(1, PositionUnit::Bytes), expected);
+        let expected = Int64Array::from(vec![Some(7), Some(0), None, Some(0)]);
+        assert_eq!(instr(3, PositionUnit::Chars), expected);
+        let expected = Int64Array::from(vec![Some(0), Some(0), None, Some(0)]);
+        assert_eq!(instr(4, PositionUnit::Chars), expected);
+
+        let pattern = StringArray::from(vec![Some("b"), Some("B"), Some("b"), Some("o")]);
+        let flags = StringArray::from(vec![None, Some("i"), None, None]);
+        let actual = regexp_instr(&array, &pattern, Some(&flags), 1, PositionUnit::Chars);
+        let expected = Int64Array::from(vec![Some(2), Some(3), None, Some(2)]);
+        assert_eq!(actual.unwrap(), expected);
+
+        let err = regexp_instr(&array, &pattern, None, 0, PositionUnit::Chars).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_instr() requires n to be greater than 0"
+        );
+
+        let flags = Scalar::new(StringArray::from(vec!["i"]));
+        let err = regexp_instr(&array, &pattern, Some(&flags), 1, PositionUnit::Chars);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Compute error: regexp_instr() requires both pattern and flags to be either scalar or array"
+        );
+    }
+
+    #[test]
+    fn test_regexp_matcher() {
+        let matcher = RegexpMatcher::try_new(r"(\d)(\d)?", Some("x")).unwrap();
+        let array = StringArray::from(vec![Some("a12b3"), None, Some("none"), Some("")]);
+
+        let expected = regexp_is_match_utf8_scalar(&array, r"(\d)(\d)?", Some("x")).unwrap();
+        assert_eq!(matcher.is_match(&array), expected);
+
+        let pattern = Scalar::new(StringArray::from(vec![r"(\d)(\d)?"]));
+        let flags = Scalar::new(StringArray::from(vec!["x"]));
+        let expected = regexp_match(&array, &pattern, Some(&flags)).unwrap();
+        assert_eq!(matcher.match_groups(&array).as_ref(), expected.as_ref());
+
+        let expected = regexp_split(&array, &pattern, Some(&flags)).unwrap();
+        assert_eq!(matcher.split(&array).as_ref(), expected.as_ref());
+
+        let expected = regexp_count(&array, &pattern, Some(&flags)).unwrap();
+        assert_eq!(matcher.count(&array), expected);
+        assert_eq!(
+            expected,
+            Int64Array::from(vec![Some(2), None, Some(0), Some(0)])
+        );
+
+        for unit in [PositionUnit::Chars, PositionUnit::Bytes] {
+            let expected = regexp_instr(&array, &pattern, Some(&flags), 2, unit).unwrap();
+            assert_eq!(matcher.instr(&array, 2, unit).unwrap(), expected);
+        }
+        let err = matcher.instr(&array, 0, PositionUnit::Chars).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_instr() requires n to be greater than 0"
+        );
+
+        // Reused across arrays of different offset types
+        let large = LargeStringArray::from(vec!["x9"]);
+        assert_eq!(matcher.count(&large), Int64Array::from(vec![1]));
+        assert_eq!(matcher.regex().as_str(), r"(?x)(\d)(\d)?");
+
+        let err = RegexpMatcher::try_new("(", None).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Regular expression did not compile"));
+    }
+
+    #[test]
+    fn test_regexp_dictionary() {
+        let values = StringArray::from(vec![Some("abc-12"), Some("xyz"), None, Some("ab-3-4")]);
+        let keys = Int8Array::from(vec![Some(0), Some(1), None, Some(2), Some(3), Some(0)]);
+        let dict = DictionaryArray::new(keys, Arc::new(values));
+        let unpacked = StringArray::from(vec![
+            Some("abc-12"),
+            Some("xyz"),
+            None,
+            None,
+            Some("ab-3-4"),
+            Some("abc-12"),
+        ]);
+
+        let pattern = Scalar::new(StringArray::from(vec![r"-(\d)"]));
+        let actual = regexp_is_match(&dict, &pattern, None).unwrap();
+        let expected = regexp_is_match(&unpacked, &pattern, None).unwrap();
+        assert_eq!(actual, expected);
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None,
+            None,
+            Some(true),
+            Some(true),
+        ]);
+        assert_eq!(actual, expected);
+
+        let actual = regexp_match(&dict, &pattern, None).unwrap();
+        let expected = regexp_match(&unpacked, &pattern, None).unwrap();
+        assert_eq!(actual.as_ref(), expected.as_ref());
+
+        let actual = regexp_split(&dict, &pattern, None).unwrap();
+        let expected = regexp_split(&unpacked, &pattern, None).unwrap();
+        assert_eq!(actual.as_ref(), expected.as_ref());
+
+        let actual = regexp_count(&dict, &pattern, None).unwrap();
+        let expected = regexp_count(&unpacked, &pattern, None).unwrap();
+        assert_eq!(actual, expected);
+
+        let actual = regexp_instr(&dict, &pattern, None, 2, PositionUnit::Chars).unwrap();
+        let expected = regexp_instr(&unpacked, &pattern, None, 2, PositionUnit::Chars).unwrap();
+        assert_eq!(actual, expected);
+
+        // Array patterns are evaluated against the unpacked dictionary
+        let patterns = StringArray::from(vec!["B", "Y", "x", "x", "4$", "z"]);
+        let flags = StringArray::from(vec!["i", "i", "i", "i", "i", "i"]);
+        let actual = regexp_is_match(&dict, &patterns, Some(&flags)).unwrap();
+        let expected = regexp_is_match(&unpacked, &patterns, Some(&flags)).unwrap();
+        assert_eq!(actual, expected);
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            None,
+            None,
+            Some(true),
+            Some(false),
+        ]);
+        assert_eq!(actual, expected);
+
+        let actual = regexp_count(&dict, &patterns, None).unwrap();
+        let expected = regexp_count(&unpacked, &patterns, None).unwrap();
+        assert_eq!(actual, expected);
+
+        // Dictionaries of non-string values are not supported
+        let dict =
+            DictionaryArray::new(Int8Array::from(vec![0]), Arc::new(Int8Array::from(vec![1])));
+        let err = regexp_is_match(&dict, &pattern, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: regexp_is_match() requires array to be either Utf8 or LargeUtf8"
+        );
+    }
+
     macro_rules! test_flag_utf8 {
         ($test_name:ident, $left:expr, $right:expr, $op:expr, $expected:expr) => {
             #[test]
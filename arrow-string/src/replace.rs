@@ -0,0 +1,394 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to replace literal substrings and characters of string arrays,
+//! as SQL `REPLACE` and `TRANSLATE`
+//!
+//! See [`regexp`](crate::regexp) for replacing regular expression matches
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use arrow_array::builder::GenericStringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+
+/// Returns `array` with every occurrence of the substring `from` replaced by `to`
+///
+/// Occurrences are found from the start of each value and do not overlap. Values are
+/// returned unchanged if `from` is empty.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8,
+///   or DictionaryArray with above Arrays as values
+/// * `from` and `to` must be the same type as `array`, or its values
+/// * `from` and `to` may be either a [`Scalar`] or an array of the same length as `array`
+/// * replace of null, or with a null `from` or `to`, is null.
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::replace::replace;
+/// let array = StringArray::from(vec![Some("a.b.c"), Some("abc"), None]);
+/// let from = StringArray::new_scalar(".");
+/// let to = StringArray::new_scalar("::");
+/// let result = replace(&array, &from, &to).unwrap();
+/// let expected = StringArray::from(vec![Some("a::b::c"), Some("abc"), None]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn replace(
+    array: &dyn Array,
+    from: &dyn Datum,
+    to: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    apply(Op::Replace, array, from, to)
+}
+
+/// Returns `array` with each character of a value found in `from` replaced by the
+/// character at the same position in `to`
+///
+/// Characters of `from` without a corresponding character in `to`, as `from` is
+/// longer, are removed. If a character occurs several times in `from`, its first
+/// occurrence is used.
+///
+/// See the documentation on [`replace`] for the supported arguments
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_string::replace::translate;
+/// let array = StringArray::from(vec!["12345", "a2x5", "héllo"]);
+/// let from = StringArray::new_scalar("143é");
+/// let to = StringArray::new_scalar("ax");
+/// let result = translate(&array, &from, &to).unwrap();
+/// let expected = StringArray::from(vec!["a2x5", "a2x5", "hllo"]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn translate(
+    array: &dyn Array,
+    from: &dyn Datum,
+    to: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    apply(Op::Translate, array, from, to)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Replace,
+    Translate,
+}
+
+impl Op {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Replace => "replace",
+            Self::Translate => "translate",
+        }
+    }
+}
+
+fn apply(
+    op: Op,
+    array: &dyn Array,
+    from: &dyn Datum,
+    to: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let (from, to) = (from.get(), to.get());
+
+    if let Some(d) = array.as_any_dictionary_opt() {
+        return match from.1 && to.1 {
+            true => {
+                let values = apply_args(op, d.values().as_ref(), from, to)?;
+                Ok(d.with_values(values))
+            }
+            false => {
+                let values = take(d.values().as_ref(), d.keys(), None)?;
+                apply_args(op, values.as_ref(), from, to)
+            }
+        };
+    }
+
+    apply_args(op, array, from, to)
+}
+
+fn apply_args(
+    op: Op,
+    array: &dyn Array,
+    from: (&dyn Array, bool),
+    to: (&dyn Array, bool),
+) -> Result<ArrayRef, ArrowError> {
+    let name = op.name();
+    for (arg_name, (arg, scalar)) in [("from", from), ("to", to)] {
+        if arg.data_type() != array.data_type() {
+            return Err(ArrowError::ComputeError(format!(
+                "{name}() requires {arg_name} to be {}, got {}",
+                array.data_type(),
+                arg.data_type()
+            )));
+        }
+        if !scalar && arg.len() != array.len() {
+            return Err(ArrowError::ComputeError(format!(
+                "{name}() requires arguments of the same length, got {} and {}",
+                array.len(),
+                arg.len()
+            )));
+        }
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => {
+            let from = (from.0.as_string::<i32>(), from.1);
+            let to = (to.0.as_string::<i32>(), to.1);
+            Ok(Arc::new(apply_impl(op, array.as_string::<i32>(), from, to)))
+        }
+        DataType::LargeUtf8 => {
+            let from = (from.0.as_string::<i64>(), from.1);
+            let to = (to.0.as_string::<i64>(), to.1);
+            Ok(Arc::new(apply_impl(op, array.as_string::<i64>(), from, to)))
+        }
+        other => Err(ArrowError::ComputeError(format!(
+            "{name}() not supported for {other:?}"
+        ))),
+    }
+}
+
+fn apply_impl<O: OffsetSizeTrait>(
+    op: Op,
+    array: &GenericStringArray<O>,
+    from: (&GenericStringArray<O>, bool),
+    to: (&GenericStringArray<O>, bool),
+) -> GenericStringArray<O> {
+    fn value_at<O: OffsetSizeTrait>(
+        (a, scalar): (&GenericStringArray<O>, bool),
+        idx: usize,
+    ) -> Option<&str> {
+        let idx = if scalar { 0 } else { idx };
+        a.is_valid(idx).then(|| a.value(idx))
+    }
+
+    let mut builder = GenericStringBuilder::with_capacity(array.len(), array.value_data().len());
+    // Scalar arguments only require a single translation
+    let scalar_args = from.1 && to.1;
+    let mut translation = Translation::default();
+    if let (true, Some(f), Some(t)) = (scalar_args, value_at(from, 0), value_at(to, 0)) {
+        translation.reset(f, t);
+    }
+    for (idx, value) in array.iter().enumerate() {
+        match (value, value_at(from, idx), value_at(to, idx)) {
+            (Some(value), Some(from_value), Some(to_value)) => {
+                match op {
+                    Op::Replace => write_replaced(value, from_value, to_value, &mut builder),
+                    Op::Translate => {
+                        if !scalar_args {
+                            translation.reset(from_value, to_value);
+                        }
+                        translation.write(value, &mut builder)
+                    }
+                }
+                .unwrap();
+                builder.append_value("")
+            }
+            _ => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+/// Writes `value` with each occurrence of `from` replaced by `to` to `out`
+fn write_replaced(value: &str, from: &str, to: &str, out: &mut impl Write) -> std::fmt::Result {
+    if from.is_empty() {
+        return out.write_str(value);
+    }
+    let mut last = 0;
+    for (start, _) in value.match_indices(from) {
+        out.write_str(&value[last..start])?;
+        out.write_str(to)?;
+        last = start + from.len();
+    }
+    out.write_str(&value[last..])
+}
+
+/// The characters of a `translate` and their replacements, or `None` if removed
+#[derive(Debug, Default)]
+struct Translation {
+    map: HashMap<char, Option<char>>,
+}
+
+impl Translation {
+    fn reset(&mut self, from: &str, to: &str) {
+        self.map.clear();
+        let mut to = to.chars();
+        for c in from.chars() {
+            let replacement = to.next();
+            self.map.entry(c).or_insert(replacement);
+        }
+    }
+
+    /// Writes `value` with its characters translated to `out`
+    fn write(&self, value: &str, out: &mut impl Write) -> std::fmt::Result {
+        if self.map.is_empty() {
+            return out.write_str(value);
+        }
+        value
+            .chars()
+            .filter_map(|c| self.map.get(&c).copied().unwrap_or(Some(c)))
+            .try_for_each(|c| out.write_char(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    #[test]
+    fn test_replace() {
+        let array = StringArray::from(vec![
+            Some("aaaa"),
+            Some("hello world"),
+            None,
+            Some(""),
+            Some("日本語の日本"),
+        ]);
+        let result = replace(
+            &array,
+            &StringArray::new_scalar("aa"),
+            &StringArray::new_scalar("b"),
+        )
+        .unwrap();
+        let expected = StringArray::from(vec![
+            Some("bb"),
+            Some("hello world"),
+            None,
+            Some(""),
+            Some("日本語の日本"),
+        ]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let result = replace(
+            &array,
+            &StringArray::new_scalar("日本"),
+            &StringArray::new_scalar(""),
+        )
+        .unwrap();
+        assert_eq!(result.as_string::<i32>().value(4), "語の");
+
+        // Arrays of arguments, with nulls and an empty from
+        let array = StringArray::from(vec!["abc", "abc", "abc", "abcabc", "abc"]);
+        let from = StringArray::from(vec![Some("b"), None, Some(""), Some("c"), Some("x")]);
+        let to = StringArray::from(vec![Some("B"), Some("x"), Some("x"), Some("--"), None]);
+        let result = replace(&array, &from, &to).unwrap();
+        let expected =
+            StringArray::from(vec![Some("aBc"), None, Some("abc"), Some("ab--ab--"), None]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let large = LargeStringArray::from(vec![Some("a-b"), None]);
+        let from = LargeStringArray::new_scalar("-");
+        let to = LargeStringArray::new_scalar("+");
+        let result = replace(&large, &from, &to).unwrap();
+        let expected = LargeStringArray::from(vec![Some("a+b"), None]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+
+        // Dictionaries with scalar arguments replace their values
+        let dict: DictionaryArray<Int32Type> = vec!["ab", "cb", "ab"].into_iter().collect();
+        let from = StringArray::new_scalar("b");
+        let result = replace(&dict, &from, &StringArray::new_scalar("d")).unwrap();
+        let result = result.as_dictionary::<Int32Type>();
+        assert_eq!(result.keys(), dict.keys());
+        let values = result.values().as_string::<i32>();
+        assert_eq!(values, &StringArray::from(vec!["ad", "cd"]));
+
+        let to = StringArray::from(vec!["1", "2", "3"]);
+        let result = replace(&dict, &from, &to).unwrap();
+        let expected = StringArray::from(vec!["a1", "c2", "a3"]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_translate() {
+        let array = StringArray::from(vec![None, Some("hello"), Some(""), Some("ñandú")]);
+        let from = StringArray::new_scalar("elñú");
+        let to = StringArray::new_scalar("ELnu");
+        let result = translate(&array, &from, &to).unwrap();
+        let expected = StringArray::from(vec![None, Some("hELLo"), Some(""), Some("nandu")]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        // Characters without a replacement are removed, and the first occurrence is used
+        let array = StringArray::from(vec!["abcabc", "abcabc", "abcabc", "abc", "abc"]);
+        let from = StringArray::from(vec![Some("abc"), Some("aa"), Some(""), None, Some("c")]);
+        let to = StringArray::from(vec![Some("x"), Some("12"), Some("x"), Some("x"), Some("")]);
+        let result = translate(&array, &from, &to).unwrap();
+        let expected = StringArray::from(vec![
+            Some("xx"),
+            Some("1bc1bc"),
+            Some("abcabc"),
+            None,
+            Some("ab"),
+        ]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        let large = LargeStringArray::from(vec!["a-b", "c"]);
+        let from = LargeStringArray::new_scalar("-c");
+        let to = LargeStringArray::from(vec!["+", "_C"]);
+        let result = translate(&large, &from, &to).unwrap();
+        let expected = LargeStringArray::from(vec!["a+b", "C"]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+
+        let null = Scalar::new(StringArray::from(vec![None::<&str>]));
+        let array = StringArray::from(vec!["a", "b"]);
+        let result = translate(&array, &null, &StringArray::new_scalar("x")).unwrap();
+        assert_eq!(result.as_string::<i32>(), &StringArray::new_null(2));
+    }
+
+    #[test]
+    fn test_replace_errors() {
+        let array = StringArray::from(vec!["a", "b"]);
+        let scalar = StringArray::new_scalar("x");
+        let cases: [(&dyn Datum, &dyn Datum, &str); 3] = [
+            (
+                &LargeStringArray::new_scalar("x"),
+                &scalar,
+                "replace() requires from to be Utf8, got LargeUtf8",
+            ),
+            (
+                &scalar,
+                &StringArray::from(vec!["x"]),
+                "replace() requires arguments of the same length, got 2 and 1",
+            ),
+            (
+                &scalar,
+                &Int32Array::new_scalar(1),
+                "replace() requires to to be Utf8, got Int32",
+            ),
+        ];
+        for (from, to, expected) in cases {
+            let err = replace(&array, from, to).unwrap_err();
+            assert_eq!(err.to_string(), format!("Compute error: {expected}"));
+        }
+
+        let ints = Int32Array::from(vec![1]);
+        let err = translate(
+            &ints,
+            &Int32Array::new_scalar(1),
+            &Int32Array::new_scalar(2),
+        );
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Compute error: translate() not supported for Int32"
+        );
+    }
+}
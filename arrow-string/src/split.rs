@@ -0,0 +1,421 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to extract the parts of string arrays separated by a delimiter,
+//! as SQL `SPLIT_PART` and `SUBSTRING_INDEX`
+
+use std::sync::Arc;
+
+use arrow_array::builder::GenericStringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+
+/// Returns the `n`-th part of each value of `array` split on `delimiter`
+///
+/// Parts are numbered from `1`, with a negative `n` counting from the end of the value.
+/// If there are fewer than `n` parts the result is an empty string, and an empty
+/// `delimiter` returns the value as its only part.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8,
+///   or DictionaryArray with above Arrays as values
+/// * `delimiter` must be the same type as `array`, or its values, and `n` must be Int64
+/// * `delimiter` and `n` may be either a [`Scalar`] or an array of the same length as `array`
+/// * split_part of null, or with a null `delimiter` or `n`, is null.
+///
+/// Returns an error if `n` is `0`
+///
+/// ```
+/// # use arrow_array::{Int64Array, StringArray};
+/// # use arrow_string::split::split_part;
+/// let array = StringArray::from(vec![Some("a,b,c"), Some("d"), None]);
+/// let delimiter = StringArray::new_scalar(",");
+///
+/// let result = split_part(&array, &delimiter, &Int64Array::new_scalar(2)).unwrap();
+/// let expected = StringArray::from(vec![Some("b"), Some(""), None]);
+/// assert_eq!(result.as_ref(), &expected);
+///
+/// let result = split_part(&array, &delimiter, &Int64Array::new_scalar(-1)).unwrap();
+/// let expected = StringArray::from(vec![Some("c"), Some("d"), None]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn split_part(
+    array: &dyn Array,
+    delimiter: &dyn Datum,
+    n: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    split(Split::Part, array, delimiter, n)
+}
+
+/// Returns each value of `array` up to the `count`-th occurrence of `delimiter`
+///
+/// If `count` is negative, returns the value after the `count`-th occurrence of
+/// `delimiter` counting from the end of the value instead. The entire value is returned
+/// if there are fewer than `count` occurrences, and an empty string if `count` is `0` or
+/// `delimiter` is empty, as MySQL `SUBSTRING_INDEX`.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8,
+///   or DictionaryArray with above Arrays as values
+/// * `delimiter` must be the same type as `array`, or its values, and `count` must be Int64
+/// * `delimiter` and `count` may be either a [`Scalar`] or an array of the same length as `array`
+/// * substring_index of null, or with a null `delimiter` or `count`, is null.
+///
+/// ```
+/// # use arrow_array::{Int64Array, StringArray};
+/// # use arrow_string::split::substring_index;
+/// let array = StringArray::from(vec![Some("www.apache.org"), Some("arrow"), None]);
+/// let delimiter = StringArray::new_scalar(".");
+///
+/// let result = substring_index(&array, &delimiter, &Int64Array::new_scalar(2)).unwrap();
+/// let expected = StringArray::from(vec![Some("www.apache"), Some("arrow"), None]);
+/// assert_eq!(result.as_ref(), &expected);
+///
+/// let result = substring_index(&array, &delimiter, &Int64Array::new_scalar(-2)).unwrap();
+/// let expected = StringArray::from(vec![Some("apache.org"), Some("arrow"), None]);
+/// assert_eq!(result.as_ref(), &expected);
+/// ```
+pub fn substring_index(
+    array: &dyn Array,
+    delimiter: &dyn Datum,
+    count: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    split(Split::Index, array, delimiter, count)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Split {
+    Part,
+    Index,
+}
+
+impl Split {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Part => "split_part",
+            Self::Index => "substring_index",
+        }
+    }
+
+    /// Returns the part of `value` selected by `n`, which must not be `0` for [`Self::Part`]
+    fn apply<'a>(&self, value: &'a str, delimiter: &str, n: i64) -> &'a str {
+        let count = usize::try_from(n.unsigned_abs()).unwrap_or(usize::MAX);
+        match self {
+            Self::Part if delimiter.is_empty() => match count {
+                1 => value,
+                _ => "",
+            },
+            Self::Part if n > 0 => value.split(delimiter).nth(count - 1).unwrap_or(""),
+            Self::Part => value.rsplit(delimiter).nth(count - 1).unwrap_or(""),
+            Self::Index if delimiter.is_empty() || n == 0 => "",
+            Self::Index if n > 0 => match value.match_indices(delimiter).nth(count - 1) {
+                Some((idx, _)) => &value[..idx],
+                None => value,
+            },
+            Self::Index => match value.rmatch_indices(delimiter).nth(count - 1) {
+                Some((idx, _)) => &value[idx + delimiter.len()..],
+                None => value,
+            },
+        }
+    }
+}
+
+fn split(
+    op: Split,
+    array: &dyn Array,
+    delimiter: &dyn Datum,
+    n: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let name = op.name();
+    let delimiter = delimiter.get();
+    let n = n.get();
+    if n.0.data_type() != &DataType::Int64 {
+        return Err(ArrowError::ComputeError(format!(
+            "{name}() requires n to be Int64, got {}",
+            n.0.data_type()
+        )));
+    }
+
+    if let Some(d) = array.as_any_dictionary_opt() {
+        return match delimiter.1 && n.1 {
+            true => {
+                let values = split_args(op, d.values().as_ref(), delimiter, n)?;
+                Ok(d.with_values(values))
+            }
+            false => {
+                let values = take(d.values().as_ref(), d.keys(), None)?;
+                split_args(op, values.as_ref(), delimiter, n)
+            }
+        };
+    }
+
+    split_args(op, array, delimiter, n)
+}
+
+fn split_args(
+    op: Split,
+    array: &dyn Array,
+    delimiter: (&dyn Array, bool),
+    n: (&dyn Array, bool),
+) -> Result<ArrayRef, ArrowError> {
+    let name = op.name();
+    if delimiter.0.data_type() != array.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "{name}() requires delimiter to be {}, got {}",
+            array.data_type(),
+            delimiter.0.data_type()
+        )));
+    }
+    for (arg, _) in [delimiter, n].into_iter().filter(|(_, s)| !s) {
+        if arg.len() != array.len() {
+            return Err(ArrowError::ComputeError(format!(
+                "{name}() requires arguments of the same length, got {} and {}",
+                array.len(),
+                arg.len()
+            )));
+        }
+    }
+
+    let n = (n.0.as_primitive::<Int64Type>(), n.1);
+    match array.data_type() {
+        DataType::Utf8 => {
+            let delimiter = (delimiter.0.as_string::<i32>(), delimiter.1);
+            Ok(Arc::new(split_impl(
+                op,
+                array.as_string::<i32>(),
+                delimiter,
+                n,
+            )?))
+        }
+        DataType::LargeUtf8 => {
+            let delimiter = (delimiter.0.as_string::<i64>(), delimiter.1);
+            Ok(Arc::new(split_impl(
+                op,
+                array.as_string::<i64>(),
+                delimiter,
+                n,
+            )?))
+        }
+        other => Err(ArrowError::ComputeError(format!(
+            "{name}() not supported for {other:?}"
+        ))),
+    }
+}
+
+fn split_impl<O: OffsetSizeTrait>(
+    op: Split,
+    array: &GenericStringArray<O>,
+    delimiter: (&GenericStringArray<O>, bool),
+    n: (&Int64Array, bool),
+) -> Result<GenericStringArray<O>, ArrowError> {
+    let mut builder = GenericStringBuilder::with_capacity(array.len(), array.value_data().len());
+    for (idx, value) in array.iter().enumerate() {
+        let delimiter_idx = if delimiter.1 { 0 } else { idx };
+        let delimiter = delimiter
+            .0
+            .is_valid(delimiter_idx)
+            .then(|| delimiter.0.value(delimiter_idx));
+        let n_idx = if n.1 { 0 } else { idx };
+        let n = n.0.is_valid(n_idx).then(|| n.0.value(n_idx));
+
+        match (value, delimiter, n) {
+            (Some(value), Some(delimiter), Some(n)) => {
+                if op == Split::Part && n == 0 {
+                    return Err(ArrowError::ComputeError(
+                        "split_part() field position must not be zero".to_string(),
+                    ));
+                }
+                builder.append_value(op.apply(value, delimiter, n))
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::Int32Type;
+
+    #[test]
+    fn test_split_part() {
+        let array = StringArray::from(vec![
+            Some("a,b,,c"),
+            Some(""),
+            None,
+            Some("日本,語"),
+            Some("abc"),
+        ]);
+        let delimiter = StringArray::new_scalar(",");
+        let cases = [
+            (1, [Some("a"), Some(""), None, Some("日本"), Some("abc")]),
+            (3, [Some(""), Some(""), None, Some(""), Some("")]),
+            (4, [Some("c"), Some(""), None, Some(""), Some("")]),
+            (-1, [Some("c"), Some(""), None, Some("語"), Some("abc")]),
+            (-4, [Some("a"), Some(""), None, Some(""), Some("")]),
+            (i64::MIN, [Some(""), Some(""), None, Some(""), Some("")]),
+        ];
+        for (n, expected) in cases {
+            let result = split_part(&array, &delimiter, &Int64Array::new_scalar(n)).unwrap();
+            let expected = StringArray::from(expected.to_vec());
+            assert_eq!(result.as_string::<i32>(), &expected, "{n}");
+        }
+
+        // Arrays of delimiters and positions, with nulls and empty delimiters
+        let array = LargeStringArray::from(vec!["a::b", "a::b", "a::b", "a::b", "a::b"]);
+        let delimiter =
+            LargeStringArray::from(vec![Some("::"), Some(":"), None, Some(""), Some("")]);
+        let n = Int64Array::from(vec![Some(2), Some(2), Some(1), Some(1), Some(2)]);
+        let result = split_part(&array, &delimiter, &n).unwrap();
+        let expected =
+            LargeStringArray::from(vec![Some("b"), Some(""), None, Some("a::b"), Some("")]);
+        assert_eq!(result.as_string::<i64>(), &expected);
+
+        let n = Int64Array::new_scalar(0);
+        let err = split_part(&array, &LargeStringArray::new_scalar(":"), &n).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: split_part() field position must not be zero"
+        );
+    }
+
+    #[test]
+    fn test_substring_index() {
+        let array = StringArray::from(vec![
+            Some("www.apache.org"),
+            Some(""),
+            None,
+            Some("aaa"),
+            Some("日.本.語"),
+        ]);
+        let delimiter = StringArray::new_scalar(".");
+        let cases = [
+            (1, [Some("www"), Some(""), None, Some("aaa"), Some("日")]),
+            (
+                2,
+                [
+                    Some("www.apache"),
+                    Some(""),
+                    None,
+                    Some("aaa"),
+                    Some("日.本"),
+                ],
+            ),
+            (
+                3,
+                [
+                    Some("www.apache.org"),
+                    Some(""),
+                    None,
+                    Some("aaa"),
+                    Some("日.本.語"),
+                ],
+            ),
+            (0, [Some(""), Some(""), None, Some(""), Some("")]),
+            (-1, [Some("org"), Some(""), None, Some("aaa"), Some("語")]),
+            (
+                -2,
+                [
+                    Some("apache.org"),
+                    Some(""),
+                    None,
+                    Some("aaa"),
+                    Some("本.語"),
+                ],
+            ),
+            (
+                i64::MIN,
+                [
+                    Some("www.apache.org"),
+                    Some(""),
+                    None,
+                    Some("aaa"),
+                    Some("日.本.語"),
+                ],
+            ),
+        ];
+        for (n, expected) in cases {
+            let result = substring_index(&array, &delimiter, &Int64Array::new_scalar(n)).unwrap();
+            let expected = StringArray::from(expected.to_vec());
+            assert_eq!(result.as_string::<i32>(), &expected, "{n}");
+        }
+
+        let array = StringArray::from(vec!["aaa", "aaa", "a.b", "a.b"]);
+        let delimiter = StringArray::from(vec![Some("aa"), Some("aa"), Some(""), None]);
+        let count = Int64Array::from(vec![1, -1, 1, 1]);
+        let result = substring_index(&array, &delimiter, &count).unwrap();
+        let expected = StringArray::from(vec![Some(""), Some(""), Some(""), None]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_split_dictionary() {
+        let dict: DictionaryArray<Int32Type> = vec!["a.b", "c.d", "a.b"].into_iter().collect();
+        let delimiter = StringArray::new_scalar(".");
+        let result = split_part(&dict, &delimiter, &Int64Array::new_scalar(2)).unwrap();
+        let result = result.as_dictionary::<Int32Type>();
+        assert_eq!(result.keys(), dict.keys());
+        let values = result.values().as_string::<i32>();
+        assert_eq!(values, &StringArray::from(vec!["b", "d"]));
+
+        let count = Int64Array::from(vec![1, -1, 0]);
+        let result = substring_index(&dict, &delimiter, &count).unwrap();
+        let expected = StringArray::from(vec!["a", "d", ""]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn test_split_errors() {
+        let array = StringArray::from(vec!["a", "b"]);
+        let cases: [(&dyn Datum, &dyn Datum, &str); 4] = [
+            (
+                &StringArray::new_scalar("."),
+                &Int32Array::new_scalar(1),
+                "split_part() requires n to be Int64, got Int32",
+            ),
+            (
+                &LargeStringArray::new_scalar("."),
+                &Int64Array::new_scalar(1),
+                "split_part() requires delimiter to be Utf8, got LargeUtf8",
+            ),
+            (
+                &StringArray::from(vec!["."]),
+                &Int64Array::new_scalar(1),
+                "split_part() requires arguments of the same length, got 2 and 1",
+            ),
+            (
+                &StringArray::new_scalar("."),
+                &Int64Array::from(vec![1]),
+                "split_part() requires arguments of the same length, got 2 and 1",
+            ),
+        ];
+        for (delimiter, n, expected) in cases {
+            let err = split_part(&array, delimiter, n).unwrap_err();
+            assert_eq!(err.to_string(), format!("Compute error: {expected}"));
+        }
+
+        let array = Int32Array::from(vec![1]);
+        let delimiter = Int32Array::new_scalar(1);
+        let err = substring_index(&array, &delimiter, &Int64Array::new_scalar(1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: substring_index() not supported for Int32"
+        );
+    }
+}
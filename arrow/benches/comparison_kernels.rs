@@ -132,6 +132,29 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| gt_eq(&arr_a, &scalar).unwrap())
     });
 
+    let arr_a = create_primitive_array_with_seed::<Int32Type>(SIZE, 0.99, 42);
+    let arr_b = create_primitive_array_with_seed::<Int32Type>(SIZE, 0.99, 43);
+    c.bench_function("eq Int32 mostly null", |b| b.iter(|| eq(&arr_a, &arr_b)));
+    c.bench_function("eq scalar Int32 mostly null", |b| {
+        b.iter(|| eq(&arr_a, &scalar).unwrap())
+    });
+    c.bench_function("distinct Int32 mostly null", |b| {
+        b.iter(|| distinct(&arr_a, &arr_b))
+    });
+
+    let arr_a = Int32Array::new_null(SIZE);
+    let arr_b = create_primitive_array_with_seed::<Int32Type>(SIZE, 0.0, 43);
+    c.bench_function("eq Int32 all null", |b| b.iter(|| eq(&arr_a, &arr_b)));
+    c.bench_function("distinct Int32 all null", |b| {
+        b.iter(|| distinct(&arr_a, &arr_b))
+    });
+
+    let arr_a = create_string_array_with_len::<i32>(SIZE, 0.99, 32);
+    let arr_b = create_string_array_with_len::<i32>(SIZE, 0.99, 32);
+    c.bench_function("eq StringArray mostly null", |b| {
+        b.iter(|| eq(&arr_a, &arr_b))
+    });
+
     c.bench_function("eq MonthDayNano", |b| {
         b.iter(|| eq(&arr_month_day_nano_a, &arr_month_day_nano_b))
     });
@@ -20,9 +20,14 @@
 pub use arrow_arith::{aggregate, arithmetic, arity, bitwise, boolean, numeric, temporal};
 pub use arrow_cast::cast;
 pub use arrow_cast::parse as cast_utils;
-pub use arrow_ord::{cmp, partition, rank, sort};
+pub use arrow_ord::{approx, cmp, collation, partition, rank, sort};
+pub use arrow_row::analyze;
 pub use arrow_select::{concat, filter, interleave, nullif, take, window, zip};
-pub use arrow_string::{concat_elements, length, regexp, substring};
+#[cfg(feature = "normalize")]
+pub use arrow_string::normalize;
+pub use arrow_string::{
+    case, concat_elements, length, levenshtein, pad, regexp, replace, split, substring,
+};
 
 /// Comparison kernels for `Array`s.
 pub mod comparison {
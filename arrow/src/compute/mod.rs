@@ -20,6 +20,7 @@
 pub mod kernels;
 
 pub use self::kernels::aggregate::*;
+pub use self::kernels::analyze::batch_statistics;
 pub use self::kernels::arithmetic::*;
 pub use self::kernels::arity::*;
 pub use self::kernels::boolean::*;
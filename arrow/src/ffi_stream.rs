@@ -264,7 +264,7 @@ impl ExportedArrayStream {
 }
 
 fn get_error_code(err: &ArrowError) -> i32 {
-    match err {
+    match err.root_cause() {
         ArrowError::NotYetImplemented(_) => ENOSYS,
         ArrowError::MemoryError(_) => ENOMEM,
         ArrowError::IoError(_, _) => EIO,
@@ -303,6 +303,108 @@ impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
     }
 }
 
+/// Writes `tensor` to `writer` as an encapsulated IPC Tensor message, as defined by
+/// the [IPC format](https://arrow.apache.org/docs/format/Columnar.html#encapsulated-message-format)
+///
+/// The message can be read back with [`read_tensor`]
+///
+/// ```
+/// # use arrow::buffer::Buffer;
+/// # use arrow::tensor::{read_tensor, write_tensor, Float32Tensor};
+/// let data = Buffer::from_slice_ref([1_f32, 2., 3., 4., 5., 6.]);
+/// let tensor = Float32Tensor::new_row_major(data, Some(vec![2, 3]), Some(vec!["x", "y"])).unwrap();
+///
+/// let mut bytes = vec![];
+/// write_tensor(&mut bytes, &tensor).unwrap();
+///
+/// let bytes = Buffer::from_vec(bytes);
+/// let read = read_tensor::<arrow::datatypes::Float32Type>(&bytes).unwrap();
+/// assert_eq!(read.shape(), tensor.shape());
+/// assert_eq!(read.names(), tensor.names());
+/// assert_eq!(read.data(), tensor.data());
+/// ```
+#[cfg(feature = "ipc")]
+pub fn write_tensor<T: ArrowPrimitiveType, W: std::io::Write>(
+    writer: W,
+    tensor: &Tensor<'_, T>,
+) -> Result<()> {
+    use arrow_ipc::writer::{write_message, IpcDataGenerator, IpcWriteOptions};
+
+    let shape = tensor.shape().map(Vec::as_slice).unwrap_or_default();
+    let strides = tensor.strides().map(Vec::as_slice).unwrap_or_default();
+    let options = IpcWriteOptions::default();
+    let encoded = IpcDataGenerator::default().encoded_tensor(
+        tensor.data_type(),
+        shape,
+        strides,
+        tensor.names().map(Vec::as_slice),
+        tensor.data(),
+        &options,
+    )?;
+    write_message(writer, encoded, &options)?;
+    Ok(())
+}
+
+/// Reads a [`Tensor`] from an encapsulated IPC Tensor message, such as one written by
+/// [`write_tensor`]
+///
+/// The data of the returned tensor is a zero-copy slice of `buf`, and the names of
+/// its dimensions borrow from `buf`. Returns an error if `buf` does not contain a
+/// Tensor message, or the values of the tensor are not of type `T`
+#[cfg(feature = "ipc")]
+pub fn read_tensor<T: ArrowPrimitiveType>(buf: &Buffer) -> Result<Tensor<'_, T>> {
+    let truncated = || ArrowError::IpcError("Tensor message is truncated".to_string());
+
+    let bytes = buf.as_slice();
+    // Skip the continuation marker, absent from messages in the legacy format
+    let prefix = match bytes.get(..4) == Some(&[0xff; 4]) {
+        true => 4,
+        false => 0,
+    };
+    let meta_len = bytes.get(prefix..prefix + 4).ok_or_else(truncated)?;
+    let meta_len = i32::from_le_bytes(meta_len.try_into().unwrap());
+    let meta_start = prefix + 4;
+    let body_start = meta_start + usize::try_from(meta_len).map_err(|_| truncated())?;
+    let meta = bytes.get(meta_start..body_start).ok_or_else(truncated)?;
+
+    let message = arrow_ipc::root_as_message(meta)
+        .map_err(|err| ArrowError::ParseError(format!("Unable to get root as message: {err:?}")))?;
+    let tensor = message.header_as_tensor().ok_or_else(|| {
+        ArrowError::IpcError(format!(
+            "Expected Tensor message, got {:?}",
+            message.header_type()
+        ))
+    })?;
+
+    let data_type = arrow_ipc::convert::tensor_data_type(tensor)?;
+    if data_type != T::DATA_TYPE {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected tensor of {}, got tensor of {data_type}",
+            T::DATA_TYPE
+        )));
+    }
+
+    let data = tensor.data();
+    let (offset, length) = (data.offset() as usize, data.length() as usize);
+    let data_end = body_start
+        .checked_add(offset)
+        .and_then(|start| start.checked_add(length))
+        .filter(|end| *end <= bytes.len())
+        .ok_or_else(truncated)?;
+    let data = buf.slice_with_length(data_end - length, length);
+
+    let dims = tensor.shape();
+    if dims.is_empty() {
+        return Tensor::try_new(data, None, None, None);
+    }
+    let shape = dims.iter().map(|d| d.size_() as usize).collect();
+    let names = dims.iter().map(|d| d.name()).collect::<Option<Vec<_>>>();
+    let strides = tensor
+        .strides()
+        .map(|s| s.iter().map(|s| s as usize).collect());
+    Tensor::try_new(data, Some(shape), strides, names)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -500,4 +602,55 @@ mod tests {
             panic!("the input stride does not match the selected shape")
         }
     }
+
+    #[test]
+    #[cfg(feature = "ipc")]
+    fn test_ipc_tensor() {
+        let data = Buffer::from_slice_ref((0..12_i64).collect::<Vec<_>>());
+        let tensor =
+            Int64Tensor::new_column_major(data, Some(vec![3, 4]), Some(vec!["a", "b"])).unwrap();
+        let mut bytes = vec![];
+        write_tensor(&mut bytes, &tensor).unwrap();
+        let bytes = Buffer::from_vec(bytes);
+
+        let read = read_tensor::<Int64Type>(&bytes).unwrap();
+        assert_eq!(read.shape(), Some(&vec![3, 4]));
+        assert_eq!(read.strides(), Some(&vec![8, 24]));
+        assert_eq!(read.names(), Some(&vec!["a", "b"]));
+        assert!(read.is_column_major().unwrap());
+        assert_eq!(read.data(), tensor.data());
+
+        let err = read_tensor::<Int32Type>(&bytes).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected tensor of Int32, got tensor of Int64"
+        );
+        let err = read_tensor::<Int64Type>(&bytes.slice_with_length(0, 40)).unwrap_err();
+        assert_eq!(err.to_string(), "Ipc error: Tensor message is truncated");
+
+        // A tensor without a shape contains a single value
+        let tensor =
+            Float64Tensor::try_new(Buffer::from_slice_ref([1.5_f64]), None, None, None).unwrap();
+        let mut bytes = vec![];
+        write_tensor(&mut bytes, &tensor).unwrap();
+        let bytes = Buffer::from_vec(bytes);
+        let read = read_tensor::<Float64Type>(&bytes).unwrap();
+        assert_eq!(read.shape(), None);
+        assert_eq!(read.data().typed_data::<f64>(), &[1.5]);
+    }
+
+    #[test]
+    #[cfg(feature = "ipc")]
+    fn test_ipc_tensor_invalid_message() {
+        let schema =
+            arrow_schema::Schema::new(vec![arrow_schema::Field::new("a", DataType::Int32, false)]);
+        let mut writer = arrow_ipc::writer::StreamWriter::try_new(vec![], &schema).unwrap();
+        writer.finish().unwrap();
+        let bytes = Buffer::from_vec(writer.into_inner().unwrap());
+        let err = read_tensor::<Int32Type>(&bytes).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Expected Tensor message, got Schema"
+        );
+    }
 }
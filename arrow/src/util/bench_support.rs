@@ -0,0 +1,286 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Standardized inputs and [criterion] harnesses for benchmarking kernels
+//!
+//! Each [`KernelFamily`] has a fixed set of named, deterministically generated
+//! inputs, so that benchmarks of a kernel written by different contributors, or in
+//! downstream forks, measure the same data and can be compared across commits and
+//! hardware.
+//!
+//! ```no_run
+//! # use arrow::compute::kernels::numeric::add;
+//! # use arrow::util::bench_support::{bench_kernel, KernelFamily};
+//! # use criterion::{criterion_group, criterion_main, Criterion};
+//! fn add_benchmark(c: &mut Criterion) {
+//!     bench_kernel(c, KernelFamily::Arithmetic, "add", 8192, |arrays| {
+//!         add(&arrays[0], &arrays[1]).unwrap()
+//!     });
+//! }
+//!
+//! criterion_group!(benches, add_benchmark);
+//! criterion_main!(benches);
+//! ```
+//!
+//! This requires the `bench-support` feature
+
+use std::fmt;
+use std::sync::Arc;
+
+use criterion::{black_box, BenchmarkId, Criterion};
+use rand::distributions::Alphanumeric;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::array::*;
+use crate::datatypes::*;
+use crate::util::bench_util::*;
+
+/// The sizes, in rows, commonly used to benchmark kernels
+pub const BENCH_SIZES: [usize; 3] = [1024, 8192, 65536];
+
+/// The proportion of null values in the inputs with nulls
+pub const NULL_DENSITY: f32 = 0.1;
+
+/// A family of kernels sharing a common signature, and therefore common inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KernelFamily {
+    /// Binary numeric kernels, such as `add`, with two arrays of the same type
+    Arithmetic,
+    /// Comparison kernels, such as `eq` and `lt`, with two arrays of the same type
+    Comparison,
+    /// Boolean kernels, such as `and`, with two boolean arrays
+    Boolean,
+    /// Sort kernels, such as `sort_to_indices`, with a single array
+    Sort,
+    /// Cast kernels, with a single array to cast to another type
+    Cast,
+    /// The `filter` kernel, with an array and a boolean predicate selecting half the rows
+    Filter,
+    /// The `take` kernel, with an array and `UInt32` indices of random rows
+    Take,
+    /// Aggregate kernels, such as `sum` and `min`, with a single array
+    Aggregate,
+}
+
+impl fmt::Display for KernelFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Arithmetic => "arithmetic",
+            Self::Comparison => "comparison",
+            Self::Boolean => "boolean",
+            Self::Sort => "sort",
+            Self::Cast => "cast",
+            Self::Filter => "filter",
+            Self::Take => "take",
+            Self::Aggregate => "aggregate",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A named set of arrays passed to a kernel under benchmark
+#[derive(Debug, Clone)]
+pub struct BenchInput {
+    /// The name of the input, such as `i32_nulls`
+    pub name: String,
+    /// The arguments of the kernel
+    pub arrays: Vec<ArrayRef>,
+}
+
+impl BenchInput {
+    fn new(name: impl Into<String>, arrays: Vec<ArrayRef>) -> Self {
+        Self {
+            name: name.into(),
+            arrays,
+        }
+    }
+}
+
+/// Returns the standard inputs of `size` rows for the kernels of `family`
+///
+/// The inputs are generated from fixed seeds, and so are identical across runs
+pub fn standard_inputs(family: KernelFamily, size: usize) -> Vec<BenchInput> {
+    let mut inputs = vec![];
+    for (suffix, nulls) in [("", 0.), ("_nulls", NULL_DENSITY)] {
+        let i32s = |seed| -> ArrayRef {
+            Arc::new(create_primitive_array_with_seed::<Int32Type>(
+                size, nulls, seed,
+            ))
+        };
+        let f64s = |seed| -> ArrayRef {
+            Arc::new(create_primitive_array_with_seed::<Float64Type>(
+                size, nulls, seed,
+            ))
+        };
+        let strings = |seed| -> ArrayRef { Arc::new(string_array(size, nulls, seed)) };
+
+        let named = |name: &str| format!("{name}{suffix}");
+        match family {
+            KernelFamily::Arithmetic => {
+                inputs.push(BenchInput::new(named("i32"), vec![i32s(1), i32s(2)]));
+                inputs.push(BenchInput::new(named("f64"), vec![f64s(1), f64s(2)]));
+            }
+            KernelFamily::Comparison => {
+                inputs.push(BenchInput::new(named("i32"), vec![i32s(1), i32s(2)]));
+                inputs.push(BenchInput::new(named("f64"), vec![f64s(1), f64s(2)]));
+                inputs.push(BenchInput::new(named("utf8"), vec![strings(1), strings(2)]));
+            }
+            KernelFamily::Boolean => {
+                let bools = |seed| -> ArrayRef { Arc::new(boolean_array(size, nulls, seed)) };
+                inputs.push(BenchInput::new(named("bool"), vec![bools(1), bools(2)]));
+            }
+            KernelFamily::Sort => {
+                let dict = create_string_dict_array::<Int32Type>(size, nulls, 4);
+                inputs.push(BenchInput::new(named("i32"), vec![i32s(1)]));
+                inputs.push(BenchInput::new(named("f64"), vec![f64s(1)]));
+                inputs.push(BenchInput::new(named("utf8"), vec![strings(1)]));
+                inputs.push(BenchInput::new(named("dict"), vec![Arc::new(dict)]));
+            }
+            KernelFamily::Cast => {
+                let ints = i32s(1);
+                let int_strings = crate::compute::cast(&ints, &DataType::Utf8).unwrap();
+                inputs.push(BenchInput::new(named("i32"), vec![ints]));
+                inputs.push(BenchInput::new(named("f64"), vec![f64s(1)]));
+                inputs.push(BenchInput::new(named("utf8_i32"), vec![int_strings]));
+            }
+            KernelFamily::Filter => {
+                let predicate: ArrayRef = Arc::new(create_boolean_array(size, 0., 0.5));
+                inputs.push(BenchInput::new(
+                    named("i32"),
+                    vec![i32s(1), predicate.clone()],
+                ));
+                inputs.push(BenchInput::new(named("utf8"), vec![strings(1), predicate]));
+            }
+            KernelFamily::Take => {
+                let indices: ArrayRef = Arc::new(take_indices(size, 1));
+                inputs.push(BenchInput::new(
+                    named("i32"),
+                    vec![i32s(1), indices.clone()],
+                ));
+                inputs.push(BenchInput::new(named("utf8"), vec![strings(1), indices]));
+            }
+            KernelFamily::Aggregate => {
+                inputs.push(BenchInput::new(named("i32"), vec![i32s(1)]));
+                inputs.push(BenchInput::new(named("f64"), vec![f64s(1)]));
+            }
+        }
+    }
+    inputs
+}
+
+/// Benchmarks `kernel` with each of the [`standard_inputs`] of `size` rows for `family`
+///
+/// The benchmarks are registered in a criterion group named `{family}/{name}`, with
+/// a benchmark per input, identified by the name of the input and `size`
+pub fn bench_kernel<F, R>(
+    c: &mut Criterion,
+    family: KernelFamily,
+    name: &str,
+    size: usize,
+    kernel: F,
+) where
+    F: Fn(&[ArrayRef]) -> R,
+{
+    let mut group = c.benchmark_group(format!("{family}/{name}"));
+    for input in standard_inputs(family, size) {
+        let id = BenchmarkId::new(&input.name, size);
+        group.bench_with_input(id, &input.arrays, |b, arrays| {
+            b.iter(|| black_box(kernel(black_box(arrays))))
+        });
+    }
+    group.finish();
+}
+
+/// Benchmarks `kernel` as [`bench_kernel`], for each of the [`BENCH_SIZES`]
+pub fn bench_kernel_sizes<F, R>(c: &mut Criterion, family: KernelFamily, name: &str, kernel: F)
+where
+    F: Fn(&[ArrayRef]) -> R,
+{
+    for size in BENCH_SIZES {
+        bench_kernel(c, family, name, size, &kernel);
+    }
+}
+
+/// Creates a random array of 8 character alphanumeric strings
+fn string_array(size: usize, null_density: f32, seed: u64) -> StringArray {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size)
+        .map(|_| {
+            if rng.gen::<f32>() < null_density {
+                return None;
+            }
+            let value: Vec<u8> = (&mut rng).sample_iter(&Alphanumeric).take(8).collect();
+            Some(String::from_utf8(value).unwrap())
+        })
+        .collect()
+}
+
+/// Creates a random boolean array, with half the values true
+fn boolean_array(size: usize, null_density: f32, seed: u64) -> BooleanArray {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size)
+        .map(|_| (rng.gen::<f32>() >= null_density).then(|| rng.gen_bool(0.5)))
+        .collect()
+}
+
+/// Creates random indices of the rows of an array of `size` rows
+fn take_indices(size: usize, seed: u64) -> UInt32Array {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..size)
+        .map(|_| rng.gen_range(0..size.max(1) as u32))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_inputs() {
+        let families = [
+            KernelFamily::Arithmetic,
+            KernelFamily::Comparison,
+            KernelFamily::Boolean,
+            KernelFamily::Sort,
+            KernelFamily::Cast,
+            KernelFamily::Filter,
+            KernelFamily::Take,
+            KernelFamily::Aggregate,
+        ];
+        for family in families {
+            let inputs = standard_inputs(family, 100);
+            assert!(!inputs.is_empty(), "{family}");
+            for input in &inputs {
+                assert!(input.arrays.iter().all(|a| a.len() == 100), "{family}");
+                let nulls = input.arrays[0].null_count();
+                assert_eq!(input.name.ends_with("_nulls"), nulls > 0, "{}", input.name);
+            }
+
+            // Inputs are deterministic
+            let again = standard_inputs(family, 100);
+            for (a, b) in inputs.iter().zip(&again) {
+                assert_eq!(a.name, b.name);
+                assert_eq!(a.arrays, b.arrays);
+            }
+        }
+
+        // Binary kernels are given distinct arguments
+        let inputs = standard_inputs(KernelFamily::Comparison, 100);
+        assert_ne!(inputs[2].arrays[0].as_ref(), inputs[2].arrays[1].as_ref());
+    }
+}
@@ -0,0 +1,339 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Utilities to report the differences between two [`RecordBatch`]
+//!
+//! Comparing large batches with `assert_eq!` prints their entire debug representation,
+//! which makes locating the differing values impractical. [`diff`] instead produces a
+//! [`BatchDiff`] summarising the differences, whose [`Display`] output is suitable
+//! for an assertion message.
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow::array::{ArrayRef, Int32Array, StringArray};
+//! # use arrow::record_batch::RecordBatch;
+//! # use arrow::util::diff::diff;
+//! let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+//! let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+//! let left = RecordBatch::try_from_iter([("a", a.clone()), ("b", b)]).unwrap();
+//!
+//! let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "x", "y"]));
+//! let right = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+//!
+//! let diff = diff(&left, &right).unwrap();
+//! assert!(!diff.is_empty());
+//! assert_eq!(
+//!     diff.to_string(),
+//!     "column \"b\": 2 differences, first at row 1: left: b, right: x\n"
+//! );
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use arrow_array::{Array, RecordBatch};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{ArrowError, FieldRef};
+
+/// The number of rows compared at once, before comparing individual rows
+const CHUNK_SIZE: usize = 1024;
+
+/// A difference between the schemas of two [`RecordBatch`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaDiff {
+    /// A field only present in the left batch
+    LeftOnly(FieldRef),
+    /// A field only present in the right batch
+    RightOnly(FieldRef),
+    /// A field present in both batches, with a different definition
+    Changed {
+        /// The field in the left batch
+        left: FieldRef,
+        /// The field in the right batch
+        right: FieldRef,
+    },
+    /// The schema metadata differs
+    Metadata,
+}
+
+impl Display for SchemaDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LeftOnly(field) => write!(f, "field {:?} only in left: {field:?}", field.name()),
+            Self::RightOnly(field) => {
+                write!(f, "field {:?} only in right: {field:?}", field.name())
+            }
+            Self::Changed { left, right } => write!(
+                f,
+                "field {:?} differs: left: {left:?}, right: {right:?}",
+                left.name()
+            ),
+            Self::Metadata => write!(f, "schema metadata differs"),
+        }
+    }
+}
+
+/// The first differing row of a column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowDiff {
+    /// The index of the row
+    pub row: usize,
+    /// The formatted value of the left batch
+    pub left: String,
+    /// The formatted value of the right batch
+    pub right: String,
+}
+
+/// The differences between the values of a column present in both batches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnDiff {
+    /// The name of the column
+    pub name: String,
+    /// The number of rows with differing values
+    pub num_differences: usize,
+    /// The first row with differing values
+    pub first: RowDiff,
+}
+
+/// The differences between two [`RecordBatch`], as returned by [`diff`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchDiff {
+    num_rows: (usize, usize),
+    schema: Vec<SchemaDiff>,
+    columns: Vec<ColumnDiff>,
+}
+
+impl BatchDiff {
+    /// Returns true if the batches are equal
+    pub fn is_empty(&self) -> bool {
+        self.num_rows.0 == self.num_rows.1 && self.schema.is_empty() && self.columns.is_empty()
+    }
+
+    /// Returns the number of rows of the left and right batches
+    pub fn num_rows(&self) -> (usize, usize) {
+        self.num_rows
+    }
+
+    /// Returns the differences between the schemas
+    pub fn schema_diffs(&self) -> &[SchemaDiff] {
+        &self.schema
+    }
+
+    /// Returns the columns with differing values, in the order of the left schema
+    pub fn column_diffs(&self) -> &[ColumnDiff] {
+        &self.columns
+    }
+}
+
+impl Display for BatchDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (left, right) = self.num_rows;
+        if left != right {
+            writeln!(f, "row count differs: left: {left}, right: {right}")?;
+        }
+        for s in &self.schema {
+            writeln!(f, "{s}")?;
+        }
+        for c in &self.columns {
+            let suffix = if c.num_differences == 1 { "" } else { "s" };
+            writeln!(
+                f,
+                "column {:?}: {} difference{suffix}, first at row {}: left: {}, right: {}",
+                c.name, c.num_differences, c.first.row, c.first.left, c.first.right
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the differences between `left` and `right`
+///
+/// Columns are matched by name. The values of columns present in both batches with the
+/// same data type are compared up to the length of the shorter batch, with nulls
+/// considered equal to each other and floating point values compared by their bit
+/// representation.
+///
+/// Returns an error if the differing values cannot be formatted
+pub fn diff(left: &RecordBatch, right: &RecordBatch) -> Result<BatchDiff, ArrowError> {
+    let (l_schema, r_schema) = (left.schema(), right.schema());
+    let mut schema = vec![];
+    let mut columns = vec![];
+
+    for (l_idx, l_field) in l_schema.fields().iter().enumerate() {
+        let r_idx = match r_schema.index_of(l_field.name()) {
+            Ok(idx) => idx,
+            Err(_) => {
+                schema.push(SchemaDiff::LeftOnly(l_field.clone()));
+                continue;
+            }
+        };
+        let r_field = &r_schema.fields()[r_idx];
+        if l_field != r_field {
+            schema.push(SchemaDiff::Changed {
+                left: l_field.clone(),
+                right: r_field.clone(),
+            });
+        }
+        if l_field.data_type() != r_field.data_type() {
+            continue;
+        }
+
+        let column = diff_column(left.column(l_idx), right.column(r_idx))?;
+        if let Some((num_differences, first)) = column {
+            columns.push(ColumnDiff {
+                name: l_field.name().clone(),
+                num_differences,
+                first,
+            });
+        }
+    }
+
+    let right_only = r_schema
+        .fields()
+        .iter()
+        .filter(|f| l_schema.field_with_name(f.name()).is_err());
+    schema.extend(right_only.map(|f| SchemaDiff::RightOnly(f.clone())));
+    if l_schema.metadata() != r_schema.metadata() {
+        schema.push(SchemaDiff::Metadata);
+    }
+
+    Ok(BatchDiff {
+        num_rows: (left.num_rows(), right.num_rows()),
+        schema,
+        columns,
+    })
+}
+
+/// Returns the number of differing rows, and the first differing row, if any
+fn diff_column(
+    left: &dyn Array,
+    right: &dyn Array,
+) -> Result<Option<(usize, RowDiff)>, ArrowError> {
+    let len = left.len().min(right.len());
+    let (left_data, right_data) = (left.to_data(), right.to_data());
+
+    let mut num_differences = 0;
+    let mut first = None;
+    for start in (0..len).step_by(CHUNK_SIZE) {
+        let chunk_len = CHUNK_SIZE.min(len - start);
+        if left_data.slice(start, chunk_len) == right_data.slice(start, chunk_len) {
+            continue;
+        }
+        for row in start..start + chunk_len {
+            if left_data.slice(row, 1) != right_data.slice(row, 1) {
+                num_differences += 1;
+                first.get_or_insert(row);
+            }
+        }
+    }
+
+    let row = match first {
+        Some(row) => row,
+        None => return Ok(None),
+    };
+    let options = FormatOptions::new().with_null("null");
+    let left = ArrayFormatter::try_new(left, &options)?
+        .value(row)
+        .try_to_string()?;
+    let right = ArrayFormatter::try_new(right, &options)?
+        .value(row)
+        .try_to_string()?;
+    Ok(Some((num_differences, RowDiff { row, left, right })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Float64Array, Int32Array, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+
+    #[test]
+    fn test_diff() {
+        let a: ArrayRef = Arc::new(Int32Array::from_iter_values(0..5000));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), None, Some("c")]));
+        let batch = RecordBatch::try_from_iter([("a", a.clone())]).unwrap();
+        assert!(diff(&batch, &batch).unwrap().is_empty());
+        assert_eq!(diff(&batch, &batch).unwrap().to_string(), "");
+
+        // Differences spanning several chunks
+        let values = (0..5000).map(|x| match x {
+            3 | 2000 | 4999 => Some(-1),
+            4000 => Some(-2),
+            _ => Some(x),
+        });
+        let changed: ArrayRef = Arc::new(Int32Array::from_iter(values));
+        let other = RecordBatch::try_from_iter([("a", changed)]).unwrap();
+        let d = diff(&batch, &other).unwrap();
+        assert!(d.schema_diffs().is_empty());
+        let expected = ColumnDiff {
+            name: "a".to_string(),
+            num_differences: 4,
+            first: RowDiff {
+                row: 3,
+                left: "3".to_string(),
+                right: "-1".to_string(),
+            },
+        };
+        assert_eq!(d.column_diffs(), &[expected]);
+
+        // Differing row counts only compare the common rows
+        let sliced = batch.slice(10, 100);
+        let other = batch.slice(10, 50);
+        let d = diff(&sliced, &other).unwrap();
+        assert!(!d.is_empty());
+        assert_eq!(d.num_rows(), (100, 50));
+        assert!(d.column_diffs().is_empty());
+
+        let left = RecordBatch::try_from_iter([("b", b.clone())]).unwrap();
+        let right: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), Some("b"), None]));
+        let right = RecordBatch::try_from_iter([("b", right)]).unwrap();
+        let d = diff(&left, &right).unwrap();
+        assert_eq!(
+            d.to_string(),
+            "column \"b\": 2 differences, first at row 1: left: null, right: b\n"
+        );
+
+        // Schema differences
+        let c: ArrayRef = Arc::new(Float64Array::from(vec![1., 2., 3.]));
+        let left = RecordBatch::try_from_iter([("a", a.slice(0, 3)), ("b", b.clone())]).unwrap();
+        let right = RecordBatch::try_from_iter([("b", c), ("c", a.slice(0, 3))]).unwrap();
+        let d = diff(&left, &right).unwrap();
+        assert!(d.column_diffs().is_empty());
+        assert_eq!(
+            d.schema_diffs(),
+            &[
+                SchemaDiff::LeftOnly(left.schema().fields()[0].clone()),
+                SchemaDiff::Changed {
+                    left: left.schema().fields()[1].clone(),
+                    right: right.schema().fields()[0].clone(),
+                },
+                SchemaDiff::RightOnly(right.schema().fields()[1].clone()),
+            ]
+        );
+
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let schema = Schema::new(vec![Field::new("b", DataType::Utf8, true)]);
+        let schema = Arc::new(schema.with_metadata(metadata));
+        let right = RecordBatch::try_new(schema, vec![b]).unwrap();
+        let left = left.project(&[1]).unwrap();
+        let d = diff(&left, &right).unwrap();
+        assert_eq!(d.schema_diffs(), &[SchemaDiff::Metadata]);
+        assert_eq!(d.to_string(), "schema metadata differs\n");
+    }
+}
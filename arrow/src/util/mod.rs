@@ -20,10 +20,13 @@ pub use arrow_buffer::{bit_chunk_iterator, bit_util};
 pub use arrow_data::bit_iterator;
 pub use arrow_data::bit_mask;
 
+#[cfg(feature = "bench-support")]
+pub mod bench_support;
 #[cfg(feature = "test_utils")]
 pub mod bench_util;
 #[cfg(feature = "test_utils")]
 pub mod data_gen;
+pub mod diff;
 #[cfg(feature = "prettyprint")]
 pub use arrow_cast::pretty;
 pub mod string_writer;
@@ -23,6 +23,7 @@ use std::sync::Arc;
 use arrow_array::cast::AsArray;
 use arrow_array::Array;
 use arrow_array::{RecordBatch, RecordBatchReader};
+use arrow_cast::date64::apply_date64_policy_batch;
 use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef};
 use arrow_select::filter::prep_null_mask_filter;
 
@@ -41,6 +42,7 @@ pub use crate::arrow::array_reader::RowGroups;
 use crate::column::page::{PageIterator, PageReader};
 use crate::file::footer;
 use crate::file::page_index::index_reader;
+pub use arrow_cast::date64::Date64Policy;
 pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
 pub use selection::{RowSelection, RowSelector};
 
@@ -74,6 +76,8 @@ pub struct ArrowReaderBuilder<T> {
     pub(crate) limit: Option<usize>,
 
     pub(crate) offset: Option<usize>,
+
+    pub(crate) date64_policy: Date64Policy,
 }
 
 impl<T> ArrowReaderBuilder<T> {
@@ -90,6 +94,7 @@ impl<T> ArrowReaderBuilder<T> {
             selection: None,
             limit: None,
             offset: None,
+            date64_policy: Date64Policy::Preserve,
         }
     }
 
@@ -180,6 +185,20 @@ impl<T> ArrowReaderBuilder<T> {
             ..self
         }
     }
+
+    /// Set how to handle [`DataType::Date64`](ArrowType::Date64) values that are
+    /// not aligned to midnight, defaults to [`Date64Policy::Preserve`]
+    ///
+    /// Such values can only be read from `INT64` columns with an embedded arrow
+    /// schema of [`DataType::Date64`](ArrowType::Date64), as `DATE` columns are
+    /// always aligned to midnight. The policy is not applied to the values seen
+    /// by a [`RowFilter`]
+    pub fn with_date64_policy(self, date64_policy: Date64Policy) -> Self {
+        Self {
+            date64_policy,
+            ..self
+        }
+    }
 }
 
 /// Options that control how metadata is read for a parquet file
@@ -429,6 +448,7 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             batch_size,
             array_reader,
             apply_range(selection, reader.num_rows(), self.offset, self.limit),
+            self.date64_policy,
         ))
     }
 }
@@ -497,6 +517,7 @@ pub struct ParquetRecordBatchReader {
     array_reader: Box<dyn ArrayReader>,
     schema: SchemaRef,
     selection: Option<VecDeque<RowSelector>>,
+    date64_policy: Date64Policy,
 }
 
 impl Iterator for ParquetRecordBatchReader {
@@ -567,7 +588,9 @@ impl Iterator for ParquetRecordBatchReader {
 
                 match struct_array {
                     Err(err) => Some(Err(err)),
-                    Ok(e) => (e.len() > 0).then(|| Ok(RecordBatch::from(e))),
+                    Ok(e) => (e.len() > 0).then(|| {
+                        apply_date64_policy_batch(&RecordBatch::from(e), self.date64_policy)
+                    }),
                 }
             }
         }
@@ -612,6 +635,7 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(Schema::new(levels.fields.clone())),
             selection: selection.map(|s| s.trim().into()),
+            date64_policy: Date64Policy::Preserve,
         })
     }
 
@@ -622,6 +646,7 @@ impl ParquetRecordBatchReader {
         batch_size: usize,
         array_reader: Box<dyn ArrayReader>,
         selection: Option<RowSelection>,
+        date64_policy: Date64Policy,
     ) -> Self {
         let schema = match array_reader.get_data_type() {
             ArrowType::Struct(ref fields) => Schema::new(fields.clone()),
@@ -633,6 +658,7 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(schema),
             selection: selection.map(|s| s.trim().into()),
+            date64_policy,
         }
     }
 }
@@ -693,7 +719,12 @@ pub(crate) fn evaluate_predicate(
     input_selection: Option<RowSelection>,
     predicate: &mut dyn ArrowPredicate,
 ) -> Result<RowSelection> {
-    let reader = ParquetRecordBatchReader::new(batch_size, array_reader, input_selection.clone());
+    let reader = ParquetRecordBatchReader::new(
+        batch_size,
+        array_reader,
+        input_selection.clone(),
+        Date64Policy::Preserve,
+    );
     let mut filters = vec![];
     for maybe_batch in reader {
         let maybe_batch = maybe_batch?;
@@ -738,7 +769,8 @@ mod tests {
     use arrow_array::builder::*;
     use arrow_array::cast::AsArray;
     use arrow_array::types::{
-        Decimal128Type, Decimal256Type, DecimalType, Float16Type, Float32Type, Float64Type,
+        Date64Type, Decimal128Type, Decimal256Type, DecimalType, Float16Type, Float32Type,
+        Float64Type,
     };
     use arrow_array::*;
     use arrow_array::{RecordBatch, RecordBatchReader};
@@ -748,7 +780,7 @@ mod tests {
     use arrow_select::concat::concat_batches;
 
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ArrowReaderOptions, ParquetRecordBatchReader,
+        ArrowPredicateFn, ArrowReaderOptions, Date64Policy, ParquetRecordBatchReader,
         ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelector,
     };
     use crate::arrow::schema::add_encoded_arrow_schema_to_metadata;
@@ -2694,6 +2726,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_date64_policy() {
+        // An INT64 column with an embedded arrow schema of Date64
+        let arrow_schema = Schema::new(vec![Field::new("d", ArrowDataType::Date64, true)]);
+        let schema = Arc::new(parse_message_type("message s { OPTIONAL INT64 d; }").unwrap());
+        let mut props = WriterProperties::builder().build();
+        add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = SerializedFileWriter::new(&mut buf, schema, Arc::new(props)).unwrap();
+        let mut row_group_writer = writer.next_row_group().unwrap();
+        let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+        col_writer
+            .typed::<Int64Type>()
+            .write_batch(&[86_400_000, 86_400_001], Some(&[1, 0, 1]), None)
+            .unwrap();
+        col_writer.close().unwrap();
+        row_group_writer.close().unwrap();
+        writer.close().unwrap();
+        let bytes = Bytes::from(buf);
+
+        let read = |policy| {
+            ParquetRecordBatchReaderBuilder::try_new(bytes.clone())
+                .unwrap()
+                .with_date64_policy(policy)
+                .build()
+                .unwrap()
+                .next()
+                .unwrap()
+        };
+
+        let batch = read(Date64Policy::Preserve).unwrap();
+        let expected = Date64Array::from(vec![Some(86_400_000), None, Some(86_400_001)]);
+        assert_eq!(batch.column(0).as_primitive::<Date64Type>(), &expected);
+
+        let batch = read(Date64Policy::Truncate).unwrap();
+        let expected = Date64Array::from(vec![Some(86_400_000), None, Some(86_400_000)]);
+        assert_eq!(batch.column(0).as_primitive::<Date64Type>(), &expected);
+
+        let err = read(Date64Policy::Error).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Date64 value 86400001 at index 2 is not a multiple of 86400000 milliseconds"
+        );
+    }
+
     #[test]
     fn test_read_lz4_raw() {
         let testdata = arrow::util::test_util::parquet_test_data();
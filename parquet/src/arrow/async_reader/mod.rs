@@ -95,7 +95,7 @@ use arrow_schema::{DataType, Fields, Schema, SchemaRef};
 use crate::arrow::array_reader::{build_array_reader, RowGroups};
 use crate::arrow::arrow_reader::{
     apply_range, evaluate_predicate, selects_any, ArrowReaderBuilder, ArrowReaderMetadata,
-    ArrowReaderOptions, ParquetRecordBatchReader, RowFilter, RowSelection,
+    ArrowReaderOptions, Date64Policy, ParquetRecordBatchReader, RowFilter, RowSelection,
 };
 use crate::arrow::ProjectionMask;
 
@@ -383,6 +383,7 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             fields: self.fields,
             limit: self.limit,
             offset: self.offset,
+            date64_policy: self.date64_policy,
         };
 
         // Ensure schema of ParquetRecordBatchStream respects projection, and does
@@ -425,6 +426,8 @@ struct ReaderFactory<T> {
     limit: Option<usize>,
 
     offset: Option<usize>,
+
+    date64_policy: Date64Policy,
 }
 
 impl<T> ReaderFactory<T>
@@ -521,6 +524,7 @@ where
             batch_size,
             build_array_reader(self.fields.as_deref(), &projection, &row_group)?,
             selection,
+            self.date64_policy,
         );
 
         Ok((self, Some(reader)))
@@ -1526,6 +1530,7 @@ mod tests {
             filter: None,
             limit: None,
             offset: None,
+            date64_policy: Date64Policy::Preserve,
         };
 
         let mut skip = true;